@@ -0,0 +1,122 @@
+//! Criterion benchmarks for the search and render hot paths
+//!
+//! Covers the three places a regression hurts the most on every keystroke:
+//! fuzzy scoring, grouped-results computation, and syntax highlighting for
+//! the preview panel. Run with `cargo bench --bench hot_paths`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use script_kit_gpui::frecency::FrecencyStore;
+use script_kit_gpui::scripts::{
+    fuzzy_search_scripts, get_grouped_results, Script, Scriptlet, SearchResult,
+};
+use script_kit_gpui::syntax::highlight_code_lines;
+use std::path::PathBuf;
+
+/// Number of scripts in the synthetic kit
+const FIXTURE_SIZE: usize = 10_000;
+
+/// Word pool for synthetic script names; cycling through combinations gives
+/// realistic prefix/substring collisions for the fuzzy matcher
+const WORDS: &[&str] = &[
+    "open", "close", "git", "deploy", "sync", "note", "clip", "window", "focus", "search", "build",
+    "test", "format", "convert", "resize", "upload", "fetch", "parse", "merge", "clean",
+];
+
+/// Generate a synthetic kit of `n` scripts with plausible names, tags, and
+/// descriptions
+fn synthetic_scripts(n: usize) -> Vec<Script> {
+    (0..n)
+        .map(|i| {
+            let a = WORDS[i % WORDS.len()];
+            let b = WORDS[(i / WORDS.len()) % WORDS.len()];
+            Script {
+                name: format!("{}-{}-{}", a, b, i),
+                path: PathBuf::from(format!("/kit/main/scripts/{}-{}-{}.ts", a, b, i)),
+                extension: "ts".to_string(),
+                description: Some(format!("{} the {} target (#{})", a, b, i)),
+                tags: vec![a.to_string()],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// A frecency store with a handful of recent entries, as a warm install has
+fn synthetic_frecency(scripts: &[Script]) -> FrecencyStore {
+    let mut store = FrecencyStore::with_path(PathBuf::from("/nonexistent/bench-frecency.json"));
+    for script in scripts.iter().take(10) {
+        store.record_use(&script.path.to_string_lossy());
+    }
+    store
+}
+
+/// Representative TypeScript source for the preview highlighter
+const SAMPLE_SOURCE: &str = r#"// Name: Deploy Preview
+// Description: Builds and deploys the current branch
+
+import { exec } from "child_process";
+
+const branch = await arg("Which branch?", ["main", "develop"]);
+const confirmed = await arg(`Deploy ${branch}?`, ["yes", "no"]);
+
+if (confirmed === "yes") {
+    exec(`./scripts/deploy.sh ${branch}`, (err, stdout) => {
+        if (err) {
+            console.error("Deploy failed", err);
+        } else {
+            console.log(stdout);
+        }
+    });
+}
+"#;
+
+fn bench_fuzzy_search(c: &mut Criterion) {
+    let scripts = synthetic_scripts(FIXTURE_SIZE);
+
+    let mut group = c.benchmark_group("fuzzy_search_scripts");
+    for query in ["g", "git", "gitsync", "zzzz-no-match"] {
+        group.bench_function(query, |b| {
+            b.iter(|| fuzzy_search_scripts(black_box(&scripts), black_box(query)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_grouped_results(c: &mut Criterion) {
+    let scripts = synthetic_scripts(FIXTURE_SIZE);
+    let scriptlets: Vec<Scriptlet> = Vec::new();
+    let frecency = synthetic_frecency(&scripts);
+
+    let mut group = c.benchmark_group("get_grouped_results");
+    for (label, filter) in [("browse", ""), ("filtered", "deploy")] {
+        group.bench_function(label, |b| {
+            b.iter(|| {
+                let (items, results): (_, Vec<SearchResult>) = get_grouped_results(
+                    black_box(&scripts),
+                    &scriptlets,
+                    &[],
+                    &[],
+                    &frecency,
+                    black_box(filter),
+                    5,
+                );
+                (items, results)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_syntax_highlighting(c: &mut Criterion) {
+    c.bench_function("highlight_code_lines/typescript", |b| {
+        b.iter(|| highlight_code_lines(black_box(SAMPLE_SOURCE), black_box("ts")))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_fuzzy_search,
+    bench_grouped_results,
+    bench_syntax_highlighting
+);
+criterion_main!(benches);
@@ -1113,6 +1113,14 @@ export interface ArgConfig {
   }>;
   /** Actions shown in the actions panel (Cmd+K to open) */
   actions?: Action[];
+  /** When false, choices keep their provided order while filtering (default: true) */
+  sort?: boolean;
+  /**
+   * Pagination for huge choice sets: called with the current choice count
+   * when the user scrolls near the end. Return the next page of choices,
+   * or an empty array when there are no more pages.
+   */
+  onMoreChoices?: (offset: number) => (string | Choice)[] | Promise<(string | Choice)[]>;
 }
 
 /**
@@ -1183,6 +1191,10 @@ interface ArgMessage {
   placeholder: string;
   choices: Choice[];
   actions?: SerializableAction[];
+  /** When false, choices keep their provided order while filtering */
+  sort?: boolean;
+  /** When true, the app requests further pages via moreChoices messages */
+  hasMore?: boolean;
 }
 
 interface DivMessage {
@@ -1236,6 +1248,15 @@ interface SelectMessage {
   multiple: boolean;
 }
 
+interface TableMessage {
+  type: 'table';
+  id: string;
+  columns: string[];
+  rows: string[][];
+  placeholder?: string;
+  multiple?: boolean;
+}
+
 interface FieldsMessage {
   type: 'fields';
   id: string;
@@ -1729,6 +1750,63 @@ interface InspectMessage {
   data: unknown;
 }
 
+// =============================================================================
+// Network Fetch Message Types (proxied through the app)
+// =============================================================================
+
+interface FetchMessage {
+  type: 'fetch';
+  requestId: string;
+  url: string;
+  method?: string;
+  headers?: Record<string, string>;
+  body?: string;
+}
+
+interface FetchResultMessage {
+  type: 'fetchResult';
+  requestId: string;
+  success: boolean;
+  status?: number;
+  body?: string;
+  error?: string;
+}
+
+interface KitFetchOptions {
+  /** HTTP method; defaults to GET */
+  method?: string;
+  /** Extra request headers */
+  headers?: Record<string, string>;
+  /** Request body for POST/PUT/PATCH */
+  body?: string;
+}
+
+interface KitFetchResponse {
+  /** HTTP status code */
+  status: number;
+  /** Response body as text */
+  body: string;
+}
+
+// =============================================================================
+// Focus (Do Not Disturb) Message Types
+// =============================================================================
+
+interface DndMessage {
+  type: 'dnd';
+  requestId: string;
+  enabled: boolean;
+}
+
+interface DndResultMessage {
+  type: 'dndResult';
+  requestId: string;
+  success: boolean;
+  /** Whether Focus was active before this change */
+  previous?: boolean;
+  error?: string;
+}
+
 // =============================================================================
 // Menu Bar Message Types
 // =============================================================================
@@ -1816,6 +1894,80 @@ function send(msg: object): void {
   process.stdout.write(`${JSON.stringify(msg)}\n`);
 }
 
+// ============================================================
+// CONSOLE CAPTURE
+// ============================================================
+// Forward console output to the app as structured `log` messages so it lands
+// in the per-run log file and the in-app log panel with jump-to-source.
+// The original methods keep writing to the terminal for local debugging;
+// console.log/info are rerouted to stderr because stdout carries the JSONL
+// protocol stream. SDK-internal `[SDK]`-prefixed lines stay local.
+
+const rawConsole = {
+  log: console.log.bind(console),
+  info: console.info.bind(console),
+  warn: console.warn.bind(console),
+  error: console.error.bind(console),
+};
+
+function formatConsoleArgs(args: unknown[]): string {
+  return args
+    .map((arg) => {
+      if (typeof arg === 'string') return arg;
+      if (arg instanceof Error) return arg.stack ?? String(arg);
+      try {
+        return JSON.stringify(arg);
+      } catch {
+        return String(arg);
+      }
+    })
+    .join(' ');
+}
+
+// First stack frame outside the SDK = the script line that logged
+function consoleCallerSource(): { file: string; line: number } | undefined {
+  const stack = new Error().stack;
+  if (!stack) return undefined;
+  for (const frame of stack.split('\n').slice(1)) {
+    const match = frame.match(/\(?((?:file:\/\/)?\/[^\s)]+?):(\d+):\d+\)?/);
+    if (!match) continue;
+    const file = match[1].replace(/^file:\/\//, '');
+    if (file.includes('kit-sdk')) continue;
+    return { file, line: Number(match[2]) };
+  }
+  return undefined;
+}
+
+function forwardConsole(level: 'info' | 'warn' | 'error', args: unknown[]): void {
+  const first = args[0];
+  // SDK-internal diagnostics stay on stderr only
+  if (typeof first === 'string' && first.startsWith('[SDK')) return;
+  const source = consoleCallerSource();
+  send({
+    type: 'log',
+    level,
+    text: formatConsoleArgs(args),
+    ...(source ? { file: source.file, line: source.line } : {}),
+  });
+}
+
+console.log = (...args: unknown[]) => {
+  forwardConsole('info', args);
+  rawConsole.error(...args); // stderr - stdout is the protocol channel
+};
+console.info = (...args: unknown[]) => {
+  forwardConsole('info', args);
+  rawConsole.error(...args); // stderr - stdout is the protocol channel
+};
+console.warn = (...args: unknown[]) => {
+  forwardConsole('warn', args);
+  rawConsole.warn(...args);
+};
+console.error = (...args: unknown[]) => {
+  forwardConsole('error', args);
+  rawConsole.error(...args);
+};
+
 // Use raw stdin reading instead of readline interface
 // This works better with bun's --preload mode
 let stdinBuffer = '';
@@ -1856,6 +2008,35 @@ function removePending(id: string): ((msg: any) => void) | undefined {
   return resolver;
 }
 
+// Pagination handlers for arg() prompts with onMoreChoices, keyed by prompt id.
+// The app sends {type: 'moreChoices', id, offset} when the user nears the end
+// of the list; we reply with a choicesPage message.
+const moreChoicesHandlers = new Map<
+  string,
+  (offset: number) => (string | Choice)[] | Promise<(string | Choice)[]>
+>();
+
+async function handleMoreChoicesRequest(id: string, offset: number): Promise<void> {
+  const handler = moreChoicesHandlers.get(id);
+  if (!handler) return;
+  let page: (string | Choice)[] = [];
+  try {
+    page = await Promise.resolve(handler(offset));
+  } catch {
+    page = [];
+  }
+  const hasMore = page.length > 0;
+  if (!hasMore) {
+    moreChoicesHandlers.delete(id);
+  }
+  send({
+    type: 'choicesPage',
+    id,
+    choices: normalizeChoices(page),
+    hasMore,
+  } as any);
+}
+
 process.stdin.on('data', (chunk: string) => {
   console.error('[SDK_DEBUG] Received stdin chunk:', chunk.length, 'bytes');
   stdinBuffer += chunk;
@@ -1890,6 +2071,11 @@ process.stdin.on('data', (chunk: string) => {
           }
         }
         
+        // Handle paginated choice requests
+        if ((msg as any).type === 'moreChoices') {
+          handleMoreChoicesRequest((msg as any).id, (msg as any).offset ?? 0);
+        }
+        
         // Handle actionTriggered messages
         if (msg.type === 'actionTriggered') {
           (globalThis as any).__handleActionTriggered(msg as ActionTriggeredMessage);
@@ -2016,7 +2202,20 @@ declare global {
    * @returns Array of selected values
    */
   function select(placeholder: string, choices: (string | Choice)[]): Promise<string[]>;
-  
+
+  /**
+   * Table prompt - displays tabular data with sortable columns and fuzzy row filtering
+   * @param placeholder - Filter input placeholder shown to user
+   * @param data - Array of row objects (columns derived from keys) or explicit { columns, rows }
+   * @param options - Set multiple: true to allow selecting several rows with Space
+   * @returns The selected row as an object keyed by column name (array of objects when multiple)
+   */
+  function table(
+    placeholder: string,
+    data: Record<string, unknown>[] | { columns: string[]; rows: unknown[][] },
+    options?: { multiple?: boolean }
+  ): Promise<Record<string, string> | Record<string, string>[]>;
+
   /**
    * Multi-field form prompt
    * @param fieldDefs - Array of field definitions (strings become both name and label)
@@ -2477,7 +2676,31 @@ declare global {
    * @param data - Data to inspect
    */
   function inspect(data: unknown): Promise<void>;
-  
+
+  /**
+   * HTTP request proxied through the app with per-domain consent
+   *
+   * The app prompts the user the first time a script contacts a domain and
+   * logs every request, so prefer this over the runtime's native fetch when
+   * the user should stay in control of network access.
+   *
+   * @param url - URL to request
+   * @param options - Method, headers, and body
+   * @returns Response status and body text; rejects when consent is denied
+   */
+  function kitFetch(url: string, options?: KitFetchOptions): Promise<KitFetchResponse>;
+
+  /**
+   * Turn Focus (Do Not Disturb) on or off
+   *
+   * Returns the previous state; the app restores it automatically when the
+   * script exits, so there's no need to turn Focus back off by hand.
+   *
+   * @param enabled - true to enable Focus, false to disable
+   * @returns Whether Focus was active before the change
+   */
+  function dnd(enabled: boolean): Promise<boolean>;
+
   // =============================================================================
   // Clipboard History Functions
   // =============================================================================
@@ -2773,15 +2996,23 @@ globalThis.arg = async function arg(
         await Promise.resolve(config.onSubmit(value));
       }
       
+      moreChoicesHandlers.delete(id);
       resolve(value);
     });
     
+    // Register pagination handler before showing the prompt
+    if (config?.onMoreChoices) {
+      moreChoicesHandlers.set(id, config.onMoreChoices);
+    }
+    
     const message: ArgMessage = {
       type: 'arg',
       id,
       placeholder,
       choices: normalizedChoices,
       actions: serializedActions,
+      sort: config?.sort,
+      hasMore: config?.onMoreChoices ? true : undefined,
     };
     
     send(message);
@@ -3122,6 +3353,61 @@ globalThis.select = async function select(
   });
 };
 
+globalThis.table = async function table(
+  placeholder: string,
+  data: Record<string, unknown>[] | { columns: string[]; rows: unknown[][] },
+  options?: { multiple?: boolean }
+): Promise<Record<string, string> | Record<string, string>[]> {
+  const id = nextId();
+  const multiple = options?.multiple ?? false;
+
+  // Accept either an array of row objects (columns derived from keys,
+  // in order of first appearance) or an explicit { columns, rows } shape
+  let columns: string[];
+  let rows: string[][];
+  if (Array.isArray(data)) {
+    columns = [];
+    for (const row of data) {
+      for (const key of Object.keys(row)) {
+        if (!columns.includes(key)) {
+          columns.push(key);
+        }
+      }
+    }
+    rows = data.map((row) => columns.map((col) => String(row[col] ?? '')));
+  } else {
+    columns = data.columns;
+    rows = data.rows.map((row) => row.map((cell) => String(cell ?? '')));
+  }
+
+  return new Promise((resolve) => {
+    pending.set(id, (msg: SubmitMessage) => {
+      // If user pressed Escape (value is null), exit the script
+      if (msg.value === null) {
+        process.exit(0);
+      }
+      // Value comes back as a JSON object (or array of objects when multiple)
+      const value = msg.value ?? (multiple ? '[]' : 'null');
+      try {
+        resolve(JSON.parse(value));
+      } catch {
+        resolve(multiple ? [] : {});
+      }
+    });
+
+    const message: TableMessage = {
+      type: 'table',
+      id,
+      columns,
+      rows,
+      placeholder,
+      multiple,
+    };
+
+    send(message);
+  });
+};
+
 globalThis.fields = async function fields(
   fieldDefs: (string | FieldDef)[],
   actionsInput?: Action[]
@@ -4548,6 +4834,68 @@ globalThis.inspect = async function inspect(data: unknown): Promise<void> {
   send(message);
 };
 
+// =============================================================================
+// Network Fetch (proxied through the app)
+// =============================================================================
+
+// Named kitFetch rather than fetch so the runtime's native fetch stays
+// available; use this one when the user should see and approve network access
+globalThis.kitFetch = async function kitFetch(
+  url: string,
+  options?: KitFetchOptions
+): Promise<KitFetchResponse> {
+  const id = nextId();
+
+  return new Promise((resolve, reject) => {
+    pending.set(id, (msg: ResponseMessage) => {
+      const result = msg as FetchResultMessage;
+      if (result.success) {
+        resolve({
+          status: result.status ?? 0,
+          body: result.body ?? '',
+        });
+      } else {
+        reject(new Error(result.error ?? 'Fetch failed'));
+      }
+    });
+
+    const message: FetchMessage = {
+      type: 'fetch',
+      requestId: id,
+      url,
+      method: options?.method,
+      headers: options?.headers,
+      body: options?.body,
+    };
+
+    send(message);
+  });
+};
+
+// =============================================================================
+// Focus (Do Not Disturb)
+// =============================================================================
+
+// The app restores the pre-script Focus state automatically when the script
+// exits, so dnd(true) at the top of a recording script is enough
+globalThis.dnd = async function dnd(enabled: boolean): Promise<boolean> {
+  const id = nextId();
+
+  return new Promise((resolve, reject) => {
+    pending.set(id, (msg: ResponseMessage) => {
+      const result = msg as DndResultMessage;
+      if (result.success) {
+        resolve(result.previous ?? false);
+      } else {
+        reject(new Error(result.error ?? 'Focus change failed'));
+      }
+    });
+
+    const message: DndMessage = { type: 'dnd', requestId: id, enabled };
+    send(message);
+  });
+};
+
 // =============================================================================
 // Clipboard History Functions
 // =============================================================================
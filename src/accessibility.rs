@@ -0,0 +1,121 @@
+//! VoiceOver support for the custom-drawn UI
+//!
+//! GPUI paints list items, buttons, and prompts as plain quads, so none of
+//! them appear in the macOS accessibility tree and VoiceOver sees an empty
+//! window. Until GPUI exposes a real AX bridge we meet screen readers
+//! halfway: the state changes that matter (list selection moves, the actions
+//! dialog opening and closing, prompt transitions) are posted as AX
+//! announcements against the key window, which VoiceOver reads aloud.
+//!
+//! Labels are composed from the same role/name/state metadata a native
+//! control would expose - see [`list_item_label`] and [`dialog_label`] - so
+//! the spoken output matches what an AX inspector would show for an
+//! equivalent AppKit control.
+
+#![allow(dead_code)]
+
+/// Label for a selectable list row: "name, description, 3 of 10"
+///
+/// `position` is 1-based to match how VoiceOver counts rows.
+pub fn list_item_label(
+    name: &str,
+    description: Option<&str>,
+    position: usize,
+    total: usize,
+) -> String {
+    let mut label = name.to_string();
+    if let Some(desc) = description {
+        if !desc.is_empty() {
+            label.push_str(", ");
+            label.push_str(desc);
+        }
+    }
+    if total > 0 {
+        label.push_str(&format!(", {} of {}", position.min(total), total));
+    }
+    label
+}
+
+/// Label for a dialog opening or closing: "Actions, dialog, 12 items"
+pub fn dialog_label(title: &str, item_count: usize) -> String {
+    format!("{}, dialog, {} items", title, item_count)
+}
+
+/// Post an accessibility announcement that VoiceOver reads aloud.
+///
+/// Must be called from the main thread (all call sites are GPUI event
+/// handlers, which run there). No-op when VoiceOver is not running - the
+/// notification is simply dropped by the AX server.
+#[cfg(target_os = "macos")]
+pub fn announce(text: &str) {
+    use cocoa::base::{id, nil};
+    use cocoa::foundation::NSString;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {
+        fn NSAccessibilityPostNotificationWithUserInfo(
+            element: id,
+            notification: id,
+            user_info: id,
+        );
+    }
+
+    unsafe {
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        let key_window: id = msg_send![app, keyWindow];
+        let element = if key_window != nil { key_window } else { app };
+
+        let announcement_key = NSString::alloc(nil).init_str("AXAnnouncementKey");
+        let priority_key = NSString::alloc(nil).init_str("AXPriorityKey");
+        let announcement_text = NSString::alloc(nil).init_str(text);
+        // NSAccessibilityPriorityHigh = 90; high priority interrupts whatever
+        // VoiceOver is currently reading so selection changes feel immediate
+        let priority: id = msg_send![class!(NSNumber), numberWithInteger: 90isize];
+
+        let user_info: id = msg_send![class!(NSMutableDictionary), dictionary];
+        let _: () = msg_send![user_info, setObject: announcement_text forKey: announcement_key];
+        let _: () = msg_send![user_info, setObject: priority forKey: priority_key];
+
+        let notification = NSString::alloc(nil).init_str("AXAnnouncementRequested");
+        NSAccessibilityPostNotificationWithUserInfo(element, notification, user_info);
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn announce(_text: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_item_label() {
+        assert_eq!(
+            list_item_label("Run Script", Some("Runs the thing"), 3, 10),
+            "Run Script, Runs the thing, 3 of 10"
+        );
+        assert_eq!(
+            list_item_label("Run Script", None, 1, 5),
+            "Run Script, 1 of 5"
+        );
+        // Empty descriptions are skipped rather than spoken as a pause
+        assert_eq!(
+            list_item_label("Run Script", Some(""), 1, 5),
+            "Run Script, 1 of 5"
+        );
+    }
+
+    #[test]
+    fn test_list_item_label_clamps_position() {
+        // Position never exceeds the total even if the caller's index is stale
+        assert_eq!(list_item_label("Item", None, 12, 10), "Item, 10 of 10");
+        // Zero total omits the position suffix entirely
+        assert_eq!(list_item_label("Item", None, 1, 0), "Item");
+    }
+
+    #[test]
+    fn test_dialog_label() {
+        assert_eq!(dialog_label("Actions", 12), "Actions, dialog, 12 items");
+    }
+}
@@ -12,6 +12,7 @@
 #![allow(dead_code)]
 
 use crate::components::scrollbar::{Scrollbar, ScrollbarColors};
+use crate::components::text_input::{TextInput, TextInputState};
 use crate::designs::{get_tokens, DesignColors, DesignVariant};
 use crate::logging;
 use crate::protocol::ProtocolAction;
@@ -34,6 +35,8 @@ pub struct ScriptInfo {
     pub name: String,
     /// Full path to the script file
     pub path: String,
+    /// Whether the item is pinned to the PINNED section
+    pub pinned: bool,
 }
 
 impl ScriptInfo {
@@ -41,8 +44,14 @@ impl ScriptInfo {
         ScriptInfo {
             name: name.into(),
             path: path.into(),
+            pinned: false,
         }
     }
+
+    pub fn with_pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
 }
 
 // Import PathInfo from prompts module (use crate:: for local import)
@@ -154,6 +163,36 @@ pub fn get_path_context_actions(path_info: &PathInfo) -> Vec<Action> {
         .with_shortcut("⌘⌫"),
     ];
 
+    // Share targets (files only - AirDrop/Mail/Messages present system UI)
+    if !path_info.is_dir {
+        actions.extend([
+            Action::new(
+                "share_airdrop",
+                "Share via AirDrop",
+                Some("Send to a nearby device".to_string()),
+                ActionCategory::ScriptContext,
+            ),
+            Action::new(
+                "share_mail",
+                "Share via Mail",
+                Some("Attach to a new email".to_string()),
+                ActionCategory::ScriptContext,
+            ),
+            Action::new(
+                "share_messages",
+                "Share via Messages",
+                Some("Send in a new message".to_string()),
+                ActionCategory::ScriptContext,
+            ),
+            Action::new(
+                "copy_to_icloud",
+                "Copy to iCloud Drive",
+                Some("Copy into the iCloud Drive folder".to_string()),
+                ActionCategory::ScriptContext,
+            ),
+        ]);
+    }
+
     // Add directory-specific action for navigating into
     if path_info.is_dir {
         actions.insert(
@@ -220,6 +259,36 @@ pub fn get_script_context_actions(script: &ScriptInfo) -> Vec<Action> {
             ActionCategory::ScriptContext,
         )
         .with_shortcut("⌘⇧C"),
+        if script.pinned {
+            Action::new(
+                "toggle_pin",
+                "Unpin from Top",
+                Some("Remove from the PINNED section".to_string()),
+                ActionCategory::ScriptContext,
+            )
+            .with_shortcut("⌘P")
+        } else {
+            Action::new(
+                "toggle_pin",
+                "Pin to Top",
+                Some("Keep above RECENT in the main list".to_string()),
+                ActionCategory::ScriptContext,
+            )
+            .with_shortcut("⌘P")
+        },
+        Action::new(
+            "toggle_hidden",
+            "Hide from Search",
+            Some("Remove from the main list; run Unhide All Scripts to restore".to_string()),
+            ActionCategory::ScriptContext,
+        ),
+        Action::new(
+            "delete_script",
+            "Delete Script",
+            Some("Move to Recently Deleted (restorable)".to_string()),
+            ActionCategory::ScriptContext,
+        )
+        .with_shortcut("⌘⌫"),
     ]
 }
 
@@ -276,7 +345,8 @@ pub struct ActionsDialog {
     pub actions: Vec<Action>,
     pub filtered_actions: Vec<usize>, // Indices into actions
     pub selected_index: usize,        // Index within filtered_actions
-    pub search_text: String,
+    /// Search input state (shared TextInputState: cursor, selection, editing)
+    pub search_input: TextInputState,
     pub focus_handle: FocusHandle,
     pub on_select: ActionCallback,
     /// Currently focused script for context-aware actions
@@ -359,7 +429,7 @@ impl ActionsDialog {
             actions,
             filtered_actions,
             selected_index: 0,
-            search_text: String::new(),
+            search_input: TextInputState::new(),
             focus_handle,
             on_select,
             focused_script: None,
@@ -405,7 +475,7 @@ impl ActionsDialog {
             actions,
             filtered_actions,
             selected_index: 0,
-            search_text: String::new(),
+            search_input: TextInputState::new(),
             focus_handle,
             on_select,
             focused_script,
@@ -465,7 +535,7 @@ impl ActionsDialog {
         self.actions = converted;
         self.filtered_actions = (0..self.actions.len()).collect();
         self.selected_index = 0;
-        self.search_text.clear();
+        self.search_input.clear();
         self.sdk_actions = Some(actions);
     }
 
@@ -520,7 +590,7 @@ impl ActionsDialog {
             self.actions = Self::build_actions(&self.focused_script);
             self.filtered_actions = (0..self.actions.len()).collect();
             self.selected_index = 0;
-            self.search_text.clear();
+            self.search_input.clear();
         }
     }
 
@@ -572,10 +642,10 @@ impl ActionsDialog {
             .get(self.selected_index)
             .and_then(|&idx| self.actions.get(idx).map(|a| a.id.clone()));
 
-        if self.search_text.is_empty() {
+        if self.search_input.is_empty() {
             self.filtered_actions = (0..self.actions.len()).collect();
         } else {
-            let search_lower = self.search_text.to_lowercase();
+            let search_lower = self.search_input.text().to_lowercase();
 
             // Score each action and collect (index, score) pairs
             let mut scored: Vec<(usize, i32)> = self
@@ -683,20 +753,37 @@ impl ActionsDialog {
         true
     }
 
-    /// Handle character input
-    pub fn handle_char(&mut self, ch: char, cx: &mut Context<Self>) {
-        self.search_text.push(ch);
-        self.refilter();
-        cx.notify();
+    /// Current search text (convenience accessor over the input state)
+    pub fn search_text(&self) -> &str {
+        self.search_input.text()
     }
 
-    /// Handle backspace
-    pub fn handle_backspace(&mut self, cx: &mut Context<Self>) {
-        if !self.search_text.is_empty() {
-            self.search_text.pop();
-            self.refilter();
+    /// Handle a key event on the search input.
+    ///
+    /// Delegates to the shared TextInputState so the search field gets full
+    /// editing support: left/right cursor movement, Option/Cmd word and line
+    /// jumps, Shift selection, Cmd+A select-all, clipboard, and mid-string
+    /// insertion/deletion. Returns true if the input consumed the key.
+    pub fn handle_key(
+        &mut self,
+        key: &str,
+        key_char: Option<&str>,
+        cmd: bool,
+        alt: bool,
+        shift: bool,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let old_text = self.search_input.text().to_string();
+        let handled = self
+            .search_input
+            .handle_key(key, key_char, cmd, alt, shift, cx);
+        if handled {
+            if self.search_input.text() != old_text {
+                self.refilter();
+            }
             cx.notify();
         }
+        handled
     }
 
     /// Move selection up
@@ -709,6 +796,7 @@ impl ActionsDialog {
                 "ACTIONS_SCROLL",
                 &format!("Up: selected_index={}", self.selected_index),
             );
+            self.announce_selected_action();
             cx.notify();
         }
     }
@@ -723,10 +811,25 @@ impl ActionsDialog {
                 "ACTIONS_SCROLL",
                 &format!("Down: selected_index={}", self.selected_index),
             );
+            self.announce_selected_action();
             cx.notify();
         }
     }
 
+    /// Announce the selected action to VoiceOver (the dialog is custom-drawn
+    /// and invisible to the accessibility tree)
+    fn announce_selected_action(&self) {
+        if let Some(action) = self.get_selected_action() {
+            let label = crate::accessibility::list_item_label(
+                &action.title,
+                action.description.as_deref(),
+                self.selected_index + 1,
+                self.filtered_actions.len(),
+            );
+            crate::accessibility::announce(&label);
+        }
+    }
+
     /// Get the currently selected action ID (for external handling)
     pub fn get_selected_action_id(&self) -> Option<String> {
         if let Some(&action_idx) = self.filtered_actions.get(self.selected_index) {
@@ -887,22 +990,22 @@ impl Render for ActionsDialog {
         // We do NOT attach our own on_key_down handler to avoid double-processing.
 
         // Render search input - compact version
-        let search_display = if self.search_text.is_empty() {
-            SharedString::from("Search actions...")
-        } else {
-            SharedString::from(self.search_text.clone())
-        };
+        let search_is_empty = self.search_input.is_empty();
 
         // Use helper method for design/theme color extraction
         let (search_box_bg, border_color, _muted_text, dimmed_text, _secondary_text) =
             self.get_search_colors(&colors);
 
-        // Get primary text color for cursor (matches main list styling)
-        let primary_text = if self.design_variant == DesignVariant::Default {
-            rgb(self.theme.colors.text.primary)
+        // Get primary/dimmed text hex for the TextInput component
+        let (primary_text_hex, dimmed_text_hex) = if self.design_variant == DesignVariant::Default {
+            (
+                self.theme.colors.text.primary,
+                self.theme.colors.text.dimmed,
+            )
         } else {
-            rgb(colors.text_primary)
+            (colors.text_primary, colors.text_dimmed)
         };
+        let primary_text = rgb(primary_text_hex);
 
         // Get accent color for the search input focus indicator
         let accent_color_hex = if self.design_variant == DesignVariant::Default {
@@ -910,8 +1013,6 @@ impl Render for ActionsDialog {
         } else {
             colors.accent
         };
-        let accent_color = rgb(accent_color_hex);
-
         // Focus border color (accent with transparency)
         let focus_border_color = rgba(hex_with_alpha(accent_color_hex, 0x60));
 
@@ -962,26 +1063,18 @@ impl Render for ActionsDialog {
                     .bg(if self.design_variant == DesignVariant::Default {
                         rgba(hex_with_alpha(
                             self.theme.colors.background.main,
-                            if self.search_text.is_empty() {
-                                0x20
-                            } else {
-                                0x40
-                            },
+                            if search_is_empty { 0x20 } else { 0x40 },
                         ))
                     } else {
                         rgba(hex_with_alpha(
                             colors.background,
-                            if self.search_text.is_empty() {
-                                0x20
-                            } else {
-                                0x40
-                            },
+                            if search_is_empty { 0x20 } else { 0x40 },
                         ))
                     })
                     .rounded(px(visual.radius_sm))
                     .border_1()
                     // ALWAYS show border - just vary intensity
-                    .border_color(if !self.search_text.is_empty() {
+                    .border_color(if !search_is_empty {
                         focus_border_color
                     } else {
                         border_color
@@ -990,35 +1083,22 @@ impl Render for ActionsDialog {
                     .flex_row()
                     .items_center()
                     .text_sm()
-                    .text_color(if self.search_text.is_empty() {
+                    .text_color(if search_is_empty {
                         dimmed_text
                     } else {
                         primary_text
                     })
-                    // ALWAYS render cursor div with consistent margin to prevent layout shift
-                    // When empty, cursor is at the start before placeholder text
-                    .when(self.search_text.is_empty(), |d| {
-                        d.child(
-                            div()
-                                .w(px(2.))
-                                .h(px(16.))
-                                .mr(px(2.)) // Use consistent 2px margin
-                                .rounded(px(1.))
-                                .when(self.cursor_visible, |d| d.bg(accent_color)),
-                        )
-                    })
-                    .child(search_display)
-                    // When has text, cursor is at the end after the text
-                    .when(!self.search_text.is_empty(), |d| {
-                        d.child(
-                            div()
-                                .w(px(2.))
-                                .h(px(16.))
-                                .ml(px(2.)) // Consistent 2px margin
-                                .rounded(px(1.))
-                                .when(self.cursor_visible, |d| d.bg(accent_color)),
-                        )
-                    }),
+                    // Shared TextInput component renders placeholder, cursor
+                    // position, and selection highlight
+                    .child(
+                        TextInput::from_state(&self.search_input, false)
+                            .placeholder(crate::i18n::strings().search_actions)
+                            .cursor_visible(self.cursor_visible)
+                            .text_color(primary_text_hex)
+                            .placeholder_color(dimmed_text_hex)
+                            .accent_color(accent_color_hex)
+                            .cursor_height(16.0),
+                    ),
             );
 
         // Render action list using uniform_list for virtualized scrolling
@@ -1035,7 +1115,7 @@ impl Render for ActionsDialog {
                         .px(px(spacing.item_padding_x))
                         .text_color(dimmed_text)
                         .text_sm()
-                        .child("No actions match your search"),
+                        .child(crate::i18n::strings().actions_no_match),
                 )
                 .into_any_element()
         } else {
@@ -1504,6 +1584,35 @@ mod tests {
         assert!(actions.iter().any(|a| a.id == "reveal_in_finder"));
         assert!(actions.iter().any(|a| a.id == "copy_path"));
         assert!(actions.iter().any(|a| a.id == "run_script"));
+        assert!(actions.iter().any(|a| a.id == "toggle_hidden"));
+    }
+
+    #[test]
+    fn test_path_context_actions_include_share_for_files() {
+        let file = PathInfo::new("report.pdf", "/tmp/report.pdf", false);
+        let actions = get_path_context_actions(&file);
+        assert!(actions.iter().any(|a| a.id == "share_airdrop"));
+        assert!(actions.iter().any(|a| a.id == "share_mail"));
+        assert!(actions.iter().any(|a| a.id == "share_messages"));
+        assert!(actions.iter().any(|a| a.id == "copy_to_icloud"));
+
+        // Directories can't be shared through these services
+        let dir = PathInfo::new("docs", "/tmp/docs", true);
+        let actions = get_path_context_actions(&dir);
+        assert!(!actions.iter().any(|a| a.id == "share_airdrop"));
+    }
+
+    #[test]
+    fn test_toggle_pin_action_reflects_pinned_state() {
+        let script = ScriptInfo::new("my-script", "/path/to/my-script.ts");
+        let actions = get_script_context_actions(&script);
+        let pin = actions.iter().find(|a| a.id == "toggle_pin").unwrap();
+        assert_eq!(pin.title, "Pin to Top");
+
+        let pinned = ScriptInfo::new("my-script", "/path/to/my-script.ts").with_pinned(true);
+        let actions = get_script_context_actions(&pinned);
+        let pin = actions.iter().find(|a| a.id == "toggle_pin").unwrap();
+        assert_eq!(pin.title, "Unpin from Top");
     }
 
     #[test]
@@ -221,8 +221,18 @@ impl ScriptListApp {
                             NEEDS_RESET.store(true, Ordering::SeqCst);
                             cx.hide();
                         }
-                        scripts::SearchResult::Scriptlet(_) => {
-                            self.last_output = Some(SharedString::from("Cannot edit scriptlets"));
+                        scripts::SearchResult::Scriptlet(scriptlet_match) => {
+                            if let Some(file_path) = scriptlet_match.scriptlet.file_path.clone() {
+                                let name = scriptlet_match.scriptlet.name.clone();
+                                self.edit_scriptlet(&file_path, &name);
+                                // Hide window after opening editor and set reset flag
+                                script_kit_gpui::set_main_window_visible(false);
+                                NEEDS_RESET.store(true, Ordering::SeqCst);
+                                cx.hide();
+                            } else {
+                                self.last_output =
+                                    Some(SharedString::from("Scriptlet has no source file"));
+                            }
                         }
                         scripts::SearchResult::BuiltIn(_) => {
                             self.last_output =
@@ -239,6 +249,59 @@ impl ScriptListApp {
                     self.last_output = Some(SharedString::from("No script selected"));
                 }
             }
+            "toggle_pin" => {
+                logging::log("UI", "Toggle pin action");
+                self.toggle_pin_selected(cx);
+            }
+            "toggle_hidden" => {
+                logging::log("UI", "Hide from search action");
+                self.toggle_hidden_selected(cx);
+            }
+            "delete_script" => {
+                logging::log("UI", "Delete script action");
+                if let Some(result) = self.get_selected_result() {
+                    match result {
+                        scripts::SearchResult::Script(script_match) => {
+                            let original = script_match.script.path.clone();
+                            let name = script_match.script.name.clone();
+                            match undo::move_to_trash(&original) {
+                                Ok(trashed) => {
+                                    logging::log(
+                                        "UI",
+                                        &format!("Moved to Recently Deleted: {:?}", original),
+                                    );
+                                    self.offer_undo(
+                                        format!("Deleted {}", name),
+                                        undo::UndoAction::FileTrashed { original, trashed },
+                                    );
+                                    self.refresh_scripts(cx);
+                                }
+                                Err(e) => {
+                                    logging::log(
+                                        "ERROR",
+                                        &format!("Failed to delete script: {}", e),
+                                    );
+                                    self.last_output =
+                                        Some(SharedString::from(format!("Delete failed: {}", e)));
+                                }
+                            }
+                        }
+                        scripts::SearchResult::Scriptlet(_) => {
+                            // A scriptlet file can hold several scriptlets; deleting
+                            // the whole file from here would be too destructive
+                            self.last_output = Some(SharedString::from(
+                                "Cannot delete scriptlets (edit their source file instead)",
+                            ));
+                        }
+                        _ => {
+                            self.last_output =
+                                Some(SharedString::from("Only scripts can be deleted"));
+                        }
+                    }
+                } else {
+                    self.last_output = Some(SharedString::from("No script selected"));
+                }
+            }
             "reload_scripts" => {
                 logging::log("UI", "Reload scripts action");
                 self.refresh_scripts(cx);
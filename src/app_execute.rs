@@ -91,6 +91,9 @@ impl ScriptListApp {
                 match window_control::list_windows() {
                     Ok(windows) => {
                         logging::log("EXEC", &format!("Loaded {} windows", windows.len()));
+                        // Drop stale thumbnails; window contents change between opens
+                        self.window_thumbnail_cache.clear();
+                        self.window_thumbnail_failed.clear();
                         self.current_view = AppView::WindowSwitcherView {
                             windows,
                             filter: String::new(),
@@ -112,6 +115,383 @@ impl ScriptListApp {
                 }
                 cx.notify();
             }
+            builtins::BuiltInFeature::SearchFiles => {
+                logging::log("EXEC", "Opening Search Files");
+                // Results start empty; the key handler re-queries Spotlight as
+                // the filter changes (typing in this view IS the query)
+                self.current_view = AppView::FileSearchView {
+                    results: Vec::new(),
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::BackgroundTasks => {
+                self.open_background_tasks(cx);
+            }
+            builtins::BuiltInFeature::OcrClipboard => {
+                #[cfg(not(feature = "ocr"))]
+                {
+                    self.toast_manager.push(
+                        components::toast::Toast::warning(
+                            "OCR support is not enabled in this build",
+                            &self.theme,
+                        )
+                        .duration_ms(Some(3000)),
+                    );
+                    cx.notify();
+                }
+                #[cfg(feature = "ocr")]
+                {
+                    logging::log("EXEC", "Extracting text from clipboard image");
+                    match ocr::extract_text_from_clipboard() {
+                        Ok(text) if !text.trim().is_empty() => {
+                            use arboard::Clipboard;
+                            let copied = Clipboard::new().and_then(|mut c| c.set_text(&text));
+                            match copied {
+                                Ok(()) => {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("OCR copied {} chars to clipboard", text.len()),
+                                    );
+                                    self.toast_manager.push(
+                                        components::toast::Toast::success(
+                                            format!(
+                                                "Copied {} characters of recognized text",
+                                                text.chars().count()
+                                            ),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(3000)),
+                                    );
+                                }
+                                Err(e) => {
+                                    logging::log(
+                                        "ERROR",
+                                        &format!("Failed to copy OCR text: {}", e),
+                                    );
+                                    self.toast_manager.push(
+                                        components::toast::Toast::error(
+                                            format!("Failed to copy text: {}", e),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(5000)),
+                                    );
+                                }
+                            }
+                        }
+                        Ok(_) => {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "No text found in clipboard image",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(3000)),
+                            );
+                        }
+                        Err(e) => {
+                            logging::log("ERROR", &format!("OCR failed: {}", e));
+                            self.toast_manager.push(
+                                components::toast::Toast::error(
+                                    format!("OCR failed: {}", e),
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(5000)),
+                            );
+                        }
+                    }
+                    cx.notify();
+                }
+            }
+            builtins::BuiltInFeature::Shortcuts => {
+                logging::log("EXEC", "Opening Shortcuts list");
+                // Load shortcuts when the view is opened (the user edits them in Shortcuts.app)
+                match macos_shortcuts::list_shortcuts() {
+                    Ok(shortcuts) => {
+                        logging::log("EXEC", &format!("Loaded {} shortcut(s)", shortcuts.len()));
+                        self.current_view = AppView::ShortcutsView {
+                            shortcuts,
+                            filter: String::new(),
+                            selected_index: 0,
+                        };
+                        // Use standard height for shortcuts view
+                        defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                    }
+                    Err(e) => {
+                        logging::log("ERROR", &format!("Failed to list shortcuts: {}", e));
+                        self.toast_manager.push(
+                            components::toast::Toast::error(
+                                format!("Failed to list shortcuts: {}", e),
+                                &self.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        );
+                    }
+                }
+                cx.notify();
+            }
+            builtins::BuiltInFeature::RecentlyDeleted => {
+                logging::log("EXEC", "Opening Recently Deleted list");
+                // Re-read the trash directory each time the view is opened
+                let entries = undo::list_trash();
+                logging::log("EXEC", &format!("Found {} trashed file(s)", entries.len()));
+                self.current_view = AppView::RecentlyDeletedView {
+                    entries,
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                // Use standard height for the recently deleted view
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::ThemeBrowser => {
+                logging::log("EXEC", "Opening Theme Browser");
+                // Remember the active theme so Escape can restore it after previews
+                self.theme_browser_saved = Some(self.theme.clone());
+                self.current_view = AppView::ThemeBrowserView {
+                    themes: theme::bundled_themes(),
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::ExpandStats => {
+                logging::log("EXEC", "Opening Expansion Stats");
+                // Re-scan scriptlets so the view reflects current triggers
+                let entries = expand_manager::expand_stats();
+                logging::log(
+                    "EXEC",
+                    &format!("Found {} expand trigger(s)", entries.len()),
+                );
+                self.current_view = AppView::ExpandStatsView {
+                    entries,
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::GitHub => {
+                logging::log("EXEC", "Opening GitHub");
+                let items = github::github_items();
+                logging::log("EXEC", &format!("GitHub cache has {} item(s)", items.len()));
+                // Cold cache (e.g. token just added): refresh in the
+                // background so the next open has data
+                if items.is_empty() && github::has_token() {
+                    std::thread::spawn(|| {
+                        if let Err(e) = github::refresh_cache() {
+                            logging::log("EXEC", &format!("GitHub refresh failed: {}", e));
+                        }
+                    });
+                }
+                self.current_view = AppView::GitHubView {
+                    items,
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::ConnectBrowser => {
+                logging::log("EXEC", "Opening Connect browser");
+                let targets = connectivity::list_connect_targets();
+                logging::log(
+                    "EXEC",
+                    &format!("Found {} connect target(s)", targets.len()),
+                );
+                self.current_view = AppView::ConnectView {
+                    targets,
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::FocusModes => {
+                logging::log("EXEC", "Opening Focus Modes");
+                let modes = focus_mode::list_modes();
+                let active = focus_mode::active_mode_identifier();
+                logging::log("EXEC", &format!("Found {} focus mode(s)", modes.len()));
+                self.current_view = AppView::FocusModesView {
+                    modes,
+                    active,
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::WorldClock => {
+                logging::log("EXEC", "Opening World Clock");
+                self.current_view = AppView::WorldClockView {
+                    favorites: self.config.world_clock_favorites.clone().unwrap_or_default(),
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::BatchRename => {
+                logging::log("EXEC", "Opening Batch Rename");
+                self.current_view = AppView::BatchRenameView {
+                    state: batch_rename::BatchRenameState::new(),
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::ScreenshotHistory => {
+                logging::log("EXEC", "Opening Screenshot History");
+                let shots = screenshot_history::list_screenshots();
+                let shot_count = shots.len();
+                self.current_view = AppView::ScreenshotHistoryView {
+                    shots,
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, shot_count, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::MoveFiles => {
+                logging::log("EXEC", "Opening Move Files");
+                self.current_view = AppView::FileMoverView {
+                    state: file_mover::MoverState::new(),
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::SecretsVault => {
+                logging::log("EXEC", "Opening Secrets Vault");
+                self.current_view = AppView::SecretsVaultView {
+                    state: secrets_vault::SecretsState::new(),
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                cx.notify();
+            }
+            builtins::BuiltInFeature::CreateIssue => {
+                logging::log("EXEC", "Create Issue requested");
+                match self.config.issue_tracker.clone() {
+                    None => {
+                        self.toast_manager.push(
+                            components::toast::Toast::warning(
+                                "Set issueTracker (provider, project, token) in config.json"
+                                    .to_string(),
+                                &self.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        );
+                        cx.notify();
+                    }
+                    Some(tracker) => {
+                        // Title from the current filter text, falling back to
+                        // the clipboard
+                        let title = if !self.filter_text.trim().is_empty() {
+                            self.filter_text.trim().to_string()
+                        } else {
+                            arboard::Clipboard::new()
+                                .and_then(|mut clipboard| clipboard.get_text())
+                                .map(|t| t.trim().to_string())
+                                .unwrap_or_default()
+                        };
+                        if title.is_empty() {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "Type an issue title or copy one to the clipboard"
+                                        .to_string(),
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(3000)),
+                            );
+                            cx.notify();
+                        } else {
+                            match issue_tracker::create_issue(&tracker, &title) {
+                                Ok(url) => {
+                                    logging::log("EXEC", &format!("Created issue: {}", url));
+                                    let url_for_copy = url.clone();
+                                    self.toast_manager.push(
+                                        components::toast::Toast::success(
+                                            format!("Created {}", url),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(8000))
+                                        .action(components::toast::ToastAction::new(
+                                            "Copy URL",
+                                            Box::new(move |_, _, _| {
+                                                if let Ok(mut clipboard) =
+                                                    arboard::Clipboard::new()
+                                                {
+                                                    let _ = clipboard
+                                                        .set_text(url_for_copy.clone());
+                                                    logging::log(
+                                                        "UI",
+                                                        "Issue URL copied to clipboard",
+                                                    );
+                                                }
+                                            }),
+                                        )),
+                                    );
+                                    self.filter_text.clear();
+                                }
+                                Err(e) => {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("Issue creation failed: {}", e),
+                                    );
+                                    self.toast_manager.push(
+                                        components::toast::Toast::error(
+                                            format!("Issue creation failed: {}", e),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(8000)),
+                                    );
+                                }
+                            }
+                            cx.notify();
+                        }
+                    }
+                }
+            }
+            builtins::BuiltInFeature::ImportMigration => {
+                logging::log("EXEC", "Running Raycast/Alfred import");
+                let report = importer::run_import();
+                if !report.errors.is_empty() {
+                    self.toast_manager.push(
+                        components::toast::Toast::error(
+                            format!(
+                                "Import finished with errors: {}",
+                                report.errors.join("; ")
+                            ),
+                            &self.theme,
+                        )
+                        .duration_ms(Some(8000)),
+                    );
+                } else if report.imported.is_empty() && report.skipped.is_empty() {
+                    self.toast_manager.push(
+                        components::toast::Toast::warning(
+                            "No Raycast script commands or Alfred workflows found".to_string(),
+                            &self.theme,
+                        )
+                        .duration_ms(Some(5000)),
+                    );
+                } else {
+                    let mut summary =
+                        format!("Imported {} command(s)", report.imported.len());
+                    if !report.skipped.is_empty() {
+                        summary.push_str(&format!(
+                            ", skipped {} already imported",
+                            report.skipped.len()
+                        ));
+                    }
+                    self.toast_manager.push(
+                        components::toast::Toast::success(summary, &self.theme)
+                            .duration_ms(Some(5000)),
+                    );
+                }
+                // Show any newly written scripts in the list right away
+                self.refresh_scripts(cx);
+                cx.notify();
+            }
             builtins::BuiltInFeature::DesignGallery => {
                 logging::log("EXEC", "Opening Design Gallery");
                 self.current_view = AppView::DesignGalleryView {
@@ -283,6 +663,12 @@ impl ScriptListApp {
                         }
                         SystemActionType::StartScreenSaver => system_actions::start_screen_saver(),
 
+                        // Connectivity
+                        SystemActionType::WifiOn => connectivity::set_wifi_power(true),
+                        SystemActionType::WifiOff => connectivity::set_wifi_power(false),
+                        SystemActionType::BluetoothOn => connectivity::set_bluetooth_power(true),
+                        SystemActionType::BluetoothOff => connectivity::set_bluetooth_power(false),
+
                         // System Preferences
                         SystemActionType::OpenSystemPreferences => {
                             system_actions::open_system_preferences_main()
@@ -779,26 +1165,725 @@ impl ScriptListApp {
                         }
                         cx.notify();
                     }
+                    PermissionCommandType::RequestScreenRecording => {
+                        let granted = permissions_wizard::request_screen_recording_permission();
+                        if granted {
+                            self.toast_manager.push(
+                                components::toast::Toast::success(
+                                    "Screen recording permission granted!",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(3000)),
+                            );
+                        } else {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "Screen recording permission not granted. Grant it in System Settings, then restart Script Kit.",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(5000)),
+                            );
+                        }
+                        cx.notify();
+                    }
+                    PermissionCommandType::OpenScreenRecordingSettings => {
+                        if let Err(e) = permissions_wizard::open_screen_recording_settings() {
+                            logging::log(
+                                "ERROR",
+                                &format!("Failed to open screen recording settings: {}", e),
+                            );
+                            self.toast_manager.push(
+                                components::toast::Toast::error(
+                                    format!("Failed to open settings: {}", e),
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(5000)),
+                            );
+                        } else {
+                            // Hide window after opening settings
+                            script_kit_gpui::set_main_window_visible(false);
+                            NEEDS_RESET.store(true, Ordering::SeqCst);
+                            cx.hide();
+                        }
+                        cx.notify();
+                    }
                 }
             }
-        }
-    }
 
-    /// Execute an application directly from the main search results
-    fn execute_app(&mut self, app: &app_launcher::AppInfo, cx: &mut Context<Self>) {
-        logging::log("EXEC", &format!("Launching app from search: {}", app.name));
+            // =========================================================================
+            // Update Commands
+            // =========================================================================
+            builtins::BuiltInFeature::UpdateCommand(cmd_type) => {
+                logging::log("EXEC", &format!("Executing update command: {:?}", cmd_type));
 
-        if let Err(e) = app_launcher::launch_application(app) {
-            logging::log("ERROR", &format!("Failed to launch {}: {}", app.name, e));
-            self.last_output = Some(SharedString::from(format!(
-                "Failed to launch: {}",
-                app.name
-            )));
-            cx.notify();
-        } else {
-            logging::log("EXEC", &format!("Launched app: {}", app.name));
-            // Hide window after launching app and set reset flag
-            // so filter_text is cleared when window is shown again
+                use builtins::UpdateCommandType;
+
+                match cmd_type {
+                    UpdateCommandType::CheckForUpdates => {
+                        // Network call - run on a thread and poll for the result
+                        let (tx, rx) = std::sync::mpsc::channel();
+                        std::thread::spawn(move || {
+                            let _ = tx.send(updater::check_for_update());
+                        });
+
+                        self.toast_manager.push(
+                            components::toast::Toast::success(
+                                "Checking for updates...",
+                                &self.theme,
+                            )
+                            .duration_ms(Some(2000)),
+                        );
+
+                        cx.spawn(async move |this, cx| {
+                            loop {
+                                Timer::after(std::time::Duration::from_millis(100)).await;
+                                match rx.try_recv() {
+                                    Ok(result) => {
+                                        let _ = cx.update(|cx| {
+                                            this.update(cx, |app, cx| {
+                                                match result {
+                                                    Ok(Some(release)) => {
+                                                        let version = release.version.clone();
+                                                        updater::set_available_update(Some(
+                                                            release,
+                                                        ));
+                                                        // We toast here - don't let the render
+                                                        // loop repeat the announcement
+                                                        let _ = updater::take_unnotified_update();
+                                                        app.toast_manager.push(
+                                                            components::toast::Toast::success(
+                                                                format!(
+                                                                    "Script Kit {} is available. Run \"Install Update\" or \"View Changelog\".",
+                                                                    version
+                                                                ),
+                                                                &app.theme,
+                                                            )
+                                                            .duration_ms(Some(8000)),
+                                                        );
+                                                    }
+                                                    Ok(None) => {
+                                                        app.toast_manager.push(
+                                                            components::toast::Toast::success(
+                                                                format!(
+                                                                    "You're up to date ({})",
+                                                                    updater::CURRENT_VERSION
+                                                                ),
+                                                                &app.theme,
+                                                            )
+                                                            .duration_ms(Some(3000)),
+                                                        );
+                                                    }
+                                                    Err(e) => {
+                                                        logging::log(
+                                                            "ERROR",
+                                                            &format!("Update check failed: {}", e),
+                                                        );
+                                                        app.toast_manager.push(
+                                                            components::toast::Toast::error(
+                                                                format!(
+                                                                    "Update check failed: {}",
+                                                                    e
+                                                                ),
+                                                                &app.theme,
+                                                            )
+                                                            .duration_ms(Some(5000)),
+                                                        );
+                                                    }
+                                                }
+                                                cx.notify();
+                                            })
+                                        });
+                                        break;
+                                    }
+                                    Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                                    Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                                }
+                            }
+                        })
+                        .detach();
+                        cx.notify();
+                    }
+                    UpdateCommandType::ViewChangelog => {
+                        if let Some(release) = updater::available_update() {
+                            self.show_changelog(&release, cx);
+                        } else {
+                            // No cached check result - fetch the latest release notes
+                            let (tx, rx) = std::sync::mpsc::channel();
+                            std::thread::spawn(move || {
+                                let _ = tx.send(updater::fetch_latest_release());
+                            });
+
+                            cx.spawn(async move |this, cx| loop {
+                                Timer::after(std::time::Duration::from_millis(100)).await;
+                                match rx.try_recv() {
+                                    Ok(result) => {
+                                        let _ = cx.update(|cx| {
+                                            this.update(cx, |app, cx| match result {
+                                                Ok(release) => {
+                                                    app.show_changelog(&release, cx);
+                                                }
+                                                Err(e) => {
+                                                    logging::log(
+                                                        "ERROR",
+                                                        &format!(
+                                                            "Failed to fetch changelog: {}",
+                                                            e
+                                                        ),
+                                                    );
+                                                    app.toast_manager.push(
+                                                        components::toast::Toast::error(
+                                                            format!(
+                                                                "Failed to fetch changelog: {}",
+                                                                e
+                                                            ),
+                                                            &app.theme,
+                                                        )
+                                                        .duration_ms(Some(5000)),
+                                                    );
+                                                    cx.notify();
+                                                }
+                                            })
+                                        });
+                                        break;
+                                    }
+                                    Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                                    Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                                }
+                            })
+                            .detach();
+                        }
+                        cx.notify();
+                    }
+                    UpdateCommandType::InstallUpdate => {
+                        let Some(release) = updater::available_update() else {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "No update available. Run \"Check for Updates\" first.",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(5000)),
+                            );
+                            cx.notify();
+                            return;
+                        };
+
+                        self.toast_manager.push(
+                            components::toast::Toast::success(
+                                format!("Downloading Script Kit {}...", release.version),
+                                &self.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        );
+
+                        // download_and_install exits the process on success,
+                        // so only errors come back over the channel
+                        let (tx, rx) = std::sync::mpsc::channel();
+                        std::thread::spawn(move || {
+                            if let Err(e) = updater::download_and_install(&release) {
+                                let _ = tx.send(e.to_string());
+                            }
+                        });
+
+                        cx.spawn(async move |this, cx| loop {
+                            Timer::after(std::time::Duration::from_millis(200)).await;
+                            match rx.try_recv() {
+                                Ok(error) => {
+                                    let _ = cx.update(|cx| {
+                                        this.update(cx, |app, cx| {
+                                            logging::log(
+                                                "ERROR",
+                                                &format!("Update install failed: {}", error),
+                                            );
+                                            app.toast_manager.push(
+                                                components::toast::Toast::error(
+                                                    format!("Update failed: {}", error),
+                                                    &app.theme,
+                                                )
+                                                .duration_ms(Some(8000)),
+                                            );
+                                            cx.notify();
+                                        })
+                                    });
+                                    break;
+                                }
+                                Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                                Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                            }
+                        })
+                        .detach();
+                        cx.notify();
+                    }
+                }
+            }
+
+            // =========================================================================
+            // Settings Commands
+            // =========================================================================
+            builtins::BuiltInFeature::SettingsCommand(cmd_type) => {
+                logging::log(
+                    "EXEC",
+                    &format!("Executing settings command: {:?}", cmd_type),
+                );
+
+                use builtins::SettingsCommandType;
+
+                match cmd_type {
+                    SettingsCommandType::ExportSettings => {
+                        let dest = dirs::home_dir()
+                            .map(|h| h.join("Desktop"))
+                            .unwrap_or_else(std::env::temp_dir)
+                            .join(settings_transfer::default_archive_name());
+
+                        match settings_transfer::export_settings(&dest) {
+                            Ok(files) => {
+                                logging::log(
+                                    "EXEC",
+                                    &format!(
+                                        "Exported {} settings file(s) to {}",
+                                        files.len(),
+                                        dest.display()
+                                    ),
+                                );
+                                self.toast_manager.push(
+                                    components::toast::Toast::success(
+                                        format!(
+                                            "Exported {} to {}",
+                                            files.join(", "),
+                                            dest.display()
+                                        ),
+                                        &self.theme,
+                                    )
+                                    .duration_ms(Some(6000)),
+                                );
+                                // Reveal the archive in Finder
+                                let _ = std::process::Command::new("open")
+                                    .arg("-R")
+                                    .arg(&dest)
+                                    .spawn();
+                            }
+                            Err(e) => {
+                                logging::log("ERROR", &format!("Settings export failed: {}", e));
+                                self.toast_manager.push(
+                                    components::toast::Toast::error(
+                                        format!("Export failed: {}", e),
+                                        &self.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                );
+                            }
+                        }
+                        cx.notify();
+                    }
+                    SettingsCommandType::ImportSettings => {
+                        let Some(archive) = settings_transfer::find_latest_archive() else {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "No settings archive found in Downloads or Desktop",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(5000)),
+                            );
+                            cx.notify();
+                            return;
+                        };
+
+                        match settings_transfer::inspect_archive(&archive) {
+                            Ok((extracted, conflicts)) => {
+                                // Conflict resolution: first run warns and arms the
+                                // confirmation, second run overwrites with backups
+                                let confirmed = self.pending_confirmation.as_deref()
+                                    == Some("builtin-import-settings");
+                                if !conflicts.is_empty() && !confirmed {
+                                    self.pending_confirmation =
+                                        Some("builtin-import-settings".to_string());
+                                    self.toast_manager.push(
+                                        components::toast::Toast::warning(
+                                            format!(
+                                                "Importing {} would overwrite: {}. Run again to overwrite (backups will be kept).",
+                                                archive
+                                                    .file_name()
+                                                    .unwrap_or_default()
+                                                    .to_string_lossy(),
+                                                conflicts.join(", ")
+                                            ),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(8000)),
+                                    );
+                                    cx.notify();
+                                    return;
+                                }
+                                self.pending_confirmation = None;
+
+                                match settings_transfer::import_extracted(&extracted, true) {
+                                    Ok(summary) => {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!(
+                                                "Imported {:?} (backed up {:?})",
+                                                summary.imported, summary.backed_up
+                                            ),
+                                        );
+                                        let mut message = format!(
+                                            "Imported {}. Restart to apply.",
+                                            summary.imported.join(", ")
+                                        );
+                                        if !summary.backed_up.is_empty() {
+                                            message.push_str(&format!(
+                                                " Backups: {}",
+                                                summary.backed_up.join(", ")
+                                            ));
+                                        }
+                                        self.toast_manager.push(
+                                            components::toast::Toast::success(message, &self.theme)
+                                                .duration_ms(Some(8000)),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        logging::log(
+                                            "ERROR",
+                                            &format!("Settings import failed: {}", e),
+                                        );
+                                        self.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Import failed: {}", e),
+                                                &self.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                            }
+                            Err(e) => {
+                                logging::log(
+                                    "ERROR",
+                                    &format!("Failed to read settings archive: {}", e),
+                                );
+                                self.toast_manager.push(
+                                    components::toast::Toast::error(
+                                        format!("Failed to read archive: {}", e),
+                                        &self.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                );
+                            }
+                        }
+                        cx.notify();
+                    }
+                    SettingsCommandType::UnhideScripts => {
+                        let count = self.hidden_store.clear();
+                        if count == 0 {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "No items are hidden from search",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(4000)),
+                            );
+                        } else {
+                            self.hidden_store.save().ok(); // Best-effort save
+                            logging::log("EXEC", &format!("Unhid {} item(s)", count));
+                            self.invalidate_grouped_cache(GroupedCacheInvalidation::SectionChange);
+                            self.toast_manager.push(
+                                components::toast::Toast::success(
+                                    format!("Unhid {} item(s)", count),
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(4000)),
+                            );
+                        }
+                        cx.notify();
+                    }
+                }
+            }
+
+            // =========================================================================
+            // Fallback actions (shown when the filter matched nothing)
+            // =========================================================================
+            builtins::BuiltInFeature::Fallback(action_type) => {
+                // The query is the live filter text - fallback rows only exist
+                // while that filter is producing zero results
+                let query = self.computed_filter_text.trim().to_string();
+                logging::log(
+                    "EXEC",
+                    &format!("Executing fallback {:?} for query '{}'", action_type, query),
+                );
+
+                use builtins::FallbackActionType;
+
+                match action_type {
+                    FallbackActionType::WebSearch => {
+                        let url = self
+                            .config
+                            .get_fallbacks()
+                            .web_search_url
+                            .replace("{query}", &url_scheme::percent_encode(&query));
+                        #[cfg(target_os = "macos")]
+                        let result = std::process::Command::new("open").arg(&url).spawn();
+                        #[cfg(target_os = "linux")]
+                        let result = std::process::Command::new("xdg-open").arg(&url).spawn();
+                        #[cfg(target_os = "windows")]
+                        let result = std::process::Command::new("cmd")
+                            .args(["/C", "start", &url])
+                            .spawn();
+
+                        match result {
+                            Ok(_) => {
+                                logging::log("EXEC", &format!("Opened web search: {}", url));
+                                // Hide window - the browser has the focus now
+                                script_kit_gpui::set_main_window_visible(false);
+                                NEEDS_RESET.store(true, Ordering::SeqCst);
+                                cx.hide();
+                            }
+                            Err(e) => {
+                                logging::log(
+                                    "ERROR",
+                                    &format!("Failed to open web search '{}': {}", url, e),
+                                );
+                                self.toast_manager.push(
+                                    components::toast::Toast::error(
+                                        format!("Failed to open browser: {}", e),
+                                        &self.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                );
+                                cx.notify();
+                            }
+                        }
+                    }
+                    FallbackActionType::CreateScript => {
+                        // Same flow as ScriptCommandType::NewScript but named
+                        // after the query (create_new_script sanitizes it)
+                        match script_creation::create_new_script(&query) {
+                            Ok(path) => {
+                                logging::log("EXEC", &format!("Created new script: {:?}", path));
+                                if let Err(e) = script_creation::open_in_editor(&path, &self.config)
+                                {
+                                    logging::log(
+                                        "ERROR",
+                                        &format!("Failed to open in editor: {}", e),
+                                    );
+                                    self.toast_manager.push(
+                                        components::toast::Toast::error(
+                                            format!(
+                                                "Created script but failed to open editor: {}",
+                                                e
+                                            ),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(5000)),
+                                    );
+                                } else {
+                                    self.toast_manager.push(
+                                        components::toast::Toast::success(
+                                            format!(
+                                                "Created {} and opened in editor",
+                                                path.file_name()
+                                                    .unwrap_or_default()
+                                                    .to_string_lossy()
+                                            ),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(3000)),
+                                    );
+                                }
+                                // Hide window
+                                script_kit_gpui::set_main_window_visible(false);
+                                NEEDS_RESET.store(true, Ordering::SeqCst);
+                                cx.hide();
+                            }
+                            Err(e) => {
+                                logging::log("ERROR", &format!("Failed to create script: {}", e));
+                                self.toast_manager.push(
+                                    components::toast::Toast::error(
+                                        format!("Failed to create script: {}", e),
+                                        &self.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                );
+                                cx.notify();
+                            }
+                        }
+                    }
+                    FallbackActionType::FileSearch => {
+                        #[cfg(target_os = "macos")]
+                        {
+                            // Spotlight query, reveal the best hit in Finder
+                            let results = file_search::search_files(&query, None, 1);
+                            match results.first() {
+                                Some(hit) => {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("File search revealing {}", hit.path),
+                                    );
+                                    let _ = std::process::Command::new("open")
+                                        .arg("-R")
+                                        .arg(&hit.path)
+                                        .spawn();
+                                    script_kit_gpui::set_main_window_visible(false);
+                                    NEEDS_RESET.store(true, Ordering::SeqCst);
+                                    cx.hide();
+                                }
+                                None => {
+                                    self.toast_manager.push(
+                                        components::toast::Toast::warning(
+                                            format!("No files matching \"{}\"", query),
+                                            &self.theme,
+                                        )
+                                        .duration_ms(Some(3000)),
+                                    );
+                                    cx.notify();
+                                }
+                            }
+                        }
+                        #[cfg(not(target_os = "macos"))]
+                        {
+                            self.toast_manager.push(
+                                components::toast::Toast::warning(
+                                    "File search is only supported on macOS",
+                                    &self.theme,
+                                )
+                                .duration_ms(Some(3000)),
+                            );
+                            cx.notify();
+                        }
+                    }
+                }
+            }
+
+            // =========================================================================
+            // Tags
+            // =========================================================================
+            builtins::BuiltInFeature::ShellCommand(command) => {
+                // `>` scope row - run the one-liner in a terminal prompt so
+                // the output stays visible and the shell remains interactive
+                logging::log("EXEC", &format!("Running shell command: {}", command));
+
+                // Remember the command for the `>` history section
+                self.shell_history.record(command);
+                self.shell_history.save().ok(); // Best-effort save
+
+                // No script on the other end - nothing to submit back to
+                let submit_callback: std::sync::Arc<dyn Fn(String, Option<String>) + Send + Sync> =
+                    std::sync::Arc::new(|_, _| {});
+
+                let term_height = window_resize::layout::MAX_HEIGHT;
+                match term_prompt::TermPrompt::with_height(
+                    "shell-command".to_string(),
+                    Some(command.clone()),
+                    self.focus_handle.clone(),
+                    submit_callback,
+                    std::sync::Arc::new(self.theme.clone()),
+                    std::sync::Arc::new(self.config.clone()),
+                    Some(term_height),
+                ) {
+                    Ok(term_prompt) => {
+                        let entity = cx.new(|_| term_prompt);
+                        self.current_view = AppView::TermPrompt {
+                            id: "shell-command".to_string(),
+                            entity,
+                        };
+                        self.focused_input = FocusedInput::None; // Terminal handles its own cursor
+                        defer_resize_to_view(ViewType::TermPrompt, 0, cx);
+                        cx.notify();
+                    }
+                    Err(e) => {
+                        logging::log("ERROR", &format!("Failed to create terminal: {}", e));
+                        self.toast_manager.push(
+                            components::toast::Toast::error(
+                                format!("Failed to create terminal: {}", e),
+                                &self.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        );
+                        cx.notify();
+                    }
+                }
+            }
+            builtins::BuiltInFeature::ClipboardPaste(content) => {
+                // `c:` scope row - copy the entry back to the clipboard
+                let copied = arboard::Clipboard::new()
+                    .and_then(|mut clipboard| clipboard.set_text(content));
+                match copied {
+                    Ok(_) => {
+                        logging::log("EXEC", "Copied clipboard scope entry");
+                        // Hide so the user can paste where they were working
+                        script_kit_gpui::set_main_window_visible(false);
+                        NEEDS_RESET.store(true, Ordering::SeqCst);
+                        cx.hide();
+                    }
+                    Err(e) => {
+                        logging::log("ERROR", &format!("Failed to copy entry: {}", e));
+                        self.toast_manager.push(
+                            components::toast::Toast::error(
+                                format!("Failed to copy entry: {}", e),
+                                &self.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        );
+                        cx.notify();
+                    }
+                }
+            }
+            builtins::BuiltInFeature::TagsBrowser => {
+                // A bare "#" makes get_grouped_results_cached list every tag
+                logging::log("EXEC", "Opening tags browser");
+                self.set_filter_text_deferred("#", cx);
+            }
+            builtins::BuiltInFeature::TagFilter(tag) => {
+                logging::log("EXEC", &format!("Filtering by tag #{}", tag));
+                self.set_filter_text_deferred(&format!("#{}", tag), cx);
+            }
+        }
+    }
+
+    /// Show release notes for an update in a div prompt (changelog view)
+    fn show_changelog(&mut self, release: &updater::ReleaseInfo, cx: &mut Context<Self>) {
+        logging::log(
+            "UI",
+            &format!("Showing changelog for version {}", release.version),
+        );
+
+        // No script is driving this prompt - submissions go nowhere,
+        // escape dismisses like any other prompt
+        let submit_callback: std::sync::Arc<dyn Fn(String, Option<String>) + Send + Sync> =
+            std::sync::Arc::new(|_id, _value| {});
+        let focus_handle = cx.focus_handle();
+
+        let div_prompt = DivPrompt::new(
+            "changelog".to_string(),
+            updater::changelog_html(release),
+            None,
+            focus_handle,
+            submit_callback,
+            std::sync::Arc::new(self.theme.clone()),
+        );
+
+        let entity = cx.new(|_| div_prompt);
+        self.current_view = AppView::DivPrompt {
+            id: "changelog".to_string(),
+            entity,
+        };
+        self.focused_input = FocusedInput::None;
+        defer_resize_to_view(ViewType::DivPrompt, 0, cx);
+        cx.notify();
+    }
+
+    /// Execute an application directly from the main search results
+    fn execute_app(&mut self, app: &app_launcher::AppInfo, cx: &mut Context<Self>) {
+        logging::log("EXEC", &format!("Launching app from search: {}", app.name));
+
+        if let Err(e) = app_launcher::launch_application(app) {
+            logging::log("ERROR", &format!("Failed to launch {}: {}", app.name, e));
+            self.last_output = Some(SharedString::from(format!(
+                "Failed to launch: {}",
+                app.name
+            )));
+            cx.notify();
+        } else {
+            logging::log("EXEC", &format!("Launched app: {}", app.name));
+            // Hide window after launching app and set reset flag
+            // so filter_text is cleared when window is shown again
             script_kit_gpui::set_main_window_visible(false);
             NEEDS_RESET.store(true, Ordering::SeqCst);
             cx.hide();
@@ -835,4 +1920,69 @@ impl ScriptListApp {
             cx.hide();
         }
     }
+
+    /// Handle a parsed scriptkit:// URL request
+    ///
+    /// Scripts marked `// AllowURL: true` run immediately; everything else is
+    /// held in `pending_url_run` and surfaced as a confirmation so a stray
+    /// link can't run arbitrary code. Enter confirms, Escape dismisses.
+    fn handle_url_request(&mut self, req: url_scheme::UrlRequest, cx: &mut Context<Self>) {
+        logging::log(
+            "EXEC",
+            &format!(
+                "URL request: script='{}' ({} arg(s))",
+                req.script,
+                req.args.len()
+            ),
+        );
+
+        let needle = req.script.to_lowercase();
+        let script = self
+            .scripts
+            .iter()
+            .find(|s| s.name.to_lowercase() == needle)
+            .cloned();
+
+        let Some(script) = script else {
+            logging::log("ERROR", &format!("URL script not found: {}", req.script));
+            self.toast_manager.push(
+                components::toast::Toast::error(
+                    format!("Script not found: {}", req.script),
+                    &self.theme,
+                )
+                .duration_ms(Some(5000)),
+            );
+            cx.notify();
+            return;
+        };
+
+        if scripts::is_url_allowed(&script) {
+            logging::log(
+                "EXEC",
+                &format!("URL script allowed via // AllowURL: true: {}", script.name),
+            );
+            self.execute_interactive_with_args(&script.clone(), &req.args, cx);
+            cx.notify();
+            return;
+        }
+
+        // Not explicitly allowed - require confirmation before running
+        logging::log(
+            "EXEC",
+            &format!("URL script requires confirmation: {}", script.name),
+        );
+        let name = script.name.clone();
+        self.pending_url_run = Some((script, req.args));
+        self.toast_manager.push(
+            components::toast::Toast::warning(
+                format!(
+                    "A link wants to run '{}' - press Enter to run, Esc to dismiss",
+                    name
+                ),
+                &self.theme,
+            )
+            .duration_ms(Some(10000)),
+        );
+        cx.notify();
+    }
 }
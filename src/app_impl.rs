@@ -1,5 +1,11 @@
 impl ScriptListApp {
-    fn new(config: config::Config, bun_available: bool, window: &mut Window, cx: &mut Context<Self>) -> Self {
+    fn new(
+        config: config::Config,
+        bun_available: bool,
+        is_fresh_install: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
         // PERF: Measure script loading time
         let load_start = std::time::Instant::now();
         let scripts = scripts::read_scripts();
@@ -17,6 +23,30 @@ impl ScriptListApp {
         let mut frecency_store = FrecencyStore::with_config(&frecency_config);
         frecency_store.load().ok(); // Ignore errors - starts fresh if file doesn't exist
 
+        // Load approved script hashes (nothing trusted if file doesn't exist)
+        let mut trust_store = script_trust::TrustStore::new();
+        trust_store.load().ok();
+
+        // Load collapsed section state (nothing collapsed if file doesn't exist)
+        let mut section_state = section_state::SectionStateStore::new();
+        section_state.load().ok();
+
+        // Load pinned items (no pins if file doesn't exist)
+        let mut pin_store = pins::PinStore::new();
+        pin_store.load().ok();
+
+        // Load hidden items (nothing hidden if file doesn't exist)
+        let mut hidden_store = hidden::HiddenStore::new();
+        hidden_store.load().ok();
+
+        // Load shell one-liner history (empty if file doesn't exist)
+        let mut shell_history = shell_history::ShellHistoryStore::new();
+        shell_history.load().ok();
+
+        // Load per-prompt query history (empty if file doesn't exist)
+        let mut query_history = query_history::QueryHistoryStore::new();
+        query_history.load().ok();
+
         // Load built-in entries based on config
         let builtin_entries = builtins::get_builtin_entries(&config.get_builtins());
 
@@ -58,6 +88,15 @@ impl ScriptListApp {
             ),
         );
 
+        // Best-effort trash housekeeping: drop undo-trash entries older than a week
+        std::thread::spawn(|| {
+            match undo::prune_trash(std::time::Duration::from_secs(7 * 24 * 60 * 60)) {
+                Ok(n) if n > 0 => logging::log("STARTUP", &format!("Pruned {} old trash entries", n)),
+                Ok(_) => {}
+                Err(e) => logging::log("STARTUP", &format!("Trash prune failed: {}", e)),
+            }
+        });
+
         // Load apps in background thread to avoid blocking startup
         let app_launcher_enabled = config.get_builtins().app_launcher;
         if app_launcher_enabled {
@@ -75,18 +114,30 @@ impl ScriptListApp {
 
             // Poll for results using a spawned task
             cx.spawn(async move |this, cx| {
-                // Poll the channel periodically
+                // Fingerprint of the applied list (len, bundle ids, icons);
+                // used to skip redundant refreshes during enrichment
+                let mut last_fingerprint = (0usize, 0usize, 0usize);
+
+                // Poll the channel for the initial snapshot (names and paths
+                // arrive immediately; the scan keeps enriching in background)
                 loop {
                     Timer::after(std::time::Duration::from_millis(50)).await;
                     match rx.try_recv() {
                         Ok((apps, elapsed)) => {
                             let app_count = apps.len();
+                            last_fingerprint = (
+                                apps.len(),
+                                apps.iter().filter(|a| a.bundle_id.is_some()).count(),
+                                apps.iter().filter(|a| a.icon.is_some()).count(),
+                            );
                             let _ = cx.update(|cx| {
                                 this.update(cx, |app, cx| {
                                     app.apps = apps;
                                     // Invalidate caches since apps changed
                                     app.filter_cache_key = String::from("\0_APPS_LOADED_\0");
-                                    app.grouped_cache_key = String::from("\0_APPS_LOADED_\0");
+                                    app.invalidate_grouped_cache(
+                                        GroupedCacheInvalidation::ScriptReload,
+                                    );
                                     logging::log(
                                         "APP",
                                         &format!(
@@ -101,9 +152,41 @@ impl ScriptListApp {
                             break;
                         }
                         Err(std::sync::mpsc::TryRecvError::Empty) => continue,
-                        Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                        Err(std::sync::mpsc::TryRecvError::Disconnected) => return,
                     }
                 }
+
+                // The scan fills in bundle ids and icons in place after the
+                // first snapshot; refresh until it settles so icons appear
+                // progressively instead of all at once. Order is stable
+                // (the scan never reshuffles the published list), so rows
+                // only gain icons - they never jump around.
+                loop {
+                    let done = !app_launcher::is_apps_loading();
+                    let apps = app_launcher::get_cached_apps();
+                    let fingerprint = (
+                        apps.len(),
+                        apps.iter().filter(|a| a.bundle_id.is_some()).count(),
+                        apps.iter().filter(|a| a.icon.is_some()).count(),
+                    );
+                    if fingerprint != last_fingerprint {
+                        last_fingerprint = fingerprint;
+                        let _ = cx.update(|cx| {
+                            this.update(cx, |app, cx| {
+                                app.apps = apps;
+                                app.filter_cache_key = String::from("\0_APPS_LOADED_\0");
+                                app.invalidate_grouped_cache(
+                                    GroupedCacheInvalidation::ScriptReload,
+                                );
+                                cx.notify();
+                            })
+                        });
+                    }
+                    if done {
+                        break;
+                    }
+                    Timer::after(std::time::Duration::from_millis(250)).await;
+                }
             })
             .detach();
         }
@@ -184,12 +267,21 @@ impl ScriptListApp {
             // Scroll activity tracking: start with scrollbar hidden
             is_scrolling: false,
             last_scroll_time: None,
+            // List bounds are captured during the first paint
+            list_bounds: None,
+            scrollbar_dragging: false,
+            context_menu: None,
             current_view: AppView::ScriptList,
+            prompt_stack: Vec::new(),
             script_session: Arc::new(ParkingMutex::new(None)),
             arg_input: TextInputState::new(),
             arg_selected_index: 0,
+            arg_sort_enabled: true,
+            arg_has_more: false,
+            arg_more_requested: false,
             prompt_receiver: None,
             response_sender: None,
+            script_cancel_token: None,
             // Variable-height list state for main menu (section headers at 24px, items at 48px)
             // Start with 0 items, will be reset when grouped_items changes
             // .measure_all() ensures all items are measured upfront for correct scroll height
@@ -199,6 +291,8 @@ impl ScriptListApp {
             clipboard_list_scroll_handle: UniformListScrollHandle::new(),
             window_list_scroll_handle: UniformListScrollHandle::new(),
             design_gallery_scroll_handle: UniformListScrollHandle::new(),
+            file_preview_cache: None,
+            file_preview_loading: None,
             show_actions_popup: false,
             actions_dialog: None,
             cursor_visible: true,
@@ -211,6 +305,7 @@ impl ScriptListApp {
             cached_grouped_items: Arc::from([]),
             cached_grouped_flat_results: Arc::from([]),
             grouped_cache_key: String::from("\0_UNINITIALIZED_\0"), // Sentinel value to force initial compute
+            grouped_cache_dirty: false,
             // P3: Two-stage filter coalescing
             computed_filter_text: String::new(),
             filter_coalescer: FilterCoalescer::new(),
@@ -223,10 +318,31 @@ impl ScriptListApp {
             current_design: DesignVariant::default(),
             // Toast manager: initialize for error notifications
             toast_manager: ToastManager::new(),
+            pending_undo: None,
+            theme_browser_saved: None,
             // Clipboard image cache: decoded RenderImages for thumbnails/preview
             clipboard_image_cache: std::collections::HashMap::new(),
+            // Window thumbnail cache: captured previews for the window switcher
+            window_thumbnail_cache: std::collections::HashMap::new(),
+            window_thumbnail_failed: std::collections::HashSet::new(),
+            // Arg choice image cache: decoded RenderImages for choice `img` paths
+            arg_choice_image_cache: std::collections::HashMap::new(),
             // Frecency store for tracking script usage
             frecency_store,
+            // Persisted collapsed state for main list sections
+            section_state,
+            // "Show more…" expansions start empty each session
+            expanded_sections: std::collections::HashSet::new(),
+            // Persisted pinned items shown in the PINNED section
+            pin_store,
+            // Persisted keys hidden from search via the "Hide from Search" action
+            hidden_store,
+            // Persisted shell one-liner history for the `>` filter scope
+            shell_history,
+            // Persisted per-prompt query history (main filter + arg prompts)
+            query_history,
+            // Not cycling query history until Up/Ctrl+R is pressed
+            history_cycle_index: None,
             // Mouse hover tracking - starts as None (no item hovered)
             hovered_index: None,
             // P0-2: Initialize hover debounce timer
@@ -258,6 +374,9 @@ impl ScriptListApp {
             nav_coalescer: NavCoalescer::new(),
             // Window focus tracking - for detecting focus lost and auto-dismissing prompts
             was_window_focused: false,
+            last_missing_permissions: Vec::new(),
+            // Pinned mode starts off - toggled with Cmd+Shift+P
+            window_pinned: false,
             // Scroll stabilization: track last scrolled index for each handle
             last_scrolled_main: None,
             last_scrolled_arg: None,
@@ -266,10 +385,33 @@ impl ScriptListApp {
             last_scrolled_design_gallery: None,
             // Show warning banner when bun is not available
             show_bun_warning: !bun_available,
+            // Start the onboarding tour on fresh installs only
+            onboarding_step: onboarding::should_show(is_fresh_install)
+                .then(onboarding::OnboardingStep::first),
             // Pending confirmation for dangerous actions
             pending_confirmation: None,
+            pending_url_run: None,
+            trust_store,
+            pending_trust_run: None,
+            // Last run script - tracked for the rerun summon hotkey
+            last_run_script_path: None,
         };
 
+        // Restore the last chosen design (Cmd+1 cycling persists it), falling
+        // back to the `design` config key when nothing has been saved yet
+        if let Some(design) = designs::load_saved_variant().or_else(|| {
+            app.config
+                .get_design()
+                .as_deref()
+                .and_then(DesignVariant::from_config_name)
+        }) {
+            logging::log(
+                "DESIGN",
+                &format!("Starting with design: {}", design.name()),
+            );
+            app.current_design = design;
+        }
+
         // Build initial alias/shortcut registries (conflicts logged, not shown via HUD on startup)
         let conflicts = app.rebuild_registries();
         if !conflicts.is_empty() {
@@ -327,9 +469,31 @@ impl ScriptListApp {
             "DESIGN",
             &format!("self.current_design is now: {:?}", self.current_design),
         );
+        // Persist the choice so it survives restarts
+        designs::save_variant(new_design);
         cx.notify();
     }
 
+    /// The design variant to render with right now
+    ///
+    /// Per-context `designOverrides` from config win over the globally
+    /// selected design: `scriptList` applies to the main list, `prompts` to
+    /// script-driven prompts. Every other view uses the global design.
+    fn effective_design(&self) -> DesignVariant {
+        let overrides = self.config.get_design_overrides();
+        let override_name = if matches!(self.current_view, AppView::ScriptList) {
+            overrides.script_list
+        } else if Self::is_script_prompt(&self.current_view) {
+            overrides.prompts
+        } else {
+            None
+        };
+        override_name
+            .as_deref()
+            .and_then(DesignVariant::from_config_name)
+            .unwrap_or(self.current_design)
+    }
+
     fn update_theme(&mut self, cx: &mut Context<Self>) {
         self.theme = theme::load_theme();
         logging::log("APP", "Theme reloaded based on system appearance");
@@ -338,13 +502,20 @@ impl ScriptListApp {
 
     fn update_config(&mut self, cx: &mut Context<Self>) {
         self.config = config::load_config();
+        i18n::set_locale(i18n::Locale::from_tag(&self.config.get_locale()));
+        designs::set_ui_scale(self.config.get_ui_scale());
+        designs::set_list_font_size(self.config.get_list_font_size());
         clipboard_history::set_max_text_content_len(
             self.config.get_clipboard_history_max_text_length(),
         );
+        clipboard_history::set_excluded_apps(self.config.get_clipboard_excluded_apps());
+        expand_manager::set_disabled_apps(self.config.get_expand_disabled_apps());
         logging::log(
             "APP",
             &format!("Config reloaded: padding={:?}", self.config.get_padding()),
         );
+        // Section order/names may have changed
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::SectionChange);
         cx.notify();
     }
 
@@ -357,7 +528,7 @@ impl ScriptListApp {
         self.main_list_state.scroll_to_reveal_item(0);
         self.last_scrolled_index = Some(0);
         self.invalidate_filter_cache();
-        self.invalidate_grouped_cache();
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::ScriptReload);
 
         // Rebuild alias/shortcut registries and show HUD for any conflicts
         let conflicts = self.rebuild_registries();
@@ -383,6 +554,109 @@ impl ScriptListApp {
         cx.notify();
     }
 
+    /// Advance the onboarding tour: perform the current step's action and
+    /// move to the next step (or finish the tour after the last one)
+    fn advance_onboarding(&mut self, cx: &mut Context<Self>) {
+        let Some(step) = self.onboarding_step else {
+            return;
+        };
+        logging::log("ONBOARDING", &format!("Running step {:?}", step));
+        self.run_onboarding_step(step, cx);
+        self.onboarding_step = step.next();
+        if self.onboarding_step.is_none() {
+            logging::log("ONBOARDING", "Tour completed");
+            onboarding::mark_complete();
+            self.toast_manager.push(
+                components::toast::Toast::success(
+                    "You're all set! Press your hotkey anytime to open Script Kit.",
+                    &self.theme,
+                )
+                .duration_ms(Some(5000)),
+            );
+        }
+        cx.notify();
+    }
+
+    /// Dismiss the onboarding tour without finishing it (never shows again)
+    fn dismiss_onboarding(&mut self, cx: &mut Context<Self>) {
+        logging::log("ONBOARDING", "Tour dismissed by user");
+        onboarding::mark_complete();
+        self.onboarding_step = None;
+        cx.notify();
+    }
+
+    /// Perform the action behind a single onboarding step
+    fn run_onboarding_step(&mut self, step: onboarding::OnboardingStep, cx: &mut Context<Self>) {
+        use onboarding::OnboardingStep;
+        match step {
+            OnboardingStep::Hotkey | OnboardingStep::Builtins => {
+                // Both hotkey and built-in toggles live in config.ts
+                let editor = self.config.get_editor();
+                let config_path = setup::get_kit_path().join("config.ts");
+                logging::log(
+                    "ONBOARDING",
+                    &format!("Opening {} in editor '{}'", config_path.display(), editor),
+                );
+                if let Err(e) = std::process::Command::new(&editor).arg(&config_path).spawn() {
+                    logging::log(
+                        "ERROR",
+                        &format!("Failed to spawn editor '{}': {}", editor, e),
+                    );
+                    self.toast_manager.push(
+                        components::toast::Toast::error(
+                            format!("Failed to open config.ts: {}", e),
+                            &self.theme,
+                        )
+                        .duration_ms(Some(5000)),
+                    );
+                }
+            }
+            OnboardingStep::Theme => {
+                self.current_view = AppView::DesignGalleryView {
+                    filter: String::new(),
+                    selected_index: 0,
+                };
+                defer_resize_to_view(ViewType::ScriptList, 0, cx);
+            }
+            OnboardingStep::FirstScript => {
+                // Create a template script but keep the window open so the
+                // tour can continue to the final step
+                match script_creation::create_new_script("hello-world") {
+                    Ok(path) => {
+                        logging::log("ONBOARDING", &format!("Created first script: {:?}", path));
+                        if let Err(e) = script_creation::open_in_editor(&path, &self.config) {
+                            logging::log("ERROR", &format!("Failed to open in editor: {}", e));
+                        }
+                        self.toast_manager.push(
+                            components::toast::Toast::success(
+                                "Created hello-world and opened it in your editor",
+                                &self.theme,
+                            )
+                            .duration_ms(Some(3000)),
+                        );
+                    }
+                    Err(e) => {
+                        logging::log("ERROR", &format!("Failed to create script: {}", e));
+                        self.toast_manager.push(
+                            components::toast::Toast::error(
+                                format!("Failed to create script: {}", e),
+                                &self.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        );
+                    }
+                }
+            }
+            OnboardingStep::Docs => {
+                let url = "https://scriptkit.com/docs";
+                logging::log("ONBOARDING", &format!("Opening docs: {}", url));
+                if let Err(e) = open::that(url) {
+                    logging::log("ERROR", &format!("Failed to open docs URL: {}", e));
+                }
+            }
+        }
+    }
+
     /// Open bun.sh in the default browser
     fn open_bun_website(&self) {
         logging::log("APP", "Opening https://bun.sh in default browser");
@@ -575,7 +849,7 @@ impl ScriptListApp {
 
         // Invalidate caches
         self.invalidate_filter_cache();
-        self.invalidate_grouped_cache();
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::ScriptReload);
 
         // Rebuild alias/shortcut registries for this file's scriptlets
         let conflicts = self.rebuild_registries();
@@ -703,8 +977,10 @@ impl ScriptListApp {
     fn get_grouped_results_cached(
         &mut self,
     ) -> (Arc<[GroupedListItem]>, Arc<[scripts::SearchResult]>) {
-        // P3: Key off computed_filter_text for two-stage filtering
-        if self.computed_filter_text == self.grouped_cache_key {
+        // P3: Key off computed_filter_text for two-stage filtering.
+        // The dirty flag covers everything the key can't see (script reloads,
+        // frecency updates, pin/section changes).
+        if !self.grouped_cache_dirty && self.computed_filter_text == self.grouped_cache_key {
             logging::log_debug(
                 "CACHE",
                 &format!("Grouped cache HIT for '{}'", self.computed_filter_text),
@@ -724,9 +1000,142 @@ impl ScriptListApp {
             ),
         );
 
+        // Scope operators that need app-level data: `w:` windows, `c:`
+        // clipboard history, `>` shell one-liner. `s:` and `a:` are narrowed
+        // inside fuzzy_search_unified_all and flow through the normal path.
+        if let Some((scope, rest)) = scripts::parse_query_scope(&self.computed_filter_text) {
+            let scoped: Option<Vec<scripts::SearchResult>> = match scope {
+                scripts::QueryScope::Windows => {
+                    // Listed per keystroke - acceptable for an explicit scope,
+                    // and windows change too often to cache across queries
+                    let windows = window_control::list_windows().unwrap_or_default();
+                    Some(
+                        scripts::fuzzy_search_windows(&windows, rest)
+                            .into_iter()
+                            .map(scripts::SearchResult::Window)
+                            .collect(),
+                    )
+                }
+                scripts::QueryScope::Clipboard => {
+                    let needle = rest.to_lowercase();
+                    let entries: Vec<(String, Option<String>)> =
+                        clipboard_history::get_cached_entries(100)
+                            .into_iter()
+                            .filter(|e| {
+                                needle.is_empty() || e.content.to_lowercase().contains(&needle)
+                            })
+                            .map(|e| (e.content, e.source_app))
+                            .collect();
+                    Some(
+                        builtins::get_clipboard_scope_entries(&entries)
+                            .into_iter()
+                            .map(|entry| {
+                                scripts::SearchResult::BuiltIn(scripts::BuiltInMatch {
+                                    entry,
+                                    score: 0,
+                                })
+                            })
+                            .collect(),
+                    )
+                }
+                scripts::QueryScope::Shell => {
+                    let command = rest.trim();
+                    let mut rows = Vec::new();
+                    if !command.is_empty() {
+                        rows.push(scripts::SearchResult::BuiltIn(scripts::BuiltInMatch {
+                            entry: builtins::get_shell_command_entry(command),
+                            score: 0,
+                        }));
+                    }
+                    // History section: a bare `>` lists everything, otherwise
+                    // entries containing the typed command (minus the exact
+                    // duplicate of the row above)
+                    let history: Vec<String> = if command.is_empty() {
+                        self.shell_history.commands().to_vec()
+                    } else {
+                        self.shell_history
+                            .matching(command)
+                            .into_iter()
+                            .filter(|c| c.as_str() != command)
+                            .cloned()
+                            .collect()
+                    };
+                    rows.extend(builtins::get_shell_history_entries(&history).into_iter().map(
+                        |entry| {
+                            scripts::SearchResult::BuiltIn(scripts::BuiltInMatch { entry, score: 0 })
+                        },
+                    ));
+                    Some(rows)
+                }
+                scripts::QueryScope::Scripts | scripts::QueryScope::Apps => None,
+            };
+
+            if let Some(flat_results) = scoped {
+                let grouped: Vec<GroupedListItem> =
+                    (0..flat_results.len()).map(GroupedListItem::Item).collect();
+                self.cached_grouped_items = grouped.into();
+                self.cached_grouped_flat_results = flat_results.into();
+                self.grouped_cache_key = self.computed_filter_text.clone();
+                self.grouped_cache_dirty = false;
+                return (
+                    self.cached_grouped_items.clone(),
+                    self.cached_grouped_flat_results.clone(),
+                );
+            }
+        }
+
+        // Incremental re-filter: when the user appended to the previous
+        // query, re-score only the cached result set instead of the full
+        // corpus. Deletion (or any other edit) falls through to full search.
+        if !self.grouped_cache_dirty
+            && scripts::incremental_refilter_applies(
+                &self.grouped_cache_key,
+                &self.computed_filter_text,
+            )
+        {
+            let start = std::time::Instant::now();
+            if let Some(flat_results) = scripts::fuzzy_search_unified_incremental(
+                &self.cached_grouped_flat_results,
+                &self.computed_filter_text,
+            ) {
+                // An empty set means a dead-end query: fall through so the
+                // full path below can append its fallback rows
+                if !flat_results.is_empty() {
+                    logging::log_debug(
+                        "CACHE",
+                        &format!(
+                            "Incremental re-filter: {} -> {} results in {:.2}ms for '{}'",
+                            self.cached_grouped_flat_results.len(),
+                            flat_results.len(),
+                            start.elapsed().as_secs_f64() * 1000.0,
+                            self.computed_filter_text
+                        ),
+                    );
+                    let grouped: Vec<GroupedListItem> =
+                        (0..flat_results.len()).map(GroupedListItem::Item).collect();
+                    self.cached_grouped_items = grouped.into();
+                    self.cached_grouped_flat_results = flat_results.into();
+                    self.grouped_cache_key = self.computed_filter_text.clone();
+                    return (
+                        self.cached_grouped_items.clone(),
+                        self.cached_grouped_flat_results.clone(),
+                    );
+                }
+            }
+        }
+
         let start = std::time::Instant::now();
         let max_recent_items = self.config.get_frecency().max_recent_items;
-        let (grouped_items, flat_results) = get_grouped_results(
+        let sections_config = self.config.get_sections();
+        let section_options = scripts::SectionOptions {
+            order: sections_config.order,
+            collapsed: self.section_state.collapsed().clone(),
+            pinned: self.pin_store.pins().to_vec(),
+            hidden: self.hidden_store.hidden().clone(),
+            max_items: sections_config.max_section_items,
+            expanded: self.expanded_sections.clone(),
+        };
+        let (mut grouped_items, mut flat_results) = scripts::get_grouped_results_with_sections(
             &self.scripts,
             &self.scriptlets,
             &self.builtin_entries,
@@ -734,13 +1143,53 @@ impl ScriptListApp {
             &self.frecency_store,
             &self.computed_filter_text,
             max_recent_items,
+            &section_options,
         );
         let elapsed = start.elapsed();
 
+        // Tags browser: a bare "#" lists every tag with its script count;
+        // running a row narrows the filter to that tag
+        if self.computed_filter_text == "#" {
+            let tags = scripts::collect_tags(&self.scripts);
+            for entry in builtins::get_tag_entries(&tags) {
+                grouped_items.push(GroupedListItem::Item(flat_results.len()));
+                flat_results.push(scripts::SearchResult::BuiltIn(scripts::BuiltInMatch {
+                    entry,
+                    score: 0,
+                }));
+            }
+        }
+
+        // Dead-end search: offer fallback rows (web search, create script, file search)
+        // instead of an empty list
+        if flat_results.is_empty()
+            && !self.computed_filter_text.is_empty()
+            // Skip the \0-prefixed cache sentinels - they aren't real queries
+            // and '#' tag queries get an empty list, not fallbacks.
+            // Scoped queries (s:/a:) stay empty too - the user asked for one
+            // result type, so cross-type fallbacks would be noise
+            && !self.computed_filter_text.starts_with('\0')
+            && !self.computed_filter_text.starts_with('#')
+            && scripts::parse_query_scope(&self.computed_filter_text).is_none()
+        {
+            let fallbacks = builtins::get_fallback_entries(
+                &self.computed_filter_text,
+                &self.config.get_fallbacks(),
+            );
+            for entry in fallbacks {
+                grouped_items.push(GroupedListItem::Item(flat_results.len()));
+                flat_results.push(scripts::SearchResult::BuiltIn(scripts::BuiltInMatch {
+                    entry,
+                    score: 0,
+                }));
+            }
+        }
+
         // P1-Arc: Convert to Arc<[T]> for cheap clone
         self.cached_grouped_items = grouped_items.into();
         self.cached_grouped_flat_results = flat_results.into();
         self.grouped_cache_key = self.computed_filter_text.clone();
+        self.grouped_cache_dirty = false;
 
         if !self.computed_filter_text.is_empty() {
             logging::log_debug(
@@ -760,12 +1209,172 @@ impl ScriptListApp {
         )
     }
 
-    /// P1: Invalidate grouped results cache (call when scripts/scriptlets/apps change)
-    fn invalidate_grouped_cache(&mut self) {
-        logging::log_debug("CACHE", "Grouped cache INVALIDATED");
-        self.grouped_cache_key = String::from("\0_INVALIDATED_\0");
-        // Also reset computed_filter_text to force recompute
-        self.computed_filter_text = String::from("\0_INVALIDATED_\0");
+    /// Expand a section past its per-section item cap ("Show more…" row).
+    /// Session-only - the cap comes back on the next launch.
+    fn expand_section(&mut self, section_id: &str, cx: &mut Context<Self>) {
+        logging::log("UI", &format!("Expanding section '{}'", section_id));
+        self.expanded_sections.insert(section_id.to_string());
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::SectionChange);
+        cx.notify();
+    }
+
+    /// Toggle a section's collapsed state (click on its header) and persist it
+    fn toggle_section_collapsed(&mut self, section_id: &str, cx: &mut Context<Self>) {
+        let collapsed = self.section_state.toggle(section_id);
+        self.section_state.save().ok(); // Best-effort save
+        logging::log(
+            "UI",
+            &format!(
+                "Section {} {}",
+                section_id,
+                if collapsed { "collapsed" } else { "expanded" }
+            ),
+        );
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::SectionChange);
+        cx.notify();
+    }
+
+    /// Toggle the selected item's pinned state (PINNED section) and persist it
+    fn toggle_pin_selected(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.get_selected_result().as_ref().and_then(scripts::result_key) else {
+            self.last_output = Some(SharedString::from("No item selected"));
+            return;
+        };
+        let pinned = self.pin_store.toggle(&key);
+        self.pin_store.save().ok(); // Best-effort save
+        logging::log(
+            "UI",
+            &format!("{} {}", if pinned { "Pinned" } else { "Unpinned" }, key),
+        );
+        if !pinned {
+            // Removing a pin is destructive enough to warrant an undo window
+            self.offer_undo("Unpinned", undo::UndoAction::PinRemoved { key });
+        }
+        self.last_output = Some(SharedString::from(if pinned {
+            "Pinned to top"
+        } else {
+            "Unpinned"
+        }));
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::PinChange);
+        cx.notify();
+    }
+
+    /// Record a destructive action and surface an undo toast (Cmd+Z, 5s window)
+    fn offer_undo(&mut self, description: impl Into<String>, action: undo::UndoAction) {
+        let description = description.into();
+        self.toast_manager.push(
+            components::toast::Toast::info(
+                format!("{} - press Cmd+Z to undo", description),
+                &self.theme,
+            )
+            .duration_ms(Some(undo::UNDO_WINDOW_MS)),
+        );
+        self.pending_undo = Some(undo::PendingUndo::new(description, action));
+    }
+
+    /// Revert the most recent destructive action if its undo window is still
+    /// open. Returns true when an undo was attempted (success or failure).
+    fn undo_last(&mut self, cx: &mut Context<Self>) -> bool {
+        let Some(pending) = self.pending_undo.take() else {
+            return false;
+        };
+        if pending.is_expired() {
+            logging::log("UNDO", &format!("Undo window expired: {}", pending.description));
+            return false;
+        }
+
+        let outcome: anyhow::Result<String> = match pending.action {
+            undo::UndoAction::FileTrashed { original, trashed } => {
+                undo::restore_from_trash(&trashed, &original).map(|()| {
+                    format!(
+                        "Restored {}",
+                        original
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_default()
+                    )
+                })
+            }
+            undo::UndoAction::PinRemoved { key } => {
+                if !self.pin_store.is_pinned(&key) {
+                    self.pin_store.toggle(&key);
+                    self.pin_store.save().ok(); // Best-effort save
+                    self.invalidate_grouped_cache(GroupedCacheInvalidation::PinChange);
+                }
+                Ok("Restored pin".to_string())
+            }
+            undo::UndoAction::ClipboardCleared => clipboard_history::restore_cleared_history()
+                .map(|count| format!("Restored {} clipboard entries", count)),
+        };
+
+        match outcome {
+            Ok(message) => {
+                logging::log("UNDO", &message);
+                self.toast_manager.push(
+                    components::toast::Toast::success(message, &self.theme)
+                        .duration_ms(Some(3000)),
+                );
+            }
+            Err(e) => {
+                logging::log("ERROR", &format!("Undo failed: {}", e));
+                self.toast_manager.push(
+                    components::toast::Toast::error(format!("Undo failed: {}", e), &self.theme)
+                        .duration_ms(Some(4000)),
+                );
+            }
+        }
+        cx.notify();
+        true
+    }
+
+    /// Move the selected pinned item up or down within the PINNED section
+    /// (Cmd+Up / Cmd+Down). No-op if the selection isn't pinned.
+    fn move_selected_pin(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let Some(key) = self.get_selected_result().as_ref().and_then(scripts::result_key) else {
+            return;
+        };
+        if self.pin_store.shift(&key, delta) {
+            self.pin_store.save().ok(); // Best-effort save
+            self.invalidate_grouped_cache(GroupedCacheInvalidation::PinChange);
+            // The item moved by exactly one row within the section; follow it
+            self.selected_index = self.selected_index.saturating_add_signed(delta);
+            cx.notify();
+        }
+    }
+
+    /// Hide the selected item from search results (or unhide it) and persist it
+    ///
+    /// Hidden items stay loaded, so aliases, shortcuts, and `run` messages
+    /// still work; the "Unhide All Scripts" built-in clears the whole list.
+    fn toggle_hidden_selected(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.get_selected_result().as_ref().and_then(scripts::result_key) else {
+            self.last_output = Some(SharedString::from("No item selected"));
+            return;
+        };
+        let now_hidden = self.hidden_store.toggle(&key);
+        self.hidden_store.save().ok(); // Best-effort save
+        logging::log(
+            "UI",
+            &format!("{} {}", if now_hidden { "Hid" } else { "Unhid" }, key),
+        );
+        self.last_output = Some(SharedString::from(if now_hidden {
+            "Hidden from search"
+        } else {
+            "Visible in search"
+        }));
+        self.invalidate_grouped_cache(GroupedCacheInvalidation::SectionChange);
+        cx.notify();
+    }
+
+    /// P1: Mark the grouped results cache dirty so the next access recomputes
+    ///
+    /// The `reason` is log-only, but forcing callers to name one documents
+    /// every mutation that can go stale. The flag (rather than a sentinel
+    /// cache key) leaves `computed_filter_text` intact, so query history and
+    /// the dead-end-search guard never see an invalidation marker.
+    fn invalidate_grouped_cache(&mut self, reason: GroupedCacheInvalidation) {
+        logging::log_debug("CACHE", &format!("Grouped cache INVALIDATED ({:?})", reason));
+        self.grouped_cache_dirty = true;
     }
 
     /// Get the currently selected search result, correctly mapping from grouped index.
@@ -788,6 +1397,31 @@ impl ScriptListApp {
         }
     }
 
+    /// Announce the currently selected list row to VoiceOver.
+    ///
+    /// The custom-drawn list is invisible to the accessibility tree, so
+    /// selection moves post an AX announcement instead (see `accessibility`).
+    fn announce_selected_result(&mut self) {
+        let selected_index = self.selected_index;
+        let label = {
+            let (grouped_items, flat_results) = self.get_grouped_results_cached();
+            match grouped_items.get(selected_index) {
+                Some(GroupedListItem::Item(idx)) => flat_results.get(*idx).map(|result| {
+                    accessibility::list_item_label(
+                        result.name(),
+                        result.description(),
+                        *idx + 1,
+                        flat_results.len(),
+                    )
+                }),
+                _ => None,
+            }
+        };
+        if let Some(label) = label {
+            accessibility::announce(&label);
+        }
+    }
+
     /// Get or update the preview cache for syntax-highlighted code lines.
     /// Only re-reads and re-highlights when the script path actually changes.
     /// Returns cached lines if path matches, otherwise updates cache and returns new lines.
@@ -903,28 +1537,58 @@ impl ScriptListApp {
         let result_idx = match grouped_items.get(self.selected_index) {
             Some(GroupedListItem::Item(idx)) => Some(*idx),
             Some(GroupedListItem::SectionHeader(_)) => None, // Section headers are not selectable
+            Some(GroupedListItem::ShowMore { section, .. }) => {
+                // Enter on a "Show more…" row expands its section in place
+                let section = section.clone();
+                self.expand_section(&section, cx);
+                return;
+            }
             None => None,
         };
 
         if let Some(idx) = result_idx {
             if let Some(result) = flat_results.get(idx).cloned() {
-                // Record frecency usage before executing
+                // Record frecency usage before executing. Fallback and tag
+                // rows are dynamic one-offs, so they never get frecency.
                 let frecency_path = match &result {
                     scripts::SearchResult::Script(sm) => {
-                        sm.script.path.to_string_lossy().to_string()
+                        Some(sm.script.path.to_string_lossy().to_string())
+                    }
+                    scripts::SearchResult::App(am) => {
+                        Some(am.app.path.to_string_lossy().to_string())
+                    }
+                    scripts::SearchResult::BuiltIn(bm) => {
+                        if matches!(
+                            bm.entry.feature,
+                            builtins::BuiltInFeature::Fallback(_)
+                                | builtins::BuiltInFeature::TagFilter(_)
+                        ) {
+                            None
+                        } else {
+                            Some(format!("builtin:{}", bm.entry.name))
+                        }
                     }
-                    scripts::SearchResult::App(am) => am.app.path.to_string_lossy().to_string(),
-                    scripts::SearchResult::BuiltIn(bm) => format!("builtin:{}", bm.entry.name),
                     scripts::SearchResult::Scriptlet(sm) => {
-                        format!("scriptlet:{}", sm.scriptlet.name)
+                        Some(format!("scriptlet:{}", sm.scriptlet.name))
                     }
                     scripts::SearchResult::Window(wm) => {
-                        format!("window:{}:{}", wm.window.app, wm.window.title)
+                        Some(format!("window:{}:{}", wm.window.app, wm.window.title))
                     }
                 };
-                self.frecency_store.record_use(&frecency_path);
-                self.frecency_store.save().ok(); // Best-effort save
-                self.invalidate_grouped_cache(); // Invalidate cache so next show reflects frecency
+                if let Some(frecency_path) = frecency_path {
+                    self.frecency_store.record_use(&frecency_path);
+                    self.frecency_store.save().ok(); // Best-effort save
+                    self.invalidate_grouped_cache(GroupedCacheInvalidation::FrecencyUpdate); // Invalidate cache so next show reflects frecency
+                }
+
+                // Remember the query that led to this choice (shell-style
+                // history, recalled with Up on an empty filter or Ctrl+R)
+                let query = self.computed_filter_text.trim().to_string();
+                if !query.is_empty() {
+                    self.query_history.record("main", &query);
+                    self.query_history.save().ok(); // Best-effort save
+                }
+                self.history_cycle_index = None;
 
                 match result {
                     scripts::SearchResult::Script(script_match) => {
@@ -932,7 +1596,26 @@ impl ScriptListApp {
                             "EXEC",
                             &format!("Executing script: {}", script_match.script.name),
                         );
-                        self.execute_interactive(&script_match.script, cx);
+                        // "script-name arg1 arg2" in the filter passes the
+                        // trailing tokens to the script as argv
+                        let script_args = self.filter_args_for_script(&script_match.script.name);
+                        if script_args.is_empty() {
+                            self.execute_interactive(&script_match.script, cx);
+                        } else {
+                            logging::log(
+                                "EXEC",
+                                &format!(
+                                    "Passing {} filter args to {}",
+                                    script_args.len(),
+                                    script_match.script.name
+                                ),
+                            );
+                            self.execute_interactive_with_args(
+                                &script_match.script,
+                                &script_args,
+                                cx,
+                            );
+                        }
                     }
                     scripts::SearchResult::Scriptlet(scriptlet_match) => {
                         logging::log(
@@ -979,6 +1662,9 @@ impl ScriptListApp {
             self.pending_confirmation = None;
         }
 
+        // Typing ends any history recall cycle
+        self.history_cycle_index = None;
+
         let previous_text = std::mem::replace(&mut self.filter_text, new_text.clone());
         self.selected_index = 0;
         self.last_scrolled_index = None;
@@ -1064,9 +1750,77 @@ impl ScriptListApp {
     }
 
     fn clear_filter(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.history_cycle_index = None;
         self.set_filter_text_immediate(String::new(), window, cx);
     }
 
+    /// Whether the selection is at (or above) the first selectable row
+    ///
+    /// Used to decide when Up should recall query history instead of
+    /// moving the selection.
+    fn selection_at_top(&mut self) -> bool {
+        let (grouped_items, _) = self.get_grouped_results_cached();
+        match grouped_items
+            .iter()
+            .position(|item| matches!(item, GroupedListItem::Item(_)))
+        {
+            Some(first) => self.selected_index <= first,
+            None => true,
+        }
+    }
+
+    /// Step through the main filter's query history, shell-style
+    ///
+    /// `older: true` (Up / Ctrl+R) moves toward the oldest recorded query;
+    /// `older: false` (Down while cycling) moves back toward the newest and
+    /// clears the filter past it. Typing resets the cycle position.
+    fn cycle_query_history(&mut self, older: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let queries = self.query_history.queries("main");
+        if queries.is_empty() {
+            return;
+        }
+
+        let next_index = match (self.history_cycle_index, older) {
+            (None, true) => Some(0),
+            (None, false) => return,
+            (Some(i), true) => Some((i + 1).min(queries.len() - 1)),
+            (Some(0), false) => None,
+            (Some(i), false) => Some(i - 1),
+        };
+
+        match next_index {
+            Some(index) => {
+                let query = queries[index].clone();
+                logging::log(
+                    "KEY",
+                    &format!("History recall {} of {}: {}", index + 1, queries.len(), query),
+                );
+                self.set_filter_text_immediate(query, window, cx);
+                self.history_cycle_index = Some(index);
+            }
+            None => {
+                // Stepped past the newest entry - back to an empty filter
+                self.clear_filter(window, cx);
+            }
+        }
+    }
+
+    /// Set the main filter text from a context without window access
+    /// (e.g. built-in execution). The input widget catches up on the next
+    /// render via sync_filter_input_if_needed.
+    fn set_filter_text_deferred(&mut self, text: &str, cx: &mut Context<Self>) {
+        self.filter_text = text.to_string();
+        self.computed_filter_text = text.to_string();
+        self.filter_coalescer.reset();
+        self.pending_filter_sync = true;
+
+        self.selected_index = 0;
+        self.last_scrolled_index = None;
+        self.main_list_state.scroll_to_reveal_item(0);
+        self.last_scrolled_index = Some(0);
+        cx.notify();
+    }
+
     fn sync_filter_input_if_needed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if !self.pending_filter_sync {
             return;
@@ -1119,8 +1873,11 @@ impl ScriptListApp {
                 (ViewType::EditorPrompt, 0)
             }
             AppView::SelectPrompt { .. } => (ViewType::ArgPromptWithChoices, 0),
+            AppView::TablePrompt { .. } => (ViewType::ArgPromptWithChoices, 0),
             AppView::PathPrompt { .. } => (ViewType::DivPrompt, 0),
             AppView::EnvPrompt { .. } => (ViewType::ArgPromptNoChoices, 0), // Env prompt is a simple input
+            AppView::NumberPrompt { .. } => (ViewType::ArgPromptNoChoices, 0), // Number prompt is a simple input
+            AppView::DatePrompt { .. } => (ViewType::ScriptList, 0), // Date prompt shows a calendar grid
             AppView::DropPrompt { .. } => (ViewType::DivPrompt, 0), // Drop prompt uses div size for drop zone
             AppView::TemplatePrompt { .. } => (ViewType::DivPrompt, 0), // Template prompt uses div size
             AppView::TermPrompt { .. } => (ViewType::TermPrompt, 0),
@@ -1171,6 +1928,153 @@ impl ScriptListApp {
                 };
                 (ViewType::ScriptList, filtered_count)
             }
+            AppView::BackgroundTasksView { tasks, filter, .. } => {
+                let filtered_count = if filter.is_empty() {
+                    tasks.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    tasks
+                        .iter()
+                        .filter(|t| t.name.to_lowercase().contains(&filter_lower))
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::ShortcutsView {
+                shortcuts, filter, ..
+            } => {
+                let filtered_count = if filter.is_empty() {
+                    shortcuts.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    shortcuts
+                        .iter()
+                        .filter(|name| name.to_lowercase().contains(&filter_lower))
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::RecentlyDeletedView {
+                entries, filter, ..
+            } => {
+                let filtered_count = if filter.is_empty() {
+                    entries.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    entries
+                        .iter()
+                        .filter(|e| e.name.to_lowercase().contains(&filter_lower))
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::ThemeBrowserView { themes, filter, .. } => {
+                let filtered_count = if filter.is_empty() {
+                    themes.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    themes
+                        .iter()
+                        .filter(|t| t.name.to_lowercase().contains(&filter_lower))
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::ExpandStatsView {
+                entries, filter, ..
+            } => {
+                let filtered_count = if filter.is_empty() {
+                    entries.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    entries
+                        .iter()
+                        .filter(|e| {
+                            e.trigger.to_lowercase().contains(&filter_lower)
+                                || e.name.to_lowercase().contains(&filter_lower)
+                        })
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::GitHubView { items, filter, .. } => {
+                let filtered_count = if filter.is_empty() {
+                    items.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    items
+                        .iter()
+                        .filter(|i| {
+                            i.title.to_lowercase().contains(&filter_lower)
+                                || i.subtitle
+                                    .as_ref()
+                                    .is_some_and(|s| s.to_lowercase().contains(&filter_lower))
+                        })
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::ConnectView {
+                targets, filter, ..
+            } => {
+                let filtered_count = if filter.is_empty() {
+                    targets.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    targets
+                        .iter()
+                        .filter(|t| t.name.to_lowercase().contains(&filter_lower))
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::FocusModesView { modes, filter, .. } => {
+                let filtered_count = if filter.is_empty() {
+                    modes.len()
+                } else {
+                    let filter_lower = filter.to_lowercase();
+                    modes
+                        .iter()
+                        .filter(|m| m.name.to_lowercase().contains(&filter_lower))
+                        .count()
+                };
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::WorldClockView {
+                favorites, filter, ..
+            } => {
+                let filtered_count = world_clock::results(filter, favorites).len();
+                (ViewType::ScriptList, filtered_count)
+            }
+            AppView::FileSearchView { results, .. } => {
+                // Results already reflect the current filter (the key handler
+                // re-queries Spotlight on every change)
+                (ViewType::ScriptList, results.len())
+            }
+            AppView::BatchRenameView { state } => {
+                let count = match state.stage {
+                    batch_rename::Stage::PickFiles => state.visible_entries().len(),
+                    batch_rename::Stage::Pattern => state.selected.len(),
+                };
+                (ViewType::ScriptList, count)
+            }
+            AppView::ScreenshotHistoryView { shots, filter, .. } => {
+                let count = screenshot_history::filter_screenshots(shots, filter).len();
+                (ViewType::ScriptList, count)
+            }
+            AppView::FileMoverView { state } => {
+                let count = match state.stage {
+                    file_mover::Stage::PickFiles => state.visible_entries().len(),
+                    file_mover::Stage::PickDest => state.dest_rows().len(),
+                    file_mover::Stage::Conflicts | file_mover::Stage::Moving => {
+                        state.selected.len()
+                    }
+                };
+                (ViewType::ScriptList, count)
+            }
+            AppView::SecretsVaultView { state } => {
+                (ViewType::ScriptList, state.visible_keys().len())
+            }
             AppView::DesignGalleryView { filter, .. } => {
                 // Calculate total gallery items (separators + icons)
                 let total_items = designs::separator_variations::SeparatorStyle::count()
@@ -1183,6 +2087,10 @@ impl ScriptListApp {
                 };
                 (ViewType::ScriptList, filtered_count)
             }
+            AppView::ErrorView { snippet, .. } => {
+                // Snippet lines plus the two action rows
+                (ViewType::ScriptList, snippet.len() + 2)
+            }
         };
 
         let target_height = height_for_view(view_type, item_count);
@@ -1205,9 +2113,18 @@ impl ScriptListApp {
             AppView::SelectPrompt { entity, .. } => {
                 entity.update(cx, |prompt, cx| prompt.set_input(text, cx));
             }
+            AppView::TablePrompt { entity, .. } => {
+                entity.update(cx, |prompt, cx| prompt.set_input(text, cx));
+            }
             AppView::EnvPrompt { entity, .. } => {
                 entity.update(cx, |prompt, cx| prompt.set_input(text, cx));
             }
+            AppView::NumberPrompt { entity, .. } => {
+                entity.update(cx, |prompt, cx| prompt.set_input(text, cx));
+            }
+            AppView::DatePrompt { entity, .. } => {
+                entity.update(cx, |prompt, cx| prompt.set_input(text, cx));
+            }
             AppView::TemplatePrompt { entity, .. } => {
                 entity.update(cx, |prompt, cx| prompt.set_input(text, cx));
             }
@@ -1247,6 +2164,7 @@ impl ScriptListApp {
             self.actions_dialog = None;
             self.focus_main_filter(window, cx);
             logging::log("FOCUS", "Actions closed, focus returned to MainFilter");
+            accessibility::announce("Actions dialog closed");
         } else {
             // Open - create dialog entity
             self.show_actions_popup = true;
@@ -1272,6 +2190,10 @@ impl ScriptListApp {
             self.actions_dialog = Some(dialog.clone());
             window.focus(&dialog_focus_handle, cx);
             logging::log("FOCUS", "Actions opened, focus moved to ActionsSearch");
+            accessibility::announce(&accessibility::dialog_label(
+                "Actions",
+                dialog.read(cx).filtered_actions.len(),
+            ));
         }
         cx.notify();
     }
@@ -1336,13 +2258,102 @@ impl ScriptListApp {
                 } else {
                     logging::log("KEY", "No SDK actions available to show (empty list)");
                 }
-            } else {
-                logging::log("KEY", "No SDK actions defined for this arg prompt (None)");
+            } else {
+                logging::log("KEY", "No SDK actions defined for this arg prompt (None)");
+            }
+        }
+        cx.notify();
+    }
+
+
+    /// Open the Background Tasks view (the running-sessions list).
+    /// Shared by the builtin entry and the header running-scripts badge.
+    pub fn open_background_tasks(&mut self, cx: &mut Context<Self>) {
+        logging::log("EXEC", "Opening Background Tasks");
+        // Snapshot the registry when the view is opened (tasks come and go)
+        let tasks = background_tasks::list();
+        logging::log(
+            "EXEC",
+            &format!("Loaded {} background task(s)", tasks.len()),
+        );
+        self.current_view = AppView::BackgroundTasksView {
+            tasks,
+            filter: String::new(),
+            selected_index: 0,
+        };
+        // Use standard height for background tasks view
+        defer_resize_to_view(ViewType::ScriptList, 0, cx);
+        cx.notify();
+    }
+
+    /// Keep `file_preview_cache` in sync with the selected path, reading the
+    /// preview on the task pool instead of the render path. Shared by the
+    /// File Search and Screenshot History views; callers render a loading
+    /// placeholder while `file_preview_loading` is set.
+    fn request_file_preview(&mut self, selected_path: Option<String>, cx: &mut Context<Self>) {
+        match selected_path {
+            Some(path) => {
+                let cached = self
+                    .file_preview_cache
+                    .as_ref()
+                    .is_some_and(|(cached_path, _)| *cached_path == path);
+                if cached || self.file_preview_loading.as_deref() == Some(path.as_str()) {
+                    return;
+                }
+                // Drop the previous selection's preview so the panel shows
+                // the placeholder rather than a stale file
+                self.file_preview_cache = None;
+                self.file_preview_loading = Some(path.clone());
+                let job_path = path.clone();
+                let rx = task_pool::run(move || file_preview::preview_for_path(&job_path));
+                cx.spawn(async move |this, cx| {
+                    loop {
+                        Timer::after(Duration::from_millis(30)).await;
+                        match rx.try_recv() {
+                            Ok(preview) => {
+                                let _ = cx.update(|cx| {
+                                    this.update(cx, |app, cx| {
+                                        // The selection may have moved on;
+                                        // only apply the preview still wanted
+                                        if app.file_preview_loading.as_deref()
+                                            == Some(path.as_str())
+                                        {
+                                            app.file_preview_loading = None;
+                                            app.file_preview_cache =
+                                                Some((path.clone(), preview));
+                                            cx.notify();
+                                        }
+                                    })
+                                });
+                                break;
+                            }
+                            Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                            Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                        }
+                    }
+                })
+                .detach();
+            }
+            None => {
+                self.file_preview_cache = None;
+                self.file_preview_loading = None;
             }
         }
-        cx.notify();
     }
 
+    /// Jump from a log panel line to its source: open the file in the
+    /// configured editor at the line that logged it
+    fn open_log_source(&mut self, path: &str, line: u32, _cx: &mut Context<Self>) {
+        logging::log("UI", &format!("Log jump-to-source: {}:{}", path, line));
+        if let Err(e) =
+            script_creation::open_in_editor_at_line(std::path::Path::new(path), line, &self.config)
+        {
+            logging::log(
+                "ERROR",
+                &format!("Failed to open {}:{} in editor: {}", path, line, e),
+            );
+        }
+    }
 
     /// Edit a script in configured editor (config.editor > $EDITOR > "code")
     #[allow(dead_code)]
@@ -1366,6 +2377,51 @@ impl ScriptListApp {
         });
     }
 
+    /// Edit a scriptlet by jumping the editor to its `## Name` heading
+    ///
+    /// Scriptlets live inside markdown bundles, so opening the whole file at
+    /// line 1 forces users to hunt through long .md files. This resolves the
+    /// heading line for the scriptlet and passes an editor-specific goto
+    /// argument (e.g. `code -g file.md:42`). Falls back to opening the file
+    /// without a line when the heading can't be found.
+    #[allow(dead_code)]
+    fn edit_scriptlet(&mut self, file_path: &str, name: &str) {
+        // file_path may carry a display anchor (e.g. "url.md#open-github")
+        let path_str = file_path
+            .split_once('#')
+            .map(|(p, _)| p.to_string())
+            .unwrap_or_else(|| file_path.to_string());
+
+        let editor = self.config.get_editor();
+        let heading_line = std::fs::read_to_string(&path_str)
+            .ok()
+            .and_then(|content| crate::utils::find_scriptlet_heading_line(&content, name));
+
+        let args = match heading_line {
+            Some(line) => crate::utils::editor_goto_args(&editor, &path_str, line),
+            None => vec![path_str.clone()],
+        };
+
+        logging::log(
+            "UI",
+            &format!(
+                "Opening scriptlet '{}' in editor '{}': {} (line {:?})",
+                name, editor, path_str, heading_line
+            ),
+        );
+
+        std::thread::spawn(move || {
+            use std::process::Command;
+            match Command::new(&editor).args(&args).spawn() {
+                Ok(_) => logging::log("UI", &format!("Successfully spawned editor: {}", editor)),
+                Err(e) => logging::log(
+                    "ERROR",
+                    &format!("Failed to spawn editor '{}': {}", editor, e),
+                ),
+            }
+        });
+    }
+
     /// Execute a path action from the actions dialog
     /// Handles actions like copy_path, open_in_finder, open_in_editor, etc.
     fn execute_path_action(
@@ -1589,53 +2645,63 @@ impl ScriptListApp {
                     }
                 }
             }
-            "move_to_trash" => {
-                // Move to trash (macOS)
-                #[cfg(target_os = "macos")]
-                {
-                    use std::process::Command;
-                    let path_str = path_info.path.clone();
-                    let name = path_info.name.clone();
-
-                    // Use AppleScript to move to trash (preserves undo capability)
-                    let script = format!(
-                        r#"tell application "Finder"
-                            delete POSIX file "{}"
-                        end tell"#,
-                        path_str
-                    );
-
-                    match Command::new("osascript").args(["-e", &script]).spawn() {
-                        Ok(mut child) => {
-                            // Wait for completion and check result
-                            match child.wait() {
-                                Ok(status) if status.success() => {
-                                    logging::log("UI", &format!("Moved to trash: {}", path_str));
-                                    self.last_output = Some(SharedString::from(format!(
-                                        "Moved to Trash: {}",
-                                        name
-                                    )));
-                                    // Refresh the path prompt to show the file is gone
-                                    path_prompt_entity.update(cx, |prompt, cx| {
-                                        let current = prompt.current_path.clone();
-                                        prompt.navigate_to(&current, cx);
-                                    });
-                                }
-                                _ => {
-                                    logging::log(
-                                        "ERROR",
-                                        &format!("Failed to move to trash: {}", path_str),
-                                    );
-                                    self.last_output =
-                                        Some(SharedString::from("Failed to move to Trash"));
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            logging::log("ERROR", &format!("Failed to spawn trash command: {}", e));
-                            self.last_output = Some(SharedString::from("Failed to move to Trash"));
+            "share_airdrop" | "share_mail" | "share_messages" | "copy_to_icloud" => {
+                let service = match action_id {
+                    "share_airdrop" => share::ShareService::AirDrop,
+                    "share_mail" => share::ShareService::Mail,
+                    "share_messages" => share::ShareService::Messages,
+                    _ => share::ShareService::ICloudDrive,
+                };
+                match share::share_file(std::path::Path::new(&path_info.path), service) {
+                    Ok(()) => {
+                        if service == share::ShareService::ICloudDrive {
+                            self.toast_manager.push(components::toast::Toast::success(
+                                format!("Copied {} to iCloud Drive", path_info.name),
+                                &self.theme,
+                            ));
+                        } else {
+                            // The sharing service shows its own UI (AirDrop
+                            // browser / compose window) - get out of its way
+                            script_kit_gpui::set_main_window_visible(false);
+                            NEEDS_RESET.store(true, Ordering::SeqCst);
+                            cx.hide();
                         }
                     }
+                    Err(e) => {
+                        logging::log("ERROR", &format!("Share failed: {}", e));
+                        self.toast_manager
+                            .push(components::toast::Toast::error(e, &self.theme));
+                    }
+                }
+            }
+            "move_to_trash" => {
+                // Move into the app-managed trash directory so the delete is
+                // undoable (Cmd+Z) instead of relying on Finder
+                let path_str = path_info.path.clone();
+                let name = path_info.name.clone();
+
+                match undo::move_to_trash(std::path::Path::new(&path_str)) {
+                    Ok(trashed) => {
+                        logging::log("UI", &format!("Moved to trash: {}", path_str));
+                        self.offer_undo(
+                            format!("Moved to Trash: {}", name),
+                            undo::UndoAction::FileTrashed {
+                                original: std::path::PathBuf::from(&path_str),
+                                trashed,
+                            },
+                        );
+                        self.last_output =
+                            Some(SharedString::from(format!("Moved to Trash: {}", name)));
+                        // Refresh the path prompt to show the file is gone
+                        path_prompt_entity.update(cx, |prompt, cx| {
+                            let current = prompt.current_path.clone();
+                            prompt.navigate_to(&current, cx);
+                        });
+                    }
+                    Err(e) => {
+                        logging::log("ERROR", &format!("Failed to move to trash: {}", e));
+                        self.last_output = Some(SharedString::from("Failed to move to Trash"));
+                    }
                 }
             }
             _ => {
@@ -1702,6 +2768,15 @@ impl ScriptListApp {
                 icon: None,
                 alias: None,
                 shortcut: None,
+                args: None,
+                hidden: false,
+                sandbox: false,
+                sudo: false,
+                profile: None,
+                env_file: None,
+                cwd: None,
+                nice: None,
+                tags: Vec::new(),
                 typed_metadata: None,
                 schema: None,
             };
@@ -1729,6 +2804,9 @@ impl ScriptListApp {
                 shortcut: scriptlet.shortcut.clone(),
                 expand: scriptlet.expand.clone(),
                 description: scriptlet.description.clone(),
+                cwd: scriptlet.cwd.clone(),
+                env: scriptlet.env.clone(),
+                confirm: scriptlet.confirm,
                 ..Default::default()
             },
             typed_metadata: None,
@@ -1875,6 +2953,15 @@ impl ScriptListApp {
                 icon: None,
                 alias: None,
                 shortcut: None,
+                args: None,
+                hidden: false,
+                sandbox: false,
+                sudo: false,
+                profile: None,
+                env_file: None,
+                cwd: None,
+                nice: None,
+                tags: Vec::new(),
                 typed_metadata: None,
                 schema: None,
             };
@@ -1960,6 +3047,13 @@ impl ScriptListApp {
     fn close_and_reset_window(&mut self, cx: &mut Context<Self>) {
         logging::log("VISIBILITY", "=== Close and reset window ===");
 
+        // Closing is an explicit action, so it also clears pinned mode -
+        // otherwise the window would re-open still pinned, which surprises
+        if self.window_pinned {
+            self.window_pinned = false;
+            platform::set_main_window_pinned(false);
+        }
+
         // Update visibility state FIRST to prevent race conditions
         script_kit_gpui::set_main_window_visible(false);
         logging::log("VISIBILITY", "WINDOW_VISIBLE set to: false");
@@ -2000,6 +3094,75 @@ impl ScriptListApp {
         logging::log("VISIBILITY", "=== Window closed ===");
     }
 
+    /// Toggle pinned mode for the main window (Cmd+Shift+P)
+    ///
+    /// Pinned windows float above other apps (status window level) and are
+    /// exempt from focus-lost auto-dismiss, which makes div/markdown output
+    /// usable as a reference panel while working in another app.
+    fn toggle_window_pin(&mut self, cx: &mut Context<Self>) {
+        self.window_pinned = !self.window_pinned;
+        platform::set_main_window_pinned(self.window_pinned);
+        let message = if self.window_pinned {
+            "Pinned - window stays on top (Cmd+Shift+P to unpin)"
+        } else {
+            "Unpinned"
+        };
+        self.toast_manager.push(
+            components::toast::Toast::success(message, &self.theme).duration_ms(Some(3000)),
+        );
+        logging::log(
+            "PANEL",
+            &format!("Window pinned: {}", self.window_pinned),
+        );
+        cx.notify();
+    }
+
+    /// Open the view a summon hotkey targets (the window is already shown
+    /// and focused by the poller before this is called)
+    ///
+    /// Summon hotkeys are configured in config.json as
+    /// `clipboardHistoryHotkey`, `fileSearchHotkey`, and
+    /// `rerunLastScriptHotkey` - see hotkeys::SummonTarget.
+    fn open_summon_target(&mut self, target: hotkeys::SummonTarget, cx: &mut Context<Self>) {
+        let builtin_id = match target {
+            hotkeys::SummonTarget::ClipboardHistory => "builtin-clipboard-history",
+            hotkeys::SummonTarget::FileSearch => "builtin-search-files",
+            hotkeys::SummonTarget::RerunLastScript => {
+                match self.last_run_script_path.clone() {
+                    Some(path) => {
+                        logging::log("HOTKEY", &format!("Re-running last script: {}", path));
+                        self.execute_script_by_path(&path, cx);
+                    }
+                    None => {
+                        self.toast_manager.push(
+                            components::toast::Toast::warning(
+                                "No script has been run yet",
+                                &self.theme,
+                            )
+                            .duration_ms(Some(3000)),
+                        );
+                        cx.notify();
+                    }
+                }
+                return;
+            }
+        };
+
+        match self.builtin_entries.iter().find(|e| e.id == builtin_id) {
+            Some(entry) => {
+                let entry = entry.clone();
+                self.execute_builtin(&entry, cx);
+            }
+            None => {
+                // Possible when the builtin is disabled in config
+                logging::log(
+                    "HOTKEY",
+                    &format!("Summon target {} not in builtin entries", builtin_id),
+                );
+            }
+        }
+    }
+
     /// Handle global keyboard shortcuts with configurable dismissability
     ///
     /// Returns `true` if the shortcut was handled (caller should return early)
@@ -2012,6 +3175,7 @@ impl ScriptListApp {
     ///
     /// # Handled shortcuts
     /// - Cmd+W: Always closes window and resets to default state
+    /// - Cmd+Shift+P: Toggles pinned mode (window stays on top, no auto-hide on blur)
     /// - Escape: Only closes window if `is_dismissable` is true AND actions popup is not showing
     fn handle_global_shortcut_with_options(
         &mut self,
@@ -2029,8 +3193,34 @@ impl ScriptListApp {
             return true;
         }
 
-        // ESC closes dismissable prompts (when actions popup is not showing)
+        // Cmd+[ goes back to the previous prompt when a script chains prompts
+        if has_cmd && key_str == "[" && self.pop_prompt_view(cx) {
+            logging::log("KEY", "Cmd+[ - back to previous prompt");
+            return true;
+        }
+
+        // Cmd+Z reverts the most recent destructive action while its undo
+        // window is open
+        if has_cmd && key_str == "z" && self.undo_last(cx) {
+            logging::log("KEY", "Cmd+Z - undid last destructive action");
+            return true;
+        }
+
+        // Cmd+Shift+P pins/unpins the window (stays on top, no auto-hide on blur)
+        if has_cmd && event.keystroke.modifiers.shift && key_str == "p" {
+            logging::log("KEY", "Cmd+Shift+P - toggling window pin");
+            self.toggle_window_pin(cx);
+            return true;
+        }
+
+        // ESC closes dismissable prompts (when actions popup is not showing).
+        // With chained prompts, ESC first navigates back through the prompt
+        // stack; only the first prompt cancels the script.
         if is_dismissable && key_str == "escape" && !self.show_actions_popup {
+            if self.pop_prompt_view(cx) {
+                logging::log("KEY", "ESC - back to previous prompt");
+                return true;
+            }
             logging::log("KEY", "ESC in dismissable prompt - closing window");
             self.close_and_reset_window(cx);
             return true;
@@ -2042,7 +3232,7 @@ impl ScriptListApp {
     /// Check if the current view is a dismissable prompt
     ///
     /// Dismissable prompts are those that feel "closeable" with escape:
-    /// - ArgPrompt, DivPrompt, FormPrompt, SelectPrompt, PathPrompt, EnvPrompt, DropPrompt, TemplatePrompt
+    /// - ArgPrompt, DivPrompt, FormPrompt, SelectPrompt, TablePrompt, PathPrompt, EnvPrompt, DropPrompt, TemplatePrompt
     /// - Built-in views (ClipboardHistory, AppLauncher, WindowSwitcher, DesignGallery)
     /// - ScriptList
     ///
@@ -2066,11 +3256,61 @@ impl ScriptListApp {
     /// Duration: 2000ms default, configurable
     /// Shape: Pill (40px tall, variable width)
     fn show_hud(&mut self, text: String, duration_ms: Option<u64>, cx: &mut Context<Self>) {
+        // During quiet hours (window hidden) the HUD queues into the
+        // "while you were away" digest shown on the next summon
+        if quiet_hours::should_suppress(&self.config) {
+            logging::log("HUD", &format!("Quiet hours - queued: {}", text));
+            quiet_hours::queue(&text);
+            return;
+        }
         // Delegate to the HUD manager which creates a separate floating window
         // This ensures the HUD is visible even when the main app window is hidden
         hud_manager::show_hud(text, duration_ms, cx);
     }
 
+    /// Surface the "while you were away" digest of notifications that were
+    /// suppressed during quiet hours. Called when the window is summoned;
+    /// a no-op when nothing was queued.
+    pub fn flush_quiet_digest(&mut self, cx: &mut Context<Self>) {
+        let entries = quiet_hours::drain();
+        if entries.is_empty() {
+            return;
+        }
+        logging::log(
+            "HUD",
+            &format!("Showing away digest ({} notifications)", entries.len()),
+        );
+        let toast = if entries.len() == 1 {
+            components::toast::Toast::info(
+                format!("While you were away: {}", entries[0].text),
+                &self.theme,
+            )
+            .duration_ms(Some(10_000))
+        } else {
+            // Sticky with per-entry timestamps in the details; the user
+            // dismisses it once they've caught up
+            let details = entries
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{} {}",
+                        entry.at.with_timezone(&chrono::Local).format("%H:%M"),
+                        entry.text
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            components::toast::Toast::info(
+                format!("While you were away: {} notifications", entries.len()),
+                &self.theme,
+            )
+            .details(details)
+            .duration_ms(None)
+        };
+        self.toast_manager.push(toast);
+        cx.notify();
+    }
+
     /// Show the debug grid overlay with specified options
     ///
     /// This method converts protocol::GridOptions to debug_grid::GridConfig
@@ -2159,14 +3399,32 @@ impl ScriptListApp {
             AppView::TermPrompt { .. } => "TermPrompt",
             AppView::FormPrompt { .. } => "FormPrompt",
             AppView::SelectPrompt { .. } => "SelectPrompt",
+            AppView::TablePrompt { .. } => "TablePrompt",
             AppView::PathPrompt { .. } => "PathPrompt",
             AppView::EnvPrompt { .. } => "EnvPrompt",
+            AppView::NumberPrompt { .. } => "NumberPrompt",
+            AppView::DatePrompt { .. } => "DatePrompt",
             AppView::DropPrompt { .. } => "DropPrompt",
             AppView::TemplatePrompt { .. } => "TemplatePrompt",
             AppView::ClipboardHistoryView { .. } => "ClipboardHistory",
             AppView::AppLauncherView { .. } => "AppLauncher",
             AppView::WindowSwitcherView { .. } => "WindowSwitcher",
+            AppView::BackgroundTasksView { .. } => "BackgroundTasks",
+            AppView::ShortcutsView { .. } => "Shortcuts",
+            AppView::RecentlyDeletedView { .. } => "Recently Deleted",
+            AppView::ThemeBrowserView { .. } => "Theme Browser",
+            AppView::ExpandStatsView { .. } => "Expansion Stats",
+            AppView::GitHubView { .. } => "GitHub",
+            AppView::ConnectView { .. } => "Connect",
+            AppView::FocusModesView { .. } => "Focus Modes",
+            AppView::WorldClockView { .. } => "World Clock",
+            AppView::FileSearchView { .. } => "Search Files",
+            AppView::BatchRenameView { .. } => "Batch Rename",
+            AppView::ScreenshotHistoryView { .. } => "Screenshot History",
+            AppView::FileMoverView { .. } => "Move Files",
+            AppView::SecretsVaultView { .. } => "Secrets Vault",
             AppView::DesignGalleryView { .. } => "DesignGallery",
+            AppView::ErrorView { .. } => "Script Error",
             AppView::ActionsDialog => "ActionsDialog",
         };
 
@@ -2346,7 +3604,7 @@ impl ScriptListApp {
                 );
             }
 
-            AppView::SelectPrompt { .. } | AppView::PathPrompt { .. } => {
+            AppView::SelectPrompt { .. } | AppView::TablePrompt { .. } | AppView::PathPrompt { .. } => {
                 // List-based prompts
                 let item_height = px(48.0);
                 bounds.push(
@@ -2620,14 +3878,32 @@ impl ScriptListApp {
             AppView::TermPrompt { .. } => "term",
             AppView::EditorPrompt { .. } => "editor",
             AppView::SelectPrompt { .. } => "select",
+            AppView::TablePrompt { .. } => "table",
             AppView::PathPrompt { .. } => "path",
             AppView::EnvPrompt { .. } => "env",
+            AppView::NumberPrompt { .. } => "number",
+            AppView::DatePrompt { .. } => "date",
             AppView::DropPrompt { .. } => "drop",
             AppView::TemplatePrompt { .. } => "template",
             AppView::ClipboardHistoryView { .. } => "clipboardHistory",
             AppView::AppLauncherView { .. } => "appLauncher",
             AppView::WindowSwitcherView { .. } => "windowSwitcher",
+            AppView::BackgroundTasksView { .. } => "backgroundTasks",
+            AppView::ShortcutsView { .. } => "shortcuts",
+            AppView::RecentlyDeletedView { .. } => "recentlyDeleted",
+            AppView::ThemeBrowserView { .. } => "themeBrowser",
+            AppView::ExpandStatsView { .. } => "expandStats",
+            AppView::GitHubView { .. } => "github",
+            AppView::ConnectView { .. } => "connect",
+            AppView::FocusModesView { .. } => "focusModes",
+            AppView::WorldClockView { .. } => "worldClock",
+            AppView::FileSearchView { .. } => "fileSearch",
+            AppView::BatchRenameView { .. } => "batchRename",
+            AppView::ScreenshotHistoryView { .. } => "screenshotHistory",
+            AppView::FileMoverView { .. } => "fileMover",
+            AppView::SecretsVaultView { .. } => "secretsVault",
             AppView::DesignGalleryView { .. } => "designGallery",
+            AppView::ErrorView { .. } => "scriptError",
             AppView::ActionsDialog => "actionsDialog",
         };
 
@@ -2919,14 +4195,32 @@ impl ScriptListApp {
             AppView::TermPrompt { .. } => "TermPrompt",
             AppView::EditorPrompt { .. } => "EditorPrompt",
             AppView::SelectPrompt { .. } => "SelectPrompt",
+            AppView::TablePrompt { .. } => "TablePrompt",
             AppView::PathPrompt { .. } => "PathPrompt",
             AppView::EnvPrompt { .. } => "EnvPrompt",
+            AppView::NumberPrompt { .. } => "NumberPrompt",
+            AppView::DatePrompt { .. } => "DatePrompt",
             AppView::DropPrompt { .. } => "DropPrompt",
             AppView::TemplatePrompt { .. } => "TemplatePrompt",
             AppView::ClipboardHistoryView { .. } => "ClipboardHistoryView",
             AppView::AppLauncherView { .. } => "AppLauncherView",
             AppView::WindowSwitcherView { .. } => "WindowSwitcherView",
+            AppView::BackgroundTasksView { .. } => "BackgroundTasksView",
+            AppView::ShortcutsView { .. } => "ShortcutsView",
+            AppView::RecentlyDeletedView { .. } => "RecentlyDeletedView",
+            AppView::ThemeBrowserView { .. } => "ThemeBrowserView",
+            AppView::ExpandStatsView { .. } => "ExpandStatsView",
+            AppView::GitHubView { .. } => "GitHubView",
+            AppView::ConnectView { .. } => "ConnectView",
+            AppView::FocusModesView { .. } => "FocusModesView",
+            AppView::WorldClockView { .. } => "WorldClockView",
+            AppView::FileSearchView { .. } => "FileSearchView",
+            AppView::BatchRenameView { .. } => "BatchRenameView",
+            AppView::ScreenshotHistoryView { .. } => "ScreenshotHistoryView",
+            AppView::FileMoverView { .. } => "FileMoverView",
+            AppView::SecretsVaultView { .. } => "SecretsVaultView",
             AppView::DesignGalleryView { .. } => "DesignGalleryView",
+            AppView::ErrorView { .. } => "ErrorView",
         };
 
         let old_focused_input = self.focused_input;
@@ -2953,8 +4247,9 @@ impl ScriptListApp {
             }
         }
 
-        // Reset view
+        // Reset view and drop any stacked prompts from the finished script
         self.current_view = AppView::ScriptList;
+        self.prompt_stack.clear();
 
         // CRITICAL: Reset focused_input to MainFilter so the cursor appears
         // This was a bug where focused_input could remain as ArgPrompt/None after
@@ -2995,6 +4290,12 @@ impl ScriptListApp {
         self.prompt_receiver = None;
         self.response_sender = None;
 
+        // Cancel the session token so the dispatch loop shuts the script down
+        // promptly even if it never writes another message
+        if let Some(token) = self.script_cancel_token.take() {
+            token.cancel();
+        }
+
         // Clear script session (parking_lot mutex never poisons)
         *self.script_session.lock() = None;
 
@@ -3029,10 +4330,88 @@ impl ScriptListApp {
                 | AppView::ClipboardHistoryView { .. }
                 | AppView::AppLauncherView { .. }
                 | AppView::WindowSwitcherView { .. }
+                | AppView::BackgroundTasksView { .. }
+                | AppView::ShortcutsView { .. }
+                | AppView::RecentlyDeletedView { .. }
+                | AppView::ThemeBrowserView { .. }
+                | AppView::ExpandStatsView { .. }
+                | AppView::GitHubView { .. }
+                | AppView::ConnectView { .. }
+                | AppView::FocusModesView { .. }
+                | AppView::WorldClockView { .. }
+                | AppView::FileSearchView { .. }
+                | AppView::BatchRenameView { .. }
+                | AppView::ScreenshotHistoryView { .. }
+                | AppView::FileMoverView { .. }
+                | AppView::SecretsVaultView { .. }
                 | AppView::DesignGalleryView { .. }
+                | AppView::ErrorView { .. }
+        )
+    }
+
+    /// Check if a view is a script-driven prompt (eligible for the prompt stack)
+    fn is_script_prompt(view: &AppView) -> bool {
+        matches!(
+            view,
+            AppView::ArgPrompt { .. }
+                | AppView::DivPrompt { .. }
+                | AppView::FormPrompt { .. }
+                | AppView::TermPrompt { .. }
+                | AppView::EditorPrompt { .. }
+                | AppView::SelectPrompt { .. }
+                | AppView::TablePrompt { .. }
+                | AppView::PathPrompt { .. }
+                | AppView::EnvPrompt { .. }
+                | AppView::NumberPrompt { .. }
+                | AppView::DatePrompt { .. }
+                | AppView::DropPrompt { .. }
+                | AppView::TemplatePrompt { .. }
         )
     }
 
+    /// Save the current prompt onto the prompt stack before a new prompt
+    /// replaces it. Entity-backed prompts keep their state inside the entity;
+    /// the shared arg input state is captured alongside the view.
+    fn push_prompt_to_stack(&mut self) {
+        if Self::is_script_prompt(&self.current_view) {
+            self.prompt_stack.push(PromptStackEntry {
+                view: self.current_view.clone(),
+                arg_input_text: self.arg_input.text().to_string(),
+                arg_selected_index: self.arg_selected_index,
+                focused_input: self.focused_input,
+            });
+            logging::log(
+                "UI",
+                &format!("Prompt stack: pushed (depth {})", self.prompt_stack.len()),
+            );
+        }
+    }
+
+    /// Go back to the previous prompt in the stack, restoring its state.
+    ///
+    /// Returns `true` if navigation happened. Returns `false` when the stack
+    /// is empty or the current view is not a script prompt - the caller falls
+    /// through to the normal Escape behavior (cancel the script).
+    fn pop_prompt_view(&mut self, cx: &mut Context<Self>) -> bool {
+        if !Self::is_script_prompt(&self.current_view) {
+            return false;
+        }
+        let Some(entry) = self.prompt_stack.pop() else {
+            return false;
+        };
+        logging::log(
+            "UI",
+            &format!("Prompt stack: back (depth {})", self.prompt_stack.len()),
+        );
+        self.current_view = entry.view;
+        self.arg_input.set_text(&entry.arg_input_text);
+        self.arg_selected_index = entry.arg_selected_index;
+        self.focused_input = entry.focused_input;
+        self.update_window_size();
+        cx.notify();
+        true
+    }
+
     /// Submit a response to the current prompt
     fn submit_prompt_response(
         &mut self,
@@ -3045,6 +4424,19 @@ impl ScriptListApp {
             &format!("Submitting response for {}: {:?}", id, value),
         );
 
+        // Remember submitted choices per prompt, keyed by the running
+        // script so the history survives regenerated prompt ids
+        if let Some(ref submitted) = value {
+            if !submitted.trim().is_empty() {
+                let key = self
+                    .last_run_script_path
+                    .clone()
+                    .unwrap_or_else(|| "prompt".to_string());
+                self.query_history.record(&key, submitted);
+                self.query_history.save().ok(); // Best-effort save
+            }
+        }
+
         let response = Message::Submit { id, value };
 
         if let Some(ref sender) = self.response_sender {
@@ -3065,17 +4457,28 @@ impl ScriptListApp {
     }
 
     /// Get filtered choices for arg prompt
+    ///
+    /// Fuzzy-matches against name and description; best matches sort first.
+    /// The stable sort preserves script-provided order among equal scores.
     fn filtered_arg_choices(&self) -> Vec<(usize, &Choice)> {
         if let AppView::ArgPrompt { choices, .. } = &self.current_view {
             if self.arg_input.is_empty() {
                 choices.iter().enumerate().collect()
             } else {
-                let filter = self.arg_input.text().to_lowercase();
-                choices
+                let filter = self.arg_input.text().to_string();
+                let mut scored: Vec<(i32, usize, &Choice)> = choices
                     .iter()
                     .enumerate()
-                    .filter(|(_, c)| c.name.to_lowercase().contains(&filter))
-                    .collect()
+                    .filter_map(|(i, c)| {
+                        scripts::fuzzy_match_choice(&c.name, c.description.as_deref(), &filter)
+                            .map(|(score, _, _)| (score, i, c))
+                    })
+                    .collect();
+                // `sort: false` prompts keep script-provided order (filter only)
+                if self.arg_sort_enabled {
+                    scored.sort_by(|a, b| b.0.cmp(&a.0));
+                }
+                scored.into_iter().map(|(_, i, c)| (i, c)).collect()
             }
         } else {
             vec![]
@@ -3083,25 +4486,57 @@ impl ScriptListApp {
     }
 
     /// P0: Get filtered choices as owned data for uniform_list closure
+    ///
+    /// Must produce the same ordering as filtered_arg_choices() so keyboard
+    /// navigation and rendering agree on which choice is selected.
     fn get_filtered_arg_choices_owned(&self) -> Vec<(usize, Choice)> {
-        if let AppView::ArgPrompt { choices, .. } = &self.current_view {
-            if self.arg_input.is_empty() {
-                choices
-                    .iter()
-                    .enumerate()
-                    .map(|(i, c)| (i, c.clone()))
-                    .collect()
-            } else {
-                let filter = self.arg_input.text().to_lowercase();
-                choices
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, c)| c.name.to_lowercase().contains(&filter))
-                    .map(|(i, c)| (i, c.clone()))
-                    .collect()
+        self.filtered_arg_choices()
+            .into_iter()
+            .map(|(i, c)| (i, c.clone()))
+            .collect()
+    }
+
+    /// Trailing argv tokens typed after a script name in the main filter.
+    ///
+    /// Active only when the search fell back to the first token (see
+    /// fuzzy_search_unified_all): if the full filter still fuzzy-matches the
+    /// script name, the user was multi-word searching, not passing args.
+    fn filter_args_for_script(&self, script_name: &str) -> Vec<String> {
+        let filter = &self.computed_filter_text;
+        let Some((_, args)) = scripts::split_query_args(filter) else {
+            return Vec::new();
+        };
+        if scripts::fuzzy_match_choice(script_name, None, filter.trim()).is_some() {
+            return Vec::new();
+        }
+        args
+    }
+
+    /// Request the next page of choices for a paginated arg prompt.
+    ///
+    /// Called after downward navigation; fires once the selection is within
+    /// 10 rows of the end of the filtered list. At most one moreChoices
+    /// request is outstanding at a time (reset when the page arrives).
+    fn maybe_request_more_choices(&mut self) {
+        if !self.arg_has_more || self.arg_more_requested {
+            return;
+        }
+        let (id, offset) = match &self.current_view {
+            AppView::ArgPrompt { id, choices, .. } => (id.clone(), choices.len()),
+            _ => return,
+        };
+        let filtered_len = self.filtered_arg_choices().len();
+        if self.arg_selected_index + 10 < filtered_len {
+            return;
+        }
+        if let Some(ref sender) = self.response_sender {
+            logging::log(
+                "UI",
+                &format!("Requesting more choices for {} (offset {})", id, offset),
+            );
+            if sender.send(Message::MoreChoices { id, offset }).is_ok() {
+                self.arg_more_requested = true;
             }
-        } else {
-            vec![]
         }
     }
 
@@ -3158,3 +4593,156 @@ impl ScriptListApp {
         }]
     }
 }
+
+// ============================================================================
+// OS Input-Method Integration (IME composition, dead keys)
+// ============================================================================
+
+impl ScriptListApp {
+    /// The hand-rolled text input the OS input handler currently edits.
+    /// Only the arg prompt routes through `TextInputState`; the main filter
+    /// is a gpui-component Input with its own input handler.
+    fn active_text_input(&mut self) -> Option<&mut TextInputState> {
+        match self.current_view {
+            AppView::ArgPrompt { .. } => Some(&mut self.arg_input),
+            _ => None,
+        }
+    }
+
+    /// Read-only access for protocol queries (`marked_text_range`)
+    fn active_text_input_ref(&self) -> Option<&TextInputState> {
+        match self.current_view {
+            AppView::ArgPrompt { .. } => Some(&self.arg_input),
+            _ => None,
+        }
+    }
+
+    /// After the input handler edits the arg input: re-filter from the top
+    /// and resize, mirroring what the key handler does for direct edits
+    fn on_ime_text_changed(&mut self, cx: &mut Context<Self>) {
+        self.arg_selected_index = 0;
+        self.update_window_size();
+        cx.notify();
+    }
+}
+
+/// Routes the OS text-input protocol to the arg prompt's `TextInputState`.
+///
+/// Registered during arg prompt paint via `ElementInputHandler` (see
+/// render_arg_prompt); without it, CJK IME composition and dead keys never
+/// reach the hand-rolled input - the OS has nowhere to put marked text.
+/// Offsets on this boundary are UTF-16 (the protocol's unit); conversion to
+/// character indices happens in `TextInputState`.
+impl gpui::EntityInputHandler for ScriptListApp {
+    fn text_for_range(
+        &mut self,
+        range_utf16: std::ops::Range<usize>,
+        adjusted_range: &mut Option<std::ops::Range<usize>>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<String> {
+        let input = self.active_text_input()?;
+        let start = input.utf16_to_char(range_utf16.start);
+        let end = input.utf16_to_char(range_utf16.end);
+        *adjusted_range = Some(input.char_to_utf16(start)..input.char_to_utf16(end));
+        Some(input.text_for_range((start, end)))
+    }
+
+    fn selected_text_range(
+        &mut self,
+        _ignore_disabled_input: bool,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<gpui::UTF16Selection> {
+        let input = self.active_text_input()?;
+        let selection = input.selection();
+        let (start, end) = selection.range();
+        Some(gpui::UTF16Selection {
+            range: input.char_to_utf16(start)..input.char_to_utf16(end),
+            reversed: selection.cursor < selection.anchor,
+        })
+    }
+
+    fn marked_text_range(
+        &self,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<std::ops::Range<usize>> {
+        let input = self.active_text_input_ref()?;
+        let (start, end) = input.marked_range()?;
+        Some(input.char_to_utf16(start)..input.char_to_utf16(end))
+    }
+
+    fn unmark_text(&mut self, _window: &mut Window, _cx: &mut Context<Self>) {
+        if let Some(input) = self.active_text_input() {
+            input.unmark();
+        }
+    }
+
+    fn replace_text_in_range(
+        &mut self,
+        range_utf16: Option<std::ops::Range<usize>>,
+        text: &str,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(input) = self.active_text_input() else {
+            return;
+        };
+        let range = range_utf16.map(|r| (input.utf16_to_char(r.start), input.utf16_to_char(r.end)));
+        input.replace_text_in_range(range, text);
+        self.on_ime_text_changed(cx);
+    }
+
+    fn replace_and_mark_text_in_range(
+        &mut self,
+        range_utf16: Option<std::ops::Range<usize>>,
+        new_text: &str,
+        new_selected_range_utf16: Option<std::ops::Range<usize>>,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(input) = self.active_text_input() else {
+            return;
+        };
+        let range = range_utf16.map(|r| (input.utf16_to_char(r.start), input.utf16_to_char(r.end)));
+        // The IME's requested selection is relative to `new_text`, so convert
+        // its UTF-16 offsets against that string rather than the input
+        let selected = new_selected_range_utf16.map(|r| {
+            let to_char = |offset: usize| {
+                let mut utf16 = 0;
+                for (ix, ch) in new_text.chars().enumerate() {
+                    if utf16 >= offset {
+                        return ix;
+                    }
+                    utf16 += ch.len_utf16();
+                }
+                new_text.chars().count()
+            };
+            (to_char(r.start), to_char(r.end))
+        });
+        input.replace_and_mark_text_in_range(range, new_text, selected);
+        self.on_ime_text_changed(cx);
+    }
+
+    fn bounds_for_range(
+        &mut self,
+        _range_utf16: std::ops::Range<usize>,
+        element_bounds: Bounds<Pixels>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<Bounds<Pixels>> {
+        // Anchor the IME candidate window to the prompt; per-character
+        // bounds aren't tracked for the hand-rolled inputs
+        Some(element_bounds)
+    }
+
+    fn character_index_for_point(
+        &mut self,
+        _point: gpui::Point<Pixels>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<usize> {
+        None
+    }
+}
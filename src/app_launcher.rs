@@ -215,6 +215,31 @@ pub fn get_cached_apps() -> Vec<AppInfo> {
         .unwrap_or_default()
 }
 
+/// Look up a cached app icon by bundle id (preferred) or display name
+///
+/// Used for clipboard source-app attribution. Cheap: clones one Arc from the
+/// in-memory cache without copying the whole app list. Returns None when the
+/// cache hasn't loaded yet or the app isn't installed.
+pub fn get_app_icon(bundle_id: Option<&str>, name: Option<&str>) -> Option<DecodedIcon> {
+    let cache = APP_CACHE.get()?;
+    let apps = cache.lock().ok()?;
+
+    if let Some(bundle_id) = bundle_id {
+        if let Some(app) = apps
+            .iter()
+            .find(|a| a.bundle_id.as_deref() == Some(bundle_id))
+        {
+            return app.icon.clone();
+        }
+    }
+    if let Some(name) = name {
+        if let Some(app) = apps.iter().find(|a| a.name.eq_ignore_ascii_case(name)) {
+            return app.icon.clone();
+        }
+    }
+    None
+}
+
 /// Get modification time for a path as Unix timestamp
 fn get_mtime(path: &Path) -> Option<i64> {
     path.metadata()
@@ -525,14 +550,18 @@ pub fn get_icon_cache_stats() -> (usize, u64) {
 /// Scan for installed macOS applications
 ///
 /// This function uses a two-phase loading strategy:
-/// 1. First, instantly load from SQLite cache (if available)
-/// 2. Then, scan directories in background to find new/changed apps
+/// 1. First, instantly load from SQLite cache (if available) or a fast
+///    names-and-paths directory scan (first run, no plist or icon work)
+/// 2. Then, scan directories in background to find new/changed apps,
+///    filling in bundle ids and icons in place as they are extracted
 ///
 /// # Returns
-/// A reference to the cached vector of AppInfo structs.
+/// A reference to the cached vector of AppInfo structs. On a first run the
+/// entries have names and paths only; poll `get_cached_apps()` while
+/// `is_apps_loading()` to pick up bundle ids and icons as they arrive.
 ///
 /// # Performance
-/// - First call: Returns SQLite-cached apps instantly, then background scans
+/// - First call: Returns cached or skeleton apps instantly, then background scans
 /// - Subsequent calls: Returns immediately from in-memory cache
 pub fn scan_applications() -> Vec<AppInfo> {
     // Initialize the cache if needed
@@ -553,24 +582,20 @@ pub fn scan_applications() -> Vec<AppInfo> {
             );
 
             // Start background scan for updates
-            let cache_arc = Arc::new(Mutex::new(cached_apps.clone()));
+            let cache_arc = Arc::new(Mutex::new(cached_apps));
             let cache_for_thread = Arc::clone(&cache_arc);
 
             std::thread::spawn(move || {
                 set_loading_state(AppLoadingState::ScanningDirectories);
 
                 let scan_start = Instant::now();
-                let fresh_apps = scan_all_directories_with_db_update();
+                scan_all_directories_incremental(&cache_for_thread);
                 let scan_duration = scan_start.elapsed().as_millis();
 
-                // Update the in-memory cache
-                if let Ok(mut guard) = cache_for_thread.lock() {
-                    *guard = fresh_apps.clone();
-                }
-
+                let app_count = cache_for_thread.lock().map(|g| g.len()).unwrap_or(0);
                 let (db_count, db_size) = get_apps_db_stats();
                 info!(
-                    app_count = fresh_apps.len(),
+                    app_count = app_count,
                     duration_ms = scan_duration,
                     db_apps = db_count,
                     db_icon_size_kb = db_size / 1024,
@@ -580,27 +605,41 @@ pub fn scan_applications() -> Vec<AppInfo> {
                 set_loading_state(AppLoadingState::Ready);
             });
 
-            return Arc::new(Mutex::new(cached_apps));
+            return cache_arc;
         }
 
-        // No SQLite cache - do a full synchronous scan
+        // No SQLite cache - publish names and paths immediately and fill in
+        // bundle ids and icons in the background (the old synchronous scan
+        // blocked first use for seconds while it shelled out per app)
         set_loading_state(AppLoadingState::ScanningDirectories);
 
-        let apps = scan_all_directories_with_db_update();
-        let duration_ms = start.elapsed().as_millis();
-
-        let (db_count, db_size) = get_apps_db_stats();
+        let apps = scan_directories_fast();
         info!(
             app_count = apps.len(),
-            duration_ms = duration_ms,
-            db_apps = db_count,
-            db_icon_size_kb = db_size / 1024,
-            "Scanned applications (no cache)"
+            duration_ms = start.elapsed().as_millis(),
+            "Fast app scan complete, enriching in background (no cache)"
         );
 
-        set_loading_state(AppLoadingState::Ready);
+        let cache_arc = Arc::new(Mutex::new(apps));
+        let cache_for_thread = Arc::clone(&cache_arc);
+
+        std::thread::spawn(move || {
+            let scan_start = Instant::now();
+            scan_all_directories_incremental(&cache_for_thread);
+            let scan_duration = scan_start.elapsed().as_millis();
 
-        Arc::new(Mutex::new(apps))
+            let (db_count, db_size) = get_apps_db_stats();
+            info!(
+                duration_ms = scan_duration,
+                db_apps = db_count,
+                db_icon_size_kb = db_size / 1024,
+                "Background app enrichment complete"
+            );
+
+            set_loading_state(AppLoadingState::Ready);
+        });
+
+        cache_arc
     });
 
     // Return a clone of the cached apps
@@ -626,6 +665,104 @@ pub fn scan_applications_fresh() -> Vec<AppInfo> {
     apps
 }
 
+/// Scan all configured directories for bundle names and paths only
+///
+/// This is the cheap part of the scan - a handful of read_dirs, no plist
+/// parsing and no icon extraction. Bundle ids and icons are filled in
+/// afterwards by `scan_all_directories_incremental`.
+fn scan_directories_fast() -> Vec<AppInfo> {
+    let mut apps = Vec::new();
+
+    for dir in APP_DIRECTORIES {
+        let expanded = shellexpand::tilde(dir);
+        let path = Path::new(expanded.as_ref());
+
+        if !path.exists() {
+            debug!(directory = %path.display(), "Directory does not exist, skipping");
+            continue;
+        }
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(
+                    directory = %path.display(),
+                    error = %e,
+                    "Failed to scan directory"
+                );
+                continue;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "app") {
+                if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                    apps.push(AppInfo {
+                        name: name.to_string(),
+                        path,
+                        bundle_id: None,
+                        icon: None,
+                    });
+                }
+            }
+        }
+    }
+
+    // Sort by name for consistent ordering
+    apps.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+
+    // Remove duplicates (same name from different directories - prefer first)
+    apps.dedup_by(|a, b| a.name.to_lowercase() == b.name.to_lowercase());
+
+    apps
+}
+
+/// Rescan incrementally: publish names and paths immediately, then fill in
+/// bundle ids and icons app by app, updating the shared cache in place
+///
+/// The skeleton list is sorted before it is published and enrichment only
+/// ever updates existing entries (never inserts or removes), so the visible
+/// order never reshuffles mid-scan. Bundle ids and icons already known from
+/// SQLite or a previous scan are carried over so existing rows don't flash
+/// back to placeholders.
+fn scan_all_directories_incremental(cache: &Arc<Mutex<Vec<AppInfo>>>) {
+    let mut skeleton = scan_directories_fast();
+
+    if let Ok(existing) = cache.lock() {
+        for app in skeleton.iter_mut() {
+            if let Some(prev) = existing.iter().find(|a| a.path == app.path) {
+                app.bundle_id = prev.bundle_id.clone();
+                app.icon = prev.icon.clone();
+            }
+        }
+    }
+
+    let paths: Vec<PathBuf> = skeleton.iter().map(|a| a.path.clone()).collect();
+
+    // Publish the skeleton: names, paths, and ordering are final from here
+    if let Ok(mut guard) = cache.lock() {
+        *guard = skeleton;
+    }
+
+    // Enrich one app at a time, updating the published entry in place
+    // (the lock is held only for the in-place field updates, not the
+    // plist parsing or icon extraction)
+    for path in paths {
+        if let Some((app_info, icon_bytes)) = parse_app_bundle_with_icon(&path) {
+            let mtime = get_mtime(&path).unwrap_or(0);
+            save_app_to_db(&app_info, icon_bytes.as_deref(), mtime);
+
+            if let Ok(mut guard) = cache.lock() {
+                if let Some(entry) = guard.iter_mut().find(|a| a.path == path) {
+                    entry.bundle_id = app_info.bundle_id;
+                    entry.icon = app_info.icon;
+                }
+            }
+        }
+    }
+}
+
 /// Scan all configured directories for applications and update SQLite
 fn scan_all_directories_with_db_update() -> Vec<AppInfo> {
     let mut apps = Vec::new();
@@ -822,7 +959,10 @@ fn parse_app_bundle(path: &Path) -> Option<AppInfo> {
 
 /// Extract CFBundleIdentifier from Info.plist
 ///
-/// Uses /usr/libexec/PlistBuddy for reliable plist parsing.
+/// Parses the plist in-process with the `plist` crate (handles both binary
+/// and XML forms). The old implementation shelled out to
+/// /usr/libexec/PlistBuddy per app, which meant hundreds of process spawns
+/// during the first scan.
 fn extract_bundle_id(app_path: &Path) -> Option<String> {
     let plist_path = app_path.join("Contents/Info.plist");
 
@@ -830,20 +970,24 @@ fn extract_bundle_id(app_path: &Path) -> Option<String> {
         return None;
     }
 
-    // Use PlistBuddy to extract CFBundleIdentifier (reliable and fast)
-    let output = Command::new("/usr/libexec/PlistBuddy")
-        .args(["-c", "Print :CFBundleIdentifier", plist_path.to_str()?])
-        .output()
-        .ok()?;
-
-    if output.status.success() {
-        let bundle_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if !bundle_id.is_empty() {
-            return Some(bundle_id);
+    let value = match plist::Value::from_file(&plist_path) {
+        Ok(value) => value,
+        Err(e) => {
+            debug!(
+                plist = %plist_path.display(),
+                error = %e,
+                "Failed to parse Info.plist"
+            );
+            return None;
         }
-    }
+    };
 
-    None
+    value
+        .as_dictionary()
+        .and_then(|dict| dict.get("CFBundleIdentifier"))
+        .and_then(|id| id.as_string())
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
 }
 
 /// Extract application icon using NSWorkspace
@@ -970,9 +1114,23 @@ pub fn launch_application_by_name(name: &str) -> Result<()> {
 mod tests {
     use super::*;
 
+    /// Kick off a scan and wait for background enrichment to finish
+    ///
+    /// On a first run `scan_applications` returns a names-and-paths skeleton
+    /// while bundle ids and icons load in the background; tests that assert
+    /// on those fields need the settled list.
+    fn scan_and_wait_ready() -> Vec<AppInfo> {
+        let _ = scan_applications();
+        let deadline = Instant::now() + std::time::Duration::from_secs(60);
+        while is_apps_loading() && Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        get_cached_apps()
+    }
+
     #[test]
     fn test_scan_applications_returns_apps() {
-        let apps = scan_applications();
+        let apps = scan_and_wait_ready();
 
         // Should find at least some apps on any macOS system
         assert!(
@@ -1052,6 +1210,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_bundle_id_xml_plist() {
+        // Build a fake .app bundle with an XML Info.plist; exercises the
+        // in-process plist parsing without depending on installed apps
+        let dir = std::env::temp_dir().join(format!("sk-plist-test-{}", std::process::id()));
+        let app_dir = dir.join("Fake.app");
+        let contents = app_dir.join("Contents");
+        std::fs::create_dir_all(&contents).expect("create test bundle");
+        std::fs::write(
+            contents.join("Info.plist"),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleIdentifier</key>
+    <string>com.example.fake</string>
+    <key>CFBundleName</key>
+    <string>Fake</string>
+</dict>
+</plist>
+"#,
+        )
+        .expect("write test plist");
+
+        let bundle_id = extract_bundle_id(&app_dir);
+        assert_eq!(bundle_id, Some("com.example.fake".to_string()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_extract_bundle_id_nonexistent() {
         let fake_path = Path::new("/nonexistent/Fake.app");
@@ -1076,6 +1264,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scan_directories_fast_names_and_order() {
+        let apps = scan_directories_fast();
+
+        // Skeleton entries carry names and paths only
+        for app in apps.iter() {
+            assert!(!app.name.is_empty(), "Skeleton app name should not be empty");
+            assert!(
+                app.path.extension().map(|e| e == "app").unwrap_or(false),
+                "Skeleton path should end with .app: {:?}",
+                app.path
+            );
+        }
+
+        // Already sorted so enrichment never reshuffles the published order
+        for window in apps.windows(2) {
+            assert!(
+                window[0].name.to_lowercase() <= window[1].name.to_lowercase(),
+                "Skeleton should be sorted: {} before {}",
+                window[0].name,
+                window[1].name
+            );
+        }
+    }
+
     #[test]
     fn test_no_duplicate_apps() {
         let apps = scan_applications();
@@ -1122,7 +1335,7 @@ mod tests {
 
     #[test]
     fn test_app_has_icon() {
-        let apps = scan_applications();
+        let apps = scan_and_wait_ready();
 
         // Check that at least some apps have icons (most should)
         let apps_with_icons = apps.iter().filter(|a| a.icon.is_some()).count();
@@ -15,9 +15,10 @@ impl ScriptListApp {
         let grouped_items = grouped_items.clone();
 
         // Find the first selectable (non-header) item index
+        // "Show more…" rows are selectable so Enter can expand a section
         let first_selectable = grouped_items
             .iter()
-            .position(|item| matches!(item, GroupedListItem::Item(_)));
+            .position(|item| !matches!(item, GroupedListItem::SectionHeader(_)));
 
         // If already at or before first selectable, can't go further up
         if let Some(first) = first_selectable {
@@ -48,6 +49,7 @@ impl ScriptListApp {
             self.selected_index = new_index;
             self.scroll_to_selected_if_needed("keyboard_up");
             self.trigger_scroll_activity(cx);
+            self.announce_selected_result();
             cx.notify();
         }
     }
@@ -66,9 +68,10 @@ impl ScriptListApp {
         let item_count = grouped_items.len();
 
         // Find the last selectable (non-header) item index
+        // "Show more…" rows are selectable so Enter can expand a section
         let last_selectable = grouped_items
             .iter()
-            .rposition(|item| matches!(item, GroupedListItem::Item(_)));
+            .rposition(|item| !matches!(item, GroupedListItem::SectionHeader(_)));
 
         // If already at or after last selectable, can't go further down
         if let Some(last) = last_selectable {
@@ -99,6 +102,7 @@ impl ScriptListApp {
             self.selected_index = new_index;
             self.scroll_to_selected_if_needed("keyboard_down");
             self.trigger_scroll_activity(cx);
+            self.announce_selected_result();
             cx.notify();
         }
     }
@@ -155,6 +159,52 @@ impl ScriptListApp {
         cx.notify();
     }
 
+    /// Scroll the main list so the given track ratio becomes the scroll position
+    ///
+    /// `ratio` is 0.0 at the top of the scrollbar track and 1.0 at the bottom.
+    /// Used by scrollbar click-to-jump and thumb dragging. Walks the grouped
+    /// items with their real heights (24px headers, 48px items) so the target
+    /// maps to an exact `ListOffset` rather than an approximated index.
+    fn scroll_main_list_to_ratio(&mut self, ratio: f32, cx: &mut Context<Self>) {
+        let (grouped_items, _) = self.get_grouped_results_cached();
+        let container_height: f32 = match self.list_bounds {
+            Some(bounds) => bounds.size.height.into(),
+            None => return, // Nothing painted yet, nothing to scroll
+        };
+
+        let item_height = |item: &GroupedListItem| match item {
+            GroupedListItem::SectionHeader(_) => SECTION_HEADER_HEIGHT,
+            GroupedListItem::Item(_) | GroupedListItem::ShowMore { .. } => LIST_ITEM_HEIGHT,
+        };
+        let total_content_height: f32 = grouped_items.iter().map(item_height).sum();
+        let max_offset = (total_content_height - container_height).max(0.0);
+        if max_offset <= 0.0 {
+            return; // Content fits, scrollbar is hidden anyway
+        }
+
+        // Find the item containing the target pixel and the offset into it
+        let target_px = ratio.clamp(0.0, 1.0) * max_offset;
+        let mut remaining = target_px;
+        let mut item_ix = 0;
+        for item in grouped_items.iter() {
+            let height = item_height(item);
+            if remaining < height {
+                break;
+            }
+            remaining -= height;
+            item_ix += 1;
+        }
+
+        self.main_list_state.scroll_to(gpui::ListOffset {
+            item_ix,
+            offset_in_item: px(remaining),
+        });
+        // Manual scroll invalidates the keyboard scroll-stabilization cache
+        self.last_scrolled_index = None;
+        self.trigger_scroll_activity(cx);
+        cx.notify();
+    }
+
     /// Apply a coalesced navigation delta in the given direction
     fn apply_nav_delta(&mut self, dir: NavDirection, delta: i32, cx: &mut Context<Self>) {
         let signed = match dir {
@@ -190,10 +240,10 @@ impl ScriptListApp {
         // Find bounds for selectable items (non-headers)
         let first_selectable = grouped_items
             .iter()
-            .position(|item| matches!(item, GroupedListItem::Item(_)));
+            .position(|item| !matches!(item, GroupedListItem::SectionHeader(_)));
         let last_selectable = grouped_items
             .iter()
-            .rposition(|item| matches!(item, GroupedListItem::Item(_)));
+            .rposition(|item| !matches!(item, GroupedListItem::SectionHeader(_)));
 
         // If no selectable items, nothing to do
         let (first, last) = match (first_selectable, last_selectable) {
@@ -210,7 +260,10 @@ impl ScriptListApp {
             // Moving down - find next non-header at or after target
             let mut idx = target;
             while idx <= last {
-                if matches!(grouped_items.get(idx), Some(GroupedListItem::Item(_))) {
+                if !matches!(
+                    grouped_items.get(idx),
+                    Some(GroupedListItem::SectionHeader(_))
+                ) {
                     break;
                 }
                 idx += 1;
@@ -220,7 +273,10 @@ impl ScriptListApp {
             // Moving up - find next non-header at or before target
             let mut idx = target;
             while idx >= first {
-                if matches!(grouped_items.get(idx), Some(GroupedListItem::Item(_))) {
+                if !matches!(
+                    grouped_items.get(idx),
+                    Some(GroupedListItem::SectionHeader(_))
+                ) {
                     break;
                 }
                 if idx == 0 {
@@ -244,6 +300,7 @@ impl ScriptListApp {
             self.selected_index = new_index;
             self.scroll_to_selected_if_needed("coalesced_nav");
             self.trigger_scroll_activity(cx);
+            self.announce_selected_result();
             cx.notify();
         }
     }
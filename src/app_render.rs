@@ -46,7 +46,7 @@ impl ScriptListApp {
         };
 
         // Use design tokens for GLOBAL theming - design applies to ALL components
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let colors = tokens.colors();
         let spacing = tokens.spacing();
         let typography = tokens.typography();
@@ -155,6 +155,151 @@ impl ScriptListApp {
                             );
                         }
 
+                        // Usage hint for scripts declaring positional args
+                        // (type "name arg1 arg2" in the filter to pass them)
+                        if let Some(arg_names) = &script.args {
+                            if !arg_names.is_empty() {
+                                let usage = arg_names
+                                    .iter()
+                                    .map(|a| format!("<{}>", a))
+                                    .collect::<Vec<_>>()
+                                    .join(" ");
+                                panel = panel.child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .pb(px(spacing.padding_md))
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(rgb(text_muted))
+                                                .pb(px(spacing.padding_xs / 2.0))
+                                                .child("Arguments"),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .font_family(typography.font_family_mono)
+                                                .text_color(rgb(text_secondary))
+                                                .child(usage),
+                                        ),
+                                );
+                            }
+                        }
+
+                        // Tag chips (filter with "#tag" to show only that tag)
+                        if !script.tags.is_empty() {
+                            let mut chips = div()
+                                .flex()
+                                .flex_row()
+                                .flex_wrap()
+                                .gap(px(spacing.padding_xs));
+                            for tag in &script.tags {
+                                chips = chips.child(
+                                    div()
+                                        .px(px(spacing.padding_sm))
+                                        .py(px(spacing.padding_xs / 2.0))
+                                        .rounded(px(border_radius))
+                                        .bg(rgba((bg_search_box << 8) | 0x80))
+                                        .text_xs()
+                                        .text_color(rgb(text_secondary))
+                                        .child(format!("#{}", tag)),
+                                );
+                            }
+                            panel = panel.child(
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .pb(px(spacing.padding_md))
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(rgb(text_muted))
+                                            .pb(px(spacing.padding_xs / 2.0))
+                                            .child("Tags"),
+                                    )
+                                    .child(chips),
+                            );
+                        }
+
+                        // Sandbox badge (`// Sandbox: true` runs with no
+                        // network and a minimal environment)
+                        if script.sandbox {
+                            let warning = colors.warning;
+                            panel = panel.child(
+                                div()
+                                    .flex()
+                                    .flex_row()
+                                    .pb(px(spacing.padding_md))
+                                    .child(
+                                        div()
+                                            .px(px(spacing.padding_sm))
+                                            .py(px(spacing.padding_xs / 2.0))
+                                            .rounded(px(border_radius))
+                                            .bg(rgba((warning << 8) | 0x30))
+                                            .text_xs()
+                                            .text_color(rgb(warning))
+                                            .child("Sandboxed \u{2013} no network"),
+                                    ),
+                            );
+                        }
+
+                        // Sudo badge (`// Sudo: true` prompts for
+                        // administrator authorization at launch)
+                        if script.sudo {
+                            let error = colors.error;
+                            panel = panel.child(
+                                div()
+                                    .flex()
+                                    .flex_row()
+                                    .pb(px(spacing.padding_md))
+                                    .child(
+                                        div()
+                                            .px(px(spacing.padding_sm))
+                                            .py(px(spacing.padding_xs / 2.0))
+                                            .rounded(px(border_radius))
+                                            .bg(rgba((error << 8) | 0x30))
+                                            .text_xs()
+                                            .text_color(rgb(error))
+                                            .child("\u{1F6E1}\u{FE0F} Sudo \u{2013} administrator privileges"),
+                                    ),
+                            );
+                        }
+
+                        // Environment section (`// EnvFile: ./.env` injects
+                        // dotenv vars; config.json `env` and profile vars
+                        // override them on collisions)
+                        if let Some(ref env_file) = script.env_file {
+                            panel = panel.child(
+                                div()
+                                    .flex()
+                                    .flex_col()
+                                    .pb(px(spacing.padding_md))
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(rgb(text_muted))
+                                            .pb(px(spacing.padding_xs / 2.0))
+                                            .child("Environment"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(rgb(text_secondary))
+                                            .child(format!("\u{1F4C4} {}", env_file)),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(rgb(text_muted))
+                                            .pt(px(spacing.padding_xs / 2.0))
+                                            .child(
+                                                "Supports ${VAR} interpolation; overridden by config.json env and profile vars",
+                                            ),
+                                    ),
+                            );
+                        }
+
                         // Divider
                         panel = panel.child(
                             div()
@@ -191,12 +336,16 @@ impl ScriptListApp {
                             .flex()
                             .flex_col();
 
-                        // Render each line as a row of spans with monospace font
+                        // Render each line as a row of spans with monospace font.
+                        // With previewSoftWrap enabled, spans flow onto extra
+                        // visual rows instead of being clipped at the panel edge.
+                        let soft_wrap = self.config.get_preview_soft_wrap();
                         for line in lines {
                             let mut line_div = div()
                                 .flex()
                                 .flex_row()
                                 .w_full()
+                                .when(soft_wrap, |d| d.flex_wrap())
                                 .font_family(typography.font_family_mono)
                                 .text_xs()
                                 .min_h(px(spacing.padding_lg)); // Line height
@@ -359,12 +508,16 @@ impl ScriptListApp {
                             .flex()
                             .flex_col();
 
-                        // Render each line as a row of spans with monospace font
+                        // Render each line as a row of spans with monospace font.
+                        // With previewSoftWrap enabled, spans flow onto extra
+                        // visual rows instead of being clipped at the panel edge.
+                        let soft_wrap = self.config.get_preview_soft_wrap();
                         for line in lines {
                             let mut line_div = div()
                                 .flex()
                                 .flex_row()
                                 .w_full()
+                                .when(soft_wrap, |d| d.flex_wrap())
                                 .font_family(typography.font_family_mono)
                                 .text_xs()
                                 .min_h(px(spacing.padding_lg)); // Line height
@@ -462,6 +615,14 @@ impl ScriptListApp {
                             builtins::BuiltInFeature::WindowSwitcher => {
                                 "Window Manager".to_string()
                             }
+                            builtins::BuiltInFeature::BackgroundTasks => {
+                                "Background Tasks".to_string()
+                            }
+                            builtins::BuiltInFeature::OcrClipboard => {
+                                "Text Recognition".to_string()
+                            }
+                            builtins::BuiltInFeature::Shortcuts => "Shortcuts".to_string(),
+                            builtins::BuiltInFeature::ImportMigration => "Import".to_string(),
                             builtins::BuiltInFeature::DesignGallery => "Design Gallery".to_string(),
                             builtins::BuiltInFeature::AiChat => "AI Assistant".to_string(),
                             builtins::BuiltInFeature::Notes => "Notes & Scratchpad".to_string(),
@@ -484,6 +645,17 @@ impl ScriptListApp {
                             builtins::BuiltInFeature::PermissionCommand(_) => {
                                 "Permission Management".to_string()
                             }
+                            builtins::BuiltInFeature::UpdateCommand(_) => {
+                                "App Updates".to_string()
+                            }
+                            builtins::BuiltInFeature::SettingsCommand(_) => {
+                                "Settings".to_string()
+                            }
+                            builtins::BuiltInFeature::Fallback(_) => "Fallback".to_string(),
+                            builtins::BuiltInFeature::ShellCommand(_) => "Shell".to_string(),
+                            builtins::BuiltInFeature::ClipboardPaste(_) => "Clipboard".to_string(),
+                            builtins::BuiltInFeature::TagsBrowser => "Tags".to_string(),
+                            builtins::BuiltInFeature::TagFilter(_) => "Tag".to_string(),
                         };
                         panel = panel.child(
                             div()
@@ -725,6 +897,8 @@ impl ScriptListApp {
 
         if let Some(idx) = result_idx {
             if let Some(result) = flat_results.get(idx) {
+                let pinned = scripts::result_key(result)
+                    .is_some_and(|key| self.pin_store.is_pinned(&key));
                 match result {
                     scripts::SearchResult::Script(m) => Some(ScriptInfo::new(
                         &m.script.name,
@@ -759,6 +933,7 @@ impl ScriptListApp {
                         ))
                     }
                 }
+                .map(|info| info.with_pinned(pinned))
             } else {
                 None
             }
@@ -769,7 +944,7 @@ impl ScriptListApp {
 
     fn render_actions_dialog(&mut self, cx: &mut Context<Self>) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
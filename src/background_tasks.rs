@@ -0,0 +1,358 @@
+//! Background Task Registry
+//!
+//! Scripts marked with `// Background: true` (or `background: true` in typed
+//! metadata) run without showing the main window — they only surface toasts,
+//! HUDs, and notifications. While such a script runs it is registered here so
+//! the "Background Tasks" builtin can list running tasks and stop them.
+//!
+//! The registry is a process-wide singleton because tasks are registered from
+//! the script reader threads and read from the UI thread.
+
+use crate::process_manager::PROCESS_MANAGER;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+/// A running background script tracked by the registry
+#[derive(Debug, Clone)]
+pub struct BackgroundTask {
+    /// PID of the script process (used to stop it)
+    pub pid: u32,
+    /// Display name of the script
+    pub name: String,
+    /// Path to the script file
+    pub path: PathBuf,
+    /// When the task started
+    pub started_at: DateTime<Utc>,
+}
+
+impl BackgroundTask {
+    /// Human-readable elapsed time since the task started (e.g. "2m 5s")
+    pub fn elapsed_display(&self) -> String {
+        let secs = (Utc::now() - self.started_at).num_seconds().max(0);
+        if secs < 60 {
+            format!("{}s", secs)
+        } else if secs < 3600 {
+            format!("{}m {}s", secs / 60, secs % 60)
+        } else {
+            format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+        }
+    }
+}
+
+/// Global registry of running background tasks
+static BACKGROUND_TASKS: Mutex<Vec<BackgroundTask>> = Mutex::new(Vec::new());
+
+/// Register a background task when its script process starts
+pub fn register(pid: u32, name: &str, path: &std::path::Path) {
+    if let Ok(mut tasks) = BACKGROUND_TASKS.lock() {
+        // Defensive: a PID should never be registered twice, but if a stale
+        // entry exists (e.g. PID reuse), replace it rather than duplicating.
+        tasks.retain(|t| t.pid != pid);
+        // A fresh launch supersedes any stale retry-failure entry
+        clear_failure(path);
+        tasks.push(BackgroundTask {
+            pid,
+            name: name.to_string(),
+            path: path.to_path_buf(),
+            started_at: Utc::now(),
+        });
+    }
+}
+
+/// Remove a task from the registry when its process exits.
+/// Returns true if a task with this PID was registered.
+pub fn unregister(pid: u32) -> bool {
+    if let Ok(mut tasks) = BACKGROUND_TASKS.lock() {
+        let before = tasks.len();
+        tasks.retain(|t| t.pid != pid);
+        tasks.len() != before
+    } else {
+        false
+    }
+}
+
+/// Snapshot of all currently running background tasks
+pub fn list() -> Vec<BackgroundTask> {
+    BACKGROUND_TASKS
+        .lock()
+        .map(|tasks| tasks.clone())
+        .unwrap_or_default()
+}
+
+/// Number of currently running background tasks
+pub fn count() -> usize {
+    BACKGROUND_TASKS.lock().map(|t| t.len()).unwrap_or(0)
+}
+
+/// Shared sysinfo state for usage sampling
+///
+/// Kept alive across calls because CPU percentages are computed from the
+/// delta between two refreshes; a fresh `System` would always report 0.
+static USAGE_SYSTEM: LazyLock<Mutex<System>> = LazyLock::new(|| Mutex::new(System::new()));
+
+/// Current resource usage for the given PIDs: (CPU %, memory bytes)
+///
+/// PIDs whose process has already exited are absent from the result. The
+/// first sample after startup reports 0% CPU (no delta yet).
+pub fn usage_for(pids: &[u32]) -> HashMap<u32, (f32, u64)> {
+    let Ok(mut system) = USAGE_SYSTEM.lock() else {
+        return HashMap::new();
+    };
+    let sysinfo_pids: Vec<Pid> = pids.iter().map(|&p| Pid::from_u32(p)).collect();
+    system.refresh_processes(ProcessesToUpdate::Some(&sysinfo_pids), true);
+    pids.iter()
+        .filter_map(|&pid| {
+            system
+                .process(Pid::from_u32(pid))
+                .map(|process| (pid, (process.cpu_usage(), process.memory())))
+        })
+        .collect()
+}
+
+/// Human-readable memory size for the tasks list (e.g. "34 MB")
+pub fn format_memory(bytes: u64) -> String {
+    if bytes < 1024 * 1024 {
+        format!("{} KB", bytes / 1024)
+    } else if bytes < 1024 * 1024 * 1024 {
+        format!("{} MB", bytes / (1024 * 1024))
+    } else {
+        format!("{:.1} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
+/// Maximum automatic restarts per script within `RESTART_WINDOW_SECS`
+const MAX_RESTARTS: u32 = 3;
+
+/// Restart attempts older than this start a fresh window (crash loop guard)
+const RESTART_WINDOW_SECS: i64 = 60;
+
+/// Restart attempt counters per script path
+static RESTART_ATTEMPTS: Mutex<Vec<(PathBuf, u32, DateTime<Utc>)>> = Mutex::new(Vec::new());
+
+/// Record a restart attempt for a crashed `// Restart: true` script.
+///
+/// Returns `Some(attempt_number)` when the restart is allowed, or `None` once
+/// the script has used up `MAX_RESTARTS` within the window - that stops a
+/// crash-looping script from restarting forever. A quiet period longer than
+/// the window resets the counter.
+pub fn note_restart(path: &std::path::Path) -> Option<u32> {
+    note_attempt(path, MAX_RESTARTS)
+}
+
+/// Record a retry attempt for a `// Retries: N` script that exited non-zero.
+///
+/// Same bookkeeping as [`note_restart`] but with the script's own budget:
+/// returns `Some(attempt_number)` while retries remain, `None` once the
+/// budget is spent. A quiet period longer than the window resets the counter
+/// so the next scheduled run starts fresh.
+pub fn note_retry(path: &std::path::Path, max_retries: u32) -> Option<u32> {
+    note_attempt(path, max_retries)
+}
+
+/// Shared attempt counting for restarts and retries (windowed per path)
+fn note_attempt(path: &std::path::Path, max: u32) -> Option<u32> {
+    if max == 0 {
+        return None;
+    }
+    let mut attempts = RESTART_ATTEMPTS.lock().ok()?;
+    let now = Utc::now();
+    match attempts.iter_mut().find(|(p, _, _)| p == path) {
+        Some(entry) => {
+            if (now - entry.2).num_seconds() > RESTART_WINDOW_SECS {
+                entry.1 = 0;
+            }
+            if entry.1 >= max {
+                return None;
+            }
+            entry.1 += 1;
+            entry.2 = now;
+            Some(entry.1)
+        }
+        None => {
+            attempts.push((path.to_path_buf(), 1, now));
+            Some(1)
+        }
+    }
+}
+
+/// Base delay before the first retry; doubles per attempt
+const RETRY_BASE_DELAY_MS: u64 = 1_000;
+
+/// Backoff ceiling. Deliberately shorter than `RESTART_WINDOW_SECS` - a
+/// delay longer than the window would reset the attempt counter and let a
+/// script retry forever.
+const RETRY_MAX_DELAY_MS: u64 = 30_000;
+
+/// Exponential backoff delay before retry `attempt` (1-based):
+/// 1s, 2s, 4s, 8s, ... capped at `RETRY_MAX_DELAY_MS`
+pub fn retry_delay_ms(attempt: u32) -> u64 {
+    RETRY_BASE_DELAY_MS
+        .saturating_mul(1u64 << attempt.saturating_sub(1).min(16))
+        .min(RETRY_MAX_DELAY_MS)
+}
+
+/// A script that used up its retry budget and was left stopped
+#[derive(Debug, Clone)]
+pub struct FailedTask {
+    /// Display name of the script
+    pub name: String,
+    /// Path to the script file
+    pub path: PathBuf,
+    /// How many attempts were made before giving up
+    pub attempts: u32,
+    /// When the final attempt failed
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Scripts whose retry budget is exhausted, for the Background Tasks view
+static FAILED_TASKS: Mutex<Vec<FailedTask>> = Mutex::new(Vec::new());
+
+/// Record that a script gave up after exhausting its retries.
+/// Replaces any earlier failure entry for the same path.
+pub fn note_failure(name: &str, path: &std::path::Path, attempts: u32) {
+    if let Ok(mut failures) = FAILED_TASKS.lock() {
+        failures.retain(|f| f.path != path);
+        failures.push(FailedTask {
+            name: name.to_string(),
+            path: path.to_path_buf(),
+            attempts,
+            failed_at: Utc::now(),
+        });
+    }
+}
+
+/// Snapshot of scripts that exhausted their retry budget
+pub fn failures() -> Vec<FailedTask> {
+    FAILED_TASKS
+        .lock()
+        .map(|failures| failures.clone())
+        .unwrap_or_default()
+}
+
+/// Drop the failure entry for a path (e.g. the script launched again)
+pub fn clear_failure(path: &std::path::Path) {
+    if let Ok(mut failures) = FAILED_TASKS.lock() {
+        failures.retain(|f| f.path != path);
+    }
+}
+
+/// Stop a background task by killing its process group and unregistering it.
+/// Returns true if the task was registered (the kill itself is best-effort).
+pub fn stop(pid: u32) -> bool {
+    let was_registered = unregister(pid);
+    if was_registered {
+        PROCESS_MANAGER.kill_process(pid);
+    }
+    was_registered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_list() {
+        // Use high PIDs unlikely to collide with other tests
+        register(900001, "poller", std::path::Path::new("/tmp/poller.ts"));
+        let tasks = list();
+        assert!(tasks.iter().any(|t| t.pid == 900001 && t.name == "poller"));
+        assert!(unregister(900001));
+    }
+
+    #[test]
+    fn test_unregister_unknown_pid() {
+        assert!(!unregister(900999));
+    }
+
+    #[test]
+    fn test_register_replaces_stale_pid() {
+        register(900002, "old", std::path::Path::new("/tmp/old.ts"));
+        register(900002, "new", std::path::Path::new("/tmp/new.ts"));
+        let tasks = list();
+        let matching: Vec<_> = tasks.iter().filter(|t| t.pid == 900002).collect();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].name, "new");
+        assert!(unregister(900002));
+    }
+
+    #[test]
+    fn test_note_restart_caps_attempts() {
+        let path = std::path::Path::new("/tmp/restart-cap-test.ts");
+        assert_eq!(note_restart(path), Some(1));
+        assert_eq!(note_restart(path), Some(2));
+        assert_eq!(note_restart(path), Some(3));
+        assert_eq!(note_restart(path), None);
+        // A different script gets its own counter
+        assert_eq!(note_restart(std::path::Path::new("/tmp/other.ts")), Some(1));
+    }
+
+    #[test]
+    fn test_note_retry_honors_script_budget() {
+        let path = std::path::Path::new("/tmp/retry-budget-test.ts");
+        assert_eq!(note_retry(path, 2), Some(1));
+        assert_eq!(note_retry(path, 2), Some(2));
+        assert_eq!(note_retry(path, 2), None);
+        // Retries: 0 never retries
+        assert_eq!(
+            note_retry(std::path::Path::new("/tmp/retry-zero.ts"), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_backoff() {
+        assert_eq!(retry_delay_ms(1), 1_000);
+        assert_eq!(retry_delay_ms(2), 2_000);
+        assert_eq!(retry_delay_ms(4), 8_000);
+        // Capped at the ceiling, even for absurd attempt counts
+        assert_eq!(retry_delay_ms(10), 30_000);
+        assert_eq!(retry_delay_ms(u32::MAX), 30_000);
+    }
+
+    #[test]
+    fn test_note_failure_and_clear() {
+        let path = std::path::Path::new("/tmp/failure-test.ts");
+        note_failure("flaky", path, 3);
+        note_failure("flaky", path, 4);
+        let matching: Vec<_> = failures().into_iter().filter(|f| f.path == path).collect();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].attempts, 4);
+        // Registering a fresh run clears the stale failure row
+        register(900003, "flaky", path);
+        assert!(!failures().iter().any(|f| f.path == path));
+        assert!(unregister(900003));
+    }
+
+    #[test]
+    fn test_usage_for_own_process() {
+        // Our own PID is guaranteed to exist; exited PIDs are absent
+        let usage = usage_for(&[std::process::id()]);
+        let (_cpu, memory) = usage[&std::process::id()];
+        assert!(memory > 0);
+    }
+
+    #[test]
+    fn test_format_memory() {
+        assert_eq!(format_memory(512 * 1024), "512 KB");
+        assert_eq!(format_memory(34 * 1024 * 1024), "34 MB");
+        assert_eq!(format_memory(3 * 1024 * 1024 * 1024 / 2), "1.5 GB");
+    }
+
+    #[test]
+    fn test_elapsed_display_formats() {
+        let mut task = BackgroundTask {
+            pid: 1,
+            name: "t".to_string(),
+            path: PathBuf::from("/tmp/t.ts"),
+            started_at: Utc::now(),
+        };
+        assert!(task.elapsed_display().ends_with('s'));
+        task.started_at = Utc::now() - chrono::Duration::seconds(125);
+        assert_eq!(task.elapsed_display(), "2m 5s");
+        task.started_at = Utc::now() - chrono::Duration::seconds(3700);
+        assert_eq!(task.elapsed_display(), "1h 1m");
+    }
+}
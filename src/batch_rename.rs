@@ -0,0 +1,645 @@
+//! Batch Rename builtin - pattern-based renaming with preview and undo
+//!
+//! Two-stage flow (rendered in render_builtins.rs):
+//! 1. Pick files: browse a directory and toggle files with Space
+//! 2. Pattern: type a rename pattern; old→new names preview live in a table,
+//!    Enter applies, Cmd+Z undoes the last applied batch
+//!
+//! ## Pattern syntax
+//! - `{n}` - 1-based counter, `{n:3}` zero-pads to 3 digits
+//! - `{name}` - original file stem, `{ext}` - original extension (no dot)
+//! - `{date}` - file modified date as YYYY-MM-DD, `{date:%Y%m%d}` for custom
+//!   chrono formats
+//! - `/regex/replacement` - when the pattern starts with a `/regex/` section,
+//!   the regex is matched against the original file name and `{1}`..`{9}`
+//!   expand to its capture groups (e.g. `/IMG_(\d+)/photo-{1}.jpg`)
+//!
+//! Applied batches are journaled to `~/.sk/kit/batch-rename-undo.json` so the
+//! most recent batch can be reverted even across restarts.
+
+use chrono::{DateTime, Local};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::logging;
+
+/// File the undo journal is persisted to
+const UNDO_FILE: &str = "~/.sk/kit/batch-rename-undo.json";
+
+// ============================================================================
+// Pattern expansion
+// ============================================================================
+
+/// Split a pattern into an optional match regex and the replacement template
+///
+/// `/IMG_(\d+)/photo-{1}` yields (Some(IMG_(\d+)), "photo-{1}"); patterns that
+/// don't start with `/` (or whose regex doesn't compile) are pure templates.
+pub fn split_pattern(input: &str) -> (Option<Regex>, String) {
+    if let Some(rest) = input.strip_prefix('/') {
+        if let Some(end) = rest.find('/') {
+            let (regex_src, replacement) = rest.split_at(end);
+            if let Ok(regex) = Regex::new(regex_src) {
+                return (Some(regex), replacement[1..].to_string());
+            }
+        }
+    }
+    (None, input.to_string())
+}
+
+/// Context for expanding one file's placeholders
+struct ExpandContext<'a> {
+    /// 1-based position in the selected file list
+    index: usize,
+    /// Original file stem
+    stem: &'a str,
+    /// Original extension without the dot ("" when none)
+    ext: &'a str,
+    /// File modified time for {date}
+    modified: Option<DateTime<Local>>,
+    /// Capture groups from the match regex, if any
+    captures: Option<regex::Captures<'a>>,
+}
+
+/// Expand `{...}` placeholders in a replacement template
+fn expand_template(template: &str, ctx: &ExpandContext) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((start, ch)) = chars.next() {
+        if ch != '{' {
+            out.push(ch);
+            continue;
+        }
+        // Find the closing brace; unterminated braces are kept literally
+        let rest = &template[start + 1..];
+        let Some(end) = rest.find('}') else {
+            out.push(ch);
+            continue;
+        };
+        let token = &rest[..end];
+        // Advance past the token and closing brace
+        for _ in 0..=token.len() {
+            chars.next();
+        }
+
+        match expand_token(token, ctx) {
+            Some(expansion) => out.push_str(&expansion),
+            None => {
+                // Unknown token: keep it literally so typos are visible
+                out.push('{');
+                out.push_str(token);
+                out.push('}');
+            }
+        }
+    }
+    out
+}
+
+/// Expand a single placeholder token, or None when unrecognized
+fn expand_token(token: &str, ctx: &ExpandContext) -> Option<String> {
+    let (key, arg) = match token.split_once(':') {
+        Some((key, arg)) => (key, Some(arg)),
+        None => (token, None),
+    };
+
+    match key {
+        "n" => {
+            let width: usize = arg.and_then(|a| a.parse().ok()).unwrap_or(1);
+            Some(format!("{:0width$}", ctx.index, width = width))
+        }
+        "name" => Some(ctx.stem.to_string()),
+        "ext" => Some(ctx.ext.to_string()),
+        "date" => {
+            let format = arg.unwrap_or("%Y-%m-%d");
+            Some(
+                ctx.modified
+                    .unwrap_or_else(Local::now)
+                    .format(format)
+                    .to_string(),
+            )
+        }
+        _ => {
+            // {1}..{9}: regex capture groups
+            let group: usize = key.parse().ok().filter(|&g| (1..=9).contains(&g))?;
+            let captures = ctx.captures.as_ref()?;
+            Some(
+                captures
+                    .get(group)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default(),
+            )
+        }
+    }
+}
+
+// ============================================================================
+// Rename plan
+// ============================================================================
+
+/// Why a planned rename can't be applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemStatus {
+    /// Rename will be applied
+    Ready,
+    /// New name equals the old name; skipped
+    Unchanged,
+    /// Rename would clobber something; whole plan is blocked
+    Conflict(String),
+}
+
+/// One planned rename
+#[derive(Debug, Clone)]
+pub struct RenameItem {
+    pub from: PathBuf,
+    pub new_name: String,
+    pub status: ItemStatus,
+}
+
+impl RenameItem {
+    /// Target path (same directory as the source)
+    pub fn to(&self) -> PathBuf {
+        self.from
+            .parent()
+            .map(|dir| dir.join(&self.new_name))
+            .unwrap_or_else(|| PathBuf::from(&self.new_name))
+    }
+}
+
+/// Preview of a batch rename: one item per selected file
+#[derive(Debug, Clone, Default)]
+pub struct RenamePlan {
+    pub items: Vec<RenameItem>,
+}
+
+impl RenamePlan {
+    /// Number of renames that would actually happen
+    pub fn ready_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|i| i.status == ItemStatus::Ready)
+            .count()
+    }
+
+    /// True when any item conflicts (plan can't be applied)
+    pub fn has_conflicts(&self) -> bool {
+        self.items
+            .iter()
+            .any(|i| matches!(i.status, ItemStatus::Conflict(_)))
+    }
+}
+
+/// Build a rename plan for `files` from a raw pattern string
+///
+/// Conflicts: empty names, duplicate targets within the batch, targets that
+/// already exist on disk, and targets that match another selected file
+/// (order-dependent renames are rejected rather than sequenced).
+pub fn build_plan(files: &[PathBuf], pattern: &str) -> RenamePlan {
+    let (regex, template) = split_pattern(pattern);
+    let mut items: Vec<RenameItem> = Vec::with_capacity(files.len());
+
+    for (i, file) in files.iter().enumerate() {
+        let file_name = file
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let stem = file
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let ext = file
+            .extension()
+            .map(|e| e.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let modified = file
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .map(DateTime::<Local>::from);
+
+        let ctx = ExpandContext {
+            index: i + 1,
+            stem: &stem,
+            ext: &ext,
+            modified,
+            captures: regex.as_ref().and_then(|r| r.captures(&file_name)),
+        };
+        let new_name = expand_template(&template, &ctx);
+
+        let status = if new_name.trim().is_empty() {
+            ItemStatus::Conflict("empty name".to_string())
+        } else if new_name.contains('/') {
+            ItemStatus::Conflict("name contains '/'".to_string())
+        } else if new_name == file_name {
+            ItemStatus::Unchanged
+        } else {
+            ItemStatus::Ready
+        };
+
+        items.push(RenameItem {
+            from: file.clone(),
+            new_name,
+            status,
+        });
+    }
+
+    // Duplicate targets within the batch
+    for i in 0..items.len() {
+        if items[i].status != ItemStatus::Ready {
+            continue;
+        }
+        let target = items[i].to();
+        let duplicate = items
+            .iter()
+            .enumerate()
+            .any(|(j, other)| j != i && other.to() == target);
+        if duplicate {
+            items[i].status = ItemStatus::Conflict("duplicate target".to_string());
+            continue;
+        }
+        // Target collides with another selected file's current name
+        if items
+            .iter()
+            .any(|other| other.from != items[i].from && other.from == target)
+        {
+            items[i].status = ItemStatus::Conflict("target is another selected file".to_string());
+            continue;
+        }
+        // Target already exists on disk
+        if target.exists() {
+            items[i].status = ItemStatus::Conflict("file already exists".to_string());
+        }
+    }
+
+    RenamePlan { items }
+}
+
+// ============================================================================
+// Apply + undo journal
+// ============================================================================
+
+/// Persisted record of the last applied batch (new path → old path)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct UndoJournal {
+    /// (from, to) pairs in the order they were applied
+    renames: Vec<(String, String)>,
+}
+
+fn undo_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde(UNDO_FILE).into_owned())
+}
+
+fn load_journal(path: &Path) -> UndoJournal {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_journal(path: &Path, journal: &UndoJournal) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(journal)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    std::fs::write(path, json)
+}
+
+/// Apply all Ready items in a plan, journaling for undo
+///
+/// Returns the number of files renamed. Stops at the first failure; renames
+/// already performed stay journaled so they can still be undone.
+pub fn apply_plan(plan: &RenamePlan) -> std::io::Result<usize> {
+    apply_plan_with_journal(plan, &undo_path())
+}
+
+fn apply_plan_with_journal(plan: &RenamePlan, journal_path: &Path) -> std::io::Result<usize> {
+    if plan.has_conflicts() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            "plan has conflicts",
+        ));
+    }
+
+    let mut journal = UndoJournal::default();
+    let mut renamed = 0;
+    for item in plan.items.iter().filter(|i| i.status == ItemStatus::Ready) {
+        let to = item.to();
+        if let Err(e) = std::fs::rename(&item.from, &to) {
+            // Journal what succeeded before bailing so undo still works
+            let _ = save_journal(journal_path, &journal);
+            logging::log(
+                "RENAME",
+                &format!("Batch rename failed at {}: {}", item.from.display(), e),
+            );
+            return Err(e);
+        }
+        journal.renames.push((
+            item.from.to_string_lossy().into_owned(),
+            to.to_string_lossy().into_owned(),
+        ));
+        renamed += 1;
+    }
+
+    save_journal(journal_path, &journal)?;
+    logging::log("RENAME", &format!("Batch renamed {} file(s)", renamed));
+    Ok(renamed)
+}
+
+/// Revert the last applied batch. Returns the number of files restored.
+///
+/// Entries whose new path is gone (or whose old path is occupied again) are
+/// skipped rather than clobbered.
+pub fn undo_last() -> std::io::Result<usize> {
+    undo_last_with_journal(&undo_path())
+}
+
+fn undo_last_with_journal(journal_path: &Path) -> std::io::Result<usize> {
+    let journal = load_journal(journal_path);
+    let mut restored = 0;
+    for (from, to) in journal.renames.iter().rev() {
+        let from = Path::new(from);
+        let to = Path::new(to);
+        if to.exists() && !from.exists() {
+            if std::fs::rename(to, from).is_ok() {
+                restored += 1;
+            }
+        }
+    }
+    // One-shot: an undone batch can't be undone again
+    let _ = std::fs::remove_file(journal_path);
+    logging::log(
+        "RENAME",
+        &format!("Batch rename undo restored {} file(s)", restored),
+    );
+    Ok(restored)
+}
+
+/// True when a previous batch is available to undo
+pub fn undo_available() -> bool {
+    !load_journal(&undo_path()).renames.is_empty()
+}
+
+// ============================================================================
+// View state
+// ============================================================================
+
+/// Which stage of the flow is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Browse a directory and toggle files with Space
+    PickFiles,
+    /// Type a pattern; preview and apply
+    Pattern,
+}
+
+/// State backing the Batch Rename builtin view
+#[derive(Debug, Clone)]
+pub struct BatchRenameState {
+    pub stage: Stage,
+    /// Directory currently browsed in the pick stage
+    pub dir: PathBuf,
+    /// (name, is_dir) entries of `dir`, dirs first, sorted
+    pub entries: Vec<(String, bool)>,
+    /// Absolute paths of toggled files (across directories)
+    pub selected: Vec<PathBuf>,
+    /// Filter text (pick stage) - cleared when entering the pattern stage
+    pub filter: String,
+    /// Rename pattern (pattern stage)
+    pub pattern: String,
+    /// Focused row in the active list
+    pub selected_index: usize,
+}
+
+impl BatchRenameState {
+    pub fn new() -> Self {
+        let dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+        let entries = Self::load_dir(&dir);
+        BatchRenameState {
+            stage: Stage::PickFiles,
+            dir,
+            entries,
+            selected: Vec::new(),
+            filter: String::new(),
+            pattern: String::new(),
+            selected_index: 0,
+        }
+    }
+
+    /// List a directory: visible entries only, dirs first, sorted by name
+    fn load_dir(dir: &Path) -> Vec<(String, bool)> {
+        let mut dirs_list: Vec<(String, bool)> = Vec::new();
+        let mut files: Vec<(String, bool)> = Vec::new();
+        if let Ok(read_dir) = std::fs::read_dir(dir) {
+            for entry in read_dir.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with('.') {
+                    continue;
+                }
+                let is_dir = entry.path().is_dir();
+                if is_dir {
+                    dirs_list.push((name, true));
+                } else {
+                    files.push((name, false));
+                }
+            }
+        }
+        dirs_list.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        files.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        dirs_list.extend(files);
+        dirs_list
+    }
+
+    /// Entries matching the current filter
+    pub fn visible_entries(&self) -> Vec<(String, bool)> {
+        if self.filter.is_empty() {
+            return self.entries.clone();
+        }
+        let filter_lower = self.filter.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|(name, _)| name.to_lowercase().contains(&filter_lower))
+            .cloned()
+            .collect()
+    }
+
+    /// Navigate into a subdirectory (by visible index)
+    pub fn enter_dir(&mut self, visible_index: usize) {
+        if let Some((name, true)) = self.visible_entries().get(visible_index).cloned() {
+            self.dir = self.dir.join(name);
+            self.entries = Self::load_dir(&self.dir);
+            self.filter.clear();
+            self.selected_index = 0;
+        }
+    }
+
+    /// Navigate to the parent directory
+    pub fn parent_dir(&mut self) {
+        if let Some(parent) = self.dir.parent() {
+            self.dir = parent.to_path_buf();
+            self.entries = Self::load_dir(&self.dir);
+            self.filter.clear();
+            self.selected_index = 0;
+        }
+    }
+
+    /// Toggle the file at a visible index in/out of the selection
+    pub fn toggle_selected(&mut self, visible_index: usize) {
+        if let Some((name, false)) = self.visible_entries().get(visible_index).cloned() {
+            let path = self.dir.join(name);
+            if let Some(pos) = self.selected.iter().position(|p| *p == path) {
+                self.selected.remove(pos);
+            } else {
+                self.selected.push(path);
+            }
+        }
+    }
+
+    /// Whether the file at a visible index is currently selected
+    pub fn is_selected(&self, visible_index: usize) -> bool {
+        self.visible_entries()
+            .get(visible_index)
+            .filter(|(_, is_dir)| !is_dir)
+            .map(|(name, _)| self.dir.join(name))
+            .is_some_and(|path| self.selected.contains(&path))
+    }
+
+    /// Move to the pattern stage (no-op with an empty selection)
+    pub fn enter_pattern_stage(&mut self) {
+        if !self.selected.is_empty() {
+            self.stage = Stage::Pattern;
+            self.selected_index = 0;
+        }
+    }
+
+    /// Back to the pick stage, keeping the selection
+    pub fn back_to_pick(&mut self) {
+        self.stage = Stage::PickFiles;
+        self.selected_index = 0;
+    }
+
+    /// Current preview plan for the selected files and pattern
+    pub fn plan(&self) -> RenamePlan {
+        build_plan(&self.selected, &self.pattern)
+    }
+}
+
+impl Default for BatchRenameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("sk-batch-rename-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn touch(dir: &Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, b"x").unwrap();
+        path
+    }
+
+    #[test]
+    fn test_expand_counter_and_padding() {
+        let files = vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("/tmp/b.txt")];
+        let plan = build_plan(&files, "file-{n:3}.{ext}");
+        assert_eq!(plan.items[0].new_name, "file-001.txt");
+        assert_eq!(plan.items[1].new_name, "file-002.txt");
+    }
+
+    #[test]
+    fn test_expand_name_and_ext() {
+        let files = vec![PathBuf::from("/tmp/report.pdf")];
+        let plan = build_plan(&files, "{name}-final.{ext}");
+        assert_eq!(plan.items[0].new_name, "report-final.pdf");
+    }
+
+    #[test]
+    fn test_regex_captures() {
+        let files = vec![PathBuf::from("/tmp/IMG_0042.png")];
+        let plan = build_plan(&files, r"/IMG_(\d+)/photo-{1}.png");
+        assert_eq!(plan.items[0].new_name, "photo-0042.png");
+    }
+
+    #[test]
+    fn test_unknown_token_kept_literally() {
+        let files = vec![PathBuf::from("/tmp/a.txt")];
+        let plan = build_plan(&files, "{bogus}.txt");
+        assert_eq!(plan.items[0].new_name, "{bogus}.txt");
+    }
+
+    #[test]
+    fn test_unchanged_name_skipped() {
+        let files = vec![PathBuf::from("/tmp/same.txt")];
+        let plan = build_plan(&files, "{name}.{ext}");
+        assert_eq!(plan.items[0].status, ItemStatus::Unchanged);
+        assert_eq!(plan.ready_count(), 0);
+    }
+
+    #[test]
+    fn test_duplicate_targets_conflict() {
+        let files = vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("/tmp/b.txt")];
+        let plan = build_plan(&files, "same.txt");
+        assert!(plan.has_conflicts());
+    }
+
+    #[test]
+    fn test_existing_file_conflicts() {
+        let dir = temp_dir("exists");
+        touch(&dir, "a.txt");
+        touch(&dir, "taken.txt");
+        let plan = build_plan(&[dir.join("a.txt")], "taken.txt");
+        assert!(plan.has_conflicts());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_apply_and_undo_roundtrip() {
+        let dir = temp_dir("roundtrip");
+        touch(&dir, "a.txt");
+        touch(&dir, "b.txt");
+        let journal = dir.join("journal.json");
+
+        let plan = build_plan(&[dir.join("a.txt"), dir.join("b.txt")], "item-{n}.txt");
+        let renamed = apply_plan_with_journal(&plan, &journal).unwrap();
+        assert_eq!(renamed, 2);
+        assert!(dir.join("item-1.txt").exists());
+        assert!(!dir.join("a.txt").exists());
+
+        let restored = undo_last_with_journal(&journal).unwrap();
+        assert_eq!(restored, 2);
+        assert!(dir.join("a.txt").exists());
+        assert!(dir.join("b.txt").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_apply_refuses_conflicted_plan() {
+        let dir = temp_dir("refuse");
+        touch(&dir, "a.txt");
+        touch(&dir, "b.txt");
+        let journal = dir.join("journal.json");
+        let plan = build_plan(&[dir.join("a.txt"), dir.join("b.txt")], "same.txt");
+        assert!(apply_plan_with_journal(&plan, &journal).is_err());
+        // Nothing moved
+        assert!(dir.join("a.txt").exists());
+        assert!(dir.join("b.txt").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_split_pattern_without_regex() {
+        let (regex, template) = split_pattern("plain-{n}");
+        assert!(regex.is_none());
+        assert_eq!(template, "plain-{n}");
+    }
+}
@@ -0,0 +1,160 @@
+//! `sk` - companion CLI for the running Script Kit app.
+//!
+//! Talks to the app over a unix socket for shell and CI integration:
+//!
+//! ```bash
+//! sk run <script>   # run a script by name or path fragment
+//! sk list           # list available script names
+//! sk toggle         # toggle the main window
+//! sk logs           # print recent app logs
+//! sk logs --follow  # tail the app's JSONL log
+//! sk schema         # print TypeScript protocol definitions
+//! ```
+
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::os::unix::net::UnixStream;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use script_kit_gpui::cli_server;
+use script_kit_gpui::logging;
+
+const USAGE: &str = "Usage: sk <command>
+
+Commands:
+  run <script>    Run a script by name or path fragment
+  list            List available script names
+  toggle          Toggle the main window
+  logs [--follow] Print recent app logs (--follow to tail)
+  schema          Print TypeScript protocol definitions to stdout";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => {
+            let name = args[1..].join(" ");
+            if name.trim().is_empty() {
+                eprintln!("sk run: missing script name");
+                return ExitCode::FAILURE;
+            }
+            send_command(&format!("run {}", name))
+        }
+        Some("list") => send_command("list"),
+        Some("toggle") => send_command("toggle"),
+        Some("logs") => {
+            let follow = args[1..].iter().any(|a| a == "--follow" || a == "-f");
+            logs(follow)
+        }
+        Some("schema") => {
+            // Generated locally from the compiled-in protocol definition;
+            // no running app required
+            print!("{}", script_kit_gpui::protocol::generate_typescript());
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Send one request line to the running app and print its response.
+fn send_command(line: &str) -> ExitCode {
+    let path = cli_server::socket_path();
+    let mut stream = match UnixStream::connect(&path) {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!(
+                "sk: cannot connect to {} - is the app running? ({})",
+                path.display(),
+                e
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if writeln!(stream, "{}", line).is_err() {
+        eprintln!("sk: failed to send command");
+        return ExitCode::FAILURE;
+    }
+
+    let mut response = String::new();
+    if stream.read_to_string(&mut response).is_err() {
+        eprintln!("sk: failed to read response");
+        return ExitCode::FAILURE;
+    }
+
+    let response = response.trim_end();
+    if !response.is_empty() {
+        println!("{}", response);
+    }
+    if response.starts_with("error:") {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Number of lines printed by a plain `sk logs` invocation.
+const LOG_TAIL_LINES: usize = 50;
+
+/// Print the tail of the JSONL log, optionally following new output.
+fn logs(follow: bool) -> ExitCode {
+    let path = logging::log_path();
+    let mut file = match std::fs::File::open(&path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("sk: cannot open log file {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Print the last LOG_TAIL_LINES lines
+    let reader = BufReader::new(&file);
+    let lines: Vec<String> = reader.lines().map_while(Result::ok).collect();
+    let start = lines.len().saturating_sub(LOG_TAIL_LINES);
+    for line in &lines[start..] {
+        println!("{}", line);
+    }
+
+    if !follow {
+        return ExitCode::SUCCESS;
+    }
+
+    // Follow: poll for new bytes appended past our current position
+    let mut position = match file.seek(SeekFrom::End(0)) {
+        Ok(position) => position,
+        Err(e) => {
+            eprintln!("sk: failed to seek log file: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    loop {
+        std::thread::sleep(Duration::from_millis(500));
+
+        let len = match std::fs::metadata(&path) {
+            Ok(meta) => meta.len(),
+            Err(_) => continue,
+        };
+        if len < position {
+            // Log was rotated or truncated - start over from the beginning
+            position = 0;
+        }
+        if len == position {
+            continue;
+        }
+
+        if file.seek(SeekFrom::Start(position)).is_err() {
+            continue;
+        }
+        let mut new_output = String::new();
+        if (&file).read_to_string(&mut new_output).is_err() {
+            continue;
+        }
+        position += new_output.len() as u64;
+        print!("{}", new_output);
+        let _ = std::io::stdout().flush();
+    }
+}
@@ -66,6 +66,12 @@ pub enum SystemActionType {
     ToggleDoNotDisturb,
     StartScreenSaver,
 
+    // Connectivity
+    WifiOn,
+    WifiOff,
+    BluetoothOn,
+    BluetoothOff,
+
     // System Preferences
     OpenSystemPreferences,
     OpenPrivacySettings,
@@ -114,12 +120,30 @@ pub enum ScriptCommandType {
     NewScriptlet,
 }
 
+/// Settings export/import command types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsCommandType {
+    ExportSettings,
+    ImportSettings,
+    UnhideScripts,
+}
+
+/// Self-update command types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateCommandType {
+    CheckForUpdates,
+    ViewChangelog,
+    InstallUpdate,
+}
+
 /// Permission management command types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PermissionCommandType {
     CheckPermissions,
     RequestAccessibility,
     OpenAccessibilitySettings,
+    RequestScreenRecording,
+    OpenScreenRecordingSettings,
 }
 
 /// Menu bar action details for executing menu commands
@@ -158,6 +182,40 @@ pub enum BuiltInFeature {
     App(String),
     /// Window switcher for managing and tiling windows
     WindowSwitcher,
+    /// Spotlight-backed file search with open/reveal actions
+    SearchFiles,
+    /// Viewer for running background scripts with stop actions
+    BackgroundTasks,
+    /// OCR the clipboard image and copy the recognized text
+    OcrClipboard,
+    /// Browser for the user's macOS Shortcuts with run actions
+    Shortcuts,
+    /// Browser for trashed scripts with restore actions
+    RecentlyDeleted,
+    /// Browser for bundled themes with live preview
+    ThemeBrowser,
+    /// Statistics for text expansion triggers with conflict detection
+    ExpandStats,
+    /// GitHub repos, PRs awaiting review, and notifications
+    GitHub,
+    /// Create a Jira/Linear issue from the filter text or clipboard
+    CreateIssue,
+    /// World clock with favorite timezones and wall-clock conversion
+    WorldClock,
+    /// Pattern-based batch file renaming with preview and undo
+    BatchRename,
+    /// Recent screenshots with thumbnails, OCR, and quick-annotate
+    ScreenshotHistory,
+    /// Two-pane "send to" file mover with bookmarks and conflict handling
+    MoveFiles,
+    /// Keyring-backed env secrets browser with reveal/copy/update/delete
+    SecretsVault,
+    /// Browser for known Wi-Fi networks and paired Bluetooth devices
+    ConnectBrowser,
+    /// Browser for macOS Focus modes with activate/deactivate actions
+    FocusModes,
+    /// Import Raycast script commands and Alfred workflows as scripts
+    ImportMigration,
     /// Design gallery for viewing separator and icon variations
     DesignGallery,
     /// AI Chat window for conversing with AI assistants
@@ -180,6 +238,31 @@ pub enum BuiltInFeature {
     ScriptCommand(ScriptCommandType),
     /// Permission management commands
     PermissionCommand(PermissionCommandType),
+    /// Self-update commands (check, changelog, install)
+    UpdateCommand(UpdateCommandType),
+    /// Settings export/import commands
+    SettingsCommand(SettingsCommandType),
+    /// Fallback action shown when the filter matches nothing
+    Fallback(FallbackActionType),
+    /// Shell one-liner from the `>` query scope (the command to run)
+    ShellCommand(String),
+    /// Clipboard history entry from the `c:` query scope (the content to copy)
+    ClipboardPaste(String),
+    /// Tags browser listing every script tag with counts (shown for `#`)
+    TagsBrowser,
+    /// Narrow the main filter to one tag (rows inside the tags browser)
+    TagFilter(String),
+}
+
+/// Fallback actions offered when a search produces no results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallbackActionType {
+    /// Open the configured search engine with the query
+    WebSearch,
+    /// Create a new script named after the query
+    CreateScript,
+    /// Search the filesystem for the query
+    FileSearch,
 }
 
 /// A built-in feature entry that appears in the main search
@@ -310,6 +393,17 @@ pub fn get_builtin_entries(config: &BuiltInConfig) -> Vec<BuiltInEntry> {
         debug!("Added Window Switcher built-in entry");
     }
 
+    // File Search is always available (backed by Spotlight on macOS)
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-search-files",
+        "Search Files",
+        "Find files with Spotlight - Enter opens, Cmd+Enter reveals in Finder",
+        vec!["file", "files", "search", "find", "spotlight", "finder"],
+        BuiltInFeature::SearchFiles,
+        "🔍",
+    ));
+    debug!("Added Search Files built-in entry");
+
     // AI Chat is always available
     entries.push(BuiltInEntry::new_with_icon(
         "builtin-ai-chat",
@@ -666,6 +760,69 @@ pub fn get_builtin_entries(config: &BuiltInConfig) -> Vec<BuiltInEntry> {
         "🖼️",
     ));
 
+    // Connectivity
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-wifi-on",
+        "Turn Wi-Fi On",
+        "Turn Wi-Fi power on",
+        vec!["wifi", "wi-fi", "wireless", "network", "on", "enable"],
+        BuiltInFeature::SystemAction(SystemActionType::WifiOn),
+        "📶",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-wifi-off",
+        "Turn Wi-Fi Off",
+        "Turn Wi-Fi power off",
+        vec!["wifi", "wi-fi", "wireless", "network", "off", "disable"],
+        BuiltInFeature::SystemAction(SystemActionType::WifiOff),
+        "📶",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-bluetooth-on",
+        "Turn Bluetooth On",
+        "Turn Bluetooth power on (requires blueutil)",
+        vec!["bluetooth", "bt", "on", "enable"],
+        BuiltInFeature::SystemAction(SystemActionType::BluetoothOn),
+        "🔷",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-bluetooth-off",
+        "Turn Bluetooth Off",
+        "Turn Bluetooth power off (requires blueutil)",
+        vec!["bluetooth", "bt", "off", "disable"],
+        BuiltInFeature::SystemAction(SystemActionType::BluetoothOff),
+        "🔷",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-connect",
+        "Connect to Network or Device",
+        "Join a known Wi-Fi network or connect a paired Bluetooth device",
+        vec![
+            "connect",
+            "wifi",
+            "network",
+            "bluetooth",
+            "device",
+            "join",
+            "pair",
+        ],
+        BuiltInFeature::ConnectBrowser,
+        "🔗",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-focus-modes",
+        "Focus Modes",
+        "Browse and toggle macOS Focus modes (Do Not Disturb, Work, ...)",
+        vec!["focus", "dnd", "disturb", "mode", "work", "sleep"],
+        BuiltInFeature::FocusModes,
+        "🌙",
+    ));
+
     // System Preferences
     entries.push(BuiltInEntry::new_with_icon(
         "builtin-system-preferences",
@@ -894,10 +1051,433 @@ pub fn get_builtin_entries(config: &BuiltInConfig) -> Vec<BuiltInEntry> {
         "♿",
     ));
 
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-request-screen-recording",
+        "Request Screen Recording Permission",
+        "Request screen recording permission for Script Kit",
+        vec!["request", "screen", "recording", "permission"],
+        BuiltInFeature::PermissionCommand(PermissionCommandType::RequestScreenRecording),
+        "🖥️",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-screen-recording-settings",
+        "Open Screen Recording Settings",
+        "Open Screen Recording settings in System Preferences",
+        vec!["screen", "recording", "settings", "permission", "open"],
+        BuiltInFeature::PermissionCommand(PermissionCommandType::OpenScreenRecordingSettings),
+        "🎥",
+    ));
+
+    // =========================================================================
+    // Update Commands
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-check-for-updates",
+        "Check for Updates",
+        "Check GitHub releases for a newer version",
+        vec!["check", "update", "updates", "version", "upgrade"],
+        BuiltInFeature::UpdateCommand(UpdateCommandType::CheckForUpdates),
+        "🔄",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-view-changelog",
+        "View Changelog",
+        "Show release notes for the latest version",
+        vec!["changelog", "release", "notes", "version", "whats", "new"],
+        BuiltInFeature::UpdateCommand(UpdateCommandType::ViewChangelog),
+        "📋",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-install-update",
+        "Install Update",
+        "Download and install the available update, then relaunch",
+        vec!["install", "update", "download", "upgrade", "relaunch"],
+        BuiltInFeature::UpdateCommand(UpdateCommandType::InstallUpdate),
+        "⬇️",
+    ));
+
+    // =========================================================================
+    // Settings Commands
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-export-settings",
+        "Export Settings",
+        "Bundle config, theme, and keymap files into a zip archive",
+        vec!["export", "settings", "backup", "config", "migrate"],
+        BuiltInFeature::SettingsCommand(SettingsCommandType::ExportSettings),
+        "📦",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-import-settings",
+        "Import Settings",
+        "Restore settings from an exported archive",
+        vec!["import", "settings", "restore", "config", "migrate"],
+        BuiltInFeature::SettingsCommand(SettingsCommandType::ImportSettings),
+        "📥",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-unhide-scripts",
+        "Unhide All Scripts",
+        "Clear the hidden-from-search list so every item shows again",
+        vec!["unhide", "hidden", "show", "scripts", "restore"],
+        BuiltInFeature::SettingsCommand(SettingsCommandType::UnhideScripts),
+        "👁️",
+    ));
+
+    // =========================================================================
+    // Background Tasks
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-background-tasks",
+        "Background Tasks",
+        "View and stop running background scripts",
+        vec![
+            "background",
+            "tasks",
+            "running",
+            "stop",
+            "poller",
+            "watcher",
+        ],
+        BuiltInFeature::BackgroundTasks,
+        "⚙️",
+    ));
+
+    // =========================================================================
+    // OCR
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-ocr-clipboard",
+        "Extract Text from Clipboard Image",
+        "OCR the clipboard image and copy the recognized text",
+        vec!["ocr", "text", "extract", "image", "screenshot", "recognize"],
+        BuiltInFeature::OcrClipboard,
+        "🔍",
+    ));
+
+    // =========================================================================
+    // Shortcuts
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-shortcuts",
+        "Run Shortcut",
+        "Browse and run your macOS Shortcuts",
+        vec!["shortcuts", "shortcut", "automation", "workflow", "run"],
+        BuiltInFeature::Shortcuts,
+        "⚡",
+    ));
+
+    // =========================================================================
+    // Recently Deleted
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-recently-deleted",
+        "Recently Deleted",
+        "Restore scripts moved to the app trash",
+        vec!["trash", "deleted", "restore", "undelete", "recover"],
+        BuiltInFeature::RecentlyDeleted,
+        "🗑️",
+    ));
+
+    // =========================================================================
+    // Theme Browser
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-theme-browser",
+        "Browse Themes",
+        "Preview bundled themes and apply one to theme.json",
+        vec!["theme", "themes", "color", "appearance", "dark", "light"],
+        BuiltInFeature::ThemeBrowser,
+        "🎨",
+    ));
+
+    // =========================================================================
+    // Expansion Stats
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-expand-stats",
+        "Expansion Stats",
+        "Text expansion triggers with usage counts and conflicts",
+        vec![
+            "expand",
+            "expansion",
+            "snippet",
+            "snippets",
+            "trigger",
+            "triggers",
+            "stats",
+        ],
+        BuiltInFeature::ExpandStats,
+        "📈",
+    ));
+
+    // =========================================================================
+    // GitHub
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-github",
+        "GitHub",
+        "Repositories, PRs awaiting your review, and notifications",
+        vec![
+            "github",
+            "gh",
+            "repo",
+            "repos",
+            "pr",
+            "pull",
+            "review",
+            "notifications",
+        ],
+        BuiltInFeature::GitHub,
+        "🐙",
+    ));
+
+    // =========================================================================
+    // Create Issue
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-create-issue",
+        "Create Issue",
+        "File a Jira/Linear issue from the filter text or clipboard",
+        vec!["issue", "jira", "linear", "ticket", "create", "bug", "task"],
+        BuiltInFeature::CreateIssue,
+        "📝",
+    ));
+
+    // =========================================================================
+    // World Clock
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-world-clock",
+        "World Clock",
+        "Time in your favorite zones; \"time in tokyo\" or \"3pm PT in CET\"",
+        vec![
+            "time", "clock", "timezone", "tz", "world", "convert", "zone",
+        ],
+        BuiltInFeature::WorldClock,
+        "🕒",
+    ));
+
+    // =========================================================================
+    // Batch Rename
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-batch-rename",
+        "Batch Rename",
+        "Rename files with {n}/{name}/{date} patterns, live preview, and undo",
+        vec!["rename", "batch", "files", "pattern", "bulk"],
+        BuiltInFeature::BatchRename,
+        "✏️",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-screenshot-history",
+        "Screenshot History",
+        "Browse recent screenshots with thumbnails, OCR text, and quick-annotate",
+        vec!["screenshot", "screen", "capture", "history", "ocr"],
+        BuiltInFeature::ScreenshotHistory,
+        "📸",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-move-files",
+        "Move Files",
+        "Send files to another folder with bookmarks, progress, and conflict handling",
+        vec!["move", "send", "files", "folder", "transfer"],
+        BuiltInFeature::MoveFiles,
+        "📦",
+    ));
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-secrets-vault",
+        "Secrets Vault",
+        "Browse keyring-stored env secrets: reveal, copy, update, delete, export",
+        vec!["secrets", "env", "keyring", "keychain", "vault", "api key"],
+        BuiltInFeature::SecretsVault,
+        "🔐",
+    ));
+
+    // =========================================================================
+    // Tags
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-browse-tags",
+        "Browse Tags",
+        "List every script tag with counts; pick one to filter by it",
+        vec!["tags", "tag", "browse", "filter", "label", "labels"],
+        BuiltInFeature::TagsBrowser,
+        "🏷️",
+    ));
+
+    // =========================================================================
+    // Import / migration
+    // =========================================================================
+
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-import",
+        "Import from Raycast/Alfred",
+        "Convert Raycast script commands and Alfred workflows into scripts",
+        vec!["import", "migrate", "raycast", "alfred", "workflow"],
+        BuiltInFeature::ImportMigration,
+        "📦",
+    ));
+
     debug!(count = entries.len(), "Built-in entries loaded");
     entries
 }
 
+/// Get the fallback entries for a query that matched nothing.
+///
+/// These are built dynamically per-query (the query is embedded in the entry
+/// name) and are never part of the static built-in list, so they don't go
+/// through `get_builtin_entries`.
+pub fn get_fallback_entries(
+    query: &str,
+    config: &crate::config::FallbacksConfig,
+) -> Vec<BuiltInEntry> {
+    let mut entries = Vec::new();
+    if query.trim().is_empty() {
+        return entries;
+    }
+
+    if config.web_search {
+        entries.push(BuiltInEntry::new_with_icon(
+            "fallback-web-search",
+            format!("Search the web for \"{}\"", query),
+            "Open your search engine with this query",
+            vec![],
+            BuiltInFeature::Fallback(FallbackActionType::WebSearch),
+            "🔍",
+        ));
+    }
+
+    if config.create_script {
+        entries.push(BuiltInEntry::new_with_icon(
+            "fallback-create-script",
+            format!("Create script \"{}\"", query),
+            "Create a new script with this name and open it in your editor",
+            vec![],
+            BuiltInFeature::Fallback(FallbackActionType::CreateScript),
+            "📝",
+        ));
+    }
+
+    if config.file_search {
+        entries.push(BuiltInEntry::new_with_icon(
+            "fallback-file-search",
+            format!("Search files for \"{}\"", query),
+            "Search your files for this query",
+            vec![],
+            BuiltInFeature::Fallback(FallbackActionType::FileSearch),
+            "📁",
+        ));
+    }
+
+    entries
+}
+
+/// Build the row shown for the `>` query scope (shell one-liner)
+pub fn get_shell_command_entry(command: &str) -> BuiltInEntry {
+    BuiltInEntry::new_with_icon(
+        "shell-command",
+        format!("Run: {}", command),
+        "Execute in your shell",
+        vec![],
+        BuiltInFeature::ShellCommand(command.to_string()),
+        "💲",
+    )
+}
+
+/// Build the shell history rows shown under the `>` query scope
+///
+/// Commands come from [`crate::shell_history::ShellHistoryStore`], most
+/// recent first. Running a row re-executes the command.
+pub fn get_shell_history_entries(commands: &[String]) -> Vec<BuiltInEntry> {
+    commands
+        .iter()
+        .enumerate()
+        .map(|(ix, command)| {
+            BuiltInEntry::new_with_icon(
+                format!("shell-history-{}", ix),
+                command.clone(),
+                "Shell history",
+                vec![],
+                BuiltInFeature::ShellCommand(command.clone()),
+                "🕘",
+            )
+        })
+        .collect()
+}
+
+/// Build rows for the `c:` query scope from clipboard history entries
+///
+/// Takes (content, source_app) pairs so this module doesn't depend on
+/// clipboard_history types. Running a row copies the content back to the
+/// clipboard.
+pub fn get_clipboard_scope_entries(entries: &[(String, Option<String>)]) -> Vec<BuiltInEntry> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(ix, (content, source_app))| {
+            // First line, truncated, as the row title
+            let first_line = content.lines().next().unwrap_or("");
+            let preview: String = if first_line.chars().count() > 60 {
+                format!("{}…", first_line.chars().take(60).collect::<String>())
+            } else {
+                first_line.to_string()
+            };
+            BuiltInEntry::new_with_icon(
+                format!("clipboard-entry-{}", ix),
+                preview,
+                source_app
+                    .clone()
+                    .unwrap_or_else(|| "Clipboard".to_string()),
+                vec![],
+                BuiltInFeature::ClipboardPaste(content.clone()),
+                "📋",
+            )
+        })
+        .collect()
+}
+
+/// Build the tags browser rows from [`crate::scripts::collect_tags`] output.
+///
+/// Like fallback entries these are dynamic (one per tag, in the given order)
+/// and never part of the static built-in list. Running a row narrows the main
+/// filter to `#tag`.
+pub fn get_tag_entries(tags: &[(String, usize)]) -> Vec<BuiltInEntry> {
+    tags.iter()
+        .map(|(tag, count)| {
+            BuiltInEntry::new_with_icon(
+                format!("tag-{}", tag),
+                format!("#{}", tag),
+                format!("{} script(s) tagged {}", count, tag),
+                vec![],
+                BuiltInFeature::TagFilter(tag.clone()),
+                "🏷️",
+            )
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -917,6 +1497,7 @@ mod tests {
             clipboard_history: false,
             app_launcher: true,
             window_switcher: false,
+            clipboard_excluded_apps: Vec::new(),
         };
         assert!(!config.clipboard_history);
         assert!(config.app_launcher);
@@ -930,8 +1511,10 @@ mod tests {
 
         // Core built-ins: Clipboard history, window switcher, AI chat, Notes, design gallery
         // Plus: system actions (28), window actions (6), notes commands (3), AI commands (1),
-        // script commands (2), permission commands (3) = 43 new entries
-        // Total: 5 + 43 = 48
+        // script commands (2), permission commands (5), update commands (3),
+        // settings commands (2), background tasks (1), OCR (1), shortcuts (1),
+        // import (1) = 54 new entries
+        // Total: 5 + 54 = 59
         assert!(entries.len() >= 5); // At minimum the core built-ins should exist
 
         // Check clipboard history entry
@@ -978,6 +1561,7 @@ mod tests {
             clipboard_history: true,
             app_launcher: false,
             window_switcher: false,
+            clipboard_excluded_apps: Vec::new(),
         };
         let entries = get_builtin_entries(&config);
 
@@ -997,6 +1581,7 @@ mod tests {
             clipboard_history: false,
             app_launcher: true,
             window_switcher: false,
+            clipboard_excluded_apps: Vec::new(),
         };
         let entries = get_builtin_entries(&config);
 
@@ -1016,6 +1601,7 @@ mod tests {
             clipboard_history: false,
             app_launcher: false,
             window_switcher: false,
+            clipboard_excluded_apps: Vec::new(),
         };
         let entries = get_builtin_entries(&config);
 
@@ -1035,6 +1621,7 @@ mod tests {
             clipboard_history: false,
             app_launcher: false,
             window_switcher: true,
+            clipboard_excluded_apps: Vec::new(),
         };
         let entries = get_builtin_entries(&config);
 
@@ -1163,6 +1750,7 @@ mod tests {
             clipboard_history: true,
             app_launcher: false,
             window_switcher: true,
+            clipboard_excluded_apps: Vec::new(),
         };
 
         let cloned = config.clone();
@@ -1237,6 +1825,331 @@ mod tests {
         assert!(entries
             .iter()
             .any(|e| e.id == "builtin-accessibility-settings"));
+        assert!(entries
+            .iter()
+            .any(|e| e.id == "builtin-request-screen-recording"));
+        assert!(entries
+            .iter()
+            .any(|e| e.id == "builtin-screen-recording-settings"));
+    }
+
+    #[test]
+    fn test_update_command_entries_exist() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        // Check that update command entries exist
+        assert!(entries.iter().any(|e| e.id == "builtin-check-for-updates"));
+        assert!(entries.iter().any(|e| e.id == "builtin-view-changelog"));
+        assert!(entries.iter().any(|e| e.id == "builtin-install-update"));
+    }
+
+    #[test]
+    fn test_settings_command_entries_exist() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        // Check that settings command entries exist
+        assert!(entries.iter().any(|e| e.id == "builtin-export-settings"));
+        assert!(entries.iter().any(|e| e.id == "builtin-import-settings"));
+    }
+
+    #[test]
+    fn test_background_tasks_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-background-tasks")
+            .expect("background tasks entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::BackgroundTasks);
+    }
+
+    #[test]
+    fn test_ocr_clipboard_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-ocr-clipboard")
+            .expect("OCR clipboard entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::OcrClipboard);
+    }
+
+    #[test]
+    fn test_shortcuts_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-shortcuts")
+            .expect("shortcuts entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::Shortcuts);
+    }
+
+    #[test]
+    fn test_recently_deleted_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-recently-deleted")
+            .expect("recently deleted entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::RecentlyDeleted);
+    }
+
+    #[test]
+    fn test_theme_browser_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-theme-browser")
+            .expect("theme browser entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::ThemeBrowser);
+    }
+
+    #[test]
+    fn test_expand_stats_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-expand-stats")
+            .expect("expansion stats entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::ExpandStats);
+    }
+
+    #[test]
+    fn test_github_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-github")
+            .expect("github entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::GitHub);
+    }
+
+    #[test]
+    fn test_create_issue_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-create-issue")
+            .expect("create issue entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::CreateIssue);
+    }
+
+    #[test]
+    fn test_search_files_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-search-files")
+            .expect("search files entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::SearchFiles);
+    }
+
+    #[test]
+    fn test_batch_rename_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-batch-rename")
+            .expect("batch rename entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::BatchRename);
+    }
+
+    #[test]
+    fn test_screenshot_history_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-screenshot-history")
+            .expect("screenshot history entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::ScreenshotHistory);
+    }
+
+    #[test]
+    fn test_move_files_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-move-files")
+            .expect("move files entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::MoveFiles);
+    }
+
+    #[test]
+    fn test_secrets_vault_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-secrets-vault")
+            .expect("secrets vault entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::SecretsVault);
+    }
+
+    #[test]
+    fn test_world_clock_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-world-clock")
+            .expect("world clock entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::WorldClock);
+    }
+
+    #[test]
+    fn test_connectivity_entries_exist() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        for id in [
+            "builtin-wifi-on",
+            "builtin-wifi-off",
+            "builtin-bluetooth-on",
+            "builtin-bluetooth-off",
+        ] {
+            assert!(
+                entries.iter().any(|e| e.id == id),
+                "{} entry should exist",
+                id
+            );
+        }
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-connect")
+            .expect("connect entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::ConnectBrowser);
+    }
+
+    #[test]
+    fn test_focus_modes_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-focus-modes")
+            .expect("focus modes entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::FocusModes);
+    }
+
+    #[test]
+    fn test_import_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-import")
+            .expect("import entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::ImportMigration);
+    }
+
+    #[test]
+    fn test_unhide_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-unhide-scripts")
+            .expect("unhide entry should exist");
+        assert_eq!(
+            entry.feature,
+            BuiltInFeature::SettingsCommand(SettingsCommandType::UnhideScripts)
+        );
+    }
+
+    #[test]
+    fn test_browse_tags_entry_exists() {
+        let config = BuiltInConfig::default();
+        let entries = get_builtin_entries(&config);
+
+        let entry = entries
+            .iter()
+            .find(|e| e.id == "builtin-browse-tags")
+            .expect("browse tags entry should exist");
+        assert_eq!(entry.feature, BuiltInFeature::TagsBrowser);
+    }
+
+    #[test]
+    fn test_tag_entries_from_counts() {
+        let tags = vec![("git".to_string(), 3), ("work".to_string(), 1)];
+        let entries = get_tag_entries(&tags);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, "tag-git");
+        assert_eq!(entries[0].name, "#git");
+        assert_eq!(
+            entries[0].feature,
+            BuiltInFeature::TagFilter("git".to_string())
+        );
+        assert_eq!(entries[1].name, "#work");
+    }
+
+    #[test]
+    fn test_fallback_entries_all_enabled() {
+        let config = crate::config::FallbacksConfig::default();
+        let entries = get_fallback_entries("frobnicate", &config);
+
+        assert_eq!(entries.len(), 3);
+        let web = entries
+            .iter()
+            .find(|e| e.id == "fallback-web-search")
+            .expect("web search fallback should exist");
+        assert_eq!(web.name, "Search the web for \"frobnicate\"");
+        assert_eq!(
+            web.feature,
+            BuiltInFeature::Fallback(FallbackActionType::WebSearch)
+        );
+        assert!(entries.iter().any(|e| e.id == "fallback-create-script"));
+        assert!(entries.iter().any(|e| e.id == "fallback-file-search"));
+    }
+
+    #[test]
+    fn test_fallback_entries_respect_config() {
+        let config = crate::config::FallbacksConfig {
+            web_search: false,
+            create_script: true,
+            file_search: false,
+            ..Default::default()
+        };
+        let entries = get_fallback_entries("frobnicate", &config);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "fallback-create-script");
+    }
+
+    #[test]
+    fn test_fallback_entries_empty_query() {
+        let config = crate::config::FallbacksConfig::default();
+        assert!(get_fallback_entries("", &config).is_empty());
+        assert!(get_fallback_entries("   ", &config).is_empty());
     }
 
     #[test]
@@ -0,0 +1,178 @@
+//! Unix socket server for the `sk` companion CLI.
+//!
+//! The running app listens on a unix socket (`~/.sk/kit/sk.sock`) and accepts
+//! simple line-based commands from the `sk` binary:
+//!
+//! - `run <script>` - run a script by name (or path fragment)
+//! - `list`         - list available script names, one per line
+//! - `toggle`       - toggle the main window (same as the global hotkey)
+//!
+//! Each connection carries a single request line; the server replies with one
+//! or more lines and closes the connection. `sk logs --follow` does not use
+//! the socket - it tails the JSONL log file directly.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::logging;
+
+/// A parsed command from an `sk` CLI connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    /// Run a script by name or path fragment.
+    Run { name: String },
+    /// List available script names.
+    List,
+    /// Toggle the main window.
+    Toggle,
+}
+
+/// A request forwarded from the socket thread to the GPUI main thread.
+///
+/// The handler sends its response text back over `reply`; the socket thread
+/// writes it to the client and closes the connection.
+pub struct CliRequest {
+    pub command: CliCommand,
+    pub reply: std::sync::mpsc::Sender<String>,
+}
+
+/// Path to the CLI socket (`~/.sk/kit/sk.sock`).
+pub fn socket_path() -> PathBuf {
+    dirs::home_dir()
+        .map(|h| h.join(".sk/kit/sk.sock"))
+        .unwrap_or_else(|| std::env::temp_dir().join("script-kit-sk.sock"))
+}
+
+/// Parse a single request line into a [`CliCommand`].
+///
+/// Returns `None` for empty or unrecognized input.
+pub fn parse_cli_command(line: &str) -> Option<CliCommand> {
+    let line = line.trim();
+    if let Some(name) = line.strip_prefix("run ") {
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        return Some(CliCommand::Run {
+            name: name.to_string(),
+        });
+    }
+    match line {
+        "list" => Some(CliCommand::List),
+        "toggle" => Some(CliCommand::Toggle),
+        _ => None,
+    }
+}
+
+/// Start the CLI socket server on a background thread.
+///
+/// Binds the socket (replacing any stale file from a previous run) and
+/// forwards parsed requests over the returned channel. Connections are
+/// handled sequentially - requests are tiny and responses are immediate.
+pub fn start_cli_server() -> async_channel::Receiver<CliRequest> {
+    let (tx, rx) = async_channel::unbounded::<CliRequest>();
+
+    std::thread::spawn(move || {
+        let path = socket_path();
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        // Remove a stale socket left behind by a previous run
+        let _ = std::fs::remove_file(&path);
+
+        let listener = match UnixListener::bind(&path) {
+            Ok(listener) => listener,
+            Err(e) => {
+                logging::log(
+                    "CLI",
+                    &format!("Failed to bind CLI socket {}: {}", path.display(), e),
+                );
+                return;
+            }
+        };
+        logging::log(
+            "CLI",
+            &format!("CLI socket listening at {}", path.display()),
+        );
+
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    logging::log("CLI", &format!("CLI socket accept error: {}", e));
+                    continue;
+                }
+            };
+
+            let mut line = String::new();
+            if BufReader::new(&stream).read_line(&mut line).is_err() {
+                continue;
+            }
+
+            let Some(command) = parse_cli_command(&line) else {
+                let _ = writeln!(stream, "error: unknown command: {}", line.trim());
+                continue;
+            };
+
+            let (reply_tx, reply_rx) = std::sync::mpsc::channel::<String>();
+            if tx
+                .send_blocking(CliRequest {
+                    command,
+                    reply: reply_tx,
+                })
+                .is_err()
+            {
+                logging::log("CLI", "CLI request channel closed, exiting server");
+                break;
+            }
+
+            // Wait briefly for the app to respond; don't hang the client forever
+            match reply_rx.recv_timeout(Duration::from_secs(2)) {
+                Ok(response) => {
+                    let _ = writeln!(stream, "{}", response);
+                }
+                Err(_) => {
+                    let _ = writeln!(stream, "error: app did not respond");
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cli_command_run() {
+        assert_eq!(
+            parse_cli_command("run my-script"),
+            Some(CliCommand::Run {
+                name: "my-script".to_string()
+            })
+        );
+        assert_eq!(
+            parse_cli_command("run  name with spaces \n"),
+            Some(CliCommand::Run {
+                name: "name with spaces".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_command_list_and_toggle() {
+        assert_eq!(parse_cli_command("list"), Some(CliCommand::List));
+        assert_eq!(parse_cli_command("toggle\n"), Some(CliCommand::Toggle));
+    }
+
+    #[test]
+    fn test_parse_cli_command_rejects_unknown() {
+        assert_eq!(parse_cli_command(""), None);
+        assert_eq!(parse_cli_command("run "), None);
+        assert_eq!(parse_cli_command("frobnicate"), None);
+    }
+}
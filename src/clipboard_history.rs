@@ -15,6 +15,8 @@
 use anyhow::{Context, Result};
 use arboard::Clipboard;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::aead::{Aead, OsRng};
+use chacha20poly1305::{AeadCore, ChaCha20Poly1305, KeyInit, Nonce};
 use chrono::{Datelike, Local, NaiveDate, TimeZone};
 use gpui::RenderImage;
 use lru::LruCache;
@@ -214,6 +216,18 @@ pub struct ClipboardEntry {
     /// OCR text extracted from images (None for text entries or pending OCR)
     #[allow(dead_code)] // Used by downstream subtasks (OCR, UI)
     pub ocr_text: Option<String>,
+    /// Display name of the application the content was copied from
+    /// (None for entries recorded before attribution existed)
+    pub source_app: Option<String>,
+    /// Bundle identifier of the source application (e.g. "com.apple.Safari")
+    pub source_bundle_id: Option<String>,
+}
+
+/// The frontmost application at the moment clipboard content was captured
+#[derive(Debug, Clone)]
+pub struct SourceApp {
+    pub name: String,
+    pub bundle_id: Option<String>,
 }
 
 /// Global database connection (thread-safe)
@@ -231,6 +245,10 @@ static RETENTION_DAYS: OnceLock<u32> = OnceLock::new();
 /// Configured maximum text entry length (bytes). usize::MAX means no limit.
 static MAX_TEXT_CONTENT_LEN: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_TEXT_CONTENT_LEN);
 
+/// Lowercased app names / bundle id patterns whose copies are never stored
+/// (password managers, banking apps, ... - the `clipboardExcludedApps` config)
+static EXCLUDED_APPS: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
 /// Global image cache for decoded RenderImages (thread-safe)
 /// Key: entry ID, Value: decoded RenderImage
 /// Uses LRU eviction to cap memory usage at ~100-400MB (100 images max)
@@ -362,6 +380,276 @@ pub fn set_max_text_content_len(max_len: usize) {
     MAX_TEXT_CONTENT_LEN.store(value, Ordering::Relaxed);
 }
 
+/// Set the list of excluded apps (from the `clipboardExcludedApps` config key)
+///
+/// Each entry is matched case-insensitively as a substring of the source
+/// app's name or bundle identifier, so "1password" covers both
+/// "1Password 8" and "com.1password.1password".
+pub fn set_excluded_apps(patterns: Vec<String>) {
+    let normalized: Vec<String> = patterns
+        .into_iter()
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let lock = EXCLUDED_APPS.get_or_init(|| Mutex::new(Vec::new()));
+    if let Ok(mut excluded) = lock.lock() {
+        *excluded = normalized;
+    }
+}
+
+/// Whether copies from this application should be skipped entirely
+pub fn is_excluded_app(source: &SourceApp) -> bool {
+    let Some(lock) = EXCLUDED_APPS.get() else {
+        return false;
+    };
+    let Ok(excluded) = lock.lock() else {
+        return false;
+    };
+    if excluded.is_empty() {
+        return false;
+    }
+    let name = source.name.to_lowercase();
+    let bundle_id = source.bundle_id.as_deref().unwrap_or("").to_lowercase();
+    excluded.iter().any(|p| {
+        name.contains(p.as_str()) || (!bundle_id.is_empty() && bundle_id.contains(p.as_str()))
+    })
+}
+
+/// Get the frontmost application (the likely source of a clipboard change)
+#[cfg(target_os = "macos")]
+pub fn frontmost_app() -> Option<SourceApp> {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let app: *mut Object = msg_send![workspace, frontmostApplication];
+        if app.is_null() {
+            return None;
+        }
+
+        let ns_string_to_string = |s: *mut Object| -> Option<String> {
+            if s.is_null() {
+                return None;
+            }
+            let utf8: *const std::os::raw::c_char = msg_send![s, UTF8String];
+            if utf8.is_null() {
+                return None;
+            }
+            std::ffi::CStr::from_ptr(utf8)
+                .to_str()
+                .ok()
+                .map(|s| s.to_string())
+        };
+
+        let name_obj: *mut Object = msg_send![app, localizedName];
+        let bundle_obj: *mut Object = msg_send![app, bundleIdentifier];
+
+        let name = ns_string_to_string(name_obj)?;
+        let bundle_id = ns_string_to_string(bundle_obj);
+
+        Some(SourceApp { name, bundle_id })
+    }
+}
+
+/// Get the frontmost application (no-op off macOS)
+#[cfg(not(target_os = "macos"))]
+pub fn frontmost_app() -> Option<SourceApp> {
+    None
+}
+
+// ============================================================================
+// Encryption at rest
+// ============================================================================
+//
+// Clipboard content is encrypted before it hits the database so that
+// cloud-synced home directories (Dropbox, iCloud Drive, etc.) never see
+// plaintext clipboard data. The key lives in the system keychain, which
+// does not sync with the home directory. Encryption is per-value: `content`
+// and `ocr_text` are encrypted, while `content_hash` stays derived from the
+// plaintext so O(1) dedup keeps working (ciphertexts are nonce-randomized
+// and never compare equal).
+
+/// Keyring service for the clipboard history encryption key
+const CLIPBOARD_KEYRING_SERVICE: &str = "com.scriptkit.clipboard";
+
+/// Keyring account under which the encryption key is stored
+const CLIPBOARD_KEYRING_ACCOUNT: &str = "history-encryption-key";
+
+/// Prefix marking an encrypted column value.
+///
+/// Values without this prefix are legacy plaintext rows; they are readable
+/// as-is and get re-encrypted in place by `encrypt_plaintext_entries` on
+/// init. Keep in sync with the `NOT LIKE 'enc1:%'` SQL filters below.
+const ENCRYPTED_PREFIX: &str = "enc1:";
+
+/// Cached encryption key, loaded from the keychain once per process.
+///
+/// `None` means the keychain was unavailable or refused us; entries are
+/// then stored plaintext so clipboard history keeps working.
+static ENCRYPTION_KEY: OnceLock<Option<[u8; 32]>> = OnceLock::new();
+
+/// Fetch the encryption key from the keychain, generating one on first use
+fn encryption_key() -> Option<[u8; 32]> {
+    *ENCRYPTION_KEY.get_or_init(|| {
+        let entry =
+            match keyring::Entry::new(CLIPBOARD_KEYRING_SERVICE, CLIPBOARD_KEYRING_ACCOUNT) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    warn!(
+                        error = %e,
+                        "Failed to open keychain entry; clipboard history will be stored unencrypted"
+                    );
+                    return None;
+                }
+            };
+
+        match entry.get_password() {
+            Ok(encoded) => match BASE64.decode(&encoded) {
+                Ok(bytes) if bytes.len() == 32 => {
+                    let mut key = [0u8; 32];
+                    key.copy_from_slice(&bytes);
+                    Some(key)
+                }
+                _ => {
+                    warn!("Clipboard encryption key in keychain is malformed; storing entries unencrypted");
+                    None
+                }
+            },
+            Err(keyring::Error::NoEntry) => {
+                // First run: generate a fresh key and persist it
+                let key: [u8; 32] = ChaCha20Poly1305::generate_key(&mut OsRng).into();
+                if let Err(e) = entry.set_password(&BASE64.encode(key)) {
+                    warn!(
+                        error = %e,
+                        "Failed to store clipboard encryption key; storing entries unencrypted"
+                    );
+                    return None;
+                }
+                info!("Generated clipboard history encryption key");
+                Some(key)
+            }
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    "Failed to read clipboard encryption key; storing entries unencrypted"
+                );
+                None
+            }
+        }
+    })
+}
+
+/// Encrypt a column value with the given key
+///
+/// Output format: `enc1:` + base64(nonce || ciphertext). A random nonce is
+/// generated per value, so encrypting the same plaintext twice yields
+/// different ciphertexts.
+fn encrypt_with_key(key: &[u8; 32], plain: &str) -> String {
+    let cipher = ChaCha20Poly1305::new(key.into());
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    match cipher.encrypt(&nonce, plain.as_bytes()) {
+        Ok(ciphertext) => {
+            let mut blob = nonce.to_vec();
+            blob.extend_from_slice(&ciphertext);
+            format!("{}{}", ENCRYPTED_PREFIX, BASE64.encode(blob))
+        }
+        Err(_) => {
+            // ChaCha20-Poly1305 encryption is infallible in practice; keep
+            // the entry rather than losing clipboard data
+            warn!("Clipboard encryption failed; storing entry unencrypted");
+            plain.to_string()
+        }
+    }
+}
+
+/// Decrypt a column value with the given key
+///
+/// Returns `None` if the value is not in the encrypted format or fails
+/// authentication (wrong key, truncated blob, tampering).
+fn decrypt_with_key(key: &[u8; 32], stored: &str) -> Option<String> {
+    let blob = BASE64.decode(stored.strip_prefix(ENCRYPTED_PREFIX)?).ok()?;
+    // 12-byte nonce followed by ciphertext + 16-byte auth tag
+    if blob.len() < 12 {
+        return None;
+    }
+    let (nonce, ciphertext) = blob.split_at(12);
+    let cipher = ChaCha20Poly1305::new(key.into());
+    let plain = cipher.decrypt(Nonce::from_slice(nonce), ciphertext).ok()?;
+    String::from_utf8(plain).ok()
+}
+
+/// Encrypt a column value for storage (plaintext passthrough when no key)
+fn encrypt_content(plain: &str) -> String {
+    match encryption_key() {
+        Some(key) => encrypt_with_key(&key, plain),
+        None => plain.to_string(),
+    }
+}
+
+/// Decrypt a stored column value
+///
+/// Legacy plaintext rows (no `enc1:` prefix) pass through unchanged.
+/// Undecryptable values (e.g. the keychain entry was deleted and recreated)
+/// come back empty with a warning rather than leaking base64 into the UI.
+fn decrypt_content(stored: &str) -> String {
+    if !stored.starts_with(ENCRYPTED_PREFIX) {
+        return stored.to_string();
+    }
+    match encryption_key().and_then(|key| decrypt_with_key(&key, stored)) {
+        Some(plain) => plain,
+        None => {
+            warn!("Failed to decrypt clipboard entry; was the keychain key replaced?");
+            String::new()
+        }
+    }
+}
+
+/// Re-encrypt legacy plaintext rows in place.
+///
+/// Migration path for databases created before encryption at rest: runs on
+/// init, finds rows whose content lacks the `enc1:` prefix, and rewrites
+/// `content` and `ocr_text` encrypted. Returns the number of rows migrated.
+fn encrypt_plaintext_entries() -> Result<usize> {
+    let Some(key) = encryption_key() else {
+        return Ok(0);
+    };
+
+    let conn = get_connection()?;
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Lock error: {}", e))?;
+
+    let mut stmt = conn
+        .prepare("SELECT id, content, ocr_text FROM history WHERE content NOT LIKE 'enc1:%'")
+        .context("Failed to prepare plaintext query")?;
+    let rows: Vec<(String, String, Option<String>)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .context("Failed to query plaintext entries")?
+        .filter_map(|r| r.ok())
+        .collect();
+    drop(stmt);
+
+    let migrated = rows.len();
+    for (id, content, ocr_text) in rows {
+        conn.execute(
+            "UPDATE history SET content = ?, ocr_text = ? WHERE id = ?",
+            params![
+                encrypt_with_key(&key, &content),
+                ocr_text.map(|t| encrypt_with_key(&key, &t)),
+                id
+            ],
+        )
+        .context("Failed to encrypt plaintext entry")?;
+    }
+
+    if migrated > 0 {
+        info!(migrated, "Encrypted legacy plaintext clipboard entries");
+    }
+
+    Ok(migrated)
+}
+
 /// Get the database path (~/.sk/kit/db/clipboard-history.sqlite)
 fn get_db_path() -> Result<PathBuf> {
     let kit_dir = PathBuf::from(shellexpand::tilde("~/.sk/kit").as_ref());
@@ -443,6 +731,24 @@ fn get_connection() -> Result<Arc<Mutex<Connection>>> {
         info!("Migrated clipboard history: added content_hash column");
     }
 
+    // Migration: Add source app attribution columns if they don't exist
+    let has_source_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('history') WHERE name='source_app'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_source_column {
+        conn.execute("ALTER TABLE history ADD COLUMN source_app TEXT", [])
+            .context("Failed to add source_app column")?;
+        conn.execute("ALTER TABLE history ADD COLUMN source_bundle_id TEXT", [])
+            .context("Failed to add source_bundle_id column")?;
+        info!("Migrated clipboard history: added source app columns");
+    }
+
     // Create index for faster queries
     conn.execute(
         "CREATE INDEX IF NOT EXISTS idx_timestamp ON history(timestamp DESC)",
@@ -482,10 +788,11 @@ fn get_connection() -> Result<Arc<Mutex<Connection>>> {
 /// This should be called once at application startup. It will:
 /// 1. Create the SQLite database if it doesn't exist (with WAL mode)
 /// 2. Run initial pruning of old entries
-/// 3. Pre-warm the entry cache
-/// 4. Pre-decode images in background
-/// 5. Start a background thread that polls the clipboard every 500ms
-/// 6. Start a background pruning job (runs hourly)
+/// 3. Encrypt any legacy plaintext rows (migration to encryption at rest)
+/// 4. Pre-warm the entry cache
+/// 5. Pre-decode images in background
+/// 6. Start a background thread that polls the clipboard every 500ms
+/// 7. Start a background pruning job (runs hourly)
 ///
 /// # Errors
 /// Returns error if database creation fails.
@@ -522,6 +829,12 @@ pub fn init_clipboard_history() -> Result<()> {
         );
     }
 
+    // Encrypt any legacy plaintext rows before caching (migration path for
+    // databases created before encryption at rest)
+    if let Err(e) = encrypt_plaintext_entries() {
+        warn!(error = %e, "Clipboard encryption migration failed");
+    }
+
     // Pre-warm the entry cache from database
     refresh_entry_cache();
 
@@ -703,7 +1016,11 @@ fn clipboard_monitor_loop(stop_flag: Arc<AtomicBool>) -> Result<()> {
 
                 if is_new {
                     debug!(text_len = text.len(), "New text detected in clipboard");
-                    if is_text_over_limit(&text) {
+                    let source = frontmost_app();
+                    if let Some(src) = source.as_ref().filter(|s| is_excluded_app(s)) {
+                        // Sensitive source (password manager etc.) - never store
+                        debug!(app = %src.name, "Skipping clipboard entry from excluded app");
+                    } else if is_text_over_limit(&text) {
                         let correlation_id = Uuid::new_v4().to_string();
                         warn!(
                             correlation_id = %correlation_id,
@@ -712,7 +1029,7 @@ fn clipboard_monitor_loop(stop_flag: Arc<AtomicBool>) -> Result<()> {
                             "Skipping oversized clipboard text entry"
                         );
                     } else {
-                        match add_entry(&text, ContentType::Text) {
+                        match add_entry(&text, ContentType::Text, source.as_ref()) {
                             Ok(entry_id) => {
                                 debug!(entry_id = %entry_id, "Added text entry to history");
                             }
@@ -743,10 +1060,14 @@ fn clipboard_monitor_loop(stop_flag: Arc<AtomicBool>) -> Result<()> {
                     "New image detected in clipboard"
                 );
 
-                // Encode image as compressed PNG (base64)
-                if let Ok(base64_content) = encode_image_as_png(&image_data) {
+                let source = frontmost_app();
+                if let Some(src) = source.as_ref().filter(|s| is_excluded_app(s)) {
+                    // Sensitive source (password manager etc.) - never store
+                    debug!(app = %src.name, "Skipping clipboard image from excluded app");
+                } else if let Ok(base64_content) = encode_image_as_png(&image_data) {
+                    // Encode image as compressed PNG (base64)
                     // Add entry and get the ID back for correct caching
-                    match add_entry(&base64_content, ContentType::Image) {
+                    match add_entry(&base64_content, ContentType::Image, source.as_ref()) {
                         Ok(entry_id) => {
                             // Pre-decode the image immediately so it's ready for display
                             // This runs in the background monitor thread, not during render
@@ -839,7 +1160,11 @@ fn encode_image_as_base64(image: &arboard::ImageData) -> Result<String> {
 ///
 /// Returns the ID of the entry (either existing or newly created).
 /// This allows callers to use the correct ID for caching (e.g., images).
-fn add_entry(content: &str, content_type: ContentType) -> Result<String> {
+fn add_entry(
+    content: &str,
+    content_type: ContentType,
+    source: Option<&SourceApp>,
+) -> Result<String> {
     if content_type == ContentType::Text && is_text_over_limit(content) {
         anyhow::bail!(
             "Clipboard text exceeds max length ({} bytes)",
@@ -878,11 +1203,21 @@ fn add_entry(content: &str, content_type: ContentType) -> Result<String> {
         return Ok(existing_id);
     }
 
-    // Insert new entry with content_hash
+    // Insert new entry with content_hash and source attribution.
+    // Content is encrypted at rest; the hash above stays plaintext-derived
+    // so dedup works (ciphertexts are nonce-randomized).
     let id = Uuid::new_v4().to_string();
     conn.execute(
-        "INSERT INTO history (id, content, content_hash, content_type, timestamp, pinned, ocr_text) VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL)",
-        params![&id, content, &content_hash, content_type.as_str(), timestamp],
+        "INSERT INTO history (id, content, content_hash, content_type, timestamp, pinned, ocr_text, source_app, source_bundle_id) VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL, ?6, ?7)",
+        params![
+            &id,
+            encrypt_content(content),
+            &content_hash,
+            content_type.as_str(),
+            timestamp,
+            source.map(|s| s.name.as_str()),
+            source.and_then(|s| s.bundle_id.as_deref()),
+        ],
     )
     .context("Failed to insert clipboard entry")?;
 
@@ -914,9 +1249,11 @@ pub fn trim_oversize_text_entries() -> Result<usize> {
         .map_err(|e| anyhow::anyhow!("Lock error: {}", e))?;
 
     let max_len_db = i64::try_from(max_len).unwrap_or(i64::MAX);
+    // Encrypted rows are skipped: base64 + nonce inflate the stored length,
+    // and they already passed the plaintext limit check at insert time
     let deleted = conn
         .execute(
-            "DELETE FROM history WHERE content_type = 'text' AND length(CAST(content AS BLOB)) > ?",
+            "DELETE FROM history WHERE content_type = 'text' AND content NOT LIKE 'enc1:%' AND length(CAST(content AS BLOB)) > ?",
             params![max_len_db],
         )
         .context("Failed to trim oversized text entries")?;
@@ -966,9 +1303,9 @@ pub fn get_clipboard_history_page(limit: usize, offset: usize) -> Vec<ClipboardE
     };
 
     let mut stmt = match conn.prepare(
-        "SELECT id, content, content_type, timestamp, pinned, ocr_text 
-         FROM history 
-         ORDER BY pinned DESC, timestamp DESC 
+        "SELECT id, content, content_type, timestamp, pinned, ocr_text, source_app, source_bundle_id
+         FROM history
+         ORDER BY pinned DESC, timestamp DESC
          LIMIT ? OFFSET ?",
     ) {
         Ok(s) => s,
@@ -982,11 +1319,15 @@ pub fn get_clipboard_history_page(limit: usize, offset: usize) -> Vec<ClipboardE
         .query_map(params![limit, offset], |row| {
             Ok(ClipboardEntry {
                 id: row.get(0)?,
-                content: row.get(1)?,
+                content: decrypt_content(&row.get::<_, String>(1)?),
                 content_type: ContentType::from_str(&row.get::<_, String>(2)?),
                 timestamp: row.get(3)?,
                 pinned: row.get::<_, i64>(4)? != 0,
-                ocr_text: row.get(5)?,
+                ocr_text: row
+                    .get::<_, Option<String>>(5)?
+                    .map(|t| decrypt_content(&t)),
+                source_app: row.get(6)?,
+                source_bundle_id: row.get(7)?,
             })
         })
         .map(|rows| rows.filter_map(|r| r.ok()).collect())
@@ -1166,6 +1507,74 @@ pub fn clear_history() -> Result<()> {
     Ok(())
 }
 
+/// Clear all clipboard history, keeping a one-shot backup for undo
+///
+/// The current rows are copied into a `history_undo` table (replacing any
+/// previous backup) before being deleted, so `restore_cleared_history` can
+/// bring them back within the undo window.
+///
+/// # Errors
+/// Returns error if database operation fails.
+pub fn clear_history_with_backup() -> Result<()> {
+    let conn = get_connection()?;
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Lock error: {}", e))?;
+
+    conn.execute_batch(
+        "BEGIN;
+         DROP TABLE IF EXISTS history_undo;
+         CREATE TABLE history_undo AS SELECT * FROM history;
+         DELETE FROM history;
+         COMMIT;",
+    )
+    .context("Failed to clear history with backup")?;
+
+    info!("Cleared all clipboard history (backup kept for undo)");
+
+    // Drop lock before cache operations
+    drop(conn);
+
+    // Clear both entry and image caches
+    clear_all_caches();
+
+    Ok(())
+}
+
+/// Restore clipboard history from the backup made by `clear_history_with_backup`
+///
+/// Returns the number of restored entries. The backup table is dropped after
+/// a successful restore.
+///
+/// # Errors
+/// Returns error if no backup exists or database operation fails.
+pub fn restore_cleared_history() -> Result<usize> {
+    let conn = get_connection()?;
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Lock error: {}", e))?;
+
+    let restored = conn
+        .execute(
+            "INSERT OR REPLACE INTO history SELECT * FROM history_undo",
+            [],
+        )
+        .context("No clipboard history backup to restore")?;
+
+    conn.execute("DROP TABLE IF EXISTS history_undo", [])
+        .context("Failed to drop history backup table")?;
+
+    info!(count = restored, "Restored clipboard history from backup");
+
+    // Drop lock before cache operations
+    drop(conn);
+
+    // Rebuild the entry cache from the restored rows
+    refresh_entry_cache();
+
+    Ok(restored)
+}
+
 /// Update OCR text for an entry (async OCR results)
 ///
 /// This is called by the OCR module after extracting text from an image.
@@ -1186,7 +1595,7 @@ pub fn update_ocr_text(id: &str, text: &str) -> Result<()> {
     let affected = conn
         .execute(
             "UPDATE history SET ocr_text = ? WHERE id = ?",
-            params![text, id],
+            params![encrypt_content(text), id],
         )
         .context("Failed to update OCR text")?;
 
@@ -1218,16 +1627,20 @@ pub fn get_entry_by_id(id: &str) -> Option<ClipboardEntry> {
     let conn = conn.lock().ok()?;
 
     conn.query_row(
-        "SELECT id, content, content_type, timestamp, pinned, ocr_text FROM history WHERE id = ?",
+        "SELECT id, content, content_type, timestamp, pinned, ocr_text, source_app, source_bundle_id FROM history WHERE id = ?",
         params![id],
         |row| {
             Ok(ClipboardEntry {
                 id: row.get(0)?,
-                content: row.get(1)?,
+                content: decrypt_content(&row.get::<_, String>(1)?),
                 content_type: ContentType::from_str(&row.get::<_, String>(2)?),
                 timestamp: row.get(3)?,
                 pinned: row.get::<_, i64>(4)? != 0,
-                ocr_text: row.get(5)?,
+                ocr_text: row
+                    .get::<_, Option<String>>(5)?
+                    .map(|t| decrypt_content(&t)),
+                source_app: row.get(6)?,
+                source_bundle_id: row.get(7)?,
             })
         },
     )
@@ -1256,6 +1669,7 @@ pub fn copy_entry_to_clipboard(id: &str) -> Result<()> {
         )
         .context("Entry not found")?;
     // Note: ocr_text not needed for copying to clipboard
+    let content = decrypt_content(&content);
 
     drop(conn); // Release lock before clipboard operation
 
@@ -1791,6 +2205,8 @@ mod tests {
                 timestamp: today_ts,
                 pinned: false,
                 ocr_text: None,
+                source_app: None,
+                source_bundle_id: None,
             },
             ClipboardEntry {
                 id: "2".to_string(),
@@ -1799,6 +2215,8 @@ mod tests {
                 timestamp: yesterday_ts,
                 pinned: false,
                 ocr_text: None,
+                source_app: None,
+                source_bundle_id: None,
             },
             ClipboardEntry {
                 id: "3".to_string(),
@@ -1807,6 +2225,8 @@ mod tests {
                 timestamp: old_ts,
                 pinned: false,
                 ocr_text: None,
+                source_app: None,
+                source_bundle_id: None,
             },
         ];
 
@@ -1875,12 +2295,43 @@ mod tests {
         // This is a compile-time check - validates the function signature change
         fn assert_returns_result_string<F>(_: F)
         where
-            F: Fn(&str, ContentType) -> Result<String>,
+            F: Fn(&str, ContentType, Option<&SourceApp>) -> Result<String>,
         {
         }
         assert_returns_result_string(add_entry);
     }
 
+    #[test]
+    fn test_excluded_app_matching() {
+        set_excluded_apps(vec![
+            "1Password".to_string(),
+            "com.banking".to_string(),
+            "  ".to_string(), // blank patterns are dropped
+        ]);
+
+        let onepassword = SourceApp {
+            name: "1Password 8".to_string(),
+            bundle_id: Some("com.1password.1password".to_string()),
+        };
+        assert!(is_excluded_app(&onepassword), "Name substring should match");
+
+        let bank = SourceApp {
+            name: "MyBank".to_string(),
+            bundle_id: Some("com.banking.mybank".to_string()),
+        };
+        assert!(is_excluded_app(&bank), "Bundle id substring should match");
+
+        let safari = SourceApp {
+            name: "Safari".to_string(),
+            bundle_id: Some("com.apple.Safari".to_string()),
+        };
+        assert!(!is_excluded_app(&safari));
+
+        // Clearing the list disables exclusions again
+        set_excluded_apps(Vec::new());
+        assert!(!is_excluded_app(&onepassword));
+    }
+
     #[test]
     fn test_compute_content_hash_deterministic() {
         // Same content should produce same hash
@@ -1921,4 +2372,48 @@ mod tests {
         let long_text = "a".repeat(DEFAULT_MAX_TEXT_CONTENT_LEN + 1);
         assert!(is_text_over_limit(&long_text));
     }
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let key = [7u8; 32];
+        let plain = "secret clipboard content 🔒";
+
+        let stored = encrypt_with_key(&key, plain);
+        assert!(
+            stored.starts_with(ENCRYPTED_PREFIX),
+            "Encrypted value should carry the enc1: prefix"
+        );
+        assert!(
+            !stored.contains(plain),
+            "Ciphertext should not leak plaintext"
+        );
+
+        assert_eq!(decrypt_with_key(&key, &stored).as_deref(), Some(plain));
+    }
+
+    #[test]
+    fn test_encrypt_is_nonce_randomized() {
+        // Same plaintext must not produce the same ciphertext, so dedup has
+        // to rely on the plaintext-derived content_hash
+        let key = [7u8; 32];
+        let a = encrypt_with_key(&key, "same content");
+        let b = encrypt_with_key(&key, "same content");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_key() {
+        let stored = encrypt_with_key(&[7u8; 32], "secret");
+        assert_eq!(decrypt_with_key(&[8u8; 32], &stored), None);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_plaintext_and_garbage() {
+        let key = [7u8; 32];
+        // Legacy plaintext rows lack the prefix entirely
+        assert_eq!(decrypt_with_key(&key, "plain old text"), None);
+        // Prefixed but malformed values fail cleanly
+        assert_eq!(decrypt_with_key(&key, "enc1:not-base64!"), None);
+        assert_eq!(decrypt_with_key(&key, "enc1:AAAA"), None);
+    }
 }
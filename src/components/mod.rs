@@ -45,6 +45,6 @@ pub use prompt_container::{PromptContainer, PromptContainerColors, PromptContain
 #[allow(unused_imports)]
 pub use prompt_header::{PromptHeader, PromptHeaderColors, PromptHeaderConfig};
 #[allow(unused_imports)]
-pub use text_input::{TextInputState, TextSelection};
+pub use text_input::{TextInput, TextInputState, TextSelection};
 #[allow(unused_imports)]
 pub use toast::{Toast, ToastAction, ToastColors, ToastVariant};
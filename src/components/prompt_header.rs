@@ -9,9 +9,8 @@
 use gpui::*;
 use std::rc::Rc;
 
-use crate::components::{Button, ButtonColors, ButtonVariant};
+use crate::components::{Button, ButtonColors, ButtonVariant, TextInput};
 use crate::designs::DesignColors;
-use crate::panel::{CURSOR_GAP_X, CURSOR_HEIGHT_LG, CURSOR_MARGIN_Y, CURSOR_WIDTH};
 use crate::theme::Theme;
 
 /// Pre-computed colors for PromptHeader rendering
@@ -233,31 +232,10 @@ impl PromptHeader {
     /// Render the search input area with cursor
     fn render_input_area(&self) -> impl IntoElement {
         let colors = self.colors;
-        let filter_is_empty = self.config.filter_text.is_empty();
         let cursor_visible = self.config.cursor_visible && self.config.is_focused;
 
-        // Display text: filter text or placeholder
-        let display_text: SharedString = if filter_is_empty {
-            self.config.placeholder.clone().into()
-        } else {
-            self.config.filter_text.clone().into()
-        };
-
-        // Text color: muted for placeholder, primary for input
-        let text_color = if filter_is_empty {
-            rgb(colors.text_muted)
-        } else {
-            rgb(colors.text_primary)
-        };
-
         // Build input container
-        let mut input = div()
-            .flex_1()
-            .flex()
-            .flex_row()
-            .items_center()
-            .text_lg()
-            .text_color(text_color);
+        let mut input = div().flex_1().flex().flex_row().items_center().text_lg();
 
         // Path prefix (if present)
         if let Some(ref prefix) = self.config.path_prefix {
@@ -268,63 +246,15 @@ impl PromptHeader {
             );
         }
 
-        // Cursor position:
-        // - When empty: cursor LEFT (before placeholder)
-        // - When typing: cursor RIGHT (after text)
-        //
-        // ALIGNMENT FIX: The left cursor (when empty) takes up space (CURSOR_WIDTH + CURSOR_GAP_X).
-        // We apply a negative margin to the placeholder text to pull it back by that amount,
-        // so placeholder and typed text share the same starting x-position. This eliminates
-        // the "jump" when typing begins.
-
-        // Left cursor (when empty)
-        // Use conditional background instead of .when() to avoid type inference issues
-        if filter_is_empty {
-            let cursor_bg = if cursor_visible {
-                rgb(colors.text_primary)
-            } else {
-                rgba(0x00000000)
-            };
-            input = input.child(
-                div()
-                    .w(px(CURSOR_WIDTH))
-                    .h(px(CURSOR_HEIGHT_LG))
-                    .my(px(CURSOR_MARGIN_Y))
-                    .mr(px(CURSOR_GAP_X))
-                    .bg(cursor_bg),
-            );
-        }
-
-        // Display text - with negative margin for placeholder alignment
-        if filter_is_empty {
-            // Placeholder: pull back by cursor space to align with typed text position
-            input = input.child(
-                div()
-                    .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
-                    .child(display_text),
-            );
-        } else {
-            input = input.child(display_text);
-        }
-
-        // Right cursor (when not empty)
-        if !filter_is_empty {
-            let cursor_bg = if cursor_visible {
-                rgb(colors.text_primary)
-            } else {
-                rgba(0x00000000)
-            };
-            input = input.child(
-                div()
-                    .w(px(CURSOR_WIDTH))
-                    .h(px(CURSOR_HEIGHT_LG))
-                    .my(px(CURSOR_MARGIN_Y))
-                    .ml(px(CURSOR_GAP_X))
-                    .bg(cursor_bg),
-            );
-        }
-
-        input
+        // Shared TextInput component: placeholder alignment, cursor-at-end,
+        // and blink handling (the header only tracks a plain String)
+        input.child(
+            TextInput::from_text(self.config.filter_text.clone())
+                .placeholder(self.config.placeholder.clone())
+                .cursor_visible(cursor_visible)
+                .text_color(colors.text_primary)
+                .placeholder_color(colors.text_muted),
+        )
     }
 
     /// Render the action buttons area (Run + Actions)
@@ -109,6 +109,14 @@ pub struct Scrollbar {
     /// Whether the scrollbar is visible (for scroll-activity-aware fade)
     /// When Some(true), shows at full opacity; Some(false), hidden; None, always visible
     is_visible: Option<bool>,
+    /// Total content height in pixels (pixel-accurate mode)
+    ///
+    /// When set (via `from_pixels`), thumb geometry is derived from real
+    /// pixel measurements instead of item counts, which is what
+    /// variable-height lists (24px headers + 48px items) need.
+    content_height: Option<f32>,
+    /// Scroll offset from the top of the content in pixels (pixel-accurate mode)
+    scroll_offset_px: Option<f32>,
 }
 
 impl Scrollbar {
@@ -132,6 +140,38 @@ impl Scrollbar {
             colors,
             container_height: None,
             is_visible: None,
+            content_height: None,
+            scroll_offset_px: None,
+        }
+    }
+
+    /// Create a scrollbar from pixel-accurate scroll metrics
+    ///
+    /// Preferred over `new` when the caller knows the real content height,
+    /// container height, and scroll offset (e.g. variable-height lists where
+    /// item counts are a poor proxy). Thumb size and position are then exact
+    /// instead of estimated.
+    ///
+    /// # Arguments
+    /// * `content_height` - Total height of the scrollable content in pixels
+    /// * `container_height` - Height of the visible viewport in pixels
+    /// * `scroll_offset_px` - Current scroll offset from the top in pixels
+    /// * `colors` - Pre-computed colors for rendering
+    pub fn from_pixels(
+        content_height: f32,
+        container_height: f32,
+        scroll_offset_px: f32,
+        colors: ScrollbarColors,
+    ) -> Self {
+        Self {
+            total_items: 0,
+            visible_items: 0,
+            scroll_offset: 0,
+            colors,
+            container_height: Some(container_height),
+            is_visible: None,
+            content_height: Some(content_height),
+            scroll_offset_px: Some(scroll_offset_px),
         }
     }
 
@@ -156,19 +196,39 @@ impl Scrollbar {
 
     /// Check if scrollbar should be visible (content overflows)
     fn should_show(&self) -> bool {
+        if let (Some(content), Some(container)) = (self.content_height, self.container_height) {
+            return content > container + 0.5;
+        }
         self.total_items > self.visible_items && self.total_items > 0
     }
 
-    /// Calculate thumb height as a ratio of visible/total items
+    /// Calculate thumb height as a ratio of visible to total content
+    ///
+    /// Uses pixel measurements when available, item counts otherwise.
     fn thumb_height_ratio(&self) -> f32 {
+        if let (Some(content), Some(container)) = (self.content_height, self.container_height) {
+            if content <= 0.0 {
+                return 1.0;
+            }
+            return (container / content).clamp(0.05, 1.0);
+        }
         if self.total_items == 0 {
             return 1.0;
         }
         (self.visible_items as f32 / self.total_items as f32).clamp(0.05, 1.0)
     }
 
-    /// Calculate thumb position as a ratio of scroll_offset/(total-visible)
+    /// Calculate thumb position as a ratio of scroll offset to scrollable range
+    ///
+    /// Uses pixel measurements when available, item counts otherwise.
     fn thumb_position_ratio(&self) -> f32 {
+        if let (Some(content), Some(container)) = (self.content_height, self.container_height) {
+            let max_offset = content - container;
+            if max_offset <= 0.0 {
+                return 0.0;
+            }
+            return (self.scroll_offset_px.unwrap_or(0.0) / max_offset).clamp(0.0, 1.0);
+        }
         if self.total_items <= self.visible_items {
             return 0.0;
         }
@@ -306,6 +366,9 @@ impl RenderOnce for Scrollbar {
 // - Scrollbar: builder pattern with .container_height()
 //
 // Logic verification (manual):
-// - should_show(): returns true when total_items > visible_items && total_items > 0
-// - thumb_height_ratio(): returns visible_items / total_items, clamped to [0.05, 1.0]
-// - thumb_position_ratio(): returns scroll_offset / max_offset, clamped to [0.0, 1.0]
+// - should_show(): returns true when total_items > visible_items && total_items > 0,
+//   or (pixel mode) when content_height exceeds container_height
+// - thumb_height_ratio(): returns visible_items / total_items (pixel mode:
+//   container_height / content_height), clamped to [0.05, 1.0]
+// - thumb_position_ratio(): returns scroll_offset / max_offset (pixel mode:
+//   scroll_offset_px / (content_height - container_height)), clamped to [0.0, 1.0]
@@ -7,7 +7,11 @@
 //! - Standard cursor movement (arrows, home/end)
 //!
 
-use gpui::{ClipboardItem, Context, Render};
+use crate::panel::{CURSOR_GAP_X, CURSOR_HEIGHT_LG, CURSOR_MARGIN_Y, CURSOR_WIDTH};
+use gpui::{
+    div, prelude::*, px, rgb, rgba, App, ClipboardItem, Context, Render, RenderOnce, SharedString,
+    Window,
+};
 
 /// Selection in a single-line text input
 /// anchor = where selection started, cursor = current position
@@ -55,6 +59,9 @@ pub struct TextInputState {
     text: String,
     /// Selection state (anchor and cursor positions)
     selection: TextSelection,
+    /// In-progress IME composition (marked text) as a character range.
+    /// None outside of composition. See the "IME Composition" section.
+    marked: Option<(usize, usize)>,
 }
 
 impl Default for TextInputState {
@@ -68,6 +75,7 @@ impl TextInputState {
         Self {
             text: String::new(),
             selection: TextSelection::caret(0),
+            marked: None,
         }
     }
 
@@ -78,6 +86,7 @@ impl TextInputState {
         Self {
             text,
             selection: TextSelection::caret(len), // Cursor at end
+            marked: None,
         }
     }
 
@@ -129,17 +138,23 @@ impl TextInputState {
         self.text = text.into();
         let len = self.text.chars().count();
         self.selection = TextSelection::caret(len.min(self.selection.cursor));
+        self.marked = None;
     }
 
     pub fn clear(&mut self) {
         self.text.clear();
         self.selection = TextSelection::caret(0);
+        self.marked = None;
     }
 
     // === Text Manipulation ===
+    //
+    // Direct edits cancel any in-progress composition: if the user types or
+    // deletes mid-composition the IME session is already over.
 
     /// Insert a character at cursor, replacing selection if any
     pub fn insert_char(&mut self, ch: char) {
+        self.marked = None;
         self.delete_selection();
         let byte_pos = self.char_to_byte(self.selection.cursor);
         self.text.insert(byte_pos, ch);
@@ -148,6 +163,7 @@ impl TextInputState {
 
     /// Insert a string at cursor, replacing selection if any
     pub fn insert_str(&mut self, s: &str) {
+        self.marked = None;
         self.delete_selection();
         let byte_pos = self.char_to_byte(self.selection.cursor);
         self.text.insert_str(byte_pos, s);
@@ -157,6 +173,7 @@ impl TextInputState {
 
     /// Delete selection, or character before cursor if no selection
     pub fn backspace(&mut self) {
+        self.marked = None;
         if !self.selection.is_empty() {
             self.delete_selection();
         } else if self.selection.cursor > 0 {
@@ -170,6 +187,7 @@ impl TextInputState {
 
     /// Delete selection, or character after cursor if no selection
     pub fn delete(&mut self) {
+        self.marked = None;
         if !self.selection.is_empty() {
             self.delete_selection();
         } else {
@@ -277,6 +295,113 @@ impl TextInputState {
         };
     }
 
+    // === IME Composition (marked text) ===
+    //
+    // These back the OS input-method protocol (NSTextInputClient on macOS).
+    // Dead keys and CJK IMEs compose through `replace_and_mark_text_in_range`
+    // (provisional text, shown in place) and commit the final text through
+    // `replace_text_in_range`. Ranges here are character indices; the
+    // `EntityInputHandler` bridge converts from the protocol's UTF-16 offsets.
+
+    /// The in-progress composition range in character indices, if any
+    pub fn marked_range(&self) -> Option<(usize, usize)> {
+        self.marked
+    }
+
+    /// Drop the composition range without touching the text
+    pub fn unmark(&mut self) {
+        self.marked = None;
+    }
+
+    /// Text within a character range (for `textForRange` queries)
+    pub fn text_for_range(&self, range: (usize, usize)) -> String {
+        let len = self.text.chars().count();
+        let start = range.0.min(len);
+        let end = range.1.min(len).max(start);
+        let byte_start = self.char_to_byte(start);
+        let byte_end = self.char_to_byte(end);
+        self.text[byte_start..byte_end].to_string()
+    }
+
+    /// Commit text into a character range (IME commit / plain insertion)
+    ///
+    /// With no explicit range, replaces the marked range if composing,
+    /// otherwise the current selection. The caret lands after the new text
+    /// and composition ends.
+    pub fn replace_text_in_range(&mut self, range: Option<(usize, usize)>, text: &str) {
+        let (start, end) = self.replace_target(range);
+        self.splice(start, end, text);
+        self.selection = TextSelection::caret(start + text.chars().count());
+        self.marked = None;
+    }
+
+    /// Replace a character range with provisional composition text and mark it
+    ///
+    /// `selected` is the IME's requested selection relative to `text` (in
+    /// character indices); None places the caret after the composition.
+    pub fn replace_and_mark_text_in_range(
+        &mut self,
+        range: Option<(usize, usize)>,
+        text: &str,
+        selected: Option<(usize, usize)>,
+    ) {
+        let (start, end) = self.replace_target(range);
+        self.splice(start, end, text);
+        let text_len = text.chars().count();
+        self.marked = if text_len == 0 {
+            None
+        } else {
+            Some((start, start + text_len))
+        };
+        let (sel_start, sel_end) = selected.unwrap_or((text_len, text_len));
+        self.selection = TextSelection {
+            anchor: start + sel_start.min(text_len),
+            cursor: start + sel_end.min(text_len),
+        };
+    }
+
+    /// Resolve the range a replacement applies to: explicit range first,
+    /// then the marked range, then the selection
+    fn replace_target(&self, range: Option<(usize, usize)>) -> (usize, usize) {
+        if let Some((start, end)) = range {
+            let len = self.text.chars().count();
+            (start.min(len), end.min(len).max(start.min(len)))
+        } else if let Some(marked) = self.marked {
+            marked
+        } else {
+            self.selection.range()
+        }
+    }
+
+    /// Replace a character range with new text (no selection/marked updates)
+    fn splice(&mut self, start: usize, end: usize, text: &str) {
+        let byte_start = self.char_to_byte(start);
+        let byte_end = self.char_to_byte(end);
+        self.text.replace_range(byte_start..byte_end, text);
+    }
+
+    /// Convert a UTF-16 offset (what the OS input protocol speaks) to a
+    /// character index, clamping past-the-end offsets
+    pub fn utf16_to_char(&self, offset: usize) -> usize {
+        let mut utf16 = 0;
+        for (ix, ch) in self.text.chars().enumerate() {
+            if utf16 >= offset {
+                return ix;
+            }
+            utf16 += ch.len_utf16();
+        }
+        self.text.chars().count()
+    }
+
+    /// Convert a character index to a UTF-16 offset
+    pub fn char_to_utf16(&self, char_idx: usize) -> usize {
+        self.text
+            .chars()
+            .take(char_idx)
+            .map(|ch| ch.len_utf16())
+            .sum()
+    }
+
     // === Clipboard Operations ===
 
     /// Copy selected text to clipboard
@@ -485,6 +610,184 @@ impl TextInputState {
     }
 }
 
+/// TextInput - Visual text input built on top of TextInputState
+///
+/// Renders the shared before-cursor | cursor | after-cursor layout with
+/// selection highlight, optional placeholder, secret masking, and blink
+/// support so prompts don't re-implement the same cursor markup.
+///
+/// Two construction modes:
+/// - `from_state` - full editing display backed by a TextInputState
+///   (cursor position, selection, masking)
+/// - `from_text` - plain string display with the cursor pinned at the end
+///   (used by headers that only track a String)
+#[derive(IntoElement)]
+pub struct TextInput {
+    /// Display text (already masked when constructed from a secret state)
+    text: String,
+    /// Cursor position in chars
+    cursor: usize,
+    /// Active selection range in chars (start < end), if any
+    selection: Option<(usize, usize)>,
+    /// Placeholder shown when the text is empty
+    placeholder: Option<SharedString>,
+    /// Whether the cursor block is filled (blink state); space is always reserved
+    cursor_visible: bool,
+    /// Text color as 0xRRGGBB
+    text_color: u32,
+    /// Placeholder color as 0xRRGGBB (defaults to text_color)
+    placeholder_color: Option<u32>,
+    /// Accent color as 0xRRGGBB; selection bg uses it at 0x60 alpha
+    accent_color: u32,
+    /// Cursor block height in pixels
+    cursor_height: f32,
+}
+
+impl TextInput {
+    /// Build from a TextInputState, rendering cursor and selection.
+    /// Pass `masked: true` to display bullets instead of the raw text (the
+    /// cursor/selection indices are unaffected since the mask is 1:1 per char).
+    pub fn from_state(state: &TextInputState, masked: bool) -> Self {
+        let selection = if state.has_selection() {
+            Some(state.selection().range())
+        } else {
+            None
+        };
+        TextInput {
+            text: state.display_text(masked),
+            cursor: state.cursor(),
+            selection,
+            placeholder: None,
+            cursor_visible: true,
+            text_color: 0xffffff,
+            placeholder_color: None,
+            accent_color: 0xffffff,
+            cursor_height: CURSOR_HEIGHT_LG,
+        }
+    }
+
+    /// Build from a plain string with the cursor at the end
+    pub fn from_text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let cursor = text.chars().count();
+        TextInput {
+            text,
+            cursor,
+            selection: None,
+            placeholder: None,
+            cursor_visible: true,
+            text_color: 0xffffff,
+            placeholder_color: None,
+            accent_color: 0xffffff,
+            cursor_height: CURSOR_HEIGHT_LG,
+        }
+    }
+
+    /// Placeholder text shown when empty
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Blink state; when false the cursor block keeps its space but is unfilled
+    pub fn cursor_visible(mut self, visible: bool) -> Self {
+        self.cursor_visible = visible;
+        self
+    }
+
+    /// Text and cursor color (0xRRGGBB)
+    pub fn text_color(mut self, color: u32) -> Self {
+        self.text_color = color;
+        self
+    }
+
+    /// Placeholder color (0xRRGGBB); defaults to the text color
+    pub fn placeholder_color(mut self, color: u32) -> Self {
+        self.placeholder_color = Some(color);
+        self
+    }
+
+    /// Accent color for the selection highlight (0xRRGGBB)
+    pub fn accent_color(mut self, color: u32) -> Self {
+        self.accent_color = color;
+        self
+    }
+
+    /// Override the cursor block height (defaults to CURSOR_HEIGHT_LG)
+    pub fn cursor_height(mut self, height: f32) -> Self {
+        self.cursor_height = height;
+        self
+    }
+
+    /// The cursor block element; space is always reserved to avoid layout
+    /// shift during blink, only the fill toggles
+    fn cursor_block(&self) -> gpui::Div {
+        div()
+            .w(px(CURSOR_WIDTH))
+            .h(px(self.cursor_height))
+            .my(px(CURSOR_MARGIN_Y))
+            .when(self.cursor_visible, |d| d.bg(rgb(self.text_color)))
+    }
+}
+
+impl RenderOnce for TextInput {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let container = div().flex().flex_row().items_center().overflow_x_hidden();
+
+        if self.text.is_empty() {
+            // Empty: cursor first, then the placeholder pulled back by the
+            // cursor's width so placeholder and typed text share the same
+            // starting x-position (no "jump" when typing begins)
+            let placeholder_color = self.placeholder_color.unwrap_or(self.text_color);
+            let placeholder = self.placeholder.clone();
+            return container
+                .text_color(rgb(placeholder_color))
+                .child(self.cursor_block().mr(px(CURSOR_GAP_X)))
+                .when_some(placeholder, |d, placeholder| {
+                    d.child(
+                        div()
+                            .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
+                            .child(placeholder),
+                    )
+                });
+        }
+
+        let chars: Vec<char> = self.text.chars().collect();
+        let container = container.text_color(rgb(self.text_color));
+
+        if let Some((start, end)) = self.selection {
+            // With selection: before | highlighted selection | after (no cursor)
+            let before: String = chars[..start].iter().collect();
+            let selected: String = chars[start..end].iter().collect();
+            let after: String = chars[end..].iter().collect();
+
+            container
+                .when(!before.is_empty(), |d| d.child(div().child(before)))
+                .child(
+                    div()
+                        .bg(rgba((self.accent_color << 8) | 0x60))
+                        .text_color(rgb(0xffffff))
+                        .child(selected),
+                )
+                .when(!after.is_empty(), |d| d.child(div().child(after)))
+        } else {
+            // No selection: before cursor | cursor | after cursor
+            let cursor_pos = self.cursor.min(chars.len());
+            let before: String = chars[..cursor_pos].iter().collect();
+            let after: String = chars[cursor_pos..].iter().collect();
+            let at_end = after.is_empty();
+            let cursor_block = self.cursor_block();
+
+            container
+                .when(!before.is_empty(), |d| d.child(div().child(before)))
+                // Small gap before an end-of-text cursor; mid-string cursors
+                // sit flush between the surrounding characters
+                .child(cursor_block.when(at_end && !before.is_empty(), |d| d.ml(px(CURSOR_GAP_X))))
+                .when(!after.is_empty(), |d| d.child(div().child(after)))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -580,6 +883,55 @@ mod tests {
         assert_eq!(input.cursor(), 0); // At start
     }
 
+    #[test]
+    fn test_dead_key_composition() {
+        // Option+E then E on a US keyboard: mark "´", then commit "é"
+        let mut input = TextInputState::with_text("caf");
+        input.replace_and_mark_text_in_range(None, "´", None);
+        assert_eq!(input.text(), "caf´");
+        assert_eq!(input.marked_range(), Some((3, 4)));
+        input.replace_text_in_range(None, "é");
+        assert_eq!(input.text(), "café");
+        assert_eq!(input.marked_range(), None);
+        assert_eq!(input.cursor(), 4);
+    }
+
+    #[test]
+    fn test_cjk_incremental_composition() {
+        // Each keystroke replaces the previous marked text, not the document
+        let mut input = TextInputState::new();
+        input.replace_and_mark_text_in_range(None, "に", None);
+        input.replace_and_mark_text_in_range(None, "にほ", None);
+        input.replace_and_mark_text_in_range(None, "にほん", None);
+        assert_eq!(input.text(), "にほん");
+        assert_eq!(input.marked_range(), Some((0, 3)));
+        input.replace_text_in_range(None, "日本");
+        assert_eq!(input.text(), "日本");
+        assert_eq!(input.marked_range(), None);
+    }
+
+    #[test]
+    fn test_direct_edit_cancels_composition() {
+        let mut input = TextInputState::new();
+        input.replace_and_mark_text_in_range(None, "あ", None);
+        assert!(input.marked_range().is_some());
+        input.backspace();
+        assert_eq!(input.marked_range(), None);
+    }
+
+    #[test]
+    fn test_utf16_offset_conversion() {
+        // "a𝄞b": the musical symbol is one char but two UTF-16 units
+        let input = TextInputState::with_text("a𝄞b");
+        assert_eq!(input.char_to_utf16(0), 0);
+        assert_eq!(input.char_to_utf16(1), 1);
+        assert_eq!(input.char_to_utf16(2), 3);
+        assert_eq!(input.utf16_to_char(3), 2);
+        assert_eq!(input.utf16_to_char(1), 1);
+        // Past-the-end offsets clamp
+        assert_eq!(input.utf16_to_char(99), 3);
+    }
+
     #[test]
     fn test_unicode() {
         let mut input = TextInputState::with_text("héllo");
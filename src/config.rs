@@ -31,6 +31,10 @@ pub const DEFAULT_FRECENCY_HALF_LIFE_DAYS: f64 = 7.0;
 pub const DEFAULT_FRECENCY_MAX_RECENT_ITEMS: usize = 10;
 pub const DEFAULT_FRECENCY_ENABLED: bool = true;
 
+/// Default URL template for the web search fallback ({query} is replaced with
+/// the percent-encoded filter text)
+pub const DEFAULT_FALLBACK_WEB_SEARCH_URL: &str = "https://www.google.com/search?q={query}";
+
 /// Commands that require confirmation before execution by default.
 /// Users can override this behavior per-command in config.ts using `confirmationRequired`.
 pub const DEFAULT_CONFIRMATION_COMMANDS: &[&str] = &[
@@ -40,6 +44,8 @@ pub const DEFAULT_CONFIRMATION_COMMANDS: &[&str] = &[
     "builtin-empty-trash",
     "builtin-sleep",
     "builtin-quit-script-kit",
+    "builtin-wifi-off",          // Drops the network connection
+    "builtin-bluetooth-off",     // Disconnects paired devices
     "builtin-test-confirmation", // Dev test item
 ];
 
@@ -56,6 +62,16 @@ pub struct BuiltInConfig {
     /// Enable window switcher built-in (default: true)
     #[serde(default = "default_window_switcher")]
     pub window_switcher: bool,
+    /// App names / bundle id patterns whose copies are never stored in
+    /// clipboard history (password managers, banking apps, ...).
+    /// Matched case-insensitively as substrings (default: empty)
+    #[serde(default)]
+    pub clipboard_excluded_apps: Vec<String>,
+    /// App names / bundle id patterns where text expansion is disabled
+    /// (e.g. terminals or editors with their own snippet systems).
+    /// Matched case-insensitively as substrings (default: empty)
+    #[serde(default)]
+    pub expand_disabled_apps: Vec<String>,
 }
 
 fn default_clipboard_history() -> bool {
@@ -74,6 +90,8 @@ impl Default for BuiltInConfig {
             clipboard_history: DEFAULT_CLIPBOARD_HISTORY,
             app_launcher: DEFAULT_APP_LAUNCHER,
             window_switcher: DEFAULT_WINDOW_SWITCHER,
+            clipboard_excluded_apps: Vec::new(),
+            expand_disabled_apps: Vec::new(),
         }
     }
 }
@@ -85,6 +103,11 @@ pub struct ProcessLimits {
     /// Maximum memory usage in MB (None = no limit)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_memory_mb: Option<u64>,
+    /// Default niceness for spawned script processes; positive values
+    /// lower priority so runaway scripts don't starve the UI. A script's
+    /// own `// Nice:` metadata overrides this. (None = inherit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
     /// Maximum runtime in seconds (None = no limit)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_runtime_seconds: Option<u64>,
@@ -102,11 +125,27 @@ impl Default for ProcessLimits {
         ProcessLimits {
             max_memory_mb: None,
             max_runtime_seconds: None,
+            nice: None,
             health_check_interval_ms: DEFAULT_HEALTH_CHECK_INTERVAL_MS,
         }
     }
 }
 
+/// Quiet hours: a daily window during which background-script toasts and
+/// HUDs are queued into a "while you were away" digest instead of shown
+/// (see the `quiet_hours` module). Only active when both times are set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietHoursConfig {
+    /// Window start as 24-hour "HH:MM", e.g. "22:00"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    /// Window end as 24-hour "HH:MM"; an end at or before the start wraps
+    /// past midnight (22:00-08:00 covers the night)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+}
+
 /// Configuration for frecency scoring (recent items ranking)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -144,6 +183,99 @@ impl Default for FrecencyConfig {
     }
 }
 
+/// Configuration for main list sections (order and display names)
+///
+/// Canonical section ids: RECENT, SCRIPTS, SCRIPTLETS, COMMANDS, APPS.
+/// Collapsed state is runtime state and lives in `~/.sk/kit/sections.json`,
+/// not here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionsConfig {
+    /// Section ids in display order; sections not listed keep their default
+    /// position after the listed ones (default: built-in order)
+    #[serde(default)]
+    pub order: Vec<String>,
+    /// Display-name overrides keyed by canonical section id
+    /// (e.g., `{ "SCRIPTS": "My Scripts" }`)
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+    /// Maximum items shown per section before a "Show more…" row; 0 disables
+    /// the cap. Keeps initial renders fast when a section (typically APPS)
+    /// has hundreds of entries.
+    #[serde(default = "default_max_section_items")]
+    pub max_section_items: usize,
+}
+
+/// Default per-section item cap before a "Show more…" row appears
+const DEFAULT_MAX_SECTION_ITEMS: usize = 50;
+
+fn default_max_section_items() -> usize {
+    DEFAULT_MAX_SECTION_ITEMS
+}
+
+impl Default for SectionsConfig {
+    fn default() -> Self {
+        Self {
+            order: Vec::new(),
+            names: HashMap::new(),
+            max_section_items: DEFAULT_MAX_SECTION_ITEMS,
+        }
+    }
+}
+
+/// Per-context design variant overrides (the `designOverrides` config key)
+///
+/// Each field names a design variant (e.g. "minimal", "retroTerminal") to use
+/// in that context instead of the globally selected design. Contexts without
+/// an override follow the global design.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DesignOverrides {
+    /// Design for the main script list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_list: Option<String>,
+    /// Design for script-driven prompts (arg, select, editor, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompts: Option<String>,
+}
+
+/// Configuration for fallback results shown when the filter matches nothing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FallbacksConfig {
+    /// Whether the "Search the web" fallback is shown (default: true)
+    #[serde(default = "default_fallback_enabled")]
+    pub web_search: bool,
+    /// Whether the "Create script" fallback is shown (default: true)
+    #[serde(default = "default_fallback_enabled")]
+    pub create_script: bool,
+    /// Whether the "Search files" fallback is shown (default: true)
+    #[serde(default = "default_fallback_enabled")]
+    pub file_search: bool,
+    /// URL template for the web search fallback; {query} is replaced with the
+    /// percent-encoded filter text (default: Google)
+    #[serde(default = "default_fallback_web_search_url")]
+    pub web_search_url: String,
+}
+
+fn default_fallback_enabled() -> bool {
+    true
+}
+fn default_fallback_web_search_url() -> String {
+    DEFAULT_FALLBACK_WEB_SEARCH_URL.to_string()
+}
+
+impl Default for FallbacksConfig {
+    fn default() -> Self {
+        FallbacksConfig {
+            web_search: true,
+            create_script: true,
+            file_search: true,
+            web_search_url: DEFAULT_FALLBACK_WEB_SEARCH_URL.to_string(),
+        }
+    }
+}
+
 /// Content padding configuration for prompts (terminal, editor, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContentPadding {
@@ -216,6 +348,47 @@ pub fn command_id_to_deeplink(command_id: &str) -> String {
     format!("kit://commands/{}", command_id)
 }
 
+/// Settings for the Create Issue builtin (Jira or Linear)
+///
+/// `provider` is "jira" or "linear". For Jira, `base_url` is the site URL
+/// (e.g. https://acme.atlassian.net), `project` the project key, and
+/// `email` + `token` the API credentials. For Linear, `project` is the team
+/// ID and `token` a personal API key; `base_url` and `email` are unused.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueTrackerConfig {
+    #[serde(default)]
+    pub provider: String,
+    #[serde(default)]
+    pub project: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub token: String,
+}
+
+/// A named environment profile scripts opt into via `// Profile: work`
+///
+/// Resolved by the executor at spawn time: `env` entries are set on the
+/// script process, `path` directories are prepended to PATH, and `cwd`
+/// (tilde-expanded) becomes the working directory. Keeps client-specific
+/// credentials out of individual scripts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvProfileConfig {
+    /// Environment variables set for the script
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<HashMap<String, String>>,
+    /// Directories prepended to PATH
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<Vec<String>>,
+    /// Working directory the script starts in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub hotkey: HotkeyConfig,
@@ -243,6 +416,40 @@ pub struct Config {
     /// UI scale factor (1.0 = 100%)
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "uiScale")]
     pub ui_scale: Option<f32>,
+    /// Explicit font size for list items (in pixels, overrides the design's)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "listFontSize"
+    )]
+    pub list_font_size: Option<f32>,
+    /// Soft-wrap long lines in the editor prompt instead of scrolling
+    /// horizontally (default: false)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "editorSoftWrap"
+    )]
+    pub editor_soft_wrap: Option<bool>,
+    /// Soft-wrap long code lines in the preview panel instead of clipping
+    /// them (default: false)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "previewSoftWrap"
+    )]
+    pub preview_soft_wrap: Option<bool>,
+    /// Default design variant name, e.g. "minimal" or "retroTerminal"
+    /// (Cmd+1 cycling still works and wins once the user has cycled)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub design: Option<String>,
+    /// Per-context design variant overrides (main list vs prompts)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "designOverrides"
+    )]
+    pub design_overrides: Option<DesignOverrides>,
     /// Built-in features configuration (clipboard history, app launcher, etc.)
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "builtIns")]
     pub built_ins: Option<BuiltInConfig>,
@@ -253,6 +460,13 @@ pub struct Config {
         rename = "processLimits"
     )]
     pub process_limits: Option<ProcessLimits>,
+    /// Quiet hours window during which background notifications are queued
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "quietHours"
+    )]
+    pub quiet_hours: Option<QuietHoursConfig>,
     /// Maximum text length for clipboard history entries (bytes). 0 = no limit.
     #[serde(
         default,
@@ -263,6 +477,12 @@ pub struct Config {
     /// Frecency configuration for recent items ranking
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub frecency: Option<FrecencyConfig>,
+    /// Fallback results configuration (web search, create script, file search)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallbacks: Option<FallbacksConfig>,
+    /// Main list section order and display names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sections: Option<SectionsConfig>,
     /// Hotkey for opening Notes window (default: Cmd+Shift+N)
     #[serde(
         default,
@@ -273,9 +493,67 @@ pub struct Config {
     /// Hotkey for opening AI Chat window (default: Cmd+Shift+Space)
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "aiHotkey")]
     pub ai_hotkey: Option<HotkeyConfig>,
+    /// Summon hotkey that opens the window directly into Clipboard History
+    /// (no default - opt-in)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "clipboardHistoryHotkey"
+    )]
+    pub clipboard_history_hotkey: Option<HotkeyConfig>,
+    /// Summon hotkey that opens the window directly into the File Search
+    /// builtin (no default - opt-in)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "fileSearchHotkey"
+    )]
+    pub file_search_hotkey: Option<HotkeyConfig>,
+    /// Summon hotkey that re-runs the last used script (no default - opt-in)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "rerunLastScriptHotkey"
+    )]
+    pub rerun_last_script_hotkey: Option<HotkeyConfig>,
     /// Per-command configuration overrides (shortcuts, visibility)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub commands: Option<HashMap<String, CommandConfig>>,
+    /// Whether to check GitHub releases for updates at startup (default: true)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "autoUpdate"
+    )]
+    pub auto_update: Option<bool>,
+    /// Custom environment variables injected into every script process
+    /// (alongside the SK_* variables the executor always sets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<HashMap<String, String>>,
+    /// UI locale tag for chrome strings, e.g. "en" or "es" (default: "en")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Whether to start the MCP server for AI agent integration (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mcpServer")]
+    pub mcp_server: Option<bool>,
+    /// Issue tracker integration for the Create Issue builtin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "issueTracker"
+    )]
+    pub issue_tracker: Option<IssueTrackerConfig>,
+    /// Favorite timezones for the World Clock builtin (IANA names or
+    /// abbreviations like "PT"); shown for a bare "time" query
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "worldClockFavorites"
+    )]
+    pub world_clock_favorites: Option<Vec<String>>,
+    /// Named environment profiles for `// Profile: name` scripts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profiles: Option<HashMap<String, EnvProfileConfig>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -315,13 +593,31 @@ impl Default for Config {
             editor_font_size: None,   // Will use DEFAULT_EDITOR_FONT_SIZE via getter
             terminal_font_size: None, // Will use DEFAULT_TERMINAL_FONT_SIZE via getter
             ui_scale: None,           // Will use DEFAULT_UI_SCALE via getter
+            list_font_size: None,     // Will use each design's own size
+            editor_soft_wrap: None,   // Editor scrolls long lines via getter default
+            preview_soft_wrap: None,  // Preview clips long lines via getter default
+            design: None,             // Will use DesignVariant::default() or saved choice
+            design_overrides: None,   // No per-context design overrides by default
             built_ins: None,          // Will use BuiltInConfig::default() via getter
             process_limits: None,     // Will use ProcessLimits::default() via getter
+            quiet_hours: None,        // Quiet hours disabled unless configured
             clipboard_history_max_text_length: None, // Will use default via getter
             frecency: None,           // Will use FrecencyConfig::default() via getter
+            fallbacks: None,          // Will use FallbacksConfig::default() via getter
+            sections: None,           // Will use SectionsConfig::default() via getter
             notes_hotkey: None,       // Will use HotkeyConfig::default_notes_hotkey() via getter
             ai_hotkey: None,          // Will use HotkeyConfig::default_ai_hotkey() via getter
+            clipboard_history_hotkey: None, // Summon hotkeys are opt-in
+            file_search_hotkey: None, // Summon hotkeys are opt-in
+            rerun_last_script_hotkey: None, // Summon hotkeys are opt-in
             commands: None,           // No per-command overrides by default
+            auto_update: None,        // Update checks enabled via getter default
+            env: None,                // No custom script env by default
+            locale: None,             // English UI strings via getter default
+            mcp_server: None,         // MCP server stays off unless opted in
+            issue_tracker: None,      // Create Issue builtin hidden until configured
+            world_clock_favorites: None, // World Clock falls back to built-in favorites
+            profiles: None,           // No named environment profiles by default
         }
     }
 }
@@ -362,12 +658,55 @@ impl Config {
         self.ui_scale.unwrap_or(DEFAULT_UI_SCALE)
     }
 
+    /// Returns the explicit list item font size, if configured
+    #[allow(dead_code)] // Applied to design tokens at startup
+    pub fn get_list_font_size(&self) -> Option<f32> {
+        self.list_font_size
+    }
+
+    /// Returns whether the editor prompt soft-wraps long lines (default: false)
+    #[allow(dead_code)] // Used by EditorPrompt on init
+    pub fn get_editor_soft_wrap(&self) -> bool {
+        self.editor_soft_wrap.unwrap_or(false)
+    }
+
+    /// Returns whether the code preview panel soft-wraps long lines (default: false)
+    pub fn get_preview_soft_wrap(&self) -> bool {
+        self.preview_soft_wrap.unwrap_or(false)
+    }
+
+    /// Returns the configured default design variant name, if set
+    pub fn get_design(&self) -> Option<String> {
+        self.design.clone()
+    }
+
+    /// Returns the per-context design overrides, or defaults (no overrides)
+    pub fn get_design_overrides(&self) -> DesignOverrides {
+        self.design_overrides.clone().unwrap_or_default()
+    }
+
     /// Returns the built-in features configuration, or defaults if not configured
     #[allow(dead_code)] // Will be used by builtins module
     pub fn get_builtins(&self) -> BuiltInConfig {
         self.built_ins.clone().unwrap_or_default()
     }
 
+    /// Returns app name / bundle id patterns excluded from clipboard history
+    pub fn get_clipboard_excluded_apps(&self) -> Vec<String> {
+        self.built_ins
+            .as_ref()
+            .map(|b| b.clipboard_excluded_apps.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns app name / bundle id patterns where text expansion is disabled
+    pub fn get_expand_disabled_apps(&self) -> Vec<String> {
+        self.built_ins
+            .as_ref()
+            .map(|b| b.expand_disabled_apps.clone())
+            .unwrap_or_default()
+    }
+
     /// Returns max clipboard history text length (bytes), or default if not configured
     #[allow(dead_code)] // Used for clipboard history limits
     pub fn get_clipboard_history_max_text_length(&self) -> usize {
@@ -381,11 +720,26 @@ impl Config {
         self.process_limits.clone().unwrap_or_default()
     }
 
+    /// Returns the quiet hours configuration, or defaults (disabled) if not configured
+    pub fn get_quiet_hours(&self) -> QuietHoursConfig {
+        self.quiet_hours.clone().unwrap_or_default()
+    }
+
     /// Returns the frecency configuration, or defaults if not configured
     pub fn get_frecency(&self) -> FrecencyConfig {
         self.frecency.clone().unwrap_or_default()
     }
 
+    /// Returns the fallback results configuration, or defaults if not configured
+    pub fn get_fallbacks(&self) -> FallbacksConfig {
+        self.fallbacks.clone().unwrap_or_default()
+    }
+
+    /// Returns the section order/name configuration, or defaults if not configured
+    pub fn get_sections(&self) -> SectionsConfig {
+        self.sections.clone().unwrap_or_default()
+    }
+
     /// Returns the notes hotkey configuration, or default (Cmd+Shift+N) if not configured
     #[allow(dead_code)]
     pub fn get_notes_hotkey(&self) -> HotkeyConfig {
@@ -416,6 +770,24 @@ impl Config {
             .unwrap_or(false)
     }
 
+    /// Returns whether startup update checks are enabled (default: true)
+    #[allow(dead_code)] // Used by the updater at startup
+    pub fn get_auto_update(&self) -> bool {
+        self.auto_update.unwrap_or(true)
+    }
+
+    /// Returns the configured UI locale tag (default: "en")
+    #[allow(dead_code)] // Applied at startup and on config reload
+    pub fn get_locale(&self) -> String {
+        self.locale.clone().unwrap_or_else(|| "en".to_string())
+    }
+
+    /// Returns whether the MCP server should start (opt-in, default: false)
+    #[allow(dead_code)] // Checked at startup in main()
+    pub fn get_mcp_server_enabled(&self) -> bool {
+        self.mcp_server.unwrap_or(false)
+    }
+
     /// Get the shortcut for a command, if configured.
     #[allow(dead_code)]
     pub fn get_command_shortcut(&self, command_id: &str) -> Option<&HotkeyConfig> {
@@ -439,6 +811,12 @@ impl Config {
         // Fall back to defaults
         DEFAULT_CONFIRMATION_COMMANDS.contains(&command_id)
     }
+
+    /// Look up a named environment profile (`// Profile: name`)
+    #[allow(dead_code)]
+    pub fn get_profile(&self, name: &str) -> Option<&EnvProfileConfig> {
+        self.profiles.as_ref()?.get(name)
+    }
 }
 
 #[instrument(name = "load_config")]
@@ -579,6 +957,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -621,6 +1000,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -645,6 +1025,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -669,6 +1050,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -698,6 +1080,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -794,6 +1177,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -824,6 +1208,7 @@ mod tests {
                 ui_scale: None,
                 built_ins: None,
                 process_limits: None,
+                quiet_hours: None,
                 clipboard_history_max_text_length: None,
                 frecency: None,
                 notes_hotkey: None,
@@ -853,6 +1238,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -882,6 +1268,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -912,6 +1299,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -944,6 +1332,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -982,6 +1371,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1020,6 +1410,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1127,6 +1518,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1161,6 +1553,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1192,6 +1585,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1223,6 +1617,7 @@ mod tests {
             ui_scale: Some(1.5),
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1315,6 +1710,7 @@ mod tests {
             ui_scale: Some(1.5),
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1356,6 +1752,8 @@ mod tests {
             clipboard_history: true,
             app_launcher: false,
             window_switcher: true,
+            clipboard_excluded_apps: Vec::new(),
+            expand_disabled_apps: Vec::new(),
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -1413,8 +1811,11 @@ mod tests {
                 clipboard_history: true,
                 app_launcher: false,
                 window_switcher: true,
+                clipboard_excluded_apps: Vec::new(),
+                expand_disabled_apps: Vec::new(),
             }),
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1507,6 +1908,7 @@ mod tests {
             ui_scale: None,
             built_ins: Some(BuiltInConfig::default()),
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1529,6 +1931,8 @@ mod tests {
             clipboard_history: false,
             app_launcher: true,
             window_switcher: true,
+            clipboard_excluded_apps: Vec::new(),
+            expand_disabled_apps: Vec::new(),
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -1545,6 +1949,7 @@ mod tests {
         let limits = ProcessLimits::default();
         assert_eq!(limits.max_memory_mb, None);
         assert_eq!(limits.max_runtime_seconds, None);
+        assert_eq!(limits.nice, None);
         assert_eq!(
             limits.health_check_interval_ms,
             DEFAULT_HEALTH_CHECK_INTERVAL_MS
@@ -1561,6 +1966,7 @@ mod tests {
         let limits = ProcessLimits {
             max_memory_mb: Some(512),
             max_runtime_seconds: Some(300),
+            nice: Some(10),
             health_check_interval_ms: 3000,
         };
 
@@ -1605,6 +2011,55 @@ mod tests {
         ); // Default
     }
 
+    #[test]
+    fn test_quiet_hours_deserialization_camel_case() {
+        let json = r#"{
+            "hotkey": {
+                "modifiers": ["meta"],
+                "key": "Semicolon"
+            },
+            "quietHours": {"start": "22:00", "end": "08:00"}
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        let quiet = config.get_quiet_hours();
+        assert_eq!(quiet.start.as_deref(), Some("22:00"));
+        assert_eq!(quiet.end.as_deref(), Some("08:00"));
+    }
+
+    #[test]
+    fn test_quiet_hours_default_disabled() {
+        let config = Config::default();
+        let quiet = config.get_quiet_hours();
+        assert_eq!(quiet.start, None);
+        assert_eq!(quiet.end, None);
+    }
+
+    #[test]
+    fn test_sections_max_items_default_and_override() {
+        // A sections block without maxSectionItems keeps the default cap
+        let json = r#"{
+            "hotkey": {
+                "modifiers": ["meta"],
+                "key": "Semicolon"
+            },
+            "sections": {"order": ["APPS"]}
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.get_sections().max_section_items, 50);
+
+        // Explicit value wins, including 0 to disable the cap
+        let json = r#"{
+            "hotkey": {
+                "modifiers": ["meta"],
+                "key": "Semicolon"
+            },
+            "sections": {"maxSectionItems": 0}
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.get_sections().max_section_items, 0);
+    }
+
     #[test]
     fn test_process_limits_deserialization_empty() {
         // Empty object should use all defaults
@@ -1820,6 +2275,8 @@ mod tests {
         assert!(config.requires_confirmation("builtin-log-out"));
         assert!(config.requires_confirmation("builtin-empty-trash"));
         assert!(config.requires_confirmation("builtin-sleep"));
+        assert!(config.requires_confirmation("builtin-wifi-off"));
+        assert!(config.requires_confirmation("builtin-bluetooth-off"));
         assert!(config.requires_confirmation("builtin-test-confirmation"));
     }
 
@@ -1860,6 +2317,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -1899,6 +2357,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -2011,6 +2470,7 @@ mod tests {
             ui_scale: None,
             built_ins: None,
             process_limits: None,
+            quiet_hours: None,
             clipboard_history_max_text_length: None,
             frecency: None,
             notes_hotkey: None,
@@ -0,0 +1,242 @@
+//! Wi-Fi and Bluetooth control for the connectivity builtins
+//!
+//! Power toggles go through `networksetup` (ships with macOS) and
+//! `blueutil` (Homebrew; Bluetooth has no scriptable system CLI). The
+//! Connect builtin lists known targets — preferred Wi-Fi networks and
+//! paired Bluetooth devices — and connects to the selected one. All
+//! commands are quick but still shell out, so callers on the UI thread
+//! should expect tens of milliseconds, not microseconds.
+
+#![allow(dead_code)]
+
+use std::process::Command;
+
+use tracing::warn;
+
+/// What a connect target is, for icons and labels
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectTargetKind {
+    WifiNetwork,
+    BluetoothDevice,
+}
+
+impl ConnectTargetKind {
+    /// Emoji shown in the list row
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ConnectTargetKind::WifiNetwork => "\u{1F4F6}",
+            ConnectTargetKind::BluetoothDevice => "\u{1F537}",
+        }
+    }
+
+    /// Label shown in the row description
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectTargetKind::WifiNetwork => "Wi-Fi network",
+            ConnectTargetKind::BluetoothDevice => "Bluetooth device",
+        }
+    }
+}
+
+/// One row in the Connect builtin
+#[derive(Clone, Debug)]
+pub struct ConnectTarget {
+    pub kind: ConnectTargetKind,
+    /// Display name (SSID or device name)
+    pub name: String,
+    /// What the connect command needs (SSID or device address)
+    pub id: String,
+}
+
+/// Run networksetup and capture stdout
+fn run_networksetup(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("networksetup")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run networksetup: {}", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "networksetup failed: {}",
+            if stderr.trim().is_empty() {
+                stdout.trim()
+            } else {
+                stderr.trim()
+            }
+        ));
+    }
+    Ok(stdout)
+}
+
+/// Run blueutil, mapping a missing binary to an install hint
+fn run_blueutil(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("blueutil")
+        .args(args)
+        .output()
+        .map_err(|_| "Bluetooth control needs blueutil (brew install blueutil)".to_string())?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("blueutil failed: {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// The Wi-Fi hardware device name (usually "en0")
+pub fn wifi_device() -> Result<String, String> {
+    let output = run_networksetup(&["-listallhardwareports"])?;
+    parse_wifi_device(&output).ok_or_else(|| "No Wi-Fi hardware port found".to_string())
+}
+
+/// Find the device line following the Wi-Fi hardware port entry
+fn parse_wifi_device(output: &str) -> Option<String> {
+    let mut in_wifi_port = false;
+    for line in output.lines() {
+        if let Some(port) = line.strip_prefix("Hardware Port:") {
+            let port = port.trim();
+            in_wifi_port = port == "Wi-Fi" || port == "AirPort";
+        } else if in_wifi_port {
+            if let Some(device) = line.strip_prefix("Device:") {
+                return Some(device.trim().to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Turn Wi-Fi on or off
+pub fn set_wifi_power(on: bool) -> Result<(), String> {
+    let device = wifi_device()?;
+    let state = if on { "on" } else { "off" };
+    run_networksetup(&["-setairportpower", &device, state]).map(|_| ())
+}
+
+/// Turn Bluetooth on or off (requires blueutil)
+pub fn set_bluetooth_power(on: bool) -> Result<(), String> {
+    let state = if on { "1" } else { "0" };
+    run_blueutil(&["-p", state]).map(|_| ())
+}
+
+/// Known targets: preferred Wi-Fi networks plus paired Bluetooth devices
+///
+/// Bluetooth targets are skipped with a warning when blueutil isn't
+/// installed so the Wi-Fi list still works on a stock system.
+pub fn list_connect_targets() -> Vec<ConnectTarget> {
+    let mut targets = Vec::new();
+
+    match wifi_device()
+        .and_then(|device| run_networksetup(&["-listpreferredwirelessnetworks", &device]))
+    {
+        Ok(output) => targets.extend(parse_preferred_networks(&output)),
+        Err(e) => warn!(error = %e, "Could not list preferred Wi-Fi networks"),
+    }
+
+    match run_blueutil(&["--paired"]) {
+        Ok(output) => targets.extend(parse_paired_devices(&output)),
+        Err(e) => warn!(error = %e, "Could not list paired Bluetooth devices"),
+    }
+
+    targets
+}
+
+/// Parse `networksetup -listpreferredwirelessnetworks` output
+///
+/// The first line is a header ("Preferred networks on en0:"); SSIDs follow
+/// one per line, indented.
+fn parse_preferred_networks(output: &str) -> Vec<ConnectTarget> {
+    output
+        .lines()
+        .skip(1)
+        .map(|line| line.trim())
+        .filter(|ssid| !ssid.is_empty())
+        .map(|ssid| ConnectTarget {
+            kind: ConnectTargetKind::WifiNetwork,
+            name: ssid.to_string(),
+            id: ssid.to_string(),
+        })
+        .collect()
+}
+
+/// Parse `blueutil --paired` output
+///
+/// Each line looks like:
+/// `address: 00-11-22-33-44-55, not connected, not favourite, paired, name: "AirPods", ...`
+fn parse_paired_devices(output: &str) -> Vec<ConnectTarget> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let address = line.strip_prefix("address: ")?.split(',').next()?.trim();
+            let name = line
+                .split("name: \"")
+                .nth(1)
+                .and_then(|rest| rest.split('"').next())
+                .unwrap_or(address);
+            Some(ConnectTarget {
+                kind: ConnectTargetKind::BluetoothDevice,
+                name: name.to_string(),
+                id: address.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Connect to a known network or device
+pub fn connect(target: &ConnectTarget) -> Result<(), String> {
+    match target.kind {
+        ConnectTargetKind::WifiNetwork => {
+            let device = wifi_device()?;
+            // networksetup exits 0 even when the join fails; failures are
+            // reported on stdout instead
+            let output = run_networksetup(&["-setairportnetwork", &device, &target.id])?;
+            if output.contains("Failed") || output.contains("Error") {
+                return Err(output.trim().to_string());
+            }
+            Ok(())
+        }
+        ConnectTargetKind::BluetoothDevice => run_blueutil(&["--connect", &target.id]).map(|_| ()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wifi_device() {
+        let output = "\
+Hardware Port: Ethernet
+Device: en1
+Ethernet Address: aa:bb:cc:dd:ee:01
+
+Hardware Port: Wi-Fi
+Device: en0
+Ethernet Address: aa:bb:cc:dd:ee:02
+";
+        assert_eq!(parse_wifi_device(output), Some("en0".to_string()));
+        assert_eq!(
+            parse_wifi_device("Hardware Port: Ethernet\nDevice: en1\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_preferred_networks_skips_header() {
+        let output = "Preferred networks on en0:\n\tHomeNet\n\tOffice 5GHz\n";
+        let targets = parse_preferred_networks(output);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].kind, ConnectTargetKind::WifiNetwork);
+        assert_eq!(targets[0].name, "HomeNet");
+        assert_eq!(targets[1].name, "Office 5GHz");
+    }
+
+    #[test]
+    fn test_parse_paired_devices() {
+        let output = "address: 00-11-22-33-44-55, not connected, not favourite, paired, name: \"AirPods Pro\", recent access date: 2026-01-01\naddress: 66-77-88-99-aa-bb, connected (master, -40 dBm), not favourite, paired, name: \"MX Keys\", recent access date: 2026-01-02\n";
+        let targets = parse_paired_devices(output);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].kind, ConnectTargetKind::BluetoothDevice);
+        assert_eq!(targets[0].name, "AirPods Pro");
+        assert_eq!(targets[0].id, "00-11-22-33-44-55");
+        assert_eq!(targets[1].name, "MX Keys");
+    }
+}
@@ -66,6 +66,7 @@ pub use playful::{
     render_playful_window_container, PlayfulColors, PlayfulRenderer,
 };
 pub use retro_terminal::{RetroTerminalRenderer, TerminalColors, TERMINAL_ITEM_HEIGHT};
+pub use traits::ScaledDesignTokens;
 pub use traits::{
     AppleHIGDesignTokens, BrutalistDesignTokens, CompactDesignTokens, DefaultDesignTokens,
     DesignColors, DesignSpacing, DesignTokens, DesignTokensBox, DesignTypography, DesignVisual,
@@ -221,6 +222,45 @@ impl DesignVariant {
         }
     }
 
+    /// Get the camelCase name used in config keys and the persisted design file
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            DesignVariant::Default => "default",
+            DesignVariant::Minimal => "minimal",
+            DesignVariant::RetroTerminal => "retroTerminal",
+            DesignVariant::Glassmorphism => "glassmorphism",
+            DesignVariant::Brutalist => "brutalist",
+            DesignVariant::NeonCyberpunk => "neonCyberpunk",
+            DesignVariant::Paper => "paper",
+            DesignVariant::AppleHIG => "appleHig",
+            DesignVariant::Material3 => "material3",
+            DesignVariant::Compact => "compact",
+            DesignVariant::Playful => "playful",
+        }
+    }
+
+    /// Parse a variant from a config name
+    ///
+    /// Matching is case-insensitive and ignores spaces, hyphens and
+    /// underscores, so "retroTerminal", "retro-terminal" and "Retro Terminal"
+    /// all resolve to the same variant. Returns `None` for unknown names.
+    pub fn from_config_name(name: &str) -> Option<DesignVariant> {
+        fn normalize(s: &str) -> String {
+            s.chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        }
+        let normalized = normalize(name);
+        if normalized.is_empty() {
+            return None;
+        }
+        Self::all()
+            .iter()
+            .copied()
+            .find(|v| normalize(v.config_name()) == normalized || normalize(v.name()) == normalized)
+    }
+
     /// Get a short description of this design variant
     pub fn description(&self) -> &'static str {
         match self {
@@ -284,6 +324,22 @@ pub fn get_item_height(variant: DesignVariant) -> f32 {
 /// for the specified variant. Use this when you need dynamic dispatch.
 ///
 pub fn get_tokens(variant: DesignVariant) -> Box<dyn DesignTokens> {
+    let inner = get_base_tokens(variant);
+    let scale = ui_scale();
+    let list_font_size = list_font_size();
+    if (scale - 1.0).abs() < f32::EPSILON && list_font_size.is_none() {
+        return inner;
+    }
+    Box::new(traits::ScaledDesignTokens::new(
+        inner,
+        scale,
+        list_font_size,
+    ))
+}
+
+/// Get the unscaled design tokens for a variant (before the user's UI scale
+/// and list font size overrides are applied)
+fn get_base_tokens(variant: DesignVariant) -> Box<dyn DesignTokens> {
     match variant {
         DesignVariant::Default => Box::new(DefaultDesignTokens),
         DesignVariant::Minimal => Box::new(MinimalDesignTokens),
@@ -299,6 +355,70 @@ pub fn get_tokens(variant: DesignVariant) -> Box<dyn DesignTokens> {
     }
 }
 
+/// Smallest and largest UI scale we honor; values outside this range are
+/// almost certainly config typos and would make the UI unusable
+const UI_SCALE_MIN: f32 = 0.5;
+const UI_SCALE_MAX: f32 = 3.0;
+
+/// Active UI scale factor as f32 bits (1.0 = 100%, the default)
+static UI_SCALE_BITS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0x3f80_0000);
+
+/// Explicit list font size in pixels as f32 bits (0 = use the design's size)
+static LIST_FONT_SIZE_BITS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+/// Set the global UI scale factor (the `uiScale` config key), clamped to a
+/// usable range. Applied to every subsequent `get_tokens` call.
+pub fn set_ui_scale(scale: f32) {
+    let clamped = scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    UI_SCALE_BITS.store(clamped.to_bits(), std::sync::atomic::Ordering::Relaxed);
+}
+
+/// The active UI scale factor
+pub fn ui_scale() -> f32 {
+    f32::from_bits(UI_SCALE_BITS.load(std::sync::atomic::Ordering::Relaxed))
+}
+
+/// Set an explicit list font size in pixels (the `listFontSize` config key),
+/// or `None` to use each design's own size
+pub fn set_list_font_size(size: Option<f32>) {
+    let bits = size.filter(|s| *s > 0.0).map(f32::to_bits).unwrap_or(0);
+    LIST_FONT_SIZE_BITS.store(bits, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// The explicit list font size, when configured
+fn list_font_size() -> Option<f32> {
+    let bits = LIST_FONT_SIZE_BITS.load(std::sync::atomic::Ordering::Relaxed);
+    if bits == 0 {
+        None
+    } else {
+        Some(f32::from_bits(bits))
+    }
+}
+
+/// Where the last chosen design variant is persisted across launches
+fn design_state_path() -> std::path::PathBuf {
+    std::path::PathBuf::from(shellexpand::tilde("~/.sk/kit/design.json").as_ref())
+}
+
+/// Persist the chosen design variant so Cmd+1 cycling survives restarts
+pub fn save_variant(variant: DesignVariant) {
+    let path = design_state_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let json = serde_json::json!({ "variant": variant.config_name() });
+    if let Err(e) = std::fs::write(&path, format!("{:#}", json)) {
+        tracing::warn!(error = %e, path = %path.display(), "Failed to persist design variant");
+    }
+}
+
+/// Load the persisted design variant, if one was saved
+pub fn load_saved_variant() -> Option<DesignVariant> {
+    let content = std::fs::read_to_string(design_state_path()).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+    DesignVariant::from_config_name(value.get("variant")?.as_str()?)
+}
+
 /// Get design tokens for a design variant (static dispatch version)
 ///
 /// Returns the concrete token type for the specified variant.
@@ -371,12 +491,14 @@ pub fn render_design_item(
                         Some(icon_name) => IconKind::Svg(icon_name.clone()),
                         None => IconKind::Svg("Code".to_string()),
                     };
-                    (
-                        sm.script.name.clone(),
-                        sm.script.description.clone(),
-                        None,
-                        Some(icon),
-                    )
+                    // Elevated scripts (`// Sudo: true`) carry a shield so the
+                    // administrator prompt at launch isn't a surprise
+                    let name = if sm.script.sudo {
+                        format!("{} \u{1F6E1}\u{FE0F}", sm.script.name)
+                    } else {
+                        sm.script.name.clone()
+                    };
+                    (name, sm.script.description.clone(), None, Some(icon))
                 }
                 SearchResult::Scriptlet(sm) => {
                     // Scriptlets use BoltFilled SVG for quick actions
@@ -483,6 +605,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_config_name_round_trip() {
+        for &variant in DesignVariant::all() {
+            assert_eq!(
+                DesignVariant::from_config_name(variant.config_name()),
+                Some(variant),
+                "Round-trip failed for {:?}",
+                variant
+            );
+            // Display names should parse too ("Retro Terminal" -> RetroTerminal)
+            assert_eq!(
+                DesignVariant::from_config_name(variant.name()),
+                Some(variant)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_config_name_ignores_case_and_separators() {
+        assert_eq!(
+            DesignVariant::from_config_name("retro-terminal"),
+            Some(DesignVariant::RetroTerminal)
+        );
+        assert_eq!(
+            DesignVariant::from_config_name("NEON_CYBERPUNK"),
+            Some(DesignVariant::NeonCyberpunk)
+        );
+        assert_eq!(DesignVariant::from_config_name("not-a-design"), None);
+        assert_eq!(DesignVariant::from_config_name(""), None);
+    }
+
     #[test]
     fn test_playful_has_no_shortcut() {
         assert_eq!(DesignVariant::Playful.shortcut_number(), None);
@@ -760,6 +913,35 @@ mod tests {
         assert!(tokens.spacing().padding_md < DesignSpacing::default().padding_md);
     }
 
+    #[test]
+    fn test_scaled_tokens_multiply_spacing_and_typography() {
+        let base = DefaultDesignTokens;
+        let tokens = ScaledDesignTokens::new(Box::new(DefaultDesignTokens), 1.5, None);
+
+        assert_eq!(
+            tokens.typography().font_size_md,
+            base.typography().font_size_md * 1.5
+        );
+        assert_eq!(tokens.spacing().padding_md, base.spacing().padding_md * 1.5);
+        assert_eq!(tokens.item_height(), base.item_height() * 1.5);
+        // Colors and variant pass through unchanged
+        assert_eq!(tokens.colors().background, base.colors().background);
+        assert_eq!(tokens.variant(), base.variant());
+    }
+
+    #[test]
+    fn test_scaled_tokens_explicit_list_font_size() {
+        let tokens = ScaledDesignTokens::new(Box::new(DefaultDesignTokens), 2.0, Some(13.0));
+
+        // The explicit list size is absolute - not multiplied by the scale
+        assert_eq!(tokens.typography().font_size_md, 13.0);
+        // Other sizes still scale
+        assert_eq!(
+            tokens.typography().font_size_lg,
+            DefaultDesignTokens.typography().font_size_lg * 2.0
+        );
+    }
+
     #[test]
     fn test_all_variants_have_positive_item_height() {
         for variant in DesignVariant::all() {
@@ -176,6 +176,32 @@ impl Default for DesignSpacing {
     }
 }
 
+impl DesignSpacing {
+    /// Return a copy with every spacing value multiplied by `factor`
+    /// (used by the global UI scale setting)
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            padding_xs: self.padding_xs * factor,
+            padding_sm: self.padding_sm * factor,
+            padding_md: self.padding_md * factor,
+            padding_lg: self.padding_lg * factor,
+            padding_xl: self.padding_xl * factor,
+
+            gap_sm: self.gap_sm * factor,
+            gap_md: self.gap_md * factor,
+            gap_lg: self.gap_lg * factor,
+
+            margin_sm: self.margin_sm * factor,
+            margin_md: self.margin_md * factor,
+            margin_lg: self.margin_lg * factor,
+
+            item_padding_x: self.item_padding_x * factor,
+            item_padding_y: self.item_padding_y * factor,
+            icon_text_gap: self.icon_text_gap * factor,
+        }
+    }
+}
+
 /// Typography tokens for a design variant
 #[derive(Debug, Clone, PartialEq)]
 pub struct DesignTypography {
@@ -294,6 +320,21 @@ impl DesignTypography {
     pub fn cursor_margin_y(&self) -> f32 {
         2.0
     }
+
+    /// Return a copy with every font size multiplied by `factor`
+    /// (used by the global UI scale setting). Families, weights, and
+    /// line-height multipliers are scale-independent and pass through.
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            font_size_xs: self.font_size_xs * factor,
+            font_size_sm: self.font_size_sm * factor,
+            font_size_md: self.font_size_md * factor,
+            font_size_lg: self.font_size_lg * factor,
+            font_size_xl: self.font_size_xl * factor,
+            font_size_title: self.font_size_title * factor,
+            ..*self
+        }
+    }
 }
 
 /// Visual effect tokens for a design variant
@@ -1594,6 +1635,65 @@ impl DesignTokens for PlayfulDesignTokens {
 /// Type alias for boxed design tokens (for dynamic dispatch)
 pub type DesignTokensBox = Box<dyn DesignTokens>;
 
+// ============================================================================
+// UI Scale Wrapper
+// ============================================================================
+
+/// Token provider that applies the user's UI scale on top of another design.
+///
+/// Wraps any [`DesignTokens`] implementation and multiplies its spacing,
+/// typography, and item height by a scale factor (the `uiScale` config key),
+/// optionally pinning the list font size to an explicit pixel value
+/// (`listFontSize`). Colors and visual effects pass through unchanged so the
+/// design keeps its identity at every scale.
+pub struct ScaledDesignTokens {
+    inner: DesignTokensBox,
+    scale: f32,
+    list_font_size: Option<f32>,
+}
+
+impl ScaledDesignTokens {
+    pub fn new(inner: DesignTokensBox, scale: f32, list_font_size: Option<f32>) -> Self {
+        ScaledDesignTokens {
+            inner,
+            scale,
+            list_font_size,
+        }
+    }
+}
+
+impl DesignTokens for ScaledDesignTokens {
+    fn colors(&self) -> DesignColors {
+        self.inner.colors()
+    }
+
+    fn spacing(&self) -> DesignSpacing {
+        self.inner.spacing().scaled(self.scale)
+    }
+
+    fn typography(&self) -> DesignTypography {
+        let mut typography = self.inner.typography().scaled(self.scale);
+        // An explicit list font size is an absolute pixel value - it replaces
+        // the scaled base size rather than being scaled again
+        if let Some(size) = self.list_font_size {
+            typography.font_size_md = size;
+        }
+        typography
+    }
+
+    fn visual(&self) -> DesignVisual {
+        self.inner.visual()
+    }
+
+    fn item_height(&self) -> f32 {
+        self.inner.item_height() * self.scale
+    }
+
+    fn variant(&self) -> DesignVariant {
+        self.inner.variant()
+    }
+}
+
 /// Trait for design renderers
 ///
 /// Each design variant implements this trait to provide its own rendering
@@ -21,6 +21,12 @@ use crate::logging;
 use crate::snippet::ParsedSnippet;
 use crate::theme::Theme;
 
+/// Longest line (in bytes) that still gets syntax highlighting
+///
+/// Looser than the preview cap in `syntax.rs` since the editor highlights
+/// incrementally, but minified one-liners beyond this freeze tree-sitter.
+const MAX_HIGHLIGHT_LINE_LEN: usize = 2000;
+
 /// Convert a character offset to a Position (line, column)
 ///
 /// This is needed because gpui-component's InputState uses Position (line, column)
@@ -302,15 +308,38 @@ impl EditorPrompt {
             ),
         );
 
+        // Minified single-line content locks up the tree-sitter highlighter,
+        // so fall back to a plain-text grammar when any line is extreme. The
+        // content stays fully editable - only the coloring is skipped.
+        let highlight_language = if pending
+            .content
+            .lines()
+            .any(|line| line.len() > MAX_HIGHLIGHT_LINE_LEN)
+        {
+            logging::log(
+                "EDITOR",
+                &format!(
+                    "Line exceeds {} chars - disabling syntax highlighting",
+                    MAX_HIGHLIGHT_LINE_LEN
+                ),
+            );
+            "text".to_string()
+        } else {
+            pending.language.clone()
+        };
+
         // Create the gpui-component InputState in code_editor mode
         // Enable tab_navigation mode if we're in snippet mode (Tab moves between tabstops)
         let in_snippet = self.snippet_state.is_some();
+        let soft_wrap = self.config.get_editor_soft_wrap();
         let editor_state = cx.new(|cx| {
             InputState::new(window, cx)
-                .code_editor(&pending.language) // Sets up syntax highlighting
+                .code_editor(&highlight_language) // Sets up syntax highlighting
                 .searchable(true) // Enable Cmd+F find/replace
-                .line_number(false) // No line numbers - cleaner UI
-                .soft_wrap(false) // Code should not wrap by default
+                // When not wrapping, the line-number gutter stays pinned while
+                // the content scrolls horizontally - an anchor for long lines
+                .line_number(!soft_wrap)
+                .soft_wrap(soft_wrap) // Default: long lines scroll, not wrap
                 .default_value(pending.content)
                 .tab_navigation(in_snippet) // Propagate Tab when in snippet mode
         });
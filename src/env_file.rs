@@ -0,0 +1,317 @@
+//! .env file loading for script execution (`// EnvFile: ./.env`)
+//!
+//! Scripts can point at a dotenv-style file with `// EnvFile: ./.env`
+//! (resolved relative to the script's directory, tilde-expanded). A
+//! kit-level `~/.sk/kit/.env` is loaded first when present, so per-script
+//! files override it. The UI layer merges the result into the spawn
+//! environment below config.json `env` entries and `// Profile:` vars.
+//!
+//! Precedence, lowest to highest:
+//! 1. `~/.sk/kit/.env` (kit-level)
+//! 2. the script's `// EnvFile:` file
+//! 3. config.json `env` entries
+//! 4. `// Profile:` environment
+//!
+//! ## Syntax
+//! - `KEY=value`, one per line; blank lines and `#` comments are skipped
+//! - an optional `export ` prefix is ignored (shell-sourceable files work)
+//! - single-quoted values are literal; double-quoted values support
+//!   `\n`, `\t`, `\"`, and `\\` escapes
+//! - `${VAR}` and `$VAR` interpolate from keys defined earlier in the same
+//!   file, then the process environment (missing vars expand to "")
+//! - unquoted values have trailing ` # comment` text stripped
+
+use std::path::{Path, PathBuf};
+
+use crate::logging;
+
+/// Kit-level .env applied to every script run when present
+const KIT_ENV_FILE: &str = "~/.sk/kit/.env";
+
+/// Path of the kit-level .env file
+pub fn kit_env_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde(KIT_ENV_FILE).into_owned())
+}
+
+/// Resolve a `// EnvFile:` value against the script's directory
+///
+/// Absolute and `~/` paths stand alone; anything else is joined onto the
+/// directory containing the script.
+pub fn resolve_env_file(script_dir: &Path, value: &str) -> PathBuf {
+    let expanded = shellexpand::tilde(value).into_owned();
+    let path = PathBuf::from(expanded);
+    if path.is_absolute() {
+        path
+    } else {
+        script_dir.join(path)
+    }
+}
+
+/// True at a `$` that starts an interpolation token
+fn is_var_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '{'
+}
+
+/// Expand `${VAR}` / `$VAR` references in a value
+///
+/// Lookup order: keys already defined in this file (later entries win),
+/// then the process environment. Unknown variables expand to "".
+fn interpolate(value: &str, defined: &[(String, String)]) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' || !chars.peek().copied().is_some_and(is_var_start) {
+            out.push(c);
+            continue;
+        }
+
+        let braced = chars.peek() == Some(&'{');
+        if braced {
+            chars.next();
+        }
+        let mut name = String::new();
+        while let Some(&next) = chars.peek() {
+            if next.is_ascii_alphanumeric() || next == '_' {
+                name.push(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if braced {
+            // Require the closing brace; otherwise keep the text literally
+            if chars.peek() == Some(&'}') {
+                chars.next();
+            } else {
+                out.push_str("${");
+                out.push_str(&name);
+                continue;
+            }
+        }
+        if name.is_empty() {
+            out.push('$');
+            continue;
+        }
+
+        let resolved = defined
+            .iter()
+            .rev()
+            .find(|(key, _)| *key == name)
+            .map(|(_, v)| v.clone())
+            .or_else(|| std::env::var(&name).ok())
+            .unwrap_or_default();
+        out.push_str(&resolved);
+    }
+    out
+}
+
+/// Unescape a double-quoted value (`\n`, `\t`, `\"`, `\\`)
+fn unescape_double_quoted(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                Some(other) => {
+                    out.push('\\');
+                    out.push(other);
+                }
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Parse dotenv-style content into (key, value) pairs, in file order
+///
+/// Malformed lines are skipped. Duplicate keys are kept in order; callers
+/// applying entries sequentially get last-wins semantics for free.
+pub fn parse_env(content: &str) -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        let Some((key, raw_value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            continue;
+        }
+        let raw_value = raw_value.trim();
+
+        let value = if let Some(inner) = raw_value
+            .strip_prefix('\'')
+            .and_then(|rest| rest.strip_suffix('\''))
+        {
+            // Single quotes: literal, no interpolation
+            inner.to_string()
+        } else if let Some(inner) = raw_value
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            interpolate(&unescape_double_quoted(inner), &pairs)
+        } else {
+            // Unquoted: strip a trailing comment, then interpolate
+            let bare = raw_value
+                .split_once(" #")
+                .map(|(v, _)| v)
+                .unwrap_or(raw_value)
+                .trim_end();
+            interpolate(bare, &pairs)
+        };
+
+        pairs.push((key.to_string(), value));
+    }
+    pairs
+}
+
+/// Load and merge .env files, earlier paths first (later files win)
+fn merge_env_files(paths: &[PathBuf]) -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for path in paths {
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                let loaded = parse_env(&content);
+                logging::log(
+                    "ENV",
+                    &format!("Loaded {} var(s) from {}", loaded.len(), path.display()),
+                );
+                pairs.extend(loaded);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                logging::log("ENV", &format!("Failed to read {}: {}", path.display(), e));
+            }
+        }
+    }
+    pairs
+}
+
+/// Environment entries for a script run: kit-level .env plus the script's
+/// `// EnvFile:` file (which wins on collisions)
+///
+/// Entries are ordered so that appending them to the spawn env before
+/// config/profile vars preserves the documented precedence.
+pub fn load_env_for_script(script_path: &Path, env_file: Option<&str>) -> Vec<(String, String)> {
+    let mut paths = vec![kit_env_path()];
+    if let Some(value) = env_file {
+        let script_dir = script_path.parent().unwrap_or_else(|| Path::new("."));
+        paths.push(resolve_env_file(script_dir, value));
+    }
+    merge_env_files(&paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_basic_pairs() {
+        let pairs = parse_env("FOO=bar\nBAZ=qux\n");
+        assert_eq!(
+            pairs,
+            vec![
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_skips_comments_and_blanks() {
+        let pairs = parse_env("# comment\n\nFOO=bar\n  # indented comment\n");
+        assert_eq!(pairs.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_export_prefix() {
+        let pairs = parse_env("export FOO=bar\n");
+        assert_eq!(pairs, vec![("FOO".to_string(), "bar".to_string())]);
+    }
+
+    #[test]
+    fn test_single_quotes_are_literal() {
+        let pairs = parse_env("FOO=bar\nREF='$FOO and ${FOO}'\n");
+        assert_eq!(pairs[1].1, "$FOO and ${FOO}");
+    }
+
+    #[test]
+    fn test_double_quote_escapes() {
+        let pairs = parse_env(r#"MSG="line1\nline2 \"quoted\"""#);
+        assert_eq!(pairs[0].1, "line1\nline2 \"quoted\"");
+    }
+
+    #[test]
+    fn test_unquoted_inline_comment_stripped() {
+        let pairs = parse_env("FOO=bar # not part of the value\n");
+        assert_eq!(pairs[0].1, "bar");
+    }
+
+    #[test]
+    fn test_interpolates_earlier_keys() {
+        let pairs = parse_env("HOST=localhost\nPORT=8080\nURL=http://${HOST}:$PORT/api\n");
+        assert_eq!(pairs[2].1, "http://localhost:8080/api");
+    }
+
+    #[test]
+    fn test_interpolates_process_env() {
+        std::env::set_var("SK_ENV_FILE_TEST_VAR", "from-process");
+        let pairs = parse_env("FOO=${SK_ENV_FILE_TEST_VAR}\n");
+        assert_eq!(pairs[0].1, "from-process");
+        std::env::remove_var("SK_ENV_FILE_TEST_VAR");
+    }
+
+    #[test]
+    fn test_unknown_var_expands_empty() {
+        let pairs = parse_env("FOO=${SK_ENV_FILE_DEFINITELY_UNSET}!\n");
+        assert_eq!(pairs[0].1, "!");
+    }
+
+    #[test]
+    fn test_resolve_relative_and_absolute() {
+        let dir = Path::new("/scripts");
+        assert_eq!(
+            resolve_env_file(dir, "./.env"),
+            PathBuf::from("/scripts/./.env")
+        );
+        assert_eq!(
+            resolve_env_file(dir, "/etc/app.env"),
+            PathBuf::from("/etc/app.env")
+        );
+    }
+
+    #[test]
+    fn test_merge_later_file_wins_by_order() {
+        let dir = std::env::temp_dir().join(format!("sk-env-file-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let kit = dir.join("kit.env");
+        let script = dir.join("script.env");
+        std::fs::write(&kit, "SHARED=kit\nKIT_ONLY=1\n").unwrap();
+        std::fs::write(&script, "SHARED=script\n").unwrap();
+
+        let pairs = merge_env_files(&[kit, script]);
+        // Applied in order, the later (script) entry wins
+        let last_shared = pairs
+            .iter()
+            .rev()
+            .find(|(k, _)| k == "SHARED")
+            .map(|(_, v)| v.clone());
+        assert_eq!(last_shared.as_deref(), Some("script"));
+        assert!(pairs.iter().any(|(k, _)| k == "KIT_ONLY"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,14 +1,164 @@
 impl ScriptListApp {
     fn execute_interactive(&mut self, script: &scripts::Script, cx: &mut Context<Self>) {
+        self.execute_interactive_with_args(script, &[], cx)
+    }
+
+    /// Execute a script with positional arguments (used by the scriptkit:// URL scheme)
+    fn execute_interactive_with_args(
+        &mut self,
+        script: &scripts::Script,
+        script_args: &[String],
+        cx: &mut Context<Self>,
+    ) {
         logging::log(
             "EXEC",
             &format!("Starting interactive execution: {}", script.name),
         );
 
+        // Trust gate: first runs and content changes require confirmation.
+        // Approval (Enter) records the hash and re-enters this function, so
+        // the check passes on the second pass (see script_trust).
+        if let Ok(content) = std::fs::read_to_string(&script.path) {
+            let hash = script_trust::hash_content(&content);
+            let path_key = script.path.to_string_lossy().to_string();
+            match self.trust_store.check(&path_key, &hash) {
+                script_trust::TrustStatus::Trusted => {}
+                status => {
+                    let reason = if status == script_trust::TrustStatus::FirstRun {
+                        "has not run on this machine before"
+                    } else {
+                        "changed since it was last approved"
+                    };
+                    logging::log(
+                        "EXEC",
+                        &format!("Trust confirmation required ({:?}): {}", status, script.name),
+                    );
+                    self.pending_trust_run = Some((script.clone(), script_args.to_vec(), hash));
+                    self.toast_manager.push(
+                        Toast::warning(
+                            format!(
+                                "'{}' {} - press Enter to trust and run, Esc to cancel",
+                                script.name, reason
+                            ),
+                            &self.theme,
+                        )
+                        .duration_ms(Some(10000)),
+                    );
+                    cx.notify();
+                    return;
+                }
+            }
+        }
+        // Unreadable scripts fall through; the spawn below reports the error
+
+        // Remember the last run script for the rerun summon hotkey
+        self.last_run_script_path = Some(script.path.to_string_lossy().to_string());
+
         // Store script path for error reporting in reader thread
         let script_path_for_errors = script.path.to_string_lossy().to_string();
 
-        match executor::execute_script_interactive(&script.path) {
+        // Per-run environment on top of the executor's SK_* defaults: theme
+        // appearance, a best-effort selection snapshot, .env file vars, and
+        // any custom `env` entries from config.json (later entries win)
+        let mut extra_env: Vec<(String, String)> = Vec::new();
+        extra_env.push(("SK_THEME".to_string(), self.theme.appearance().to_string()));
+        if selected_text::has_accessibility_permission() {
+            if let Ok(text) = selected_text::get_selected_text() {
+                if !text.is_empty() {
+                    extra_env.push(("SK_SELECTED_TEXT".to_string(), text));
+                }
+            }
+        }
+        // .env files before config entries, so config.json `env` wins:
+        // kit-level ~/.sk/kit/.env first, then the script's `// EnvFile:`
+        let dotenv_vars = env_file::load_env_for_script(&script.path, script.env_file.as_deref());
+        if !dotenv_vars.is_empty() {
+            logging::log(
+                "EXEC",
+                &format!(
+                    "Injecting {} .env var(s) for {}",
+                    dotenv_vars.len(),
+                    script.name
+                ),
+            );
+            extra_env.extend(dotenv_vars);
+        }
+        if let Some(ref custom) = self.config.env {
+            for (key, value) in custom {
+                extra_env.push((key.clone(), value.clone()));
+            }
+        }
+
+        // Opt-in sandbox for untrusted scripts (`// Sandbox: true`): no
+        // network, minimal environment
+        if script.sandbox {
+            logging::log(
+                "EXEC",
+                &format!("Sandboxed execution requested: {}", script.name),
+            );
+        }
+
+        // Elevated scripts (`// Sudo: true`) go through the system
+        // authorization prompt; the password never touches this process
+        if script.sudo {
+            logging::log(
+                "EXEC",
+                &format!("Elevated execution requested: {}", script.name),
+            );
+        }
+
+        // Named environment profile (`// Profile: work`) resolved from the
+        // config `profiles` map; unknown names run without one
+        let spawn_profile = script.profile.as_deref().and_then(|name| {
+            match self.config.get_profile(name) {
+                Some(p) => {
+                    logging::log(
+                        "EXEC",
+                        &format!("Using profile '{}' for {}", name, script.name),
+                    );
+                    Some(executor::SpawnProfile {
+                        env: p
+                            .env
+                            .clone()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .collect(),
+                        path_additions: p.path.clone().unwrap_or_default(),
+                        cwd: p.cwd.clone(),
+                    })
+                }
+                None => {
+                    logging::log(
+                        "WARN",
+                        &format!(
+                            "Unknown profile '{}' for {}; check `profiles` in config.json",
+                            name, script.name
+                        ),
+                    );
+                    None
+                }
+            }
+        });
+
+        // Resource limits: `// Nice:` beats the config default, the memory
+        // cap comes from `processLimits.maxMemoryMb`
+        let process_limits = self.config.get_process_limits();
+        let spawn_limits = executor::SpawnLimits {
+            nice: script.nice.or(process_limits.nice),
+            max_memory_mb: process_limits.max_memory_mb,
+        };
+        let spawn_limits = (!spawn_limits.is_empty()).then_some(spawn_limits);
+
+        match executor::execute_script_interactive_with_options(
+            &script.path,
+            script_args,
+            &extra_env,
+            script.sandbox,
+            script.sudo,
+            spawn_profile.as_ref(),
+            script.cwd.as_deref(),
+            spawn_limits.as_ref(),
+        ) {
             Ok(session) => {
                 logging::log("EXEC", "Interactive session started successfully");
 
@@ -17,6 +167,44 @@ impl ScriptListApp {
                 self.current_script_pid = Some(pid);
                 logging::log("EXEC", &format!("Stored script PID {} for cleanup", pid));
 
+                // Per-run log file: console output forwarded by the SDK and
+                // raw stderr are appended here for run history / crash reports
+                let run_log_path = run_logs::create(&script.name, pid);
+                logging::log(
+                    "EXEC",
+                    &format!("Run log: {}", run_log_path.display()),
+                );
+
+                // Background scripts (// Background: true) run without the main
+                // window - register them so the Background Tasks builtin can
+                // list and stop them, then hide the window immediately.
+                // Toasts/HUDs/notifications from the script still surface.
+                let is_background = scripts::is_background_script(script);
+                // `// Restart: true` background scripts restart automatically if
+                // they crash (capped by background_tasks::note_restart)
+                let restart_policy = is_background && scripts::should_restart_script(script);
+                // `// Retries: N` background scripts retry on non-zero exit with
+                // exponential backoff instead of the plain restart policy
+                let retry_limit = if is_background {
+                    scripts::script_retry_limit(script)
+                } else {
+                    None
+                };
+                let script_name_for_retry = script.name.clone();
+                if is_background {
+                    background_tasks::register(pid, &script.name, &script.path);
+                    logging::log(
+                        "EXEC",
+                        &format!(
+                            "Background script registered: {} (PID {})",
+                            script.name, pid
+                        ),
+                    );
+                    script_kit_gpui::set_main_window_visible(false);
+                    NEEDS_RESET.store(true, Ordering::SeqCst);
+                    cx.hide();
+                }
+
                 *self.script_session.lock() = Some(session);
 
                 // Create async_channel for script thread to send prompt messages to UI (event-driven)
@@ -52,7 +240,7 @@ impl ScriptListApp {
                 let split = session.split();
 
                 let mut stdin = split.stdin;
-                let mut stdout_reader = split.stdout_reader;
+                let stdout_reader = split.stdout_reader;
                 // Capture stderr for error reporting - we'll read it in real-time for debugging
                 let stderr_handle = split.stderr;
                 // CRITICAL: Keep process_handle and child alive - they kill the process on drop!
@@ -60,14 +248,34 @@ impl ScriptListApp {
                 let _process_handle = split.process_handle;
                 let mut _child = split.child;
 
+                // Ring buffer of the script's most recent stderr lines; crash
+                // reports attach these so the error toast shows why it died
+                let stderr_tail = std::sync::Arc::new(std::sync::Mutex::new(
+                    std::collections::VecDeque::<String>::new(),
+                ));
+
                 // Stderr reader thread - forwards script stderr to logs in real-time
                 if let Some(stderr) = stderr_handle {
+                    let stderr_tail = stderr_tail.clone();
+                    let stderr_script_path = script.path.to_string_lossy().to_string();
+                    let stderr_run_log = run_log_path.clone();
                     std::thread::spawn(move || {
                         use std::io::BufRead;
                         let reader = std::io::BufReader::new(stderr);
                         for line in reader.lines() {
                             match line {
-                                Ok(l) => logging::log("SCRIPT", &l),
+                                Ok(l) => {
+                                    // Tagged with the script so the log panel
+                                    // can jump to the line that logged it
+                                    logging::log_script_output(&stderr_script_path, &l);
+                                    run_logs::append(&stderr_run_log, "stderr", &l);
+                                    if let Ok(mut tail) = stderr_tail.lock() {
+                                        if tail.len() >= executor::STDERR_TAIL_LINES {
+                                            tail.pop_front();
+                                        }
+                                        tail.push_back(l);
+                                    }
+                                }
                                 Err(e) => {
                                     logging::log("SCRIPT", &format!("stderr read error: {}", e));
                                     break;
@@ -78,9 +286,6 @@ impl ScriptListApp {
                     });
                 }
 
-                // Now stderr_handle is consumed, we pass None to reader thread
-                let stderr_handle: Option<std::process::ChildStderr> = None;
-
                 // Channel for sending responses from UI to writer thread
                 let (response_tx, response_rx) = mpsc::channel::<Message>();
 
@@ -189,6 +394,12 @@ impl ScriptListApp {
                     logging::log("EXEC", "Writer thread exiting");
                 });
 
+                // Cancellation token tied to this execution session. Tearing the
+                // session down cancels it; the dispatch loop below notices on its
+                // next poll and exits, dropping the process handle.
+                let cancel_token = executor::CancellationToken::new();
+                self.script_cancel_token = Some(cancel_token.clone());
+
                 // Reader thread - handles receiving messages from script (blocking is OK here)
                 // CRITICAL: Move _process_handle and _child into this thread to keep them alive!
                 // When the reader thread exits, they'll be dropped and the process killed.
@@ -197,86 +408,105 @@ impl ScriptListApp {
                     // These variables keep the process alive - they're dropped when the thread exits
                     let _keep_alive_handle = _process_handle;
                     let mut keep_alive_child = _child;
-                    let mut stderr_for_errors = stderr_handle;
                     let script_path = script_path_clone;
 
-                    loop {
-                        // Use next_message_graceful_with_handler to skip non-JSON lines and report parse issues
-                        match stdout_reader.next_message_graceful_with_handler(|issue| {
-                            let should_report = matches!(
-                                issue.kind,
-                                protocol::ParseIssueKind::InvalidPayload
-                                    | protocol::ParseIssueKind::UnknownType
-                            );
-                            if !should_report {
-                                return;
-                            }
+                    // Focus state before the script's first dnd message, so it
+                    // can be restored when the script exits
+                    let mut dnd_restore: Option<bool> = None;
 
-                            let summary = match issue.kind {
-                                protocol::ParseIssueKind::InvalidPayload => issue
-                                    .message_type
-                                    .as_deref()
-                                    .map(|message_type| {
-                                        format!(
-                                            "Invalid '{}' message payload from script",
-                                            message_type
-                                        )
-                                    })
-                                    .unwrap_or_else(|| {
-                                        "Invalid message payload from script".to_string()
-                                    }),
-                                protocol::ParseIssueKind::UnknownType => issue
-                                    .message_type
-                                    .as_deref()
-                                    .map(|message_type| {
-                                        format!(
-                                            "Unknown '{}' message type from script",
-                                            message_type
-                                        )
-                                    })
-                                    .unwrap_or_else(|| {
-                                        "Unknown message type from script".to_string()
-                                    }),
-                                _ => "Protocol message issue from script".to_string(),
-                            };
+                    // Blocking JSONL reads happen on the SessionReader's thread;
+                    // this loop only dispatches events, so it notices cancellation
+                    // even while the script is quiet. The bounded event channel
+                    // provides backpressure against chatty scripts.
+                    let session_reader =
+                        executor::SessionReader::spawn(stdout_reader, cancel_token);
 
-                            let mut details_lines = Vec::new();
-                            details_lines.push(format!("Script: {}", script_path));
-                            if let Some(ref message_type) = issue.message_type {
-                                details_lines.push(format!("Type: {}", message_type));
-                            }
-                            if let Some(ref error) = issue.error {
-                                details_lines.push(format!("Error: {}", error));
+                    loop {
+                        match session_reader.recv_timeout(std::time::Duration::from_secs(1)) {
+                            executor::SessionRead::TimedOut => {
+                                // Idle poll - loop back so cancellation is noticed
+                                continue;
                             }
-                            if !issue.raw_preview.is_empty() {
-                                details_lines.push(format!("Preview: {}", issue.raw_preview));
+                            executor::SessionRead::Cancelled => {
+                                logging::log("EXEC", "Session cancelled, stopping script");
+                                let _ = tx.send_blocking(PromptMessage::ScriptExit);
+                                break;
                             }
-                            let details = Some(details_lines.join("\n"));
-
-                            let severity = match issue.kind {
-                                protocol::ParseIssueKind::InvalidPayload => ErrorSeverity::Error,
-                                protocol::ParseIssueKind::UnknownType => ErrorSeverity::Warning,
-                                _ => ErrorSeverity::Warning,
-                            };
-
-                            let correlation_id = issue.correlation_id.clone();
-                            let prompt_msg = PromptMessage::ProtocolError {
-                                correlation_id: issue.correlation_id,
-                                summary,
-                                details,
-                                severity,
-                                script_path: script_path.clone(),
-                            };
-
-                            if tx.send_blocking(prompt_msg).is_err() {
-                                tracing::warn!(
-                                    correlation_id = %correlation_id,
-                                    script_path = %script_path,
-                                    "Prompt channel closed, dropping protocol error"
+                            executor::SessionRead::Issue(issue) => {
+                                let should_report = matches!(
+                                    issue.kind,
+                                    protocol::ParseIssueKind::InvalidPayload
+                                        | protocol::ParseIssueKind::UnknownType
                                 );
+                                if !should_report {
+                                    continue;
+                                }
+
+                                let summary = match issue.kind {
+                                    protocol::ParseIssueKind::InvalidPayload => issue
+                                        .message_type
+                                        .as_deref()
+                                        .map(|message_type| {
+                                            format!(
+                                                "Invalid '{}' message payload from script",
+                                                message_type
+                                            )
+                                        })
+                                        .unwrap_or_else(|| {
+                                            "Invalid message payload from script".to_string()
+                                        }),
+                                    protocol::ParseIssueKind::UnknownType => issue
+                                        .message_type
+                                        .as_deref()
+                                        .map(|message_type| {
+                                            format!(
+                                                "Unknown '{}' message type from script",
+                                                message_type
+                                            )
+                                        })
+                                        .unwrap_or_else(|| {
+                                            "Unknown message type from script".to_string()
+                                        }),
+                                    _ => "Protocol message issue from script".to_string(),
+                                };
+
+                                let mut details_lines = Vec::new();
+                                details_lines.push(format!("Script: {}", script_path));
+                                if let Some(ref message_type) = issue.message_type {
+                                    details_lines.push(format!("Type: {}", message_type));
+                                }
+                                if let Some(ref error) = issue.error {
+                                    details_lines.push(format!("Error: {}", error));
+                                }
+                                if !issue.raw_preview.is_empty() {
+                                    details_lines.push(format!("Preview: {}", issue.raw_preview));
+                                }
+                                let details = Some(details_lines.join("\n"));
+
+                                let severity = match issue.kind {
+                                    protocol::ParseIssueKind::InvalidPayload => ErrorSeverity::Error,
+                                    protocol::ParseIssueKind::UnknownType => ErrorSeverity::Warning,
+                                    _ => ErrorSeverity::Warning,
+                                };
+
+                                let correlation_id = issue.correlation_id.clone();
+                                let prompt_msg = PromptMessage::ProtocolError {
+                                    correlation_id: issue.correlation_id,
+                                    summary,
+                                    details,
+                                    severity,
+                                    script_path: script_path.clone(),
+                                };
+
+                                if tx.send_blocking(prompt_msg).is_err() {
+                                    tracing::warn!(
+                                        correlation_id = %correlation_id,
+                                        script_path = %script_path,
+                                        "Prompt channel closed, dropping protocol error"
+                                    );
+                                }
                             }
-                        }) {
-                            Ok(Some(msg)) => {
+                            executor::SessionRead::Message(msg) => {
                                 logging::log("EXEC", &format!("Received message: {:?}", msg));
 
                                 // First, try to handle selected text messages directly (no UI needed)
@@ -299,6 +529,44 @@ impl ScriptListApp {
                                     }
                                 }
 
+                                // Handshake: reply with our protocol version and
+                                // capabilities so SDKs can feature-detect (no UI needed)
+                                if let Message::Hello {
+                                    protocol_version,
+                                    sdk,
+                                } = &msg
+                                {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!(
+                                            "SDK hello: protocol v{} ({})",
+                                            protocol_version,
+                                            sdk.as_deref().unwrap_or("unknown sdk")
+                                        ),
+                                    );
+                                    if *protocol_version > protocol::PROTOCOL_VERSION {
+                                        // Newer SDK against an older app: fine, its
+                                        // unknown messages are logged and skipped
+                                        logging::log(
+                                            "EXEC",
+                                            &format!(
+                                                "Script speaks protocol v{} but app supports v{}; unknown messages will be ignored",
+                                                protocol_version,
+                                                protocol::PROTOCOL_VERSION
+                                            ),
+                                        );
+                                    }
+                                    if let Err(e) =
+                                        reader_response_tx.send(Message::hello_result())
+                                    {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Failed to send hello result: {}", e),
+                                        );
+                                    }
+                                    continue;
+                                }
+
                                 // Handle ClipboardHistory directly (no UI needed)
                                 if let Message::ClipboardHistory {
                                     request_id,
@@ -408,7 +676,8 @@ impl ScriptListApp {
                                             }
                                         }
                                         protocol::ClipboardHistoryAction::Clear => {
-                                            match clipboard_history::clear_history() {
+                                            // Keep a backup so the clear is restorable
+                                            match clipboard_history::clear_history_with_backup() {
                                                 Ok(()) => Message::clipboard_history_success(
                                                     request_id.clone(),
                                                 ),
@@ -443,6 +712,323 @@ impl ScriptListApp {
                                     continue;
                                 }
 
+                                // Handle key-value store messages directly (no UI needed)
+                                // Namespaced per script so keys don't collide across scripts
+                                if matches!(
+                                    &msg,
+                                    Message::DbGet { .. }
+                                        | Message::DbSet { .. }
+                                        | Message::DbDelete { .. }
+                                ) {
+                                    let namespace =
+                                        script_store::namespace_for_script(&script_path);
+                                    let response = match &msg {
+                                        Message::DbGet { request_id, key } => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!("DbGet: {}/{}", namespace, key),
+                                            );
+                                            match script_store::get(&namespace, key) {
+                                                Ok(stored) => {
+                                                    let value = stored.and_then(|s| {
+                                                        serde_json::from_str(&s).ok()
+                                                    });
+                                                    Message::db_value(request_id.clone(), value)
+                                                }
+                                                Err(e) => Message::db_error(
+                                                    request_id.clone(),
+                                                    e.to_string(),
+                                                ),
+                                            }
+                                        }
+                                        Message::DbSet {
+                                            request_id,
+                                            key,
+                                            value,
+                                        } => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!("DbSet: {}/{}", namespace, key),
+                                            );
+                                            let serialized = value.to_string();
+                                            match script_store::set(&namespace, key, &serialized) {
+                                                Ok(()) => Message::db_success(request_id.clone()),
+                                                Err(e) => Message::db_error(
+                                                    request_id.clone(),
+                                                    e.to_string(),
+                                                ),
+                                            }
+                                        }
+                                        Message::DbDelete { request_id, key } => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!("DbDelete: {}/{}", namespace, key),
+                                            );
+                                            match script_store::delete(&namespace, key) {
+                                                Ok(_) => Message::db_success(request_id.clone()),
+                                                Err(e) => Message::db_error(
+                                                    request_id.clone(),
+                                                    e.to_string(),
+                                                ),
+                                            }
+                                        }
+                                        _ => unreachable!(),
+                                    };
+
+                                    if let Err(e) = reader_response_tx.send(response) {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Failed to send db response: {}", e),
+                                        );
+                                    }
+                                    continue;
+                                }
+
+                                // Structured console output from the SDK's console
+                                // wrapper: append to the per-run log file and the
+                                // in-app log panel (with jump-to-source)
+                                if let Message::Log {
+                                    level,
+                                    text,
+                                    file,
+                                    line,
+                                } = &msg
+                                {
+                                    let level = level.as_deref().unwrap_or("info");
+                                    run_logs::append(&run_log_path, level, text);
+                                    logging::log_script_console(
+                                        &script_path,
+                                        text,
+                                        file.clone(),
+                                        *line,
+                                    );
+                                    continue;
+                                }
+
+                                // Record a typed final result (`result` message).
+                                // Fire-and-forget: the last value a script sends wins
+                                if let Message::ScriptResult { value } = &msg {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("Result recorded for {}", script_path),
+                                    );
+                                    run_results::record(
+                                        &script_path,
+                                        value.clone(),
+                                        Some(run_log_path.to_string_lossy().to_string()),
+                                    );
+                                    continue;
+                                }
+
+                                // Handle script-to-script invocation directly (no UI needed)
+                                // The child runs non-interactively on its own thread so the
+                                // parent's reader loop keeps draining other messages
+                                if let Message::Run {
+                                    request_id,
+                                    name,
+                                    cwd,
+                                } = &msg
+                                {
+                                    let request_id = request_id.clone();
+                                    let name = name.clone();
+                                    let cwd = cwd.clone();
+                                    let run_response_tx = reader_response_tx.clone();
+                                    logging::log("EXEC", &format!("Run request: {}", name));
+                                    std::thread::spawn(move || {
+                                        let response = match scripts::find_script_by_name(&name) {
+                                            Some(child) => {
+                                                // The requested cwd wins over the child's own
+                                                // `// Cwd:` metadata; both are tilde-expanded
+                                                let run_cwd = cwd
+                                                    .or(child.cwd.clone())
+                                                    .map(|c| {
+                                                        std::path::PathBuf::from(
+                                                            shellexpand::tilde(&c).as_ref(),
+                                                        )
+                                                    });
+                                                match executor::execute_script_in(
+                                                    &child.path,
+                                                    run_cwd.as_deref(),
+                                                ) {
+                                                    Ok(output) => {
+                                                        // Prefer the child's typed `result`
+                                                        // message over raw stdout
+                                                        let reply = match run_results::extract_result_value(&output) {
+                                                            Some(value) => {
+                                                                run_results::record(
+                                                                    &child.path.to_string_lossy(),
+                                                                    value.clone(),
+                                                                    None,
+                                                                );
+                                                                run_results::value_to_display(&value)
+                                                            }
+                                                            None => output.trim().to_string(),
+                                                        };
+                                                        Message::run_success(request_id, reply)
+                                                    }
+                                                    Err(e) => {
+                                                        logging::log(
+                                                            "EXEC",
+                                                            &format!(
+                                                                "Run of '{}' failed: {}",
+                                                                name, e
+                                                            ),
+                                                        );
+                                                        Message::run_error(request_id, e)
+                                                    }
+                                                }
+                                            }
+                                            None => Message::run_error(
+                                                request_id,
+                                                format!("Script not found: {}", name),
+                                            ),
+                                        };
+                                        if let Err(e) = run_response_tx.send(response) {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!("Failed to send run response: {}", e),
+                                            );
+                                        }
+                                    });
+                                    continue;
+                                }
+
+                                // Handle proxied network fetch directly (no UI needed)
+                                // The consent dialog and HTTP call both block,
+                                // so they run on their own thread
+                                if let Message::Fetch {
+                                    request_id,
+                                    url,
+                                    method,
+                                    headers,
+                                    body,
+                                } = &msg
+                                {
+                                    let request_id = request_id.clone();
+                                    let url = url.clone();
+                                    let method = method.clone().unwrap_or_else(|| "GET".to_string());
+                                    let headers = headers.clone().unwrap_or_default();
+                                    let body = body.clone();
+                                    let script_name = std::path::Path::new(&script_path)
+                                        .file_stem()
+                                        .map(|s| s.to_string_lossy().to_string())
+                                        .unwrap_or_else(|| script_path.clone());
+                                    let fetch_response_tx = reader_response_tx.clone();
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("Fetch request: {} {}", method, url),
+                                    );
+                                    std::thread::spawn(move || {
+                                        let response = match network_proxy::handle_fetch(
+                                            &script_name,
+                                            &method,
+                                            &url,
+                                            &headers,
+                                            body.as_deref(),
+                                        ) {
+                                            Ok((status, body)) => {
+                                                Message::fetch_success(request_id, status, body)
+                                            }
+                                            Err(e) => {
+                                                logging::log(
+                                                    "EXEC",
+                                                    &format!("Fetch of {} failed: {}", url, e),
+                                                );
+                                                Message::fetch_error(request_id, e)
+                                            }
+                                        };
+                                        if let Err(e) = fetch_response_tx.send(response) {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!("Failed to send fetch response: {}", e),
+                                            );
+                                        }
+                                    });
+                                    continue;
+                                }
+
+                                // Handle Focus (Do Not Disturb) changes directly (no UI
+                                // needed). Runs inline so dnd_restore tracking stays
+                                // race-free; the state read and toggle are quick
+                                if let Message::Dnd {
+                                    request_id,
+                                    enabled,
+                                } = &msg
+                                {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("Dnd request: enabled={}", enabled),
+                                    );
+                                    let response = match focus_mode::set_focus(*enabled) {
+                                        Ok(previous) => {
+                                            // Remember the original state from the first
+                                            // change only; restored on script exit
+                                            if dnd_restore.is_none() && previous != *enabled {
+                                                dnd_restore = Some(previous);
+                                            } else if dnd_restore == Some(*enabled) {
+                                                // Script put the state back itself
+                                                dnd_restore = None;
+                                            }
+                                            Message::dnd_success(request_id.clone(), previous)
+                                        }
+                                        Err(e) => {
+                                            logging::log(
+                                                "ERROR",
+                                                &format!("Focus change failed: {}", e),
+                                            );
+                                            Message::dnd_error(request_id.clone(), e)
+                                        }
+                                    };
+                                    if let Err(e) = reader_response_tx.send(response) {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Failed to send dnd response: {}", e),
+                                        );
+                                    }
+                                    continue;
+                                }
+
+                                // Handle Keyboard type/tap directly (no UI needed)
+                                // Runs on its own thread so long typing with
+                                // per-key delays doesn't block the reader
+                                if let Message::Keyboard {
+                                    action,
+                                    keys,
+                                    delay_ms,
+                                } = &msg
+                                {
+                                    let action = action.clone();
+                                    let keys = keys.clone().unwrap_or_default();
+                                    let delay_ms = delay_ms
+                                        .unwrap_or(keyboard_automation::DEFAULT_KEY_DELAY_MS);
+                                    logging::log(
+                                        "EXEC",
+                                        &format!(
+                                            "Keyboard request: {:?} ({} chars, delay {}ms)",
+                                            action,
+                                            keys.len(),
+                                            delay_ms
+                                        ),
+                                    );
+                                    std::thread::spawn(move || {
+                                        let result = match action {
+                                            protocol::KeyboardAction::Type => {
+                                                keyboard_automation::type_text(&keys, delay_ms)
+                                            }
+                                            protocol::KeyboardAction::Tap => {
+                                                keyboard_automation::tap_key(&keys, delay_ms)
+                                            }
+                                        };
+                                        if let Err(e) = result {
+                                            logging::log(
+                                                "ERROR",
+                                                &format!("Keyboard automation failed: {}", e),
+                                            );
+                                        }
+                                    });
+                                    continue;
+                                }
+
                                 // Handle Clipboard read/write directly (no UI needed)
                                 if let Message::Clipboard {
                                     id,
@@ -900,6 +1486,25 @@ impl ScriptListApp {
                                     continue;
                                 }
 
+                                // Handle GetAppState - needs app state, forward to UI thread
+                                if let Message::GetAppState { request_id } = &msg {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("GetAppState request: {}", request_id),
+                                    );
+                                    let prompt_msg = PromptMessage::GetAppState {
+                                        request_id: request_id.clone(),
+                                    };
+                                    if tx.send_blocking(prompt_msg).is_err() {
+                                        logging::log(
+                                            "EXEC",
+                                            "Prompt channel closed, reader exiting",
+                                        );
+                                        break;
+                                    }
+                                    continue;
+                                }
+
                                 // Handle GetLayoutInfo - needs UI state, forward to UI thread
                                 if let Message::GetLayoutInfo { request_id } = &msg {
                                     logging::log(
@@ -967,17 +1572,107 @@ impl ScriptListApp {
                                     continue;
                                 }
 
+                                // Handle shortcut runs - blocks the reader thread like screenshots,
+                                // which is fine since the script is awaiting the result anyway
+                                if let Message::RunShortcut {
+                                    request_id,
+                                    name,
+                                    input,
+                                } = &msg
+                                {
+                                    tracing::info!(request_id = %request_id, name = %name, "Running shortcut");
+
+                                    let response =
+                                        match macos_shortcuts::run_shortcut(name, input.as_deref())
+                                        {
+                                            Ok(output) => {
+                                                tracing::info!(
+                                                    request_id = %request_id,
+                                                    output_len = output.len(),
+                                                    "Shortcut completed"
+                                                );
+                                                Message::shortcut_result(request_id.clone(), output)
+                                            }
+                                            Err(e) => {
+                                                tracing::error!(
+                                                    request_id = %request_id,
+                                                    error = %e,
+                                                    "Shortcut failed"
+                                                );
+                                                Message::shortcut_error(
+                                                    request_id.clone(),
+                                                    e.to_string(),
+                                                )
+                                            }
+                                        };
+
+                                    if let Err(e) = reader_response_tx.send(response) {
+                                        tracing::error!(error = %e, "Failed to send shortcut response");
+                                    }
+                                    continue;
+                                }
+
+                                // Handle OCR requests - extract text from a PNG file or the clipboard image
+                                #[cfg(feature = "ocr")]
+                                if let Message::Ocr { request_id, path } = &msg {
+                                    tracing::info!(request_id = %request_id, ?path, "OCR requested");
+
+                                    let result = match path {
+                                        Some(p) => {
+                                            ocr::extract_text_from_png_file(std::path::Path::new(p))
+                                        }
+                                        None => ocr::extract_text_from_clipboard(),
+                                    };
+
+                                    let response = match result {
+                                        Ok(text) => {
+                                            tracing::info!(
+                                                request_id = %request_id,
+                                                text_length = text.len(),
+                                                "OCR completed"
+                                            );
+                                            Message::ocr_result(request_id.clone(), text)
+                                        }
+                                        Err(e) => {
+                                            tracing::error!(
+                                                request_id = %request_id,
+                                                error = %e,
+                                                "OCR failed"
+                                            );
+                                            Message::ocr_error(request_id.clone(), e.to_string())
+                                        }
+                                    };
+
+                                    if let Err(e) = reader_response_tx.send(response) {
+                                        tracing::error!(error = %e, "Failed to send OCR response");
+                                    }
+                                    continue;
+                                }
+
                                 let prompt_msg = match msg {
                                     Message::Arg {
                                         id,
                                         placeholder,
                                         choices,
                                         actions,
+                                        sort,
+                                        has_more,
                                     } => Some(PromptMessage::ShowArg {
                                         id,
                                         placeholder,
                                         choices,
                                         actions,
+                                        sort,
+                                        has_more,
+                                    }),
+                                    Message::ChoicesPage {
+                                        id,
+                                        choices,
+                                        has_more,
+                                    } => Some(PromptMessage::AppendChoices {
+                                        id,
+                                        choices,
+                                        has_more,
                                     }),
                                     Message::Div {
                                         id,
@@ -1033,10 +1728,53 @@ impl ScriptListApp {
                                         id,
                                         start_path,
                                         hint,
+                                        only_dirs,
+                                        extensions,
+                                        show_hidden,
+                                    } => Some(PromptMessage::ShowPath {
+                                        id,
+                                        start_path,
+                                        hint,
+                                        options: prompts::PathPromptOptions {
+                                            only_dirs: only_dirs.unwrap_or(false),
+                                            extensions,
+                                            show_hidden: show_hidden.unwrap_or(false),
+                                            ..Default::default()
+                                        },
+                                    }),
+                                    // File dialogs are the path prompt pre-configured
+                                    Message::OpenFile {
+                                        id,
+                                        start_path,
+                                        extensions,
+                                        only_dirs,
+                                        hint,
+                                    } => Some(PromptMessage::ShowPath {
+                                        id,
+                                        start_path,
+                                        hint,
+                                        options: prompts::PathPromptOptions {
+                                            only_dirs: only_dirs.unwrap_or(false),
+                                            extensions,
+                                            ..Default::default()
+                                        },
+                                    }),
+                                    Message::SaveFile {
+                                        id,
+                                        start_path,
+                                        default_filename,
+                                        extensions,
+                                        hint,
                                     } => Some(PromptMessage::ShowPath {
                                         id,
                                         start_path,
                                         hint,
+                                        options: prompts::PathPromptOptions {
+                                            extensions,
+                                            save_mode: true,
+                                            default_filename,
+                                            ..Default::default()
+                                        },
                                     }),
                                     Message::Env { id, key, secret } => {
                                         Some(PromptMessage::ShowEnv {
@@ -1046,6 +1784,32 @@ impl ScriptListApp {
                                             secret: secret.unwrap_or(false),
                                         })
                                     }
+                                    Message::Number {
+                                        id,
+                                        placeholder,
+                                        min,
+                                        max,
+                                        step,
+                                        value,
+                                    } => Some(PromptMessage::ShowNumber {
+                                        id,
+                                        placeholder,
+                                        min,
+                                        max,
+                                        step,
+                                        value,
+                                    }),
+                                    Message::Date {
+                                        id,
+                                        placeholder,
+                                        mode,
+                                        value,
+                                    } => Some(PromptMessage::ShowDate {
+                                        id,
+                                        placeholder,
+                                        mode,
+                                        value,
+                                    }),
                                     Message::Drop { id } => Some(PromptMessage::ShowDrop {
                                         id,
                                         placeholder: None,
@@ -1065,6 +1829,19 @@ impl ScriptListApp {
                                         choices,
                                         multiple: multiple.unwrap_or(false),
                                     }),
+                                    Message::Table {
+                                        id,
+                                        columns,
+                                        rows,
+                                        placeholder,
+                                        multiple,
+                                    } => Some(PromptMessage::ShowTable {
+                                        id,
+                                        placeholder,
+                                        columns,
+                                        rows,
+                                        multiple: multiple.unwrap_or(false),
+                                    }),
                                     Message::Exit { .. } => Some(PromptMessage::ScriptExit),
                                     Message::ForceSubmit { value } => {
                                         Some(PromptMessage::ForceSubmit { value })
@@ -1116,111 +1893,201 @@ impl ScriptListApp {
                                     }
                                 }
                             }
-                            Ok(None) => {
+                            executor::SessionRead::Closed { error: None } => {
                                 logging::log("EXEC", "Script stdout closed (EOF)");
 
-                                // Check if process exited with error
-                                let exit_code = match keep_alive_child.try_wait() {
-                                    Ok(Some(status)) => status.code(),
+                                // Check how the process exited (code + signal info)
+                                let exit_status = match keep_alive_child.try_wait() {
+                                    Ok(Some(status)) => Some(status),
                                     Ok(None) => {
                                         // Process still running, wait for it
-                                        match keep_alive_child.wait() {
-                                            Ok(status) => status.code(),
-                                            Err(_) => None,
-                                        }
+                                        keep_alive_child.wait().ok()
                                     }
                                     Err(_) => None,
                                 };
+                                let crash_info =
+                                    exit_status.map(executor::CrashInfo::from_exit_status);
+                                let exit_code = exit_status.and_then(|s| s.code());
 
-                                logging::log("EXEC", &format!("Script exit code: {:?}", exit_code));
-
-                                // If non-zero exit code, capture stderr and send error
-                                if let Some(code) = exit_code {
-                                    if code != 0 {
-                                        // Read stderr if available
-                                        let stderr_output =
-                                            if let Some(mut stderr) = stderr_for_errors.take() {
-                                                use std::io::Read;
-                                                let mut stderr_str = String::new();
-                                                if stderr.read_to_string(&mut stderr_str).is_ok()
-                                                    && !stderr_str.is_empty()
-                                                {
-                                                    Some(stderr_str)
-                                                } else {
-                                                    None
-                                                }
-                                            } else {
-                                                None
-                                            };
+                                logging::log(
+                                    "EXEC",
+                                    &format!(
+                                        "Script exit code: {:?} (crash info: {:?})",
+                                        exit_code, crash_info
+                                    ),
+                                );
 
-                                        if let Some(ref stderr_text) = stderr_output {
+                                // Report crashes (signals) and non-zero exits with
+                                // the last stderr lines attached
+                                let crashed = crash_info
+                                    .as_ref()
+                                    .map(|ci| ci.is_crash)
+                                    .unwrap_or(false);
+                                if crashed || exit_code.map(|code| code != 0).unwrap_or(false) {
+                                    let stderr_output = stderr_tail
+                                        .lock()
+                                        .ok()
+                                        .map(|tail| {
+                                            tail.iter().cloned().collect::<Vec<_>>().join("\n")
+                                        })
+                                        .filter(|s| !s.is_empty())
+                                        // A script whose console went through the
+                                        // SDK wrapper may have an empty stderr -
+                                        // fall back to the per-run log tail
+                                        .or_else(|| {
+                                            let tail = run_logs::read_tail(&run_log_path, 15);
+                                            (!tail.is_empty()).then(|| tail.join("\n"))
+                                        });
+
+                                    if let Some(ref stderr_text) = stderr_output {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!(
+                                                "Captured stderr tail ({} bytes)",
+                                                stderr_text.len()
+                                            ),
+                                        );
+
+                                        // Parse error info and generate suggestions
+                                        let error_message =
+                                            executor::extract_error_message(stderr_text);
+                                        let stack_trace =
+                                            executor::parse_stack_trace(stderr_text);
+                                        let suggestions = executor::generate_suggestions(
+                                            stderr_text,
+                                            exit_code,
+                                        );
+
+                                        // Send script error message
+                                        let _ = tx.send_blocking(PromptMessage::ScriptError {
+                                            error_message,
+                                            stderr_output: Some(stderr_text.clone()),
+                                            exit_code,
+                                            stack_trace,
+                                            script_path: script_path.clone(),
+                                            suggestions,
+                                        });
+                                    } else {
+                                        // No stderr - describe the crash from its
+                                        // exit status (signal name when present)
+                                        let error_message = crash_info
+                                            .as_ref()
+                                            .map(|ci| ci.error_message())
+                                            .unwrap_or_else(|| {
+                                                "Script terminated unexpectedly".to_string()
+                                            });
+                                        let suggestions = crash_info
+                                            .as_ref()
+                                            .map(executor::generate_crash_suggestions)
+                                            .unwrap_or_else(|| {
+                                                vec!["Check the script for errors".to_string()]
+                                            });
+                                        let _ = tx.send_blocking(PromptMessage::ScriptError {
+                                            error_message,
+                                            stderr_output: None,
+                                            exit_code,
+                                            stack_trace: None,
+                                            script_path: script_path.clone(),
+                                            suggestions,
+                                        });
+                                    }
+                                }
+
+                                let _ = tx.send_blocking(PromptMessage::ScriptExit);
+
+                                // Crashed `// Retries: N` background scripts retry
+                                // with exponential backoff; once the budget is
+                                // spent the failure is surfaced as a sticky toast
+                                // and in the Background Tasks view
+                                if let (true, Some(limit)) = (crashed, retry_limit) {
+                                    match background_tasks::note_retry(
+                                        std::path::Path::new(&script_path),
+                                        limit,
+                                    ) {
+                                        Some(attempt) => {
+                                            let delay = background_tasks::retry_delay_ms(attempt);
                                             logging::log(
                                                 "EXEC",
                                                 &format!(
-                                                    "Captured stderr ({} bytes)",
-                                                    stderr_text.len()
+                                                    "Retrying background script in {}ms (attempt {}/{}): {}",
+                                                    delay, attempt, limit, script_path
                                                 ),
                                             );
-
-                                            // Parse error info and generate suggestions
-                                            let error_message =
-                                                executor::extract_error_message(stderr_text);
-                                            let stack_trace =
-                                                executor::parse_stack_trace(stderr_text);
-                                            let suggestions = executor::generate_suggestions(
-                                                stderr_text,
-                                                Some(code),
+                                            // The reader thread is done anyway, so
+                                            // backing off here blocks nothing
+                                            std::thread::sleep(
+                                                std::time::Duration::from_millis(delay),
                                             );
-
-                                            // Send script error message
-                                            let _ = tx.send_blocking(PromptMessage::ScriptError {
-                                                error_message,
-                                                stderr_output: Some(stderr_text.clone()),
-                                                exit_code: Some(code),
-                                                stack_trace,
-                                                script_path: script_path.clone(),
-                                                suggestions,
-                                            });
-                                        } else {
-                                            // No stderr, send generic error
-                                            let _ = tx.send_blocking(PromptMessage::ScriptError {
-                                                error_message: format!(
-                                                    "Script exited with code {}",
-                                                    code
+                                            let _ =
+                                                tx.send_blocking(PromptMessage::RestartScript {
+                                                    path: script_path.clone(),
+                                                });
+                                        }
+                                        None => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!(
+                                                    "Retry budget exhausted after {} attempts: {}",
+                                                    limit, script_path
                                                 ),
-                                                stderr_output: None,
-                                                exit_code: Some(code),
-                                                stack_trace: None,
-                                                script_path: script_path.clone(),
-                                                suggestions: vec![
-                                                    "Check the script for errors".to_string()
-                                                ],
-                                            });
+                                            );
+                                            background_tasks::note_failure(
+                                                &script_name_for_retry,
+                                                std::path::Path::new(&script_path),
+                                                limit,
+                                            );
+                                            let _ =
+                                                tx.send_blocking(PromptMessage::RetriesExhausted {
+                                                    path: script_path.clone(),
+                                                    attempts: limit,
+                                                });
+                                        }
+                                    }
+                                }
+                                // Crashed `// Restart: true` background scripts
+                                // restart after the session tears down, capped so
+                                // a crash loop cannot restart forever
+                                else if crashed && restart_policy {
+                                    match background_tasks::note_restart(
+                                        std::path::Path::new(&script_path),
+                                    ) {
+                                        Some(attempt) => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!(
+                                                    "Restarting background script (attempt {}): {}",
+                                                    attempt, script_path
+                                                ),
+                                            );
+                                            let _ =
+                                                tx.send_blocking(PromptMessage::RestartScript {
+                                                    path: script_path.clone(),
+                                                });
+                                        }
+                                        None => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!(
+                                                    "Restart budget exhausted, leaving script stopped: {}",
+                                                    script_path
+                                                ),
+                                            );
                                         }
                                     }
                                 }
-
-                                let _ = tx.send_blocking(PromptMessage::ScriptExit);
                                 break;
                             }
-                            Err(e) => {
+                            executor::SessionRead::Closed { error: Some(e) } => {
                                 logging::log("EXEC", &format!("Error reading from script: {}", e));
 
-                                // Try to read stderr for error details
-                                let stderr_output =
-                                    if let Some(mut stderr) = stderr_for_errors.take() {
-                                        use std::io::Read;
-                                        let mut stderr_str = String::new();
-                                        if stderr.read_to_string(&mut stderr_str).is_ok()
-                                            && !stderr_str.is_empty()
-                                        {
-                                            Some(stderr_str)
-                                        } else {
-                                            None
-                                        }
-                                    } else {
-                                        None
-                                    };
+                                // Attach the last stderr lines for error details
+                                let stderr_output = stderr_tail
+                                    .lock()
+                                    .ok()
+                                    .map(|tail| {
+                                        tail.iter().cloned().collect::<Vec<_>>().join("\n")
+                                    })
+                                    .filter(|s| !s.is_empty());
 
                                 if let Some(ref stderr_text) = stderr_output {
                                     let error_message =
@@ -1244,6 +2111,20 @@ impl ScriptListApp {
                             }
                         }
                     }
+                    // Restore Focus to its pre-script state if the script
+                    // changed it and didn't change it back
+                    if let Some(previous) = dnd_restore {
+                        logging::log(
+                            "EXEC",
+                            &format!("Restoring Focus to pre-script state: {}", previous),
+                        );
+                        if let Err(e) = focus_mode::set_focus(previous) {
+                            logging::log("ERROR", &format!("Failed to restore Focus: {}", e));
+                        }
+                    }
+                    // Remove from the background task registry if this was a
+                    // background script (no-op for regular scripts)
+                    background_tasks::unregister(pid);
                     logging::log(
                         "EXEC",
                         "Reader thread exited, process handle will now be dropped",
@@ -1,11 +1,14 @@
 use crate::logging;
 use crate::process_manager::PROCESS_MANAGER;
-use crate::protocol::{serialize_message, JsonlReader, Message};
+use crate::protocol::{serialize_message, JsonlReader, Message, ParseIssue};
 use crate::scriptlets::{format_scriptlet, process_conditionals, Scriptlet, SHELL_TOOLS};
+use crate::setup::get_kit_path;
 use std::collections::HashMap;
-use std::io::{BufReader, Write};
+use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::time::{Duration, Instant};
 use tracing::{debug, error, info, instrument, warn};
 
@@ -335,10 +338,7 @@ fn find_sdk_path() -> Option<PathBuf> {
             &format!("  Checking kit sdk: {}", kit_sdk.display()),
         );
         if kit_sdk.exists() {
-            logging::log(
-                "EXEC",
-                &format!("  FOUND SDK (kit): {}", kit_sdk.display()),
-            );
+            logging::log("EXEC", &format!("  FOUND SDK (kit): {}", kit_sdk.display()));
             return Some(kit_sdk);
         }
     }
@@ -662,10 +662,367 @@ impl ScriptSession {
     }
 }
 
+// ============================================================================
+// Session Reader with Cancellation, Timeouts, and Backpressure
+// ============================================================================
+
+/// Buffer size for the session event channel.
+///
+/// When the buffer fills, the read thread blocks on `send`, which stops it
+/// draining the pipe; the OS pipe then fills and the script's writes block.
+/// That chain is the backpressure that protects a slow UI from a chatty script.
+const SESSION_EVENT_BUFFER: usize = 64;
+
+/// How long `recv_timeout` waits between cancellation checks
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Cancellation token tied to an execution session
+///
+/// Clones share the same flag. The UI cancels the token when it tears a
+/// session down; `SessionReader::recv_timeout` observes it within
+/// `CANCEL_POLL_INTERVAL` even while the script is quiet. The blocking read
+/// thread itself exits once the process's stdout closes (killing the process
+/// does that), so cancellation never requires interrupting a blocked read.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Request shutdown of the session this token is tied to
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// One read outcome from a `SessionReader`
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum SessionRead {
+    /// A parsed protocol message
+    Message(Message),
+    /// A parse issue the reader skipped (unknown type, invalid payload, ...)
+    Issue(ParseIssue),
+    /// No event arrived within the timeout; poll again
+    TimedOut,
+    /// The session's cancellation token was triggered
+    Cancelled,
+    /// The stream ended - cleanly on EOF, or with a read error
+    Closed { error: Option<String> },
+}
+
+/// Channel-backed reader that decouples blocking JSONL reads from dispatch
+///
+/// A dedicated thread owns the blocking `JsonlReader` and pushes events into a
+/// bounded channel. Consumers call `recv_timeout`, which gives them
+/// per-message timeouts and prompt cancellation without ever blocking on the
+/// pipe themselves. See `SESSION_EVENT_BUFFER` for how the bounded channel
+/// throttles scripts that outpace the UI.
+pub struct SessionReader {
+    events: mpsc::Receiver<SessionRead>,
+    token: CancellationToken,
+}
+
+impl SessionReader {
+    /// Spawn the read thread and return the consumer half
+    pub fn spawn<R>(mut reader: JsonlReader<R>, token: CancellationToken) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        let (event_tx, events) = mpsc::sync_channel::<SessionRead>(SESSION_EVENT_BUFFER);
+        let thread_token = token.clone();
+        std::thread::spawn(move || {
+            let issue_tx = event_tx.clone();
+            loop {
+                if thread_token.is_cancelled() {
+                    logging::log("EXEC", "Session read thread exiting (cancelled)");
+                    break;
+                }
+                match reader.next_message_graceful_with_handler(|issue| {
+                    let _ = issue_tx.send(SessionRead::Issue(issue));
+                }) {
+                    Ok(Some(msg)) => {
+                        if event_tx.send(SessionRead::Message(msg)).is_err() {
+                            logging::log("EXEC", "Session consumer dropped, read thread exiting");
+                            break;
+                        }
+                    }
+                    Ok(None) => {
+                        let _ = event_tx.send(SessionRead::Closed { error: None });
+                        break;
+                    }
+                    Err(e) => {
+                        let _ = event_tx.send(SessionRead::Closed {
+                            error: Some(e.to_string()),
+                        });
+                        break;
+                    }
+                }
+            }
+        });
+        Self { events, token }
+    }
+
+    /// Wait up to `timeout` for the next event
+    ///
+    /// Polls in short slices so cancellation is noticed within
+    /// `CANCEL_POLL_INTERVAL` even when the timeout is long.
+    pub fn recv_timeout(&self, timeout: Duration) -> SessionRead {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.token.is_cancelled() {
+                return SessionRead::Cancelled;
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return SessionRead::TimedOut;
+            }
+            match self
+                .events
+                .recv_timeout(remaining.min(CANCEL_POLL_INTERVAL))
+            {
+                Ok(event) => return event,
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return SessionRead::Closed { error: None }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Environment Injection
+// ============================================================================
+
+/// Build the environment variables injected into a script process
+///
+/// Always set:
+/// - `SK_KIT_PATH`: root of the kit directory scripts are loaded from
+/// - `SK_SCRIPT_PATH`: absolute path of the running script
+/// - `SK_EXECUTION_ID`: unique id for this run (fresh per spawn)
+///
+/// `extra_env` carries the caller's per-run variables (`SK_THEME`,
+/// `SK_SELECTED_TEXT`) plus any custom `env` entries from config.json.
+/// Entries are applied in order, so later ones win on key collisions.
+pub fn script_env(script_path: &str, extra_env: &[(String, String)]) -> Vec<(String, String)> {
+    let mut env = vec![
+        (
+            "SK_KIT_PATH".to_string(),
+            get_kit_path().to_string_lossy().into_owned(),
+        ),
+        ("SK_SCRIPT_PATH".to_string(), script_path.to_string()),
+        (
+            "SK_EXECUTION_ID".to_string(),
+            uuid::Uuid::new_v4().to_string(),
+        ),
+    ];
+    env.extend(extra_env.iter().cloned());
+    env
+}
+
+/// Resolved environment profile applied at spawn time (`// Profile: name`)
+///
+/// Built by the UI layer from the `profiles` map in config.json; the
+/// executor applies it to whichever runtime ends up spawning the script.
+#[derive(Debug, Clone, Default)]
+pub struct SpawnProfile {
+    /// Environment variables set on the script process
+    pub env: Vec<(String, String)>,
+    /// Directories prepended to PATH
+    pub path_additions: Vec<String>,
+    /// Working directory the script starts in
+    pub cwd: Option<String>,
+}
+
+/// Resource limits applied to the script process at spawn time
+///
+/// Built by the UI layer from `// Nice:` metadata and the `processLimits`
+/// section of config.json; applied between fork and exec on Unix so the
+/// limits are in place before the runtime starts.
+#[derive(Debug, Clone, Default)]
+pub struct SpawnLimits {
+    /// Niceness for the process; positive values lower its scheduling
+    /// priority so runaway scripts don't starve the UI
+    pub nice: Option<i32>,
+    /// Address-space cap in MB (RLIMIT_AS); allocations beyond it fail
+    pub max_memory_mb: Option<u64>,
+}
+
+impl SpawnLimits {
+    /// True when no limit is set (the spawn path skips the pre-exec hook)
+    pub fn is_empty(&self) -> bool {
+        self.nice.is_none() && self.max_memory_mb.is_none()
+    }
+}
+
+/// Build the PATH value with profile additions prepended
+///
+/// Additions are tilde-expanded and come before the current PATH, so
+/// profile toolchains win over system ones.
+pub fn profile_path(additions: &[String]) -> String {
+    let mut parts: Vec<String> = additions
+        .iter()
+        .map(|p| shellexpand::tilde(p).into_owned())
+        .collect();
+    if let Ok(existing) = std::env::var("PATH") {
+        if !existing.is_empty() {
+            parts.push(existing);
+        }
+    }
+    parts.join(":")
+}
+
+// ============================================================================
+// Sandboxed Execution
+// ============================================================================
+
+/// macOS seatbelt profile for sandboxed scripts (`// Sandbox: true`)
+///
+/// Allow-by-default with all network access denied. File writes are still
+/// possible, so this guards against exfiltration and remote fetches rather
+/// than local damage; pair it with the minimal environment below.
+pub const SANDBOX_PROFILE: &str = "(version 1)\n(allow default)\n(deny network*)\n";
+
+/// Environment variables preserved when a sandboxed script starts
+///
+/// Everything else from the app's environment (API tokens, cloud
+/// credentials) is stripped; the injected SK_* vars are added afterwards.
+pub const SANDBOX_KEPT_ENV: &[&str] = &["PATH", "HOME", "TMPDIR", "USER", "SHELL", "LANG"];
+
+/// Wrap the runtime executable in `sandbox-exec` with the no-network profile
+#[cfg(target_os = "macos")]
+fn sandboxed_command(executable: &str) -> Command {
+    let mut command = Command::new("/usr/bin/sandbox-exec");
+    command.arg("-p").arg(SANDBOX_PROFILE).arg(executable);
+    command
+}
+
+/// No seatbelt off macOS; the minimal environment still applies
+#[cfg(not(target_os = "macos"))]
+fn sandboxed_command(executable: &str) -> Command {
+    Command::new(executable)
+}
+
+/// Quote a word for the `sh` line behind `do shell script`
+#[cfg(target_os = "macos")]
+fn shell_single_quote(word: &str) -> String {
+    format!("'{}'", word.replace('\'', "'\\''"))
+}
+
+/// Wrap the runtime invocation in an osascript administrator prompt
+/// (`// Sudo: true`)
+///
+/// `do shell script ... with administrator privileges` shows the system
+/// authorization dialog; macOS collects and discards the password, so it
+/// never passes through (or is cached by) this process. Output is only
+/// delivered when the script exits, so elevated scripts run fire-and-forget:
+/// protocol prompts that need stdin are unavailable.
+#[cfg(target_os = "macos")]
+fn elevated_command(executable: &str, args: &[&str]) -> Command {
+    let shell_line = std::iter::once(executable)
+        .chain(args.iter().copied())
+        .map(shell_single_quote)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let apple_script = format!(
+        "do shell script \"{}\" with administrator privileges",
+        shell_line.replace('\\', "\\\\").replace('"', "\\\"")
+    );
+    let mut command = Command::new("/usr/bin/osascript");
+    command.arg("-e").arg(apple_script);
+    command
+}
+
+/// Plain `sudo` off macOS; the system's own prompt (or askpass helper) owns
+/// the password
+#[cfg(not(target_os = "macos"))]
+fn elevated_command(executable: &str, args: &[&str]) -> Command {
+    let mut command = Command::new("sudo");
+    command.arg(executable).args(args);
+    command
+}
+
 /// Execute a script with bidirectional JSONL communication
-#[instrument(skip_all, fields(script_path = %path.display()))]
 pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String> {
+    execute_script_interactive_with_args(path, &[])
+}
+
+/// Execute a script with positional arguments (e.g. from a scriptkit:// URL)
+///
+/// Arguments are appended to the runtime's argv after the script path, so
+/// scripts see them as normal process arguments.
+pub fn execute_script_interactive_with_args(
+    path: &Path,
+    script_args: &[String],
+) -> Result<ScriptSession, String> {
+    execute_script_interactive_with_env(path, script_args, &[])
+}
+
+/// Execute a script with positional arguments and per-run environment
+///
+/// `extra_env` is merged over the standard injected variables (see
+/// [`script_env`]); the UI layer uses it for SK_THEME, SK_SELECTED_TEXT, and
+/// the config-defined custom env.
+pub fn execute_script_interactive_with_env(
+    path: &Path,
+    script_args: &[String],
+    extra_env: &[(String, String)],
+) -> Result<ScriptSession, String> {
+    execute_script_interactive_with_options(
+        path,
+        script_args,
+        extra_env,
+        false,
+        false,
+        None,
+        None,
+        None,
+    )
+}
+
+/// Execute a script with positional arguments, per-run environment, an
+/// optional sandbox, and optional elevation
+///
+/// When `sandboxed` is set the runtime is wrapped in `sandbox-exec` with
+/// [`SANDBOX_PROFILE`] (macOS) and starts from a minimal environment
+/// ([`SANDBOX_KEPT_ENV`] plus the injected vars). When `elevated` is set the
+/// runtime goes through the system authorization prompt instead (see
+/// [`elevated_command`]); elevation and the sandbox are mutually exclusive,
+/// with elevation winning. `profile` carries a resolved `// Profile: name`
+/// environment profile (see [`SpawnProfile`]). `script_cwd` is the script's
+/// own `// Cwd:` working directory (tilde-expanded); a profile cwd
+/// overrides it when both are set. `limits` carries resolved niceness and
+/// memory limits (see [`SpawnLimits`]).
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all, fields(script_path = %path.display(), sandboxed = sandboxed, elevated = elevated))]
+pub fn execute_script_interactive_with_options(
+    path: &Path,
+    script_args: &[String],
+    extra_env: &[(String, String)],
+    sandboxed: bool,
+    elevated: bool,
+    profile: Option<&SpawnProfile>,
+    script_cwd: Option<&str>,
+    limits: Option<&SpawnLimits>,
+) -> Result<ScriptSession, String> {
     let start = Instant::now();
+    let sandboxed = if sandboxed && elevated {
+        warn!("Sandbox ignored for elevated run; `// Sudo: true` takes precedence");
+        false
+    } else {
+        sandboxed
+    };
     debug!(path = %path.display(), "Starting interactive script execution");
     logging::log(
         "EXEC",
@@ -679,6 +1036,41 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
     // Find SDK for preloading
     let sdk_path = find_sdk_path();
 
+    // Environment injected into whichever runtime ends up spawning
+    let mut env = script_env(path_str, extra_env);
+
+    // Script-level working directory (`// Cwd: ~/projects/foo`); a profile
+    // cwd below replaces it
+    let mut cwd: Option<std::path::PathBuf> = script_cwd
+        .map(|c| std::path::PathBuf::from(shellexpand::tilde(c).as_ref()));
+    if cwd.is_some() {
+        logging::log("EXEC", &format!("Script cwd: {:?}", cwd));
+    }
+
+    // Apply the named environment profile: its env entries win over the
+    // injected defaults, PATH additions come first, and cwd moves the
+    // process out of the app's working directory
+    if let Some(profile) = profile {
+        env.extend(profile.env.iter().cloned());
+        if !profile.path_additions.is_empty() {
+            env.push(("PATH".to_string(), profile_path(&profile.path_additions)));
+        }
+        if let Some(profile_cwd) = profile.cwd.as_deref() {
+            cwd = Some(std::path::PathBuf::from(
+                shellexpand::tilde(profile_cwd).as_ref(),
+            ));
+        }
+        logging::log(
+            "EXEC",
+            &format!(
+                "Profile applied: {} env var(s), {} PATH addition(s), cwd: {:?}",
+                profile.env.len(),
+                profile.path_additions.len(),
+                cwd
+            ),
+        );
+    }
+
     // Try bun with preload (preferred - supports TypeScript natively)
     if let Some(ref sdk) = sdk_path {
         let sdk_str = sdk.to_str().unwrap_or("");
@@ -686,7 +1078,18 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
             "EXEC",
             &format!("Trying: bun run --preload {} {}", sdk_str, path_str),
         );
-        match spawn_script("bun", &["run", "--preload", sdk_str, path_str], path_str) {
+        let mut args = vec!["run", "--preload", sdk_str, path_str];
+        args.extend(script_args.iter().map(|s| s.as_str()));
+        match spawn_script(
+            "bun",
+            &args,
+            path_str,
+            &env,
+            sandboxed,
+            elevated,
+            cwd.as_deref(),
+            limits,
+        ) {
             Ok(session) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -707,7 +1110,18 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
     // Try bun without preload as fallback
     if is_typescript(path) {
         logging::log("EXEC", &format!("Trying: bun run {}", path_str));
-        match spawn_script("bun", &["run", path_str], path_str) {
+        let mut args = vec!["run", path_str];
+        args.extend(script_args.iter().map(|s| s.as_str()));
+        match spawn_script(
+            "bun",
+            &args,
+            path_str,
+            &env,
+            sandboxed,
+            elevated,
+            cwd.as_deref(),
+            limits,
+        ) {
             Ok(session) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -728,7 +1142,18 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
     // Try node for JavaScript files
     if is_javascript(path) {
         logging::log("EXEC", &format!("Trying: node {}", path_str));
-        match spawn_script("node", &[path_str], path_str) {
+        let mut args = vec![path_str];
+        args.extend(script_args.iter().map(|s| s.as_str()));
+        match spawn_script(
+            "node",
+            &args,
+            path_str,
+            &env,
+            sandboxed,
+            elevated,
+            cwd.as_deref(),
+            limits,
+        ) {
             Ok(session) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -759,8 +1184,18 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
 }
 
 /// Spawn a script as an interactive process with piped stdin/stdout
+#[allow(clippy::too_many_arguments)]
 #[instrument(skip_all, fields(cmd = %cmd))]
-fn spawn_script(cmd: &str, args: &[&str], script_path: &str) -> Result<ScriptSession, String> {
+fn spawn_script(
+    cmd: &str,
+    args: &[&str],
+    script_path: &str,
+    env: &[(String, String)],
+    sandboxed: bool,
+    elevated: bool,
+    cwd: Option<&Path>,
+    limits: Option<&SpawnLimits>,
+) -> Result<ScriptSession, String> {
     // Try to find the executable in common locations
     let executable = find_executable(cmd)
         .map(|p| p.to_string_lossy().into_owned())
@@ -769,9 +1204,43 @@ fn spawn_script(cmd: &str, args: &[&str], script_path: &str) -> Result<ScriptSes
     debug!(executable = %executable, args = ?args, "Spawning script process");
     logging::log("EXEC", &format!("spawn_script: {} {:?}", executable, args));
 
-    let mut command = Command::new(&executable);
+    let mut command = if elevated {
+        elevated_command(&executable, args)
+    } else if sandboxed {
+        sandboxed_command(&executable)
+    } else {
+        Command::new(&executable)
+    };
+
+    if elevated {
+        logging::log("EXEC", "Elevated: system administrator prompt");
+    }
+
+    if sandboxed && !elevated {
+        // Start from a minimal environment so app secrets never reach the
+        // untrusted script; env_clear must come before the .envs() below
+        command.env_clear();
+        for key in SANDBOX_KEPT_ENV {
+            if let Ok(value) = std::env::var(key) {
+                command.env(key, value);
+            }
+        }
+        logging::log("EXEC", "Sandbox: no-network profile + minimal env");
+    }
+
+    if !elevated {
+        // elevated_command already embedded the args in its shell line
+        command.args(args);
+    }
+
+    if let Some(dir) = cwd {
+        // Requested working directory (`// Cwd:` or a profile's cwd)
+        command.current_dir(dir);
+        logging::log("EXEC", &format!("Working directory: {}", dir.display()));
+    }
+
     command
-        .args(args)
+        .envs(env.iter().map(|(k, v)| (k.as_str(), v.as_str())))
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped()); // Capture stderr for error handling
@@ -784,6 +1253,43 @@ fn spawn_script(cmd: &str, args: &[&str], script_path: &str) -> Result<ScriptSes
         logging::log("EXEC", "Using process group for child process");
     }
 
+    // Apply resource limits between fork and exec so the runtime starts
+    // with them already in place (`// Nice:` / config `processLimits`)
+    #[cfg(unix)]
+    if let Some(limits) = limits {
+        if !limits.is_empty() {
+            let nice = limits.nice;
+            let mem_bytes = limits
+                .max_memory_mb
+                .map(|mb| mb.saturating_mul(1024 * 1024));
+            logging::log(
+                "EXEC",
+                &format!(
+                    "Limits: nice {:?}, memory {:?} MB",
+                    nice, limits.max_memory_mb
+                ),
+            );
+            unsafe {
+                command.pre_exec(move || {
+                    if let Some(n) = nice {
+                        // Best-effort; only root can lower niceness below 0
+                        libc::setpriority(libc::PRIO_PROCESS, 0, n);
+                    }
+                    if let Some(bytes) = mem_bytes {
+                        let limit = libc::rlimit {
+                            rlim_cur: bytes,
+                            rlim_max: bytes,
+                        };
+                        libc::setrlimit(libc::RLIMIT_AS, &limit);
+                    }
+                    Ok(())
+                });
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = limits;
+
     let mut child = command.spawn().map_err(|e| {
         error!(error = %e, executable = %executable, "Process spawn failed");
         let err = format!("Failed to spawn '{}': {}", executable, e);
@@ -826,8 +1332,16 @@ fn spawn_script(cmd: &str, args: &[&str], script_path: &str) -> Result<ScriptSes
 
 /// Execute a script and return its output (non-interactive, for backwards compatibility)
 #[allow(dead_code)]
-#[instrument(skip_all, fields(script_path = %path.display()))]
 pub fn execute_script(path: &Path) -> Result<String, String> {
+    execute_script_in(path, None)
+}
+
+/// Execute a script non-interactively in an optional working directory
+///
+/// `cwd` comes from the `run` protocol message's cwd field or the child's
+/// own `// Cwd:` metadata; None inherits the app's working directory.
+#[instrument(skip_all, fields(script_path = %path.display()))]
+pub fn execute_script_in(path: &Path, cwd: Option<&Path>) -> Result<String, String> {
     let start = Instant::now();
     debug!(path = %path.display(), "Starting blocking script execution");
     logging::log(
@@ -845,7 +1359,7 @@ pub fn execute_script(path: &Path) -> Result<String, String> {
 
     // Try kit CLI first (preferred for script-kit)
     logging::log("EXEC", &format!("Trying: kit run {}", path_str));
-    match run_command("kit", &["run", path_str]) {
+    match run_command("kit", &["run", path_str], cwd) {
         Ok(output) => {
             info!(
                 duration_ms = start.elapsed().as_millis() as u64,
@@ -873,7 +1387,7 @@ pub fn execute_script(path: &Path) -> Result<String, String> {
                 "EXEC",
                 &format!("Trying: bun run --preload {} {}", sdk_str, path_str),
             );
-            match run_command("bun", &["run", "--preload", sdk_str, path_str]) {
+            match run_command("bun", &["run", "--preload", sdk_str, path_str], cwd) {
                 Ok(output) => {
                     info!(
                         duration_ms = start.elapsed().as_millis() as u64,
@@ -900,7 +1414,7 @@ pub fn execute_script(path: &Path) -> Result<String, String> {
             "EXEC",
             &format!("Trying: bun run {} (no preload)", path_str),
         );
-        match run_command("bun", &["run", path_str]) {
+        match run_command("bun", &["run", path_str], cwd) {
             Ok(output) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -925,7 +1439,7 @@ pub fn execute_script(path: &Path) -> Result<String, String> {
     // Try node for JavaScript files
     if is_javascript(path) {
         logging::log("EXEC", &format!("Trying: node {}", path_str));
-        match run_command("node", &[path_str]) {
+        match run_command("node", &[path_str], cwd) {
             Ok(output) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -959,9 +1473,9 @@ pub fn execute_script(path: &Path) -> Result<String, String> {
     Err(err)
 }
 
-/// Run a command and capture its output
+/// Run a command and capture its output, optionally in a working directory
 #[allow(dead_code)]
-fn run_command(cmd: &str, args: &[&str]) -> Result<String, String> {
+fn run_command(cmd: &str, args: &[&str], cwd: Option<&Path>) -> Result<String, String> {
     // Try to find the executable in common locations
     let executable = find_executable(cmd)
         .map(|p| p.to_string_lossy().into_owned())
@@ -969,7 +1483,12 @@ fn run_command(cmd: &str, args: &[&str]) -> Result<String, String> {
 
     logging::log("EXEC", &format!("run_command: {} {:?}", executable, args));
 
-    let output = Command::new(&executable).args(args).output().map_err(|e| {
+    let mut command = Command::new(&executable);
+    if let Some(dir) = cwd {
+        command.current_dir(dir);
+        logging::log("EXEC", &format!("Working directory: {}", dir.display()));
+    }
+    let output = command.args(args).output().map_err(|e| {
         let err = format!("Failed to run '{}': {}", executable, e);
         logging::log("EXEC", &format!("COMMAND ERROR: {}", err));
         err
@@ -1025,6 +1544,9 @@ fn is_javascript(path: &Path) -> bool {
 // Error Parsing and Suggestion Generation
 // ============================================================================
 
+/// Number of trailing stderr lines kept in memory for crash reports
+pub const STDERR_TAIL_LINES: usize = 20;
+
 /// Parse stderr output to extract stack trace if present
 pub fn parse_stack_trace(stderr: &str) -> Option<String> {
     // Look for common stack trace patterns
@@ -1224,7 +1746,7 @@ pub fn generate_suggestions(stderr: &str, exit_code: Option<i32>) -> Vec<String>
 /// to create from a process's exit status.
 ///
 #[derive(Debug, Clone)]
-#[allow(dead_code)] // Infrastructure ready for integration into main.rs
+#[allow(dead_code)] // was_signaled is kept for completeness of the crash record
 pub struct CrashInfo {
     /// Whether the process was terminated by a signal
     pub was_signaled: bool,
@@ -1238,7 +1760,6 @@ pub struct CrashInfo {
     pub is_crash: bool,
 }
 
-#[allow(dead_code)] // Infrastructure ready for integration into main.rs
 impl CrashInfo {
     /// Create CrashInfo from an ExitStatus
     #[cfg(unix)]
@@ -1329,7 +1850,6 @@ fn signal_to_name(signal: i32) -> String {
 }
 
 /// Generate suggestions specifically for crash scenarios
-#[allow(dead_code)] // Infrastructure ready for integration into main.rs
 pub fn generate_crash_suggestions(crash_info: &CrashInfo) -> Vec<String> {
     let mut suggestions = Vec::new();
 
@@ -1386,6 +1906,95 @@ pub fn generate_crash_suggestions(crash_info: &CrashInfo) -> Vec<String> {
     suggestions
 }
 
+/// A source location parsed from a stack trace frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    /// Absolute path to the source file
+    pub path: String,
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column number, when present
+    pub column: Option<u32>,
+}
+
+/// Parse the top user-code frame from bun/node stderr
+///
+/// Walks the `at ...` stack lines top-down and returns the first location
+/// that points at a real file, skipping runtime internals (`node:`, `bun:`,
+/// `native`) and anything under `node_modules`.
+pub fn parse_top_user_frame(stderr: &str) -> Option<StackFrame> {
+    for line in stderr.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with("at ") {
+            continue;
+        }
+        // "at fn (/path/file.ts:12:5)" or "at /path/file.ts:12:5"
+        let location = trimmed
+            .rfind('(')
+            .and_then(|open| trimmed[open + 1..].strip_suffix(')'))
+            .unwrap_or_else(|| trimmed.trim_start_matches("at ").trim());
+        if let Some(frame) = parse_frame_location(location) {
+            return Some(frame);
+        }
+    }
+    None
+}
+
+/// Parse "path:line" or "path:line:column" from a single frame location
+fn parse_frame_location(location: &str) -> Option<StackFrame> {
+    // Skip runtime internals and dependencies - the user can't fix those
+    if location.starts_with("node:")
+        || location.starts_with("bun:")
+        || location == "native"
+        || location.contains("node_modules")
+    {
+        return None;
+    }
+
+    let (rest, last) = location.rsplit_once(':')?;
+    let last_num: u32 = last.parse().ok()?;
+
+    // Prefer path:line:column; fall back to path:line
+    let (path, line, column) = match rest.rsplit_once(':') {
+        Some((path, line_str)) => match line_str.parse::<u32>() {
+            Ok(line) => (path, line, Some(last_num)),
+            Err(_) => (rest, last_num, None),
+        },
+        None => (rest, last_num, None),
+    };
+
+    // A real file path, not "fn_name:12" noise
+    if !path.contains('/') || line == 0 {
+        return None;
+    }
+
+    Some(StackFrame {
+        path: path.to_string(),
+        line,
+        column,
+    })
+}
+
+/// Read the source lines around `line` (1-based) for the error panel
+///
+/// Returns (line_number, text) pairs covering `context` lines either side,
+/// or None when the file can't be read or the line is out of range.
+pub fn read_source_snippet(path: &str, line: u32, context: u32) -> Option<Vec<(u32, String)>> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let lines: Vec<&str> = content.lines().collect();
+    if line == 0 || line as usize > lines.len() {
+        return None;
+    }
+
+    let start = line.saturating_sub(context).max(1);
+    let end = (line + context).min(lines.len() as u32);
+    Some(
+        (start..=end)
+            .map(|n| (n, lines[n as usize - 1].to_string()))
+            .collect(),
+    )
+}
+
 // ============================================================================
 // Scriptlet Execution
 // ============================================================================
@@ -1405,6 +2014,8 @@ pub struct ScriptletExecOptions {
     pub positional_args: Vec<String>,
     /// Flags for conditional processing
     pub flags: HashMap<String, bool>,
+    /// Extra environment variables for the child process
+    pub env: HashMap<String, String>,
 }
 
 /// Result of a scriptlet execution
@@ -1440,6 +2051,33 @@ fn tool_extension(tool: &str) -> &'static str {
     }
 }
 
+/// Ask the user to confirm running a scriptlet marked `confirm: true`
+///
+/// Shows a native dialog (osascript on macOS). Returns `Ok(false)` when the
+/// user cancels. Off macOS there is no dialog and execution proceeds.
+fn confirm_scriptlet_execution(name: &str) -> Result<bool, String> {
+    #[cfg(target_os = "macos")]
+    {
+        let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+        let script = format!(
+            "display dialog \"Run {}?\" with title \"Script Kit\" buttons {{\"Cancel\", \"Run\"}} default button \"Run\"",
+            escaped
+        );
+        let output = Command::new("osascript")
+            .arg("-e")
+            .arg(script)
+            .output()
+            .map_err(|e| format!("Failed to show confirmation dialog: {}", e))?;
+        // osascript exits non-zero when the user hits Cancel
+        Ok(output.status.success())
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = name;
+        Ok(true)
+    }
+}
+
 /// Execute a scriptlet based on its tool type
 ///
 /// # Arguments
@@ -1475,6 +2113,35 @@ pub fn run_scriptlet(
         ),
     );
 
+    // Section metadata: `confirm: true` gates execution behind a dialog
+    if scriptlet.metadata.confirm == Some(true) && !confirm_scriptlet_execution(&scriptlet.name)? {
+        logging::log(
+            "EXEC",
+            &format!("Scriptlet cancelled by user: {}", scriptlet.name),
+        );
+        return Ok(ScriptletResult {
+            exit_code: 1,
+            stdout: String::new(),
+            stderr: "Cancelled by user".to_string(),
+            success: false,
+        });
+    }
+
+    // Section metadata: `cwd:` applies when the caller didn't specify one,
+    // and `env: KEY=value` fills in variables the caller didn't set
+    let mut options = options;
+    if options.cwd.is_none() {
+        if let Some(ref cwd) = scriptlet.metadata.cwd {
+            options.cwd = Some(PathBuf::from(shellexpand::tilde(cwd).as_ref()));
+        }
+    }
+    for (key, value) in &scriptlet.metadata.env {
+        options
+            .env
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+
     // Process conditionals and variable substitution
     let content = process_conditionals(&scriptlet.scriptlet_content, &options.flags);
     let is_windows = cfg!(target_os = "windows");
@@ -1632,6 +2299,10 @@ fn execute_shell_scriptlet(
         cmd.current_dir(cwd);
     }
 
+    for (key, value) in &options.env {
+        cmd.env(key, value);
+    }
+
     let output = cmd.output().map_err(|e| {
         // Clean up temp file before returning error
         let _ = std::fs::remove_file(&temp_file);
@@ -1752,6 +2423,10 @@ fn execute_with_interpreter(
         cmd.current_dir(cwd);
     }
 
+    for (key, value) in &options.env {
+        cmd.env(key, value);
+    }
+
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to execute {} script: {}", interpreter, e))?;
@@ -1781,6 +2456,10 @@ fn execute_applescript(
         cmd.current_dir(cwd);
     }
 
+    for (key, value) in &options.env {
+        cmd.env(key, value);
+    }
+
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to execute AppleScript: {}", e))?;
@@ -1831,6 +2510,10 @@ fn execute_typescript(
         cmd.current_dir(cwd);
     }
 
+    for (key, value) in &options.env {
+        cmd.env(key, value);
+    }
+
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to execute TypeScript: {}", e))?;
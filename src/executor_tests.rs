@@ -572,18 +572,24 @@ fn test_auto_submit_config_get_arg_value() {
             name: "Apple".to_string(),
             value: "apple".to_string(),
             description: None,
+            icon: None,
+            img: None,
             semantic_id: None,
         },
         Choice {
             name: "Banana".to_string(),
             value: "banana".to_string(),
             description: None,
+            icon: None,
+            img: None,
             semantic_id: None,
         },
         Choice {
             name: "Cherry".to_string(),
             value: "cherry".to_string(),
             description: None,
+            icon: None,
+            img: None,
             semantic_id: None,
         },
     ];
@@ -702,12 +708,16 @@ fn test_auto_submit_config_get_select_value() {
             name: "Apple".to_string(),
             value: "apple".to_string(),
             description: None,
+            icon: None,
+            img: None,
             semantic_id: None,
         },
         Choice {
             name: "Banana".to_string(),
             value: "banana".to_string(),
             description: None,
+            icon: None,
+            img: None,
             semantic_id: None,
         },
     ];
@@ -1000,6 +1010,107 @@ fn test_run_scriptlet_with_cwd() {
     );
 }
 
+#[cfg(unix)]
+#[test]
+fn test_run_scriptlet_metadata_cwd_applied() {
+    let mut scriptlet = Scriptlet::new(
+        "Metadata CWD Test".to_string(),
+        "bash".to_string(),
+        "pwd".to_string(),
+    );
+    scriptlet.metadata.cwd = Some("/tmp".to_string());
+
+    let result = run_scriptlet(&scriptlet, ScriptletExecOptions::default());
+    assert!(result.is_ok(), "Expected success, got: {:?}", result);
+
+    let result = result.unwrap();
+    assert!(result.success);
+    // /tmp might be symlinked to /private/tmp on macOS
+    assert!(
+        result.stdout.contains("/tmp") || result.stdout.contains("/private/tmp"),
+        "Expected '/tmp' in stdout: {}",
+        result.stdout
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_scriptlet_options_cwd_wins_over_metadata() {
+    let mut scriptlet = Scriptlet::new(
+        "CWD Precedence Test".to_string(),
+        "bash".to_string(),
+        "pwd".to_string(),
+    );
+    // If metadata won, the spawn would fail on this nonexistent directory
+    scriptlet.metadata.cwd = Some("/nonexistent-scriptlet-cwd".to_string());
+
+    let options = ScriptletExecOptions {
+        cwd: Some(PathBuf::from("/tmp")),
+        ..Default::default()
+    };
+
+    let result = run_scriptlet(&scriptlet, options);
+    assert!(result.is_ok(), "Expected success, got: {:?}", result);
+    assert!(result.unwrap().success);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_scriptlet_metadata_env_applied() {
+    let mut scriptlet = Scriptlet::new(
+        "Metadata Env Test".to_string(),
+        "bash".to_string(),
+        "echo \"$SCRIPTLET_META_VAR\"".to_string(),
+    );
+    scriptlet.metadata.env.insert(
+        "SCRIPTLET_META_VAR".to_string(),
+        "from-metadata".to_string(),
+    );
+
+    let result = run_scriptlet(&scriptlet, ScriptletExecOptions::default());
+    assert!(result.is_ok(), "Expected success, got: {:?}", result);
+
+    let result = result.unwrap();
+    assert!(result.success);
+    assert!(
+        result.stdout.contains("from-metadata"),
+        "Expected 'from-metadata' in stdout: {}",
+        result.stdout
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_scriptlet_options_env_wins_over_metadata() {
+    let mut scriptlet = Scriptlet::new(
+        "Env Precedence Test".to_string(),
+        "bash".to_string(),
+        "echo \"$SCRIPTLET_META_VAR\"".to_string(),
+    );
+    scriptlet.metadata.env.insert(
+        "SCRIPTLET_META_VAR".to_string(),
+        "from-metadata".to_string(),
+    );
+
+    let mut env = HashMap::new();
+    env.insert("SCRIPTLET_META_VAR".to_string(), "from-caller".to_string());
+    let options = ScriptletExecOptions {
+        env,
+        ..Default::default()
+    };
+
+    let result = run_scriptlet(&scriptlet, options);
+    assert!(result.is_ok(), "Expected success, got: {:?}", result);
+
+    let result = result.unwrap();
+    assert!(result.success);
+    assert!(
+        result.stdout.contains("from-caller"),
+        "Expected 'from-caller' in stdout: {}",
+        result.stdout
+    );
+}
+
 #[test]
 fn test_run_scriptlet_template_returns_content() {
     let scriptlet = Scriptlet::new(
@@ -2169,3 +2280,212 @@ fn test_valid_tools_includes_all_typescript_tools() {
         "VALID_TOOLS should contain 'deno'"
     );
 }
+
+// ============================================================================
+// SessionReader Tests
+// ============================================================================
+
+use super::{CancellationToken, SessionRead, SessionReader};
+use crate::protocol::{JsonlReader, ParseIssueKind};
+use std::io::Cursor;
+
+#[test]
+fn test_session_reader_delivers_messages_and_eof() {
+    let jsonl = "{\"type\":\"beep\"}\n{\"type\":\"show\"}\n";
+    let reader = JsonlReader::new(Cursor::new(jsonl.to_string()));
+    let session = SessionReader::spawn(reader, CancellationToken::new());
+
+    assert!(matches!(
+        session.recv_timeout(Duration::from_secs(1)),
+        SessionRead::Message(Message::Beep {})
+    ));
+    assert!(matches!(
+        session.recv_timeout(Duration::from_secs(1)),
+        SessionRead::Message(Message::Show {})
+    ));
+    assert!(matches!(
+        session.recv_timeout(Duration::from_secs(1)),
+        SessionRead::Closed { error: None }
+    ));
+}
+
+#[test]
+fn test_session_reader_cancellation_wins_over_pending_events() {
+    let reader = JsonlReader::new(Cursor::new("{\"type\":\"beep\"}\n".to_string()));
+    let token = CancellationToken::new();
+    let session = SessionReader::spawn(reader, token.clone());
+
+    token.cancel();
+    assert!(matches!(
+        session.recv_timeout(Duration::from_secs(1)),
+        SessionRead::Cancelled
+    ));
+}
+
+#[test]
+fn test_session_reader_times_out_while_stream_is_quiet() {
+    // A stream that stays open without producing data, like a quiet script
+    struct Stalled;
+    impl std::io::Read for Stalled {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            std::thread::sleep(Duration::from_millis(500));
+            Ok(0)
+        }
+    }
+
+    let session = SessionReader::spawn(JsonlReader::new(Stalled), CancellationToken::new());
+    assert!(matches!(
+        session.recv_timeout(Duration::from_millis(50)),
+        SessionRead::TimedOut
+    ));
+}
+
+#[test]
+fn test_session_reader_forwards_parse_issues() {
+    let jsonl = "{\"type\":\"notARealType\"}\n{\"type\":\"beep\"}\n";
+    let reader = JsonlReader::new(Cursor::new(jsonl.to_string()));
+    let session = SessionReader::spawn(reader, CancellationToken::new());
+
+    match session.recv_timeout(Duration::from_secs(1)) {
+        SessionRead::Issue(issue) => assert_eq!(issue.kind, ParseIssueKind::UnknownType),
+        other => panic!("Expected parse issue, got {:?}", other),
+    }
+    assert!(matches!(
+        session.recv_timeout(Duration::from_secs(1)),
+        SessionRead::Message(Message::Beep {})
+    ));
+}
+
+// ============================================================================
+// Environment Injection Tests
+// ============================================================================
+
+use super::script_env;
+
+#[test]
+fn test_script_env_injects_standard_vars() {
+    let env = script_env("/tmp/scripts/hello.ts", &[]);
+    let lookup = |key: &str| env.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+
+    assert!(!lookup("SK_KIT_PATH").unwrap().is_empty());
+    assert_eq!(lookup("SK_SCRIPT_PATH"), Some("/tmp/scripts/hello.ts"));
+    assert!(!lookup("SK_EXECUTION_ID").unwrap().is_empty());
+}
+
+#[test]
+fn test_script_env_execution_id_is_unique_per_run() {
+    let first = script_env("/tmp/a.ts", &[]);
+    let second = script_env("/tmp/a.ts", &[]);
+    let id = |env: &[(String, String)]| {
+        env.iter()
+            .find(|(k, _)| k == "SK_EXECUTION_ID")
+            .map(|(_, v)| v.clone())
+            .unwrap()
+    };
+    assert_ne!(id(&first), id(&second));
+}
+
+#[test]
+fn test_script_env_appends_extra_entries_last() {
+    let extra = vec![
+        ("SK_THEME".to_string(), "dark".to_string()),
+        ("MY_TOKEN".to_string(), "abc".to_string()),
+    ];
+    let env = script_env("/tmp/a.ts", &extra);
+
+    // Extra entries come after the standard ones, so they win on collisions
+    // when applied in order via Command::envs
+    let tail: Vec<&str> = env.iter().rev().take(2).map(|(k, _)| k.as_str()).collect();
+    assert_eq!(tail, vec!["MY_TOKEN", "SK_THEME"]);
+}
+
+#[test]
+fn test_profile_path_prepends_additions() {
+    let additions = vec!["/opt/work/bin".to_string(), "/opt/work/sbin".to_string()];
+    let path = profile_path(&additions);
+
+    assert!(path.starts_with("/opt/work/bin:/opt/work/sbin"));
+    // The current PATH is preserved after the additions
+    if let Ok(existing) = std::env::var("PATH") {
+        if !existing.is_empty() {
+            assert!(path.ends_with(&existing));
+        }
+    }
+}
+
+#[test]
+fn test_profile_path_expands_tilde() {
+    let additions = vec!["~/bin".to_string()];
+    let path = profile_path(&additions);
+
+    assert!(!path.starts_with("~"), "tilde should be expanded: {}", path);
+}
+
+#[test]
+fn test_parse_top_user_frame_parenthesized() {
+    let stderr = "Error: boom\n    at doThing (/Users/me/.kit/scripts/deploy.ts:12:5)\n    at main (/Users/me/.kit/scripts/deploy.ts:30:1)";
+    let frame = parse_top_user_frame(stderr).unwrap();
+    assert_eq!(frame.path, "/Users/me/.kit/scripts/deploy.ts");
+    assert_eq!(frame.line, 12);
+    assert_eq!(frame.column, Some(5));
+}
+
+#[test]
+fn test_parse_top_user_frame_bare_location() {
+    let stderr = "TypeError: x is not a function\n    at /Users/me/script.ts:3:10";
+    let frame = parse_top_user_frame(stderr).unwrap();
+    assert_eq!(frame.path, "/Users/me/script.ts");
+    assert_eq!(frame.line, 3);
+    assert_eq!(frame.column, Some(10));
+}
+
+#[test]
+fn test_parse_top_user_frame_skips_internals() {
+    // Internals and node_modules frames are skipped in favor of user code
+    let stderr = "Error: boom\n    at emit (node:events:512:28)\n    at run (native)\n    at wrapped (/proj/node_modules/lib/index.js:9:1)\n    at handler (/proj/script.ts:42:7)";
+    let frame = parse_top_user_frame(stderr).unwrap();
+    assert_eq!(frame.path, "/proj/script.ts");
+    assert_eq!(frame.line, 42);
+}
+
+#[test]
+fn test_parse_top_user_frame_no_frames() {
+    assert_eq!(parse_top_user_frame("Error: boom"), None);
+    assert_eq!(parse_top_user_frame("at nothing useful"), None);
+}
+
+#[test]
+fn test_parse_top_user_frame_line_without_column() {
+    let stderr = "Error: boom\n    at /proj/script.ts:7";
+    let frame = parse_top_user_frame(stderr).unwrap();
+    assert_eq!(frame.path, "/proj/script.ts");
+    assert_eq!(frame.line, 7);
+    assert_eq!(frame.column, None);
+}
+
+#[test]
+fn test_read_source_snippet_centers_on_line() {
+    let path = std::env::temp_dir().join(format!("sk-snippet-test-{}.ts", std::process::id()));
+    std::fs::write(&path, "one\ntwo\nthree\nfour\nfive\n").unwrap();
+
+    let snippet = read_source_snippet(path.to_str().unwrap(), 3, 1).unwrap();
+    assert_eq!(
+        snippet,
+        vec![
+            (2, "two".to_string()),
+            (3, "three".to_string()),
+            (4, "four".to_string())
+        ]
+    );
+
+    // Clamped at the edges of the file
+    let snippet = read_source_snippet(path.to_str().unwrap(), 1, 2).unwrap();
+    assert_eq!(snippet[0].0, 1);
+    assert_eq!(snippet.last().unwrap().0, 3);
+
+    // Out-of-range lines and unreadable files return None
+    assert!(read_source_snippet(path.to_str().unwrap(), 99, 1).is_none());
+    assert!(read_source_snippet("/nonexistent/file.ts", 1, 1).is_none());
+
+    std::fs::remove_file(path).ok();
+}
@@ -21,7 +21,7 @@
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::thread;
 use std::time::Duration;
 
@@ -307,6 +307,7 @@ impl ExpandManager {
                         if let Some(scriptlet) = scriptlet_opt {
                             // Perform expansion in a separate thread to not block the callback
                             let chars_to_delete = result.chars_to_delete;
+                            let trigger_keyword = result.trigger.clone();
                             let content = scriptlet.content.clone();
                             let tool = scriptlet.tool.clone();
                             let name = scriptlet.name.clone();
@@ -314,6 +315,17 @@ impl ExpandManager {
                             let injector_config_clone = injector_config.clone();
 
                             thread::spawn(move || {
+                                // Per-app disable rules: leave the typed trigger
+                                // untouched in apps the user excluded
+                                if let Some(app) = expansion_disabled_in_frontmost() {
+                                    info!(
+                                        app = %app,
+                                        trigger = %trigger_keyword,
+                                        "Expansion disabled in frontmost app, skipping"
+                                    );
+                                    return;
+                                }
+
                                 // Small delay to let the keyboard event complete
                                 thread::sleep(Duration::from_millis(config_clone.stop_delay_ms));
 
@@ -368,6 +380,9 @@ impl ExpandManager {
                                     return;
                                 }
 
+                                // Count the expansion for the stats view
+                                record_expansion(&trigger_keyword);
+
                                 info!(
                                     trigger = %name,
                                     replacement_len = replacement.len(),
@@ -806,6 +821,181 @@ impl Drop for ExpandManager {
     }
 }
 
+// ============================================================================
+// Expansion statistics & per-app disable rules
+// ============================================================================
+//
+// These live at module level (not on ExpandManager) because the production
+// manager instance is forgotten in a background thread at startup; the
+// Expansion Stats manager view reads through these free functions instead.
+
+/// Usage counts by trigger, persisted to ~/.sk/kit/expand-stats.json
+static USAGE_COUNTS: OnceLock<Mutex<HashMap<String, u64>>> = OnceLock::new();
+
+/// Lowercased app name / bundle id patterns where expansion is disabled
+static DISABLED_APPS: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
+/// Path to the persisted usage counts (~/.sk/kit/expand-stats.json)
+fn stats_file_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/.sk/kit/expand-stats.json").as_ref())
+}
+
+/// Get the usage counts map, loading it from disk on first access
+fn usage_counts() -> &'static Mutex<HashMap<String, u64>> {
+    USAGE_COUNTS.get_or_init(|| {
+        let mut counts = HashMap::new();
+        if let Ok(contents) = std::fs::read_to_string(stats_file_path()) {
+            if let Ok(serde_json::Value::Object(map)) = serde_json::from_str(&contents) {
+                for (trigger, value) in map {
+                    if let Some(count) = value.as_u64() {
+                        counts.insert(trigger, count);
+                    }
+                }
+            }
+        }
+        Mutex::new(counts)
+    })
+}
+
+/// Record a successful expansion of `trigger` and persist the counts
+pub fn record_expansion(trigger: &str) {
+    let snapshot = {
+        let mut counts = usage_counts().lock().unwrap();
+        *counts.entry(trigger.to_string()).or_insert(0) += 1;
+        counts.clone()
+    };
+    let json = serde_json::to_value(&snapshot).unwrap_or_default();
+    if let Err(e) = std::fs::write(stats_file_path(), format!("{:#}", json)) {
+        warn!(error = %e, "Failed to save expansion stats");
+    }
+}
+
+/// Replace the per-app disable list from config (`expandDisabledApps`)
+///
+/// Patterns are matched case-insensitively as substrings against the
+/// frontmost app's display name and bundle identifier.
+pub fn set_disabled_apps(patterns: Vec<String>) {
+    let normalized: Vec<String> = patterns
+        .iter()
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let count = normalized.len();
+    *DISABLED_APPS
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap() = normalized;
+    debug!(count, "Updated expansion disabled-app patterns");
+}
+
+/// Check an app name and bundle id against the disable patterns
+fn matches_disabled_app(name: &str, bundle_id: Option<&str>) -> bool {
+    let Some(patterns) = DISABLED_APPS.get() else {
+        return false;
+    };
+    let patterns = patterns.lock().unwrap();
+    let name_lower = name.to_lowercase();
+    let bundle_lower = bundle_id.map(|b| b.to_lowercase());
+    patterns.iter().any(|p| {
+        name_lower.contains(p)
+            || bundle_lower
+                .as_ref()
+                .map(|b| b.contains(p))
+                .unwrap_or(false)
+    })
+}
+
+/// Name of the frontmost app if expansion is disabled there, else None
+fn expansion_disabled_in_frontmost() -> Option<String> {
+    // Skip the frontmost-app lookup entirely when no rules are configured
+    let has_patterns = DISABLED_APPS
+        .get()
+        .map(|p| !p.lock().unwrap().is_empty())
+        .unwrap_or(false);
+    if !has_patterns {
+        return None;
+    }
+
+    let app = crate::clipboard_history::frontmost_app()?;
+    if matches_disabled_app(&app.name, app.bundle_id.as_deref()) {
+        Some(app.name)
+    } else {
+        None
+    }
+}
+
+/// One row in the Expansion Stats manager view
+#[derive(Debug, Clone)]
+pub struct ExpandStatEntry {
+    /// The trigger keyword (e.g., ":sig")
+    pub trigger: String,
+    /// The scriptlet name
+    pub name: String,
+    /// Source file path, if known
+    pub source_path: Option<String>,
+    /// How many times this trigger has expanded
+    pub usage_count: u64,
+    /// Names of other scriptlets claiming the same trigger
+    pub conflicts_with: Vec<String>,
+}
+
+/// Build statistics rows from (trigger, name, source_path) tuples and counts
+///
+/// Conflicts are triggers claimed by more than one scriptlet; only one of
+/// them actually wins at registration time (HashMap insert order), so the
+/// manager view flags all of them.
+fn build_stat_entries(
+    scriptlets: &[(String, String, Option<String>)],
+    counts: &HashMap<String, u64>,
+) -> Vec<ExpandStatEntry> {
+    let mut entries: Vec<ExpandStatEntry> = scriptlets
+        .iter()
+        .enumerate()
+        .map(|(ix, (trigger, name, source_path))| ExpandStatEntry {
+            trigger: trigger.clone(),
+            name: name.clone(),
+            source_path: source_path.clone(),
+            usage_count: counts.get(trigger).copied().unwrap_or(0),
+            conflicts_with: scriptlets
+                .iter()
+                .enumerate()
+                .filter(|(other_ix, (other_trigger, _, _))| {
+                    *other_ix != ix && other_trigger == trigger
+                })
+                .map(|(_, (_, other_name, _))| other_name.clone())
+                .collect(),
+        })
+        .collect();
+
+    // Most-used first, then by trigger for a stable ordering
+    entries.sort_by(|a, b| {
+        b.usage_count
+            .cmp(&a.usage_count)
+            .then_with(|| a.trigger.cmp(&b.trigger))
+    });
+    entries
+}
+
+/// Scan scriptlets and build statistics rows for the manager view
+///
+/// Re-reads scriptlet files each call so the view reflects edits made
+/// since the expansion system started.
+pub fn expand_stats() -> Vec<ExpandStatEntry> {
+    let scriptlets: Vec<(String, String, Option<String>)> = read_scriptlets()
+        .into_iter()
+        .filter_map(|s| {
+            let trigger = s.expand.clone()?;
+            if trigger.is_empty() {
+                return None;
+            }
+            Some((trigger, s.name.clone(), s.file_path.clone()))
+        })
+        .collect();
+
+    let counts = usage_counts().lock().unwrap().clone();
+    build_stat_entries(&scriptlets, &counts)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1233,6 +1423,75 @@ mod tests {
         assert!(triggers.contains(&":b".to_string()));
     }
 
+    // ========================================
+    // Expansion Statistics Tests
+    // ========================================
+
+    #[test]
+    fn test_build_stat_entries_detects_conflicts() {
+        let scriptlets = vec![
+            (
+                ":sig".to_string(),
+                "Work Signature".to_string(),
+                Some("/test/work.md".to_string()),
+            ),
+            (
+                ":sig".to_string(),
+                "Personal Signature".to_string(),
+                Some("/test/personal.md".to_string()),
+            ),
+            (":addr".to_string(), "Address".to_string(), None),
+        ];
+        let counts = HashMap::new();
+
+        let entries = build_stat_entries(&scriptlets, &counts);
+        assert_eq!(entries.len(), 3);
+
+        let work = entries.iter().find(|e| e.name == "Work Signature").unwrap();
+        assert_eq!(work.conflicts_with, vec!["Personal Signature".to_string()]);
+
+        let personal = entries
+            .iter()
+            .find(|e| e.name == "Personal Signature")
+            .unwrap();
+        assert_eq!(personal.conflicts_with, vec!["Work Signature".to_string()]);
+
+        let addr = entries.iter().find(|e| e.trigger == ":addr").unwrap();
+        assert!(addr.conflicts_with.is_empty());
+    }
+
+    #[test]
+    fn test_build_stat_entries_sorted_by_usage() {
+        let scriptlets = vec![
+            (":rare".to_string(), "Rare".to_string(), None),
+            (":common".to_string(), "Common".to_string(), None),
+            (":unused".to_string(), "Unused".to_string(), None),
+        ];
+        let mut counts = HashMap::new();
+        counts.insert(":common".to_string(), 42);
+        counts.insert(":rare".to_string(), 3);
+
+        let entries = build_stat_entries(&scriptlets, &counts);
+        assert_eq!(entries[0].trigger, ":common");
+        assert_eq!(entries[0].usage_count, 42);
+        assert_eq!(entries[1].trigger, ":rare");
+        assert_eq!(entries[2].trigger, ":unused");
+        assert_eq!(entries[2].usage_count, 0);
+    }
+
+    #[test]
+    fn test_disabled_app_matching() {
+        set_disabled_apps(vec!["1Password".to_string(), "com.banking".to_string()]);
+
+        assert!(matches_disabled_app("1Password 8", None));
+        assert!(matches_disabled_app("My Bank", Some("com.banking.mobile")));
+        assert!(!matches_disabled_app("Safari", Some("com.apple.Safari")));
+
+        // Clearing the list disables the rules again
+        set_disabled_apps(Vec::new());
+        assert!(!matches_disabled_app("1Password 8", None));
+    }
+
     // Integration tests that require system permissions
     #[test]
     #[ignore = "Requires accessibility permissions"]
@@ -0,0 +1,703 @@
+//! Move Files builtin - two-pane "send to" workflow
+//!
+//! Flow (rendered in render_builtins.rs):
+//! 1. Pick files: browse a directory and toggle files/folders with Space
+//! 2. Pick destination: browse directories (bookmarked destinations first),
+//!    Enter moves the selection into the highlighted destination
+//! 3. Conflicts: when any target name already exists, choose how to resolve
+//!    (skip / replace / keep both) before anything is touched
+//! 4. Moving: renames when possible, falls back to a chunked copy + delete
+//!    across filesystems, with byte-level progress for large copies
+//!
+//! Destination bookmarks persist to `~/.sk/kit/move-bookmarks.json`; the most
+//! recently used destination is bookmarked automatically after each move.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::logging;
+
+/// File destination bookmarks are persisted to
+const BOOKMARKS_FILE: &str = "~/.sk/kit/move-bookmarks.json";
+
+/// Maximum bookmarked destinations kept (most recent first)
+const MAX_BOOKMARKS: usize = 10;
+
+/// Copy buffer size for the cross-filesystem fallback
+const COPY_CHUNK: usize = 1024 * 1024;
+
+// ============================================================================
+// Bookmarks
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Bookmarks {
+    /// Destination directories, most recently used first
+    dirs: Vec<String>,
+}
+
+fn bookmarks_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde(BOOKMARKS_FILE).into_owned())
+}
+
+fn load_bookmarks_from(path: &Path) -> Bookmarks {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_bookmarks_to(path: &Path, bookmarks: &Bookmarks) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(bookmarks)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    std::fs::write(path, json)
+}
+
+/// Bookmarked destination directories, most recently used first
+pub fn bookmarks() -> Vec<PathBuf> {
+    load_bookmarks_from(&bookmarks_path())
+        .dirs
+        .into_iter()
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Record a destination as the most recently used bookmark
+pub fn add_bookmark(dir: &Path) {
+    add_bookmark_at(&bookmarks_path(), dir);
+}
+
+fn add_bookmark_at(path: &Path, dir: &Path) {
+    let mut bookmarks = load_bookmarks_from(path);
+    let entry = dir.to_string_lossy().into_owned();
+    bookmarks.dirs.retain(|d| *d != entry);
+    bookmarks.dirs.insert(0, entry);
+    bookmarks.dirs.truncate(MAX_BOOKMARKS);
+    if let Err(e) = save_bookmarks_to(path, &bookmarks) {
+        logging::log("MOVE", &format!("Failed to save bookmarks: {}", e));
+    }
+}
+
+// ============================================================================
+// Move plan + conflict resolution
+// ============================================================================
+
+/// How name collisions in the destination are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictChoice {
+    /// Leave conflicting sources where they are
+    Skip,
+    /// Overwrite the existing target
+    Replace,
+    /// Move under a unique "name 2.ext" style name
+    KeepBoth,
+}
+
+/// One planned move
+#[derive(Debug, Clone)]
+pub struct MoveItem {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    /// Target name already exists in the destination
+    pub conflict: bool,
+}
+
+/// Plan moving `sources` into `dest`, flagging name collisions
+pub fn plan_moves(sources: &[PathBuf], dest: &Path) -> Vec<MoveItem> {
+    sources
+        .iter()
+        .map(|from| {
+            let name = from
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let to = dest.join(name);
+            let conflict = to != *from && to.exists();
+            MoveItem {
+                from: from.clone(),
+                to,
+                conflict,
+            }
+        })
+        .collect()
+}
+
+/// Next free "name 2.ext" style variant of a target path
+fn unique_target(to: &Path, taken: &[PathBuf]) -> PathBuf {
+    let dir = to.parent().map(Path::to_path_buf).unwrap_or_default();
+    let stem = to
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = to.extension().map(|e| e.to_string_lossy().into_owned());
+
+    for n in 2.. {
+        let name = match &ext {
+            Some(ext) => format!("{} {}.{}", stem, n, ext),
+            None => format!("{} {}", stem, n),
+        };
+        let candidate = dir.join(name);
+        if !candidate.exists() && !taken.contains(&candidate) {
+            return candidate;
+        }
+    }
+    unreachable!()
+}
+
+/// Resolve a plan into final (from, to) pairs
+///
+/// Sources already at their target are always dropped; conflicting items are
+/// dropped, kept, or renamed according to `choice`.
+pub fn resolve_moves(plan: &[MoveItem], choice: ConflictChoice) -> Vec<(PathBuf, PathBuf)> {
+    let mut taken: Vec<PathBuf> = Vec::new();
+    let mut moves = Vec::new();
+    for item in plan {
+        if item.to == item.from {
+            continue;
+        }
+        let to = if item.conflict {
+            match choice {
+                ConflictChoice::Skip => continue,
+                ConflictChoice::Replace => item.to.clone(),
+                ConflictChoice::KeepBoth => unique_target(&item.to, &taken),
+            }
+        } else {
+            item.to.clone()
+        };
+        taken.push(to.clone());
+        moves.push((item.from.clone(), to));
+    }
+    moves
+}
+
+// ============================================================================
+// Executing moves with progress
+// ============================================================================
+
+/// Progress shared between the move thread and the render loop
+#[derive(Debug, Default)]
+pub struct MoveProgress {
+    pub files_total: AtomicUsize,
+    pub files_done: AtomicUsize,
+    pub bytes_total: AtomicU64,
+    pub bytes_done: AtomicU64,
+    /// File currently being moved
+    pub current: Mutex<String>,
+    pub errors: Mutex<Vec<String>>,
+    pub finished: AtomicBool,
+}
+
+impl MoveProgress {
+    /// Completed fraction in 0.0..=1.0 (byte-weighted)
+    pub fn fraction(&self) -> f32 {
+        let total = self.bytes_total.load(Ordering::Relaxed);
+        if total == 0 {
+            return if self.finished.load(Ordering::Relaxed) {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        (self.bytes_done.load(Ordering::Relaxed) as f32 / total as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// Recursive size of a path in bytes
+fn path_size(path: &Path) -> u64 {
+    if path.is_dir() {
+        std::fs::read_dir(path)
+            .map(|entries| entries.flatten().map(|e| path_size(&e.path())).sum())
+            .unwrap_or(0)
+    } else {
+        path.metadata().map(|m| m.len()).unwrap_or(0)
+    }
+}
+
+/// Copy one file in chunks, advancing byte progress as it goes
+fn copy_file_chunked(from: &Path, to: &Path, progress: &MoveProgress) -> std::io::Result<()> {
+    use std::io::{Read, Write};
+    let mut reader = std::fs::File::open(from)?;
+    let mut writer = std::fs::File::create(to)?;
+    let mut buf = vec![0u8; COPY_CHUNK];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        writer.write_all(&buf[..n])?;
+        progress.bytes_done.fetch_add(n as u64, Ordering::Relaxed);
+    }
+    Ok(())
+}
+
+/// Recursively copy a directory tree with chunked file copies
+fn copy_dir_recursive(from: &Path, to: &Path, progress: &MoveProgress) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)?.flatten() {
+        let src = entry.path();
+        let dst = to.join(entry.file_name());
+        if src.is_dir() {
+            copy_dir_recursive(&src, &dst, progress)?;
+        } else {
+            copy_file_chunked(&src, &dst, progress)?;
+        }
+    }
+    Ok(())
+}
+
+/// Move one path, preferring rename and falling back to copy + delete
+fn move_path(from: &Path, to: &Path, progress: &MoveProgress) -> std::io::Result<()> {
+    let size = path_size(from);
+
+    // Replace: clear the target first (rename won't overwrite directories)
+    if to.exists() {
+        if to.is_dir() {
+            std::fs::remove_dir_all(to)?;
+        } else {
+            std::fs::remove_file(to)?;
+        }
+    }
+
+    if std::fs::rename(from, to).is_ok() {
+        progress.bytes_done.fetch_add(size, Ordering::Relaxed);
+        return Ok(());
+    }
+
+    // Cross-filesystem: copy then delete the source
+    if from.is_dir() {
+        copy_dir_recursive(from, to, progress)?;
+        std::fs::remove_dir_all(from)?;
+    } else {
+        copy_file_chunked(from, to, progress)?;
+        std::fs::remove_file(from)?;
+    }
+    Ok(())
+}
+
+/// Execute resolved moves, updating `progress` as each path completes
+///
+/// Intended to run on a worker thread; the view polls `progress` and shows a
+/// byte-weighted bar. Failures are collected rather than aborting the batch.
+pub fn run_moves(moves: Vec<(PathBuf, PathBuf)>, progress: Arc<MoveProgress>) {
+    progress.files_total.store(moves.len(), Ordering::Relaxed);
+    let total: u64 = moves.iter().map(|(from, _)| path_size(from)).sum();
+    progress.bytes_total.store(total, Ordering::Relaxed);
+
+    for (from, to) in &moves {
+        if let Ok(mut current) = progress.current.lock() {
+            *current = from
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+        }
+        if let Err(e) = move_path(from, to, &progress) {
+            logging::log("MOVE", &format!("Failed to move {}: {}", from.display(), e));
+            if let Ok(mut errors) = progress.errors.lock() {
+                errors.push(format!("{}: {}", from.display(), e));
+            }
+        }
+        progress.files_done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    logging::log("MOVE", &format!("Moved {} item(s)", moves.len()));
+    progress.finished.store(true, Ordering::Relaxed);
+}
+
+// ============================================================================
+// View state
+// ============================================================================
+
+/// Which stage of the flow is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Browse a directory and toggle items with Space
+    PickFiles,
+    /// Browse directories/bookmarks and pick where to move
+    PickDest,
+    /// Choose skip/replace/keep-both for name collisions
+    Conflicts,
+    /// Moves running on a worker thread
+    Moving,
+}
+
+/// One row of the destination list
+#[derive(Debug, Clone)]
+pub enum DestRow {
+    /// Move into the directory currently browsed
+    Here,
+    /// A bookmarked destination
+    Bookmark(PathBuf),
+    /// A subdirectory of the browsed directory
+    Dir(String),
+}
+
+/// State backing the Move Files builtin view
+#[derive(Debug, Clone)]
+pub struct MoverState {
+    pub stage: Stage,
+    /// Directory currently browsed (pick and destination stages)
+    pub dir: PathBuf,
+    /// (name, is_dir) entries of `dir`, dirs first, sorted
+    pub entries: Vec<(String, bool)>,
+    /// Absolute paths toggled for moving (across directories)
+    pub selected: Vec<PathBuf>,
+    /// Filter text for the active list
+    pub filter: String,
+    /// Focused row in the active list
+    pub selected_index: usize,
+    /// Planned moves once a destination is chosen
+    pub plan: Vec<MoveItem>,
+    /// Progress of the running move, set when the Moving stage starts
+    pub progress: Option<Arc<MoveProgress>>,
+}
+
+impl MoverState {
+    pub fn new() -> Self {
+        let dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+        let entries = Self::load_dir(&dir);
+        MoverState {
+            stage: Stage::PickFiles,
+            dir,
+            entries,
+            selected: Vec::new(),
+            filter: String::new(),
+            selected_index: 0,
+            plan: Vec::new(),
+            progress: None,
+        }
+    }
+
+    /// List a directory: visible entries only, dirs first, sorted by name
+    fn load_dir(dir: &Path) -> Vec<(String, bool)> {
+        let mut dirs_list: Vec<(String, bool)> = Vec::new();
+        let mut files: Vec<(String, bool)> = Vec::new();
+        if let Ok(read_dir) = std::fs::read_dir(dir) {
+            for entry in read_dir.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with('.') {
+                    continue;
+                }
+                let is_dir = entry.path().is_dir();
+                if is_dir {
+                    dirs_list.push((name, true));
+                } else {
+                    files.push((name, false));
+                }
+            }
+        }
+        dirs_list.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        files.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        dirs_list.extend(files);
+        dirs_list
+    }
+
+    /// Entries matching the current filter (pick stage)
+    pub fn visible_entries(&self) -> Vec<(String, bool)> {
+        if self.filter.is_empty() {
+            return self.entries.clone();
+        }
+        let filter_lower = self.filter.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|(name, _)| name.to_lowercase().contains(&filter_lower))
+            .cloned()
+            .collect()
+    }
+
+    /// Rows of the destination list: move-here, bookmarks, then subdirs
+    pub fn dest_rows(&self) -> Vec<DestRow> {
+        let filter_lower = self.filter.to_lowercase();
+        let mut rows = vec![DestRow::Here];
+        for bookmark in bookmarks() {
+            if bookmark.is_dir()
+                && (filter_lower.is_empty()
+                    || bookmark
+                        .to_string_lossy()
+                        .to_lowercase()
+                        .contains(&filter_lower))
+            {
+                rows.push(DestRow::Bookmark(bookmark));
+            }
+        }
+        for (name, is_dir) in &self.entries {
+            if *is_dir && (filter_lower.is_empty() || name.to_lowercase().contains(&filter_lower)) {
+                rows.push(DestRow::Dir(name.clone()));
+            }
+        }
+        rows
+    }
+
+    /// Navigate into a subdirectory (by visible index of the active list)
+    pub fn enter_dir(&mut self, visible_index: usize) {
+        let target = match self.stage {
+            Stage::PickFiles => match self.visible_entries().get(visible_index) {
+                Some((name, true)) => Some(self.dir.join(name)),
+                _ => None,
+            },
+            Stage::PickDest => match self.dest_rows().get(visible_index) {
+                Some(DestRow::Dir(name)) => Some(self.dir.join(name)),
+                Some(DestRow::Bookmark(path)) => Some(path.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(dir) = target {
+            self.dir = dir;
+            self.entries = Self::load_dir(&self.dir);
+            self.filter.clear();
+            self.selected_index = 0;
+        }
+    }
+
+    /// Navigate to the parent directory
+    pub fn parent_dir(&mut self) {
+        if let Some(parent) = self.dir.parent() {
+            self.dir = parent.to_path_buf();
+            self.entries = Self::load_dir(&self.dir);
+            self.filter.clear();
+            self.selected_index = 0;
+        }
+    }
+
+    /// Toggle the item at a visible index in/out of the selection
+    pub fn toggle_selected(&mut self, visible_index: usize) {
+        if let Some((name, _)) = self.visible_entries().get(visible_index).cloned() {
+            let path = self.dir.join(name);
+            if let Some(pos) = self.selected.iter().position(|p| *p == path) {
+                self.selected.remove(pos);
+            } else {
+                self.selected.push(path);
+            }
+        }
+    }
+
+    /// Whether the item at a visible index is currently selected
+    pub fn is_selected(&self, visible_index: usize) -> bool {
+        self.visible_entries()
+            .get(visible_index)
+            .map(|(name, _)| self.dir.join(name))
+            .is_some_and(|path| self.selected.contains(&path))
+    }
+
+    /// Move to the destination stage (no-op with an empty selection)
+    pub fn enter_dest_stage(&mut self) {
+        if !self.selected.is_empty() {
+            self.stage = Stage::PickDest;
+            self.filter.clear();
+            self.selected_index = 0;
+        }
+    }
+
+    /// Back to the pick stage, keeping the selection
+    pub fn back_to_pick(&mut self) {
+        self.stage = Stage::PickFiles;
+        self.filter.clear();
+        self.selected_index = 0;
+    }
+
+    /// Destination the highlighted row resolves to (destination stage)
+    pub fn chosen_dest(&self, visible_index: usize) -> Option<PathBuf> {
+        match self.dest_rows().get(visible_index)? {
+            DestRow::Here => Some(self.dir.clone()),
+            DestRow::Bookmark(path) => Some(path.clone()),
+            DestRow::Dir(name) => Some(self.dir.join(name)),
+        }
+    }
+
+    /// Plan moving the selection into `dest`
+    ///
+    /// Advances to the Conflicts stage when any target name collides, or
+    /// straight to Moving otherwise. Returns true when moves can start
+    /// immediately (the caller spawns the worker thread).
+    pub fn choose_destination(&mut self, dest: PathBuf) -> bool {
+        self.plan = plan_moves(&self.selected, &dest);
+        self.dir = dest;
+        self.selected_index = 0;
+        if self.plan.iter().any(|item| item.conflict) {
+            self.stage = Stage::Conflicts;
+            false
+        } else {
+            self.stage = Stage::Moving;
+            true
+        }
+    }
+
+    /// Conflicting items in the current plan
+    pub fn conflicts(&self) -> Vec<&MoveItem> {
+        self.plan.iter().filter(|item| item.conflict).collect()
+    }
+}
+
+impl Default for MoverState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sk-mover-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn touch(dir: &Path, name: &str, contents: &[u8]) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_plan_flags_conflicts() {
+        let src = temp_dir("plan-src");
+        let dest = temp_dir("plan-dest");
+        touch(&src, "a.txt", b"a");
+        touch(&src, "b.txt", b"b");
+        touch(&dest, "a.txt", b"old");
+
+        let plan = plan_moves(&[src.join("a.txt"), src.join("b.txt")], &dest);
+        assert!(plan[0].conflict);
+        assert!(!plan[1].conflict);
+        let _ = std::fs::remove_dir_all(&src);
+        let _ = std::fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_resolve_skip_drops_conflicts() {
+        let plan = vec![
+            MoveItem {
+                from: PathBuf::from("/tmp/a.txt"),
+                to: PathBuf::from("/dest/a.txt"),
+                conflict: true,
+            },
+            MoveItem {
+                from: PathBuf::from("/tmp/b.txt"),
+                to: PathBuf::from("/dest/b.txt"),
+                conflict: false,
+            },
+        ];
+        let moves = resolve_moves(&plan, ConflictChoice::Skip);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].0, PathBuf::from("/tmp/b.txt"));
+    }
+
+    #[test]
+    fn test_resolve_replace_keeps_target() {
+        let plan = vec![MoveItem {
+            from: PathBuf::from("/tmp/a.txt"),
+            to: PathBuf::from("/dest/a.txt"),
+            conflict: true,
+        }];
+        let moves = resolve_moves(&plan, ConflictChoice::Replace);
+        assert_eq!(moves[0].1, PathBuf::from("/dest/a.txt"));
+    }
+
+    #[test]
+    fn test_resolve_keep_both_renames() {
+        let dest = temp_dir("keepboth");
+        touch(&dest, "a.txt", b"old");
+        let plan = vec![MoveItem {
+            from: PathBuf::from("/tmp/a.txt"),
+            to: dest.join("a.txt"),
+            conflict: true,
+        }];
+        let moves = resolve_moves(&plan, ConflictChoice::KeepBoth);
+        assert_eq!(moves[0].1, dest.join("a 2.txt"));
+        let _ = std::fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_resolve_drops_same_place() {
+        let plan = vec![MoveItem {
+            from: PathBuf::from("/dest/a.txt"),
+            to: PathBuf::from("/dest/a.txt"),
+            conflict: false,
+        }];
+        assert!(resolve_moves(&plan, ConflictChoice::Replace).is_empty());
+    }
+
+    #[test]
+    fn test_run_moves_moves_files() {
+        let src = temp_dir("run-src");
+        let dest = temp_dir("run-dest");
+        touch(&src, "a.txt", b"hello");
+
+        let progress = Arc::new(MoveProgress::default());
+        run_moves(
+            vec![(src.join("a.txt"), dest.join("a.txt"))],
+            progress.clone(),
+        );
+
+        assert!(dest.join("a.txt").exists());
+        assert!(!src.join("a.txt").exists());
+        assert!(progress.finished.load(Ordering::Relaxed));
+        assert_eq!(progress.files_done.load(Ordering::Relaxed), 1);
+        let _ = std::fs::remove_dir_all(&src);
+        let _ = std::fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_run_moves_replace_overwrites() {
+        let src = temp_dir("replace-src");
+        let dest = temp_dir("replace-dest");
+        touch(&src, "a.txt", b"new");
+        touch(&dest, "a.txt", b"old");
+
+        run_moves(
+            vec![(src.join("a.txt"), dest.join("a.txt"))],
+            Arc::new(MoveProgress::default()),
+        );
+
+        assert_eq!(std::fs::read(dest.join("a.txt")).unwrap(), b"new");
+        let _ = std::fs::remove_dir_all(&src);
+        let _ = std::fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_run_moves_moves_directories() {
+        let src = temp_dir("dir-src");
+        let dest = temp_dir("dir-dest");
+        std::fs::create_dir_all(src.join("nested")).unwrap();
+        touch(&src.join("nested"), "inner.txt", b"x");
+
+        run_moves(
+            vec![(src.join("nested"), dest.join("nested"))],
+            Arc::new(MoveProgress::default()),
+        );
+
+        assert!(dest.join("nested/inner.txt").exists());
+        assert!(!src.join("nested").exists());
+        let _ = std::fs::remove_dir_all(&src);
+        let _ = std::fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_bookmarks_dedupe_and_order() {
+        let dir = temp_dir("bookmarks");
+        let path = dir.join("bookmarks.json");
+        add_bookmark_at(&path, Path::new("/tmp/one"));
+        add_bookmark_at(&path, Path::new("/tmp/two"));
+        add_bookmark_at(&path, Path::new("/tmp/one"));
+
+        let loaded = load_bookmarks_from(&path);
+        assert_eq!(loaded.dirs, vec!["/tmp/one", "/tmp/two"]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_progress_fraction() {
+        let progress = MoveProgress::default();
+        progress.bytes_total.store(100, Ordering::Relaxed);
+        progress.bytes_done.store(25, Ordering::Relaxed);
+        assert!((progress.fraction() - 0.25).abs() < f32::EPSILON);
+    }
+}
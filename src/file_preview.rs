@@ -0,0 +1,466 @@
+//! Type-appropriate file previews for PathPrompt and file search
+//!
+//! Maps a selected file to preview content based on its extension:
+//! - `.json`: pretty-printed head
+//! - `.csv` / `.tsv`: table head (first rows, quote-aware splitting)
+//! - `.md`: text head with lightweight heading emphasis
+//! - `.png`: decoded thumbnail with dimensions and size
+//!
+//! Reads are capped (MAX_PREVIEW_BYTES / MAX_IMAGE_BYTES) so previewing a
+//! multi-gigabyte file never blocks the render loop. Callers should cache
+//! the result per path and only reload when the selection changes.
+
+#![allow(dead_code)]
+
+use gpui::*;
+use std::io::Read as _;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::list_item::decode_png_to_render_image_with_bgra_conversion;
+
+/// Maximum bytes read from a text file for preview
+pub const MAX_PREVIEW_BYTES: u64 = 64 * 1024;
+/// Maximum lines shown for JSON/markdown previews
+pub const MAX_PREVIEW_LINES: usize = 60;
+/// Maximum data rows shown for CSV previews
+pub const MAX_CSV_ROWS: usize = 15;
+/// Maximum columns shown for CSV previews
+pub const MAX_CSV_COLS: usize = 6;
+/// Maximum bytes decoded for image previews (PNG decode needs the full file)
+pub const MAX_IMAGE_BYTES: u64 = 8 * 1024 * 1024;
+/// Maximum characters per preview line before truncation
+const MAX_LINE_CHARS: usize = 120;
+
+/// Structured preview content for a file
+pub enum FilePreview {
+    /// Pretty-printed JSON head (raw head if the file doesn't parse)
+    Json { lines: Vec<String> },
+    /// Table head for CSV/TSV files
+    Csv {
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+        total_truncated: bool,
+    },
+    /// Markdown text head (headings get emphasis at render time)
+    Markdown { lines: Vec<String> },
+    /// Decoded image thumbnail with metadata
+    Image {
+        image: Arc<RenderImage>,
+        width: u32,
+        height: u32,
+        size_bytes: u64,
+    },
+}
+
+/// Build a preview for a path, or None when the extension isn't previewable
+/// (or the file can't be read). Call on selection change, not per frame.
+pub fn preview_for_path(path: &str) -> Option<FilePreview> {
+    let ext = Path::new(path)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())?;
+
+    match ext.as_str() {
+        "json" => Some(json_preview(path)),
+        "csv" => Some(csv_preview(path, ',')),
+        "tsv" => Some(csv_preview(path, '\t')),
+        "md" | "markdown" => Some(markdown_preview(path)),
+        // Only PNG: the image crate is built with the png feature alone
+        "png" => image_preview(path),
+        _ => None,
+    }
+    .filter(|p| !is_empty_preview(p))
+}
+
+/// True when a text preview came back with no content (unreadable file)
+fn is_empty_preview(preview: &FilePreview) -> bool {
+    match preview {
+        FilePreview::Json { lines } | FilePreview::Markdown { lines } => lines.is_empty(),
+        FilePreview::Csv { columns, .. } => columns.is_empty(),
+        FilePreview::Image { .. } => false,
+    }
+}
+
+/// Read at most MAX_PREVIEW_BYTES from a file, lossily decoded
+fn read_head(path: &str) -> Option<String> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut buf = Vec::new();
+    file.take(MAX_PREVIEW_BYTES).read_to_end(&mut buf).ok()?;
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Truncate a single line to MAX_LINE_CHARS on a char boundary
+fn clip_line(line: &str) -> String {
+    if line.chars().count() <= MAX_LINE_CHARS {
+        line.to_string()
+    } else {
+        let mut clipped: String = line.chars().take(MAX_LINE_CHARS).collect();
+        clipped.push('…');
+        clipped
+    }
+}
+
+/// Cap a block of text to MAX_PREVIEW_LINES, appending an ellipsis line
+fn head_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = text
+        .lines()
+        .take(MAX_PREVIEW_LINES)
+        .map(clip_line)
+        .collect();
+    if text.lines().nth(MAX_PREVIEW_LINES).is_some() {
+        lines.push("…".to_string());
+    }
+    lines
+}
+
+fn json_preview(path: &str) -> FilePreview {
+    let head = read_head(path).unwrap_or_default();
+    // Pretty-print when the head parses as complete JSON; a file larger than
+    // the read cap won't parse, so its raw head is shown instead
+    let lines = match serde_json::from_str::<serde_json::Value>(&head) {
+        Ok(value) => {
+            let pretty = serde_json::to_string_pretty(&value).unwrap_or(head);
+            head_lines(&pretty)
+        }
+        Err(_) => head_lines(&head),
+    };
+    FilePreview::Json { lines }
+}
+
+fn markdown_preview(path: &str) -> FilePreview {
+    let head = read_head(path).unwrap_or_default();
+    FilePreview::Markdown {
+        lines: head_lines(&head),
+    }
+}
+
+fn csv_preview(path: &str, delimiter: char) -> FilePreview {
+    let head = read_head(path).unwrap_or_default();
+    let mut lines = head.lines();
+
+    let columns: Vec<String> = lines
+        .next()
+        .map(|header| split_delimited_line(header, delimiter))
+        .unwrap_or_default()
+        .into_iter()
+        .take(MAX_CSV_COLS)
+        .collect();
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut total_truncated = false;
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if rows.len() >= MAX_CSV_ROWS {
+            total_truncated = true;
+            break;
+        }
+        let mut cells = split_delimited_line(line, delimiter);
+        cells.truncate(MAX_CSV_COLS);
+        // Pad short rows so the table stays rectangular
+        while cells.len() < columns.len() {
+            cells.push(String::new());
+        }
+        rows.push(cells.into_iter().map(|c| clip_line(&c)).collect());
+    }
+
+    FilePreview::Csv {
+        columns,
+        rows,
+        total_truncated,
+    }
+}
+
+/// Split one CSV/TSV line, honoring double-quoted cells ("a,b" stays one cell)
+fn split_delimited_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            if ch == '"' {
+                if chars.peek() == Some(&'"') {
+                    // Escaped quote inside a quoted cell
+                    current.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                current.push(ch);
+            }
+        } else if ch == '"' && current.is_empty() {
+            in_quotes = true;
+        } else if ch == delimiter {
+            cells.push(std::mem::take(&mut current));
+        } else {
+            current.push(ch);
+        }
+    }
+    cells.push(current);
+    cells
+}
+
+fn image_preview(path: &str) -> Option<FilePreview> {
+    let size_bytes = std::fs::metadata(path).ok()?.len();
+    if size_bytes > MAX_IMAGE_BYTES {
+        return None;
+    }
+    // Dimensions come from the header; the decode below is the expensive part
+    let (width, height) = image::image_dimensions(path).ok()?;
+    let bytes = std::fs::read(path).ok()?;
+    let image = decode_png_to_render_image_with_bgra_conversion(&bytes).ok()?;
+    Some(FilePreview::Image {
+        image,
+        width,
+        height,
+        size_bytes,
+    })
+}
+
+/// Colors for rendering a preview panel, supplied by the hosting view
+#[derive(Clone, Copy)]
+pub struct PreviewColors {
+    pub text: u32,
+    pub muted: u32,
+    pub border: u32,
+    pub accent: u32,
+}
+
+/// Human-readable file size ("3.2 KB", "1.5 MB")
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Render a preview into an element the hosting panel can embed
+pub fn render_preview(preview: &FilePreview, colors: PreviewColors) -> AnyElement {
+    match preview {
+        FilePreview::Json { lines } | FilePreview::Markdown { lines } => {
+            let is_markdown = matches!(preview, FilePreview::Markdown { .. });
+            let mut container = div().flex().flex_col().w_full();
+            for line in lines {
+                let is_heading = is_markdown && line.trim_start().starts_with('#');
+                let mut line_div = div()
+                    .w_full()
+                    .text_xs()
+                    .whitespace_nowrap()
+                    .overflow_hidden()
+                    .text_color(rgb(colors.text));
+                if is_heading {
+                    line_div = line_div
+                        .text_color(rgb(colors.accent))
+                        .font_weight(FontWeight::SEMIBOLD);
+                }
+                // Preserve blank lines with a space so the row keeps its height
+                let content = if line.is_empty() {
+                    " ".to_string()
+                } else {
+                    line.clone()
+                };
+                container = container.child(line_div.child(content));
+            }
+            container.into_any_element()
+        }
+        FilePreview::Csv {
+            columns,
+            rows,
+            total_truncated,
+        } => {
+            let mut header = div().flex().flex_row().gap_2().w_full().pb_1();
+            for column in columns {
+                header = header.child(
+                    div()
+                        .flex_1()
+                        .text_xs()
+                        .whitespace_nowrap()
+                        .overflow_hidden()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .text_color(rgb(colors.accent))
+                        .child(column.clone()),
+                );
+            }
+
+            let mut container = div().flex().flex_col().w_full().child(header);
+            for row in rows {
+                let mut row_div = div()
+                    .flex()
+                    .flex_row()
+                    .gap_2()
+                    .w_full()
+                    .border_t_1()
+                    .border_color(rgb(colors.border));
+                for cell in row {
+                    row_div = row_div.child(
+                        div()
+                            .flex_1()
+                            .text_xs()
+                            .whitespace_nowrap()
+                            .overflow_hidden()
+                            .text_color(rgb(colors.text))
+                            .child(cell.clone()),
+                    );
+                }
+                container = container.child(row_div);
+            }
+            if *total_truncated {
+                container = container.child(
+                    div()
+                        .text_xs()
+                        .text_color(rgb(colors.muted))
+                        .child(format!("… first {} rows shown", MAX_CSV_ROWS)),
+                );
+            }
+            container.into_any_element()
+        }
+        FilePreview::Image {
+            image,
+            width,
+            height,
+            size_bytes,
+        } => {
+            let image = image.clone();
+            div()
+                .flex()
+                .flex_col()
+                .w_full()
+                .gap_2()
+                .items_center()
+                .child(
+                    img(move |_window: &mut Window, _cx: &mut App| Some(Ok(image.clone())))
+                        .max_w_full()
+                        .max_h(px(200.0)),
+                )
+                .child(div().text_xs().text_color(rgb(colors.muted)).child(format!(
+                    "{}×{} • {}",
+                    width,
+                    height,
+                    format_size(*size_bytes)
+                )))
+                .into_any_element()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write as _;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("sk-preview-test-{}-{}", std::process::id(), name));
+        let mut file = std::fs::File::create(&path).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_split_delimited_line_basic() {
+        assert_eq!(split_delimited_line("a,b,c", ','), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_split_delimited_line_quoted() {
+        assert_eq!(
+            split_delimited_line("\"a,b\",c", ','),
+            vec!["a,b".to_string(), "c".to_string()]
+        );
+        assert_eq!(
+            split_delimited_line("\"say \"\"hi\"\"\",x", ','),
+            vec!["say \"hi\"".to_string(), "x".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_json_preview_pretty_prints() {
+        let path = temp_file("pretty.json", "{\"b\":1,\"a\":[1,2]}");
+        let preview = preview_for_path(path.to_str().unwrap()).expect("json preview");
+        let _ = std::fs::remove_file(&path);
+        match preview {
+            FilePreview::Json { lines } => {
+                // Pretty-printed output spans multiple lines with indentation
+                assert!(lines.len() > 1);
+                assert!(lines.iter().any(|l| l.contains("\"a\"")));
+            }
+            _ => panic!("expected Json preview"),
+        }
+    }
+
+    #[test]
+    fn test_csv_preview_table_head() {
+        let path = temp_file("head.csv", "name,size\nfoo,1\nbar,2\n");
+        let preview = preview_for_path(path.to_str().unwrap()).expect("csv preview");
+        let _ = std::fs::remove_file(&path);
+        match preview {
+            FilePreview::Csv { columns, rows, .. } => {
+                assert_eq!(columns, vec!["name", "size"]);
+                assert_eq!(rows.len(), 2);
+                assert_eq!(rows[0], vec!["foo", "1"]);
+            }
+            _ => panic!("expected Csv preview"),
+        }
+    }
+
+    #[test]
+    fn test_csv_preview_caps_rows() {
+        let mut contents = String::from("n\n");
+        for i in 0..(MAX_CSV_ROWS + 10) {
+            contents.push_str(&format!("{}\n", i));
+        }
+        let path = temp_file("big.csv", &contents);
+        let preview = preview_for_path(path.to_str().unwrap()).expect("csv preview");
+        let _ = std::fs::remove_file(&path);
+        match preview {
+            FilePreview::Csv {
+                rows,
+                total_truncated,
+                ..
+            } => {
+                assert_eq!(rows.len(), MAX_CSV_ROWS);
+                assert!(total_truncated);
+            }
+            _ => panic!("expected Csv preview"),
+        }
+    }
+
+    #[test]
+    fn test_unknown_extension_has_no_preview() {
+        let path = temp_file("plain.txt", "hello");
+        let preview = preview_for_path(path.to_str().unwrap());
+        let _ = std::fs::remove_file(&path);
+        assert!(preview.is_none());
+    }
+
+    #[test]
+    fn test_missing_file_has_no_preview() {
+        assert!(preview_for_path("/nonexistent/definitely-missing.json").is_none());
+    }
+
+    #[test]
+    fn test_head_lines_caps_and_marks() {
+        let text = (0..(MAX_PREVIEW_LINES + 5))
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let lines = head_lines(&text);
+        assert_eq!(lines.len(), MAX_PREVIEW_LINES + 1);
+        assert_eq!(lines.last().unwrap(), "…");
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    }
+}
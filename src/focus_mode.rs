@@ -0,0 +1,179 @@
+//! macOS Focus (Do Not Disturb) state and control
+//!
+//! Reads the user's Focus configuration from the DoNotDisturb database
+//! under ~/Library/DoNotDisturb/DB (the same files Control Center uses)
+//! and toggles Focus through Control Center UI scripting, since recent
+//! macOS versions have no public DND API. Backs both the Focus Modes
+//! builtin and the `dnd` protocol message, which records the previous
+//! state so scripts can restore it when they finish.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use tracing::{info, warn};
+
+/// One configured Focus mode (Do Not Disturb, Work, Sleep, ...)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FocusMode {
+    /// Display name shown in Control Center
+    pub name: String,
+    /// Stable identifier used in assertion records
+    pub identifier: String,
+}
+
+/// The DoNotDisturb database directory
+fn dnd_db_dir() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/Library/DoNotDisturb/DB").as_ref())
+}
+
+/// Read and parse one JSON file from the DND database
+fn read_db_json(file: &str) -> Option<serde_json::Value> {
+    let path = dnd_db_dir().join(file);
+    let contents = std::fs::read_to_string(&path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// The user's configured Focus modes
+pub fn list_modes() -> Vec<FocusMode> {
+    match read_db_json("ModeConfigurations.json") {
+        Some(json) => parse_mode_configurations(&json),
+        None => {
+            warn!("Could not read Focus mode configurations");
+            Vec::new()
+        }
+    }
+}
+
+/// Parse ModeConfigurations.json into Focus modes
+///
+/// The file holds `data[0].modeConfigurations`, a map keyed by mode
+/// identifier where each value carries `mode.name`.
+fn parse_mode_configurations(json: &serde_json::Value) -> Vec<FocusMode> {
+    let mut modes: Vec<FocusMode> = json["data"][0]["modeConfigurations"]
+        .as_object()
+        .map(|configs| {
+            configs
+                .iter()
+                .filter_map(|(identifier, config)| {
+                    Some(FocusMode {
+                        name: config["mode"]["name"].as_str()?.to_string(),
+                        identifier: identifier.clone(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    modes.sort_by(|a, b| a.name.cmp(&b.name));
+    modes
+}
+
+/// Identifier of the currently active Focus mode, if any
+pub fn active_mode_identifier() -> Option<String> {
+    read_db_json("Assertions.json").and_then(|json| parse_active_assertion(&json))
+}
+
+/// Parse Assertions.json for the active mode identifier
+///
+/// An active Focus leaves a record in `data[0].storeAssertionRecords`;
+/// no records means no Focus is active.
+fn parse_active_assertion(json: &serde_json::Value) -> Option<String> {
+    json["data"][0]["storeAssertionRecords"]
+        .as_array()?
+        .first()?["assertionDetails"]["assertionDetailsModeIdentifier"]
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+/// Whether any Focus mode is currently active
+pub fn is_focus_active() -> bool {
+    active_mode_identifier().is_some()
+}
+
+/// Toggle Focus via Control Center (flips the current state)
+fn toggle_focus() -> Result<(), String> {
+    crate::system_actions::toggle_do_not_disturb()
+}
+
+/// Set Focus on or off, returning the previous state
+///
+/// No-op when the requested state already holds, so scripts can call it
+/// unconditionally and restore `previous` afterwards.
+pub fn set_focus(enabled: bool) -> Result<bool, String> {
+    let previous = is_focus_active();
+    if previous != enabled {
+        info!(enabled = enabled, "Setting Focus");
+        toggle_focus()?;
+    }
+    Ok(previous)
+}
+
+/// Activate a specific Focus mode by its Control Center name
+///
+/// Opens the Focus section of Control Center and clicks the mode's
+/// toggle. UI scripting, like the brightness controls: best effort, and
+/// requires the Accessibility permission.
+pub fn activate_mode(name: &str) -> Result<(), String> {
+    info!(mode = name, "Activating Focus mode");
+    let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+    let script = format!(
+        r#"tell application "System Events"
+            tell process "ControlCenter"
+                click menu bar item "Focus" of menu bar 1
+                delay 0.4
+                click checkbox "{}" of window "Control Center"
+                delay 0.2
+                key code 53 -- escape to close Control Center
+            end tell
+        end tell"#,
+        escaped
+    );
+    crate::system_actions::run_applescript(&script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mode_configurations() {
+        let json = serde_json::json!({
+            "data": [{
+                "modeConfigurations": {
+                    "com.apple.donotdisturb.mode.default": {
+                        "mode": { "name": "Do Not Disturb" }
+                    },
+                    "com.apple.focus.work": {
+                        "mode": { "name": "Work" }
+                    }
+                }
+            }]
+        });
+        let modes = parse_mode_configurations(&json);
+        assert_eq!(modes.len(), 2);
+        // Sorted by name
+        assert_eq!(modes[0].name, "Do Not Disturb");
+        assert_eq!(modes[1].name, "Work");
+        assert_eq!(modes[1].identifier, "com.apple.focus.work");
+    }
+
+    #[test]
+    fn test_parse_active_assertion() {
+        let active = serde_json::json!({
+            "data": [{
+                "storeAssertionRecords": [{
+                    "assertionDetails": {
+                        "assertionDetailsModeIdentifier": "com.apple.focus.work"
+                    }
+                }]
+            }]
+        });
+        assert_eq!(
+            parse_active_assertion(&active),
+            Some("com.apple.focus.work".to_string())
+        );
+
+        let inactive = serde_json::json!({ "data": [{ "storeAssertionRecords": [] }] });
+        assert_eq!(parse_active_assertion(&inactive), None);
+    }
+}
@@ -0,0 +1,307 @@
+//! GitHub integration for the GitHub builtin
+//!
+//! Fetches the user's repositories, pull requests awaiting their review, and
+//! notifications from the GitHub REST API. The token comes from the keyring
+//! (the GITHUB_TOKEN entry managed by the env() prompt) with the
+//! GITHUB_TOKEN environment variable as a fallback; no token is ever written
+//! to disk by this module.
+//!
+//! Results are cached in-process and refreshed on a background interval (see
+//! [`start_background_refresh`]) so opening the builtin stays instant. All
+//! network calls are blocking (ureq) and must run off the UI thread.
+
+#![allow(dead_code)]
+
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+/// How often the background thread refreshes the cache
+const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Keyring/env key holding the API token
+const TOKEN_KEY: &str = "GITHUB_TOKEN";
+
+/// What a cached item represents, in display order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GitHubItemKind {
+    PullRequest,
+    Notification,
+    Repository,
+}
+
+impl GitHubItemKind {
+    /// Emoji shown in the list row
+    pub fn icon(&self) -> &'static str {
+        match self {
+            GitHubItemKind::PullRequest => "\u{1F500}",
+            GitHubItemKind::Notification => "\u{1F514}",
+            GitHubItemKind::Repository => "\u{1F4E6}",
+        }
+    }
+
+    /// Section label shown in the row description
+    pub fn label(&self) -> &'static str {
+        match self {
+            GitHubItemKind::PullRequest => "Awaiting review",
+            GitHubItemKind::Notification => "Notification",
+            GitHubItemKind::Repository => "Repository",
+        }
+    }
+}
+
+/// One row in the GitHub builtin
+#[derive(Clone, Debug)]
+pub struct GitHubItem {
+    pub kind: GitHubItemKind,
+    pub title: String,
+    /// Repo name or description shown under the title
+    pub subtitle: Option<String>,
+    /// Web URL opened on Enter and copied with Cmd+C
+    pub url: String,
+}
+
+/// Cached items from the last successful refresh
+static ITEM_CACHE: OnceLock<Mutex<Vec<GitHubItem>>> = OnceLock::new();
+
+fn item_cache() -> &'static Mutex<Vec<GitHubItem>> {
+    ITEM_CACHE.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Get the API token from the keyring, falling back to the environment
+pub fn token() -> Option<String> {
+    crate::prompts::env::get_secret(TOKEN_KEY)
+        .or_else(|| std::env::var(TOKEN_KEY).ok())
+        .filter(|t| !t.trim().is_empty())
+}
+
+/// Whether a token is configured (the builtin shows setup hints when not)
+pub fn has_token() -> bool {
+    token().is_some()
+}
+
+/// Snapshot of the cached items for the view
+pub fn github_items() -> Vec<GitHubItem> {
+    item_cache().lock().unwrap().clone()
+}
+
+/// Authenticated GET returning parsed JSON. Blocking — run off the UI thread.
+fn api_get(token: &str, url: &str) -> Result<serde_json::Value, String> {
+    let response = ureq::get(url)
+        .header("Authorization", &format!("Bearer {}", token))
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "script-kit-gpui")
+        .call()
+        .map_err(|e| format!("GitHub request failed: {}", e))?;
+    response
+        .into_body()
+        .read_json()
+        .map_err(|e| format!("Failed to parse GitHub response: {}", e))
+}
+
+/// Convert a notification's API subject URL to the web URL
+///
+/// The notifications endpoint only carries API URLs
+/// (api.github.com/repos/owner/repo/pulls/123); the web equivalent swaps the
+/// host and the `pulls`/`issues` path segment.
+fn web_url_from_api(api_url: &str) -> String {
+    api_url
+        .replace("https://api.github.com/repos/", "https://github.com/")
+        .replace("/pulls/", "/pull/")
+}
+
+/// Parse /user/repos into repository items
+fn parse_repos(json: &serde_json::Value) -> Vec<GitHubItem> {
+    json.as_array()
+        .map(|repos| {
+            repos
+                .iter()
+                .filter_map(|repo| {
+                    Some(GitHubItem {
+                        kind: GitHubItemKind::Repository,
+                        title: repo["full_name"].as_str()?.to_string(),
+                        subtitle: repo["description"].as_str().map(|s| s.to_string()),
+                        url: repo["html_url"].as_str()?.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse /search/issues (review-requested query) into PR items
+fn parse_review_requests(json: &serde_json::Value) -> Vec<GitHubItem> {
+    json["items"]
+        .as_array()
+        .map(|prs| {
+            prs.iter()
+                .filter_map(|pr| {
+                    // repository_url is api.github.com/repos/owner/repo
+                    let repo = pr["repository_url"]
+                        .as_str()
+                        .and_then(|u| u.split("/repos/").nth(1))
+                        .map(|s| s.to_string());
+                    Some(GitHubItem {
+                        kind: GitHubItemKind::PullRequest,
+                        title: pr["title"].as_str()?.to_string(),
+                        subtitle: repo,
+                        url: pr["html_url"].as_str()?.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse /notifications into notification items
+fn parse_notifications(json: &serde_json::Value) -> Vec<GitHubItem> {
+    json.as_array()
+        .map(|threads| {
+            threads
+                .iter()
+                .filter_map(|thread| {
+                    let repo = thread["repository"]["full_name"].as_str();
+                    let url = thread["subject"]["url"]
+                        .as_str()
+                        .map(web_url_from_api)
+                        .or_else(|| repo.map(|r| format!("https://github.com/{}", r)))?;
+                    Some(GitHubItem {
+                        kind: GitHubItemKind::Notification,
+                        title: thread["subject"]["title"].as_str()?.to_string(),
+                        subtitle: repo.map(|s| s.to_string()),
+                        url,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Refresh the cache from the API (PRs awaiting review, notifications, repos)
+///
+/// Returns the number of cached items. Individual endpoint failures are
+/// logged and skipped so one bad call doesn't empty the whole view.
+/// Blocking — run off the UI thread.
+pub fn refresh_cache() -> Result<usize, String> {
+    let token = token().ok_or_else(|| {
+        "No GitHub token configured. Set GITHUB_TOKEN via the env() prompt.".to_string()
+    })?;
+
+    let mut items = Vec::new();
+    let endpoints: [(&str, fn(&serde_json::Value) -> Vec<GitHubItem>); 3] = [
+        (
+            "https://api.github.com/search/issues?q=is%3Aopen+is%3Apr+review-requested%3A%40me&per_page=20",
+            parse_review_requests,
+        ),
+        (
+            "https://api.github.com/notifications?per_page=20",
+            parse_notifications,
+        ),
+        (
+            "https://api.github.com/user/repos?sort=updated&per_page=50",
+            parse_repos,
+        ),
+    ];
+    for (url, parse) in endpoints {
+        match api_get(&token, url) {
+            Ok(json) => items.extend(parse(&json)),
+            Err(e) => warn!(url = url, error = %e, "GitHub endpoint refresh failed"),
+        }
+    }
+
+    let count = items.len();
+    *item_cache().lock().unwrap() = items;
+    info!(count = count, "GitHub cache refreshed");
+    Ok(count)
+}
+
+/// Spawn the background refresh thread
+///
+/// Refreshes immediately when a token is configured, then every
+/// [`REFRESH_INTERVAL`]. Token checks repeat each cycle so adding a token
+/// later starts populating the cache without a restart.
+pub fn start_background_refresh() {
+    std::thread::spawn(|| loop {
+        if has_token() {
+            if let Err(e) = refresh_cache() {
+                warn!(error = %e, "GitHub background refresh failed");
+            }
+        }
+        std::thread::sleep(REFRESH_INTERVAL);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repos() {
+        let json = serde_json::json!([
+            {
+                "full_name": "octocat/hello-world",
+                "description": "My first repo",
+                "html_url": "https://github.com/octocat/hello-world"
+            },
+            {
+                "full_name": "octocat/spoon-knife",
+                "description": null,
+                "html_url": "https://github.com/octocat/spoon-knife"
+            }
+        ]);
+        let items = parse_repos(&json);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].kind, GitHubItemKind::Repository);
+        assert_eq!(items[0].title, "octocat/hello-world");
+        assert_eq!(items[0].subtitle.as_deref(), Some("My first repo"));
+        assert_eq!(items[1].subtitle, None);
+    }
+
+    #[test]
+    fn test_parse_review_requests_extracts_repo_name() {
+        let json = serde_json::json!({
+            "items": [{
+                "title": "Fix the flux capacitor",
+                "repository_url": "https://api.github.com/repos/octocat/hello-world",
+                "html_url": "https://github.com/octocat/hello-world/pull/42"
+            }]
+        });
+        let items = parse_review_requests(&json);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, GitHubItemKind::PullRequest);
+        assert_eq!(items[0].subtitle.as_deref(), Some("octocat/hello-world"));
+        assert_eq!(
+            items[0].url,
+            "https://github.com/octocat/hello-world/pull/42"
+        );
+    }
+
+    #[test]
+    fn test_parse_notifications_maps_api_url_to_web() {
+        let json = serde_json::json!([{
+            "subject": {
+                "title": "Please review",
+                "url": "https://api.github.com/repos/octocat/hello-world/pulls/42"
+            },
+            "repository": { "full_name": "octocat/hello-world" }
+        }]);
+        let items = parse_notifications(&json);
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            items[0].url,
+            "https://github.com/octocat/hello-world/pull/42"
+        );
+    }
+
+    #[test]
+    fn test_parse_notifications_falls_back_to_repo_url() {
+        let json = serde_json::json!([{
+            "subject": { "title": "CI finished", "url": null },
+            "repository": { "full_name": "octocat/hello-world" }
+        }]);
+        let items = parse_notifications(&json);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].url, "https://github.com/octocat/hello-world");
+    }
+}
@@ -0,0 +1,211 @@
+//! Persisted hidden items for the main list
+//!
+//! Items hidden via the "Hide from Search" action never appear in search or
+//! grouped results, but stay loaded so aliases, shortcuts, and `run` messages
+//! still work. Items are identified by the same keys the frecency store uses
+//! (script path, `builtin:Name`, `scriptlet:Name`, app path), and the set is
+//! stored in `~/.sk/kit/hidden.json` alongside `frecency.json`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// Store for hidden items with persistence
+#[derive(Debug, Clone)]
+pub struct HiddenStore {
+    /// Keys hidden from the main list
+    hidden: HashSet<String>,
+    /// Path to the hidden items file
+    file_path: PathBuf,
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Serialize, Deserialize)]
+struct HiddenData {
+    hidden: Vec<String>,
+}
+
+impl HiddenStore {
+    /// Create a new HiddenStore with the default path (~/.sk/kit/hidden.json)
+    pub fn new() -> Self {
+        HiddenStore {
+            hidden: HashSet::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a HiddenStore with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        HiddenStore {
+            hidden: HashSet::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    /// Get the default hidden items file path
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.sk/kit/hidden.json").as_ref())
+    }
+
+    /// Load hidden items from disk
+    ///
+    /// Starts with nothing hidden if the file doesn't exist.
+    #[instrument(name = "hidden_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            debug!(path = %self.file_path.display(), "Hidden items file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path).with_context(|| {
+            format!(
+                "Failed to read hidden items file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        let data: HiddenData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse hidden items JSON")?;
+
+        self.hidden = data.hidden.into_iter().collect();
+        self.dirty = false;
+
+        info!(
+            path = %self.file_path.display(),
+            hidden_count = self.hidden.len(),
+            "Loaded hidden items"
+        );
+        Ok(())
+    }
+
+    /// Save hidden items to disk
+    #[instrument(name = "hidden_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        // Sorted for stable, diff-friendly files
+        let mut keys: Vec<String> = self.hidden.iter().cloned().collect();
+        keys.sort();
+        let data = HiddenData { hidden: keys };
+
+        let json =
+            serde_json::to_string_pretty(&data).context("Failed to serialize hidden items")?;
+
+        std::fs::write(&self.file_path, json).with_context(|| {
+            format!(
+                "Failed to write hidden items file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Toggle a key's hidden state, returning true if it is now hidden
+    pub fn toggle(&mut self, key: &str) -> bool {
+        let now_hidden = if self.hidden.remove(key) {
+            false
+        } else {
+            self.hidden.insert(key.to_string());
+            true
+        };
+        self.dirty = true;
+        now_hidden
+    }
+
+    /// Whether a key is currently hidden
+    #[allow(dead_code)] // Used by tests; the app filters via hidden()
+    pub fn is_hidden(&self, key: &str) -> bool {
+        self.hidden.contains(key)
+    }
+
+    /// Unhide everything, returning how many keys were cleared
+    pub fn clear(&mut self) -> usize {
+        let count = self.hidden.len();
+        if count > 0 {
+            self.hidden.clear();
+            self.dirty = true;
+        }
+        count
+    }
+
+    /// The set of hidden keys
+    pub fn hidden(&self) -> &HashSet<String> {
+        &self.hidden
+    }
+}
+
+impl Default for HiddenStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "sk-hidden-test-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_toggle_hides_and_unhides() {
+        let mut store = HiddenStore::with_path(temp_path("toggle"));
+        assert!(store.toggle("/a.ts"));
+        assert!(store.is_hidden("/a.ts"));
+        assert!(!store.toggle("/a.ts"));
+        assert!(!store.is_hidden("/a.ts"));
+    }
+
+    #[test]
+    fn test_clear_unhides_everything() {
+        let mut store = HiddenStore::with_path(temp_path("clear"));
+        store.toggle("/a.ts");
+        store.toggle("builtin:Clipboard History");
+        assert_eq!(store.clear(), 2);
+        assert!(!store.is_hidden("/a.ts"));
+        // Clearing an empty store is a no-op
+        assert_eq!(store.clear(), 0);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        {
+            let mut store = HiddenStore::with_path(path.clone());
+            store.toggle("/b.ts");
+            store.toggle("/a.ts");
+            store.save().unwrap();
+        }
+        {
+            let mut store = HiddenStore::with_path(path.clone());
+            store.load().unwrap();
+            assert!(store.is_hidden("/a.ts"));
+            assert!(store.is_hidden("/b.ts"));
+            assert_eq!(store.hidden().len(), 2);
+        }
+        std::fs::remove_file(&path).ok();
+    }
+}
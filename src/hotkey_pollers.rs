@@ -253,6 +253,81 @@ impl ScriptHotkeyPoller {
     }
 }
 
+/// A model that listens for summon hotkey triggers via async_channel.
+///
+/// Summon hotkeys open the window pre-scoped (Clipboard History, File
+/// Search, or re-running the last used script) instead of on the script
+/// list. The show sequence mirrors the main hotkey's, then hands off to
+/// ScriptListApp::open_summon_target for the view change.
+#[allow(dead_code)]
+pub struct SummonHotkeyPoller {
+    window: WindowHandle<ScriptListApp>,
+}
+
+impl SummonHotkeyPoller {
+    pub fn new(window: WindowHandle<ScriptListApp>) -> Self {
+        Self { window }
+    }
+
+    pub fn start_listening(&self, cx: &mut Context<Self>) {
+        let window = self.window;
+        cx.spawn(async move |_this, cx: &mut AsyncApp| {
+            logging::log("HOTKEY", "Summon hotkey listener started");
+
+            while let Ok(target) = hotkeys::summon_hotkey_channel().1.recv().await {
+                logging::log("HOTKEY", &format!("Summon hotkey received: {:?}", target));
+
+                // Same guard as the main hotkey: Notes/AI windows take priority
+                if notes::is_notes_window_open() || ai::is_ai_window_open() {
+                    logging::log("HOTKEY", "Notes/AI window open - summon hotkey ignored");
+                    continue;
+                }
+
+                let was_visible = script_kit_gpui::is_main_window_visible();
+                script_kit_gpui::set_main_window_visible(true);
+
+                let _ = cx.update(move |cx: &mut App| {
+                    if !was_visible {
+                        // Condensed version of the main hotkey show sequence:
+                        // move to the mouse display, activate, configure panel
+                        // on first show
+                        platform::ensure_move_to_active_space();
+                        let window_size = size(px(750.), initial_window_height());
+                        let new_bounds = calculate_eye_line_bounds_on_mouse_display(window_size);
+                        move_first_window_to_bounds(&new_bounds);
+                        cx.activate(true);
+                        if !PANEL_CONFIGURED.swap(true, std::sync::atomic::Ordering::SeqCst) {
+                            platform::configure_as_floating_panel();
+                        }
+                    }
+
+                    let _ = window.update(
+                        cx,
+                        |view: &mut ScriptListApp,
+                         win: &mut Window,
+                         ctx: &mut Context<ScriptListApp>| {
+                            if !was_visible {
+                                win.activate_window();
+                                let focus_handle = view.focus_handle(ctx);
+                                win.focus(&focus_handle, ctx);
+                                reset_resize_debounce();
+                                NEEDS_RESET.store(false, std::sync::atomic::Ordering::SeqCst);
+                            }
+                            // Start from a clean script list so the target view's
+                            // state (filter, selection) is fresh
+                            view.reset_to_script_list(ctx);
+                            view.open_summon_target(target, ctx);
+                        },
+                    );
+                });
+            }
+
+            logging::log("HOTKEY", "Summon hotkey listener exiting");
+        })
+        .detach();
+    }
+}
+
 /// A model that listens for notes hotkey triggers via async_channel.
 #[allow(dead_code)]
 pub struct NotesHotkeyPoller;
@@ -325,6 +400,12 @@ pub(crate) fn start_hotkey_event_handler(cx: &mut App, window: WindowHandle<Scri
         p.start_listening(cx);
     });
 
+    // Start summon hotkey listener (for opening the window pre-scoped)
+    let summon_handler = cx.new(|_| SummonHotkeyPoller::new(window));
+    summon_handler.update(cx, |p, cx| {
+        p.start_listening(cx);
+    });
+
     // Start notes hotkey listener (for opening notes window)
     let notes_handler = cx.new(|_| NotesHotkeyPoller::new());
     notes_handler.update(cx, |p, cx| {
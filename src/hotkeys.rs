@@ -339,6 +339,34 @@ fn dispatch_notes_hotkey() {
     }
 }
 
+/// What a summon hotkey opens the launcher into.
+///
+/// Summon hotkeys are additional global hotkeys (configurable in config.json
+/// as `clipboardHistoryHotkey`, `fileSearchHotkey`, `rerunLastScriptHotkey`)
+/// that open the window pre-scoped instead of on the script list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SummonTarget {
+    /// Open directly into the Clipboard History builtin
+    ClipboardHistory,
+    /// Open directly into the File Search builtin
+    FileSearch,
+    /// Re-run the last used script
+    RerunLastScript,
+}
+
+/// Dispatch a summon hotkey: send the target to the channel and wake the
+/// GPUI run loop via GCD so it gets processed even when the app is idle.
+fn dispatch_summon_hotkey(target: SummonTarget) {
+    if summon_hotkey_channel().0.try_send(target).is_err() {
+        logging::log("HOTKEY", "Summon hotkey channel full/closed");
+    }
+
+    // Wake GPUI's event loop so the channel message gets processed
+    gcd::dispatch_to_main(|| {
+        // Empty closure - just wakes the run loop
+    });
+}
+
 /// Dispatch the AI hotkey handler to the main thread.
 /// Same strategy as Notes hotkey.
 fn dispatch_ai_hotkey() {
@@ -418,9 +446,47 @@ pub(crate) fn ai_hotkey_channel(
     AI_HOTKEY_CHANNEL.get_or_init(|| async_channel::bounded(10))
 }
 
+// SUMMON_HOTKEY_CHANNEL: Channel for summon hotkey events (sends the target)
+#[allow(dead_code)]
+static SUMMON_HOTKEY_CHANNEL: OnceLock<(
+    async_channel::Sender<SummonTarget>,
+    async_channel::Receiver<SummonTarget>,
+)> = OnceLock::new();
+
+/// Get the summon hotkey channel, initializing it on first access.
+#[allow(dead_code)]
+pub(crate) fn summon_hotkey_channel() -> &'static (
+    async_channel::Sender<SummonTarget>,
+    async_channel::Receiver<SummonTarget>,
+) {
+    SUMMON_HOTKEY_CHANNEL.get_or_init(|| async_channel::bounded(10))
+}
+
 #[allow(dead_code)]
 static HOTKEY_TRIGGER_COUNT: AtomicU64 = AtomicU64::new(0);
 
+/// Convert a config `HotkeyConfig` ({key: "KeyC", modifiers: ["meta","shift"]})
+/// into a (Modifiers, Code) pair via `shortcuts::parse_shortcut`.
+///
+/// Key names use the same format as the main hotkey: "KeyA"-"KeyZ",
+/// "Digit0"-"Digit9", "F1"-"F12", "Space", "Enter", "Semicolon".
+#[allow(dead_code)]
+fn parse_hotkey_config(hotkey_config: &config::HotkeyConfig) -> Option<(Modifiers, Code)> {
+    let key = hotkey_config
+        .key
+        .strip_prefix("Key")
+        .or_else(|| hotkey_config.key.strip_prefix("Digit"))
+        .unwrap_or(&hotkey_config.key);
+    let shortcut = hotkey_config
+        .modifiers
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(key))
+        .collect::<Vec<_>>()
+        .join("+");
+    shortcuts::parse_shortcut(&shortcut)
+}
+
 #[allow(dead_code)]
 pub(crate) fn start_hotkey_listener(config: config::Config) {
     std::thread::spawn(move || {
@@ -635,6 +701,76 @@ pub(crate) fn start_hotkey_listener(config: config::Config) {
             );
         }
 
+        // Register summon hotkeys (opt-in, no defaults): each opens the
+        // launcher pre-scoped instead of on the script list
+        let mut summon_hotkey_map: std::collections::HashMap<u32, SummonTarget> =
+            std::collections::HashMap::new();
+        let summon_configs = [
+            (
+                config.clipboard_history_hotkey.as_ref(),
+                SummonTarget::ClipboardHistory,
+                "clipboard history",
+            ),
+            (
+                config.file_search_hotkey.as_ref(),
+                SummonTarget::FileSearch,
+                "file search",
+            ),
+            (
+                config.rerun_last_script_hotkey.as_ref(),
+                SummonTarget::RerunLastScript,
+                "rerun last script",
+            ),
+        ];
+        for (summon_config, target, label) in summon_configs {
+            let Some(summon_config) = summon_config else {
+                continue;
+            };
+            let summon_display = format!(
+                "{}{}",
+                summon_config.modifiers.join("+"),
+                if summon_config.modifiers.is_empty() {
+                    String::new()
+                } else {
+                    "+".to_string()
+                }
+            ) + &summon_config.key;
+
+            match parse_hotkey_config(summon_config) {
+                Some((mods, code)) => {
+                    let summon_hotkey = HotKey::new(Some(mods), code);
+                    let summon_hotkey_id = summon_hotkey.id();
+                    match manager.register(summon_hotkey) {
+                        Ok(()) => {
+                            summon_hotkey_map.insert(summon_hotkey_id, target);
+                            logging::log(
+                                "HOTKEY",
+                                &format!(
+                                    "Registered {} hotkey {} (id: {})",
+                                    label, summon_display, summon_hotkey_id
+                                ),
+                            );
+                        }
+                        Err(e) => {
+                            logging::log(
+                                "HOTKEY",
+                                &format!(
+                                    "Failed to register {} hotkey {}: {}",
+                                    label, summon_display, e
+                                ),
+                            );
+                        }
+                    }
+                }
+                None => {
+                    logging::log(
+                        "HOTKEY",
+                        &format!("Failed to parse {} hotkey {}", label, summon_display),
+                    );
+                }
+            }
+        }
+
         // Register script shortcuts
         // Map from hotkey ID to script path
         let mut script_hotkey_map: std::collections::HashMap<u32, String> =
@@ -789,6 +925,11 @@ pub(crate) fn start_hotkey_listener(config: config::Config) {
                     );
                     dispatch_ai_hotkey();
                 }
+                // Check if it's a summon hotkey (opens the window pre-scoped)
+                else if let Some(target) = summon_hotkey_map.get(&event.id).copied() {
+                    logging::log("HOTKEY", &format!("Summon hotkey triggered: {:?}", target));
+                    dispatch_summon_hotkey(target);
+                }
                 // Check if it's a script shortcut
                 else if let Some(script_path) = script_hotkey_map.get(&event.id) {
                     logging::log(
@@ -839,6 +980,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_hotkey_config_uses_main_hotkey_key_names() {
+        let letter = config::HotkeyConfig {
+            modifiers: vec!["meta".to_string(), "shift".to_string()],
+            key: "KeyC".to_string(),
+        };
+        let (mods, code) = parse_hotkey_config(&letter).expect("parse letter");
+        assert!(mods.contains(Modifiers::META));
+        assert!(mods.contains(Modifiers::SHIFT));
+        assert_eq!(code, Code::KeyC);
+
+        let digit = config::HotkeyConfig {
+            modifiers: vec!["meta".to_string()],
+            key: "Digit5".to_string(),
+        };
+        assert_eq!(
+            parse_hotkey_config(&digit).expect("parse digit").1,
+            Code::Digit5
+        );
+
+        let space = config::HotkeyConfig {
+            modifiers: vec!["alt".to_string()],
+            key: "Space".to_string(),
+        };
+        assert_eq!(
+            parse_hotkey_config(&space).expect("parse space").1,
+            Code::Space
+        );
+    }
+
     // =============================================================================
     // ScriptHotkeyManager Unit Tests
     // =============================================================================
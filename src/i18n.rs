@@ -0,0 +1,187 @@
+//! Locale support for user-facing UI strings
+//!
+//! UI chrome strings (search placeholders, empty states, action labels) live
+//! in a per-locale [`UiStrings`] table instead of being hardcoded at each call
+//! site. The active locale is picked from the `locale` config key at startup
+//! (and on config reload) and read through [`strings()`], so render code stays
+//! a plain field access with no locking.
+//!
+//! Script-provided text (prompt placeholders, choice names, panel HTML) is
+//! authored by the script and passes through untranslated.
+
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Supported UI locales
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// English (default)
+    En,
+    /// Spanish
+    Es,
+}
+
+impl Locale {
+    /// Parse a BCP 47-ish tag ("en", "es", "es-MX"); unknown tags fall back
+    /// to English so a typo in config never blanks the UI
+    pub fn from_tag(tag: &str) -> Self {
+        let primary = tag
+            .split(['-', '_'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match primary.as_str() {
+            "es" => Locale::Es,
+            _ => Locale::En,
+        }
+    }
+
+    /// The canonical tag for this locale
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+}
+
+/// Translated UI chrome strings for one locale
+pub struct UiStrings {
+    // Actions dialog
+    pub search_actions: &'static str,
+    pub actions_no_match: &'static str,
+    // Clipboard history view
+    pub search_clipboard: &'static str,
+    pub clipboard_empty: &'static str,
+    pub clipboard_no_match: &'static str,
+    // App launcher view
+    pub search_apps: &'static str,
+    pub apps_empty: &'static str,
+    pub apps_no_match: &'static str,
+    // Window switcher view
+    pub search_windows: &'static str,
+    pub windows_empty: &'static str,
+    pub windows_no_match: &'static str,
+    // Background tasks view
+    pub search_tasks: &'static str,
+    pub tasks_empty: &'static str,
+    pub tasks_no_match: &'static str,
+    // Shortcuts view
+    pub search_shortcuts: &'static str,
+    pub shortcuts_empty: &'static str,
+    pub shortcuts_no_match: &'static str,
+    // Recently deleted view
+    pub search_deleted: &'static str,
+    pub deleted_empty: &'static str,
+    pub deleted_no_match: &'static str,
+    // Design gallery view
+    pub search_design: &'static str,
+    pub design_no_match: &'static str,
+}
+
+static EN: UiStrings = UiStrings {
+    search_actions: "Search actions...",
+    actions_no_match: "No actions match your search",
+    search_clipboard: "Search clipboard history...",
+    clipboard_empty: "No clipboard history",
+    clipboard_no_match: "No entries match your filter",
+    search_apps: "Search applications...",
+    apps_empty: "No applications found",
+    apps_no_match: "No apps match your filter",
+    search_windows: "Search windows...",
+    windows_empty: "No windows found",
+    windows_no_match: "No windows match your filter",
+    search_tasks: "Search background tasks...",
+    tasks_empty: "No background tasks running",
+    tasks_no_match: "No tasks match your filter",
+    search_shortcuts: "Search shortcuts...",
+    shortcuts_empty: "No shortcuts found - create some in Shortcuts.app",
+    shortcuts_no_match: "No shortcuts match your filter",
+    search_deleted: "Search deleted files...",
+    deleted_empty: "Recently Deleted is empty",
+    deleted_no_match: "No deleted files match your filter",
+    search_design: "Search design variations...",
+    design_no_match: "No items match your filter",
+};
+
+static ES: UiStrings = UiStrings {
+    search_actions: "Buscar acciones...",
+    actions_no_match: "Ninguna acción coincide con tu búsqueda",
+    search_clipboard: "Buscar en el historial del portapapeles...",
+    clipboard_empty: "Sin historial de portapapeles",
+    clipboard_no_match: "Ninguna entrada coincide con tu filtro",
+    search_apps: "Buscar aplicaciones...",
+    apps_empty: "No se encontraron aplicaciones",
+    apps_no_match: "Ninguna aplicación coincide con tu filtro",
+    search_windows: "Buscar ventanas...",
+    windows_empty: "No se encontraron ventanas",
+    windows_no_match: "Ninguna ventana coincide con tu filtro",
+    search_tasks: "Buscar tareas en segundo plano...",
+    tasks_empty: "No hay tareas en segundo plano",
+    tasks_no_match: "Ninguna tarea coincide con tu filtro",
+    search_shortcuts: "Buscar atajos...",
+    shortcuts_empty: "No se encontraron atajos - créalos en Shortcuts.app",
+    shortcuts_no_match: "Ningún atajo coincide con tu filtro",
+    search_deleted: "Buscar archivos eliminados...",
+    deleted_empty: "No hay archivos eliminados recientemente",
+    deleted_no_match: "Ningún archivo eliminado coincide con tu filtro",
+    search_design: "Buscar variaciones de diseño...",
+    design_no_match: "Ningún elemento coincide con tu filtro",
+};
+
+/// Active locale as a u8 (`Locale::En` = 0, `Locale::Es` = 1)
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Set the active UI locale (called at startup and on config reload)
+pub fn set_locale(locale: Locale) {
+    let value = match locale {
+        Locale::En => 0,
+        Locale::Es => 1,
+    };
+    CURRENT.store(value, Ordering::Relaxed);
+}
+
+/// The active UI locale
+pub fn current_locale() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::Es,
+        _ => Locale::En,
+    }
+}
+
+/// The string table for the active locale
+pub fn strings() -> &'static UiStrings {
+    match current_locale() {
+        Locale::En => &EN,
+        Locale::Es => &ES,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Locale::from_tag("en"), Locale::En);
+        assert_eq!(Locale::from_tag("es"), Locale::Es);
+        assert_eq!(Locale::from_tag("es-MX"), Locale::Es);
+        assert_eq!(Locale::from_tag("ES_es"), Locale::Es);
+        // Unknown tags fall back to English
+        assert_eq!(Locale::from_tag("fr"), Locale::En);
+        assert_eq!(Locale::from_tag(""), Locale::En);
+    }
+
+    #[test]
+    fn test_tag_round_trip() {
+        assert_eq!(Locale::from_tag(Locale::En.tag()), Locale::En);
+        assert_eq!(Locale::from_tag(Locale::Es.tag()), Locale::Es);
+    }
+
+    #[test]
+    fn test_locale_tables_differ() {
+        assert_ne!(EN.search_actions, ES.search_actions);
+        assert_ne!(EN.shortcuts_empty, ES.shortcuts_empty);
+    }
+}
@@ -0,0 +1,736 @@
+//! Raycast / Alfred import tool.
+//!
+//! Converts Raycast script commands and Alfred workflow script filters into
+//! Script Kit scripts so users can migrate without rewriting everything by
+//! hand. The "Import from Raycast/Alfred" builtin scans the default locations,
+//! writes one wrapper script per command into `~/.sk/kit/scripts/`, and
+//! reports what was imported or skipped.
+//!
+//! Metadata mapping:
+//! - Raycast `@raycast.title` / Alfred workflow name -> `// Name:`
+//! - Raycast `@raycast.icon` -> `// Icon:` comment (kept for reference)
+//! - Alfred script filter `keyword` -> `// Alias:`
+//!
+//! The generated script shells out to the original interpreter with the
+//! original body, so imported commands keep working even when they were
+//! written in bash, python, or AppleScript. Existing files are never
+//! overwritten - re-running the import skips anything already present.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::logging;
+
+// =============================================================================
+// Candidates and reports
+// =============================================================================
+
+/// Where an import candidate came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    Raycast,
+    Alfred,
+}
+
+impl ImportSource {
+    /// Human-readable label used in generated `// Description:` comments.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportSource::Raycast => "Raycast script command",
+            ImportSource::Alfred => "Alfred workflow",
+        }
+    }
+}
+
+/// One command found in Raycast or Alfred, ready to convert.
+#[derive(Debug, Clone)]
+pub struct ImportCandidate {
+    pub source: ImportSource,
+    pub title: String,
+    /// Emoji or icon reference from the source metadata, if any
+    pub icon: Option<String>,
+    /// Alfred keyword mapped to a Script Kit alias, if any
+    pub alias: Option<String>,
+    /// Interpreter binary used to run the original body (e.g. `/bin/bash`)
+    pub interpreter: String,
+    /// The original script body
+    pub body: String,
+    /// File the candidate was read from (for the description comment)
+    pub origin: PathBuf,
+}
+
+/// Summary of one import run, shown to the user when the flow finishes.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// Scripts written during this run
+    pub imported: Vec<PathBuf>,
+    /// Candidates skipped because the target file already exists
+    pub skipped: Vec<String>,
+    /// Errors encountered while writing
+    pub errors: Vec<String>,
+}
+
+// =============================================================================
+// Raycast script commands
+// =============================================================================
+
+/// Parse `@raycast.<key> <value>` metadata comments from a script command.
+///
+/// Returns `None` unless the file declares `@raycast.schemaVersion`, which is
+/// what marks a file as a Raycast script command. Only the first 40 lines are
+/// checked, matching Raycast's own header convention.
+pub fn parse_raycast_metadata(content: &str) -> Option<HashMap<String, String>> {
+    let mut metadata = HashMap::new();
+    for line in content.lines().take(40) {
+        let trimmed = line
+            .trim_start()
+            .trim_start_matches('#')
+            .trim_start_matches("//")
+            .trim_start();
+        if let Some(rest) = trimmed.strip_prefix("@raycast.") {
+            if let Some((key, value)) = rest.split_once(char::is_whitespace) {
+                metadata.insert(key.to_string(), value.trim().to_string());
+            }
+        }
+    }
+    if metadata.contains_key("schemaVersion") {
+        Some(metadata)
+    } else {
+        None
+    }
+}
+
+/// Pick an interpreter for a Raycast script command from its shebang or
+/// file extension.
+fn interpreter_for(path: &Path, content: &str) -> String {
+    if let Some(first) = content.lines().next() {
+        if let Some(shebang) = first.strip_prefix("#!") {
+            // "#!/usr/bin/env python3" -> "python3", otherwise the binary path
+            let parts: Vec<&str> = shebang.split_whitespace().collect();
+            if parts.first() == Some(&"/usr/bin/env") {
+                if let Some(binary) = parts.get(1) {
+                    return binary.to_string();
+                }
+            } else if let Some(binary) = parts.first() {
+                return binary.to_string();
+            }
+        }
+    }
+    match path.extension().and_then(|e| e.to_str()).unwrap_or("") {
+        "py" => "python3".to_string(),
+        "rb" => "ruby".to_string(),
+        "js" | "ts" => "bun".to_string(),
+        "applescript" | "scpt" => "osascript".to_string(),
+        "zsh" => "/bin/zsh".to_string(),
+        _ => "/bin/bash".to_string(),
+    }
+}
+
+/// Build an import candidate from one Raycast script command file.
+pub fn raycast_candidate(path: &Path, content: &str) -> Option<ImportCandidate> {
+    let metadata = parse_raycast_metadata(content)?;
+    let title = metadata.get("title")?.clone();
+    Some(ImportCandidate {
+        source: ImportSource::Raycast,
+        title,
+        icon: metadata.get("icon").cloned(),
+        alias: None,
+        interpreter: interpreter_for(path, content),
+        body: content.to_string(),
+        origin: path.to_path_buf(),
+    })
+}
+
+/// Directories where users commonly keep Raycast script commands.
+/// Raycast lets users pick arbitrary directories, so this is best-effort.
+pub fn default_raycast_dirs() -> Vec<PathBuf> {
+    let Some(home) = dirs::home_dir() else {
+        return Vec::new();
+    };
+    vec![
+        home.join("raycast-scripts"),
+        home.join(".raycast/scripts"),
+        home.join("Documents/raycast-scripts"),
+    ]
+}
+
+/// Scan one directory (non-recursive) for Raycast script commands.
+pub fn scan_raycast_scripts(dir: &Path) -> Vec<ImportCandidate> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut candidates = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Some(candidate) = raycast_candidate(&path, &content) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+// =============================================================================
+// Alfred workflows
+// =============================================================================
+
+/// A parsed plist value. Only the subset Alfred's info.plist uses is modeled;
+/// `<data>` and `<date>` are carried as strings.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlistValue {
+    Dict(Vec<(String, PlistValue)>),
+    Array(Vec<PlistValue>),
+    String(String),
+    Integer(i64),
+    Real(f64),
+    Bool(bool),
+}
+
+impl PlistValue {
+    /// Look up a key in a dict value.
+    pub fn get(&self, key: &str) -> Option<&PlistValue> {
+        match self {
+            PlistValue::Dict(pairs) => pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Borrow the string payload, if this is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PlistValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Borrow the integer payload, if this is an integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            PlistValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+}
+
+/// Decode the XML entities that appear in plist string payloads.
+fn unescape_xml(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Minimal cursor-based parser for XML plists.
+///
+/// Alfred's info.plist files are machine-generated and regular, so a small
+/// hand-rolled parser keeps us from pulling in an XML dependency for one
+/// import path. Unknown elements fail the parse rather than being guessed at.
+struct PlistParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> PlistParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.input.len() && self.input.as_bytes()[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    /// Consume `tag` if it is next in the input.
+    fn eat(&mut self, tag: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(tag) {
+            self.pos += tag.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Read text up to the closing `tag`, consuming the tag.
+    fn text_until(&mut self, tag: &str) -> Option<String> {
+        let end = self.rest().find(tag)?;
+        let text = &self.rest()[..end];
+        self.pos += end + tag.len();
+        Some(unescape_xml(text))
+    }
+
+    /// Parse the next plist value element.
+    fn parse_value(&mut self) -> Option<PlistValue> {
+        self.skip_whitespace();
+        if self.eat("<dict>") {
+            let mut pairs = Vec::new();
+            loop {
+                if self.eat("</dict>") {
+                    return Some(PlistValue::Dict(pairs));
+                }
+                if !self.eat("<key>") {
+                    return None;
+                }
+                let key = self.text_until("</key>")?;
+                let value = self.parse_value()?;
+                pairs.push((key, value));
+            }
+        }
+        if self.eat("<dict/>") {
+            return Some(PlistValue::Dict(Vec::new()));
+        }
+        if self.eat("<array>") {
+            let mut items = Vec::new();
+            loop {
+                if self.eat("</array>") {
+                    return Some(PlistValue::Array(items));
+                }
+                items.push(self.parse_value()?);
+            }
+        }
+        if self.eat("<array/>") {
+            return Some(PlistValue::Array(Vec::new()));
+        }
+        if self.eat("<string>") {
+            return Some(PlistValue::String(self.text_until("</string>")?));
+        }
+        if self.eat("<string/>") {
+            return Some(PlistValue::String(String::new()));
+        }
+        if self.eat("<integer>") {
+            return self
+                .text_until("</integer>")?
+                .trim()
+                .parse()
+                .ok()
+                .map(PlistValue::Integer);
+        }
+        if self.eat("<real>") {
+            return self
+                .text_until("</real>")?
+                .trim()
+                .parse()
+                .ok()
+                .map(PlistValue::Real);
+        }
+        if self.eat("<true/>") {
+            return Some(PlistValue::Bool(true));
+        }
+        if self.eat("<false/>") {
+            return Some(PlistValue::Bool(false));
+        }
+        if self.eat("<data>") {
+            return Some(PlistValue::String(self.text_until("</data>")?));
+        }
+        if self.eat("<date>") {
+            return Some(PlistValue::String(self.text_until("</date>")?));
+        }
+        None
+    }
+}
+
+/// Parse an XML plist document into its root value.
+pub fn parse_plist(xml: &str) -> Option<PlistValue> {
+    // Skip the prolog and DOCTYPE, then the <plist ...> wrapper
+    let start = xml.find("<plist")?;
+    let body_start = start + xml[start..].find('>')? + 1;
+    let mut parser = PlistParser::new(&xml[body_start..]);
+    parser.parse_value()
+}
+
+/// Map Alfred's script `type` integer to an interpreter binary.
+/// Unknown or "external script" types fall back to bash.
+fn alfred_interpreter(script_type: i64) -> String {
+    match script_type {
+        1 => "/usr/bin/php".to_string(),
+        2 => "/usr/bin/ruby".to_string(),
+        3 => "/usr/bin/python3".to_string(),
+        4 => "/usr/bin/perl".to_string(),
+        5 => "/bin/zsh".to_string(),
+        6 | 7 => "/usr/bin/osascript".to_string(),
+        _ => "/bin/bash".to_string(),
+    }
+}
+
+/// Extract script filter candidates from one Alfred workflow's info.plist.
+pub fn parse_alfred_workflow(info_plist_xml: &str, origin: &Path) -> Vec<ImportCandidate> {
+    let Some(root) = parse_plist(info_plist_xml) else {
+        return Vec::new();
+    };
+    let workflow_name = root
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Alfred Workflow")
+        .to_string();
+
+    let Some(PlistValue::Array(objects)) = root.get("objects") else {
+        return Vec::new();
+    };
+
+    let mut candidates = Vec::new();
+    for object in objects {
+        let is_script_filter = object
+            .get("type")
+            .and_then(|v| v.as_str())
+            .map(|t| t == "alfred.workflow.input.scriptfilter")
+            .unwrap_or(false);
+        if !is_script_filter {
+            continue;
+        }
+        let Some(config) = object.get("config") else {
+            continue;
+        };
+        let Some(script) = config.get("script").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if script.trim().is_empty() {
+            continue;
+        }
+
+        let title = config
+            .get("title")
+            .and_then(|v| v.as_str())
+            .filter(|t| !t.is_empty())
+            .unwrap_or(&workflow_name)
+            .to_string();
+        let keyword = config
+            .get("keyword")
+            .and_then(|v| v.as_str())
+            .filter(|k| !k.is_empty())
+            .map(String::from);
+        let script_type = config.get("type").and_then(|v| v.as_i64()).unwrap_or(0);
+
+        candidates.push(ImportCandidate {
+            source: ImportSource::Alfred,
+            title,
+            icon: None,
+            alias: keyword,
+            interpreter: alfred_interpreter(script_type),
+            body: script.to_string(),
+            origin: origin.to_path_buf(),
+        });
+    }
+    candidates
+}
+
+/// Alfred's default workflows directory.
+pub fn default_alfred_workflows_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+        .map(|h| h.join("Library/Application Support/Alfred/Alfred.alfredpreferences/workflows"))
+}
+
+/// Scan every workflow under the Alfred workflows directory.
+pub fn scan_alfred_workflows(dir: &Path) -> Vec<ImportCandidate> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut candidates = Vec::new();
+    for entry in entries.flatten() {
+        let info_plist = entry.path().join("info.plist");
+        let Ok(xml) = std::fs::read_to_string(&info_plist) else {
+            continue;
+        };
+        candidates.extend(parse_alfred_workflow(&xml, &info_plist));
+    }
+    candidates
+}
+
+// =============================================================================
+// Conversion and writing
+// =============================================================================
+
+/// Convert a title to a file-name-friendly slug
+/// e.g., "Open Project…" -> "open-project"
+fn slugify(title: &str) -> String {
+    title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Escape a string for embedding inside a TypeScript template literal.
+pub fn escape_template_literal(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+/// The flag the interpreter uses to evaluate an inline program.
+fn eval_flag(interpreter: &str) -> &'static str {
+    let binary = Path::new(interpreter)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(interpreter);
+    match binary {
+        "osascript" | "ruby" | "perl" | "node" | "bun" => "-e",
+        _ => "-c",
+    }
+}
+
+/// Generate the Script Kit wrapper script for one candidate.
+pub fn convert_to_script(candidate: &ImportCandidate) -> String {
+    let mut header = format!("// Name: {}\n", candidate.title);
+    header.push_str(&format!(
+        "// Description: Imported from {} ({})\n",
+        candidate.source.label(),
+        candidate
+            .origin
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| candidate.origin.display().to_string())
+    ));
+    if let Some(ref alias) = candidate.alias {
+        header.push_str(&format!("// Alias: {}\n", alias));
+    }
+    if let Some(ref icon) = candidate.icon {
+        header.push_str(&format!("// Icon: {}\n", icon));
+    }
+
+    format!(
+        "{header}\n\
+         import {{ spawnSync }} from \"child_process\";\n\n\
+         const result = spawnSync(\"{interpreter}\", [\"{flag}\", `{body}`], {{\n\
+         \x20 encoding: \"utf8\",\n\
+         }});\n\
+         if (result.stdout) console.log(result.stdout.trimEnd());\n\
+         if (result.status !== 0 && result.stderr) console.error(result.stderr.trimEnd());\n",
+        header = header,
+        interpreter = candidate.interpreter,
+        flag = eval_flag(&candidate.interpreter),
+        body = escape_template_literal(&candidate.body),
+    )
+}
+
+/// Write one candidate into `scripts_dir` as `imported-<slug>.ts`.
+///
+/// Returns `Ok(None)` when the target already exists (skip, never overwrite).
+pub fn write_candidate(
+    candidate: &ImportCandidate,
+    scripts_dir: &Path,
+) -> Result<Option<PathBuf>, String> {
+    let slug = slugify(&candidate.title);
+    if slug.is_empty() {
+        return Err(format!(
+            "Cannot derive a file name from title '{}'",
+            candidate.title
+        ));
+    }
+    let target = scripts_dir.join(format!("imported-{}.ts", slug));
+    if target.exists() {
+        return Ok(None);
+    }
+    std::fs::create_dir_all(scripts_dir)
+        .map_err(|e| format!("Failed to create {}: {}", scripts_dir.display(), e))?;
+    std::fs::write(&target, convert_to_script(candidate))
+        .map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+    Ok(Some(target))
+}
+
+/// Gather candidates from all default Raycast and Alfred locations.
+pub fn gather_candidates() -> Vec<ImportCandidate> {
+    let mut candidates = Vec::new();
+    for dir in default_raycast_dirs() {
+        candidates.extend(scan_raycast_scripts(&dir));
+    }
+    if let Some(dir) = default_alfred_workflows_dir() {
+        candidates.extend(scan_alfred_workflows(&dir));
+    }
+    candidates
+}
+
+/// Run the full import flow against `~/.sk/kit/scripts`.
+pub fn run_import() -> ImportReport {
+    let scripts_dir = dirs::home_dir()
+        .map(|h| h.join(".sk/kit/scripts"))
+        .unwrap_or_else(|| PathBuf::from(".sk/kit/scripts"));
+    run_import_into(&scripts_dir)
+}
+
+/// Run the full import flow, writing into the given scripts directory.
+pub fn run_import_into(scripts_dir: &Path) -> ImportReport {
+    let mut report = ImportReport::default();
+    for candidate in gather_candidates() {
+        match write_candidate(&candidate, scripts_dir) {
+            Ok(Some(path)) => {
+                logging::log(
+                    "IMPORT",
+                    &format!(
+                        "Imported {} '{}' -> {}",
+                        candidate.source.label(),
+                        candidate.title,
+                        path.display()
+                    ),
+                );
+                report.imported.push(path);
+            }
+            Ok(None) => report.skipped.push(candidate.title.clone()),
+            Err(e) => report.errors.push(e),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAYCAST_SCRIPT: &str = "#!/bin/bash\n\
+        # @raycast.schemaVersion 1\n\
+        # @raycast.title Say Hello\n\
+        # @raycast.mode fullOutput\n\
+        # @raycast.icon 👋\n\
+        echo hello\n";
+
+    #[test]
+    fn test_parse_raycast_metadata() {
+        let metadata = parse_raycast_metadata(RAYCAST_SCRIPT).expect("should parse");
+        assert_eq!(metadata.get("title").map(String::as_str), Some("Say Hello"));
+        assert_eq!(metadata.get("icon").map(String::as_str), Some("👋"));
+    }
+
+    #[test]
+    fn test_parse_raycast_metadata_requires_schema_version() {
+        assert!(parse_raycast_metadata("# @raycast.title Orphan\necho hi\n").is_none());
+        assert!(parse_raycast_metadata("echo hi\n").is_none());
+    }
+
+    #[test]
+    fn test_raycast_candidate_uses_shebang_interpreter() {
+        let path = PathBuf::from("/tmp/say-hello.sh");
+        let candidate = raycast_candidate(&path, RAYCAST_SCRIPT).expect("should convert");
+        assert_eq!(candidate.title, "Say Hello");
+        assert_eq!(candidate.interpreter, "/bin/bash");
+        assert_eq!(candidate.icon.as_deref(), Some("👋"));
+    }
+
+    #[test]
+    fn test_interpreter_from_env_shebang() {
+        let path = PathBuf::from("/tmp/x.py");
+        let content = "#!/usr/bin/env python3\n# @raycast.schemaVersion 1\n# @raycast.title X\n";
+        let candidate = raycast_candidate(&path, content).unwrap();
+        assert_eq!(candidate.interpreter, "python3");
+    }
+
+    const ALFRED_PLIST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>My Workflow</string>
+    <key>objects</key>
+    <array>
+        <dict>
+            <key>type</key>
+            <string>alfred.workflow.input.scriptfilter</string>
+            <key>config</key>
+            <dict>
+                <key>keyword</key>
+                <string>gh</string>
+                <key>title</key>
+                <string>Search GitHub</string>
+                <key>script</key>
+                <string>echo "query: $1" &amp;&amp; open https://github.com</string>
+                <key>type</key>
+                <integer>0</integer>
+            </dict>
+        </dict>
+        <dict>
+            <key>type</key>
+            <string>alfred.workflow.action.openurl</string>
+            <key>config</key>
+            <dict/>
+        </dict>
+    </array>
+</dict>
+</plist>"#;
+
+    #[test]
+    fn test_parse_plist_round_trip() {
+        let root = parse_plist(ALFRED_PLIST).expect("should parse");
+        assert_eq!(
+            root.get("name").and_then(|v| v.as_str()),
+            Some("My Workflow")
+        );
+        match root.get("objects") {
+            Some(PlistValue::Array(objects)) => assert_eq!(objects.len(), 2),
+            other => panic!("expected objects array, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_alfred_workflow_extracts_script_filters() {
+        let origin = PathBuf::from("/tmp/info.plist");
+        let candidates = parse_alfred_workflow(ALFRED_PLIST, &origin);
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert_eq!(candidate.title, "Search GitHub");
+        assert_eq!(candidate.alias.as_deref(), Some("gh"));
+        assert_eq!(candidate.interpreter, "/bin/bash");
+        // XML entities should be decoded in the script body
+        assert!(candidate.body.contains("&&"));
+    }
+
+    #[test]
+    fn test_escape_template_literal() {
+        assert_eq!(escape_template_literal("a`b"), "a\\`b");
+        assert_eq!(escape_template_literal("${x}"), "\\${x}");
+        assert_eq!(escape_template_literal("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn test_convert_to_script_includes_metadata() {
+        let candidate = ImportCandidate {
+            source: ImportSource::Alfred,
+            title: "Search GitHub".to_string(),
+            icon: None,
+            alias: Some("gh".to_string()),
+            interpreter: "/bin/bash".to_string(),
+            body: "echo hi".to_string(),
+            origin: PathBuf::from("/tmp/info.plist"),
+        };
+        let script = convert_to_script(&candidate);
+        assert!(script.contains("// Name: Search GitHub"));
+        assert!(script.contains("// Alias: gh"));
+        assert!(script.contains("Imported from Alfred workflow"));
+        assert!(script.contains("spawnSync(\"/bin/bash\", [\"-c\", `echo hi`]"));
+    }
+
+    #[test]
+    fn test_write_candidate_skips_existing() {
+        let temp_dir = std::env::temp_dir().join(format!("importer-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        let candidate = ImportCandidate {
+            source: ImportSource::Raycast,
+            title: "Say Hello".to_string(),
+            icon: None,
+            alias: None,
+            interpreter: "/bin/bash".to_string(),
+            body: "echo hello".to_string(),
+            origin: PathBuf::from("/tmp/say-hello.sh"),
+        };
+
+        let written = write_candidate(&candidate, &temp_dir).unwrap();
+        assert!(written.is_some());
+        // Second run skips instead of overwriting
+        let second = write_candidate(&candidate, &temp_dir).unwrap();
+        assert!(second.is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,185 @@
+//! Issue quick-create for the Create Issue builtin
+//!
+//! Creates a Jira or Linear issue from a title string using the provider,
+//! project, and token configured under `issueTracker` in config.json (see
+//! [`crate::config::IssueTrackerConfig`]). Returns the created issue's web
+//! URL so the UI can surface it in a toast.
+//!
+//! All network calls are blocking (ureq); the requests are small and the
+//! APIs respond quickly, matching how the importer builtin runs inline.
+
+#![allow(dead_code)]
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+use crate::config::IssueTrackerConfig;
+
+/// Create an issue and return its web URL
+///
+/// Dispatches on `provider` ("jira" or "linear"); anything else is a
+/// configuration error.
+pub fn create_issue(config: &IssueTrackerConfig, title: &str) -> Result<String, String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("Issue title is empty".to_string());
+    }
+    if config.token.trim().is_empty() {
+        return Err("No issueTracker token configured".to_string());
+    }
+
+    match config.provider.to_lowercase().as_str() {
+        "jira" => create_jira_issue(config, title),
+        "linear" => create_linear_issue(config, title),
+        other => Err(format!(
+            "Unknown issueTracker provider '{}' (expected \"jira\" or \"linear\")",
+            other
+        )),
+    }
+}
+
+/// POST to the Jira REST API and build the browse URL from the issue key
+fn create_jira_issue(config: &IssueTrackerConfig, title: &str) -> Result<String, String> {
+    let base_url = config
+        .base_url
+        .as_deref()
+        .map(|u| u.trim_end_matches('/'))
+        .filter(|u| !u.is_empty())
+        .ok_or_else(|| {
+            "Jira needs issueTracker.baseUrl (e.g. https://acme.atlassian.net)".to_string()
+        })?;
+    let email = config
+        .email
+        .as_deref()
+        .filter(|e| !e.trim().is_empty())
+        .ok_or_else(|| "Jira needs issueTracker.email for API token auth".to_string())?;
+
+    let auth = BASE64.encode(format!("{}:{}", email, config.token));
+    let payload = serde_json::json!({
+        "fields": {
+            "project": { "key": config.project },
+            "summary": title,
+            "issuetype": { "name": "Task" }
+        }
+    });
+
+    let response = ureq::post(&format!("{}/rest/api/2/issue", base_url))
+        .header("Authorization", &format!("Basic {}", auth))
+        .header("Content-Type", "application/json")
+        .send_json(&payload)
+        .map_err(|e| format!("Jira request failed: {}", e))?;
+    let json: serde_json::Value = response
+        .into_body()
+        .read_json()
+        .map_err(|e| format!("Failed to parse Jira response: {}", e))?;
+
+    parse_jira_response(base_url, &json)
+}
+
+/// Extract the issue key from a Jira create response and build the web URL
+fn parse_jira_response(base_url: &str, json: &serde_json::Value) -> Result<String, String> {
+    json["key"]
+        .as_str()
+        .map(|key| format!("{}/browse/{}", base_url, key))
+        .ok_or_else(|| format!("Jira response missing issue key: {}", json))
+}
+
+/// POST an issueCreate mutation to the Linear GraphQL API
+fn create_linear_issue(config: &IssueTrackerConfig, title: &str) -> Result<String, String> {
+    if config.project.trim().is_empty() {
+        return Err("Linear needs issueTracker.project set to a team ID".to_string());
+    }
+
+    let payload = serde_json::json!({
+        "query": "mutation($teamId: String!, $title: String!) { issueCreate(input: { teamId: $teamId, title: $title }) { success issue { url } } }",
+        "variables": { "teamId": config.project, "title": title }
+    });
+
+    let response = ureq::post("https://api.linear.app/graphql")
+        .header("Authorization", &config.token)
+        .header("Content-Type", "application/json")
+        .send_json(&payload)
+        .map_err(|e| format!("Linear request failed: {}", e))?;
+    let json: serde_json::Value = response
+        .into_body()
+        .read_json()
+        .map_err(|e| format!("Failed to parse Linear response: {}", e))?;
+
+    parse_linear_response(&json)
+}
+
+/// Extract the issue URL from a Linear issueCreate response
+fn parse_linear_response(json: &serde_json::Value) -> Result<String, String> {
+    if let Some(errors) = json["errors"].as_array() {
+        if let Some(message) = errors.first().and_then(|e| e["message"].as_str()) {
+            return Err(format!("Linear rejected the issue: {}", message));
+        }
+    }
+    json["data"]["issueCreate"]["issue"]["url"]
+        .as_str()
+        .map(|url| url.to_string())
+        .ok_or_else(|| format!("Linear response missing issue URL: {}", json))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jira_response_builds_browse_url() {
+        let json = serde_json::json!({ "id": "10001", "key": "ACME-42" });
+        assert_eq!(
+            parse_jira_response("https://acme.atlassian.net", &json),
+            Ok("https://acme.atlassian.net/browse/ACME-42".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_jira_response_missing_key_is_error() {
+        let json = serde_json::json!({ "errorMessages": ["project is required"] });
+        assert!(parse_jira_response("https://acme.atlassian.net", &json).is_err());
+    }
+
+    #[test]
+    fn test_parse_linear_response_extracts_url() {
+        let json = serde_json::json!({
+            "data": { "issueCreate": { "success": true, "issue": {
+                "url": "https://linear.app/acme/issue/ACM-7"
+            }}}
+        });
+        assert_eq!(
+            parse_linear_response(&json),
+            Ok("https://linear.app/acme/issue/ACM-7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_linear_response_surfaces_graphql_errors() {
+        let json = serde_json::json!({
+            "errors": [{ "message": "Team not found" }]
+        });
+        let err = parse_linear_response(&json).unwrap_err();
+        assert!(err.contains("Team not found"));
+    }
+
+    #[test]
+    fn test_create_issue_rejects_unknown_provider() {
+        let config = IssueTrackerConfig {
+            provider: "asana".to_string(),
+            token: "tok".to_string(),
+            ..Default::default()
+        };
+        assert!(create_issue(&config, "A title")
+            .unwrap_err()
+            .contains("asana"));
+    }
+
+    #[test]
+    fn test_create_issue_rejects_empty_title_and_token() {
+        let config = IssueTrackerConfig {
+            provider: "linear".to_string(),
+            ..Default::default()
+        };
+        assert!(create_issue(&config, "   ").is_err());
+        assert!(create_issue(&config, "A title").is_err());
+    }
+}
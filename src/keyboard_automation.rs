@@ -0,0 +1,335 @@
+//! Keyboard Automation Module for macOS
+//!
+//! Handles the `keyboard` protocol message so scripts can type text or tap
+//! key combos into other applications after the launcher hides:
+//! - `keyboard.type`: Types a string into the focused app, character by
+//!   character, using CGEvent unicode payloads (works for any text, no
+//!   keyboard-layout mapping needed)
+//! - `keyboard.tap`: Presses a single key combo like `"cmd+shift+a"` or
+//!   `"enter"`
+//!
+//! ## Configurable Delay
+//!
+//! Both operations accept a per-keystroke delay in milliseconds. A small
+//! delay (the default) makes injection reliable in apps that debounce input.
+//!
+//! ## Permissions
+//!
+//! Requires Accessibility permission in System Preferences > Privacy &
+//! Security > Accessibility. Both entry points check the permission up front
+//! and return an error instead of silently posting events that macOS drops.
+
+use anyhow::{bail, Context, Result};
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, info, instrument, warn};
+
+/// Default delay between keystrokes in milliseconds
+pub const DEFAULT_KEY_DELAY_MS: u64 = 5;
+
+// ============================================================================
+// Combo Parsing (pure, testable)
+// ============================================================================
+
+/// A parsed key combo: modifier names plus the final key name
+///
+/// Modifier and key names are normalized to lowercase. Modifier aliases are
+/// canonicalized (`command`/`meta`/`super` → `cmd`, `option` → `alt`,
+/// `control` → `ctrl`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyCombo {
+    /// Canonical modifier names: "cmd", "shift", "alt", "ctrl"
+    pub modifiers: Vec<String>,
+    /// The non-modifier key (e.g. "a", "enter", "f5")
+    pub key: String,
+}
+
+/// Parse a combo string like `"cmd+shift+a"` into modifiers and key.
+///
+/// The last `+`-separated segment is the key; everything before it must be a
+/// modifier. Returns `None` for empty input or when a non-final segment isn't
+/// a recognized modifier.
+pub fn parse_key_combo(combo: &str) -> Option<KeyCombo> {
+    let parts: Vec<&str> = combo.split('+').map(|p| p.trim()).collect();
+    if parts.is_empty() || parts.iter().any(|p| p.is_empty()) {
+        return None;
+    }
+
+    let mut modifiers = Vec::new();
+    for part in &parts[..parts.len() - 1] {
+        match canonical_modifier(part) {
+            Some(modifier) => modifiers.push(modifier.to_string()),
+            None => return None,
+        }
+    }
+
+    Some(KeyCombo {
+        modifiers,
+        key: parts[parts.len() - 1].to_lowercase(),
+    })
+}
+
+/// Canonicalize a modifier name, returning `None` for non-modifiers
+fn canonical_modifier(name: &str) -> Option<&'static str> {
+    match name.to_lowercase().as_str() {
+        "cmd" | "command" | "meta" | "super" => Some("cmd"),
+        "shift" => Some("shift"),
+        "alt" | "option" | "opt" => Some("alt"),
+        "ctrl" | "control" => Some("ctrl"),
+        _ => None,
+    }
+}
+
+/// Map a key name to its macOS virtual keycode
+///
+/// Covers letters, digits, and the common navigation/function keys scripts
+/// automate. Returns `None` for unknown keys.
+pub fn keycode_for(key: &str) -> Option<u16> {
+    let code = match key {
+        "a" => 0,
+        "s" => 1,
+        "d" => 2,
+        "f" => 3,
+        "h" => 4,
+        "g" => 5,
+        "z" => 6,
+        "x" => 7,
+        "c" => 8,
+        "v" => 9,
+        "b" => 11,
+        "q" => 12,
+        "w" => 13,
+        "e" => 14,
+        "r" => 15,
+        "y" => 16,
+        "t" => 17,
+        "1" => 18,
+        "2" => 19,
+        "3" => 20,
+        "4" => 21,
+        "6" => 22,
+        "5" => 23,
+        "=" | "equal" => 24,
+        "9" => 25,
+        "7" => 26,
+        "-" | "minus" => 27,
+        "8" => 28,
+        "0" => 29,
+        "o" => 31,
+        "u" => 32,
+        "i" => 34,
+        "p" => 35,
+        "l" => 37,
+        "j" => 38,
+        "k" => 40,
+        "n" => 45,
+        "m" => 46,
+        "enter" | "return" => 36,
+        "tab" => 48,
+        "space" => 49,
+        "backspace" | "delete" => 51,
+        "escape" | "esc" => 53,
+        "left" => 123,
+        "right" => 124,
+        "down" => 125,
+        "up" => 126,
+        "home" => 115,
+        "end" => 119,
+        "pageup" => 116,
+        "pagedown" => 121,
+        "f1" => 122,
+        "f2" => 120,
+        "f3" => 99,
+        "f4" => 118,
+        "f5" => 96,
+        "f6" => 97,
+        "f7" => 98,
+        "f8" => 100,
+        "f9" => 101,
+        "f10" => 109,
+        "f11" => 103,
+        "f12" => 111,
+        _ => return None,
+    };
+    Some(code)
+}
+
+// ============================================================================
+// Typing and Tapping
+// ============================================================================
+
+/// Type a string into the focused application, one character at a time.
+///
+/// Uses CGEvent's unicode string payload so any character can be typed
+/// regardless of the active keyboard layout.
+///
+/// # Arguments
+/// * `text` - The text to type
+/// * `delay_ms` - Delay between characters in milliseconds
+///
+/// # Errors
+/// Returns error if Accessibility permission is missing or event posting fails
+#[instrument(skip(text), fields(text_len = text.len(), delay_ms))]
+pub fn type_text(text: &str, delay_ms: u64) -> Result<()> {
+    if !crate::selected_text::has_accessibility_permission() {
+        bail!("Accessibility permission required. Enable in System Preferences > Privacy & Security > Accessibility");
+    }
+
+    if text.is_empty() {
+        debug!("Empty text, nothing to type");
+        return Ok(());
+    }
+
+    debug!(text_len = text.len(), "Typing text via CGEvent");
+
+    for ch in text.chars() {
+        post_unicode_char(ch)?;
+        if delay_ms > 0 {
+            thread::sleep(Duration::from_millis(delay_ms));
+        }
+    }
+
+    info!(text_len = text.len(), "Typed text successfully");
+    Ok(())
+}
+
+/// Press a key combo like `"cmd+shift+a"` in the focused application.
+///
+/// # Arguments
+/// * `combo` - The combo string; the last segment is the key, the rest modifiers
+/// * `delay_ms` - Delay between key-down and key-up in milliseconds
+///
+/// # Errors
+/// Returns error if Accessibility permission is missing, the combo can't be
+/// parsed, or event posting fails
+#[instrument(fields(combo, delay_ms))]
+pub fn tap_key(combo: &str, delay_ms: u64) -> Result<()> {
+    if !crate::selected_text::has_accessibility_permission() {
+        bail!("Accessibility permission required. Enable in System Preferences > Privacy & Security > Accessibility");
+    }
+
+    let parsed = parse_key_combo(combo)
+        .with_context(|| format!("Invalid key combo: '{}'", combo))?;
+    let keycode = keycode_for(&parsed.key)
+        .with_context(|| format!("Unknown key in combo: '{}'", parsed.key))?;
+
+    debug!(combo, keycode, "Tapping key combo via CGEvent");
+    post_key_combo(keycode, &parsed.modifiers, delay_ms)?;
+
+    info!(combo, "Tapped key combo successfully");
+    Ok(())
+}
+
+/// Post a single unicode character as key-down/key-up events
+fn post_unicode_char(ch: char) -> Result<()> {
+    use core_graphics::event::{CGEvent, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .ok()
+        .context("Failed to create CGEventSource")?;
+
+    let mut buf = [0u16; 2];
+    let units = ch.encode_utf16(&mut buf);
+
+    // Keycode 0 is a placeholder; the unicode payload determines the character
+    let key_down = CGEvent::new_keyboard_event(source.clone(), 0, true)
+        .ok()
+        .context("Failed to create key down event")?;
+    key_down.set_string_from_utf16_unchecked(units);
+    key_down.post(CGEventTapLocation::HID);
+
+    let key_up = CGEvent::new_keyboard_event(source, 0, false)
+        .ok()
+        .context("Failed to create key up event")?;
+    key_up.set_string_from_utf16_unchecked(units);
+    key_up.post(CGEventTapLocation::HID);
+
+    Ok(())
+}
+
+/// Post a keycode with modifier flags as key-down/key-up events
+fn post_key_combo(keycode: u16, modifiers: &[String], delay_ms: u64) -> Result<()> {
+    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .ok()
+        .context("Failed to create CGEventSource")?;
+
+    let mut flags = CGEventFlags::empty();
+    for modifier in modifiers {
+        match modifier.as_str() {
+            "cmd" => flags |= CGEventFlags::CGEventFlagCommand,
+            "shift" => flags |= CGEventFlags::CGEventFlagShift,
+            "alt" => flags |= CGEventFlags::CGEventFlagAlternate,
+            "ctrl" => flags |= CGEventFlags::CGEventFlagControl,
+            other => warn!(modifier = other, "Ignoring unknown modifier"),
+        }
+    }
+
+    let key_down = CGEvent::new_keyboard_event(source.clone(), keycode, true)
+        .ok()
+        .context("Failed to create key down event")?;
+    key_down.set_flags(flags);
+
+    let key_up = CGEvent::new_keyboard_event(source, keycode, false)
+        .ok()
+        .context("Failed to create key up event")?;
+    key_up.set_flags(flags);
+
+    key_down.post(CGEventTapLocation::HID);
+    thread::sleep(Duration::from_millis(delay_ms.max(1)));
+    key_up.post(CGEventTapLocation::HID);
+
+    Ok(())
+}
+
+// ============================================================================
+// Unit Tests (pure parsing/mapping - no system interaction)
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_key() {
+        let combo = parse_key_combo("a").unwrap();
+        assert!(combo.modifiers.is_empty());
+        assert_eq!(combo.key, "a");
+    }
+
+    #[test]
+    fn test_parse_combo_with_modifiers() {
+        let combo = parse_key_combo("cmd+shift+a").unwrap();
+        assert_eq!(combo.modifiers, vec!["cmd", "shift"]);
+        assert_eq!(combo.key, "a");
+    }
+
+    #[test]
+    fn test_parse_combo_normalizes_aliases() {
+        let combo = parse_key_combo("Command+Option+Enter").unwrap();
+        assert_eq!(combo.modifiers, vec!["cmd", "alt"]);
+        assert_eq!(combo.key, "enter");
+    }
+
+    #[test]
+    fn test_parse_combo_rejects_invalid() {
+        // Non-modifier in a modifier position
+        assert_eq!(parse_key_combo("a+b"), None);
+        // Empty segments
+        assert_eq!(parse_key_combo(""), None);
+        assert_eq!(parse_key_combo("cmd+"), None);
+    }
+
+    #[test]
+    fn test_keycode_for_common_keys() {
+        assert_eq!(keycode_for("a"), Some(0));
+        assert_eq!(keycode_for("enter"), Some(36));
+        assert_eq!(keycode_for("return"), Some(36));
+        assert_eq!(keycode_for("escape"), Some(53));
+        assert_eq!(keycode_for("f5"), Some(96));
+        assert_eq!(keycode_for("not-a-key"), None);
+    }
+}
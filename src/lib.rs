@@ -5,33 +5,50 @@
 //! This library provides the core functionality for executing scripts
 //! with bidirectional JSONL communication.
 
+pub mod accessibility;
 pub mod components;
 pub mod config;
 pub mod debug_grid;
 pub mod designs;
 pub mod editor;
+pub mod env_file;
 pub mod error;
 pub mod executor;
 pub mod form_prompt;
+pub mod hidden;
 pub mod hotkeys;
+pub mod i18n;
 pub mod list_item;
 pub mod logging;
 pub mod navigation;
+pub mod onboarding;
 pub mod panel;
 pub mod perf;
+pub mod pins;
 pub mod platform;
+pub mod prompt_harness;
 pub mod prompts;
 pub mod protocol;
+pub mod query_history;
+pub mod quiet_hours;
+pub mod run_logs;
+pub mod run_results;
 pub mod scripts;
+pub mod section_state;
 pub mod selected_text;
+pub mod settings_transfer;
+pub mod shell_history;
 pub mod shortcuts;
 pub mod syntax;
+pub mod task_pool;
 pub mod term_prompt;
 pub mod terminal;
 pub mod theme;
 pub mod toast_manager;
 #[cfg(not(test))]
 pub mod tray;
+pub mod undo;
+pub mod updater;
 pub mod warning_banner;
 pub mod utils;
 pub mod window_manager;
@@ -39,6 +56,7 @@ pub mod window_resize;
 
 // Phase 1 system API modules
 pub mod clipboard_history;
+pub mod file_preview;
 pub mod file_search;
 pub mod window_control;
 
@@ -46,8 +64,15 @@ pub mod window_control;
 #[cfg(target_os = "macos")]
 pub mod system_actions;
 
+// Quick-share actions for files (AirDrop/Mail/Messages/iCloud)
+pub mod share;
+
 // Script creation - Create new scripts and scriptlets
 pub mod script_creation;
+pub mod script_store;
+
+// Script trust - hash tracking and first-run/changed confirmation
+pub mod script_trust;
 
 // Permissions wizard - Check and request macOS permissions
 pub mod permissions_wizard;
@@ -69,7 +94,14 @@ pub mod menu_cache;
 
 // Built-in features registry
 pub mod app_launcher;
+pub mod batch_rename;
 pub mod builtins;
+pub mod file_mover;
+pub mod screenshot_history;
+pub mod secrets_vault;
+
+// Background task registry for scripts with `// Background: true`
+pub mod background_tasks;
 
 // Frecency tracking for script usage
 pub mod frecency;
@@ -106,6 +138,9 @@ pub mod template_variables;
 // Text injection for text expansion/snippet systems
 pub mod text_injector;
 
+// Keyboard automation for the `keyboard` protocol message (type/tap)
+pub mod keyboard_automation;
+
 // Expand trigger matching for text expansion
 pub mod expand_matcher;
 
@@ -123,6 +158,36 @@ pub mod expand_manager;
 #[cfg(feature = "ocr")]
 pub mod ocr;
 
+// macOS Shortcuts.app integration via the `shortcuts` CLI
+pub mod macos_shortcuts;
+
+// scriptkit:// URL scheme handling
+pub mod url_scheme;
+
+// Unix socket server for the `sk` companion CLI
+pub mod cli_server;
+
+// Proxied HTTP with per-domain consent for the `fetch` protocol message
+pub mod network_proxy;
+
+// GitHub integration (repos, review requests, notifications) for the builtin
+pub mod github;
+
+// Jira/Linear issue quick-create for the Create Issue builtin
+pub mod issue_tracker;
+
+// Timezone lookup and conversion for the World Clock builtin
+pub mod world_clock;
+
+// Wi-Fi and Bluetooth control for the connectivity builtins
+pub mod connectivity;
+
+// macOS Focus (Do Not Disturb) state and control
+pub mod focus_mode;
+
+// Raycast / Alfred import tool
+pub mod importer;
+
 // Script scheduling with cron expressions and natural language
 pub mod scheduler;
 
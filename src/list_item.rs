@@ -49,6 +49,10 @@ pub enum GroupedListItem {
     SectionHeader(String),
     /// A regular list item - usize is the index in the flat results array
     Item(usize),
+    /// A "Show more…" row at the end of a capped section. `hidden` is how
+    /// many items the cap is hiding; activating the row expands the section
+    /// for the rest of the session.
+    ShowMore { section: String, hidden: usize },
 }
 
 /// Pre-computed grouped list state for efficient navigation
@@ -246,6 +250,10 @@ pub struct ListItem {
     semantic_id: Option<String>,
     /// Show left accent bar when selected (3px colored bar on left edge)
     show_accent_bar: bool,
+    /// Char indices in `name` to highlight in accent color (from fuzzy matching)
+    name_match_indices: Vec<usize>,
+    /// Char indices in `description` to highlight in accent color (from fuzzy matching)
+    description_match_indices: Vec<usize>,
 }
 
 /// Width of the left accent bar for selected items
@@ -266,6 +274,8 @@ impl ListItem {
             on_hover: None,
             semantic_id: None,
             show_accent_bar: false,
+            name_match_indices: Vec::new(),
+            description_match_indices: Vec::new(),
         }
     }
 
@@ -361,6 +371,18 @@ impl ListItem {
         self
     }
 
+    /// Highlight matched characters in the name (char indices from fuzzy matching)
+    pub fn name_match_indices(mut self, indices: Vec<usize>) -> Self {
+        self.name_match_indices = indices;
+        self
+    }
+
+    /// Highlight matched characters in the description (char indices from fuzzy matching)
+    pub fn description_match_indices(mut self, indices: Vec<usize>) -> Self {
+        self.description_match_indices = indices;
+        self
+    }
+
     /// Set whether this item is selected
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -475,33 +497,63 @@ impl RenderOnce for ListItem {
             .flex_col()
             .justify_center();
 
+        // Accent color used for fuzzy-matched characters in name/description
+        let match_highlight_color = rgb(colors.accent_selected);
+
         // Name - text_sm (0.875rem ≈ 14px), medium weight (tighter than before)
         // Single-line with ellipsis truncation for long content
-        item_content = item_content.child(
-            div()
-                .text_sm()
-                .font_weight(FontWeight::MEDIUM)
-                .overflow_hidden()
-                .text_ellipsis()
-                .whitespace_nowrap()
-                .line_height(px(18.))
-                .child(self.name),
-        );
+        // When match indices are present, matched characters render in accent color
+        let name_div = div()
+            .text_sm()
+            .font_weight(FontWeight::MEDIUM)
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .line_height(px(18.));
+        let name_div = if self.name_match_indices.is_empty() {
+            name_div.child(self.name)
+        } else {
+            let mut row = name_div.flex().flex_row();
+            for (text, is_highlighted) in highlight_segments(&self.name, &self.name_match_indices) {
+                let segment = div().child(text);
+                row = row.child(if is_highlighted {
+                    segment.text_color(match_highlight_color)
+                } else {
+                    segment
+                });
+            }
+            row
+        };
+        item_content = item_content.child(name_div);
 
         // Description - text_xs (0.75rem ≈ 12px), muted color (never changes on selection - only bg shows selection)
         // Single-line with ellipsis truncation for long content
         if let Some(desc) = self.description {
             let desc_color = rgb(colors.text_muted);
-            item_content = item_content.child(
-                div()
-                    .text_xs()
-                    .line_height(px(14.))
-                    .text_color(desc_color)
-                    .overflow_hidden()
-                    .text_ellipsis()
-                    .whitespace_nowrap()
-                    .child(desc),
-            );
+            let desc_div = div()
+                .text_xs()
+                .line_height(px(14.))
+                .text_color(desc_color)
+                .overflow_hidden()
+                .text_ellipsis()
+                .whitespace_nowrap();
+            let desc_div = if self.description_match_indices.is_empty() {
+                desc_div.child(desc)
+            } else {
+                let mut row = desc_div.flex().flex_row();
+                for (text, is_highlighted) in
+                    highlight_segments(&desc, &self.description_match_indices)
+                {
+                    let segment = div().child(text);
+                    row = row.child(if is_highlighted {
+                        segment.text_color(match_highlight_color)
+                    } else {
+                        segment
+                    });
+                }
+                row
+            };
+            item_content = item_content.child(desc_div);
         }
 
         // Shortcut badge (if present) - right-aligned
@@ -633,6 +685,22 @@ impl RenderOnce for ListItem {
     }
 }
 
+/// Split `text` into (segment, is_highlighted) runs based on matched char indices.
+///
+/// Consecutive characters with the same highlight state are grouped into one
+/// segment so the renderer emits a minimal number of child divs.
+fn highlight_segments(text: &str, indices: &[usize]) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    for (i, ch) in text.chars().enumerate() {
+        let is_highlighted = indices.contains(&i);
+        match segments.last_mut() {
+            Some((segment, highlighted)) if *highlighted == is_highlighted => segment.push(ch),
+            _ => segments.push((ch.to_string(), is_highlighted)),
+        }
+    }
+    segments
+}
+
 /// Decode PNG bytes to GPUI RenderImage
 ///
 /// Decode PNG bytes to a GPUI RenderImage
@@ -694,6 +762,19 @@ fn decode_png_to_render_image_internal(
     Ok(Arc::new(render_image))
 }
 
+/// Build a GPUI RenderImage from raw BGRA pixels (e.g. a window capture)
+///
+/// CoreGraphics window captures already use the BGRA byte order Metal
+/// expects, so no channel swap is needed here. Returns None if the buffer
+/// length doesn't match the stated dimensions.
+pub fn bgra_to_render_image(width: u32, height: u32, bgra: Vec<u8>) -> Option<Arc<RenderImage>> {
+    use smallvec::SmallVec;
+
+    let buffer = image::RgbaImage::from_raw(width, height, bgra)?;
+    let frame = image::Frame::new(buffer);
+    Some(Arc::new(RenderImage::new(SmallVec::from_elem(frame, 1))))
+}
+
 /// Create an IconKind from PNG bytes by pre-decoding them
 ///
 /// Returns None if decoding fails. This should be called once when loading
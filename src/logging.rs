@@ -279,7 +279,30 @@ impl<'a> MakeWriter<'a> for StderrWriter {
 // LEGACY SUPPORT - In-memory log buffer for UI display
 // =============================================================================
 
-static LOG_BUFFER: OnceLock<Mutex<VecDeque<String>>> = OnceLock::new();
+/// A log line held in the in-memory buffer for the log panel.
+/// Lines that came from a script carry the source file and (when the SDK's
+/// console wrapper emitted a `path:line` reference) the originating line,
+/// so the panel can jump to the source on click.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// Log category, e.g. "UI" or "SCRIPT"
+    pub category: String,
+    /// The log message text
+    pub message: String,
+    /// Script file this line originated from, if any
+    pub script_path: Option<String>,
+    /// 1-based line in `script_path` that logged it, if known
+    pub line: Option<u32>,
+}
+
+impl LogEntry {
+    /// The panel display string, matching the legacy buffer format
+    pub fn display(&self) -> String {
+        format!("[{}] {}", self.category, self.message)
+    }
+}
+
+static LOG_BUFFER: OnceLock<Mutex<VecDeque<LogEntry>>> = OnceLock::new();
 const MAX_LOG_LINES: usize = 50;
 
 /// Guard that must be kept alive for the duration of the program.
@@ -428,21 +451,113 @@ pub fn log(category: &str, message: &str) {
 
 /// Add a log entry to the in-memory buffer for UI display
 fn add_to_buffer(category: &str, message: &str) {
+    push_entry(LogEntry {
+        category: category.to_string(),
+        message: message.to_string(),
+        script_path: None,
+        line: None,
+    });
+}
+
+fn push_entry(entry: LogEntry) {
     if let Some(buffer) = LOG_BUFFER.get() {
         if let Ok(mut buf) = buffer.lock() {
             if buf.len() >= MAX_LOG_LINES {
                 buf.pop_front();
             }
-            buf.push_back(format!("[{}] {}", category, message));
+            buf.push_back(entry);
+        }
+    }
+}
+
+/// Log a line of script output (stderr/console) tagged with the script it
+/// came from. When the SDK's console wrapper appended a `path:line` source
+/// reference, the mapped file wins over the script path so clicking the
+/// line in the log panel jumps to the file that actually logged it.
+pub fn log_script_output(script_path: &str, message: &str) {
+    let (source_path, line) = match parse_console_source(message) {
+        Some((path, line)) => (path, Some(line)),
+        None => (script_path.to_string(), None),
+    };
+    push_entry(LogEntry {
+        category: "SCRIPT".to_string(),
+        message: message.to_string(),
+        script_path: Some(source_path),
+        line,
+    });
+    tracing::info!(
+        category = "SCRIPT",
+        script_path = script_path,
+        legacy = true,
+        "{}",
+        message
+    );
+}
+
+/// Log a structured console message forwarded by the SDK's `log` protocol
+/// message. `file`/`line` come from the console wrapper's own source
+/// mapping; without them the line still links back to the script itself.
+pub fn log_script_console(script_path: &str, text: &str, file: Option<String>, line: Option<u32>) {
+    push_entry(LogEntry {
+        category: "SCRIPT".to_string(),
+        message: text.to_string(),
+        script_path: Some(file.unwrap_or_else(|| script_path.to_string())),
+        line,
+    });
+    tracing::info!(
+        category = "SCRIPT",
+        script_path = script_path,
+        legacy = true,
+        "{}",
+        text
+    );
+}
+
+/// Parse a `path:line` source reference from a console line, e.g.
+/// "fetching (/Users/me/.sk/kit/scripts/sync.ts:12)" from the SDK's console
+/// wrapper or "at /Users/me/.sk/kit/scripts/sync.ts:12:5" from a stack frame.
+/// Returns the file and 1-based line of the last reference on the line.
+pub fn parse_console_source(message: &str) -> Option<(String, u32)> {
+    for token in message.split_whitespace().rev() {
+        let token = token.trim_matches(|c| matches!(c, '(' | ')' | ',' | ';'));
+        let token = token.strip_prefix("file://").unwrap_or(token);
+        if let Some(parsed) = parse_path_line(token) {
+            return Some(parsed);
         }
     }
+    None
+}
+
+/// Parse "path.ts:12" or "path.ts:12:5" into (path, line)
+fn parse_path_line(token: &str) -> Option<(String, u32)> {
+    let mut parts: Vec<&str> = token.split(':').collect();
+    if parts.len() < 2 {
+        return None;
+    }
+    // Drop a trailing column number ("path:line:col")
+    if parts.len() >= 3
+        && parts[parts.len() - 1].parse::<u32>().is_ok()
+        && parts[parts.len() - 2].parse::<u32>().is_ok()
+    {
+        parts.pop();
+    }
+    let line: u32 = parts.pop()?.parse().ok()?;
+    let path = parts.join(":");
+    let is_source = [".ts", ".tsx", ".js", ".jsx", ".mjs", ".mts"]
+        .iter()
+        .any(|ext| path.ends_with(ext));
+    if is_source && line > 0 {
+        Some((path, line))
+    } else {
+        None
+    }
 }
 
 /// Get recent log lines for UI display
 pub fn get_recent_logs() -> Vec<String> {
     if let Some(buffer) = LOG_BUFFER.get() {
         if let Ok(buf) = buffer.lock() {
-            return buf.iter().cloned().collect();
+            return buf.iter().map(LogEntry::display).collect();
         }
     }
     Vec::new()
@@ -450,6 +565,16 @@ pub fn get_recent_logs() -> Vec<String> {
 
 /// Get the last N log lines
 pub fn get_last_logs(n: usize) -> Vec<String> {
+    if let Some(buffer) = LOG_BUFFER.get() {
+        if let Ok(buf) = buffer.lock() {
+            return buf.iter().rev().take(n).map(|e| e.display()).collect();
+        }
+    }
+    Vec::new()
+}
+
+/// Get the last N log entries with their source metadata (newest first)
+pub fn get_last_log_entries(n: usize) -> Vec<LogEntry> {
     if let Some(buffer) = LOG_BUFFER.get() {
         if let Ok(buf) = buffer.lock() {
             return buf.iter().rev().take(n).cloned().collect();
@@ -1146,6 +1271,59 @@ pub use tracing;
 mod tests {
     use super::*;
 
+    // -------------------------------------------------------------------------
+    // parse_console_source tests - SDK console wrapper and stack frame formats
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_console_source_wrapper_format() {
+        // SDK console wrapper appends "(path:line)" to console output
+        let parsed = parse_console_source("fetching feed (/Users/me/.sk/kit/scripts/sync.ts:12)");
+        assert_eq!(
+            parsed,
+            Some(("/Users/me/.sk/kit/scripts/sync.ts".to_string(), 12))
+        );
+    }
+
+    #[test]
+    fn test_parse_console_source_stack_frame() {
+        // Stack frames carry a column which is dropped
+        let parsed = parse_console_source("at /Users/me/.sk/kit/scripts/sync.ts:12:5");
+        assert_eq!(
+            parsed,
+            Some(("/Users/me/.sk/kit/scripts/sync.ts".to_string(), 12))
+        );
+    }
+
+    #[test]
+    fn test_parse_console_source_file_url() {
+        let parsed = parse_console_source("at file:///Users/me/.sk/kit/scripts/sync.ts:3");
+        assert_eq!(
+            parsed,
+            Some(("/Users/me/.sk/kit/scripts/sync.ts".to_string(), 3))
+        );
+    }
+
+    #[test]
+    fn test_parse_console_source_none_for_plain_lines() {
+        assert_eq!(parse_console_source("plain console output"), None);
+        // A bare time is not a source reference
+        assert_eq!(parse_console_source("started at 12:30"), None);
+        // Zero is not a valid 1-based line
+        assert_eq!(parse_console_source("(/tmp/a.ts:0)"), None);
+    }
+
+    #[test]
+    fn test_log_entry_display_matches_legacy_format() {
+        let entry = LogEntry {
+            category: "SCRIPT".to_string(),
+            message: "hello".to_string(),
+            script_path: None,
+            line: None,
+        };
+        assert_eq!(entry.display(), "[SCRIPT] hello");
+    }
+
     // -------------------------------------------------------------------------
     // category_to_code tests - using real category strings from logs
     // -------------------------------------------------------------------------
@@ -0,0 +1,145 @@
+//! macOS Shortcuts.app Integration
+//!
+//! Lists and runs the user's Shortcuts via the `shortcuts` command line tool
+//! that ships with macOS 12+. Listing backs the "Run Shortcut" builtin view;
+//! running backs both the builtin and the `shortcuts.run` protocol message.
+//!
+//! ## Platform Support
+//! This module only works on macOS. On other platforms, the functions will
+//! return an error indicating Shortcuts are not supported.
+
+use anyhow::{anyhow, Result};
+
+#[cfg(target_os = "macos")]
+use std::io::Write;
+#[cfg(target_os = "macos")]
+use std::process::{Command, Stdio};
+
+/// Parse the output of `shortcuts list` into shortcut names
+///
+/// The tool prints one shortcut name per line; blank lines are skipped.
+pub fn parse_shortcuts_list(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// List the user's Shortcuts via `shortcuts list`
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - Shortcut names in Shortcuts.app order
+/// * `Err` - If the `shortcuts` tool is missing or exits with an error
+#[cfg(target_os = "macos")]
+pub fn list_shortcuts() -> Result<Vec<String>> {
+    let output = Command::new("shortcuts")
+        .arg("list")
+        .output()
+        .map_err(|e| anyhow!("Failed to run `shortcuts list`: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("`shortcuts list` failed: {}", stderr.trim()));
+    }
+
+    Ok(parse_shortcuts_list(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn list_shortcuts() -> Result<Vec<String>> {
+    Err(anyhow!("Shortcuts are only supported on macOS"))
+}
+
+/// Run a Shortcut by name, optionally passing input
+///
+/// Input is piped to the shortcut via stdin (`--input-path -`) and the
+/// shortcut's output is captured from stdout (`--output-path -`).
+///
+/// # Returns
+/// * `Ok(String)` - The shortcut's output, may be empty
+/// * `Err` - If the shortcut fails or the `shortcuts` tool is missing
+#[cfg(target_os = "macos")]
+pub fn run_shortcut(name: &str, input: Option<&str>) -> Result<String> {
+    let mut cmd = Command::new("shortcuts");
+    cmd.arg("run")
+        .arg(name)
+        .args(["--output-path", "-"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if input.is_some() {
+        // `-` makes the tool read the shortcut input from stdin
+        cmd.args(["--input-path", "-"]).stdin(Stdio::piped());
+    }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| anyhow!("Failed to run shortcut '{}': {}", name, e))?;
+
+    if let (Some(text), Some(mut stdin)) = (input, child.stdin.take()) {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| anyhow!("Failed to pass input to shortcut '{}': {}", name, e))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| anyhow!("Failed to wait for shortcut '{}': {}", name, e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("Shortcut '{}' failed: {}", name, stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn run_shortcut(_name: &str, _input: Option<&str>) -> Result<String> {
+    Err(anyhow!("Shortcuts are only supported on macOS"))
+}
+
+/// Run a Shortcut asynchronously on a background thread
+///
+/// Shortcuts can take seconds to complete, so the builtin uses this to
+/// avoid blocking the UI. The callback is called with the result when done.
+pub fn run_shortcut_async<F>(name: String, input: Option<String>, callback: F)
+where
+    F: FnOnce(Result<String>) + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let result = run_shortcut(&name, input.as_deref());
+        callback(result);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shortcuts_list_basic() {
+        let output = "Resize Images\nStart Focus\nLog Water\n";
+        let shortcuts = parse_shortcuts_list(output);
+        assert_eq!(shortcuts, vec!["Resize Images", "Start Focus", "Log Water"]);
+    }
+
+    #[test]
+    fn test_parse_shortcuts_list_skips_blank_lines() {
+        let output = "One\n\n  \nTwo\n";
+        let shortcuts = parse_shortcuts_list(output);
+        assert_eq!(shortcuts, vec!["One", "Two"]);
+    }
+
+    #[test]
+    fn test_parse_shortcuts_list_empty() {
+        assert!(parse_shortcuts_list("").is_empty());
+        assert!(parse_shortcuts_list("\n\n").is_empty());
+    }
+}
@@ -2,8 +2,9 @@
 
 use gpui::{
     div, hsla, list, point, prelude::*, px, rgb, rgba, size, svg, uniform_list, AnyElement, App,
-    Application, BoxShadow, Context, ElementId, Entity, FocusHandle, Focusable, ListAlignment,
-    ListSizingBehavior, ListState, Render, ScrollStrategy, SharedString, Subscription, Timer,
+    Application, Bounds, BoxShadow, Context, ElementId, Entity, FocusHandle, Focusable,
+    ListAlignment, ListSizingBehavior, ListState, Pixels, Render, ScrollStrategy, SharedString,
+    Subscription, Timer,
     UniformListScrollHandle, Window, WindowBackgroundAppearance, WindowBounds, WindowHandle,
     WindowOptions,
 };
@@ -28,40 +29,58 @@ use platform::{
 #[macro_use]
 extern crate objc;
 
+mod accessibility;
 mod actions;
 mod ai;
 mod components;
 mod config;
 mod designs;
 mod editor;
+mod env_file;
 mod error;
 mod executor;
 mod filter_coalescer;
 mod form_prompt;
+mod hidden;
 #[allow(dead_code)] // TODO: Re-enable once hotkey_pollers is updated for Root wrapper
 mod hotkey_pollers;
 mod hotkeys;
+mod i18n;
 mod list_item;
 mod logging;
 mod login_item;
 mod navigation;
+mod onboarding;
 mod panel;
 mod perf;
+mod pins;
 mod platform;
+#[cfg(test)]
+mod prompt_harness;
 mod prompts;
 mod protocol;
+mod query_history;
+mod quiet_hours;
+mod run_logs;
+mod run_results;
 mod scripts;
+mod section_state;
 #[cfg(target_os = "macos")]
 mod selected_text;
+mod settings_transfer;
 mod setup;
+mod shell_history;
 mod shortcuts;
 mod stdin_commands;
 mod syntax;
+mod task_pool;
 mod term_prompt;
 mod terminal;
 mod theme;
 mod transitions;
 mod tray;
+mod undo;
+mod updater;
 mod utils;
 mod warning_banner;
 mod watcher;
@@ -70,6 +89,7 @@ mod window_resize;
 
 // Phase 1 system API modules
 mod clipboard_history;
+mod file_preview;
 mod file_search;
 mod toast_manager;
 mod window_control;
@@ -78,15 +98,29 @@ mod window_control;
 #[cfg(target_os = "macos")]
 mod system_actions;
 
+// Quick-share actions for files (AirDrop/Mail/Messages/iCloud)
+mod share;
+
 // Script creation - Create new scripts and scriptlets
 mod script_creation;
+mod script_store;
+
+// Script trust - hash tracking and first-run/changed confirmation
+mod script_trust;
 
 // Permissions wizard - Check and request macOS permissions
 mod permissions_wizard;
 
 // Built-in features registry
 mod app_launcher;
+mod batch_rename;
 mod builtins;
+mod file_mover;
+mod screenshot_history;
+mod secrets_vault;
+
+// Background task registry for scripts with `// Background: true`
+mod background_tasks;
 
 // Frecency tracking for script usage
 mod frecency;
@@ -118,6 +152,9 @@ mod expand_matcher;
 mod keyboard_monitor;
 mod text_injector;
 
+// Keyboard automation for the `keyboard` protocol message (type/tap)
+mod keyboard_automation;
+
 // Expand manager - text expansion system integration
 #[cfg(target_os = "macos")]
 mod expand_manager;
@@ -131,6 +168,40 @@ mod hud_manager;
 // Debug grid overlay for visual testing
 mod debug_grid;
 
+// OCR text extraction via the macOS Vision framework
+#[cfg(feature = "ocr")]
+mod ocr;
+
+// macOS Shortcuts.app integration via the `shortcuts` CLI
+mod macos_shortcuts;
+
+// scriptkit:// URL scheme handling
+mod url_scheme;
+
+// Unix socket server for the `sk` companion CLI
+mod cli_server;
+
+// Proxied HTTP with per-domain consent for the `fetch` protocol message
+mod network_proxy;
+
+// GitHub integration (repos, review requests, notifications) for the builtin
+mod github;
+
+// Jira/Linear issue quick-create for the Create Issue builtin
+mod issue_tracker;
+
+// Timezone lookup and conversion for the World Clock builtin
+mod world_clock;
+
+// Wi-Fi and Bluetooth control for the connectivity builtins
+mod connectivity;
+
+// macOS Focus (Do Not Disturb) state and control
+mod focus_mode;
+
+// Raycast / Alfred import tool
+mod importer;
+
 // MCP Server modules for AI agent integration
 mod mcp_kit_tools;
 mod mcp_protocol;
@@ -142,7 +213,7 @@ mod mcp_streaming;
 // Notes - Raycast Notes feature parity (separate floating window)
 mod notes;
 
-use crate::components::text_input::TextInputState;
+use crate::components::text_input::{TextInput, TextInputState};
 use crate::components::toast::{Toast, ToastAction};
 use crate::error::ErrorSeverity;
 use crate::filter_coalescer::FilterCoalescer;
@@ -154,8 +225,8 @@ use crate::toast_manager::{PendingToast, ToastManager};
 use components::ToastVariant;
 use editor::EditorPrompt;
 use prompts::{
-    ContainerOptions, ContainerPadding, DivPrompt, DropPrompt, EnvPrompt, PathInfo, PathPrompt,
-    SelectPrompt, TemplatePrompt,
+    ContainerOptions, ContainerPadding, DatePrompt, DivPrompt, DropPrompt, EnvPrompt, NumberPrompt,
+    PathInfo, PathPrompt, SelectPrompt, TablePrompt, TemplatePrompt,
 };
 use tray::{TrayManager, TrayMenuAction};
 use warning_banner::{WarningBanner, WarningBannerColors};
@@ -166,20 +237,20 @@ use window_resize::{
 
 use components::{
     Button, ButtonColors, ButtonVariant, FormFieldColors, Scrollbar, ScrollbarColors,
+    SCROLLBAR_PADDING, SCROLLBAR_WIDTH,
 };
 use designs::{get_tokens, render_design_item, DesignVariant};
 use frecency::FrecencyStore;
 use list_item::{
-    render_section_header, GroupedListItem, ListItem, ListItemColors, LIST_ITEM_HEIGHT,
+    render_section_header, GroupedListItem, IconKind, ListItem, ListItemColors, LIST_ITEM_HEIGHT,
     SECTION_HEADER_HEIGHT,
 };
-use scripts::get_grouped_results;
 // strip_html_tags removed - DivPrompt now renders HTML properly
 
 use actions::{ActionsDialog, ScriptInfo};
 use panel::{
-    CURSOR_GAP_X, CURSOR_HEIGHT_LG, CURSOR_MARGIN_Y, CURSOR_WIDTH, DEFAULT_PLACEHOLDER, HEADER_GAP,
-    HEADER_PADDING_X, HEADER_PADDING_Y,
+    CURSOR_HEIGHT_LG, CURSOR_MARGIN_Y, DEFAULT_PLACEHOLDER, HEADER_GAP, HEADER_PADDING_X,
+    HEADER_PADDING_Y,
 };
 use parking_lot::Mutex as ParkingMutex;
 use protocol::{Choice, Message, ProtocolAction};
@@ -339,6 +410,12 @@ enum AppView {
         id: String,
         entity: Entity<SelectPrompt>,
     },
+    /// Showing a table prompt from a script (tabular data)
+    TablePrompt {
+        #[allow(dead_code)]
+        id: String,
+        entity: Entity<TablePrompt>,
+    },
     /// Showing a path prompt from a script (file/folder picker)
     PathPrompt {
         #[allow(dead_code)]
@@ -352,6 +429,18 @@ enum AppView {
         id: String,
         entity: Entity<EnvPrompt>,
     },
+    /// Showing number prompt for typed numeric input
+    NumberPrompt {
+        #[allow(dead_code)]
+        id: String,
+        entity: Entity<NumberPrompt>,
+    },
+    /// Showing date prompt with calendar grid and natural-language input
+    DatePrompt {
+        #[allow(dead_code)]
+        id: String,
+        entity: Entity<DatePrompt>,
+    },
     /// Showing drop prompt for drag and drop file handling
     DropPrompt {
         #[allow(dead_code)]
@@ -382,17 +471,132 @@ enum AppView {
         filter: String,
         selected_index: usize,
     },
+    /// Showing running background tasks with stop actions
+    BackgroundTasksView {
+        tasks: Vec<background_tasks::BackgroundTask>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing the user's macOS Shortcuts as runnable entries
+    ShortcutsView {
+        shortcuts: Vec<String>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing trashed files with restore actions
+    RecentlyDeletedView {
+        entries: Vec<undo::TrashEntry>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing bundled themes with live preview
+    ThemeBrowserView {
+        themes: Vec<theme::BundledTheme>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing text expansion triggers with usage counts and conflicts
+    ExpandStatsView {
+        entries: Vec<expand_manager::ExpandStatEntry>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing GitHub repos, PRs awaiting review, and notifications
+    GitHubView {
+        items: Vec<github::GitHubItem>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing known Wi-Fi networks and paired Bluetooth devices
+    ConnectView {
+        targets: Vec<connectivity::ConnectTarget>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing macOS Focus modes with the active one marked
+    FocusModesView {
+        modes: Vec<focus_mode::FocusMode>,
+        active: Option<String>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing world clock rows (favorites, lookups, and conversions)
+    WorldClockView {
+        favorites: Vec<String>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing Spotlight file search results (re-queried as the filter changes)
+    FileSearchView {
+        results: Vec<file_search::FileResult>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing the batch rename builtin (pick files, then pattern + preview)
+    BatchRenameView {
+        state: batch_rename::BatchRenameState,
+    },
+    /// Showing the screenshot history builtin (thumbnails + OCR)
+    ScreenshotHistoryView {
+        shots: Vec<screenshot_history::Screenshot>,
+        filter: String,
+        selected_index: usize,
+    },
+    /// Showing the move files builtin (pick files, destination, conflicts)
+    FileMoverView { state: file_mover::MoverState },
+    /// Showing the secrets vault builtin (keyring-backed env keys)
+    SecretsVaultView { state: secrets_vault::SecretsState },
     /// Showing design gallery (separator and icon variations)
     DesignGalleryView {
         filter: String,
         selected_index: usize,
     },
+    /// Rich script-failure panel: source snippet at the failing line plus
+    /// "Open at line" / "Copy error" actions (replaces the error toast when
+    /// the top stack frame maps back to a readable user file)
+    ErrorView {
+        error_message: String,
+        details: Option<String>,
+        script_path: String,
+        frame: executor::StackFrame,
+        snippet: Vec<(u32, String)>,
+        selected_index: usize,
+    },
+}
+
+/// Saved prompt state for back navigation (Escape / Cmd+[)
+///
+/// Captures the replaced prompt view plus the shared arg input state that
+/// lives on ScriptListApp rather than inside the AppView variant.
+struct PromptStackEntry {
+    view: AppView,
+    arg_input_text: String,
+    arg_selected_index: usize,
+    focused_input: FocusedInput,
 }
 
 /// Wrapper to hold a script session that can be shared across async boundaries
 /// Uses parking_lot::Mutex which doesn't poison on panic, avoiding .unwrap() calls
 type SharedSession = Arc<ParkingMutex<Option<executor::ScriptSession>>>;
 
+/// Why the grouped-results cache must be recomputed
+///
+/// Filter-text changes invalidate implicitly (the cache is keyed off the
+/// filter); every other mutation goes through
+/// `ScriptListApp::invalidate_grouped_cache` with one of these reasons so
+/// cache-debug logs say what went stale.
+#[derive(Debug, Clone, Copy)]
+enum GroupedCacheInvalidation {
+    /// Scripts, scriptlets, or apps were reloaded
+    ScriptReload,
+    /// A frecency score changed (an item was executed)
+    FrecencyUpdate,
+    /// Pins changed (pin/unpin/reorder/undo)
+    PinChange,
+    /// Section presentation changed (collapse, hide, expand, config reload)
+    SectionChange,
+}
+
 /// Tracks which input field currently has focus for cursor display
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum FocusedInput {
@@ -414,6 +618,16 @@ enum PromptMessage {
         placeholder: String,
         choices: Vec<Choice>,
         actions: Option<Vec<ProtocolAction>>,
+        /// When false, choices keep script-provided order while filtering
+        sort: Option<bool>,
+        /// When true, the script has more pages of choices to deliver
+        has_more: Option<bool>,
+    },
+    /// Script delivered a page of choices for a paginated arg prompt
+    AppendChoices {
+        id: String,
+        choices: Vec<Choice>,
+        has_more: bool,
     },
     ShowDiv {
         id: String,
@@ -456,6 +670,8 @@ enum PromptMessage {
         id: String,
         start_path: Option<String>,
         hint: Option<String>,
+        /// Filtering/save-dialog options (dirs-only, extension filters, etc.)
+        options: prompts::PathPromptOptions,
     },
     /// Environment variable prompt with optional secret handling
     ShowEnv {
@@ -464,6 +680,22 @@ enum PromptMessage {
         prompt: Option<String>,
         secret: bool,
     },
+    /// Numeric input prompt with min/max/step constraints
+    ShowNumber {
+        id: String,
+        placeholder: Option<String>,
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>,
+        value: Option<f64>,
+    },
+    /// Date/time picker prompt ("date" or "time" mode)
+    ShowDate {
+        id: String,
+        placeholder: Option<String>,
+        mode: Option<String>,
+        value: Option<String>,
+    },
     /// Drag and drop prompt for file uploads
     ShowDrop {
         id: String,
@@ -482,6 +714,14 @@ enum PromptMessage {
         choices: Vec<Choice>,
         multiple: bool,
     },
+    /// Table prompt with sortable columns and row selection
+    ShowTable {
+        id: String,
+        placeholder: Option<String>,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+        multiple: bool,
+    },
     HideWindow,
     OpenBrowser {
         url: String,
@@ -491,6 +731,15 @@ enum PromptMessage {
     RunScript {
         path: String,
     },
+    /// Auto-restart a crashed `// Restart: true` background script
+    RestartScript {
+        path: String,
+    },
+    /// A `// Retries: N` script used up its retry budget and was left stopped
+    RetriesExhausted {
+        path: String,
+        attempts: u32,
+    },
     /// Script error with detailed information for toast display
     ScriptError {
         error_message: String,
@@ -516,6 +765,10 @@ enum PromptMessage {
     GetState {
         request_id: String,
     },
+    /// Request to get app-level state - triggers AppStateResult response
+    GetAppState {
+        request_id: String,
+    },
     /// Request to get layout info with component tree and computed styles
     GetLayoutInfo {
         request_id: String,
@@ -574,17 +827,38 @@ struct ScriptListApp {
     is_scrolling: bool,
     /// Timestamp of last scroll activity (for fade-out timer)
     last_scroll_time: Option<std::time::Instant>,
+    /// Measured bounds of the main list container, captured at paint time.
+    /// Gives the scrollbar its real viewport height (instead of an estimate)
+    /// and maps thumb click/drag positions back to scroll offsets.
+    list_bounds: Option<Bounds<Pixels>>,
+    /// True while the scrollbar thumb is being dragged
+    scrollbar_dragging: bool,
+    /// Right-click context menu on main list items: cursor position in
+    /// window coordinates plus the clicked item's index. None when closed.
+    context_menu: Option<(gpui::Point<Pixels>, usize)>,
     // Interactive script state
     current_view: AppView,
+    // Previous prompts from the current script, newest last.
+    // Escape / Cmd+[ pops back through these; only the first prompt cancels.
+    prompt_stack: Vec<PromptStackEntry>,
     script_session: SharedSession,
     // Prompt-specific state (used when view is ArgPrompt or DivPrompt)
     // Uses TextInputState for selection and clipboard support
     arg_input: TextInputState,
     arg_selected_index: usize,
+    // Whether the current arg prompt re-sorts choices by fuzzy score while
+    // filtering (true unless the script sent `sort: false`)
+    arg_sort_enabled: bool,
+    // Whether the script has more pages of choices for the current arg prompt
+    arg_has_more: bool,
+    // True while a moreChoices request is in flight (prevents duplicates)
+    arg_more_requested: bool,
     // Channel for receiving prompt messages from script thread (async_channel for event-driven)
     prompt_receiver: Option<async_channel::Receiver<PromptMessage>>,
     // Channel for sending responses back to script
     response_sender: Option<mpsc::Sender<Message>>,
+    // Cancellation token for the running script session (cancelled on reset)
+    script_cancel_token: Option<executor::CancellationToken>,
     // List state for variable-height list (supports section headers at 24px + items at 48px)
     main_list_state: ListState,
     // Scroll handle for uniform_list (still used for backward compat in some views)
@@ -597,6 +871,11 @@ struct ScriptListApp {
     window_list_scroll_handle: UniformListScrollHandle,
     // Scroll handle for design gallery list
     design_gallery_scroll_handle: UniformListScrollHandle,
+    // Cached preview for the selected file-search result: (path, preview-or-none)
+    // Reloaded only when the selection changes, never per frame
+    file_preview_cache: Option<(String, Option<file_preview::FilePreview>)>,
+    // Path whose preview is being read on the task pool (dedupes spawns)
+    file_preview_loading: Option<String>,
     // Actions popup overlay
     show_actions_popup: bool,
     // ActionsDialog entity for focus management
@@ -616,6 +895,10 @@ struct ScriptListApp {
     cached_grouped_items: Arc<[GroupedListItem]>,
     cached_grouped_flat_results: Arc<[scripts::SearchResult]>,
     grouped_cache_key: String,
+    /// Dirty flag for the grouped cache: set via `invalidate_grouped_cache`
+    /// when something other than the filter text changes (script reload,
+    /// frecency update, pin change, ...). Cleared on recompute.
+    grouped_cache_dirty: bool,
     // P3: Two-stage filter - display vs search separation with coalescing
     /// What the search cache is built from (may lag behind filter_text during rapid typing)
     computed_filter_text: String,
@@ -630,10 +913,36 @@ struct ScriptListApp {
     current_design: DesignVariant,
     // Toast manager for notification queue
     toast_manager: ToastManager,
+    // Most recent destructive action, undoable with Cmd+Z while its window is open
+    pending_undo: Option<undo::PendingUndo>,
+    /// Theme active before the Theme Browser opened, restored if the user
+    /// escapes without applying a theme
+    theme_browser_saved: Option<theme::Theme>,
     // Cache for decoded clipboard images (entry_id -> RenderImage)
     clipboard_image_cache: std::collections::HashMap<String, Arc<gpui::RenderImage>>,
+    /// Window switcher thumbnail cache: captured previews keyed by window id
+    window_thumbnail_cache: std::collections::HashMap<u32, Arc<gpui::RenderImage>>,
+    /// Window ids whose thumbnail capture failed (don't retry every frame)
+    window_thumbnail_failed: std::collections::HashSet<u32>,
+    // Cache for decoded arg choice images (img path -> RenderImage)
+    arg_choice_image_cache: std::collections::HashMap<String, Arc<gpui::RenderImage>>,
     // Frecency store for tracking script usage
     frecency_store: FrecencyStore,
+    // Persisted collapsed state for main list sections
+    section_state: section_state::SectionStateStore,
+    // Sections expanded past the per-section item cap via "Show more…"
+    // (session-only, not persisted)
+    expanded_sections: std::collections::HashSet<String>,
+    // Persisted pinned items shown in the PINNED section
+    pin_store: pins::PinStore,
+    // Persisted keys hidden from search via the "Hide from Search" action
+    hidden_store: hidden::HiddenStore,
+    // Persisted shell one-liner history for the `>` filter scope
+    shell_history: shell_history::ShellHistoryStore,
+    // Persisted per-prompt query history (main filter + arg prompts)
+    query_history: query_history::QueryHistoryStore,
+    // Position while cycling query history with Up/Ctrl+R (None = not cycling)
+    history_cycle_index: Option<usize>,
     // Mouse hover tracking - independent from selected_index (keyboard focus)
     // hovered_index shows subtle visual feedback, selected_index shows full focus styling
     hovered_index: Option<usize>,
@@ -670,11 +979,33 @@ struct ScriptListApp {
     // Window focus tracking - for detecting focus lost and auto-dismissing prompts
     // When window loses focus while in a dismissable prompt, close and reset
     was_window_focused: bool,
+    // Missing-permission names from the last focus-gain re-check
+    // Used to only notify when the set of missing permissions changes
+    last_missing_permissions: Vec<String>,
+    // Pinned mode (Cmd+Shift+P): window floats above other apps and is
+    // exempt from focus-lost auto-dismiss. Useful for keeping div/markdown
+    // output visible as a reference panel while working elsewhere.
+    window_pinned: bool,
     // Show warning banner when bun is not available
     show_bun_warning: bool,
+    // Current onboarding tour step (Some only on fresh installs until
+    // the user finishes or dismisses the tour)
+    onboarding_step: Option<onboarding::OnboardingStep>,
     // Pending confirmation: when set, the entry with this ID is awaiting confirmation
     // Used for dangerous actions like Shut Down, Restart, Log Out, Empty Trash
     pending_confirmation: Option<String>,
+    // Pending scriptkit:// URL run awaiting confirmation (script + args)
+    // Set when a URL targets a script without `// AllowURL: true`
+    pending_url_run: Option<(scripts::Script, Vec<String>)>,
+    // Approved script hashes (~/.sk/kit/trusted-scripts.json); first runs and
+    // changed scripts are held in pending_trust_run until confirmed
+    trust_store: script_trust::TrustStore,
+    // Pending trust confirmation (script + args + content hash). Enter
+    // approves the hash and runs, Escape dismisses.
+    pending_trust_run: Option<(scripts::Script, Vec<String>, String)>,
+    // Path of the most recently run script, for the rerun summon hotkey
+    // (rerunLastScriptHotkey in config.json)
+    last_run_script_path: Option<String>,
     // Scroll stabilization: track last scrolled-to index for each scroll handle
     #[allow(dead_code)]
     last_scrolled_main: Option<usize>,
@@ -732,7 +1063,12 @@ impl Render for ScriptListApp {
         if self.was_window_focused && !is_window_focused {
             // Window just lost focus (user clicked another window)
             // Only auto-dismiss if we're in a dismissable view AND window is visible
-            if self.is_dismissable_view() && script_kit_gpui::is_main_window_visible() {
+            // Pinned windows (Cmd+Shift+P) are exempt - staying visible on blur
+            // is the whole point of pinning
+            if self.is_dismissable_view()
+                && !self.window_pinned
+                && script_kit_gpui::is_main_window_visible()
+            {
                 logging::log(
                     "FOCUS",
                     "Main window lost focus while in dismissable view - closing",
@@ -740,8 +1076,57 @@ impl Render for ScriptListApp {
                 self.close_and_reset_window(cx);
             }
         }
+        // Permission re-check on focus gain: users grant permissions in System
+        // Settings and switch back, so this is the natural moment to detect the
+        // change. Only notify when the set of missing permissions changes so
+        // the toast doesn't repeat on every focus.
+        if !self.was_window_focused && is_window_focused {
+            let status = permissions_wizard::check_all_permissions();
+            let missing: Vec<String> = status
+                .missing_permissions()
+                .iter()
+                .map(|p| p.permission_type.name().to_string())
+                .collect();
+            if missing != self.last_missing_permissions {
+                if missing.is_empty() {
+                    logging::log("FOCUS", "All permissions granted after re-check");
+                } else {
+                    logging::log(
+                        "FOCUS",
+                        &format!("Missing permissions on focus: {}", missing.join(", ")),
+                    );
+                    self.toast_manager.push(
+                        components::toast::Toast::warning(
+                            format!(
+                                "Missing permissions: {}. Run \"Check Permissions\" for guided setup.",
+                                missing.join(", ")
+                            ),
+                            &self.theme,
+                        )
+                        .duration_ms(Some(6000)),
+                    );
+                }
+                self.last_missing_permissions = missing;
+            }
+        }
         self.was_window_focused = is_window_focused;
 
+        // Surface the startup update check result once (per discovered update)
+        if is_window_focused {
+            if let Some(release) = updater::take_unnotified_update() {
+                self.toast_manager.push(
+                    components::toast::Toast::success(
+                        format!(
+                            "Script Kit {} is available. Run \"Install Update\" or \"View Changelog\".",
+                            release.version
+                        ),
+                        &self.theme,
+                    )
+                    .duration_ms(Some(8000)),
+                );
+            }
+        }
+
         // P0-4: Focus handling using reference match (avoids clone for focus check)
         // Focus handling depends on the view:
         // - For EditorPrompt: Use its own focus handle (not the parent's)
@@ -863,12 +1248,21 @@ impl Render for ScriptListApp {
             AppView::SelectPrompt { entity, .. } => {
                 self.render_select_prompt(entity, cx).into_any_element()
             }
+            AppView::TablePrompt { entity, .. } => {
+                self.render_table_prompt(entity, cx).into_any_element()
+            }
             AppView::PathPrompt { entity, .. } => {
                 self.render_path_prompt(entity, cx).into_any_element()
             }
             AppView::EnvPrompt { entity, .. } => {
                 self.render_env_prompt(entity, cx).into_any_element()
             }
+            AppView::NumberPrompt { entity, .. } => {
+                self.render_number_prompt(entity, cx).into_any_element()
+            }
+            AppView::DatePrompt { entity, .. } => {
+                self.render_date_prompt(entity, cx).into_any_element()
+            }
             AppView::DropPrompt { entity, .. } => {
                 self.render_drop_prompt(entity, cx).into_any_element()
             }
@@ -896,12 +1290,117 @@ impl Render for ScriptListApp {
             } => self
                 .render_window_switcher(windows, filter, selected_index, cx)
                 .into_any_element(),
+            AppView::BackgroundTasksView {
+                tasks,
+                filter,
+                selected_index,
+            } => self
+                .render_background_tasks(tasks, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::ShortcutsView {
+                shortcuts,
+                filter,
+                selected_index,
+            } => self
+                .render_shortcuts_list(shortcuts, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::RecentlyDeletedView {
+                entries,
+                filter,
+                selected_index,
+            } => self
+                .render_recently_deleted(entries, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::ThemeBrowserView {
+                themes,
+                filter,
+                selected_index,
+            } => self
+                .render_theme_browser(themes, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::ExpandStatsView {
+                entries,
+                filter,
+                selected_index,
+            } => self
+                .render_expand_stats(entries, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::GitHubView {
+                items,
+                filter,
+                selected_index,
+            } => self
+                .render_github(items, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::ConnectView {
+                targets,
+                filter,
+                selected_index,
+            } => self
+                .render_connect(targets, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::FocusModesView {
+                modes,
+                active,
+                filter,
+                selected_index,
+            } => self
+                .render_focus_modes(modes, active, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::WorldClockView {
+                favorites,
+                filter,
+                selected_index,
+            } => self
+                .render_world_clock(favorites, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::FileSearchView {
+                results,
+                filter,
+                selected_index,
+            } => self
+                .render_file_search(results, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::BatchRenameView { state } => {
+                self.render_batch_rename(state, cx).into_any_element()
+            }
+            AppView::ScreenshotHistoryView {
+                shots,
+                filter,
+                selected_index,
+            } => self
+                .render_screenshot_history(shots, filter, selected_index, cx)
+                .into_any_element(),
+            AppView::FileMoverView { state } => {
+                self.render_file_mover(state, cx).into_any_element()
+            }
+            AppView::SecretsVaultView { state } => {
+                self.render_secrets_vault(state, cx).into_any_element()
+            }
             AppView::DesignGalleryView {
                 filter,
                 selected_index,
             } => self
                 .render_design_gallery(filter, selected_index, cx)
                 .into_any_element(),
+            AppView::ErrorView {
+                error_message,
+                details,
+                script_path,
+                frame,
+                snippet,
+                selected_index,
+            } => self
+                .render_error_view(
+                    error_message,
+                    details,
+                    script_path,
+                    frame,
+                    snippet,
+                    selected_index,
+                    cx,
+                )
+                .into_any_element(),
         };
 
         // Wrap content in a container that can have the debug grid overlay
@@ -947,6 +1446,40 @@ impl Render for ScriptListApp {
             None
         };
 
+        // Build onboarding tour banner (fresh installs only, script list view only)
+        let onboarding_banner = match (self.onboarding_step, &self.current_view) {
+            (Some(step), AppView::ScriptList) => {
+                let banner_colors = WarningBannerColors::from_theme(&self.theme);
+                let entity = cx.entity().downgrade();
+                let entity_for_dismiss = entity.clone();
+
+                Some(
+                    div()
+                        .w_full()
+                        .px(px(12.))
+                        .pt(px(8.))
+                        .child(
+                            WarningBanner::new(step.message(), banner_colors)
+                                .on_click(Box::new(move |_event, _window, cx| {
+                                    if let Some(app) = entity.upgrade() {
+                                        app.update(cx, |this, cx| {
+                                            this.advance_onboarding(cx);
+                                        });
+                                    }
+                                }))
+                                .on_dismiss(Box::new(move |_event, _window, cx| {
+                                    if let Some(app) = entity_for_dismiss.upgrade() {
+                                        app.update(cx, |this, cx| {
+                                            this.dismiss_onboarding(cx);
+                                        });
+                                    }
+                                })),
+                        ),
+                )
+            }
+            _ => None,
+        };
+
         div()
             .w_full()
             .h_full()
@@ -957,6 +1490,10 @@ impl Render for ScriptListApp {
             .when_some(warning_banner, |container, banner| {
                 container.child(banner)
             })
+            // Onboarding tour banner appears below the warning banner
+            .when_some(onboarding_banner, |container, banner| {
+                container.child(banner)
+            })
             // Main content takes remaining space
             .child(
                 div()
@@ -976,6 +1513,27 @@ impl Render for ScriptListApp {
                     &component_bounds,
                 ))
             })
+            // Pin indicator (top-right) while the window is pinned - click to unpin
+            .when(self.window_pinned, |container| {
+                container.child(
+                    div()
+                        .id("pin-indicator")
+                        .absolute()
+                        .top(px(6.))
+                        .right(px(10.))
+                        .px(px(6.))
+                        .py(px(2.))
+                        .rounded(px(4.))
+                        .bg(rgba((self.theme.colors.background.search_box << 8) | 0xcc))
+                        .text_size(px(11.))
+                        .text_color(rgb(self.theme.colors.text.muted))
+                        .cursor_pointer()
+                        .child("📌 Pinned")
+                        .on_click(cx.listener(|this, _event: &gpui::ClickEvent, _window, cx| {
+                            this.toggle_window_pin(cx);
+                        })),
+                )
+            })
     }
 }
 
@@ -1080,6 +1638,9 @@ fn main() {
     // Load config early so we can use it for hotkey registration AND clipboard history settings
     // This avoids duplicate config::load_config() calls (~100-300ms startup savings)
     let loaded_config = config::load_config();
+    i18n::set_locale(i18n::Locale::from_tag(&loaded_config.get_locale()));
+    designs::set_ui_scale(loaded_config.get_ui_scale());
+    designs::set_list_font_size(loaded_config.get_list_font_size());
     logging::log(
         "APP",
         &format!(
@@ -1090,6 +1651,40 @@ fn main() {
     clipboard_history::set_max_text_content_len(
         loaded_config.get_clipboard_history_max_text_length(),
     );
+    clipboard_history::set_excluded_apps(loaded_config.get_clipboard_excluded_apps());
+    expand_manager::set_disabled_apps(loaded_config.get_expand_disabled_apps());
+
+    // Background refresh for the GitHub builtin (no-op until a token is set)
+    github::start_background_refresh();
+
+    // Kick off the startup update check (config-gated via autoUpdate).
+    // Result lands in updater's shared state; the render loop surfaces a toast.
+    if loaded_config.get_auto_update() {
+        std::thread::spawn(|| match updater::check_for_update() {
+            Ok(Some(release)) => {
+                logging::log(
+                    "UPDATER",
+                    &format!(
+                        "Update available: {} (current: {})",
+                        release.version,
+                        updater::CURRENT_VERSION
+                    ),
+                );
+                updater::set_available_update(Some(release));
+            }
+            Ok(None) => {
+                logging::log(
+                    "UPDATER",
+                    &format!("Up to date ({})", updater::CURRENT_VERSION),
+                );
+            }
+            Err(e) => {
+                logging::log("UPDATER", &format!("Startup update check failed: {}", e));
+            }
+        });
+    } else {
+        logging::log("UPDATER", "Auto-update check disabled in config");
+    }
 
     // Initialize clipboard history monitoring (background thread)
     if let Err(e) = clipboard_history::init_clipboard_history() {
@@ -1168,29 +1763,34 @@ fn main() {
     // Clone before start_hotkey_listener consumes original
     let config_for_app = loaded_config.clone();
 
-    // Start MCP server for AI agent integration
+    // Start MCP server for AI agent integration (opt-in via "mcpServer": true)
     // Server runs on localhost:43210 with Bearer token authentication
     // Discovery file written to ~/.sk/kit/server.json
-    let _mcp_handle = match mcp_server::McpServer::with_defaults() {
-        Ok(server) => match server.start() {
-            Ok(handle) => {
-                logging::log(
-                    "MCP",
-                    &format!(
-                        "MCP server started on {} (token in ~/.sk/kit/agent-token)",
-                        server.url()
-                    ),
-                );
-                Some(handle)
-            }
+    let _mcp_handle = if !loaded_config.get_mcp_server_enabled() {
+        logging::log("MCP", "MCP server disabled (enable with \"mcpServer\": true)");
+        None
+    } else {
+        match mcp_server::McpServer::with_defaults() {
+            Ok(server) => match server.start() {
+                Ok(handle) => {
+                    logging::log(
+                        "MCP",
+                        &format!(
+                            "MCP server started on {} (token in ~/.sk/kit/agent-token)",
+                            server.url()
+                        ),
+                    );
+                    Some(handle)
+                }
+                Err(e) => {
+                    logging::log("MCP", &format!("Failed to start MCP server: {}", e));
+                    None
+                }
+            },
             Err(e) => {
-                logging::log("MCP", &format!("Failed to start MCP server: {}", e));
+                logging::log("MCP", &format!("Failed to create MCP server: {}", e));
                 None
             }
-        },
-        Err(e) => {
-            logging::log("MCP", &format!("Failed to create MCP server: {}", e));
-            None
         }
     };
 
@@ -1234,7 +1834,18 @@ fn main() {
     // Wrap scheduler in Arc<Mutex<>> for thread-safe access (needed for re-scanning on file changes)
     let scheduler = Arc::new(Mutex::new(scheduler));
 
-    Application::new().run(move |cx: &mut App| {
+    // scriptkit:// URLs arrive via the platform open-urls callback; forward them
+    // onto a channel so the handler can run inside the app context
+    let (url_tx, url_rx) = async_channel::unbounded::<String>();
+
+    let app = Application::new();
+    app.on_open_urls(move |urls| {
+        for url in urls {
+            logging::log("URL", &format!("Received URL: {}", url));
+            let _ = url_tx.send_blocking(url);
+        }
+    });
+    app.run(move |cx: &mut App| {
         logging::log("APP", "GPUI Application starting");
 
         // Configure as accessory app FIRST, before any windows are created
@@ -1292,8 +1903,9 @@ fn main() {
         let app_entity_holder: Arc<Mutex<Option<Entity<ScriptListApp>>>> = Arc::new(Mutex::new(None));
         let app_entity_for_closure = app_entity_holder.clone();
 
-        // Capture bun_available for use in window creation
+        // Capture setup results for use in window creation
         let bun_available = setup_result.bun_available;
+        let is_fresh_install = setup_result.is_fresh_install;
         
         let window: WindowHandle<Root> = cx.open_window(
             WindowOptions {
@@ -1307,7 +1919,9 @@ fn main() {
             },
             |window, cx| {
                 logging::log("APP", "Window opened, creating ScriptListApp wrapped in Root");
-                let view = cx.new(|cx| ScriptListApp::new(config_for_app, bun_available, window, cx));
+                let view = cx.new(|cx| {
+                    ScriptListApp::new(config_for_app, bun_available, is_fresh_install, window, cx)
+                });
                 // Store the entity for external access
                 *app_entity_for_closure.lock().unwrap() = Some(view.clone());
                 cx.new(|cx| Root::new(view, window, cx))
@@ -1423,6 +2037,11 @@ fn main() {
                             window.activate_window();
                         });
 
+                        // Surface notifications queued during quiet hours
+                        app_entity_inner.update(cx, |view, ctx| {
+                            view.flush_quiet_digest(ctx);
+                        });
+
                         logging::log("HOTKEY", "Window shown and activated");
                     });
                 }
@@ -1464,6 +2083,95 @@ fn main() {
             logging::log("HOTKEY", "AI hotkey listener exiting (channel closed)");
         }).detach();
 
+        // Summon hotkey listener - opens the window pre-scoped (Clipboard
+        // History, File Search, or re-running the last used script) instead
+        // of on the script list. Opt-in via config.json hotkeys.
+        let app_entity_for_summon = app_entity.clone();
+        let window_for_summon = window;
+        cx.spawn(async move |cx: &mut gpui::AsyncApp| {
+            logging::log("HOTKEY", "Summon hotkey listener started (event-driven)");
+            while let Ok(target) = hotkeys::summon_hotkey_channel().1.recv().await {
+                logging::log("HOTKEY", &format!("Summon hotkey triggered: {:?}", target));
+
+                // Same guard as the main hotkey: Notes/AI windows take priority
+                if notes::is_notes_window_open() || ai::is_ai_window_open() {
+                    logging::log("HOTKEY", "Notes/AI window open - summon hotkey ignored");
+                    continue;
+                }
+
+                let was_visible = script_kit_gpui::is_main_window_visible();
+                script_kit_gpui::set_main_window_visible(true);
+
+                let app_entity_inner = app_entity_for_summon.clone();
+                let window_inner = window_for_summon;
+                let _ = cx.update(move |cx: &mut gpui::App| {
+                    if !was_visible {
+                        // Same show sequence as the main hotkey
+                        platform::ensure_move_to_active_space();
+
+                        let window_size = gpui::size(px(750.), initial_window_height());
+                        let bounds = platform::calculate_eye_line_bounds_on_mouse_display(window_size);
+                        platform::move_first_window_to_bounds(&bounds);
+
+                        if !PANEL_CONFIGURED.load(std::sync::atomic::Ordering::SeqCst) {
+                            platform::configure_as_floating_panel();
+                            PANEL_CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
+                        }
+
+                        cx.activate(true);
+                        let _ = window_inner.update(cx, |_root, window, _cx| {
+                            window.activate_window();
+                        });
+                    }
+
+                    // Start from a clean script list so the target view's
+                    // state (filter, selection) is fresh, then open the target
+                    app_entity_inner.update(cx, |view, ctx| {
+                        view.reset_to_script_list(ctx);
+                        view.flush_quiet_digest(ctx);
+                        view.open_summon_target(target, ctx);
+                    });
+                });
+            }
+            logging::log("HOTKEY", "Summon hotkey listener exiting (channel closed)");
+        }).detach();
+
+        // scriptkit:// URL listener - event-driven via async_channel
+        // URLs are forwarded from the open-urls callback registered before run()
+        let app_entity_for_urls = app_entity.clone();
+        let window_for_urls = window;
+        cx.spawn(async move |cx: &mut gpui::AsyncApp| {
+            logging::log("URL", "URL scheme listener started");
+            while let Ok(url) = url_rx.recv().await {
+                let Some(req) = url_scheme::parse_scriptkit_url(&url) else {
+                    logging::log("URL", &format!("Ignoring unsupported URL: {}", url));
+                    continue;
+                };
+
+                let app_entity_inner = app_entity_for_urls.clone();
+                let _ = cx.update(move |cx: &mut gpui::App| {
+                    // Show and focus the window so the user sees what is about to run
+                    script_kit_gpui::set_main_window_visible(true);
+                    platform::ensure_move_to_active_space();
+                    let window_size = gpui::size(px(750.), initial_window_height());
+                    let bounds = platform::calculate_eye_line_bounds_on_mouse_display(window_size);
+                    platform::move_first_window_to_bounds(&bounds);
+                    if !PANEL_CONFIGURED.load(std::sync::atomic::Ordering::SeqCst) {
+                        platform::configure_as_floating_panel();
+                        PANEL_CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
+                    }
+                    cx.activate(true);
+                    let _ = window_for_urls.update(cx, |_root, window, _cx| {
+                        window.activate_window();
+                    });
+                    app_entity_inner.update(cx, |view, ctx| {
+                        view.handle_url_request(req, ctx);
+                    });
+                });
+            }
+            logging::log("URL", "URL scheme listener exiting (channel closed)");
+        }).detach();
+
         // Appearance change watcher - event-driven with async_channel
         let app_entity_for_appearance = app_entity.clone();
         cx.spawn(async move |cx: &mut gpui::AsyncApp| {
@@ -1621,49 +2329,83 @@ fn main() {
                                     })
                                     .unwrap_or_else(|| "bun".to_string());
 
-                                // Spawn bun process to run the script
-                                match std::process::Command::new(&bun_path)
-                                    .arg("run")
-                                    .arg("--preload")
-                                    .arg(format!("{}/.sk/kit/sdk/kit-sdk.ts", std::env::var("HOME").unwrap_or_default()))
-                                    .arg(&path_str)
-                                    .stdout(std::process::Stdio::piped())
-                                    .stderr(std::process::Stdio::piped())
-                                    .spawn()
-                                {
-                                    Ok(child) => {
-                                        let pid = child.id();
-                                        // Track the process
-                                        PROCESS_MANAGER.register_process(pid, &path_str);
-                                        logging::log("SCHEDULER", &format!("Spawned scheduled script PID {}: {}", pid, path_str));
-
-                                        // Wait for completion in a separate thread to not block scheduler
-                                        let path_for_log = path_str.clone();
-                                        std::thread::spawn(move || {
-                                            match child.wait_with_output() {
-                                                Ok(output) => {
-                                                    // Unregister the process now that it's done
-                                                    PROCESS_MANAGER.unregister_process(pid);
-
-                                                    if output.status.success() {
-                                                        logging::log("SCHEDULER", &format!("Scheduled script completed: {}", path_for_log));
-                                                    } else {
+                                // `// Retries: N` scheduled scripts get retried with
+                                // exponential backoff when they exit non-zero
+                                let retry_limit = std::fs::read_to_string(&path)
+                                    .ok()
+                                    .and_then(|content| scripts::extract_retries_metadata(&content))
+                                    .unwrap_or(0);
+                                let script_name = path.file_stem()
+                                    .map(|s| s.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| path_str.clone());
+
+                                // Run (and retry) in a separate thread so spawn
+                                // failures and backoff sleeps never block the scheduler
+                                std::thread::spawn(move || {
+                                    let preload = format!("{}/.sk/kit/sdk/kit-sdk.ts", std::env::var("HOME").unwrap_or_default());
+                                    loop {
+                                        // Spawn bun process to run the script
+                                        let spawned = std::process::Command::new(&bun_path)
+                                            .arg("run")
+                                            .arg("--preload")
+                                            .arg(&preload)
+                                            .arg(&path_str)
+                                            .stdout(std::process::Stdio::piped())
+                                            .stderr(std::process::Stdio::piped())
+                                            .spawn();
+
+                                        // Wait for completion; a clean exit ends the loop
+                                        match spawned {
+                                            Ok(child) => {
+                                                let pid = child.id();
+                                                // Track the process
+                                                PROCESS_MANAGER.register_process(pid, &path_str);
+                                                logging::log("SCHEDULER", &format!("Spawned scheduled script PID {}: {}", pid, path_str));
+
+                                                match child.wait_with_output() {
+                                                    Ok(output) => {
+                                                        // Unregister the process now that it's done
+                                                        PROCESS_MANAGER.unregister_process(pid);
+
+                                                        if output.status.success() {
+                                                            logging::log("SCHEDULER", &format!("Scheduled script completed: {}", path_str));
+                                                            return;
+                                                        }
                                                         let stderr = String::from_utf8_lossy(&output.stderr);
-                                                        logging::log("SCHEDULER", &format!("Scheduled script failed: {} - {}", path_for_log, stderr));
+                                                        logging::log("SCHEDULER", &format!("Scheduled script failed: {} - {}", path_str, stderr));
+                                                    }
+                                                    Err(e) => {
+                                                        // Unregister on error too
+                                                        PROCESS_MANAGER.unregister_process(pid);
+                                                        logging::log("SCHEDULER", &format!("Scheduled script error: {} - {}", path_str, e));
                                                     }
                                                 }
-                                                Err(e) => {
-                                                    // Unregister on error too
-                                                    PROCESS_MANAGER.unregister_process(pid);
-                                                    logging::log("SCHEDULER", &format!("Scheduled script error: {} - {}", path_for_log, e));
+                                            }
+                                            Err(e) => {
+                                                logging::log("SCHEDULER", &format!("Failed to spawn scheduled script: {} - {}", path_str, e));
+                                            }
+                                        }
+
+                                        // Failure: retry with backoff while the budget lasts.
+                                        // The scheduler thread has no toast channel, so an
+                                        // exhausted budget is surfaced via the failure
+                                        // registry shown in the Background Tasks view.
+                                        match background_tasks::note_retry(&path, retry_limit) {
+                                            Some(attempt) => {
+                                                let delay = background_tasks::retry_delay_ms(attempt);
+                                                logging::log("SCHEDULER", &format!("Retrying scheduled script in {}ms (attempt {}/{}): {}", delay, attempt, retry_limit, path_str));
+                                                std::thread::sleep(std::time::Duration::from_millis(delay));
+                                            }
+                                            None => {
+                                                if retry_limit > 0 {
+                                                    logging::log("SCHEDULER", &format!("Retry budget exhausted after {} attempts: {}", retry_limit, path_str));
+                                                    background_tasks::note_failure(&script_name, &path, retry_limit);
                                                 }
+                                                return;
                                             }
-                                        });
-                                    }
-                                    Err(e) => {
-                                        logging::log("SCHEDULER", &format!("Failed to spawn scheduled script: {} - {}", path_str, e));
+                                        }
                                     }
-                                }
+                                });
                             }
                             scheduler::SchedulerEvent::Error(msg) => {
                                 logging::log("SCHEDULER", &format!("Scheduler error: {}", msg));
@@ -1717,6 +2459,58 @@ fn main() {
             }
         }).detach();
 
+        // `sk` CLI listener - serves the companion binary over a unix socket
+        let cli_rx = cli_server::start_cli_server();
+        let app_entity_for_cli = app_entity.clone();
+        cx.spawn(async move |cx: &mut gpui::AsyncApp| {
+            logging::log("CLI", "sk CLI listener started");
+            while let Ok(req) = cli_rx.recv().await {
+                let cli_server::CliRequest { command, reply } = req;
+                let app_entity_inner = app_entity_for_cli.clone();
+                match command {
+                    cli_server::CliCommand::List => {
+                        let _ = cx.update(|cx| {
+                            app_entity_inner.update(cx, |view, _ctx| {
+                                let names: Vec<String> =
+                                    view.scripts.iter().map(|s| s.name.clone()).collect();
+                                let _ = reply.send(names.join("\n"));
+                            });
+                        });
+                    }
+                    cli_server::CliCommand::Run { name } => {
+                        logging::log("CLI", &format!("sk run: '{}'", name));
+                        let _ = cx.update(|cx| {
+                            app_entity_inner.update(cx, |view, ctx| {
+                                // Same matching as the test command watcher: exact name
+                                // or path fragment
+                                if let Some(script) = view
+                                    .scripts
+                                    .iter()
+                                    .find(|s| {
+                                        s.name == name
+                                            || s.path.to_string_lossy().contains(&name)
+                                    })
+                                    .cloned()
+                                {
+                                    let _ = reply.send(format!("running: {}", script.name));
+                                    view.execute_interactive(&script, ctx);
+                                } else {
+                                    let _ =
+                                        reply.send(format!("error: script not found: {}", name));
+                                }
+                            });
+                        });
+                    }
+                    cli_server::CliCommand::Toggle => {
+                        // Reuse the global hotkey path so show/hide behavior stays identical
+                        let _ = hotkeys::hotkey_channel().0.send(()).await;
+                        let _ = reply.send("ok".to_string());
+                    }
+                }
+            }
+            logging::log("CLI", "sk CLI listener exiting (channel closed)");
+        }).detach();
+
         // External command listener - receives commands via stdin (event-driven, no polling)
         let stdin_rx = start_stdin_listener();
         let window_for_stdin = window;
@@ -1808,6 +2602,9 @@ fn main() {
                                 window.activate_window();
                                 let focus_handle = view.focus_handle(ctx);
                                 window.focus(&focus_handle, ctx);
+
+                                // Surface notifications queued during quiet hours
+                                view.flush_quiet_digest(ctx);
                             }
                             ExternalCommand::Hide { ref request_id } => {
                                 let rid = request_id.as_deref().unwrap_or("-");
@@ -1989,6 +2786,7 @@ fn main() {
                                                         view.arg_list_scroll_handle.scroll_to_item(view.arg_selected_index, ScrollStrategy::Nearest);
                                                         logging::log("STDIN", &format!("SimulateKey: Arg down, index={}", view.arg_selected_index));
                                                     }
+                                                    view.maybe_request_more_choices();
                                                 }
                                                 "enter" => {
                                                     logging::log("STDIN", "SimulateKey: Enter - submit selection");
@@ -2179,10 +2977,19 @@ fn main() {
             cx.spawn(async move |cx: &mut gpui::AsyncApp| {
                 logging::log("TRAY", "Tray menu event handler started");
 
+                let mut last_running_count = 0usize;
                 loop {
                     // Poll for tray menu events every 100ms
                     Timer::after(std::time::Duration::from_millis(100)).await;
 
+                    // Reflect the running-script count in the tray tooltip.
+                    // Only touch the tray when the count actually changes.
+                    let running_count = PROCESS_MANAGER.active_count();
+                    if running_count != last_running_count {
+                        last_running_count = running_count;
+                        tray_mgr.set_running_count(running_count);
+                    }
+
                     // Check for menu events
                     if let Ok(event) = tray_mgr.menu_event_receiver().try_recv() {
                         match tray_mgr.match_menu_event(&event) {
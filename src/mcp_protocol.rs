@@ -1037,6 +1037,15 @@ mod tests {
                 icon: None,
                 alias: None,
                 shortcut: None,
+                args: None,
+                hidden: false,
+                sandbox: false,
+                sudo: false,
+                profile: None,
+                env_file: None,
+                cwd: None,
+                nice: None,
+                tags: Vec::new(),
                 typed_metadata: None,
                 schema: Some(schema),
             }
@@ -1219,6 +1228,15 @@ mod tests {
                 icon: None,
                 alias: None,
                 shortcut: None,
+                args: None,
+                hidden: false,
+                sandbox: false,
+                sudo: false,
+                profile: None,
+                env_file: None,
+                cwd: None,
+                nice: None,
+                tags: Vec::new(),
                 typed_metadata: None,
                 schema: None, // No schema!
             };
@@ -1277,6 +1295,15 @@ mod tests {
                 icon: None,
                 alias: None,
                 shortcut: None,
+                args: None,
+                hidden: false,
+                sandbox: false,
+                sudo: false,
+                profile: None,
+                env_file: None,
+                cwd: None,
+                nice: None,
+                tags: Vec::new(),
                 typed_metadata: None,
                 schema: None,
             }
@@ -1295,6 +1322,9 @@ mod tests {
                 file_path: None,
                 command: None,
                 alias: None,
+                cwd: None,
+                env: std::collections::HashMap::new(),
+                confirm: None,
             }
         }
 
@@ -145,6 +145,14 @@ pub fn get_resource_definitions() -> Vec<McpResource> {
             description: Some("List of all available scriptlets from markdown files".to_string()),
             mime_type: "application/json".to_string(),
         },
+        McpResource {
+            uri: "kit://results".to_string(),
+            name: "Run Results".to_string(),
+            description: Some(
+                "Recent typed script results set via the `result` protocol message".to_string(),
+            ),
+            mime_type: "application/json".to_string(),
+        },
     ]
 }
 
@@ -169,6 +177,7 @@ pub fn read_resource(
         "kit://state" => read_state_resource(app_state),
         "scripts://" => read_scripts_resource(scripts),
         "scriptlets://" => read_scriptlets_resource(scriptlets),
+        "kit://results" => read_results_resource(),
         _ => Err(format!("Resource not found: {}", uri)),
     }
 }
@@ -215,6 +224,19 @@ fn read_scriptlets_resource(scriptlets: &[Scriptlet]) -> Result<ResourceContent,
     })
 }
 
+/// Read kit://results resource (persisted run history, newest first)
+fn read_results_resource() -> Result<ResourceContent, String> {
+    let results = crate::run_results::history();
+    let json = serde_json::to_string_pretty(&results)
+        .map_err(|e| format!("Failed to serialize run results: {}", e))?;
+
+    Ok(ResourceContent {
+        uri: "kit://results".to_string(),
+        mime_type: "application/json".to_string(),
+        text: json,
+    })
+}
+
 /// Convert resource content to JSON-RPC result format
 pub fn resource_content_to_value(content: ResourceContent) -> Value {
     serde_json::json!({
@@ -256,6 +278,15 @@ mod tests {
             icon: None,
             alias: None,
             shortcut: None,
+            args: None,
+            hidden: false,
+            sandbox: false,
+            sudo: false,
+            profile: None,
+            env_file: None,
+            cwd: None,
+            nice: None,
+            tags: Vec::new(),
             typed_metadata: None,
             schema: None,
         }
@@ -274,6 +305,9 @@ mod tests {
             file_path: None,
             command: None,
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         }
     }
 
@@ -282,10 +316,14 @@ mod tests {
         // REQUIREMENT: resources/list returns all three resources
         let resources = get_resource_definitions();
 
-        assert_eq!(resources.len(), 3, "Should have exactly 3 resources");
+        assert_eq!(resources.len(), 4, "Should have exactly 4 resources");
 
         let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
         assert!(uris.contains(&"kit://state"), "Should include kit://state");
+        assert!(
+            uris.contains(&"kit://results"),
+            "Should include kit://results"
+        );
         assert!(uris.contains(&"scripts://"), "Should include scripts://");
         assert!(
             uris.contains(&"scriptlets://"),
@@ -504,6 +542,15 @@ mod tests {
             icon: None,
             alias: None,
             shortcut: None,
+            args: None,
+            hidden: false,
+            sandbox: false,
+            sudo: false,
+            profile: None,
+            env_file: None,
+            cwd: None,
+            nice: None,
+            tags: Vec::new(),
             typed_metadata: None,
             schema: Some(Schema {
                 input,
@@ -528,6 +575,9 @@ mod tests {
             file_path: None,
             command: None,
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         };
 
         let entry: ScriptletResourceEntry = (&scriptlet).into();
@@ -212,6 +212,15 @@ mod tests {
             icon: None,
             alias: None,
             shortcut: None,
+            args: None,
+            hidden: false,
+            sandbox: false,
+            sudo: false,
+            profile: None,
+            env_file: None,
+            cwd: None,
+            nice: None,
+            tags: Vec::new(),
             typed_metadata: None,
             schema: Some(schema),
         }
@@ -245,6 +254,15 @@ mod tests {
             icon: None,
             alias: None,
             shortcut: None,
+            args: None,
+            hidden: false,
+            sandbox: false,
+            sudo: false,
+            profile: None,
+            env_file: None,
+            cwd: None,
+            nice: None,
+            tags: Vec::new(),
             typed_metadata: None,
             schema: None,
         }
@@ -2,20 +2,26 @@
 //!
 //! Provides an HTTP server for MCP (Model Context Protocol) integration.
 //! Features:
-//! - HTTP server on localhost:43210
+//! - HTTP server on localhost:43210 (opt-in via `"mcpServer": true` in config)
 //! - Bearer token authentication from ~/.sk/kit/agent-token
 //! - Health endpoint at GET /health
 //! - Discovery file at ~/.sk/kit/server.json
+//! - Per-script allow-listing: only scripts tagged `// MCP: true` are
+//!   advertised as tools
+//! - Execution audit log at ~/.sk/kit/logs/mcp-audit.jsonl
 
 // Allow dead code - ServerHandle methods provide full lifecycle API for future use
 #![allow(dead_code)]
 
-use crate::mcp_protocol::{self, JsonRpcResponse};
+use crate::mcp_protocol::{self, JsonRpcRequest, JsonRpcResponse};
+use crate::mcp_script_tools;
+use crate::scripts::{self, Script};
 use anyhow::{Context, Result};
+use serde_json::{json, Value};
 use std::fs;
 use std::io::{BufRead, BufReader, Read, Write};
 use std::net::{TcpListener, TcpStream};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread;
@@ -204,8 +210,9 @@ impl McpServer {
                     Ok((stream, addr)) => {
                         debug!("Connection from {}", addr);
                         let token = token.clone();
+                        let kit_path = kit_path.clone();
                         thread::spawn(move || {
-                            if let Err(e) = handle_connection(stream, &token) {
+                            if let Err(e) = handle_connection(stream, &token, &kit_path) {
                                 error!("Error handling connection: {}", e);
                             }
                         });
@@ -271,7 +278,7 @@ impl Drop for ServerHandle {
 }
 
 /// Handle a single HTTP connection
-fn handle_connection(mut stream: TcpStream, expected_token: &str) -> Result<()> {
+fn handle_connection(mut stream: TcpStream, expected_token: &str, kit_path: &Path) -> Result<()> {
     let mut reader = BufReader::new(stream.try_clone()?);
 
     // Read request line
@@ -333,7 +340,7 @@ fn handle_connection(mut stream: TcpStream, expected_token: &str) -> Result<()>
         }
         ("POST", "/rpc") => {
             // Handle JSON-RPC request
-            handle_rpc_request(&mut reader, &mut stream, &headers)
+            handle_rpc_request(&mut reader, &mut stream, &headers, kit_path)
         }
         _ => send_response(&mut stream, 404, "Not Found", "Endpoint not found"),
     }
@@ -344,6 +351,7 @@ fn handle_rpc_request(
     reader: &mut BufReader<TcpStream>,
     stream: &mut TcpStream,
     headers: &std::collections::HashMap<String, String>,
+    kit_path: &Path,
 ) -> Result<()> {
     // Get Content-Length
     let content_length: usize = headers
@@ -369,14 +377,26 @@ fn handle_rpc_request(
     debug!("RPC request body: {}", body_str);
 
     // Load scripts and scriptlets for context-aware responses
-    // This allows resources/read and tools/list to return actual data
-    let scripts = crate::scripts::read_scripts();
-    let scriptlets = crate::scripts::load_scriptlets();
+    // This allows resources/read and tools/list to return actual data.
+    // Per-script allow-listing: only scripts tagged `// MCP: true` are
+    // visible to agents - everything else stays hidden.
+    let scripts: Vec<Script> = scripts::read_scripts()
+        .into_iter()
+        .filter(scripts::is_mcp_exposed)
+        .collect();
+    let scriptlets = scripts::load_scriptlets();
 
     // Parse and handle request with full context
     let response = match mcp_protocol::parse_request(&body_str) {
         Ok(request) => {
-            mcp_protocol::handle_request_with_context(request, &scripts, &scriptlets, None)
+            // scripts/* tool calls are executed here (with audit logging);
+            // everything else goes through the stateless protocol handler
+            match try_execute_script_tool(&request, &scripts, kit_path) {
+                Some(response) => response,
+                None => {
+                    mcp_protocol::handle_request_with_context(request, &scripts, &scriptlets, None)
+                }
+            }
         }
         Err(error_response) => error_response,
     };
@@ -385,6 +405,143 @@ fn handle_rpc_request(
     send_response(stream, 200, "OK", &response_body)
 }
 
+/// Path to the tool-call audit log under the kit directory
+fn audit_log_path(kit_path: &Path) -> PathBuf {
+    kit_path.join("logs/mcp-audit.jsonl")
+}
+
+/// Append one JSONL entry to the audit log. Failures are logged, not fatal.
+fn append_audit(kit_path: &Path, entry: &Value) {
+    let path = audit_log_path(kit_path);
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let result = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", entry));
+    if let Err(e) = result {
+        warn!("Failed to write MCP audit log: {}", e);
+    }
+}
+
+/// Execute a scripts/* tool call for real, recording it in the audit log.
+///
+/// Returns `None` when the request is not a script tool call so it falls
+/// through to the stateless protocol handler.
+fn try_execute_script_tool(
+    request: &JsonRpcRequest,
+    scripts: &[Script],
+    kit_path: &Path,
+) -> Option<JsonRpcResponse> {
+    if request.method != "tools/call" {
+        return None;
+    }
+    let params = request.params.as_object()?;
+    let tool_name = params.get("name")?.as_str()?;
+    if !mcp_script_tools::is_script_tool(tool_name) {
+        return None;
+    }
+    let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+
+    let Some(script) = mcp_script_tools::find_script_by_tool_name(scripts, tool_name) else {
+        return Some(JsonRpcResponse::error(
+            request.id.clone(),
+            mcp_protocol::error_codes::METHOD_NOT_FOUND,
+            format!("Tool not found: {}", tool_name),
+        ));
+    };
+
+    info!("MCP tool call: {} -> {:?}", tool_name, script.path);
+    let started = std::time::Instant::now();
+    let result = run_script_tool(script, &arguments);
+    let success = matches!(result, Ok((_, true)));
+
+    append_audit(
+        kit_path,
+        &json!({
+            "ts": chrono::Utc::now().to_rfc3339(),
+            "tool": tool_name,
+            "script": script.path.to_string_lossy(),
+            "arguments": arguments,
+            "success": success,
+            "duration_ms": started.elapsed().as_millis() as u64,
+        }),
+    );
+
+    let (text, ok) = match result {
+        Ok(pair) => pair,
+        Err(e) => (e, false),
+    };
+    Some(JsonRpcResponse::success(
+        request.id.clone(),
+        json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": !ok
+        }),
+    ))
+}
+
+/// Run a script non-interactively with bun, capturing its output.
+///
+/// Mirrors the scheduler's spawn path (bun with the SDK preloaded). The tool
+/// arguments object is forwarded as a single JSON argv entry; stdin is closed
+/// so interactive prompts fail fast instead of hanging the agent.
+fn run_script_tool(
+    script: &Script,
+    arguments: &Value,
+) -> std::result::Result<(String, bool), String> {
+    let bun_path = std::env::var("BUN_PATH")
+        .ok()
+        .or_else(|| {
+            let home_bun = std::env::var("HOME")
+                .map(|h| format!("{}/.bun/bin/bun", h))
+                .unwrap_or_default();
+            for candidate in &[
+                "/opt/homebrew/bin/bun",
+                "/usr/local/bin/bun",
+                home_bun.as_str(),
+            ] {
+                if Path::new(candidate).exists() {
+                    return Some(candidate.to_string());
+                }
+            }
+            None
+        })
+        .unwrap_or_else(|| "bun".to_string());
+
+    let sdk_path = format!(
+        "{}/.sk/kit/sdk/kit-sdk.ts",
+        std::env::var("HOME").unwrap_or_default()
+    );
+
+    let output = std::process::Command::new(&bun_path)
+        .arg("run")
+        .arg("--preload")
+        .arg(&sdk_path)
+        .arg(&script.path)
+        .arg(arguments.to_string())
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to spawn {}: {}", bun_path, e))?;
+
+    let success = output.status.success();
+    let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+    if !success {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !stderr.trim().is_empty() {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(stderr.trim_end());
+        }
+    }
+    Ok((text, success))
+}
+
 /// Send an HTTP response
 fn send_response(stream: &mut TcpStream, status: u16, reason: &str, body: &str) -> Result<()> {
     let response = format!(
@@ -766,4 +923,53 @@ mod tests {
         assert_eq!(response["id"], 2);
         assert!(response["result"]["resources"].is_array());
     }
+
+    #[test]
+    fn test_audit_log_appends_jsonl() {
+        let temp_dir = TempDir::new().unwrap();
+
+        append_audit(
+            temp_dir.path(),
+            &json!({ "tool": "scripts/one", "success": true }),
+        );
+        append_audit(
+            temp_dir.path(),
+            &json!({ "tool": "scripts/two", "success": false }),
+        );
+
+        let content = fs::read_to_string(audit_log_path(temp_dir.path())).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["tool"], "scripts/one");
+        assert_eq!(first["success"], true);
+    }
+
+    #[test]
+    fn test_try_execute_ignores_non_script_tools() {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: "tools/call".to_string(),
+            params: json!({ "name": "kit/show" }),
+        };
+        let temp_dir = TempDir::new().unwrap();
+        assert!(try_execute_script_tool(&request, &[], temp_dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_try_execute_unknown_script_tool_errors() {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(7),
+            method: "tools/call".to_string(),
+            params: json!({ "name": "scripts/does-not-exist" }),
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let response = try_execute_script_tool(&request, &[], temp_dir.path())
+            .expect("scripts/* call should be handled");
+        let value = serde_json::to_value(&response).unwrap();
+        assert_eq!(value["error"]["code"], -32601);
+    }
 }
@@ -56,6 +56,18 @@ pub struct TypedMetadata {
     /// Background script (runs without UI)
     #[serde(default)]
     pub background: bool,
+    /// Restart the script automatically if it crashes (background scripts only)
+    #[serde(default)]
+    pub restart: bool,
+    /// Run under the restricted sandbox profile (no network, minimal env)
+    #[serde(default)]
+    pub sandbox: bool,
+    /// Run with elevated privileges via the system authorization prompt
+    #[serde(default)]
+    pub sudo: bool,
+    /// Named environment profile from config.json, resolved at spawn time
+    #[serde(default)]
+    pub profile: Option<String>,
     /// System-level script (higher privileges)
     #[serde(default)]
     pub system: bool,
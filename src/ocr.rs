@@ -4,6 +4,7 @@
 //!
 //! ## Features
 //! - Extract text from RGBA image data
+//! - Clipboard image and PNG file convenience wrappers
 //! - Async wrapper for background thread execution
 //! - Automatic Vision framework initialization
 //! - Graceful error handling
@@ -120,6 +121,34 @@ pub fn extract_text_from_rgba(_width: u32, _height: u32, _rgba_data: &[u8]) -> R
     Err(anyhow!("OCR is only supported on macOS"))
 }
 
+/// Extract text from the current clipboard image
+///
+/// Reads the image off the system clipboard (arboard returns raw RGBA)
+/// and runs it through the Vision framework.
+///
+/// # Returns
+/// * `Ok(String)` - Extracted text, may be empty if no text was found
+/// * `Err` - If the clipboard holds no image or OCR fails
+pub fn extract_text_from_clipboard() -> Result<String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| anyhow!("Failed to access clipboard: {}", e))?;
+    let img = clipboard
+        .get_image()
+        .map_err(|e| anyhow!("No image on clipboard: {}", e))?;
+    extract_text_from_rgba(img.width as u32, img.height as u32, &img.bytes)
+}
+
+/// Extract text from a PNG file on disk (e.g. a captured screenshot)
+pub fn extract_text_from_png_file(path: &std::path::Path) -> Result<String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let img = image::load_from_memory_with_format(&bytes, image::ImageFormat::Png)
+        .map_err(|e| anyhow!("Failed to decode PNG {}: {}", path.display(), e))?;
+    let rgba = img.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    extract_text_from_rgba(width, height, rgba.as_raw())
+}
+
 /// Extract text asynchronously on a background thread
 ///
 /// This function spawns a background thread to perform OCR, avoiding blocking
@@ -406,6 +435,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_text_from_png_file_missing() {
+        let result =
+            extract_text_from_png_file(std::path::Path::new("/nonexistent/screenshot.png"));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Failed to read"));
+    }
+
     #[test]
     fn test_async_extraction_calls_callback() {
         use std::sync::atomic::{AtomicBool, Ordering};
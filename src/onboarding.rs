@@ -0,0 +1,158 @@
+//! First-run onboarding tour for GPUI Script Kit
+//!
+//! On a fresh install (no pre-existing ~/.sk/kit), the main window shows a
+//! dismissible step-by-step tour instead of dumping the user into an empty
+//! script list. Each step points at one piece of setup: the global hotkey,
+//! theme selection, built-in features, creating a first script, and the docs.
+//!
+//! Completion is tracked with a marker file in the kit directory so the tour
+//! only ever shows once, even across restarts during the first session.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+/// File name of the completion marker inside the kit directory
+const MARKER_FILE: &str = ".onboarding-complete";
+
+/// A single step in the onboarding tour, in presentation order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    /// Set the global hotkey (opens config.ts in the editor)
+    Hotkey,
+    /// Pick a theme (opens the Design Gallery)
+    Theme,
+    /// Review built-in features (opens config.ts in the editor)
+    Builtins,
+    /// Create a first script from a template
+    FirstScript,
+    /// Link to the documentation
+    Docs,
+}
+
+impl OnboardingStep {
+    /// All steps in presentation order
+    pub const ALL: [OnboardingStep; 5] = [
+        OnboardingStep::Hotkey,
+        OnboardingStep::Theme,
+        OnboardingStep::Builtins,
+        OnboardingStep::FirstScript,
+        OnboardingStep::Docs,
+    ];
+
+    /// The first step of the tour
+    pub fn first() -> Self {
+        Self::ALL[0]
+    }
+
+    /// The step after this one, or None when the tour is finished
+    pub fn next(self) -> Option<Self> {
+        let idx = Self::ALL.iter().position(|s| *s == self)?;
+        Self::ALL.get(idx + 1).copied()
+    }
+
+    /// 1-based position of this step, for "Step N of M" display
+    pub fn position(self) -> usize {
+        Self::ALL.iter().position(|s| *s == self).unwrap_or(0) + 1
+    }
+
+    /// Total number of steps, for "Step N of M" display
+    pub fn total() -> usize {
+        Self::ALL.len()
+    }
+
+    /// Short banner message for this step, including the action hint
+    pub fn message(self) -> String {
+        let text = match self {
+            OnboardingStep::Hotkey => {
+                "Set your global hotkey — click to open config.ts in your editor"
+            }
+            OnboardingStep::Theme => "Pick a theme — click to browse the Design Gallery",
+            OnboardingStep::Builtins => {
+                "Toggle built-ins (clipboard history, app launcher) — click to open config.ts"
+            }
+            OnboardingStep::FirstScript => {
+                "Create your first script — click to open a template in your editor"
+            }
+            OnboardingStep::Docs => "Learn more — click to open the Script Kit docs",
+        };
+        format!(
+            "Welcome! Step {} of {}: {}",
+            self.position(),
+            Self::total(),
+            text
+        )
+    }
+}
+
+/// Path to the completion marker file (~/.sk/kit/.onboarding-complete)
+fn marker_path() -> PathBuf {
+    crate::setup::get_kit_path().join(MARKER_FILE)
+}
+
+/// Whether the tour has already been completed or dismissed
+pub fn is_complete() -> bool {
+    marker_path().exists()
+}
+
+/// Record that the tour was completed or dismissed so it never shows again
+pub fn mark_complete() {
+    let path = marker_path();
+    if let Err(e) = std::fs::write(&path, "") {
+        crate::logging::log(
+            "ONBOARDING",
+            &format!("Failed to write marker {}: {}", path.display(), e),
+        );
+    }
+}
+
+/// Whether the tour should be shown on this launch
+///
+/// Shown only on a fresh install, and only until the user finishes or
+/// dismisses it (the marker file persists across restarts).
+pub fn should_show(is_fresh_install: bool) -> bool {
+    is_fresh_install && !is_complete()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_are_ordered_and_terminate() {
+        let mut step = OnboardingStep::first();
+        let mut visited = vec![step];
+        while let Some(next) = step.next() {
+            visited.push(next);
+            step = next;
+        }
+        assert_eq!(visited.len(), OnboardingStep::total());
+        assert_eq!(step, OnboardingStep::Docs);
+        assert_eq!(step.next(), None);
+    }
+
+    #[test]
+    fn test_positions_are_one_based_and_sequential() {
+        for (i, step) in OnboardingStep::ALL.iter().enumerate() {
+            assert_eq!(step.position(), i + 1);
+        }
+    }
+
+    #[test]
+    fn test_messages_include_step_counter() {
+        for step in OnboardingStep::ALL {
+            let msg = step.message();
+            assert!(msg.contains(&format!(
+                "Step {} of {}",
+                step.position(),
+                OnboardingStep::total()
+            )));
+        }
+    }
+
+    #[test]
+    fn test_should_show_requires_fresh_install() {
+        // A non-fresh install never shows the tour regardless of marker state
+        assert!(!should_show(false));
+    }
+}
@@ -11,6 +11,9 @@
 //! - **Accessibility**: Required for keyboard monitoring (text expansion),
 //!   window control, and getting selected text. This is the primary permission
 //!   that most features depend on.
+//! - **Screen Recording**: Required for reading window titles from other
+//!   applications (window switcher) and capturing screenshots. Without it
+//!   these features fail silently with blank data.
 //!
 //! ## Usage
 //!
@@ -52,6 +55,8 @@ use tracing::{debug, info, instrument};
 pub enum PermissionType {
     /// Accessibility permission for keyboard monitoring, window control, selected text
     Accessibility,
+    /// Screen Recording permission for window titles/thumbnails and screenshot capture
+    ScreenRecording,
 }
 
 impl PermissionType {
@@ -59,6 +64,7 @@ impl PermissionType {
     pub fn name(&self) -> &'static str {
         match self {
             PermissionType::Accessibility => "Accessibility",
+            PermissionType::ScreenRecording => "Screen Recording",
         }
     }
 
@@ -67,6 +73,21 @@ impl PermissionType {
     pub fn settings_path(&self) -> &'static str {
         match self {
             PermissionType::Accessibility => "System Settings > Privacy & Security > Accessibility",
+            PermissionType::ScreenRecording => {
+                "System Settings > Privacy & Security > Screen Recording"
+            }
+        }
+    }
+
+    /// Get the deep-link URL that opens the matching System Settings pane
+    pub fn settings_url(&self) -> &'static str {
+        match self {
+            PermissionType::Accessibility => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility"
+            }
+            PermissionType::ScreenRecording => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_ScreenCapture"
+            }
         }
     }
 
@@ -79,6 +100,10 @@ impl PermissionType {
                 "Get selected text from other apps",
                 "Global keyboard shortcuts",
             ],
+            PermissionType::ScreenRecording => &[
+                "Window switcher titles and thumbnails",
+                "Screenshot capture for scripts",
+            ],
         }
     }
 }
@@ -140,6 +165,28 @@ impl PermissionInfo {
                 .collect(),
         }
     }
+
+    /// Create a new PermissionInfo for screen recording permission
+    fn screen_recording(granted: bool) -> Self {
+        Self {
+            permission_type: PermissionType::ScreenRecording,
+            granted,
+            description: "Screen Recording permission allows Script Kit to read window titles \
+                from other applications and capture screenshots for scripts."
+                .to_string(),
+            instructions: "1. Open System Settings\n\
+                 2. Go to Privacy & Security > Screen Recording\n\
+                 3. Click the + button\n\
+                 4. Find and select Script Kit\n\
+                 5. Enable the toggle next to Script Kit"
+                .to_string(),
+            features: PermissionType::ScreenRecording
+                .dependent_features()
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
 }
 
 // ============================================================================
@@ -155,12 +202,14 @@ impl PermissionInfo {
 pub struct PermissionStatus {
     /// Accessibility permission status
     pub accessibility: PermissionInfo,
+    /// Screen recording permission status
+    pub screen_recording: PermissionInfo,
 }
 
 impl PermissionStatus {
     /// Check if all required permissions are granted
     pub fn all_granted(&self) -> bool {
-        self.accessibility.granted
+        self.accessibility.granted && self.screen_recording.granted
     }
 
     /// Get a list of all permissions that are missing
@@ -169,29 +218,28 @@ impl PermissionStatus {
         if !self.accessibility.granted {
             missing.push(&self.accessibility);
         }
+        if !self.screen_recording.granted {
+            missing.push(&self.screen_recording);
+        }
         missing
     }
 
     /// Get the count of granted permissions
     #[allow(dead_code)]
     pub fn granted_count(&self) -> usize {
-        if self.accessibility.granted {
-            1
-        } else {
-            0
-        }
+        self.all_permissions().iter().filter(|p| p.granted).count()
     }
 
     /// Get the total count of required permissions
     #[allow(dead_code)]
     pub fn total_count(&self) -> usize {
-        1 // Currently only accessibility
+        2 // Accessibility + Screen Recording
     }
 
     /// Get all permission infos as a vector
     #[allow(dead_code)]
     pub fn all_permissions(&self) -> Vec<&PermissionInfo> {
-        vec![&self.accessibility]
+        vec![&self.accessibility, &self.screen_recording]
     }
 }
 
@@ -217,14 +265,17 @@ impl PermissionStatus {
 #[instrument]
 pub fn check_all_permissions() -> PermissionStatus {
     let accessibility_granted = check_accessibility_permission();
+    let screen_recording_granted = check_screen_recording_permission();
 
     let status = PermissionStatus {
         accessibility: PermissionInfo::accessibility(accessibility_granted),
+        screen_recording: PermissionInfo::screen_recording(screen_recording_granted),
     };
 
     info!(
         all_granted = status.all_granted(),
         accessibility = accessibility_granted,
+        screen_recording = screen_recording_granted,
         "Checked all permissions"
     );
 
@@ -273,6 +324,50 @@ pub fn request_accessibility_permission() -> bool {
     granted
 }
 
+/// Check if screen recording permission is granted
+///
+/// Uses `CGPreflightScreenCaptureAccess` which reports the current grant
+/// without prompting the user. Without this permission, window titles from
+/// other apps are blank and screenshot capture returns empty frames -
+/// features fail silently rather than erroring.
+///
+/// # Returns
+///
+/// `true` if screen recording permission is granted, `false` otherwise.
+#[instrument]
+pub fn check_screen_recording_permission() -> bool {
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGPreflightScreenCaptureAccess() -> bool;
+    }
+
+    let granted = unsafe { CGPreflightScreenCaptureAccess() };
+    debug!(granted, "Checked screen recording permission");
+    granted
+}
+
+/// Request screen recording permission from the user
+///
+/// This triggers the macOS system prompt asking the user to grant screen
+/// recording permission. Returns `true` immediately if already granted.
+///
+/// # Returns
+///
+/// `true` if permission is granted, `false` if denied or pending. macOS
+/// requires an app restart after granting for the change to take effect.
+#[instrument]
+pub fn request_screen_recording_permission() -> bool {
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGRequestScreenCaptureAccess() -> bool;
+    }
+
+    info!("Requesting screen recording permission");
+    let granted = unsafe { CGRequestScreenCaptureAccess() };
+    info!(granted, "Screen recording permission request completed");
+    granted
+}
+
 /// Open System Settings to the accessibility privacy pane
 ///
 /// This opens the Privacy & Security > Accessibility section of
@@ -286,7 +381,22 @@ pub fn open_accessibility_settings() -> std::io::Result<()> {
 
     // Use the macOS URL scheme to open the specific settings pane
     std::process::Command::new("open")
-        .arg("x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility")
+        .arg(PermissionType::Accessibility.settings_url())
+        .spawn()?;
+
+    Ok(())
+}
+
+/// Open System Settings to the screen recording privacy pane
+///
+/// # Errors
+///
+/// Returns an error if the system settings URL could not be opened.
+pub fn open_screen_recording_settings() -> std::io::Result<()> {
+    info!("Opening screen recording settings");
+
+    std::process::Command::new("open")
+        .arg(PermissionType::ScreenRecording.settings_url())
         .spawn()?;
 
     Ok(())
@@ -337,36 +447,75 @@ mod tests {
         assert!(!info.features.is_empty());
     }
 
+    #[test]
+    fn test_permission_info_screen_recording() {
+        let info = PermissionInfo::screen_recording(false);
+        assert_eq!(info.permission_type, PermissionType::ScreenRecording);
+        assert!(!info.granted);
+        assert!(!info.description.is_empty());
+        assert!(!info.instructions.is_empty());
+        assert!(!info.features.is_empty());
+    }
+
+    #[test]
+    fn test_permission_type_settings_url() {
+        assert!(PermissionType::Accessibility
+            .settings_url()
+            .contains("Privacy_Accessibility"));
+        assert!(PermissionType::ScreenRecording
+            .settings_url()
+            .contains("Privacy_ScreenCapture"));
+    }
+
     #[test]
     fn test_permission_status_all_granted_true() {
         let status = PermissionStatus {
             accessibility: PermissionInfo::accessibility(true),
+            screen_recording: PermissionInfo::screen_recording(true),
         };
         assert!(status.all_granted());
         assert!(status.missing_permissions().is_empty());
-        assert_eq!(status.granted_count(), 1);
-        assert_eq!(status.total_count(), 1);
+        assert_eq!(status.granted_count(), 2);
+        assert_eq!(status.total_count(), 2);
     }
 
     #[test]
     fn test_permission_status_all_granted_false() {
         let status = PermissionStatus {
             accessibility: PermissionInfo::accessibility(false),
+            screen_recording: PermissionInfo::screen_recording(false),
         };
         assert!(!status.all_granted());
-        assert_eq!(status.missing_permissions().len(), 1);
+        assert_eq!(status.missing_permissions().len(), 2);
         assert_eq!(status.granted_count(), 0);
-        assert_eq!(status.total_count(), 1);
+        assert_eq!(status.total_count(), 2);
+    }
+
+    #[test]
+    fn test_permission_status_partially_granted() {
+        let status = PermissionStatus {
+            accessibility: PermissionInfo::accessibility(true),
+            screen_recording: PermissionInfo::screen_recording(false),
+        };
+        assert!(!status.all_granted());
+        assert_eq!(status.missing_permissions().len(), 1);
+        assert_eq!(
+            status.missing_permissions()[0].permission_type,
+            PermissionType::ScreenRecording
+        );
+        assert_eq!(status.granted_count(), 1);
     }
 
     #[test]
     fn test_permission_status_all_permissions() {
         let status = PermissionStatus {
             accessibility: PermissionInfo::accessibility(true),
+            screen_recording: PermissionInfo::screen_recording(true),
         };
         let all = status.all_permissions();
-        assert_eq!(all.len(), 1);
+        assert_eq!(all.len(), 2);
         assert_eq!(all[0].permission_type, PermissionType::Accessibility);
+        assert_eq!(all[1].permission_type, PermissionType::ScreenRecording);
     }
 
     #[test]
@@ -0,0 +1,218 @@
+//! Persisted pinned items for the main list
+//!
+//! Pinned items appear in a PINNED section above RECENT, in a user-chosen
+//! order, regardless of frecency. Items are identified by the same keys the
+//! frecency store uses (script path, `builtin:Name`, `scriptlet:Name`, app
+//! path), and the ordered list is stored in `~/.sk/kit/pins.json` alongside
+//! `frecency.json`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// Store for pinned items with persistence
+#[derive(Debug, Clone)]
+pub struct PinStore {
+    /// Pinned item keys, in display order
+    pins: Vec<String>,
+    /// Path to the pins file
+    file_path: PathBuf,
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Serialize, Deserialize)]
+struct PinData {
+    pins: Vec<String>,
+}
+
+impl PinStore {
+    /// Create a new PinStore with the default path (~/.sk/kit/pins.json)
+    pub fn new() -> Self {
+        PinStore {
+            pins: Vec::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a PinStore with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        PinStore {
+            pins: Vec::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    /// Get the default pins file path
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.sk/kit/pins.json").as_ref())
+    }
+
+    /// Load pins from disk
+    ///
+    /// Starts with no pins if the file doesn't exist.
+    #[instrument(name = "pins_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            debug!(path = %self.file_path.display(), "Pins file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path)
+            .with_context(|| format!("Failed to read pins file: {}", self.file_path.display()))?;
+
+        let data: PinData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse pins JSON")?;
+
+        self.pins = data.pins;
+        self.dirty = false;
+
+        info!(
+            path = %self.file_path.display(),
+            pin_count = self.pins.len(),
+            "Loaded pins"
+        );
+        Ok(())
+    }
+
+    /// Save pins to disk
+    #[instrument(name = "pins_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let data = PinData {
+            pins: self.pins.clone(),
+        };
+
+        let json = serde_json::to_string_pretty(&data).context("Failed to serialize pins")?;
+
+        std::fs::write(&self.file_path, json)
+            .with_context(|| format!("Failed to write pins file: {}", self.file_path.display()))?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Toggle a key's pinned state, returning true if it is now pinned
+    ///
+    /// Newly pinned items go to the end of the pin order.
+    pub fn toggle(&mut self, key: &str) -> bool {
+        let now_pinned = if let Some(pos) = self.pins.iter().position(|p| p == key) {
+            self.pins.remove(pos);
+            false
+        } else {
+            self.pins.push(key.to_string());
+            true
+        };
+        self.dirty = true;
+        now_pinned
+    }
+
+    /// Whether a key is currently pinned
+    pub fn is_pinned(&self, key: &str) -> bool {
+        self.pins.iter().any(|p| p == key)
+    }
+
+    /// Move a pinned key by `delta` positions (negative = toward the top)
+    ///
+    /// Returns true if the pin actually moved.
+    pub fn shift(&mut self, key: &str, delta: isize) -> bool {
+        let Some(pos) = self.pins.iter().position(|p| p == key) else {
+            return false;
+        };
+        let new_pos = pos.saturating_add_signed(delta).min(self.pins.len() - 1);
+        if new_pos == pos {
+            return false;
+        }
+        let pin = self.pins.remove(pos);
+        self.pins.insert(new_pos, pin);
+        self.dirty = true;
+        true
+    }
+
+    /// The pinned keys, in display order
+    pub fn pins(&self) -> &[String] {
+        &self.pins
+    }
+}
+
+impl Default for PinStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("sk-pins-test-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_toggle_pins_and_unpins() {
+        let mut store = PinStore::with_path(temp_path("toggle"));
+        assert!(store.toggle("/a.ts"));
+        assert!(store.is_pinned("/a.ts"));
+        assert!(!store.toggle("/a.ts"));
+        assert!(!store.is_pinned("/a.ts"));
+    }
+
+    #[test]
+    fn test_pin_order_is_preserved() {
+        let mut store = PinStore::with_path(temp_path("order"));
+        store.toggle("/a.ts");
+        store.toggle("/b.ts");
+        store.toggle("/c.ts");
+        assert_eq!(store.pins(), &["/a.ts", "/b.ts", "/c.ts"]);
+    }
+
+    #[test]
+    fn test_shift_reorders_pins() {
+        let mut store = PinStore::with_path(temp_path("shift"));
+        store.toggle("/a.ts");
+        store.toggle("/b.ts");
+        store.toggle("/c.ts");
+
+        assert!(store.shift("/c.ts", -1));
+        assert_eq!(store.pins(), &["/a.ts", "/c.ts", "/b.ts"]);
+
+        // Clamped at the edges
+        assert!(!store.shift("/a.ts", -1));
+        assert!(!store.shift("/b.ts", 1));
+        // Unknown keys are a no-op
+        assert!(!store.shift("/missing.ts", 1));
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        {
+            let mut store = PinStore::with_path(path.clone());
+            store.toggle("/b.ts");
+            store.toggle("/a.ts");
+            store.save().unwrap();
+        }
+        {
+            let mut store = PinStore::with_path(path.clone());
+            store.load().unwrap();
+            assert_eq!(store.pins(), &["/b.ts", "/a.ts"]);
+        }
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -243,6 +243,49 @@ pub fn hide_main_window() {
     // No-op on non-macOS platforms
 }
 
+/// Raise or restore the main window's level for pinned mode.
+///
+/// When `pinned` is true the window is moved to NSStatusWindowLevel so it
+/// floats above other apps' windows even while another app is active. When
+/// false it returns to the normal floating-panel level set by
+/// `configure_as_floating_panel()`.
+///
+/// # Other Platforms
+///
+/// No-op on non-macOS platforms.
+#[cfg(target_os = "macos")]
+pub fn set_main_window_pinned(pinned: bool) {
+    unsafe {
+        let window = match window_manager::get_main_window() {
+            Some(w) => w,
+            None => {
+                logging::log(
+                    "PANEL",
+                    "set_main_window_pinned: Main window not registered, nothing to do",
+                );
+                return;
+            }
+        };
+
+        let level = if pinned {
+            NS_STATUS_WINDOW_LEVEL
+        } else {
+            NS_FLOATING_WINDOW_LEVEL
+        };
+        let _: () = msg_send![window, setLevel:level];
+
+        logging::log(
+            "PANEL",
+            &format!("Main window level set to {} (pinned={})", level, pinned),
+        );
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn set_main_window_pinned(_pinned: bool) {
+    // No-op on non-macOS platforms
+}
+
 // ============================================================================
 // App Active State Detection
 // ============================================================================
@@ -311,6 +354,13 @@ pub fn is_main_window_focused() -> bool {
 #[allow(dead_code)]
 pub const NS_FLOATING_WINDOW_LEVEL: i32 = 3;
 
+/// NSStatusWindowLevel constant value (25)
+/// Windows at this level stay above other apps' windows even when our app
+/// is inactive - used for pinned mode (Cmd+Shift+P).
+#[cfg(target_os = "macos")]
+#[allow(dead_code)]
+pub const NS_STATUS_WINDOW_LEVEL: i32 = 25;
+
 /// NSWindowCollectionBehaviorMoveToActiveSpace constant value (1 << 1 = 2)
 /// When set, the window moves to the currently active space when shown.
 #[cfg(target_os = "macos")]
@@ -955,6 +1005,7 @@ mod tests {
     #[test]
     fn test_macos_constants() {
         assert_eq!(NS_FLOATING_WINDOW_LEVEL, 3);
+        assert_eq!(NS_STATUS_WINDOW_LEVEL, 25);
         assert_eq!(NS_WINDOW_COLLECTION_BEHAVIOR_MOVE_TO_ACTIVE_SPACE, 2);
     }
 
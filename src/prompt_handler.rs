@@ -4,12 +4,35 @@
 impl ScriptListApp {
     /// Handle a prompt message from the script
     fn handle_prompt_message(&mut self, msg: PromptMessage, cx: &mut Context<Self>) {
+        // When a script chains prompts, save the one being replaced so
+        // Escape / Cmd+[ can navigate back to it with its state preserved
+        if matches!(
+            msg,
+            PromptMessage::ShowArg { .. }
+                | PromptMessage::ShowDiv { .. }
+                | PromptMessage::ShowForm { .. }
+                | PromptMessage::ShowTerm { .. }
+                | PromptMessage::ShowEditor { .. }
+                | PromptMessage::ShowNumber { .. }
+                | PromptMessage::ShowDate { .. }
+                | PromptMessage::ShowSelect { .. }
+                | PromptMessage::ShowTable { .. }
+                | PromptMessage::ShowPath { .. }
+                | PromptMessage::ShowEnv { .. }
+                | PromptMessage::ShowDrop { .. }
+                | PromptMessage::ShowTemplate { .. }
+        ) {
+            self.push_prompt_to_stack();
+        }
+
         match msg {
             PromptMessage::ShowArg {
                 id,
                 placeholder,
                 choices,
                 actions,
+                sort,
+                has_more,
             } => {
                 logging::log(
                     "UI",
@@ -52,6 +75,15 @@ impl ScriptListApp {
                 };
                 self.arg_input.clear();
                 self.arg_selected_index = 0;
+                // Scripts controlling their own order (e.g. recent-first
+                // histories) send `sort: false` to skip fuzzy-score sorting
+                self.arg_sort_enabled = sort.unwrap_or(true);
+                // Paginated prompts (`hasMore: true`) get further pages via
+                // moreChoices requests as the selection nears the end
+                self.arg_has_more = has_more.unwrap_or(false);
+                self.arg_more_requested = false;
+                // Drop decoded images from the previous prompt's choices
+                self.arg_choice_image_cache.clear();
                 self.focused_input = FocusedInput::ArgPrompt;
                 // Resize window based on number of choices
                 let view_type = if choice_count == 0 {
@@ -62,6 +94,39 @@ impl ScriptListApp {
                 defer_resize_to_view(view_type, choice_count, cx);
                 cx.notify();
             }
+            PromptMessage::AppendChoices {
+                id,
+                choices: page,
+                has_more,
+            } => {
+                if let AppView::ArgPrompt {
+                    id: current_id,
+                    choices,
+                    ..
+                } = &mut self.current_view
+                {
+                    if *current_id == id {
+                        logging::log(
+                            "UI",
+                            &format!(
+                                "Appending {} choices to arg prompt {} (has_more={})",
+                                page.len(),
+                                id,
+                                has_more
+                            ),
+                        );
+                        choices.extend(page);
+                        self.arg_has_more = has_more;
+                        self.arg_more_requested = false;
+                        cx.notify();
+                    } else {
+                        logging::log(
+                            "UI",
+                            &format!("Ignoring choices page for stale prompt {}", id),
+                        );
+                    }
+                }
+            }
             PromptMessage::ShowDiv {
                 id,
                 html,
@@ -316,6 +381,17 @@ impl ScriptListApp {
             }
             PromptMessage::ScriptExit => {
                 logging::log("VISIBILITY", "=== ScriptExit message received ===");
+
+                // A rich error panel stays up until the user dismisses it -
+                // hiding here would destroy it the instant it appeared
+                if matches!(self.current_view, AppView::ErrorView { .. }) {
+                    logging::log(
+                        "VISIBILITY",
+                        "Error panel showing - skipping hide on script exit",
+                    );
+                    cx.notify();
+                    return;
+                }
                 let was_visible = script_kit_gpui::is_main_window_visible();
                 logging::log(
                     "VISIBILITY",
@@ -428,6 +504,15 @@ impl ScriptListApp {
                     icon: None,
                     alias: None,
                     shortcut: None,
+                    args: None,
+                    hidden: false,
+                    sandbox: false,
+                    sudo: false,
+                    profile: None,
+                    env_file: None,
+                    cwd: None,
+                    nice: None,
+                    tags: Vec::new(),
                     typed_metadata: None,
                     schema: None,
                 };
@@ -435,6 +520,57 @@ impl ScriptListApp {
                 logging::log("EXEC", &format!("Executing script: {}", script_name));
                 self.execute_interactive(&script, cx);
             }
+            PromptMessage::RestartScript { path } => {
+                logging::log("EXEC", &format!("Auto-restart requested: {}", path));
+
+                // Restart the real script entry so its metadata (background,
+                // restart policy) still applies to the new session
+                let script = self
+                    .scripts
+                    .iter()
+                    .find(|s| s.path.to_string_lossy() == path)
+                    .cloned();
+                match script {
+                    Some(script) => {
+                        let message = format!("Restarting {} after crash", script.name);
+                        if quiet_hours::should_suppress(&self.config) {
+                            quiet_hours::queue(&message);
+                        } else {
+                            self.toast_manager.push(
+                                Toast::warning(message, &self.theme).duration_ms(Some(5000)),
+                            );
+                        }
+                        self.execute_interactive(&script, cx);
+                    }
+                    None => {
+                        logging::log(
+                            "EXEC",
+                            &format!("Restart skipped - script no longer listed: {}", path),
+                        );
+                    }
+                }
+            }
+            PromptMessage::RetriesExhausted { path, attempts } => {
+                logging::log(
+                    "EXEC",
+                    &format!("Retries exhausted ({} attempts): {}", attempts, path),
+                );
+                let name = self
+                    .scripts
+                    .iter()
+                    .find(|s| s.path.to_string_lossy() == path)
+                    .map(|s| s.name.clone())
+                    .unwrap_or_else(|| path.clone());
+                let message = format!("{} failed after {} retries", name, attempts);
+                if quiet_hours::should_suppress(&self.config) {
+                    quiet_hours::queue(&message);
+                } else {
+                    // Sticky: the user has to dismiss it, so a failure that
+                    // happens while they are away is still visible later
+                    self.toast_manager
+                        .push(Toast::error(message, &self.theme).duration_ms(None));
+                }
+            }
             PromptMessage::ScriptError {
                 error_message,
                 stderr_output,
@@ -451,6 +587,37 @@ impl ScriptListApp {
                     ),
                 );
 
+                // Prefer the rich error panel when the top stack frame maps
+                // back to a readable user file; fall back to the toast
+                let source_text = stderr_output.clone().or_else(|| stack_trace.clone());
+                if let Some(ref text) = source_text {
+                    if let Some(frame) = executor::parse_top_user_frame(text) {
+                        if let Some(snippet) =
+                            executor::read_source_snippet(&frame.path, frame.line, 3)
+                        {
+                            logging::log(
+                                "UI",
+                                &format!(
+                                    "Showing error panel for {}:{}",
+                                    frame.path, frame.line
+                                ),
+                            );
+                            self.current_view = AppView::ErrorView {
+                                error_message,
+                                details: source_text,
+                                script_path,
+                                frame,
+                                snippet,
+                                selected_index: 0,
+                            };
+                            self.focused_input = FocusedInput::None;
+                            defer_resize_to_view(ViewType::ScriptList, 0, cx);
+                            cx.notify();
+                            return;
+                        }
+                    }
+                }
+
                 // Create error toast with expandable details
                 // Use stderr_output if available, otherwise use stack_trace
                 let details_text = stderr_output.clone().or_else(|| stack_trace.clone());
@@ -663,6 +830,16 @@ impl ScriptListApp {
                         -1,
                         None,
                     ),
+                    AppView::TablePrompt { id, .. } => (
+                        "table".to_string(),
+                        Some(id.clone()),
+                        None,
+                        String::new(),
+                        0,
+                        0,
+                        -1,
+                        None,
+                    ),
                     AppView::PathPrompt { id, .. } => (
                         "path".to_string(),
                         Some(id.clone()),
@@ -683,6 +860,26 @@ impl ScriptListApp {
                         -1,
                         None,
                     ),
+                    AppView::NumberPrompt { id, .. } => (
+                        "number".to_string(),
+                        Some(id.clone()),
+                        None,
+                        String::new(),
+                        0,
+                        0,
+                        -1,
+                        None,
+                    ),
+                    AppView::DatePrompt { id, .. } => (
+                        "date".to_string(),
+                        Some(id.clone()),
+                        None,
+                        String::new(),
+                        0,
+                        0,
+                        -1,
+                        None,
+                    ),
                     AppView::DropPrompt { id, .. } => (
                         "drop".to_string(),
                         Some(id.clone()),
@@ -790,6 +987,311 @@ impl ScriptListApp {
                             None,
                         )
                     }
+                    AppView::BackgroundTasksView {
+                        tasks,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            tasks.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            tasks
+                                .iter()
+                                .filter(|t| t.name.to_lowercase().contains(&filter_lower))
+                                .count()
+                        };
+                        (
+                            "backgroundTasks".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            tasks.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::ShortcutsView {
+                        shortcuts,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            shortcuts.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            shortcuts
+                                .iter()
+                                .filter(|name| name.to_lowercase().contains(&filter_lower))
+                                .count()
+                        };
+                        (
+                            "shortcuts".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            shortcuts.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::RecentlyDeletedView {
+                        entries,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            entries.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            entries
+                                .iter()
+                                .filter(|e| e.name.to_lowercase().contains(&filter_lower))
+                                .count()
+                        };
+                        (
+                            "recentlyDeleted".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            entries.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::ThemeBrowserView {
+                        themes,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            themes.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            themes
+                                .iter()
+                                .filter(|t| t.name.to_lowercase().contains(&filter_lower))
+                                .count()
+                        };
+                        (
+                            "themeBrowser".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            themes.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::ExpandStatsView {
+                        entries,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            entries.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            entries
+                                .iter()
+                                .filter(|e| {
+                                    e.trigger.to_lowercase().contains(&filter_lower)
+                                        || e.name.to_lowercase().contains(&filter_lower)
+                                })
+                                .count()
+                        };
+                        (
+                            "expandStats".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            entries.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::GitHubView {
+                        items,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            items.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            items
+                                .iter()
+                                .filter(|i| {
+                                    i.title.to_lowercase().contains(&filter_lower)
+                                        || i.subtitle.as_ref().is_some_and(|s| {
+                                            s.to_lowercase().contains(&filter_lower)
+                                        })
+                                })
+                                .count()
+                        };
+                        (
+                            "github".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            items.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::ConnectView {
+                        targets,
+                        filter,
+                        selected_index,
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            targets.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            targets
+                                .iter()
+                                .filter(|t| t.name.to_lowercase().contains(&filter_lower))
+                                .count()
+                        };
+                        (
+                            "connect".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            targets.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::FocusModesView {
+                        modes,
+                        filter,
+                        selected_index,
+                        ..
+                    } => {
+                        let filtered_count = if filter.is_empty() {
+                            modes.len()
+                        } else {
+                            let filter_lower = filter.to_lowercase();
+                            modes
+                                .iter()
+                                .filter(|m| m.name.to_lowercase().contains(&filter_lower))
+                                .count()
+                        };
+                        (
+                            "focusModes".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            modes.len(),
+                            filtered_count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::WorldClockView {
+                        favorites,
+                        filter,
+                        selected_index,
+                    } => {
+                        let count = world_clock::results(filter, favorites).len();
+                        (
+                            "worldClock".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            count,
+                            count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::FileSearchView {
+                        results,
+                        filter,
+                        selected_index,
+                    } => (
+                        "fileSearch".to_string(),
+                        None,
+                        None,
+                        filter.clone(),
+                        results.len(),
+                        results.len(),
+                        *selected_index as i32,
+                        None,
+                    ),
+                    AppView::BatchRenameView { state } => {
+                        let count = match state.stage {
+                            batch_rename::Stage::PickFiles => state.visible_entries().len(),
+                            batch_rename::Stage::Pattern => state.selected.len(),
+                        };
+                        (
+                            "batchRename".to_string(),
+                            None,
+                            None,
+                            state.filter.clone(),
+                            count,
+                            count,
+                            state.selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::ScreenshotHistoryView {
+                        shots,
+                        filter,
+                        selected_index,
+                    } => {
+                        let count = screenshot_history::filter_screenshots(shots, filter).len();
+                        (
+                            "screenshotHistory".to_string(),
+                            None,
+                            None,
+                            filter.clone(),
+                            count,
+                            count,
+                            *selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::FileMoverView { state } => {
+                        let count = match state.stage {
+                            file_mover::Stage::PickFiles => state.visible_entries().len(),
+                            file_mover::Stage::PickDest => state.dest_rows().len(),
+                            file_mover::Stage::Conflicts | file_mover::Stage::Moving => {
+                                state.selected.len()
+                            }
+                        };
+                        (
+                            "fileMover".to_string(),
+                            None,
+                            None,
+                            state.filter.clone(),
+                            count,
+                            count,
+                            state.selected_index as i32,
+                            None,
+                        )
+                    }
+                    AppView::SecretsVaultView { state } => {
+                        let count = state.visible_keys().len();
+                        (
+                            "secretsVault".to_string(),
+                            None,
+                            None,
+                            state.filter.clone(),
+                            count,
+                            count,
+                            state.selected_index as i32,
+                            None,
+                        )
+                    }
                     AppView::DesignGalleryView {
                         filter,
                         selected_index,
@@ -809,6 +1311,20 @@ impl ScriptListApp {
                             None,
                         )
                     }
+                    AppView::ErrorView {
+                        error_message,
+                        selected_index,
+                        ..
+                    } => (
+                        "scriptError".to_string(),
+                        None,
+                        Some(error_message.clone()),
+                        String::new(),
+                        2,
+                        2,
+                        *selected_index as i32,
+                        None,
+                    ),
                 };
 
                 // Focus state: we use focused_input as a proxy since we don't have Window access here.
@@ -845,6 +1361,30 @@ impl ScriptListApp {
                     logging::log("ERROR", "No response sender available for state result");
                 }
             }
+            PromptMessage::GetAppState { request_id } => {
+                logging::log(
+                    "UI",
+                    &format!("Collecting app state for request: {}", request_id),
+                );
+
+                let response = Message::app_state_result(
+                    request_id.clone(),
+                    env!("CARGO_PKG_VERSION").to_string(),
+                    setup::get_kit_path().to_string_lossy().into_owned(),
+                    self.theme.appearance().to_string(),
+                    self.scripts.len(),
+                    script_kit_gpui::is_main_window_visible(),
+                );
+
+                // Send the response
+                if let Some(ref sender) = self.response_sender {
+                    if let Err(e) = sender.send(response) {
+                        logging::log("ERROR", &format!("Failed to send app state result: {}", e));
+                    }
+                } else {
+                    logging::log("ERROR", "No response sender available for app state result");
+                }
+            }
             PromptMessage::GetLayoutInfo { request_id } => {
                 logging::log(
                     "UI",
@@ -923,6 +1463,7 @@ impl ScriptListApp {
                 id,
                 start_path,
                 hint,
+                options,
             } => {
                 logging::log(
                     "UI",
@@ -988,6 +1529,7 @@ impl ScriptListApp {
                     submit_callback,
                     std::sync::Arc::new(self.theme.clone()),
                 )
+                .with_options(options)
                 .with_show_actions(show_actions_callback)
                 .with_close_actions(close_actions_callback)
                 .with_actions_showing(path_actions_showing)
@@ -1066,6 +1608,107 @@ impl ScriptListApp {
                 defer_resize_to_view(ViewType::ArgPromptNoChoices, 0, cx);
                 cx.notify();
             }
+            PromptMessage::ShowNumber {
+                id,
+                placeholder,
+                min,
+                max,
+                step,
+                value,
+            } => {
+                tracing::info!(id, ?min, ?max, ?step, "ShowNumber received");
+                logging::log(
+                    "UI",
+                    &format!(
+                        "ShowNumber prompt received: {} (min: {:?}, max: {:?}, step: {:?})",
+                        id, min, max, step
+                    ),
+                );
+
+                // Create submit callback for number prompt
+                let response_sender = self.response_sender.clone();
+                let submit_callback: std::sync::Arc<dyn Fn(String, Option<String>) + Send + Sync> =
+                    std::sync::Arc::new(move |id, value| {
+                        if let Some(ref sender) = response_sender {
+                            let response = Message::Submit { id, value };
+                            if let Err(e) = sender.send(response) {
+                                logging::log(
+                                    "UI",
+                                    &format!("Failed to send number response: {}", e),
+                                );
+                            }
+                        }
+                    });
+
+                let focus_handle = self.focus_handle.clone();
+                let number_prompt = prompts::NumberPrompt::new(
+                    id.clone(),
+                    placeholder,
+                    min,
+                    max,
+                    step,
+                    value,
+                    focus_handle,
+                    submit_callback,
+                    std::sync::Arc::new(self.theme.clone()),
+                );
+
+                let entity = cx.new(|_| number_prompt);
+                self.current_view = AppView::NumberPrompt { id, entity };
+                self.focused_input = FocusedInput::None; // NumberPrompt has its own focus handling
+
+                defer_resize_to_view(ViewType::ArgPromptNoChoices, 0, cx);
+                cx.notify();
+            }
+            PromptMessage::ShowDate {
+                id,
+                placeholder,
+                mode,
+                value,
+            } => {
+                tracing::info!(id, ?mode, "ShowDate received");
+                logging::log(
+                    "UI",
+                    &format!("ShowDate prompt received: {} (mode: {:?})", id, mode),
+                );
+
+                // Create submit callback for date prompt
+                let response_sender = self.response_sender.clone();
+                let submit_callback: std::sync::Arc<dyn Fn(String, Option<String>) + Send + Sync> =
+                    std::sync::Arc::new(move |id, value| {
+                        if let Some(ref sender) = response_sender {
+                            let response = Message::Submit { id, value };
+                            if let Err(e) = sender.send(response) {
+                                logging::log("UI", &format!("Failed to send date response: {}", e));
+                            }
+                        }
+                    });
+
+                let date_mode = prompts::DateMode::parse(mode.as_deref().unwrap_or("date"));
+                let focus_handle = self.focus_handle.clone();
+                let date_prompt = prompts::DatePrompt::new(
+                    id.clone(),
+                    placeholder,
+                    date_mode,
+                    value,
+                    focus_handle,
+                    submit_callback,
+                    std::sync::Arc::new(self.theme.clone()),
+                );
+
+                let entity = cx.new(|_| date_prompt);
+                self.current_view = AppView::DatePrompt { id, entity };
+                self.focused_input = FocusedInput::None; // DatePrompt has its own focus handling
+
+                // Date mode shows a calendar grid below the input; time mode is a single row
+                let view_type = if date_mode == prompts::DateMode::Date {
+                    ViewType::ScriptList
+                } else {
+                    ViewType::ArgPromptNoChoices
+                };
+                defer_resize_to_view(view_type, 0, cx);
+                cx.notify();
+            }
             PromptMessage::ShowDrop {
                 id,
                 placeholder,
@@ -1214,6 +1857,72 @@ impl ScriptListApp {
                 defer_resize_to_view(view_type, choice_count, cx);
                 cx.notify();
             }
+            PromptMessage::ShowTable {
+                id,
+                placeholder,
+                columns,
+                rows,
+                multiple,
+            } => {
+                tracing::info!(
+                    id,
+                    ?placeholder,
+                    column_count = columns.len(),
+                    row_count = rows.len(),
+                    multiple,
+                    "ShowTable received"
+                );
+                logging::log(
+                    "UI",
+                    &format!(
+                        "ShowTable prompt received: {} ({} columns, {} rows, multiple: {})",
+                        id,
+                        columns.len(),
+                        rows.len(),
+                        multiple
+                    ),
+                );
+
+                // Create submit callback for table prompt
+                let response_sender = self.response_sender.clone();
+                let submit_callback: std::sync::Arc<dyn Fn(String, Option<String>) + Send + Sync> =
+                    std::sync::Arc::new(move |id, value| {
+                        if let Some(ref sender) = response_sender {
+                            let response = Message::Submit { id, value };
+                            if let Err(e) = sender.send(response) {
+                                logging::log(
+                                    "UI",
+                                    &format!("Failed to send table response: {}", e),
+                                );
+                            }
+                        }
+                    });
+
+                // Create TablePrompt entity
+                let row_count = rows.len();
+                let table_prompt = prompts::TablePrompt::new(
+                    id.clone(),
+                    placeholder,
+                    columns,
+                    rows,
+                    multiple,
+                    self.focus_handle.clone(),
+                    submit_callback,
+                    std::sync::Arc::new(self.theme.clone()),
+                );
+                let entity = cx.new(|_| table_prompt);
+                self.current_view = AppView::TablePrompt { id, entity };
+                self.focused_input = FocusedInput::None; // TablePrompt has its own focus handling
+
+                // Resize window based on number of rows
+                let view_type = if row_count == 0 {
+                    ViewType::ArgPromptNoChoices
+                } else {
+                    ViewType::ArgPromptWithChoices
+                };
+                defer_resize_to_view(view_type, row_count, cx);
+                cx.notify();
+            }
             PromptMessage::ShowHud { text, duration_ms } => {
                 self.show_hud(text, duration_ms, cx);
             }
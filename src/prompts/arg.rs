@@ -90,14 +90,20 @@ impl ArgPrompt {
         }
     }
 
-    /// Refilter choices based on current input_text
+    /// Refilter choices based on current input_text (matches name and description)
     fn refilter(&mut self) {
         let filter_lower = self.input_text.to_lowercase();
         self.filtered_choices = self
             .choices
             .iter()
             .enumerate()
-            .filter(|(_, choice)| choice.name.to_lowercase().contains(&filter_lower))
+            .filter(|(_, choice)| {
+                choice.name.to_lowercase().contains(&filter_lower)
+                    || choice
+                        .description
+                        .as_ref()
+                        .is_some_and(|d| d.to_lowercase().contains(&filter_lower))
+            })
             .map(|(idx, _)| idx)
             .collect();
         self.selected_index = 0; // Reset selection when filtering
@@ -372,12 +378,18 @@ impl Render for ArgPrompt {
                         .gap_1();
 
                     // Choice name (bold-ish via uppercase and text styling)
-                    choice_item = choice_item.child(
-                        div()
-                            .text_color(name_color)
-                            .text_base()
-                            .child(choice.name.clone()),
-                    );
+                    // Prefixed with the emoji icon when the choice provides one
+                    let mut name_row = div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_2()
+                        .text_color(name_color)
+                        .text_base();
+                    if let Some(icon) = &choice.icon {
+                        name_row = name_row.child(div().child(icon.clone()));
+                    }
+                    choice_item = choice_item.child(name_row.child(choice.name.clone()));
 
                     // Choice description if present (dimmed)
                     if let Some(desc) = &choice.description {
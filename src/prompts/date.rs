@@ -0,0 +1,706 @@
+//! DatePrompt - Date/time input prompt with calendar grid
+//!
+//! Features:
+//! - Calendar grid navigation (arrows move by day/week, PageUp/PageDown by month)
+//! - Natural-language parsing: "today", "tomorrow", "next friday", "in 3 days"
+//! - Time mode: "17:30", "5:30pm", "noon", "in 20 minutes"
+//! - Returns ISO strings: "YYYY-MM-DD" for dates, "HH:MM" for times
+//!
+//! Design: Input row matches ArgPrompt; the calendar grid renders below it.
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveTime, Timelike, Weekday};
+use gpui::{
+    div, prelude::*, px, rgb, rgba, svg, Context, Div, FocusHandle, Focusable, Render,
+    SharedString, Window,
+};
+use std::sync::Arc;
+
+use crate::components::{TextInput, TextInputState};
+use crate::designs::{get_tokens, DesignVariant};
+use crate::logging;
+use crate::panel::{HEADER_GAP, HEADER_PADDING_X, HEADER_PADDING_Y};
+use crate::theme;
+
+use super::SubmitCallback;
+
+/// Whether the prompt selects a date or a time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateMode {
+    Date,
+    Time,
+}
+
+impl DateMode {
+    pub fn parse(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "time" => DateMode::Time,
+            _ => DateMode::Date,
+        }
+    }
+}
+
+/// Parse a weekday name ("mon", "monday", ...) into a chrono Weekday
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    match s {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parse natural-language date input relative to `today`.
+///
+/// Supported: ISO dates ("2026-09-01"), US dates ("9/1" or "9/1/2026"),
+/// "today"/"tomorrow"/"yesterday", bare weekday names (upcoming occurrence,
+/// today counts), "next <weekday>" (strictly after today), "next week",
+/// "in N days"/"in N weeks".
+pub fn parse_natural_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = input.trim().to_lowercase();
+    if text.is_empty() {
+        return None;
+    }
+
+    match text.as_str() {
+        "today" => return Some(today),
+        "tomorrow" => return Some(today + Duration::days(1)),
+        "yesterday" => return Some(today - Duration::days(1)),
+        "next week" => return Some(today + Duration::days(7)),
+        _ => {}
+    }
+
+    // ISO date: 2026-09-01
+    if let Ok(date) = NaiveDate::parse_from_str(&text, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    // US dates: 9/1/2026 or 9/1 (current year)
+    if let Ok(date) = NaiveDate::parse_from_str(&text, "%m/%d/%Y") {
+        return Some(date);
+    }
+    if text.contains('/') {
+        let with_year = format!("{}/{}", text, today.year());
+        if let Ok(date) = NaiveDate::parse_from_str(&with_year, "%m/%d/%Y") {
+            return Some(date);
+        }
+    }
+
+    // "next friday" - strictly after today
+    if let Some(rest) = text.strip_prefix("next ") {
+        if let Some(weekday) = parse_weekday(rest.trim()) {
+            let days_ahead = (weekday.num_days_from_monday() as i64
+                - today.weekday().num_days_from_monday() as i64)
+                .rem_euclid(7);
+            let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+            return Some(today + Duration::days(days_ahead));
+        }
+    }
+
+    // Bare weekday - upcoming occurrence, today counts
+    if let Some(weekday) = parse_weekday(&text) {
+        let days_ahead = (weekday.num_days_from_monday() as i64
+            - today.weekday().num_days_from_monday() as i64)
+            .rem_euclid(7);
+        return Some(today + Duration::days(days_ahead));
+    }
+
+    // "in N days" / "in N weeks"
+    if let Some(rest) = text.strip_prefix("in ") {
+        let parts: Vec<&str> = rest.split_whitespace().collect();
+        if parts.len() == 2 {
+            if let Ok(n) = parts[0].parse::<i64>() {
+                match parts[1] {
+                    "day" | "days" => return Some(today + Duration::days(n)),
+                    "week" | "weeks" => return Some(today + Duration::days(n * 7)),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Parse natural-language time input relative to `now`.
+///
+/// Supported: "17:30", "5:30pm", "5pm", "noon", "midnight",
+/// "in N minutes"/"in N hours".
+pub fn parse_natural_time(input: &str, now: NaiveTime) -> Option<NaiveTime> {
+    let text = input.trim().to_lowercase().replace(' ', "");
+    if text.is_empty() {
+        return None;
+    }
+
+    match text.as_str() {
+        "noon" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return NaiveTime::from_hms_opt(0, 0, 0),
+        "now" => return Some(now),
+        _ => {}
+    }
+
+    // 24-hour: 17:30
+    if let Ok(time) = NaiveTime::parse_from_str(&text, "%H:%M") {
+        return Some(time);
+    }
+
+    // 12-hour: 5:30pm / 5pm
+    if let Ok(time) = NaiveTime::parse_from_str(&text, "%I:%M%p") {
+        return Some(time);
+    }
+    if let Ok(time) = NaiveTime::parse_from_str(&text, "%I%p") {
+        return Some(time);
+    }
+
+    // "in N minutes" / "in N hours" (spaces already stripped)
+    if let Some(rest) = text.strip_prefix("in") {
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let unit = &rest[digits.len()..];
+        if let Ok(n) = digits.parse::<i64>() {
+            match unit {
+                "minute" | "minutes" | "min" | "mins" | "m" => {
+                    return Some(now + Duration::minutes(n));
+                }
+                "hour" | "hours" | "hr" | "hrs" | "h" => {
+                    return Some(now + Duration::hours(n));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    None
+}
+
+/// DatePrompt - calendar-backed date/time picker
+pub struct DatePrompt {
+    /// Unique ID for this prompt instance
+    pub id: String,
+    /// Placeholder text
+    pub placeholder: Option<String>,
+    /// Date or time selection
+    pub mode: DateMode,
+    /// Text input state for natural-language entry
+    input: TextInputState,
+    /// Currently selected date (date mode)
+    selected_date: NaiveDate,
+    /// Currently selected time (time mode)
+    selected_time: NaiveTime,
+    /// First day of the month shown in the calendar grid
+    visible_month: NaiveDate,
+    /// Focus handle for keyboard input
+    pub focus_handle: FocusHandle,
+    /// Callback when user submits a value
+    pub on_submit: SubmitCallback,
+    /// Theme for styling
+    pub theme: Arc<theme::Theme>,
+    /// Design variant for styling
+    pub design_variant: DesignVariant,
+}
+
+impl DatePrompt {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        placeholder: Option<String>,
+        mode: DateMode,
+        initial: Option<String>,
+        focus_handle: FocusHandle,
+        on_submit: SubmitCallback,
+        theme: Arc<theme::Theme>,
+    ) -> Self {
+        logging::log("PROMPTS", &format!("DatePrompt::new (mode: {:?})", mode));
+
+        let today = Local::now().date_naive();
+        let now = Local::now().time();
+
+        // Apply initial value from the script if it parses
+        let selected_date = initial
+            .as_deref()
+            .and_then(|s| parse_natural_date(s, today))
+            .unwrap_or(today);
+        let selected_time = initial
+            .as_deref()
+            .and_then(|s| parse_natural_time(s, now))
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(now.hour(), now.minute(), 0).unwrap_or(now));
+
+        DatePrompt {
+            id,
+            placeholder,
+            mode,
+            input: TextInputState::new(),
+            selected_date,
+            selected_time,
+            visible_month: selected_date.with_day(1).unwrap_or(selected_date),
+            focus_handle,
+            on_submit,
+            theme,
+            design_variant: DesignVariant::Default,
+        }
+    }
+
+    /// Move the selected date by `days`, keeping the calendar in view
+    fn move_days(&mut self, days: i64) {
+        self.selected_date += Duration::days(days);
+        self.visible_month = self.selected_date.with_day(1).unwrap_or(self.selected_date);
+    }
+
+    /// Shift the visible month by `months` (selection follows)
+    fn move_months(&mut self, months: i32) {
+        let total = self.visible_month.year() * 12 + self.visible_month.month0() as i32 + months;
+        let (year, month0) = (total.div_euclid(12), total.rem_euclid(12));
+        if let Some(first) = NaiveDate::from_ymd_opt(year, month0 as u32 + 1, 1) {
+            self.visible_month = first;
+            let day = self.selected_date.day().min(days_in_month(first));
+            self.selected_date = first.with_day(day).unwrap_or(first);
+        }
+    }
+
+    /// Re-parse the input text and update the selection if it's valid
+    fn apply_input(&mut self) {
+        match self.mode {
+            DateMode::Date => {
+                if let Some(date) = parse_natural_date(self.input.text(), Local::now().date_naive())
+                {
+                    self.selected_date = date;
+                    self.visible_month = date.with_day(1).unwrap_or(date);
+                }
+            }
+            DateMode::Time => {
+                if let Some(time) = parse_natural_time(self.input.text(), Local::now().time()) {
+                    self.selected_time = time;
+                }
+            }
+        }
+    }
+
+    /// Submit the current selection as an ISO string
+    fn submit(&mut self) {
+        let value = match self.mode {
+            DateMode::Date => self.selected_date.format("%Y-%m-%d").to_string(),
+            DateMode::Time => self.selected_time.format("%H:%M").to_string(),
+        };
+        (self.on_submit)(self.id.clone(), Some(value));
+    }
+
+    /// Set the input text programmatically
+    pub fn set_input(&mut self, text: String, cx: &mut Context<Self>) {
+        if self.input.text() == text {
+            return;
+        }
+
+        self.input.set_text(text);
+        self.apply_input();
+        cx.notify();
+    }
+
+    /// Cancel - submit None
+    fn submit_cancel(&mut self) {
+        (self.on_submit)(self.id.clone(), None);
+    }
+
+    /// Render the calendar grid for the visible month
+    fn render_calendar(&self, text_primary: u32, text_dimmed: u32, accent_color: u32) -> Div {
+        let today = Local::now().date_naive();
+        let first = self.visible_month;
+        // Monday-first column offset of the 1st of the month
+        let offset = first.weekday().num_days_from_monday() as i64;
+        let grid_start = first - Duration::days(offset);
+
+        let mut weeks = div().flex().flex_col().gap(px(2.));
+        for week in 0..6 {
+            let mut row = div().flex().flex_row().gap(px(2.));
+            for day in 0..7 {
+                let date = grid_start + Duration::days(week * 7 + day);
+                let in_month = date.month() == first.month();
+                let is_selected = date == self.selected_date;
+                let is_today = date == today;
+
+                let mut cell = div()
+                    .w(px(32.))
+                    .h(px(26.))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .rounded(px(4.))
+                    .text_sm()
+                    .child(format!("{}", date.day()));
+
+                cell = if is_selected {
+                    cell.bg(rgb(accent_color)).text_color(rgb(0xffffff))
+                } else if is_today {
+                    cell.border_1()
+                        .border_color(rgb(accent_color))
+                        .text_color(rgb(text_primary))
+                } else if in_month {
+                    cell.text_color(rgb(text_primary))
+                } else {
+                    cell.text_color(rgba((text_dimmed << 8) | 0x60))
+                };
+
+                row = row.child(cell);
+            }
+            weeks = weeks.child(row);
+        }
+
+        // Weekday header row (Monday-first to match the grid)
+        let mut header = div().flex().flex_row().gap(px(2.));
+        for label in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+            header = header.child(
+                div()
+                    .w(px(32.))
+                    .h(px(20.))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_xs()
+                    .text_color(rgb(text_dimmed))
+                    .child(label),
+            );
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .gap(px(4.))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(rgb(text_primary))
+                    .child(self.visible_month.format("%B %Y").to_string()),
+            )
+            .child(header)
+            .child(weeks)
+    }
+}
+
+/// Number of days in the month containing `date`
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = (date.year(), date.month());
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    match (next, date.with_day(1)) {
+        (Some(next), Some(first)) => (next - first).num_days() as u32,
+        _ => 28,
+    }
+}
+
+impl Focusable for DatePrompt {
+    fn focus_handle(&self, _cx: &gpui::App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DatePrompt {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tokens = get_tokens(self.design_variant);
+        let design_colors = tokens.colors();
+        let design_typography = tokens.typography();
+
+        let handle_key = cx.listener(
+            |this: &mut Self,
+             event: &gpui::KeyDownEvent,
+             _window: &mut Window,
+             cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+                let modifiers = &event.keystroke.modifiers;
+
+                match key_str.as_str() {
+                    "enter" => {
+                        this.submit();
+                        return;
+                    }
+                    "escape" => {
+                        this.submit_cancel();
+                        return;
+                    }
+                    "left" | "arrowleft" if this.input.is_empty() => {
+                        match this.mode {
+                            DateMode::Date => this.move_days(-1),
+                            DateMode::Time => {
+                                this.selected_time += Duration::hours(-1);
+                            }
+                        }
+                        cx.notify();
+                        return;
+                    }
+                    "right" | "arrowright" if this.input.is_empty() => {
+                        match this.mode {
+                            DateMode::Date => this.move_days(1),
+                            DateMode::Time => {
+                                this.selected_time += Duration::hours(1);
+                            }
+                        }
+                        cx.notify();
+                        return;
+                    }
+                    "up" | "arrowup" => {
+                        match this.mode {
+                            DateMode::Date => this.move_days(-7),
+                            DateMode::Time => {
+                                this.selected_time += Duration::minutes(5);
+                            }
+                        }
+                        cx.notify();
+                        return;
+                    }
+                    "down" | "arrowdown" => {
+                        match this.mode {
+                            DateMode::Date => this.move_days(7),
+                            DateMode::Time => {
+                                this.selected_time += Duration::minutes(-5);
+                            }
+                        }
+                        cx.notify();
+                        return;
+                    }
+                    "pageup" => {
+                        if this.mode == DateMode::Date {
+                            this.move_months(-1);
+                            cx.notify();
+                        }
+                        return;
+                    }
+                    "pagedown" => {
+                        if this.mode == DateMode::Date {
+                            this.move_months(1);
+                            cx.notify();
+                        }
+                        return;
+                    }
+                    _ => {}
+                }
+
+                // Delegate all other keys to TextInputState, then re-parse
+                let key_char = event.keystroke.key_char.as_deref();
+                let handled = this.input.handle_key(
+                    &key_str,
+                    key_char,
+                    modifiers.platform, // On macOS, platform = Cmd key
+                    modifiers.alt,
+                    modifiers.shift,
+                    cx,
+                );
+
+                if handled {
+                    this.apply_input();
+                    cx.notify();
+                }
+            },
+        );
+
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let accent_color = design_colors.accent;
+
+        let placeholder: SharedString = self
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| match self.mode {
+                DateMode::Date => "Pick a date or type \"next friday\"...".to_string(),
+                DateMode::Time => "Pick a time or type \"5:30pm\"...".to_string(),
+            })
+            .into();
+
+        // Selection preview: the value Enter will submit
+        let preview = match self.mode {
+            DateMode::Date => self.selected_date.format("%a %Y-%m-%d").to_string(),
+            DateMode::Time => self.selected_time.format("%H:%M").to_string(),
+        };
+
+        div()
+            .id(gpui::ElementId::Name("window:date".into()))
+            .flex()
+            .flex_col()
+            .w_full()
+            .h_full()
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("date_prompt")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header row with input + preview + logo
+            .child(
+                div()
+                    .w_full()
+                    .px(px(HEADER_PADDING_X))
+                    .py(px(HEADER_PADDING_Y))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap(px(HEADER_GAP))
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            .child(
+                                TextInput::from_state(&self.input, false)
+                                    .placeholder(placeholder.clone())
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted)
+                                    .accent_color(accent_color),
+                            ),
+                    )
+                    .child(div().text_sm().text_color(rgb(accent_color)).child(preview))
+                    .child(
+                        svg()
+                            .path(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/logo.svg"))
+                            .size(px(16.))
+                            .text_color(rgb(accent_color)),
+                    ),
+            )
+            // Calendar grid (date mode only)
+            .when(self.mode == DateMode::Date, |d: Div| {
+                d.child(
+                    div()
+                        .w_full()
+                        .flex()
+                        .justify_center()
+                        .py(px(8.))
+                        .child(self.render_calendar(text_primary, text_dimmed, accent_color)),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monday() -> NaiveDate {
+        // 2026-08-31 is a Monday
+        NaiveDate::from_ymd_opt(2026, 8, 31).unwrap()
+    }
+
+    #[test]
+    fn test_parse_natural_date_keywords() {
+        let today = monday();
+        assert_eq!(parse_natural_date("today", today), Some(today));
+        assert_eq!(
+            parse_natural_date("tomorrow", today),
+            Some(today + Duration::days(1))
+        );
+        assert_eq!(
+            parse_natural_date("yesterday", today),
+            Some(today - Duration::days(1))
+        );
+    }
+
+    #[test]
+    fn test_parse_natural_date_iso() {
+        let today = monday();
+        assert_eq!(
+            parse_natural_date("2026-09-15", today),
+            NaiveDate::from_ymd_opt(2026, 9, 15)
+        );
+    }
+
+    #[test]
+    fn test_parse_natural_date_us_without_year() {
+        let today = monday();
+        assert_eq!(
+            parse_natural_date("9/15", today),
+            NaiveDate::from_ymd_opt(2026, 9, 15)
+        );
+    }
+
+    #[test]
+    fn test_parse_natural_date_weekdays() {
+        let today = monday();
+        // Bare weekday: upcoming occurrence, today counts
+        assert_eq!(parse_natural_date("monday", today), Some(today));
+        assert_eq!(
+            parse_natural_date("friday", today),
+            Some(today + Duration::days(4))
+        );
+        // "next monday" is strictly after today
+        assert_eq!(
+            parse_natural_date("next monday", today),
+            Some(today + Duration::days(7))
+        );
+        assert_eq!(
+            parse_natural_date("next friday", today),
+            Some(today + Duration::days(4))
+        );
+    }
+
+    #[test]
+    fn test_parse_natural_date_relative() {
+        let today = monday();
+        assert_eq!(
+            parse_natural_date("in 3 days", today),
+            Some(today + Duration::days(3))
+        );
+        assert_eq!(
+            parse_natural_date("in 2 weeks", today),
+            Some(today + Duration::days(14))
+        );
+    }
+
+    #[test]
+    fn test_parse_natural_date_garbage() {
+        assert_eq!(parse_natural_date("not a date", monday()), None);
+        assert_eq!(parse_natural_date("", monday()), None);
+    }
+
+    #[test]
+    fn test_parse_natural_time_formats() {
+        let now = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+        assert_eq!(
+            parse_natural_time("17:30", now),
+            NaiveTime::from_hms_opt(17, 30, 0)
+        );
+        assert_eq!(
+            parse_natural_time("5:30pm", now),
+            NaiveTime::from_hms_opt(17, 30, 0)
+        );
+        assert_eq!(
+            parse_natural_time("5pm", now),
+            NaiveTime::from_hms_opt(17, 0, 0)
+        );
+        assert_eq!(
+            parse_natural_time("noon", now),
+            NaiveTime::from_hms_opt(12, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_natural_time_relative() {
+        let now = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+        assert_eq!(
+            parse_natural_time("in 20 minutes", now),
+            NaiveTime::from_hms_opt(10, 20, 0)
+        );
+        assert_eq!(
+            parse_natural_time("in 2 hours", now),
+            NaiveTime::from_hms_opt(12, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(
+            days_in_month(NaiveDate::from_ymd_opt(2026, 2, 10).unwrap()),
+            28
+        );
+        assert_eq!(
+            days_in_month(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap()),
+            29
+        );
+        assert_eq!(
+            days_in_month(NaiveDate::from_ymd_opt(2026, 12, 1).unwrap()),
+            31
+        );
+    }
+}
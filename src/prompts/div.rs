@@ -8,7 +8,7 @@
 
 use gpui::{
     div, prelude::*, px, rgb, rgba, Context, Div, FocusHandle, Focusable, FontWeight, Hsla, Point,
-    Render, ScrollHandle, Window,
+    Render, ScrollHandle, SharedString, Window,
 };
 use std::sync::Arc;
 
@@ -283,6 +283,8 @@ struct RenderContext {
     quote_border: u32,
     /// HR color
     hr_color: u32,
+    /// Monospace font family for inline code and code blocks
+    mono_font: SharedString,
     /// Optional link click callback
     on_link_click: Option<LinkClickCallback>,
 }
@@ -297,10 +299,16 @@ impl RenderContext {
             code_bg: colors.background.search_box,
             quote_border: colors.ui.border,
             hr_color: colors.ui.border,
+            mono_font: SharedString::from("Menlo"),
             on_link_click: None,
         }
     }
 
+    fn with_mono_font(mut self, family: impl Into<SharedString>) -> Self {
+        self.mono_font = family.into();
+        self
+    }
+
     fn with_link_callback(mut self, callback: LinkClickCallback) -> Self {
         self.on_link_click = Some(callback);
         self
@@ -380,7 +388,7 @@ fn render_element(element: &HtmlElement, ctx: RenderContext) -> Div {
             .py(px(2.0))
             .bg(rgba((ctx.code_bg << 8) | 0x80))
             .rounded(px(4.0))
-            .font_family("Menlo")
+            .font_family(ctx.mono_font.clone())
             .text_sm()
             .text_color(rgb(ctx.accent_color))
             .child(code.clone()),
@@ -391,7 +399,7 @@ fn render_element(element: &HtmlElement, ctx: RenderContext) -> Div {
             .mb(px(8.0))
             .bg(rgba((ctx.code_bg << 8) | 0xC0))
             .rounded(px(6.0))
-            .font_family("Menlo")
+            .font_family(ctx.mono_font.clone())
             .text_sm()
             .text_color(rgb(ctx.text_primary))
             .child(code.clone()),
@@ -771,7 +779,7 @@ fn render_inline(element: &HtmlElement, ctx: RenderContext) -> Div {
             .py(px(1.0))
             .bg(rgba((ctx.code_bg << 8) | 0x80))
             .rounded(px(3.0))
-            .font_family("Menlo")
+            .font_family(ctx.mono_font.clone())
             .text_xs()
             .text_color(rgb(ctx.accent_color))
             .child(code.clone()),
@@ -835,7 +843,7 @@ fn render_inline(element: &HtmlElement, ctx: RenderContext) -> Div {
             .py(px(1.0))
             .bg(rgba((ctx.code_bg << 8) | 0x80))
             .rounded(px(3.0))
-            .font_family("Menlo")
+            .font_family(ctx.mono_font.clone())
             .text_xs()
             .child(code.clone()),
 
@@ -901,7 +909,9 @@ impl Render for DivPrompt {
 
         // Create render context from theme with link callback
         let render_ctx = if self.design_variant == DesignVariant::Default {
-            RenderContext::from_theme(&self.theme.colors).with_link_callback(on_link_click)
+            RenderContext::from_theme(&self.theme.colors)
+                .with_mono_font(self.theme.get_fonts().mono_family)
+                .with_link_callback(on_link_click)
         } else {
             RenderContext {
                 text_primary: colors.text_primary,
@@ -911,6 +921,7 @@ impl Render for DivPrompt {
                 code_bg: colors.background_tertiary, // Use background_tertiary for code bg
                 quote_border: colors.border,
                 hr_color: colors.border,
+                mono_font: self.theme.get_fonts().mono_family.into(),
                 on_link_click: Some(on_link_click),
             }
         };
@@ -1,11 +1,13 @@
 //! DropPrompt - Drag and drop file handling
 //!
 //! Features:
-//! - Drop zone for files
-//! - Display dropped file information
-//! - Submit file paths
+//! - Drop zone for files dragged from Finder or other apps
+//! - Highlight feedback while a drag hovers the zone
+//! - Submits the dropped paths as a JSON payload list
 
-use gpui::{div, prelude::*, px, rgb, Context, FocusHandle, Focusable, Render, Window};
+use gpui::{
+    div, prelude::*, px, rgb, Context, ExternalPaths, FocusHandle, Focusable, Render, Window,
+};
 use std::sync::Arc;
 
 use crate::designs::{get_tokens, DesignVariant};
@@ -99,12 +101,37 @@ impl DropPrompt {
         (self.on_submit)(self.id.clone(), None);
     }
 
-    /// Handle file drop (would be called from GPUI drag/drop events)
-    #[allow(dead_code)]
-    fn handle_drop(&mut self, files: Vec<DroppedFile>, cx: &mut Context<Self>) {
+    /// Handle an external file drop from GPUI
+    ///
+    /// Records the dropped paths and submits immediately - `await drop()`
+    /// resolves as soon as the user lets go, matching Script Kit.
+    fn handle_drop(&mut self, paths: &ExternalPaths, cx: &mut Context<Self>) {
+        let files: Vec<DroppedFile> = paths
+            .paths()
+            .iter()
+            .map(|path| {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| path.to_string_lossy().to_string());
+                let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                DroppedFile {
+                    path: path.to_string_lossy().to_string(),
+                    name,
+                    size,
+                }
+            })
+            .collect();
+
+        logging::log(
+            "PROMPTS",
+            &format!("DropPrompt {} received {} file(s)", self.id, files.len()),
+        );
+
         self.dropped_files = files;
         self.is_drag_over = false;
         cx.notify();
+        self.submit();
     }
 }
 
@@ -162,12 +189,14 @@ impl Render for DropPrompt {
             .clone()
             .unwrap_or_else(|| "Drag and drop files to upload".to_string());
 
-        // Drop zone styling
+        // Drop zone styling - drag_over() below overrides these while hovering
         let drop_zone_bg = if self.is_drag_over {
             rgb(self.theme.colors.accent.selected_subtle)
         } else {
             rgb(self.theme.colors.background.search_box)
         };
+        let drag_over_bg = rgb(self.theme.colors.accent.selected_subtle);
+        let drag_over_border = rgb(self.theme.colors.accent.selected);
 
         div()
             .id(gpui::ElementId::Name("window:drop".into()))
@@ -182,8 +211,9 @@ impl Render for DropPrompt {
             .track_focus(&self.focus_handle)
             .on_key_down(handle_key)
             .child(
-                // Drop zone
+                // Drop zone - stateful id is required for drag_over/on_drop
                 div()
+                    .id("drop_zone")
                     .flex()
                     .flex_col()
                     .items_center()
@@ -194,6 +224,17 @@ impl Render for DropPrompt {
                     .border_2()
                     .border_color(border_color)
                     .rounded(px(8.))
+                    .drag_over::<ExternalPaths>(move |style, _, _, _| {
+                        style.bg(drag_over_bg).border_color(drag_over_border)
+                    })
+                    .on_drop(cx.listener(
+                        |this: &mut Self,
+                         paths: &ExternalPaths,
+                         _window: &mut Window,
+                         cx: &mut Context<Self>| {
+                            this.handle_drop(paths, cx);
+                        },
+                    ))
                     .child(div().text_2xl().child("📁"))
                     .child(
                         div()
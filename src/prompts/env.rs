@@ -10,18 +10,15 @@
 //! Design: Matches ArgPrompt-no-choices (single input line, minimal height)
 
 use gpui::{
-    div, prelude::*, px, rgb, rgba, svg, Context, Div, FocusHandle, Focusable, Render,
-    SharedString, Window,
+    div, prelude::*, px, rgb, rgba, svg, Context, FocusHandle, Focusable, Render, SharedString,
+    Window,
 };
 use std::sync::Arc;
 
-use crate::components::TextInputState;
+use crate::components::{TextInput, TextInputState};
 use crate::designs::{get_tokens, DesignVariant};
 use crate::logging;
-use crate::panel::{
-    CURSOR_GAP_X, CURSOR_HEIGHT_LG, CURSOR_MARGIN_Y, CURSOR_WIDTH, HEADER_GAP, HEADER_PADDING_X,
-    HEADER_PADDING_Y,
-};
+use crate::panel::{HEADER_GAP, HEADER_PADDING_X, HEADER_PADDING_Y};
 use crate::theme;
 
 use super::SubmitCallback;
@@ -69,6 +66,9 @@ pub fn set_secret(key: &str, value: &str) -> Result<(), String> {
         .set_password(value)
         .map_err(|e| format!("Failed to store secret: {}", e))?;
 
+    // Keep the Secrets Vault index aware of this key
+    crate::secrets_vault::record_key(key);
+
     logging::log("KEYRING", &format!("Stored secret for key: {}", key));
     Ok(())
 }
@@ -83,6 +83,8 @@ pub fn delete_secret(key: &str) -> Result<(), String> {
         .delete_credential()
         .map_err(|e| format!("Failed to delete secret: {}", e))?;
 
+    crate::secrets_vault::forget_key(key);
+
     logging::log("KEYRING", &format!("Deleted secret for key: {}", key));
     Ok(())
 }
@@ -191,71 +193,6 @@ impl EnvPrompt {
     fn submit_cancel(&mut self) {
         (self.on_submit)(self.id.clone(), None);
     }
-
-    /// Get display text (masked if secret)
-    fn display_text(&self) -> String {
-        self.input.display_text(self.secret)
-    }
-
-    /// Render the text input with cursor and selection
-    fn render_input_text(&self, text_primary: u32, accent_color: u32) -> Div {
-        let text = self.display_text();
-        let chars: Vec<char> = text.chars().collect();
-        let cursor_pos = self.input.cursor();
-        let has_selection = self.input.has_selection();
-
-        if text.is_empty() {
-            // Empty - just show cursor
-            return div().flex().flex_row().items_center().child(
-                div()
-                    .w(px(CURSOR_WIDTH))
-                    .h(px(CURSOR_HEIGHT_LG))
-                    .bg(rgb(text_primary)),
-            );
-        }
-
-        if has_selection {
-            // With selection: before | selected | after
-            let selection = self.input.selection();
-            let (start, end) = selection.range();
-
-            let before: String = chars[..start].iter().collect();
-            let selected: String = chars[start..end].iter().collect();
-            let after: String = chars[end..].iter().collect();
-
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .overflow_x_hidden()
-                .when(!before.is_empty(), |d: Div| d.child(div().child(before)))
-                .child(
-                    div()
-                        .bg(rgba((accent_color << 8) | 0x60))
-                        .text_color(rgb(0xffffff))
-                        .child(selected),
-                )
-                .when(!after.is_empty(), |d: Div| d.child(div().child(after)))
-        } else {
-            // No selection: before cursor | cursor | after cursor
-            let before: String = chars[..cursor_pos].iter().collect();
-            let after: String = chars[cursor_pos..].iter().collect();
-
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .overflow_x_hidden()
-                .when(!before.is_empty(), |d: Div| d.child(div().child(before)))
-                .child(
-                    div()
-                        .w(px(CURSOR_WIDTH))
-                        .h(px(CURSOR_HEIGHT_LG))
-                        .bg(rgb(text_primary)),
-                )
-                .when(!after.is_empty(), |d: Div| d.child(div().child(after)))
-        }
-    }
 }
 
 impl Focusable for EnvPrompt {
@@ -334,8 +271,6 @@ impl Render for EnvPrompt {
             })
             .into();
 
-        let input_is_empty = self.input.is_empty();
-
         // Main container - matches ArgPrompt-no-choices layout exactly
         // Single row with: input area + Submit button + logo
         div()
@@ -367,27 +302,13 @@ impl Render for EnvPrompt {
                             .flex_row()
                             .items_center()
                             .text_lg()
-                            .text_color(if input_is_empty {
-                                rgb(text_muted)
-                            } else {
-                                rgb(text_primary)
-                            })
-                            // When empty: show cursor + placeholder
-                            .when(input_is_empty, |d: Div| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .mr(px(CURSOR_GAP_X))
-                                        .bg(rgb(text_primary)),
-                                )
-                                .child(div().child(placeholder.clone()))
-                            })
-                            // When has text: show text with cursor/selection
-                            .when(!input_is_empty, |d: Div| {
-                                d.child(self.render_input_text(text_primary, accent_color))
-                            }),
+                            .child(
+                                TextInput::from_state(&self.input, self.secret)
+                                    .placeholder(placeholder.clone())
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted)
+                                    .accent_color(accent_color),
+                            ),
                     )
                     // Submit button area (matches ArgPrompt style)
                     .child(
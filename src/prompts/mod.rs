@@ -11,15 +11,21 @@
 //! - `drop`: DropPrompt - Drag and drop (skeleton)
 //! - `template`: TemplatePrompt - String templates with placeholders (skeleton)
 //! - `select`: SelectPrompt - Multi-select with checkboxes (skeleton)
+//! - `table`: TablePrompt - Tabular data with sortable columns
+//! - `number`: NumberPrompt - Numeric input with min/max/step
+//! - `date`: DatePrompt - Date/time picker with calendar grid
 
 #![allow(dead_code)]
 
 mod arg;
+mod date;
 pub mod div;
 mod drop;
-mod env;
+pub mod env;
+mod number;
 mod path;
 mod select;
+mod table;
 mod template;
 
 // Re-export prompt types for use when they're integrated into main.rs
@@ -32,18 +38,26 @@ pub use div::{ContainerOptions, ContainerPadding, DivPrompt};
 // These exports are ready for use in main.rs when AppView variants are added
 // The #[allow(unused_imports)] is temporary until main.rs integrations are complete
 #[allow(unused_imports)]
+pub use date::{DateMode, DatePrompt};
+#[allow(unused_imports)]
 pub use drop::DropPrompt;
 #[allow(unused_imports)]
 pub use env::EnvPrompt;
 #[allow(unused_imports)]
+pub use number::NumberPrompt;
+#[allow(unused_imports)]
 pub use path::PathInfo;
 #[allow(unused_imports)]
 pub use path::PathPrompt;
 #[allow(unused_imports)]
+pub use path::PathPromptOptions;
+#[allow(unused_imports)]
 pub use path::ShowActionsCallback;
 #[allow(unused_imports)]
 pub use select::SelectPrompt;
 #[allow(unused_imports)]
+pub use table::TablePrompt;
+#[allow(unused_imports)]
 pub use template::TemplatePrompt;
 
 // Re-export common types used by prompts
@@ -0,0 +1,339 @@
+//! NumberPrompt - Typed numeric input prompt
+//!
+//! Features:
+//! - Optional min/max/step constraints from the script
+//! - Up/Down arrows increment/decrement by step (default 1)
+//! - Enter submits the parsed, clamped value as a string
+//! - Full text selection and clipboard support (cmd+c/v/x, shift+arrows)
+//!
+//! Design: Matches ArgPrompt-no-choices (single input line, minimal height)
+
+use gpui::{
+    div, prelude::*, px, rgb, rgba, svg, Context, FocusHandle, Focusable, Render, SharedString,
+    Window,
+};
+use std::sync::Arc;
+
+use crate::components::{TextInput, TextInputState};
+use crate::designs::{get_tokens, DesignVariant};
+use crate::logging;
+use crate::panel::{HEADER_GAP, HEADER_PADDING_X, HEADER_PADDING_Y};
+use crate::theme;
+
+use super::SubmitCallback;
+
+/// Format a numeric value for display/submission, dropping a trailing ".0"
+/// for whole numbers so `5.0` submits as "5".
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// NumberPrompt - numeric input with min/max/step constraints
+pub struct NumberPrompt {
+    /// Unique ID for this prompt instance
+    pub id: String,
+    /// Placeholder text (defaults to "Enter a number")
+    pub placeholder: Option<String>,
+    /// Minimum allowed value (inclusive)
+    pub min: Option<f64>,
+    /// Maximum allowed value (inclusive)
+    pub max: Option<f64>,
+    /// Arrow-key increment (defaults to 1)
+    pub step: Option<f64>,
+    /// Text input state with selection and clipboard support
+    input: TextInputState,
+    /// Focus handle for keyboard input
+    pub focus_handle: FocusHandle,
+    /// Callback when user submits a value
+    pub on_submit: SubmitCallback,
+    /// Theme for styling
+    pub theme: Arc<theme::Theme>,
+    /// Design variant for styling
+    pub design_variant: DesignVariant,
+}
+
+impl NumberPrompt {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        placeholder: Option<String>,
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>,
+        initial: Option<f64>,
+        focus_handle: FocusHandle,
+        on_submit: SubmitCallback,
+        theme: Arc<theme::Theme>,
+    ) -> Self {
+        logging::log(
+            "PROMPTS",
+            &format!(
+                "NumberPrompt::new (min: {:?}, max: {:?}, step: {:?})",
+                min, max, step
+            ),
+        );
+
+        let mut input = TextInputState::new();
+        if let Some(value) = initial {
+            input.set_text(format_number(value));
+        }
+
+        NumberPrompt {
+            id,
+            placeholder,
+            min,
+            max,
+            step,
+            input,
+            focus_handle,
+            on_submit,
+            theme,
+            design_variant: DesignVariant::Default,
+        }
+    }
+
+    /// Parse the current input text as a number, if valid
+    fn current_value(&self) -> Option<f64> {
+        self.input.text().trim().parse::<f64>().ok()
+    }
+
+    /// Clamp a value into the configured min/max range
+    fn clamp(&self, value: f64) -> f64 {
+        let mut v = value;
+        if let Some(min) = self.min {
+            v = v.max(min);
+        }
+        if let Some(max) = self.max {
+            v = v.min(max);
+        }
+        v
+    }
+
+    /// Increment or decrement by step (direction is +1 or -1)
+    fn step_by(&mut self, direction: f64) {
+        let step = self.step.unwrap_or(1.0);
+        let base = self.current_value().or(self.min).unwrap_or(0.0);
+        let next = self.clamp(base + step * direction);
+        self.input.set_text(format_number(next));
+    }
+
+    /// Submit the entered value if it parses as a number
+    fn submit(&mut self) {
+        if let Some(value) = self.current_value() {
+            let clamped = self.clamp(value);
+            (self.on_submit)(self.id.clone(), Some(format_number(clamped)));
+        }
+    }
+
+    /// Set the input text programmatically
+    pub fn set_input(&mut self, text: String, cx: &mut Context<Self>) {
+        if self.input.text() == text {
+            return;
+        }
+
+        self.input.set_text(text);
+        cx.notify();
+    }
+
+    /// Cancel - submit None
+    fn submit_cancel(&mut self) {
+        (self.on_submit)(self.id.clone(), None);
+    }
+}
+
+impl Focusable for NumberPrompt {
+    fn focus_handle(&self, _cx: &gpui::App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for NumberPrompt {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tokens = get_tokens(self.design_variant);
+        let design_colors = tokens.colors();
+        let design_typography = tokens.typography();
+
+        let handle_key = cx.listener(
+            |this: &mut Self,
+             event: &gpui::KeyDownEvent,
+             _window: &mut Window,
+             cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+                let modifiers = &event.keystroke.modifiers;
+
+                // Handle submit/cancel/increment first
+                match key_str.as_str() {
+                    "enter" => {
+                        this.submit();
+                        return;
+                    }
+                    "escape" => {
+                        this.submit_cancel();
+                        return;
+                    }
+                    "up" | "arrowup" => {
+                        this.step_by(1.0);
+                        cx.notify();
+                        return;
+                    }
+                    "down" | "arrowdown" => {
+                        this.step_by(-1.0);
+                        cx.notify();
+                        return;
+                    }
+                    _ => {}
+                }
+
+                // Delegate all other keys to TextInputState
+                let key_char = event.keystroke.key_char.as_deref();
+                let handled = this.input.handle_key(
+                    &key_str,
+                    key_char,
+                    modifiers.platform, // On macOS, platform = Cmd key
+                    modifiers.alt,
+                    modifiers.shift,
+                    cx,
+                );
+
+                if handled {
+                    cx.notify();
+                }
+            },
+        );
+
+        // Use design tokens for consistent styling (matches ArgPrompt)
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let accent_color = design_colors.accent;
+
+        // Build placeholder with range hint: "Enter a number (1-10)"
+        let range_hint = match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                format!(" ({}-{})", format_number(min), format_number(max))
+            }
+            (Some(min), None) => format!(" (min {})", format_number(min)),
+            (None, Some(max)) => format!(" (max {})", format_number(max)),
+            (None, None) => String::new(),
+        };
+        let placeholder: SharedString = format!(
+            "{}{}",
+            self.placeholder
+                .clone()
+                .unwrap_or_else(|| "Enter a number".to_string()),
+            range_hint
+        )
+        .into();
+
+        // Main container - matches ArgPrompt-no-choices layout exactly
+        div()
+            .id(gpui::ElementId::Name("window:number".into()))
+            .flex()
+            .flex_col()
+            .w_full()
+            .h_full()
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("number_prompt")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Single header row - uses shared header constants for visual consistency
+            .child(
+                div()
+                    .w_full()
+                    .px(px(HEADER_PADDING_X))
+                    .py(px(HEADER_PADDING_Y))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap(px(HEADER_GAP))
+                    // Input area with cursor and selection
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            .child(
+                                TextInput::from_state(&self.input, false)
+                                    .placeholder(placeholder.clone())
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted)
+                                    .accent_color(accent_color),
+                            ),
+                    )
+                    // Stepper hint + submit button area (matches ArgPrompt style)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .child(
+                                div()
+                                    .mr(px(4.))
+                                    .px(px(4.))
+                                    .py(px(2.))
+                                    .rounded(px(4.))
+                                    .bg(rgba((text_dimmed << 8) | 0x30))
+                                    .text_color(rgb(text_muted))
+                                    .text_xs()
+                                    .child("↑↓"),
+                            )
+                            .child(
+                                div()
+                                    .text_color(rgb(accent_color))
+                                    .text_sm()
+                                    .child("Submit"),
+                            )
+                            .child(
+                                div()
+                                    .ml(px(4.))
+                                    .px(px(4.))
+                                    .py(px(2.))
+                                    .rounded(px(4.))
+                                    .bg(rgba((text_dimmed << 8) | 0x30))
+                                    .text_color(rgb(text_muted))
+                                    .text_xs()
+                                    .child("↵"),
+                            )
+                            .child(
+                                div()
+                                    .mx(px(4.))
+                                    .text_color(rgba((text_dimmed << 8) | 0x60))
+                                    .text_sm()
+                                    .child("|"),
+                            ),
+                    )
+                    // Script Kit logo
+                    .child(
+                        svg()
+                            .path(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/logo.svg"))
+                            .size(px(16.))
+                            .text_color(rgb(accent_color)),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_number;
+
+    #[test]
+    fn test_format_number_whole() {
+        assert_eq!(format_number(5.0), "5");
+        assert_eq!(format_number(-3.0), "-3");
+        assert_eq!(format_number(0.0), "0");
+    }
+
+    #[test]
+    fn test_format_number_fractional() {
+        assert_eq!(format_number(2.5), "2.5");
+        assert_eq!(format_number(-0.25), "-0.25");
+    }
+}
@@ -7,7 +7,7 @@
 //! - Submit selected path
 
 use gpui::{
-    div, prelude::*, uniform_list, Context, FocusHandle, Focusable, Render,
+    div, prelude::*, px, uniform_list, Context, FocusHandle, Focusable, Render, Timer,
     UniformListScrollHandle, Window,
 };
 use std::path::Path;
@@ -18,8 +18,10 @@ use crate::components::{
     PromptHeaderColors, PromptHeaderConfig,
 };
 use crate::designs::{get_tokens, DesignVariant};
-use crate::list_item::{IconKind, ListItem, ListItemColors};
+use crate::file_preview::{self, FilePreview, PreviewColors};
+use crate::list_item::{IconKind, ListItem, ListItemColors, LIST_ITEM_HEIGHT};
 use crate::logging;
+use crate::task_pool;
 use crate::theme;
 
 /// Callback for prompt submission
@@ -48,6 +50,52 @@ impl PathInfo {
     }
 }
 
+/// Options that constrain what the PathPrompt shows and submits
+///
+/// Used by the `path` protocol message as well as the `openFile`/`saveFile`
+/// dialogs so scripts can pre-configure the picker instead of re-implementing
+/// filtering on the submitted value.
+#[derive(Clone, Debug, Default)]
+pub struct PathPromptOptions {
+    /// Only show (and submit) directories
+    pub only_dirs: bool,
+    /// When non-empty, only show files with these extensions (dirs always shown
+    /// so the user can still navigate). Extensions are matched without the dot,
+    /// case-insensitively (e.g. ["png", "jpg"]).
+    pub extensions: Vec<String>,
+    /// Show dotfiles (hidden entries). Defaults to false.
+    pub show_hidden: bool,
+    /// Save-dialog behavior: Enter submits `current_path/filter_text` so the
+    /// user can name a file that doesn't exist yet
+    pub save_mode: bool,
+    /// Pre-filled filename for save mode
+    pub default_filename: Option<String>,
+}
+
+impl PathPromptOptions {
+    /// Whether an entry passes the extension/directory filters
+    pub fn allows(&self, name: &str, is_dir: bool) -> bool {
+        if !self.show_hidden && name.starts_with('.') {
+            return false;
+        }
+        if is_dir {
+            // Directories are always listed for navigation
+            return true;
+        }
+        if self.only_dirs {
+            return false;
+        }
+        if self.extensions.is_empty() {
+            return true;
+        }
+        let ext = Path::new(name)
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        self.extensions.iter().any(|e| e.to_lowercase() == ext)
+    }
+}
+
 /// Callback for showing actions dialog
 /// Signature: (path_info: PathInfo)
 pub type ShowActionsCallback = Arc<dyn Fn(PathInfo) + Send + Sync>;
@@ -98,6 +146,15 @@ pub struct PathPrompt {
     pub actions_search_text: Arc<Mutex<String>>,
     /// Whether to show blinking cursor (for focused state)
     pub cursor_visible: bool,
+    /// Filtering/save-dialog options for this prompt
+    pub options: PathPromptOptions,
+    /// Cached preview for the selected file: (path, preview-or-not-previewable)
+    /// Reloaded only when the selection changes, never per frame
+    preview_cache: Option<(String, Option<FilePreview>)>,
+    /// Path whose preview is being read on the task pool (dedupes spawns)
+    preview_loading: Option<String>,
+    /// Whether a directory listing is in flight on the task pool
+    pub loading: bool,
 }
 
 /// A file system entry (file or directory)
@@ -132,7 +189,8 @@ impl PathPrompt {
         );
 
         // Load entries from current path
-        let entries = Self::load_entries(&current_path);
+        let options = PathPromptOptions::default();
+        let entries = Self::load_entries(&current_path, &options);
         let filtered_entries = entries.clone();
 
         PathPrompt {
@@ -154,9 +212,86 @@ impl PathPrompt {
             actions_showing: Arc::new(Mutex::new(false)),
             actions_search_text: Arc::new(Mutex::new(String::new())),
             cursor_visible: true,
+            options,
+            preview_cache: None,
+            preview_loading: None,
+            loading: false,
+        }
+    }
+
+    /// Refresh the preview cache for the currently selected entry
+    ///
+    /// Previews only apply to files with previewable extensions
+    /// (json/csv/tsv/md/png). Directories and other files clear the cache.
+    fn refresh_preview(&mut self, cx: &mut Context<Self>) {
+        let selected_path = self
+            .filtered_entries
+            .get(self.selected_index)
+            .filter(|e| !e.is_dir)
+            .map(|e| e.path.clone());
+
+        match selected_path {
+            Some(path) => {
+                let cached = self
+                    .preview_cache
+                    .as_ref()
+                    .is_some_and(|(cached_path, _)| *cached_path == path);
+                if cached || self.preview_loading.as_deref() == Some(path.as_str()) {
+                    return;
+                }
+                // Read and parse off the render path on the task pool;
+                // the panel shows a placeholder until the preview arrives
+                self.preview_cache = None;
+                self.preview_loading = Some(path.clone());
+                let job_path = path.clone();
+                let rx = task_pool::run(move || file_preview::preview_for_path(&job_path));
+                cx.spawn(async move |this, cx| {
+                    loop {
+                        Timer::after(std::time::Duration::from_millis(30)).await;
+                        match rx.try_recv() {
+                            Ok(preview) => {
+                                let _ = cx.update(|cx| {
+                                    this.update(cx, |prompt, cx| {
+                                        // The selection may have moved on;
+                                        // only apply the preview still wanted
+                                        if prompt.preview_loading.as_deref()
+                                            == Some(path.as_str())
+                                        {
+                                            prompt.preview_loading = None;
+                                            prompt.preview_cache = Some((path.clone(), preview));
+                                            cx.notify();
+                                        }
+                                    })
+                                });
+                                break;
+                            }
+                            Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                            Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                        }
+                    }
+                })
+                .detach();
+            }
+            None => {
+                self.preview_cache = None;
+                self.preview_loading = None;
+            }
         }
     }
 
+    /// Apply filtering/save options, reloading entries under the new rules
+    pub fn with_options(mut self, options: PathPromptOptions) -> Self {
+        self.options = options;
+        if let Some(ref filename) = self.options.default_filename {
+            // Pre-fill the filter with the suggested filename for save dialogs
+            self.filter_text = filename.clone();
+        }
+        self.entries = Self::load_entries(&self.current_path, &self.options);
+        self.filtered_entries = self.entries.clone();
+        self.update_filtered();
+        self
+    }
+
     /// Set the callback for showing actions dialog
     pub fn with_show_actions(mut self, callback: ShowActionsCallback) -> Self {
         self.on_show_actions = Some(callback);
@@ -186,8 +321,8 @@ impl PathPrompt {
         self
     }
 
-    /// Load directory entries from a path
-    fn load_entries(dir_path: &str) -> Vec<PathEntry> {
+    /// Load directory entries from a path, honoring the prompt's options
+    fn load_entries(dir_path: &str, options: &PathPromptOptions) -> Vec<PathEntry> {
         let path = Path::new(dir_path);
         let mut entries = Vec::new();
 
@@ -201,13 +336,13 @@ impl PathPrompt {
             for entry in read_dir.flatten() {
                 let entry_path = entry.path();
                 let name = entry.file_name().to_string_lossy().to_string();
+                let is_dir = entry_path.is_dir();
 
-                // Skip hidden files (starting with .)
-                if name.starts_with('.') {
+                // Enforce hidden/directory/extension constraints
+                if !options.allows(&name, is_dir) {
                     continue;
                 }
 
-                let is_dir = entry_path.is_dir();
                 let path_entry = PathEntry {
                     name,
                     path: entry_path.to_string_lossy().to_string(),
@@ -278,10 +413,43 @@ impl PathPrompt {
     /// Navigate into a directory
     pub fn navigate_to(&mut self, path: &str, cx: &mut Context<Self>) {
         self.current_path = path.to_string();
-        self.entries = Self::load_entries(path);
         self.filter_text.clear();
-        self.filtered_entries = self.entries.clone();
+        // The listing happens on the task pool; drop the previous
+        // directory's entries so the loading placeholder shows instead
+        self.entries.clear();
+        self.filtered_entries.clear();
         self.selected_index = 0;
+        self.loading = true;
+
+        let dir = path.to_string();
+        let options = self.options.clone();
+        let rx = task_pool::run(move || Self::load_entries(&dir, &options));
+        let target = self.current_path.clone();
+        cx.spawn(async move |this, cx| {
+            loop {
+                Timer::after(std::time::Duration::from_millis(30)).await;
+                match rx.try_recv() {
+                    Ok(entries) => {
+                        let _ = cx.update(|cx| {
+                            this.update(cx, |prompt, cx| {
+                                // A newer navigation may have started; only
+                                // apply entries for the directory still shown
+                                if prompt.current_path == target {
+                                    prompt.entries = entries;
+                                    prompt.filtered_entries = prompt.entries.clone();
+                                    prompt.loading = false;
+                                    cx.notify();
+                                }
+                            })
+                        });
+                        break;
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+                }
+            }
+        })
+        .detach();
         cx.notify();
     }
 
@@ -333,7 +501,26 @@ impl PathPrompt {
     /// For files and directories: submit the path (script will handle it)
     /// Navigation into directories is handled by → and Tab keys
     fn submit_selected(&mut self, _cx: &mut Context<Self>) {
+        // Save dialogs submit the typed filename joined to the current
+        // directory, so the user can name a file that doesn't exist yet
+        if self.options.save_mode && !self.filter_text.is_empty() {
+            let save_path = Path::new(&self.current_path)
+                .join(&self.filter_text)
+                .to_string_lossy()
+                .to_string();
+            logging::log(
+                "PROMPTS",
+                &format!("PathPrompt (save mode) submitting path: {}", save_path),
+            );
+            (self.on_submit)(self.id.clone(), Some(save_path));
+            return;
+        }
+
         if let Some(entry) = self.filtered_entries.get(self.selected_index) {
+            // Directory-only mode never submits a file
+            if self.options.only_dirs && !entry.is_dir {
+                return;
+            }
             // Always submit the path, whether it's a file or directory
             // The calling script or default handler will decide what to do with it
             logging::log(
@@ -397,6 +584,32 @@ impl PathPrompt {
         }
     }
 
+    /// Move selection by a signed delta (mouse wheel scrolling)
+    ///
+    /// Clamps to the filtered entries like repeated move_up/move_down would,
+    /// but scrolls with `Nearest` so wheel movement doesn't snap the viewport.
+    pub fn move_by(&mut self, delta: i32, cx: &mut Context<Self>) {
+        if self.filtered_entries.is_empty() {
+            return;
+        }
+        let max = self.filtered_entries.len() as i32 - 1;
+        let target = (self.selected_index as i32 + delta).clamp(0, max) as usize;
+        if target != self.selected_index {
+            self.selected_index = target;
+            self.list_scroll_handle
+                .scroll_to_item(target, gpui::ScrollStrategy::Nearest);
+            cx.notify();
+        }
+    }
+
+    /// Select an entry by index (mouse click) without submitting
+    pub fn select_index(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if ix < self.filtered_entries.len() && ix != self.selected_index {
+            self.selected_index = ix;
+            cx.notify();
+        }
+    }
+
     /// Handle character input
     fn handle_char(&mut self, ch: char, cx: &mut Context<Self>) {
         self.filter_text.push(ch);
@@ -465,6 +678,9 @@ impl Render for PathPrompt {
         let tokens = get_tokens(self.design_variant);
         let design_colors = tokens.colors();
 
+        // Keep the preview panel in sync with the current selection
+        self.refresh_preview(cx);
+
         let handle_key = cx.listener(
             |this: &mut Self,
              event: &gpui::KeyDownEvent,
@@ -542,6 +758,10 @@ impl Render for PathPrompt {
             .map(|e| (e.name.clone(), e.is_dir))
             .collect();
 
+        // Entity handle for mouse handlers inside the uniform_list closure
+        // (the closure only receives `&mut App`, not a Context)
+        let list_entity = cx.entity();
+
         // Build list items using ListItem component for consistent styling
         let list = uniform_list(
             "path-list",
@@ -562,13 +782,39 @@ impl Render for PathPrompt {
                         // No description needed - folder icon 📁 is sufficient
                         let description: Option<String> = None;
 
+                        let click_entity = list_entity.clone();
+                        let context_entity = list_entity.clone();
+
                         // Use ListItem component for consistent styling with main menu
-                        ListItem::new(name.clone(), list_colors)
-                            .index(ix)
-                            .icon_kind(icon)
-                            .description_opt(description)
-                            .selected(is_selected)
-                            .with_accent_bar(true)
+                        div()
+                            .id(ix)
+                            .cursor_pointer()
+                            // Click selects; double-click submits like Enter
+                            .on_click(move |event: &gpui::ClickEvent, _window, cx| {
+                                click_entity.update(cx, |this, cx| {
+                                    this.select_index(ix, cx);
+                                    if let gpui::ClickEvent::Mouse(mouse_event) = event {
+                                        if mouse_event.down.click_count == 2 {
+                                            this.handle_enter(cx);
+                                        }
+                                    }
+                                });
+                            })
+                            // Right-click selects and opens the actions dialog
+                            .on_mouse_down(gpui::MouseButton::Right, move |_event, _window, cx| {
+                                context_entity.update(cx, |this, cx| {
+                                    this.select_index(ix, cx);
+                                    this.show_actions(cx);
+                                });
+                            })
+                            .child(
+                                ListItem::new(name.clone(), list_colors)
+                                    .index(ix)
+                                    .icon_kind(icon)
+                                    .description_opt(description)
+                                    .selected(is_selected)
+                                    .with_accent_bar(true),
+                            )
                             .into_any_element()
                     })
                     .collect()
@@ -578,6 +824,89 @@ impl Render for PathPrompt {
         .flex_1()
         .w_full();
 
+        // Right-hand preview panel for the selected file (json/csv/md/png)
+        let preview_colors = if self.design_variant == DesignVariant::Default {
+            PreviewColors {
+                text: self.theme.colors.text.secondary,
+                muted: self.theme.colors.text.muted,
+                border: self.theme.colors.ui.border,
+                accent: self.theme.colors.accent.selected,
+            }
+        } else {
+            PreviewColors {
+                text: design_colors.text_secondary,
+                muted: design_colors.text_muted,
+                border: design_colors.border,
+                accent: design_colors.accent,
+            }
+        };
+
+        let preview_panel = self
+            .preview_cache
+            .as_ref()
+            .and_then(|(_, preview)| preview.as_ref())
+            .map(|preview| {
+                div()
+                    .w(px(280.0))
+                    .flex_none()
+                    .h_full()
+                    .overflow_hidden()
+                    .border_l_1()
+                    .border_color(gpui::rgb(preview_colors.border))
+                    .p_2()
+                    .child(file_preview::render_preview(preview, preview_colors))
+            })
+            // Previews load on the task pool; hold the panel's width with a
+            // placeholder while one is in flight
+            .or_else(|| {
+                self.preview_loading.is_some().then(|| {
+                    div()
+                        .w(px(280.0))
+                        .flex_none()
+                        .h_full()
+                        .border_l_1()
+                        .border_color(gpui::rgb(preview_colors.border))
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .text_sm()
+                        .text_color(gpui::rgb(preview_colors.muted))
+                        .child("Loading preview…")
+                })
+            });
+
+        // Directory listings load on the task pool; show a lightweight
+        // placeholder instead of an empty list while one is in flight
+        let list_element: gpui::AnyElement = if self.loading {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(gpui::rgb(list_colors.text_muted))
+                .child("Loading…")
+                .into_any_element()
+        } else {
+            list.into_any_element()
+        };
+
+        // List and optional preview side by side
+        let content = div()
+            .flex()
+            .flex_row()
+            .size_full()
+            .min_h(px(0.))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_w(px(0.))
+                    .child(list_element),
+            )
+            .when_some(preview_panel, |d, panel| d.child(panel));
+
         // Get entity handles for click callbacks
         let handle_select = cx.entity().downgrade();
         let handle_actions = cx.entity().downgrade();
@@ -648,6 +977,25 @@ impl Render for PathPrompt {
             .show_divider(true)
             .hint(hint_text);
 
+        // Wheel scrolling mirrors keyboard navigation: index-based movement
+        // through the filtered entries
+        let handle_wheel = cx.listener(
+            |this: &mut Self, event: &gpui::ScrollWheelEvent, _window, cx| {
+                let lines = match event.delta {
+                    gpui::ScrollDelta::Lines(point) => point.y,
+                    gpui::ScrollDelta::Pixels(point) => {
+                        let pixels: f32 = point.y.into();
+                        pixels / LIST_ITEM_HEIGHT
+                    }
+                };
+                // Invert: wheel up (positive delta) moves toward earlier items
+                let delta = -lines.round() as i32;
+                if delta != 0 {
+                    this.move_by(delta, cx);
+                }
+            },
+        );
+
         // Build the final container with the outer wrapper for key handling and focus
         div()
             .id(gpui::ElementId::Name("window:path".into()))
@@ -656,11 +1004,60 @@ impl Render for PathPrompt {
             .key_context("path_prompt")
             .track_focus(&self.focus_handle)
             .on_key_down(handle_key)
+            .on_scroll_wheel(handle_wheel)
             .child(
                 PromptContainer::new(container_colors)
                     .config(container_config)
                     .header(header)
-                    .content(list),
+                    .content(content),
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_default_hides_hidden_files() {
+        let options = PathPromptOptions::default();
+        assert!(!options.allows(".gitignore", false));
+        assert!(!options.allows(".config", true));
+        assert!(options.allows("readme.md", false));
+    }
+
+    #[test]
+    fn test_options_show_hidden() {
+        let options = PathPromptOptions {
+            show_hidden: true,
+            ..Default::default()
+        };
+        assert!(options.allows(".gitignore", false));
+        assert!(options.allows(".config", true));
+    }
+
+    #[test]
+    fn test_options_only_dirs_keeps_directories() {
+        let options = PathPromptOptions {
+            only_dirs: true,
+            ..Default::default()
+        };
+        assert!(options.allows("Documents", true));
+        assert!(!options.allows("file.txt", false));
+    }
+
+    #[test]
+    fn test_options_extension_filter() {
+        let options = PathPromptOptions {
+            extensions: vec!["png".to_string(), "jpg".to_string()],
+            ..Default::default()
+        };
+        assert!(options.allows("photo.png", false));
+        // Extension matching is case-insensitive
+        assert!(options.allows("photo.JPG", false));
+        assert!(!options.allows("notes.txt", false));
+        assert!(!options.allows("no-extension", false));
+        // Directories still shown for navigation
+        assert!(options.allows("Pictures", true));
+    }
+}
@@ -0,0 +1,568 @@
+//! TablePrompt - Tabular data with sortable columns
+//!
+//! Features:
+//! - Rows/columns provided by the script as JSON
+//! - Sortable headers (click a header, or Cmd+S on the active column)
+//! - Column resizing (Cmd+Left / Cmd+Right adjusts the active column)
+//! - Fuzzy row filtering by typing
+//! - Enter returns the selected row(s) to the script as JSON objects
+
+use gpui::{
+    div, prelude::*, px, rgb, Context, FocusHandle, Focusable, MouseButton, Render, SharedString,
+    Window,
+};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use crate::designs::{get_tokens, DesignVariant};
+use crate::logging;
+use crate::theme;
+
+use super::SubmitCallback;
+
+/// Default column width in pixels before content-based sizing
+const DEFAULT_COL_WIDTH: f32 = 120.0;
+/// Column width bounds for resizing
+const MIN_COL_WIDTH: f32 = 60.0;
+const MAX_COL_WIDTH: f32 = 600.0;
+/// Width change per Cmd+Left / Cmd+Right press
+const RESIZE_STEP: f32 = 20.0;
+/// Approximate pixels per character for initial column sizing
+const CHAR_WIDTH: f32 = 8.0;
+
+/// Case-insensitive subsequence match ("usw2" matches "us-west-2")
+fn fuzzy_match(needle: &str, haystack: &str) -> bool {
+    let mut haystack_chars = haystack.chars();
+    needle.chars().all(|n| haystack_chars.any(|h| h == n))
+}
+
+/// Compare two cells numerically when both parse as numbers, else as strings
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a_num), Ok(b_num)) => a_num.partial_cmp(&b_num).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+/// TablePrompt - Tabular data with sortable columns and row selection
+///
+/// Rows are filtered by typing, sorted by clicking a header (or Cmd+S on
+/// the active column), and submitted as JSON objects keyed by column name.
+pub struct TablePrompt {
+    /// Unique ID for this prompt instance
+    pub id: String,
+    /// Placeholder text for the search input
+    pub placeholder: Option<String>,
+    /// Column headers, in display order
+    pub columns: Vec<String>,
+    /// Rows as cell strings aligned with `columns`
+    pub rows: Vec<Vec<String>>,
+    /// Current column widths in pixels
+    pub col_widths: Vec<f32>,
+    /// Column the keyboard currently targets (sorting/resizing)
+    pub active_col: usize,
+    /// Current sort: (column index, ascending)
+    pub sort: Option<(usize, bool)>,
+    /// Filtered + sorted row indices (for display)
+    pub filtered_rows: Vec<usize>,
+    /// Currently focused index in the filtered list
+    pub focused_index: usize,
+    /// Indices of selected rows (multiple mode)
+    pub selected: Vec<usize>,
+    /// Filter text
+    pub filter_text: String,
+    /// Whether multiple row selection is allowed
+    pub multiple: bool,
+    /// Focus handle for keyboard input
+    pub focus_handle: FocusHandle,
+    /// Callback when user submits
+    pub on_submit: SubmitCallback,
+    /// Theme for styling
+    pub theme: Arc<theme::Theme>,
+    /// Design variant for styling
+    pub design_variant: DesignVariant,
+}
+
+impl TablePrompt {
+    pub fn new(
+        id: String,
+        placeholder: Option<String>,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+        multiple: bool,
+        focus_handle: FocusHandle,
+        on_submit: SubmitCallback,
+        theme: Arc<theme::Theme>,
+    ) -> Self {
+        logging::log(
+            "PROMPTS",
+            &format!(
+                "TablePrompt::new with {} columns, {} rows (multiple: {})",
+                columns.len(),
+                rows.len(),
+                multiple
+            ),
+        );
+
+        // Size each column to its widest cell (header included), clamped
+        let col_widths: Vec<f32> = columns
+            .iter()
+            .enumerate()
+            .map(|(col, header)| {
+                let widest = rows
+                    .iter()
+                    .filter_map(|row| row.get(col))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(header.chars().count());
+                (widest as f32 * CHAR_WIDTH)
+                    .max(DEFAULT_COL_WIDTH.min(MAX_COL_WIDTH))
+                    .clamp(MIN_COL_WIDTH, MAX_COL_WIDTH)
+            })
+            .collect();
+
+        let filtered_rows: Vec<usize> = (0..rows.len()).collect();
+
+        TablePrompt {
+            id,
+            placeholder,
+            columns,
+            rows,
+            col_widths,
+            active_col: 0,
+            sort: None,
+            filtered_rows,
+            focused_index: 0,
+            selected: Vec::new(),
+            filter_text: String::new(),
+            multiple,
+            focus_handle,
+            on_submit,
+            theme,
+            design_variant: DesignVariant::Default,
+        }
+    }
+
+    /// Refilter and re-sort rows based on filter_text and sort state
+    fn refilter(&mut self) {
+        let filter_lower = self.filter_text.to_lowercase();
+        self.filtered_rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                filter_lower.is_empty() || {
+                    let haystack = row.join(" ").to_lowercase();
+                    fuzzy_match(&filter_lower, &haystack)
+                }
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        if let Some((col, ascending)) = self.sort {
+            self.filtered_rows.sort_by(|&a, &b| {
+                let empty = String::new();
+                let cell_a = self.rows[a].get(col).unwrap_or(&empty);
+                let cell_b = self.rows[b].get(col).unwrap_or(&empty);
+                let ordering = compare_cells(cell_a, cell_b);
+                if ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+        }
+
+        self.focused_index = 0;
+    }
+
+    /// Set the filter text programmatically
+    pub fn set_input(&mut self, text: String, cx: &mut Context<Self>) {
+        if self.filter_text == text {
+            return;
+        }
+
+        self.filter_text = text;
+        self.refilter();
+        cx.notify();
+    }
+
+    /// Cycle sort on a column: ascending, descending, then unsorted
+    fn toggle_sort(&mut self, col: usize, cx: &mut Context<Self>) {
+        self.active_col = col;
+        self.sort = match self.sort {
+            Some((sorted_col, true)) if sorted_col == col => Some((col, false)),
+            Some((sorted_col, false)) if sorted_col == col => None,
+            _ => Some((col, true)),
+        };
+        self.refilter();
+        cx.notify();
+    }
+
+    /// Resize the active column by `delta` pixels
+    fn resize_active_col(&mut self, delta: f32, cx: &mut Context<Self>) {
+        if let Some(width) = self.col_widths.get_mut(self.active_col) {
+            *width = (*width + delta).clamp(MIN_COL_WIDTH, MAX_COL_WIDTH);
+            cx.notify();
+        }
+    }
+
+    /// Move the active column left/right
+    fn move_active_col(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let col_count = self.columns.len();
+        if col_count == 0 {
+            return;
+        }
+        let next = self.active_col as isize + delta;
+        self.active_col = next.clamp(0, col_count as isize - 1) as usize;
+        cx.notify();
+    }
+
+    /// Toggle selection of the currently focused row
+    fn toggle_selection(&mut self, cx: &mut Context<Self>) {
+        if let Some(&row_idx) = self.filtered_rows.get(self.focused_index) {
+            if self.multiple {
+                if let Some(pos) = self.selected.iter().position(|&x| x == row_idx) {
+                    self.selected.remove(pos);
+                } else {
+                    self.selected.push(row_idx);
+                }
+            } else {
+                self.selected = vec![row_idx];
+            }
+            cx.notify();
+        }
+    }
+
+    /// Convert one row to a JSON object keyed by column name
+    fn row_to_json(&self, row_idx: usize) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        if let Some(row) = self.rows.get(row_idx) {
+            for (col, header) in self.columns.iter().enumerate() {
+                let cell = row.get(col).cloned().unwrap_or_default();
+                object.insert(header.clone(), serde_json::Value::String(cell));
+            }
+        }
+        serde_json::Value::Object(object)
+    }
+
+    /// Submit selected rows (or the focused row) as JSON
+    fn submit(&mut self) {
+        let row_indices: Vec<usize> = if !self.selected.is_empty() {
+            self.selected.clone()
+        } else {
+            // Nothing toggled - submit the focused row
+            self.filtered_rows
+                .get(self.focused_index)
+                .copied()
+                .into_iter()
+                .collect()
+        };
+
+        let value = if self.multiple {
+            serde_json::Value::Array(row_indices.iter().map(|&i| self.row_to_json(i)).collect())
+        } else {
+            match row_indices.first() {
+                Some(&idx) => self.row_to_json(idx),
+                None => serde_json::Value::Null,
+            }
+        };
+
+        let json_str = serde_json::to_string(&value).unwrap_or_else(|_| "null".to_string());
+        (self.on_submit)(self.id.clone(), Some(json_str));
+    }
+
+    /// Cancel - submit None
+    fn submit_cancel(&mut self) {
+        (self.on_submit)(self.id.clone(), None);
+    }
+
+    /// Move focus up
+    fn move_up(&mut self, cx: &mut Context<Self>) {
+        if self.focused_index > 0 {
+            self.focused_index -= 1;
+            cx.notify();
+        }
+    }
+
+    /// Move focus down
+    fn move_down(&mut self, cx: &mut Context<Self>) {
+        if self.focused_index < self.filtered_rows.len().saturating_sub(1) {
+            self.focused_index += 1;
+            cx.notify();
+        }
+    }
+
+    /// Handle character input
+    fn handle_char(&mut self, ch: char, cx: &mut Context<Self>) {
+        self.filter_text.push(ch);
+        self.refilter();
+        cx.notify();
+    }
+
+    /// Handle backspace
+    fn handle_backspace(&mut self, cx: &mut Context<Self>) {
+        if !self.filter_text.is_empty() {
+            self.filter_text.pop();
+            self.refilter();
+            cx.notify();
+        }
+    }
+}
+
+impl Focusable for TablePrompt {
+    fn focus_handle(&self, _cx: &gpui::App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TablePrompt {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tokens = get_tokens(self.design_variant);
+        let colors = tokens.colors();
+        let spacing = tokens.spacing();
+        let visual = tokens.visual();
+
+        let handle_key = cx.listener(
+            |this: &mut Self,
+             event: &gpui::KeyDownEvent,
+             _window: &mut Window,
+             cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform; // Cmd on macOS
+
+                if has_cmd {
+                    match key_str.as_str() {
+                        "left" | "arrowleft" => this.resize_active_col(-RESIZE_STEP, cx),
+                        "right" | "arrowright" => this.resize_active_col(RESIZE_STEP, cx),
+                        "s" => {
+                            let col = this.active_col;
+                            this.toggle_sort(col, cx);
+                        }
+                        _ => {}
+                    }
+                    return;
+                }
+
+                match key_str.as_str() {
+                    "up" | "arrowup" => this.move_up(cx),
+                    "down" | "arrowdown" => this.move_down(cx),
+                    "left" | "arrowleft" => this.move_active_col(-1, cx),
+                    "right" | "arrowright" => this.move_active_col(1, cx),
+                    "space" | " " => this.toggle_selection(cx),
+                    "enter" => this.submit(),
+                    "escape" => this.submit_cancel(),
+                    "backspace" => this.handle_backspace(cx),
+                    _ => {
+                        if let Some(ref key_char) = event.keystroke.key_char {
+                            if let Some(ch) = key_char.chars().next() {
+                                if !ch.is_control() && ch != ' ' {
+                                    this.handle_char(ch, cx);
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let (main_bg, text_color, muted_color, border_color) =
+            if self.design_variant == DesignVariant::Default {
+                (
+                    rgb(self.theme.colors.background.main),
+                    rgb(self.theme.colors.text.secondary),
+                    rgb(self.theme.colors.text.muted),
+                    rgb(self.theme.colors.ui.border),
+                )
+            } else {
+                (
+                    rgb(colors.background),
+                    rgb(colors.text_secondary),
+                    rgb(colors.text_muted),
+                    rgb(colors.border),
+                )
+            };
+        let accent_color = rgb(self.theme.colors.accent.selected);
+
+        let placeholder = self
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| "Filter rows...".to_string());
+
+        let input_display = if self.filter_text.is_empty() {
+            SharedString::from(placeholder)
+        } else {
+            SharedString::from(self.filter_text.clone())
+        };
+
+        // Search input
+        let input_container = div()
+            .id(gpui::ElementId::Name("input:table-filter".into()))
+            .w_full()
+            .px(px(spacing.item_padding_x))
+            .py(px(spacing.padding_md))
+            .bg(rgb(self.theme.colors.background.search_box))
+            .border_b_1()
+            .border_color(border_color)
+            .flex()
+            .flex_row()
+            .gap_2()
+            .items_center()
+            .child(div().text_color(muted_color).child("🔍"))
+            .child(
+                div()
+                    .flex_1()
+                    .text_color(if self.filter_text.is_empty() {
+                        muted_color
+                    } else {
+                        text_color
+                    })
+                    .child(input_display),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(muted_color)
+                    .child(format!("{} rows", self.filtered_rows.len())),
+            );
+
+        // Header row with sortable, resizable columns
+        let mut header_row = div()
+            .id(gpui::ElementId::Name("row:table-header".into()))
+            .w_full()
+            .px(px(spacing.item_padding_x))
+            .py(px(spacing.item_padding_y))
+            .border_b_1()
+            .border_color(border_color)
+            .flex()
+            .flex_row()
+            .gap_2();
+
+        for (col, header) in self.columns.iter().enumerate() {
+            let width = self
+                .col_widths
+                .get(col)
+                .copied()
+                .unwrap_or(DEFAULT_COL_WIDTH);
+            let is_active = col == self.active_col;
+            let sort_marker = match self.sort {
+                Some((sorted_col, true)) if sorted_col == col => " ▲",
+                Some((sorted_col, false)) if sorted_col == col => " ▼",
+                _ => "",
+            };
+
+            header_row = header_row.child(
+                div()
+                    .id(gpui::ElementId::Name(
+                        format!("header:table-col-{}", col).into(),
+                    ))
+                    .w(px(width))
+                    .flex_none()
+                    .overflow_hidden()
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(if is_active { accent_color } else { text_color })
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, _event, _window, cx| {
+                            this.toggle_sort(col, cx);
+                        }),
+                    )
+                    .child(format!("{}{}", header, sort_marker)),
+            );
+        }
+
+        // Data rows
+        let mut rows_container = div()
+            .id(gpui::ElementId::Name("list:table-rows".into()))
+            .flex()
+            .flex_col()
+            .flex_1()
+            .w_full()
+            .overflow_y_hidden();
+
+        if self.filtered_rows.is_empty() {
+            rows_container = rows_container.child(
+                div()
+                    .w_full()
+                    .py(px(spacing.padding_xl))
+                    .px(px(spacing.item_padding_x))
+                    .text_color(muted_color)
+                    .child("No rows match your filter"),
+            );
+        } else {
+            for (display_idx, &row_idx) in self.filtered_rows.iter().enumerate() {
+                let Some(row) = self.rows.get(row_idx) else {
+                    continue;
+                };
+                let is_focused = display_idx == self.focused_index;
+                let is_selected = self.selected.contains(&row_idx);
+
+                let bg = if is_focused { accent_color } else { main_bg };
+
+                let mut row_div = div()
+                    .id(gpui::ElementId::Name(
+                        format!("row:table-{}", display_idx).into(),
+                    ))
+                    .w_full()
+                    .px(px(spacing.item_padding_x))
+                    .py(px(spacing.item_padding_y))
+                    .bg(bg)
+                    .border_b_1()
+                    .border_color(border_color)
+                    .rounded(px(visual.radius_sm))
+                    .flex()
+                    .flex_row()
+                    .gap_2()
+                    .items_center();
+
+                if self.multiple {
+                    let checkbox = if is_selected { "☑" } else { "☐" };
+                    row_div = row_div.child(
+                        div()
+                            .text_color(if is_selected {
+                                accent_color
+                            } else {
+                                muted_color
+                            })
+                            .child(checkbox),
+                    );
+                }
+
+                for (col, cell) in row.iter().enumerate() {
+                    let width = self
+                        .col_widths
+                        .get(col)
+                        .copied()
+                        .unwrap_or(DEFAULT_COL_WIDTH);
+                    row_div = row_div.child(
+                        div()
+                            .w(px(width))
+                            .flex_none()
+                            .overflow_hidden()
+                            .text_color(text_color)
+                            .child(cell.clone()),
+                    );
+                }
+
+                rows_container = rows_container.child(row_div);
+            }
+        }
+
+        div()
+            .id(gpui::ElementId::Name("window:table".into()))
+            .flex()
+            .flex_col()
+            .w_full()
+            .h_full()
+            .bg(main_bg)
+            .text_color(text_color)
+            .key_context("table_prompt")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            .child(input_container)
+            .child(header_row)
+            .child(rows_container)
+    }
+}
@@ -4,6 +4,8 @@
 //! - `parse_message` / `parse_message_graceful` for parsing JSON messages
 //! - `serialize_message` for serializing messages to JSON
 //! - `JsonlReader` for streaming JSONL reads
+//! - Length-prefixed binary frames (`@@frame:<len>`) so large payloads
+//!   (editor content, base64 images) are not limited by line buffering
 
 use std::io::{BufRead, BufReader, Read};
 use tracing::{debug, warn};
@@ -195,6 +197,91 @@ pub fn serialize_message(msg: &Message) -> Result<String, serde_json::Error> {
     serde_json::to_string(msg)
 }
 
+/// Header prefix for length-prefixed binary frames
+///
+/// A frame is `@@frame:<byte-len>\n` followed by exactly `<byte-len>` bytes of
+/// JSON payload and a trailing newline. Frames let large payloads (editor
+/// content, base64 images) bypass line buffering entirely - the payload bytes
+/// are read with `read_exact`, so embedded newlines are safe.
+///
+/// The prefix cannot collide with JSONL because JSON messages always start
+/// with `{`. Peers advertise support via the "binary-frames" capability in the
+/// hello handshake; the reader accepts frames unconditionally.
+pub const FRAME_PREFIX: &str = "@@frame:";
+
+/// Byte-length threshold above which `serialize_message_auto` switches to a frame
+pub const FRAME_THRESHOLD: usize = 32 * 1024;
+
+/// Maximum accepted frame payload size (guards against corrupt length headers)
+pub const MAX_FRAME_BYTES: usize = 64 * 1024 * 1024;
+
+/// Wrap raw payload bytes in a frame header and trailing newline
+pub fn frame_payload(payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(FRAME_PREFIX.len() + 24 + payload.len());
+    out.extend_from_slice(FRAME_PREFIX.as_bytes());
+    out.extend_from_slice(payload.len().to_string().as_bytes());
+    out.push(b'\n');
+    out.extend_from_slice(payload);
+    out.push(b'\n');
+    out
+}
+
+/// Serialize a message as a length-prefixed binary frame
+pub fn serialize_message_framed(msg: &Message) -> Result<Vec<u8>, serde_json::Error> {
+    let json = serde_json::to_vec(msg)?;
+    Ok(frame_payload(&json))
+}
+
+/// Serialize a message, framing it only when the payload exceeds `FRAME_THRESHOLD`
+///
+/// Small messages stay plain JSONL (newline-terminated) so logs and simple
+/// consumers keep working. Only use framed output after the peer advertised
+/// the "binary-frames" capability during the hello handshake.
+pub fn serialize_message_auto(msg: &Message) -> Result<Vec<u8>, serde_json::Error> {
+    let json = serde_json::to_vec(msg)?;
+    if json.len() > FRAME_THRESHOLD {
+        Ok(frame_payload(&json))
+    } else {
+        let mut out = json;
+        out.push(b'\n');
+        Ok(out)
+    }
+}
+
+/// Parse a frame header line of the form `@@frame:<byte-len>`
+///
+/// # Returns
+/// * `None` - the line is not a frame header (treat it as plain JSONL)
+/// * `Some(Ok(len))` - valid header with payload length
+/// * `Some(Err(msg))` - header-shaped line with a bad or oversized length
+fn parse_frame_header(line: &str) -> Option<Result<usize, String>> {
+    let rest = line.strip_prefix(FRAME_PREFIX)?;
+    Some(match rest.trim().parse::<usize>() {
+        Ok(len) if len <= MAX_FRAME_BYTES => Ok(len),
+        Ok(len) => Err(format!(
+            "frame length {} exceeds maximum {}",
+            len, MAX_FRAME_BYTES
+        )),
+        Err(e) => Err(format!("invalid frame length '{}': {}", rest.trim(), e)),
+    })
+}
+
+/// Read exactly `len` frame payload bytes, then consume the trailing newline
+fn read_frame_payload<R: Read>(
+    reader: &mut BufReader<R>,
+    len: usize,
+) -> Result<Vec<u8>, std::io::Error> {
+    let mut payload = vec![0u8; len];
+    reader.read_exact(&mut payload)?;
+    // Consume the newline the writer appends after the payload. Peek via
+    // fill_buf so a malformed frame never steals a byte from the next message.
+    let buf = reader.fill_buf()?;
+    if buf.first() == Some(&b'\n') {
+        reader.consume(1);
+    }
+    Ok(payload)
+}
+
 /// JSONL reader for streaming/chunked message reads
 ///
 /// Provides utilities to read messages one at a time from a reader.
@@ -241,6 +328,14 @@ impl<R: Read> JsonlReader<R> {
                         debug!("Skipping empty line in JSONL stream");
                         continue; // Skip empty lines (loop instead of recursion)
                     }
+                    if let Some(header) = parse_frame_header(trimmed) {
+                        let len = header?;
+                        debug!(len, "Reading binary frame payload");
+                        let payload = read_frame_payload(&mut self.reader, len)?;
+                        let payload = String::from_utf8(payload)?;
+                        let msg = parse_message(payload.trim())?;
+                        return Ok(Some(msg));
+                    }
                     let msg = parse_message(trimmed)?;
                     return Ok(Some(msg));
                 }
@@ -289,10 +384,56 @@ impl<R: Read> JsonlReader<R> {
                         continue;
                     }
 
+                    // Binary frames: read the exact payload bytes, then classify
+                    // the JSON inside just like a plain line
+                    let framed_payload: Option<String> = match parse_frame_header(trimmed) {
+                        Some(Ok(len)) => {
+                            debug!(len, "Reading binary frame payload");
+                            let bytes = read_frame_payload(&mut self.reader, len)?;
+                            match String::from_utf8(bytes) {
+                                Ok(payload) => Some(payload),
+                                Err(e) => {
+                                    let issue = ParseIssue::new(
+                                        ParseIssueKind::ParseError,
+                                        None,
+                                        Some(e.to_string()),
+                                        trimmed.to_string(),
+                                        len,
+                                    );
+                                    warn!(
+                                        correlation_id = %issue.correlation_id,
+                                        error = %e,
+                                        "Skipping binary frame with non-UTF-8 payload"
+                                    );
+                                    on_issue(issue);
+                                    continue;
+                                }
+                            }
+                        }
+                        Some(Err(e)) => {
+                            let issue = ParseIssue::new(
+                                ParseIssueKind::ParseError,
+                                None,
+                                Some(e.clone()),
+                                trimmed.to_string(),
+                                trimmed.len(),
+                            );
+                            warn!(
+                                correlation_id = %issue.correlation_id,
+                                error = %e,
+                                "Skipping binary frame with malformed header"
+                            );
+                            on_issue(issue);
+                            continue;
+                        }
+                        None => None,
+                    };
+                    let payload = framed_payload.as_deref().map(str::trim).unwrap_or(trimmed);
+
                     // Get preview for logging (security: truncate large payloads)
-                    let (preview, raw_len) = log_preview(trimmed);
+                    let (preview, raw_len) = log_preview(payload);
 
-                    match parse_message_graceful(trimmed) {
+                    match parse_message_graceful(payload) {
                         ParseResult::Ok(msg) => {
                             debug!(message_id = ?msg.id(), "Successfully parsed message");
                             return Ok(Some(msg));
@@ -411,6 +552,414 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_paginated_arg_messages() {
+        // Arg with pagination flags
+        let json = r#"{"type":"arg","id":"1","placeholder":"Pick","choices":[],"sort":false,"hasMore":true}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Arg { sort, has_more, .. }) => {
+                assert_eq!(sort, Some(false));
+                assert_eq!(has_more, Some(true));
+            }
+            _ => panic!("Expected ParseResult::Ok with Arg message"),
+        }
+
+        // Choices page appended by the script
+        let json = r#"{"type":"choicesPage","id":"1","choices":[{"name":"A","value":"a"}],"hasMore":false}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::ChoicesPage {
+                id,
+                choices,
+                has_more,
+            }) => {
+                assert_eq!(id, "1");
+                assert_eq!(choices.len(), 1);
+                assert!(!has_more);
+            }
+            _ => panic!("Expected ParseResult::Ok with ChoicesPage message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_path_message_with_options() {
+        let json =
+            r#"{"type":"path","id":"1","onlyDirs":true,"extensions":["md"],"showHidden":true}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Path {
+                id,
+                only_dirs,
+                extensions,
+                show_hidden,
+                ..
+            }) => {
+                assert_eq!(id, "1");
+                assert_eq!(only_dirs, Some(true));
+                assert_eq!(extensions, vec!["md".to_string()]);
+                assert_eq!(show_hidden, Some(true));
+            }
+            _ => panic!("Expected ParseResult::Ok with Path message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_path_message_options_default() {
+        // Options are all optional - a bare path message still parses
+        let json = r#"{"type":"path","id":"1"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Path {
+                only_dirs,
+                extensions,
+                show_hidden,
+                ..
+            }) => {
+                assert_eq!(only_dirs, None);
+                assert!(extensions.is_empty());
+                assert_eq!(show_hidden, None);
+            }
+            _ => panic!("Expected ParseResult::Ok with Path message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_open_file_message() {
+        let json = r#"{"type":"openFile","id":"1","extensions":["png","jpg"],"onlyDirs":false}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::OpenFile {
+                id,
+                extensions,
+                only_dirs,
+                ..
+            }) => {
+                assert_eq!(id, "1");
+                assert_eq!(extensions, vec!["png".to_string(), "jpg".to_string()]);
+                assert_eq!(only_dirs, Some(false));
+            }
+            _ => panic!("Expected ParseResult::Ok with OpenFile message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_save_file_message() {
+        let json = r#"{"type":"saveFile","id":"1","defaultFilename":"notes.md"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::SaveFile {
+                id,
+                default_filename,
+                extensions,
+                ..
+            }) => {
+                assert_eq!(id, "1");
+                assert_eq!(default_filename.as_deref(), Some("notes.md"));
+                assert!(extensions.is_empty());
+            }
+            _ => panic!("Expected ParseResult::Ok with SaveFile message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_get_message() {
+        let json = r#"{"type":"dbGet","requestId":"req-1","key":"count"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::DbGet { request_id, key }) => {
+                assert_eq!(request_id, "req-1");
+                assert_eq!(key, "count");
+            }
+            _ => panic!("Expected ParseResult::Ok with DbGet message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_set_message_with_json_value() {
+        let json = r#"{"type":"dbSet","requestId":"req-2","key":"user","value":{"name":"ada"}}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::DbSet {
+                request_id,
+                key,
+                value,
+            }) => {
+                assert_eq!(request_id, "req-2");
+                assert_eq!(key, "user");
+                assert_eq!(value["name"], "ada");
+            }
+            _ => panic!("Expected ParseResult::Ok with DbSet message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_fetch_message() {
+        let json = r#"{"type":"fetch","requestId":"req-9","url":"https://api.example.com/items","method":"POST","headers":{"Content-Type":"application/json"},"body":"{}"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Fetch {
+                request_id,
+                url,
+                method,
+                headers,
+                body,
+            }) => {
+                assert_eq!(request_id, "req-9");
+                assert_eq!(url, "https://api.example.com/items");
+                assert_eq!(method.as_deref(), Some("POST"));
+                assert_eq!(
+                    headers.unwrap().get("Content-Type").map(|s| s.as_str()),
+                    Some("application/json")
+                );
+                assert_eq!(body.as_deref(), Some("{}"));
+            }
+            _ => panic!("Expected ParseResult::Ok with Fetch message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_fetch_message_defaults() {
+        let json = r#"{"type":"fetch","requestId":"req-10","url":"https://example.com"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Fetch {
+                method,
+                headers,
+                body,
+                ..
+            }) => {
+                assert!(method.is_none());
+                assert!(headers.is_none());
+                assert!(body.is_none());
+            }
+            _ => panic!("Expected ParseResult::Ok with Fetch message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_dnd_message() {
+        let json = r#"{"type":"dnd","requestId":"req-11","enabled":true}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Dnd {
+                request_id,
+                enabled,
+            }) => {
+                assert_eq!(request_id, "req-11");
+                assert!(enabled);
+            }
+            _ => panic!("Expected ParseResult::Ok with Dnd message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_db_delete_message() {
+        let json = r#"{"type":"dbDelete","requestId":"req-3","key":"count"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::DbDelete { request_id, key }) => {
+                assert_eq!(request_id, "req-3");
+                assert_eq!(key, "count");
+            }
+            _ => panic!("Expected ParseResult::Ok with DbDelete message"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_db_result_omits_empty_fields() {
+        let msg = Message::db_success("req-4".to_string());
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"dbResult""#));
+        assert!(json.contains(r#""requestId":"req-4""#));
+        assert!(!json.contains("value"));
+        assert!(!json.contains("error"));
+    }
+
+    #[test]
+    fn test_parse_run_message() {
+        let json = r#"{"type":"run","requestId":"req-5","name":"git-commit"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Run {
+                request_id,
+                name,
+                cwd,
+            }) => {
+                assert_eq!(request_id, "req-5");
+                assert_eq!(name, "git-commit");
+                assert_eq!(cwd, None);
+            }
+            _ => panic!("Expected ParseResult::Ok with Run message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_run_message_with_cwd() {
+        let json = r#"{"type":"run","requestId":"req-6","name":"deploy","cwd":"~/projects/foo"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Run { cwd, .. }) => {
+                assert_eq!(cwd.as_deref(), Some("~/projects/foo"));
+            }
+            _ => panic!("Expected ParseResult::Ok with Run message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_result_message() {
+        let json = r#"{"type":"result","value":{"count":3,"ok":true}}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::ScriptResult { value }) => {
+                assert_eq!(value["count"], 3);
+                assert_eq!(value["ok"], true);
+            }
+            _ => panic!("Expected ParseResult::Ok with ScriptResult message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_log_message() {
+        let json =
+            r#"{"type":"log","level":"warn","text":"low disk","file":"/tmp/check.ts","line":8}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Log {
+                level,
+                text,
+                file,
+                line,
+            }) => {
+                assert_eq!(level.as_deref(), Some("warn"));
+                assert_eq!(text, "low disk");
+                assert_eq!(file.as_deref(), Some("/tmp/check.ts"));
+                assert_eq!(line, Some(8));
+            }
+            _ => panic!("Expected ParseResult::Ok with Log message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_log_message_minimal() {
+        // Level and source mapping are optional
+        let json = r#"{"type":"log","text":"hello"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Log {
+                level,
+                text,
+                file,
+                line,
+            }) => {
+                assert_eq!(level, None);
+                assert_eq!(text, "hello");
+                assert_eq!(file, None);
+                assert_eq!(line, None);
+            }
+            _ => panic!("Expected ParseResult::Ok with Log message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_number_message() {
+        let json = r#"{"type":"number","id":"n1","min":1.0,"max":10.0,"step":0.5}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Number {
+                id,
+                min,
+                max,
+                step,
+                value,
+                ..
+            }) => {
+                assert_eq!(id, "n1");
+                assert_eq!(min, Some(1.0));
+                assert_eq!(max, Some(10.0));
+                assert_eq!(step, Some(0.5));
+                assert_eq!(value, None);
+            }
+            _ => panic!("Expected ParseResult::Ok with Number message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_date_message() {
+        let json = r#"{"type":"date","id":"d1","mode":"time","value":"5:30pm"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Date {
+                id, mode, value, ..
+            }) => {
+                assert_eq!(id, "d1");
+                assert_eq!(mode.as_deref(), Some("time"));
+                assert_eq!(value.as_deref(), Some("5:30pm"));
+            }
+            _ => panic!("Expected ParseResult::Ok with Date message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_run_shortcut_message() {
+        let json = r#"{"type":"shortcuts.run","requestId":"s1","name":"Log Water","input":"8oz"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::RunShortcut {
+                request_id,
+                name,
+                input,
+            }) => {
+                assert_eq!(request_id, "s1");
+                assert_eq!(name, "Log Water");
+                assert_eq!(input.as_deref(), Some("8oz"));
+            }
+            _ => panic!("Expected ParseResult::Ok with RunShortcut message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_ocr_message() {
+        let json = r#"{"type":"ocr","requestId":"o1","path":"/tmp/shot.png"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Ocr { request_id, path }) => {
+                assert_eq!(request_id, "o1");
+                assert_eq!(path.as_deref(), Some("/tmp/shot.png"));
+            }
+            _ => panic!("Expected ParseResult::Ok with Ocr message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_hello_message() {
+        let json = r#"{"type":"hello","protocolVersion":1,"sdk":"sdk-ts/1.4.2"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Hello {
+                protocol_version,
+                sdk,
+            }) => {
+                assert_eq!(protocol_version, 1);
+                assert_eq!(sdk.as_deref(), Some("sdk-ts/1.4.2"));
+            }
+            _ => panic!("Expected ParseResult::Ok with Hello message"),
+        }
+
+        // The sdk field is optional
+        let json = r#"{"type":"hello","protocolVersion":2}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Hello {
+                protocol_version,
+                sdk,
+            }) => {
+                assert_eq!(protocol_version, 2);
+                assert_eq!(sdk, None);
+            }
+            _ => panic!("Expected ParseResult::Ok with Hello message"),
+        }
+    }
+
+    #[test]
+    fn test_hello_result_roundtrip() {
+        use super::super::message::{CAPABILITIES, PROTOCOL_VERSION};
+
+        let msg = Message::hello_result();
+        let json = serialize_message(&msg).unwrap();
+        assert!(json.contains(r#""type":"helloResult""#));
+        assert!(json.contains(r#""protocolVersion""#));
+
+        match parse_message_graceful(&json) {
+            ParseResult::Ok(Message::HelloResult {
+                protocol_version,
+                capabilities,
+            }) => {
+                assert_eq!(protocol_version, PROTOCOL_VERSION);
+                assert_eq!(capabilities.len(), CAPABILITIES.len());
+                assert!(capabilities.iter().any(|c| c == "prompts"));
+            }
+            _ => panic!("Expected ParseResult::Ok with HelloResult message"),
+        }
+    }
+
     #[test]
     fn test_parse_message_graceful_unknown_type() {
         let json = r#"{"type":"futureFeature","id":"1","data":"test"}"#;
@@ -533,6 +1082,85 @@ mod tests {
             .contains("placeholder"));
     }
 
+    #[test]
+    fn test_jsonl_reader_reads_binary_frames() {
+        use std::io::Cursor;
+
+        // Pretty-printed JSON spans multiple lines - only a frame can carry it
+        let payload = b"{\n  \"type\": \"show\"\n}";
+        let mut stream = Vec::new();
+        stream.extend_from_slice(b"{\"type\":\"beep\"}\n");
+        stream.extend_from_slice(&frame_payload(payload));
+        stream.extend_from_slice(b"{\"type\":\"beep\"}\n");
+
+        let mut reader = JsonlReader::new(Cursor::new(stream));
+        let msg1 = reader.next_message().unwrap();
+        assert!(matches!(msg1, Some(Message::Beep {})));
+        let msg2 = reader.next_message().unwrap();
+        assert!(matches!(msg2, Some(Message::Show {})));
+        let msg3 = reader.next_message().unwrap();
+        assert!(matches!(msg3, Some(Message::Beep {})));
+        assert!(reader.next_message().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_jsonl_reader_graceful_skips_bad_frame_headers() {
+        use std::io::Cursor;
+
+        let stream = format!(
+            "{}not-a-number\n{}{}\n{{\"type\":\"beep\"}}\n",
+            FRAME_PREFIX,
+            FRAME_PREFIX,
+            MAX_FRAME_BYTES + 1
+        );
+        let mut reader = JsonlReader::new(Cursor::new(stream));
+        let mut issues: Vec<ParseIssue> = Vec::new();
+
+        let msg = reader
+            .next_message_graceful_with_handler(|issue| issues.push(issue))
+            .unwrap();
+
+        assert!(matches!(msg, Some(Message::Beep {})));
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].kind, ParseIssueKind::ParseError);
+        assert!(issues[0]
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("invalid frame length"));
+        assert!(issues[1]
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("exceeds maximum"));
+    }
+
+    #[test]
+    fn test_serialize_message_auto_frames_large_payloads() {
+        use std::io::Cursor;
+
+        // Small messages stay plain JSONL
+        let small = serialize_message_auto(&Message::Beep {}).unwrap();
+        assert!(!small.starts_with(FRAME_PREFIX.as_bytes()));
+        assert!(small.ends_with(b"\n"));
+
+        // A payload over the threshold gets framed and roundtrips intact
+        let big = "x".repeat(FRAME_THRESHOLD + 1);
+        let json = format!(
+            r#"{{"type":"arg","id":"1","placeholder":"{}","choices":[]}}"#,
+            big
+        );
+        let msg = parse_message(&json).unwrap();
+        let out = serialize_message_auto(&msg).unwrap();
+        assert!(out.starts_with(FRAME_PREFIX.as_bytes()));
+
+        let mut reader = JsonlReader::new(Cursor::new(out));
+        match reader.next_message().unwrap() {
+            Some(Message::Arg { placeholder, .. }) => assert_eq!(placeholder, big),
+            other => panic!("Expected framed Arg message, got {:?}", other),
+        }
+    }
+
     // ============================================================
     // Debug Grid Message Tests
     // ============================================================
@@ -666,4 +1294,184 @@ mod tests {
             _ => panic!("Expected ShowGrid message"),
         }
     }
+
+    // ============================================================
+    // Conformance Fuzz Tests
+    // ============================================================
+    //
+    // Scripts write whatever they like to stdout, so the reader must
+    // survive arbitrary byte salad without panicking. These tests use a
+    // seeded xorshift PRNG (no proptest dependency) so failures are
+    // reproducible - if one trips, the seed pins the exact input.
+
+    /// Deterministic xorshift64* PRNG for reproducible fuzz inputs
+    fn next_rand(state: &mut u64) -> u64 {
+        let mut x = *state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        *state = x;
+        x.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// Generate a printable-ASCII garbage line that is never a frame header
+    fn garbage_line(state: &mut u64, len: usize) -> String {
+        let mut line = String::with_capacity(len);
+        for _ in 0..len {
+            // Printable ASCII including braces, quotes, colons, backslashes
+            let c = (0x20 + (next_rand(state) % 0x5F) as u8) as char;
+            line.push(c);
+        }
+        // Frame headers start with '@' - keep garbage unambiguously a line
+        if line.starts_with('@') {
+            line.replace_range(0..1, "#");
+        }
+        line
+    }
+
+    #[test]
+    fn test_fuzz_truncated_messages_never_panic() {
+        let samples = [
+            r#"{"type":"arg","id":"1","placeholder":"Pick","choices":[{"name":"A","value":"a"}]}"#,
+            r#"{"type":"submit","id":"p1","value":"ok"}"#,
+            r#"{"type":"hello","protocolVersion":1,"sdk":"sdk-ts/1.4.2"}"#,
+            r#"{"type":"dbSet","requestId":"r1","key":"user","value":{"name":"ada"}}"#,
+        ];
+
+        for sample in samples {
+            // Every prefix must classify without panicking; only the full
+            // string may parse successfully
+            for cut in 0..sample.len() {
+                let result = parse_message_graceful(&sample[..cut]);
+                assert!(
+                    !matches!(result, ParseResult::Ok(_)),
+                    "truncated input parsed as Ok: {:?}",
+                    &sample[..cut]
+                );
+            }
+            assert!(matches!(parse_message_graceful(sample), ParseResult::Ok(_)));
+        }
+    }
+
+    #[test]
+    fn test_fuzz_random_garbage_never_panics() {
+        let mut state = 0x5EED_0001_u64;
+        for _ in 0..500 {
+            let len = (next_rand(&mut state) % 300) as usize;
+            let line = garbage_line(&mut state, len);
+            // Any classification is fine; the call just must not panic
+            let _ = parse_message_graceful(&line);
+        }
+    }
+
+    #[test]
+    fn test_fuzz_interleaved_garbage_stream() {
+        use std::io::Cursor;
+
+        // Valid messages scattered through garbage, malformed JSON, bad
+        // frame headers, and unknown types - the graceful reader must
+        // recover every valid message in order
+        let mut state = 0x5EED_0002_u64;
+        let mut stream = String::new();
+        let mut expected = 0usize;
+        for round in 0..100 {
+            match next_rand(&mut state) % 5 {
+                0 => {
+                    stream.push_str("{\"type\":\"beep\"}\n");
+                    expected += 1;
+                }
+                1 => {
+                    let len = (next_rand(&mut state) % 200) as usize;
+                    stream.push_str(&garbage_line(&mut state, len));
+                    stream.push('\n');
+                }
+                2 => {
+                    // Truncated JSON
+                    stream.push_str("{\"type\":\"arg\",\"id\":\n");
+                }
+                3 => {
+                    // Unknown type / missing type
+                    if round % 2 == 0 {
+                        stream.push_str("{\"type\":\"fromTheFuture\",\"x\":1}\n");
+                    } else {
+                        stream.push_str("{\"id\":\"no-type\"}\n");
+                    }
+                }
+                _ => {
+                    // Malformed frame header
+                    stream.push_str("@@frame:not-a-number\n");
+                }
+            }
+        }
+
+        let mut reader = JsonlReader::new(Cursor::new(stream));
+        let mut received = 0usize;
+        while let Some(msg) = reader.next_message_graceful().unwrap() {
+            assert!(matches!(msg, Message::Beep {}));
+            received += 1;
+        }
+        assert_eq!(received, expected);
+    }
+
+    #[test]
+    fn test_fuzz_unknown_fields_are_ignored() {
+        let mut state = 0x5EED_0003_u64;
+        let base = r#"{"type":"submit","id":"p1","value":"ok"}"#;
+
+        for _ in 0..100 {
+            // Inject random extra fields - serde ignores unknown fields,
+            // so the message must still parse
+            let mut value: serde_json::Value = serde_json::from_str(base).unwrap();
+            let extras = 1 + (next_rand(&mut state) % 4) as usize;
+            for _ in 0..extras {
+                let key = format!("extra_{}", next_rand(&mut state) % 1000);
+                value[key] = serde_json::json!(next_rand(&mut state));
+            }
+            let line = serde_json::to_string(&value).unwrap();
+            match parse_message_graceful(&line) {
+                ParseResult::Ok(Message::Submit { id, .. }) => assert_eq!(id, "p1"),
+                other => panic!("Expected Submit despite extra fields, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_fuzz_huge_lines() {
+        use std::io::Cursor;
+
+        // A megabyte of garbage on one line must be skipped, not crash
+        let mut state = 0x5EED_0004_u64;
+        let mut stream = garbage_line(&mut state, 1024 * 1024);
+        stream.push('\n');
+        stream.push_str("{\"type\":\"beep\"}\n");
+
+        let mut reader = JsonlReader::new(Cursor::new(stream));
+        let msg = reader.next_message_graceful().unwrap();
+        assert!(matches!(msg, Some(Message::Beep {})));
+        assert!(reader.next_message_graceful().unwrap().is_none());
+
+        // A huge but valid message must parse intact
+        let big = "y".repeat(1024 * 1024);
+        let line = format!(
+            r#"{{"type":"arg","id":"1","placeholder":"{}","choices":[]}}"#,
+            big
+        );
+        match parse_message_graceful(&line) {
+            ParseResult::Ok(Message::Arg { placeholder, .. }) => {
+                assert_eq!(placeholder.len(), big.len())
+            }
+            other => panic!("Expected huge Arg to parse, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_fuzz_truncated_frame_payload_is_io_error() {
+        use std::io::Cursor;
+
+        // Header promises more bytes than the stream holds - the reader
+        // must surface an IO error, never panic or hang
+        let stream = format!("{}1000\nonly ten b", FRAME_PREFIX);
+        let mut reader = JsonlReader::new(Cursor::new(stream));
+        assert!(reader.next_message_graceful().is_err());
+    }
 }
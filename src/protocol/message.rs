@@ -27,6 +27,32 @@ pub enum Message {
         /// Optional actions for the actions panel (Cmd+K to open)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         actions: Option<Vec<ProtocolAction>>,
+        /// When false, choices keep script-provided order while filtering
+        /// (e.g. most-recent-first histories). Defaults to true (score sort).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sort: Option<bool>,
+        /// When true, the script has more choices than it sent; the app
+        /// requests further pages (moreChoices) as the user nears the end.
+        #[serde(default, skip_serializing_if = "Option::is_none", rename = "hasMore")]
+        has_more: Option<bool>,
+    },
+
+    /// App requests the next page of choices for a paginated arg prompt
+    #[serde(rename = "moreChoices")]
+    MoreChoices {
+        id: String,
+        /// Number of choices the app already holds (the next page starts here)
+        offset: usize,
+    },
+
+    /// Script appends a page of choices to the active arg prompt
+    #[serde(rename = "choicesPage")]
+    ChoicesPage {
+        id: String,
+        choices: Vec<Choice>,
+        /// Whether more pages remain after this one
+        #[serde(default, rename = "hasMore")]
+        has_more: bool,
     },
 
     /// Script sends div (HTML display)
@@ -141,6 +167,20 @@ pub enum Message {
         multiple: Option<bool>,
     },
 
+    /// Tabular data with sortable columns and row selection
+    #[serde(rename = "table")]
+    Table {
+        id: String,
+        /// Column headers, in display order
+        columns: Vec<String>,
+        /// Rows as cell strings aligned with `columns`
+        rows: Vec<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        placeholder: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        multiple: Option<bool>,
+    },
+
     // ============================================================
     // FORM PROMPTS
     // ============================================================
@@ -175,6 +215,52 @@ pub enum Message {
         start_path: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         hint: Option<String>,
+        /// Only show (and submit) directories
+        #[serde(rename = "onlyDirs", skip_serializing_if = "Option::is_none")]
+        only_dirs: Option<bool>,
+        /// Only show files with these extensions, without the dot (e.g. ["png", "jpg"])
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extensions: Vec<String>,
+        /// Show dotfiles (hidden entries). Defaults to false.
+        #[serde(rename = "showHidden", skip_serializing_if = "Option::is_none")]
+        show_hidden: Option<bool>,
+    },
+
+    /// Open-file dialog: path picker pre-configured for choosing an existing file
+    ///
+    /// Responds with a `submit` carrying the chosen path (or null on cancel),
+    /// so scripts don't have to re-implement path flows on top of `path`.
+    #[serde(rename = "openFile")]
+    OpenFile {
+        id: String,
+        #[serde(rename = "startPath", skip_serializing_if = "Option::is_none")]
+        start_path: Option<String>,
+        /// Only show files with these extensions, without the dot (e.g. ["png", "jpg"])
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extensions: Vec<String>,
+        /// Only allow choosing directories
+        #[serde(rename = "onlyDirs", skip_serializing_if = "Option::is_none")]
+        only_dirs: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
+    },
+
+    /// Save-file dialog: path picker that submits a (possibly not yet existing) filename
+    ///
+    /// The typed filename is joined to the browsed directory on submit.
+    #[serde(rename = "saveFile")]
+    SaveFile {
+        id: String,
+        #[serde(rename = "startPath", skip_serializing_if = "Option::is_none")]
+        start_path: Option<String>,
+        /// Pre-filled filename shown in the filter field
+        #[serde(rename = "defaultFilename", skip_serializing_if = "Option::is_none")]
+        default_filename: Option<String>,
+        /// Only show files with these extensions, without the dot
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extensions: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
     },
 
     /// File drop zone
@@ -192,6 +278,40 @@ pub enum Message {
         placeholder: Option<String>,
     },
 
+    // ============================================================
+    // TYPED INPUT PROMPTS
+    // ============================================================
+    /// Numeric input with optional min/max/step constraints
+    #[serde(rename = "number")]
+    Number {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        placeholder: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        step: Option<f64>,
+        /// Initial value shown in the input
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<f64>,
+    },
+
+    /// Date/time picker with calendar grid and natural-language parsing.
+    /// `mode` is "date" (default) or "time"; submits ISO strings.
+    #[serde(rename = "date")]
+    Date {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        placeholder: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mode: Option<String>,
+        /// Initial value (parsed like typed input, e.g. "tomorrow")
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+    },
+
     // ============================================================
     // TEMPLATE/TEXT PROMPTS
     // ============================================================
@@ -283,6 +403,20 @@ pub enum Message {
         duration_ms: Option<u64>,
     },
 
+    /// Structured console output from the SDK's console wrapper.
+    /// `file`/`line` map the output back to the source that logged it;
+    /// the app appends it to the per-run log file (see `run_logs`).
+    #[serde(rename = "log")]
+    Log {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        level: Option<String>,
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        line: Option<u32>,
+    },
+
     // ============================================================
     // SYSTEM CONTROL MESSAGES
     // ============================================================
@@ -313,6 +447,10 @@ pub enum Message {
         action: KeyboardAction,
         #[serde(skip_serializing_if = "Option::is_none")]
         keys: Option<String>,
+        /// Delay between keystrokes in milliseconds (type) or between
+        /// key-down and key-up (tap)
+        #[serde(rename = "delayMs", skip_serializing_if = "Option::is_none")]
+        delay_ms: Option<u64>,
     },
 
     /// Mouse control
@@ -486,6 +624,88 @@ pub enum Message {
         error: Option<String>,
     },
 
+    // ============================================================
+    // KEY-VALUE STORE (per-script persistent storage)
+    // ============================================================
+    /// Get a value from the script's key-value store
+    #[serde(rename = "dbGet")]
+    DbGet {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        key: String,
+    },
+
+    /// Set a value in the script's key-value store
+    #[serde(rename = "dbSet")]
+    DbSet {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        key: String,
+        value: serde_json::Value,
+    },
+
+    /// Delete a key from the script's key-value store
+    #[serde(rename = "dbDelete")]
+    DbDelete {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        key: String,
+    },
+
+    /// Response for key-value store operations
+    ///
+    /// `value` is present for successful gets (None when the key is missing);
+    /// set/delete respond with just success/error.
+    #[serde(rename = "dbResult")]
+    DbResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<serde_json::Value>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
+    // ============================================================
+    // SCRIPT-TO-SCRIPT INVOCATION
+    // ============================================================
+    /// Run another script by name and await its result
+    #[serde(rename = "run")]
+    Run {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        /// Script name as shown in the main list (or its file stem)
+        name: String,
+        /// Working directory for the child script (tilde-expanded);
+        /// defaults to the child's own `// Cwd:` metadata, then the
+        /// app's working directory
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cwd: Option<String>,
+    },
+
+    /// Set the script's typed final return value
+    ///
+    /// Recorded as the run's result (see `run_results`): stored in run
+    /// history and returned to invokers instead of the last submit. Sent
+    /// any number of times; the last value wins.
+    #[serde(rename = "result")]
+    ScriptResult { value: serde_json::Value },
+
+    /// Response with the result of a `run` request
+    ///
+    /// `value` is the child script's final output (trimmed stdout).
+    #[serde(rename = "runResult")]
+    RunResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
     // ============================================================
     // WINDOW MANAGEMENT (System Windows)
     // ============================================================
@@ -547,6 +767,76 @@ pub enum Message {
         files: Vec<FileSearchResultEntry>,
     },
 
+    // ============================================================
+    // NETWORK FETCH (proxied through the app)
+    // ============================================================
+    /// Request an HTTP call proxied through the app
+    ///
+    /// The app checks per-domain consent (prompting on first use) and
+    /// appends every request to the network log, so simple scripts get an
+    /// HTTP client without bundling their own and users keep visibility
+    /// into network access.
+    #[serde(rename = "fetch")]
+    Fetch {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        url: String,
+        /// HTTP method; defaults to GET
+        #[serde(skip_serializing_if = "Option::is_none")]
+        method: Option<String>,
+        /// Extra request headers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        headers: Option<std::collections::HashMap<String, String>>,
+        /// Request body for POST/PUT/PATCH
+        #[serde(skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+    },
+
+    /// Response with the result of a proxied fetch
+    ///
+    /// Non-2xx statuses are successful results (scripts branch on `status`);
+    /// `error` covers transport failures and denied consent.
+    #[serde(rename = "fetchResult")]
+    FetchResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status: Option<u16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
+    // ============================================================
+    // DO NOT DISTURB (Focus control)
+    // ============================================================
+    /// Request to turn Focus (Do Not Disturb) on or off
+    ///
+    /// The app tracks the state before the first change and restores it
+    /// when the script exits, so recordings and deep-work scripts can't
+    /// leave Focus stuck on.
+    #[serde(rename = "dnd")]
+    Dnd {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        enabled: bool,
+    },
+
+    /// Response with the result of a Focus change
+    #[serde(rename = "dndResult")]
+    DndResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        success: bool,
+        /// Whether Focus was active before this change
+        #[serde(skip_serializing_if = "Option::is_none")]
+        previous: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
     // ============================================================
     // SCREENSHOT CAPTURE
     // ============================================================
@@ -571,6 +861,60 @@ pub enum Message {
         height: u32,
     },
 
+    // ============================================================
+    // SHORTCUTS (macOS Shortcuts.app)
+    // ============================================================
+    /// Request to run a macOS Shortcut by name
+    #[serde(rename = "shortcuts.run")]
+    RunShortcut {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        /// Shortcut name as shown in Shortcuts.app
+        name: String,
+        /// Optional input passed to the shortcut via stdin
+        #[serde(skip_serializing_if = "Option::is_none")]
+        input: Option<String>,
+    },
+
+    /// Response with the shortcut's output
+    #[serde(rename = "shortcuts.runResult")]
+    ShortcutResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        success: bool,
+        /// The shortcut's output (stdout), present on success
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+        /// Error message if the shortcut failed
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
+    // ============================================================
+    // OCR
+    // ============================================================
+    /// Request to extract text from an image via OCR (macOS Vision framework)
+    #[serde(rename = "ocr")]
+    Ocr {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        /// PNG file to read; when absent the clipboard image is used
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+    },
+
+    /// Response with recognized text
+    #[serde(rename = "ocrResult")]
+    OcrResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        /// Recognized text joined with newlines; empty if none was found
+        text: String,
+        /// Error message if OCR failed
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
     // ============================================================
     // STATE QUERY
     // ============================================================
@@ -618,6 +962,37 @@ pub enum Message {
         window_visible: bool,
     },
 
+    /// Request app-level state (version, paths, theme)
+    ///
+    /// Unlike `getState` this does not depend on the active prompt, so it
+    /// works the same for background scripts that never show a window.
+    #[serde(rename = "getAppState")]
+    GetAppState {
+        #[serde(rename = "requestId")]
+        request_id: String,
+    },
+
+    /// Response with app-level state
+    #[serde(rename = "appStateResult")]
+    AppStateResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        /// App version (Cargo package version)
+        #[serde(rename = "appVersion")]
+        app_version: String,
+        /// Root of the kit directory scripts are loaded from
+        #[serde(rename = "kitPath")]
+        kit_path: String,
+        /// Theme appearance: "dark" or "light" (matches the SK_THEME env var)
+        theme: String,
+        /// Number of scripts currently listed
+        #[serde(rename = "scriptCount")]
+        script_count: usize,
+        /// Whether the main window is visible
+        #[serde(rename = "windowVisible")]
+        window_visible: bool,
+    },
+
     // ============================================================
     // ELEMENT QUERY (AI-driven UX)
     // ============================================================
@@ -887,8 +1262,63 @@ pub enum Message {
         #[serde(skip_serializing_if = "Option::is_none")]
         error: Option<String>,
     },
+
+    // ============================================================
+    // HANDSHAKE / CAPABILITY NEGOTIATION
+    // ============================================================
+    /// SDK announces its protocol version when the stream opens
+    ///
+    /// Optional - scripts that never send it get the pre-handshake behavior
+    /// (unknown message types are logged and skipped either way).
+    #[serde(rename = "hello")]
+    Hello {
+        /// Protocol version the SDK was built against (see [`PROTOCOL_VERSION`])
+        #[serde(rename = "protocolVersion")]
+        protocol_version: u32,
+        /// SDK name/version string for diagnostics (e.g. "sdk-ts/1.4.2")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sdk: Option<String>,
+    },
+
+    /// App replies to `hello` with its version and supported capabilities
+    ///
+    /// SDKs feature-detect against `capabilities` instead of version-gating,
+    /// so newer apps can add messages without breaking older scripts.
+    #[serde(rename = "helloResult")]
+    HelloResult {
+        /// Protocol version the app speaks (see [`PROTOCOL_VERSION`])
+        #[serde(rename = "protocolVersion")]
+        protocol_version: u32,
+        /// Capability identifiers from [`CAPABILITIES`]
+        capabilities: Vec<String>,
+    },
 }
 
+/// Version of the JSONL protocol this app speaks
+///
+/// Bump only for changes old SDKs cannot safely ignore; additive messages
+/// are covered by the capability list instead.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Capability identifiers reported in `helloResult`
+///
+/// Each names a message group the app understands; SDKs check for the
+/// capability before using the group so the same script runs against older
+/// app versions.
+pub const CAPABILITIES: &[&str] = &[
+    "prompts",           // arg/div/editor/form/term/path/... prompt messages
+    "paginated-choices", // moreChoices/choicesPage
+    "sdk-actions",       // setActions/actionTriggered
+    "window-control",    // show/hide/setPosition/setSize/...
+    "state-queries",     // getState/captureScreenshot/getWindowBounds/...
+    "selected-text",     // getSelectedText/setSelectedText
+    "clipboard-history", // clipboardHistory request/response
+    "db-store",          // dbGet/dbSet/dbDelete
+    "scriptlets",        // runScriptlet/getScriptlets
+    "menu-bar",          // getMenuBar/executeMenuAction
+    "binary-frames",     // length-prefixed @@frame payloads for large messages
+];
+
 impl Message {
     /// Create an arg prompt message
     pub fn arg(id: String, placeholder: String, choices: Vec<Choice>) -> Self {
@@ -897,6 +1327,8 @@ impl Message {
             placeholder,
             choices,
             actions: None,
+            sort: None,
+            has_more: None,
         }
     }
 
@@ -916,6 +1348,8 @@ impl Message {
             } else {
                 Some(actions)
             },
+            sort: None,
+            has_more: None,
         }
     }
 
@@ -978,14 +1412,20 @@ impl Message {
             | Message::Micro { id, .. }
             // Selection prompts
             | Message::Select { id, .. }
+            | Message::Table { id, .. }
             // Form prompts
             | Message::Fields { id, .. }
             | Message::Form { id, .. }
             // File/path prompts
             | Message::Path { id, .. }
+            | Message::OpenFile { id, .. }
+            | Message::SaveFile { id, .. }
             | Message::Drop { id, .. }
             // Input capture prompts
             | Message::Hotkey { id, .. }
+            // Typed input prompts
+            | Message::Number { id, .. }
+            | Message::Date { id, .. }
             // Template/text prompts
             | Message::Template { id, .. }
             | Message::Env { id, .. }
@@ -1024,6 +1464,14 @@ impl Message {
             | Message::ClipboardHistoryEntry { request_id, .. }
             | Message::ClipboardHistoryList { request_id, .. }
             | Message::ClipboardHistoryResult { request_id, .. }
+            // Key-value store
+            | Message::DbGet { request_id, .. }
+            | Message::DbSet { request_id, .. }
+            | Message::DbDelete { request_id, .. }
+            | Message::DbResult { request_id, .. }
+            // Script-to-script invocation
+            | Message::Run { request_id, .. }
+            | Message::RunResult { request_id, .. }
             // Window management
             | Message::WindowList { request_id, .. }
             | Message::WindowAction { request_id, .. }
@@ -1035,9 +1483,17 @@ impl Message {
             // Screenshot capture
             | Message::CaptureScreenshot { request_id, .. }
             | Message::ScreenshotResult { request_id, .. }
+            // Shortcuts
+            | Message::RunShortcut { request_id, .. }
+            | Message::ShortcutResult { request_id, .. }
+            // OCR
+            | Message::Ocr { request_id, .. }
+            | Message::OcrResult { request_id, .. }
             // State query
             | Message::GetState { request_id, .. }
             | Message::StateResult { request_id, .. }
+            | Message::GetAppState { request_id, .. }
+            | Message::AppStateResult { request_id, .. }
             // Element query
             | Message::GetElements { request_id, .. }
             | Message::ElementsResult { request_id, .. }
@@ -1166,6 +1622,9 @@ impl Message {
             id,
             start_path,
             hint: None,
+            only_dirs: None,
+            extensions: Vec::new(),
+            show_hidden: None,
         }
     }
 
@@ -1284,6 +1743,7 @@ impl Message {
         Message::Keyboard {
             action: KeyboardAction::Type,
             keys: Some(keys),
+            delay_ms: None,
         }
     }
 
@@ -1292,6 +1752,7 @@ impl Message {
         Message::Keyboard {
             action: KeyboardAction::Tap,
             keys: Some(keys),
+            delay_ms: None,
         }
     }
 
@@ -1516,6 +1977,114 @@ impl Message {
         }
     }
 
+    // ============================================================
+    // Constructor methods for key-value store
+    // ============================================================
+
+    /// Create a db result carrying a fetched value (None = key not found)
+    pub fn db_value(request_id: String, value: Option<serde_json::Value>) -> Self {
+        Message::DbResult {
+            request_id,
+            success: true,
+            value,
+            error: None,
+        }
+    }
+
+    /// Create a db result (success, no value)
+    pub fn db_success(request_id: String) -> Self {
+        Message::DbResult {
+            request_id,
+            success: true,
+            value: None,
+            error: None,
+        }
+    }
+
+    /// Create a db result (error)
+    pub fn db_error(request_id: String, error: String) -> Self {
+        Message::DbResult {
+            request_id,
+            success: false,
+            value: None,
+            error: Some(error),
+        }
+    }
+
+    // ============================================================
+    // Constructor methods for script-to-script invocation
+    // ============================================================
+
+    /// Create a run result carrying the child script's final value
+    pub fn run_success(request_id: String, value: String) -> Self {
+        Message::RunResult {
+            request_id,
+            success: true,
+            value: Some(value),
+            error: None,
+        }
+    }
+
+    /// Create a run result (error)
+    pub fn run_error(request_id: String, error: String) -> Self {
+        Message::RunResult {
+            request_id,
+            success: false,
+            value: None,
+            error: Some(error),
+        }
+    }
+
+    // ============================================================
+    // Constructor methods for proxied fetch
+    // ============================================================
+
+    /// Create a fetch result carrying the response status and body
+    pub fn fetch_success(request_id: String, status: u16, body: String) -> Self {
+        Message::FetchResult {
+            request_id,
+            success: true,
+            status: Some(status),
+            body: Some(body),
+            error: None,
+        }
+    }
+
+    /// Create a fetch result (error or denied consent)
+    pub fn fetch_error(request_id: String, error: String) -> Self {
+        Message::FetchResult {
+            request_id,
+            success: false,
+            status: None,
+            body: None,
+            error: Some(error),
+        }
+    }
+
+    // ============================================================
+    // Constructor methods for Focus control
+    // ============================================================
+
+    /// Create a dnd result carrying the prior Focus state
+    pub fn dnd_success(request_id: String, previous: bool) -> Self {
+        Message::DndResult {
+            request_id,
+            success: true,
+            previous: Some(previous),
+            error: None,
+        }
+    }
+
+    /// Create a dnd error result
+    pub fn dnd_error(request_id: String, error: String) -> Self {
+        Message::DndResult {
+            request_id,
+            success: false,
+            previous: None,
+            error: Some(error),
+        }
+    }
+
     // ============================================================
     // Constructor methods for window management
     // ============================================================
@@ -1611,6 +2180,52 @@ impl Message {
         }
     }
 
+    // ============================================================
+    // Constructor methods for Shortcuts
+    // ============================================================
+
+    /// Create a successful shortcut run response
+    pub fn shortcut_result(request_id: String, output: String) -> Self {
+        Message::ShortcutResult {
+            request_id,
+            success: true,
+            output: Some(output),
+            error: None,
+        }
+    }
+
+    /// Create a failed shortcut run response
+    pub fn shortcut_error(request_id: String, error: String) -> Self {
+        Message::ShortcutResult {
+            request_id,
+            success: false,
+            output: None,
+            error: Some(error),
+        }
+    }
+
+    // ============================================================
+    // Constructor methods for OCR
+    // ============================================================
+
+    /// Create an OCR result response
+    pub fn ocr_result(request_id: String, text: String) -> Self {
+        Message::OcrResult {
+            request_id,
+            text,
+            error: None,
+        }
+    }
+
+    /// Create an OCR error response
+    pub fn ocr_error(request_id: String, error: String) -> Self {
+        Message::OcrResult {
+            request_id,
+            text: String::new(),
+            error: Some(error),
+        }
+    }
+
     // ============================================================
     // Constructor methods for state query
     // ============================================================
@@ -1650,6 +2265,30 @@ impl Message {
         }
     }
 
+    /// Create a get app state request
+    pub fn get_app_state(request_id: String) -> Self {
+        Message::GetAppState { request_id }
+    }
+
+    /// Create an app state result response
+    pub fn app_state_result(
+        request_id: String,
+        app_version: String,
+        kit_path: String,
+        theme: String,
+        script_count: usize,
+        window_visible: bool,
+    ) -> Self {
+        Message::AppStateResult {
+            request_id,
+            app_version,
+            kit_path,
+            theme,
+            script_count,
+            window_visible,
+        }
+    }
+
     // ============================================================
     // Constructor methods for element query
     // ============================================================
@@ -1963,4 +2602,24 @@ impl Message {
             error: Some(error),
         }
     }
+
+    // ============================================================
+    // Constructor methods for the handshake
+    // ============================================================
+
+    /// Create a Hello announcement message
+    pub fn hello(protocol_version: u32, sdk: Option<String>) -> Self {
+        Message::Hello {
+            protocol_version,
+            sdk,
+        }
+    }
+
+    /// Create the HelloResult reply with this app's version and capabilities
+    pub fn hello_result() -> Self {
+        Message::HelloResult {
+            protocol_version: PROTOCOL_VERSION,
+            capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+        }
+    }
 }
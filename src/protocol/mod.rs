@@ -29,7 +29,8 @@
 //! - `setActions`, `actionTriggered`: Actions menu
 //!
 //! ## State Queries (request/response pattern)
-//! - `getState`/`stateResult`: App state
+//! - `getState`/`stateResult`: Prompt/UI state
+//! - `getAppState`/`appStateResult`: App-level state (version, kit path, theme)
 //! - `getSelectedText`/`selectedText`: System selection
 //! - `captureScreenshot`/`screenshotResult`: Window capture
 //! - `getWindowBounds`/`windowBounds`: Window geometry
@@ -38,22 +39,33 @@
 //! ## Scriptlets
 //! - `runScriptlet`, `getScriptlets`, `scriptletList`, `scriptletResult`
 //!
+//! ## Handshake
+//! - `hello`/`helloResult`: SDK announces its protocol version, app replies
+//!   with supported capabilities; unknown message types are always logged
+//!   and skipped, so the handshake is optional
+//! - Binary frames: peers that saw the "binary-frames" capability may send
+//!   any message as `@@frame:<byte-len>` + payload instead of a JSONL line,
+//!   so large editor/image payloads bypass line buffering
+//!
 //! # Module Structure
 //!
 //! - `types`: Helper types (Choice, Field, ClipboardAction, MouseEventData, ExecOptions, etc.)
 //! - `message`: The main Message enum (59+ variants) and constructors
 //! - `semantic_id`: Semantic ID generation for AI-driven UX
 //! - `io`: JSONL parsing with graceful error handling, serialization, streaming readers
+//! - `schema`: TypeScript definition generation from the Message enum (`sk schema`)
 
 #![allow(dead_code)]
 
 mod io;
 mod message;
+mod schema;
 mod semantic_id;
 mod types;
 
 // Re-export all public types
 pub use io::*;
 pub use message::*;
+pub use schema::*;
 pub use semantic_id::*;
 pub use types::*;
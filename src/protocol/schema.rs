@@ -0,0 +1,451 @@
+//! TypeScript definition generation for the JSONL protocol
+//!
+//! Derives `kit-sdk` type definitions (message shapes, capabilities) from
+//! the [`Message`](super::Message) enum source via `sk schema`, so the
+//! embedded SDK and the app can't drift apart silently: new variants and
+//! fields show up in the generated output without anyone maintaining a
+//! parallel list.
+//!
+//! Rust has no type reflection, so the generator parses `message.rs`
+//! itself (embedded via `include_str!`). The parser only understands the
+//! shapes that file actually uses - serde-tagged struct variants with
+//! per-field `rename`/`skip_serializing_if` attributes - and the tests
+//! below serialize real messages against the parsed schema to catch any
+//! construct it misses.
+
+use super::{CAPABILITIES, PROTOCOL_VERSION};
+
+/// The Message enum source this build was compiled from
+const MESSAGE_SOURCE: &str = include_str!("message.rs");
+
+/// Schema for one Message variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantSchema {
+    /// Wire tag from the variant's `#[serde(rename = "...")]`
+    pub tag: String,
+    /// Rust variant name (used for the generated interface name)
+    pub name: String,
+    /// Fields in declaration order
+    pub fields: Vec<FieldSchema>,
+}
+
+/// Schema for one field of a Message variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSchema {
+    /// Wire name (serde rename when present, Rust name otherwise)
+    pub name: String,
+    /// TypeScript type the Rust type maps to
+    pub ts_type: String,
+    /// Whether the field is optional on the wire (`Option<T>`)
+    pub optional: bool,
+}
+
+/// Parse the Message enum source into variant schemas
+///
+/// Returns variants in declaration order. Variants without a serde rename
+/// are skipped (the enum has none today; a new one would surface in the
+/// round-trip test below).
+pub fn parse_message_enum(source: &str) -> Vec<VariantSchema> {
+    let mut variants = Vec::new();
+
+    // Slice out the enum body by brace depth
+    let Some(start) = source.find("pub enum Message {") else {
+        return variants;
+    };
+    let body = &source[start..];
+    let mut depth = 0usize;
+
+    // Attributes seen since the last item at each depth
+    let mut pending_tag: Option<String> = None;
+    let mut pending_field_rename: Option<String> = None;
+    let mut current: Option<VariantSchema> = None;
+
+    for line in body.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("#[serde(") {
+            let rename = extract_attr_value(trimmed, "rename = \"");
+            match depth {
+                1 => pending_tag = rename.or(pending_tag.take()),
+                2 => pending_field_rename = rename.or(pending_field_rename.take()),
+                _ => {}
+            }
+        } else if depth == 1 && !trimmed.starts_with("//") && !trimmed.starts_with("#[") {
+            if let Some(brace) = trimmed.find('{') {
+                // Variant start, either multi-line ("Arg {") or inline
+                // ("Submit { id: String, value: Option<String> },")
+                let name = trimmed[..brace].trim().to_string();
+                if let Some(tag) = pending_tag.take() {
+                    let mut variant = VariantSchema {
+                        tag,
+                        name,
+                        fields: Vec::new(),
+                    };
+                    if let Some(end) = trimmed.rfind('}') {
+                        if end > brace {
+                            for field_src in split_inline_fields(&trimmed[brace + 1..end]) {
+                                if let Some(field) = parse_field(&field_src, None) {
+                                    variant.fields.push(field);
+                                }
+                            }
+                        }
+                    }
+                    current = Some(variant);
+                }
+            } else if trimmed.ends_with(',') {
+                // Unit variant: "HideGrid,"
+                let name = trimmed.trim_end_matches(',').trim();
+                if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    if let Some(tag) = pending_tag.take() {
+                        variants.push(VariantSchema {
+                            tag,
+                            name: name.to_string(),
+                            fields: Vec::new(),
+                        });
+                    }
+                }
+            }
+        } else if depth == 2 && !trimmed.starts_with("//") && !trimmed.starts_with("#[") {
+            // Field line: "id: String," (doc comments filtered above)
+            if let Some(field) = parse_field(trimmed, pending_field_rename.take()) {
+                if let Some(ref mut variant) = current {
+                    variant.fields.push(field);
+                }
+            }
+        }
+
+        // Track depth after interpreting the line so "Arg {" is seen at
+        // the variant level, not the field level
+        for c in trimmed.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 1 {
+                        // Variant closed
+                        if let Some(variant) = current.take() {
+                            variants.push(variant);
+                        }
+                        pending_field_rename = None;
+                    }
+                    if depth == 0 {
+                        return variants;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    variants
+}
+
+/// Parse one field declaration like `id: String,`
+///
+/// Returns None for anything that doesn't look like a field (closing
+/// braces, stray syntax), which the caller just skips.
+fn parse_field(src: &str, rename: Option<String>) -> Option<FieldSchema> {
+    let (name, rust_type) = src.trim().split_once(':')?;
+    let name = name.trim();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c == '_' || c.is_ascii_digit())
+    {
+        return None;
+    }
+    let (ts_type, optional) = map_rust_type(rust_type.trim().trim_end_matches(','));
+    Some(FieldSchema {
+        name: rename.unwrap_or_else(|| name.to_string()),
+        ts_type,
+        optional,
+    })
+}
+
+/// Split inline fields on top-level commas, respecting generics
+/// (`a: String, b: HashMap<String, u32>` is two fields, not three)
+fn split_inline_fields(src: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut angle_depth = 0usize;
+    let mut start = 0;
+    for (ix, c) in src.char_indices() {
+        match c {
+            '<' => angle_depth += 1,
+            '>' => angle_depth = angle_depth.saturating_sub(1),
+            ',' if angle_depth == 0 => {
+                fields.push(src[start..ix].to_string());
+                start = ix + 1;
+            }
+            _ => {}
+        }
+    }
+    if !src[start..].trim().is_empty() {
+        fields.push(src[start..].to_string());
+    }
+    fields
+}
+
+/// Extract a quoted attribute value, e.g. `rename = "arg"`
+fn extract_attr_value(attr_line: &str, key: &str) -> Option<String> {
+    let start = attr_line.find(key)? + key.len();
+    let rest = &attr_line[start..];
+    let end = rest.find('"')?;
+    Some(rest[..end].to_string())
+}
+
+/// Map a Rust field type to (TypeScript type, optional)
+fn map_rust_type(rust_type: &str) -> (String, bool) {
+    let rust_type = rust_type.trim();
+
+    if let Some(inner) = strip_generic(rust_type, "Option<") {
+        let (ts, _) = map_rust_type(inner);
+        return (ts, true);
+    }
+    if let Some(inner) = strip_generic(rust_type, "Vec<") {
+        let (ts, _) = map_rust_type(inner);
+        return (format!("{}[]", ts), false);
+    }
+    if let Some(inner) = strip_generic(rust_type, "HashMap<String,") {
+        let (ts, _) = map_rust_type(inner);
+        return (format!("Record<string, {}>", ts), false);
+    }
+
+    let ts = match rust_type {
+        "String" | "&str" | "PathBuf" => "string".to_string(),
+        "bool" => "boolean".to_string(),
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" | "f32"
+        | "f64" => "number".to_string(),
+        "serde_json::Value" | "Value" => "unknown".to_string(),
+        // Custom protocol types keep their name; a placeholder alias is
+        // emitted for each so the file stands alone
+        other => other.rsplit("::").next().unwrap_or(other).to_string(),
+    };
+    (ts, false)
+}
+
+/// Strip a generic wrapper like `Option<` ... `>` and return the inner type
+fn strip_generic<'a>(rust_type: &'a str, prefix: &str) -> Option<&'a str> {
+    rust_type
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix('>'))
+        .map(str::trim)
+}
+
+/// Whether a TypeScript type is one of the generated primitives
+fn is_ts_primitive(ts_type: &str) -> bool {
+    let base = ts_type
+        .trim_end_matches("[]")
+        .trim_start_matches("Record<string, ")
+        .trim_end_matches('>');
+    matches!(base, "string" | "boolean" | "number" | "unknown")
+}
+
+/// Convert a wire tag like "helloResult" to an interface name ("HelloResultMessage")
+fn interface_name(variant: &VariantSchema) -> String {
+    format!("{}Message", variant.name)
+}
+
+/// Generate the kit-sdk TypeScript definitions
+///
+/// Output is deterministic for a given build so it can be committed and
+/// diffed; the header carries the protocol version it was generated from.
+pub fn generate_typescript() -> String {
+    let variants = parse_message_enum(MESSAGE_SOURCE);
+
+    let mut out = String::new();
+    out.push_str("// Generated by `sk schema` - do not edit by hand.\n");
+    out.push_str("// Message shapes for the Script Kit JSONL protocol.\n\n");
+
+    out.push_str(&format!(
+        "export const PROTOCOL_VERSION = {} as const;\n\n",
+        PROTOCOL_VERSION
+    ));
+
+    out.push_str("export const CAPABILITIES = [\n");
+    for capability in CAPABILITIES {
+        out.push_str(&format!("  \"{}\",\n", capability));
+    }
+    out.push_str("] as const;\n");
+    out.push_str("export type Capability = (typeof CAPABILITIES)[number];\n\n");
+
+    // Placeholder aliases for protocol helper types referenced by fields.
+    // Detailed shapes live in the SDK; these keep the file self-contained.
+    let mut helper_types: Vec<String> = Vec::new();
+    for variant in &variants {
+        for field in &variant.fields {
+            let base = field.ts_type.trim_end_matches("[]");
+            if !is_ts_primitive(base) && !helper_types.contains(&base.to_string()) {
+                helper_types.push(base.to_string());
+            }
+        }
+    }
+    helper_types.sort();
+    for helper in &helper_types {
+        out.push_str(&format!(
+            "export type {} = Record<string, unknown>;\n",
+            helper
+        ));
+    }
+    if !helper_types.is_empty() {
+        out.push('\n');
+    }
+
+    for variant in &variants {
+        out.push_str(&format!(
+            "export interface {} {{\n",
+            interface_name(variant)
+        ));
+        out.push_str(&format!("  type: \"{}\";\n", variant.tag));
+        for field in &variant.fields {
+            out.push_str(&format!(
+                "  {}{}: {};\n",
+                field.name,
+                if field.optional { "?" } else { "" },
+                field.ts_type
+            ));
+        }
+        out.push_str("}\n\n");
+    }
+
+    out.push_str("export type Message =\n");
+    for (ix, variant) in variants.iter().enumerate() {
+        let sep = if ix + 1 == variants.len() { ";" } else { "" };
+        out.push_str(&format!("  | {}{}\n", interface_name(variant), sep));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::Message;
+
+    #[test]
+    fn test_parses_known_variants() {
+        let variants = parse_message_enum(MESSAGE_SOURCE);
+        assert!(
+            variants.len() > 50,
+            "expected 59+ variants, got {}",
+            variants.len()
+        );
+
+        let arg = variants.iter().find(|v| v.tag == "arg").unwrap();
+        assert_eq!(arg.name, "Arg");
+        let id = arg.fields.iter().find(|f| f.name == "id").unwrap();
+        assert_eq!(id.ts_type, "string");
+        assert!(!id.optional);
+        let has_more = arg.fields.iter().find(|f| f.name == "hasMore").unwrap();
+        assert_eq!(has_more.ts_type, "boolean");
+        assert!(has_more.optional);
+    }
+
+    #[test]
+    fn test_serialized_messages_match_schema() {
+        // The actual drift guard: real serialized messages must use tags
+        // and field names the parsed schema knows about
+        let variants = parse_message_enum(MESSAGE_SOURCE);
+
+        let samples = vec![
+            serde_json::to_value(Message::arg("p1".to_string(), "Pick".to_string(), vec![]))
+                .unwrap(),
+            serde_json::to_value(Message::div("p2".to_string(), "<b>hi</b>".to_string())).unwrap(),
+            serde_json::to_value(Message::Submit {
+                id: "p3".to_string(),
+                value: Some("ok".to_string()),
+            })
+            .unwrap(),
+            serde_json::to_value(Message::HelloResult {
+                protocol_version: PROTOCOL_VERSION,
+                capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+            })
+            .unwrap(),
+        ];
+
+        for sample in samples {
+            let tag = sample["type"].as_str().unwrap();
+            let variant = variants
+                .iter()
+                .find(|v| v.tag == tag)
+                .unwrap_or_else(|| panic!("schema missing variant for tag {}", tag));
+            for key in sample.as_object().unwrap().keys() {
+                if key == "type" {
+                    continue;
+                }
+                assert!(
+                    variant.fields.iter().any(|f| &f.name == key),
+                    "schema for '{}' missing field '{}'",
+                    tag,
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parses_inline_and_unit_variants() {
+        let source = r#"
+#[serde(tag = "type")]
+pub enum Message {
+    /// Inline struct variant
+    #[serde(rename = "submit")]
+    Submit { id: String, value: Option<String> },
+
+    /// Empty braces
+    #[serde(rename = "beep")]
+    Beep {},
+
+    /// Unit variant
+    #[serde(rename = "hideGrid")]
+    HideGrid,
+}
+"#;
+        let variants = parse_message_enum(source);
+        assert_eq!(variants.len(), 3);
+
+        assert_eq!(variants[0].tag, "submit");
+        assert_eq!(variants[0].fields.len(), 2);
+        assert_eq!(variants[0].fields[0].name, "id");
+        assert!(!variants[0].fields[0].optional);
+        assert_eq!(variants[0].fields[1].name, "value");
+        assert!(variants[0].fields[1].optional);
+
+        assert_eq!(variants[1].tag, "beep");
+        assert!(variants[1].fields.is_empty());
+
+        assert_eq!(variants[2].tag, "hideGrid");
+        assert_eq!(variants[2].name, "HideGrid");
+        assert!(variants[2].fields.is_empty());
+    }
+
+    #[test]
+    fn test_map_rust_type() {
+        assert_eq!(map_rust_type("String"), ("string".to_string(), false));
+        assert_eq!(map_rust_type("Option<u32>"), ("number".to_string(), true));
+        assert_eq!(
+            map_rust_type("Vec<Choice>"),
+            ("Choice[]".to_string(), false)
+        );
+        assert_eq!(
+            map_rust_type("Option<Vec<ProtocolAction>>"),
+            ("ProtocolAction[]".to_string(), true)
+        );
+        assert_eq!(
+            map_rust_type("serde_json::Value"),
+            ("unknown".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn test_generated_typescript_shape() {
+        let ts = generate_typescript();
+        assert!(ts.contains("export const PROTOCOL_VERSION = 1 as const;"));
+        assert!(ts.contains("\"prompts\","));
+        assert!(ts.contains("export interface ArgMessage {"));
+        assert!(ts.contains("  type: \"arg\";"));
+        assert!(ts.contains("export type Message ="));
+        assert!(ts.contains("  | ArgMessage"));
+        // Deterministic output
+        assert_eq!(ts, generate_typescript());
+    }
+}
@@ -14,7 +14,8 @@ use super::semantic_id::{generate_semantic_id, generate_semantic_id_named};
 
 /// A choice option for arg() prompts
 ///
-/// Supports Script Kit API: name, value, and optional description.
+/// Supports Script Kit API: name, value, optional description, and
+/// optional icon/img visuals rendered in the choice list.
 /// Semantic IDs are generated for AI-driven UX targeting.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Choice {
@@ -22,6 +23,13 @@ pub struct Choice {
     pub value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Emoji or short symbol rendered left of the choice name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Path to an image file (PNG) rendered as the choice icon.
+    /// Takes precedence over `icon` when the file decodes successfully.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
     /// Semantic ID for AI targeting. Format: choice:{index}:{value_slug}
     /// This field is typically generated at render time, not provided by scripts.
     #[serde(skip_serializing_if = "Option::is_none", rename = "semanticId")]
@@ -34,6 +42,8 @@ impl Choice {
             name,
             value,
             description: None,
+            icon: None,
+            img: None,
             semantic_id: None,
         }
     }
@@ -43,6 +53,8 @@ impl Choice {
             name,
             value,
             description: Some(description),
+            icon: None,
+            img: None,
             semantic_id: None,
         }
     }
@@ -0,0 +1,232 @@
+//! Persisted query history for the main filter and script prompts
+//!
+//! Queries that led to an executed choice are recorded per prompt key
+//! ("main" for the main filter, the script path for arg prompts) so they
+//! can be recalled shell-style: Up with an empty filter (or Ctrl+R) cycles
+//! through previous queries. Entries are stored most-recent-first,
+//! deduplicated, capped per key, and persisted to
+//! `~/.sk/kit/query_history.json` alongside the other JSON stores.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// Maximum queries kept per prompt key
+const MAX_ENTRIES_PER_PROMPT: usize = 50;
+
+/// Store for per-prompt query history with persistence
+#[derive(Debug, Clone)]
+pub struct QueryHistoryStore {
+    /// Queries per prompt key, most recent first
+    entries: HashMap<String, Vec<String>>,
+    /// Path to the history file
+    file_path: PathBuf,
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Serialize, Deserialize)]
+struct QueryHistoryData {
+    entries: HashMap<String, Vec<String>>,
+}
+
+impl QueryHistoryStore {
+    /// Create a new store with the default path (~/.sk/kit/query_history.json)
+    pub fn new() -> Self {
+        QueryHistoryStore {
+            entries: HashMap::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a store with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        QueryHistoryStore {
+            entries: HashMap::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    /// Get the default history file path
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.sk/kit/query_history.json").as_ref())
+    }
+
+    /// Load history from disk
+    ///
+    /// Starts empty if the file doesn't exist.
+    #[instrument(name = "query_history_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            debug!(path = %self.file_path.display(), "Query history file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path).with_context(|| {
+            format!(
+                "Failed to read query history file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        let data: QueryHistoryData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse query history JSON")?;
+
+        self.entries = data.entries;
+        for queries in self.entries.values_mut() {
+            queries.truncate(MAX_ENTRIES_PER_PROMPT);
+        }
+        self.dirty = false;
+
+        info!(
+            path = %self.file_path.display(),
+            prompt_count = self.entries.len(),
+            "Loaded query history"
+        );
+        Ok(())
+    }
+
+    /// Save history to disk
+    #[instrument(name = "query_history_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let data = QueryHistoryData {
+            entries: self.entries.clone(),
+        };
+
+        let json =
+            serde_json::to_string_pretty(&data).context("Failed to serialize query history")?;
+
+        std::fs::write(&self.file_path, json).with_context(|| {
+            format!(
+                "Failed to write query history file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Record a query under a prompt key, moving it to the front if present
+    ///
+    /// Blank queries are ignored; each key's list is capped at
+    /// `MAX_ENTRIES_PER_PROMPT` with the oldest entries dropped.
+    pub fn record(&mut self, prompt_key: &str, query: &str) {
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        let queries = self.entries.entry(prompt_key.to_string()).or_default();
+        if let Some(pos) = queries.iter().position(|q| q == query) {
+            queries.remove(pos);
+        }
+        queries.insert(0, query.to_string());
+        queries.truncate(MAX_ENTRIES_PER_PROMPT);
+        self.dirty = true;
+    }
+
+    /// The recorded queries for a prompt key, most recent first
+    pub fn queries(&self, prompt_key: &str) -> &[String] {
+        self.entries
+            .get(prompt_key)
+            .map(|q| q.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+impl Default for QueryHistoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "sk-query-history-test-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_record_is_most_recent_first_per_key() {
+        let mut store = QueryHistoryStore::with_path(temp_path("recent"));
+        store.record("main", "deploy");
+        store.record("main", "notes");
+        store.record("/script.ts", "yes");
+        assert_eq!(store.queries("main"), &["notes", "deploy"]);
+        assert_eq!(store.queries("/script.ts"), &["yes"]);
+    }
+
+    #[test]
+    fn test_record_dedupes_and_moves_to_front() {
+        let mut store = QueryHistoryStore::with_path(temp_path("dedupe"));
+        store.record("main", "deploy");
+        store.record("main", "notes");
+        store.record("main", "deploy");
+        assert_eq!(store.queries("main"), &["deploy", "notes"]);
+    }
+
+    #[test]
+    fn test_record_ignores_blank_and_trims() {
+        let mut store = QueryHistoryStore::with_path(temp_path("blank"));
+        store.record("main", "   ");
+        store.record("main", "  deploy  ");
+        assert_eq!(store.queries("main"), &["deploy"]);
+    }
+
+    #[test]
+    fn test_unknown_key_is_empty() {
+        let store = QueryHistoryStore::with_path(temp_path("unknown"));
+        assert!(store.queries("missing").is_empty());
+    }
+
+    #[test]
+    fn test_history_is_capped_per_key() {
+        let mut store = QueryHistoryStore::with_path(temp_path("cap"));
+        for i in 0..(MAX_ENTRIES_PER_PROMPT + 10) {
+            store.record("main", &format!("query {}", i));
+        }
+        assert_eq!(store.queries("main").len(), MAX_ENTRIES_PER_PROMPT);
+        assert_eq!(
+            store.queries("main")[0],
+            format!("query {}", MAX_ENTRIES_PER_PROMPT + 9)
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        let mut store = QueryHistoryStore::with_path(path.clone());
+        store.record("main", "deploy");
+        store.record("main", "notes");
+        store.save().unwrap();
+
+        let mut loaded = QueryHistoryStore::with_path(path.clone());
+        loaded.load().unwrap();
+        assert_eq!(loaded.queries("main"), &["notes", "deploy"]);
+
+        std::fs::remove_file(path).ok();
+    }
+}
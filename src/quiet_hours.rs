@@ -0,0 +1,174 @@
+//! Quiet Hours
+//!
+//! During a configured daily window (e.g. 22:00-08:00) background-script
+//! toasts and HUDs are not shown. Instead they are queued here and surfaced
+//! as a "while you were away" digest the next time the user summons the
+//! window. Quiet hours only suppress notifications while the main window is
+//! hidden - feedback for a prompt the user is actively looking at still
+//! shows.
+//!
+//! The queue is a process-wide singleton because notifications arrive from
+//! script reader threads while the digest is drained on the UI thread.
+
+use crate::config::{Config, QuietHoursConfig};
+use chrono::{DateTime, Local, Timelike, Utc};
+use std::sync::Mutex;
+
+/// A notification suppressed during quiet hours
+#[derive(Debug, Clone)]
+pub struct DigestEntry {
+    /// The notification text that would have been shown
+    pub text: String,
+    /// When the notification arrived
+    pub at: DateTime<Utc>,
+}
+
+/// Queued notifications awaiting the next summon
+static DIGEST: Mutex<Vec<DigestEntry>> = Mutex::new(Vec::new());
+
+/// Cap so a chatty overnight script can't grow the digest unbounded;
+/// older entries are dropped first
+const MAX_DIGEST_ENTRIES: usize = 50;
+
+/// Parse a 24-hour "HH:MM" time into minutes since midnight
+pub fn parse_time(value: &str) -> Option<u32> {
+    let (hours, minutes) = value.trim().split_once(':')?;
+    let hours: u32 = hours.parse().ok()?;
+    let minutes: u32 = minutes.parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(hours * 60 + minutes)
+}
+
+/// Whether `now` (minutes since midnight) falls inside the window.
+/// An end at or before the start wraps past midnight (22:00-08:00).
+pub fn in_window(now: u32, start: u32, end: u32) -> bool {
+    if start < end {
+        now >= start && now < end
+    } else {
+        // Overnight window; start == end means the full day
+        now >= start || now < end
+    }
+}
+
+/// Whether quiet hours are active right now for this configuration.
+/// Requires both times set and parseable; otherwise quiet hours are off.
+pub fn is_quiet(config: &QuietHoursConfig) -> bool {
+    let (Some(start), Some(end)) = (config.start.as_deref(), config.end.as_deref()) else {
+        return false;
+    };
+    let (Some(start), Some(end)) = (parse_time(start), parse_time(end)) else {
+        return false;
+    };
+    let now = Local::now();
+    in_window(now.hour() * 60 + now.minute(), start, end)
+}
+
+/// Whether a background notification should be suppressed and queued:
+/// quiet hours are active and the main window is hidden
+pub fn should_suppress(config: &Config) -> bool {
+    is_quiet(&config.get_quiet_hours()) && !crate::is_main_window_visible()
+}
+
+/// Queue a suppressed notification for the next digest
+pub fn queue(text: &str) {
+    if let Ok(mut digest) = DIGEST.lock() {
+        if digest.len() >= MAX_DIGEST_ENTRIES {
+            digest.remove(0);
+        }
+        digest.push(DigestEntry {
+            text: text.to_string(),
+            at: Utc::now(),
+        });
+    }
+}
+
+/// Take all queued notifications, leaving the digest empty
+pub fn drain() -> Vec<DigestEntry> {
+    DIGEST
+        .lock()
+        .map(|mut digest| digest.drain(..).collect())
+        .unwrap_or_default()
+}
+
+/// Number of notifications waiting in the digest
+pub fn pending_count() -> usize {
+    DIGEST.lock().map(|d| d.len()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("22:00"), Some(22 * 60));
+        assert_eq!(parse_time("08:30"), Some(8 * 60 + 30));
+        assert_eq!(parse_time(" 7:05 "), Some(7 * 60 + 5));
+        assert_eq!(parse_time("24:00"), None);
+        assert_eq!(parse_time("12:60"), None);
+        assert_eq!(parse_time("noon"), None);
+        assert_eq!(parse_time(""), None);
+    }
+
+    #[test]
+    fn test_in_window_same_day() {
+        let start = parse_time("13:00").unwrap();
+        let end = parse_time("17:00").unwrap();
+        assert!(in_window(parse_time("13:00").unwrap(), start, end));
+        assert!(in_window(parse_time("16:59").unwrap(), start, end));
+        assert!(!in_window(parse_time("17:00").unwrap(), start, end));
+        assert!(!in_window(parse_time("09:00").unwrap(), start, end));
+    }
+
+    #[test]
+    fn test_in_window_overnight() {
+        let start = parse_time("22:00").unwrap();
+        let end = parse_time("08:00").unwrap();
+        assert!(in_window(parse_time("23:30").unwrap(), start, end));
+        assert!(in_window(parse_time("02:00").unwrap(), start, end));
+        assert!(in_window(parse_time("07:59").unwrap(), start, end));
+        assert!(!in_window(parse_time("08:00").unwrap(), start, end));
+        assert!(!in_window(parse_time("12:00").unwrap(), start, end));
+    }
+
+    #[test]
+    fn test_is_quiet_requires_both_times() {
+        assert!(!is_quiet(&QuietHoursConfig {
+            start: Some("22:00".to_string()),
+            end: None,
+        }));
+        assert!(!is_quiet(&QuietHoursConfig {
+            start: Some("later".to_string()),
+            end: Some("08:00".to_string()),
+        }));
+        assert!(!is_quiet(&QuietHoursConfig::default()));
+    }
+
+    #[test]
+    fn test_queue_and_drain() {
+        // Drain anything other tests left behind
+        let _ = drain();
+        queue("build finished");
+        queue("sync failed");
+        assert_eq!(pending_count(), 2);
+        let entries = drain();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].text, "build finished");
+        assert_eq!(entries[1].text, "sync failed");
+        assert_eq!(pending_count(), 0);
+    }
+
+    #[test]
+    fn test_queue_caps_entries() {
+        let _ = drain();
+        for i in 0..(MAX_DIGEST_ENTRIES + 5) {
+            queue(&format!("entry {}", i));
+        }
+        let entries = drain();
+        assert_eq!(entries.len(), MAX_DIGEST_ENTRIES);
+        // Oldest entries were dropped first
+        assert_eq!(entries[0].text, "entry 5");
+    }
+}
@@ -1,6 +1,7 @@
 // Builtin view render methods - extracted from app_render.rs
 // This file is included via include!() macro in main.rs
-// Contains: render_clipboard_history, render_app_launcher, render_window_switcher, render_design_gallery
+// Contains: render_clipboard_history, render_app_launcher, render_window_switcher,
+// render_background_tasks, render_shortcuts_list, render_design_gallery
 
 impl ScriptListApp {
     /// Render clipboard history view
@@ -12,7 +13,7 @@ impl ScriptListApp {
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
@@ -182,12 +183,7 @@ impl ScriptListApp {
             },
         );
 
-        let input_display = if filter.is_empty() {
-            SharedString::from("Search clipboard history...")
-        } else {
-            SharedString::from(filter.clone())
-        };
-        let input_is_empty = filter.is_empty();
+        let input_placeholder = SharedString::from(i18n::strings().search_clipboard);
 
         // Pre-compute colors
         let list_colors = ListItemColors::from_design(&design_colors);
@@ -205,9 +201,9 @@ impl ScriptListApp {
                 .text_color(rgb(design_colors.text_muted))
                 .font_family(design_typography.font_family)
                 .child(if filter.is_empty() {
-                    "No clipboard history"
+                    i18n::strings().clipboard_empty
                 } else {
-                    "No entries match your filter"
+                    i18n::strings().clipboard_no_match
                 })
                 .into_any_element()
         } else {
@@ -275,6 +271,12 @@ impl ScriptListApp {
                                     format!("{}d ago", age_secs / 86400)
                                 };
 
+                                // Attribute the entry to its source application
+                                let description = match &entry.source_app {
+                                    Some(app) => format!("{} - {}", app, relative_time),
+                                    None => relative_time,
+                                };
+
                                 // Add pin indicator
                                 let name = if entry.pinned {
                                     format!("📌 {}", display_content)
@@ -284,16 +286,24 @@ impl ScriptListApp {
 
                                 // Build list item with optional thumbnail
                                 let mut item = ListItem::new(name, list_colors)
-                                    .description_opt(Some(relative_time))
+                                    .description_opt(Some(description))
                                     .selected(is_selected)
                                     .with_accent_bar(true);
 
-                                // Add thumbnail for images, text icon for text entries
+                                // Add thumbnail for images; text entries show their
+                                // source app's icon when we have it, else a text icon
                                 if let Some(render_image) = cached_image {
                                     item = item.icon_image(render_image);
                                 } else if entry.content_type == clipboard_history::ContentType::Text
                                 {
-                                    item = item.icon("📄");
+                                    if let Some(app_icon) = app_launcher::get_app_icon(
+                                        entry.source_bundle_id.as_deref(),
+                                        entry.source_app.as_deref(),
+                                    ) {
+                                        item = item.icon_image(app_icon);
+                                    } else {
+                                        item = item.icon("📄");
+                                    }
                                 }
 
                                 div().id(ix).child(item)
@@ -354,39 +364,15 @@ impl ScriptListApp {
                             .flex_row()
                             .items_center()
                             .text_lg()
-                            .text_color(if input_is_empty {
-                                rgb(text_muted)
-                            } else {
-                                rgb(text_primary)
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .mr(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
-                                        .child(input_display.clone()),
-                                )
-                            })
-                            .when(!input_is_empty, |d| d.child(input_display.clone()))
-                            .when(!input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .ml(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            }),
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
                     )
                     .child(
                         div()
@@ -524,6 +510,38 @@ impl ScriptListApp {
                         .child(relative_time),
                 );
 
+                // Source app attribution (entries recorded before attribution
+                // existed have no source)
+                if let Some(source_app) = &entry.source_app {
+                    let app_icon = app_launcher::get_app_icon(
+                        entry.source_bundle_id.as_deref(),
+                        Some(source_app.as_str()),
+                    );
+                    panel = panel.child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .gap_2()
+                            .pb(px(spacing.padding_md))
+                            .when_some(app_icon, |d, icon| {
+                                d.child(
+                                    gpui::img(move |_window: &mut Window, _cx: &mut App| {
+                                        Some(Ok(icon.clone()))
+                                    })
+                                    .w(px(16.0))
+                                    .h(px(16.0)),
+                                )
+                            })
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(rgb(text_secondary))
+                                    .child(format!("Copied from {}", source_app)),
+                            ),
+                    );
+                }
+
                 // Divider
                 panel = panel.child(
                     div()
@@ -685,7 +703,7 @@ impl ScriptListApp {
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
@@ -794,12 +812,7 @@ impl ScriptListApp {
             },
         );
 
-        let input_display = if filter.is_empty() {
-            SharedString::from("Search applications...")
-        } else {
-            SharedString::from(filter.clone())
-        };
-        let input_is_empty = filter.is_empty();
+        let input_placeholder = SharedString::from(i18n::strings().search_apps);
 
         // Pre-compute colors
         let list_colors = ListItemColors::from_design(&design_colors);
@@ -817,9 +830,9 @@ impl ScriptListApp {
                 .text_color(rgb(design_colors.text_muted))
                 .font_family(design_typography.font_family)
                 .child(if filter.is_empty() {
-                    "No applications found"
+                    i18n::strings().apps_empty
                 } else {
-                    "No apps match your filter"
+                    i18n::strings().apps_no_match
                 })
                 .into_any_element()
         } else {
@@ -911,39 +924,15 @@ impl ScriptListApp {
                             .flex_row()
                             .items_center()
                             .text_lg()
-                            .text_color(if input_is_empty {
-                                rgb(text_muted)
-                            } else {
-                                rgb(text_primary)
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .mr(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
-                                        .child(input_display.clone()),
-                                )
-                            })
-                            .when(!input_is_empty, |d| d.child(input_display.clone()))
-                            .when(!input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .ml(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            }),
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
                     )
                     .child(
                         div()
@@ -982,7 +971,7 @@ impl ScriptListApp {
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
@@ -1142,12 +1131,7 @@ impl ScriptListApp {
             },
         );
 
-        let input_display = if filter.is_empty() {
-            SharedString::from("Search windows...")
-        } else {
-            SharedString::from(filter.clone())
-        };
-        let input_is_empty = filter.is_empty();
+        let input_placeholder = SharedString::from(i18n::strings().search_windows);
 
         // Pre-compute colors
         let list_colors = ListItemColors::from_design(&design_colors);
@@ -1165,9 +1149,9 @@ impl ScriptListApp {
                 .text_color(rgb(design_colors.text_muted))
                 .font_family(design_typography.font_family)
                 .child(if filter.is_empty() {
-                    "No windows found"
+                    i18n::strings().windows_empty
                 } else {
-                    "No windows match your filter"
+                    i18n::strings().windows_no_match
                 })
                 .into_any_element()
         } else {
@@ -1221,8 +1205,41 @@ impl ScriptListApp {
         let selected_window = filtered_windows
             .get(selected_index)
             .map(|(_, w)| (*w).clone());
+
+        // Capture a thumbnail for the selected window on first selection.
+        // Cached per window id; failures are remembered so we don't retry on
+        // every frame (e.g. when Screen Recording permission is missing).
+        if let Some(w) = &selected_window {
+            if !self.window_thumbnail_cache.contains_key(&w.id)
+                && !self.window_thumbnail_failed.contains(&w.id)
+            {
+                match window_control::capture_window_thumbnail(w) {
+                    Ok(thumb) => {
+                        if let Some(image) =
+                            list_item::bgra_to_render_image(thumb.width, thumb.height, thumb.bgra)
+                        {
+                            self.window_thumbnail_cache.insert(w.id, image);
+                        } else {
+                            self.window_thumbnail_failed.insert(w.id);
+                        }
+                    }
+                    Err(e) => {
+                        logging::log(
+                            "WINDOW",
+                            &format!("Thumbnail capture failed for '{}': {}", w.title, e),
+                        );
+                        self.window_thumbnail_failed.insert(w.id);
+                    }
+                }
+            }
+        }
+        let selected_thumbnail = selected_window
+            .as_ref()
+            .and_then(|w| self.window_thumbnail_cache.get(&w.id).cloned());
+
         let actions_panel = self.render_window_actions_panel(
             &selected_window,
+            selected_thumbnail,
             &design_colors,
             &design_spacing,
             &design_typography,
@@ -1269,39 +1286,15 @@ impl ScriptListApp {
                             .flex_row()
                             .items_center()
                             .text_lg()
-                            .text_color(if input_is_empty {
-                                rgb(text_muted)
-                            } else {
-                                rgb(text_primary)
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .mr(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
-                                        .child(input_display.clone()),
-                                )
-                            })
-                            .when(!input_is_empty, |d| d.child(input_display.clone()))
-                            .when(!input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .ml(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            }),
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
                     )
                     .child(
                         div()
@@ -1352,6 +1345,7 @@ impl ScriptListApp {
     fn render_window_actions_panel(
         &self,
         selected_window: &Option<window_control::WindowInfo>,
+        thumbnail: Option<Arc<gpui::RenderImage>>,
         colors: &designs::DesignColors,
         spacing: &designs::DesignSpacing,
         typography: &designs::DesignTypography,
@@ -1412,6 +1406,31 @@ impl ScriptListApp {
                         )),
                 );
 
+                // Live thumbnail of the window's contents (when capture succeeded)
+                if let Some(thumbnail) = thumbnail {
+                    panel = panel.child(
+                        div()
+                            .w_full()
+                            .h(px(140.0))
+                            .mb(px(spacing.padding_lg))
+                            .rounded(px(visual.radius_sm))
+                            .border_1()
+                            .border_color(rgba((ui_border << 8) | 0x60))
+                            .overflow_hidden()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .child(
+                                gpui::img(move |_window: &mut Window, _cx: &mut App| {
+                                    Some(Ok(thumbnail.clone()))
+                                })
+                                .object_fit(gpui::ObjectFit::Contain)
+                                .w_full()
+                                .h_full(),
+                            ),
+                    );
+                }
+
                 // Divider
                 panel = panel.child(
                     div()
@@ -1568,18 +1587,17 @@ impl ScriptListApp {
         cx.notify();
     }
 
-    /// Render design gallery view with group header and icon variations
-    fn render_design_gallery(
+    /// Render background tasks view listing running background scripts
+    /// Enter stops the selected task (kills its process group)
+    fn render_background_tasks(
         &mut self,
+        tasks: Vec<background_tasks::BackgroundTask>,
         filter: String,
         selected_index: usize,
         cx: &mut Context<Self>,
     ) -> AnyElement {
-        use designs::group_header_variations::{GroupHeaderCategory, GroupHeaderStyle};
-        use designs::icon_variations::{IconCategory, IconName, IconStyle};
-
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
@@ -1591,107 +1609,98 @@ impl ScriptListApp {
         let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
         let box_shadows = self.create_box_shadows();
 
-        // Build gallery items: group headers grouped by category, then icons grouped by category
-        #[derive(Clone)]
-        enum GalleryItem {
-            GroupHeaderCategory(GroupHeaderCategory),
-            GroupHeader(GroupHeaderStyle),
-            IconCategoryHeader(IconCategory),
-            Icon(IconName, IconStyle),
-        }
-
-        let mut gallery_items: Vec<GalleryItem> = Vec::new();
-
-        // Add group headers by category
-        for category in GroupHeaderCategory::all() {
-            gallery_items.push(GalleryItem::GroupHeaderCategory(*category));
-            for style in category.styles() {
-                gallery_items.push(GalleryItem::GroupHeader(*style));
-            }
-        }
-
-        // Add icons by category, showing each icon with default style
-        for category in IconCategory::all() {
-            gallery_items.push(GalleryItem::IconCategoryHeader(*category));
-            for icon in category.icons() {
-                gallery_items.push(GalleryItem::Icon(icon, IconStyle::Default));
-            }
-        }
-
-        // Filter items based on current filter
-        let filtered_items: Vec<(usize, GalleryItem)> = if filter.is_empty() {
-            gallery_items
-                .iter()
-                .enumerate()
-                .map(|(i, item)| (i, item.clone()))
-                .collect()
+        // Filter tasks based on current filter
+        let filtered_tasks: Vec<_> = if filter.is_empty() {
+            tasks.iter().enumerate().collect()
         } else {
             let filter_lower = filter.to_lowercase();
-            gallery_items
+            tasks
                 .iter()
                 .enumerate()
-                .filter(|(_, item)| match item {
-                    GalleryItem::GroupHeaderCategory(cat) => {
-                        cat.name().to_lowercase().contains(&filter_lower)
-                    }
-                    GalleryItem::GroupHeader(style) => {
-                        style.name().to_lowercase().contains(&filter_lower)
-                            || style.description().to_lowercase().contains(&filter_lower)
-                    }
-                    GalleryItem::IconCategoryHeader(cat) => {
-                        cat.name().to_lowercase().contains(&filter_lower)
-                    }
-                    GalleryItem::Icon(icon, _) => {
-                        icon.name().to_lowercase().contains(&filter_lower)
-                            || icon.description().to_lowercase().contains(&filter_lower)
-                    }
-                })
-                .map(|(i, item)| (i, item.clone()))
+                .filter(|(_, t)| t.name.to_lowercase().contains(&filter_lower))
                 .collect()
         };
-        let filtered_len = filtered_items.len();
+        let filtered_len = filtered_tasks.len();
 
-        // Key handler for design gallery
+        // Key handler for background tasks
         let handle_key = cx.listener(
             move |this: &mut Self,
                   event: &gpui::KeyDownEvent,
                   _window: &mut Window,
                   cx: &mut Context<Self>| {
-                // Global shortcuts (Cmd+W) - handled first regardless of view state
                 // Global shortcuts (Cmd+W, ESC for dismissable views)
                 if this.handle_global_shortcut_with_options(event, true, cx) {
                     return;
                 }
 
                 let key_str = event.keystroke.key.to_lowercase();
-                logging::log("KEY", &format!("DesignGallery key: '{}'", key_str));
+                logging::log("KEY", &format!("BackgroundTasks key: '{}'", key_str));
 
-                if let AppView::DesignGalleryView {
+                if let AppView::BackgroundTasksView {
+                    tasks,
                     filter,
                     selected_index,
                 } = &mut this.current_view
                 {
-                    // Re-compute filtered_len for this scope
-                    let total_items = GroupHeaderStyle::count()
-                        + IconName::count()
-                        + GroupHeaderCategory::all().len()
-                        + IconCategory::all().len();
-                    let current_filtered_len = total_items;
+                    // Apply filter to get current filtered list
+                    let filtered_tasks: Vec<_> = if filter.is_empty() {
+                        tasks.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        tasks
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, t)| t.name.to_lowercase().contains(&filter_lower))
+                            .collect()
+                    };
+                    let filtered_len = filtered_tasks.len();
 
                     match key_str.as_str() {
                         "up" | "arrowup" => {
                             if *selected_index > 0 {
                                 *selected_index -= 1;
-                                this.design_gallery_scroll_handle
-                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
                                 cx.notify();
                             }
                         }
                         "down" | "arrowdown" => {
-                            if *selected_index < current_filtered_len.saturating_sub(1) {
+                            if *selected_index < filtered_len.saturating_sub(1) {
                                 *selected_index += 1;
-                                this.design_gallery_scroll_handle
-                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Stop selected task - extract fields to avoid borrow issues
+                            if let Some((_, task)) = filtered_tasks.get(*selected_index) {
+                                let pid = task.pid;
+                                let name = task.name.clone();
+                                drop(filtered_tasks);
+                                logging::log(
+                                    "EXEC",
+                                    &format!("Stopping background task: {} (PID {})", name, pid),
+                                );
+                                let stopped = background_tasks::stop(pid);
+                                // Refresh the view from the registry
+                                *tasks = background_tasks::list();
+                                if *selected_index >= tasks.len() {
+                                    *selected_index = tasks.len().saturating_sub(1);
+                                }
+                                if stopped {
+                                    this.toast_manager.push(
+                                        components::toast::Toast::success(
+                                            format!("Stopped {}", name),
+                                            &this.theme,
+                                        )
+                                        .duration_ms(Some(3000)),
+                                    );
+                                } else {
+                                    this.toast_manager.push(
+                                        components::toast::Toast::warning(
+                                            format!("{} already exited", name),
+                                            &this.theme,
+                                        )
+                                        .duration_ms(Some(3000)),
+                                    );
+                                }
                                 cx.notify();
                             }
                         }
@@ -1700,8 +1709,6 @@ impl ScriptListApp {
                             if !filter.is_empty() {
                                 filter.pop();
                                 *selected_index = 0;
-                                this.design_gallery_scroll_handle
-                                    .scroll_to_item(0, ScrollStrategy::Top);
                                 cx.notify();
                             }
                         }
@@ -1711,8 +1718,6 @@ impl ScriptListApp {
                                     if !ch.is_control() {
                                         filter.push(ch);
                                         *selected_index = 0;
-                                        this.design_gallery_scroll_handle
-                                            .scroll_to_item(0, ScrollStrategy::Top);
                                         cx.notify();
                                     }
                                 }
@@ -1723,12 +1728,7 @@ impl ScriptListApp {
             },
         );
 
-        let input_display = if filter.is_empty() {
-            SharedString::from("Search design variations...")
-        } else {
-            SharedString::from(filter.clone())
-        };
-        let input_is_empty = filter.is_empty();
+        let input_placeholder = SharedString::from(i18n::strings().search_tasks);
 
         // Pre-compute colors
         let list_colors = ListItemColors::from_design(&design_colors);
@@ -1736,7 +1736,10 @@ impl ScriptListApp {
         let text_muted = design_colors.text_muted;
         let text_dimmed = design_colors.text_dimmed;
         let ui_border = design_colors.border;
-        let _accent = design_colors.accent;
+        let error_color = design_colors.error;
+
+        // Scripts whose `// Retries:` budget ran out, shown above the list
+        let failed_tasks = background_tasks::failures();
 
         // Build virtualized list
         let list_element: AnyElement = if filtered_len == 0 {
@@ -1746,72 +1749,5055 @@ impl ScriptListApp {
                 .text_center()
                 .text_color(rgb(design_colors.text_muted))
                 .font_family(design_typography.font_family)
-                .child("No items match your filter")
+                .child(if filter.is_empty() {
+                    i18n::strings().tasks_empty
+                } else {
+                    i18n::strings().tasks_no_match
+                })
                 .into_any_element()
         } else {
             // Clone data for the closure
-            let items_for_closure = filtered_items.clone();
+            let tasks_for_closure: Vec<_> = filtered_tasks
+                .iter()
+                .map(|(i, t)| (*i, (*t).clone()))
+                .collect();
             let selected = selected_index;
-            let _list_colors_clone = list_colors; // Kept for future use
-            let design_spacing_clone = design_spacing;
-            let design_typography_clone = design_typography;
-            let design_visual_clone = design_visual;
-            let design_colors_clone = design_colors;
+
+            // Sample resource usage for the visible tasks (one sysinfo
+            // refresh per render, scoped to these PIDs)
+            let task_pids: Vec<u32> = tasks_for_closure.iter().map(|(_, t)| t.pid).collect();
+            let usage = background_tasks::usage_for(&task_pids);
 
             uniform_list(
-                "design-gallery",
+                "background-tasks",
                 filtered_len,
                 move |visible_range, _window, _cx| {
                     visible_range
                         .map(|ix| {
-                            if let Some((_, item)) = items_for_closure.get(ix) {
+                            if let Some((_, task)) = tasks_for_closure.get(ix) {
                                 let is_selected = ix == selected;
 
-                                let element: AnyElement = match item {
-                                    GalleryItem::GroupHeaderCategory(category) => {
-                                        // Category header - styled as section header
-                                        div()
-                                            .id(ElementId::NamedInteger(
-                                                "gallery-header-cat".into(),
-                                                ix as u64,
-                                            ))
-                                            .w_full()
-                                            .h(px(32.0))
-                                            .px(px(design_spacing_clone.padding_lg))
-                                            .flex()
-                                            .items_center()
-                                            .bg(rgba(
-                                                (design_colors_clone.background_secondary << 8)
-                                                    | 0x80,
-                                            ))
-                                            .child(
-                                                div()
-                                                    .text_sm()
-                                                    .font_weight(gpui::FontWeight::BOLD)
-                                                    .text_color(rgb(design_colors_clone.accent))
-                                                    .child(format!(
-                                                        "── Group Headers: {} ──",
-                                                        category.name()
-                                                    )),
-                                            )
-                                            .into_any_element()
-                                    }
-                                    GalleryItem::GroupHeader(style) => render_group_header_item(
-                                        ix,
-                                        is_selected,
-                                        style,
-                                        &design_spacing_clone,
-                                        &design_typography_clone,
-                                        &design_visual_clone,
-                                        &design_colors_clone,
-                                    ),
-                                    GalleryItem::IconCategoryHeader(category) => {
-                                        // Icon category header
-                                        div()
-                                            .id(ElementId::NamedInteger(
-                                                "gallery-icon-cat".into(),
-                                                ix as u64,
-                                            ))
+                                // Format: running time + PID + usage as description
+                                let resource = usage
+                                    .get(&task.pid)
+                                    .map(|(cpu, memory)| {
+                                        format!(
+                                            " · {:.0}% CPU · {}",
+                                            cpu,
+                                            background_tasks::format_memory(*memory)
+                                        )
+                                    })
+                                    .unwrap_or_default();
+                                let description = format!(
+                                    "Running {} · PID {}{} · Enter to stop",
+                                    task.elapsed_display(),
+                                    task.pid,
+                                    resource
+                                );
+
+                                div().id(ix).child(
+                                    ListItem::new(task.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji("⚙️".to_string()))
+                                        .description_opt(Some(description))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("background_tasks")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("⚙️ Background Tasks"),
+                    )
+                    // Search input with blinking cursor
+                    // ALIGNMENT FIX: Uses canonical cursor constants and negative margin for placeholder
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(format!("{} running", tasks.len())),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Scripts that used up their retry budget stay listed until they
+            // launch again (register() clears the entry)
+            .when(!failed_tasks.is_empty(), |d| {
+                d.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .w_full()
+                        .px(px(design_spacing.padding_lg))
+                        .py(px(design_spacing.padding_sm))
+                        .gap_1()
+                        .children(failed_tasks.iter().map(|failure| {
+                            div()
+                                .text_sm()
+                                .text_color(rgb(error_color))
+                                .child(format!(
+                                    "⚠️ {} — gave up after {} retries",
+                                    failure.name, failure.attempts
+                                ))
+                        })),
+                )
+            })
+            // Task list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the macOS Shortcuts list with run actions
+    fn render_shortcuts_list(
+        &mut self,
+        shortcuts: Vec<String>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter shortcuts based on current filter
+        let filtered_shortcuts: Vec<_> = if filter.is_empty() {
+            shortcuts.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            shortcuts
+                .iter()
+                .enumerate()
+                .filter(|(_, name)| name.to_lowercase().contains(&filter_lower))
+                .collect()
+        };
+        let filtered_len = filtered_shortcuts.len();
+
+        // Key handler for the shortcuts list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("Shortcuts key: '{}'", key_str));
+
+                if let AppView::ShortcutsView {
+                    shortcuts,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_shortcuts: Vec<_> = if filter.is_empty() {
+                        shortcuts.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        shortcuts
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, name)| name.to_lowercase().contains(&filter_lower))
+                            .collect()
+                    };
+                    let filtered_len = filtered_shortcuts.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Run selected shortcut on a background thread
+                            if let Some((_, name)) = filtered_shortcuts.get(*selected_index) {
+                                let name = (*name).clone();
+                                logging::log("EXEC", &format!("Running shortcut: {}", name));
+                                // Hide the window first - shortcuts often present their own UI
+                                script_kit_gpui::set_main_window_visible(false);
+                                cx.hide();
+                                NEEDS_RESET.store(true, Ordering::SeqCst);
+                                macos_shortcuts::run_shortcut_async(name.clone(), None, move |result| {
+                                    match result {
+                                        Ok(output) => logging::log(
+                                            "EXEC",
+                                            &format!(
+                                                "Shortcut '{}' finished ({} bytes of output)",
+                                                name,
+                                                output.len()
+                                            ),
+                                        ),
+                                        Err(e) => logging::log(
+                                            "ERROR",
+                                            &format!("Shortcut '{}' failed: {}", name, e),
+                                        ),
+                                    }
+                                });
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from(i18n::strings().search_shortcuts);
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(if filter.is_empty() {
+                    i18n::strings().shortcuts_empty
+                } else {
+                    i18n::strings().shortcuts_no_match
+                })
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let shortcuts_for_closure: Vec<_> = filtered_shortcuts
+                .iter()
+                .map(|(i, name)| (*i, (*name).clone()))
+                .collect();
+            let selected = selected_index;
+
+            uniform_list(
+                "shortcuts-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, name)) = shortcuts_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+
+                                div().id(ix).child(
+                                    ListItem::new(name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji("⚡".to_string()))
+                                        .description_opt(Some(
+                                            "Press Enter to run".to_string(),
+                                        ))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("shortcuts_list")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("⚡ Shortcuts"),
+                    )
+                    // Search input with blinking cursor
+                    // ALIGNMENT FIX: Uses canonical cursor constants and negative margin for placeholder
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(format!("{} shortcuts", shortcuts.len())),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Shortcut list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the recently deleted list with restore actions
+    fn render_recently_deleted(
+        &mut self,
+        entries: Vec<undo::TrashEntry>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter entries based on current filter
+        let filtered_entries: Vec<_> = if filter.is_empty() {
+            entries.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            entries
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| e.name.to_lowercase().contains(&filter_lower))
+                .collect()
+        };
+        let filtered_len = filtered_entries.len();
+
+        // Key handler for the recently deleted list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("RecentlyDeleted key: '{}'", key_str));
+
+                let mut restored_file = false;
+                if let AppView::RecentlyDeletedView {
+                    entries,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_entries: Vec<_> = if filter.is_empty() {
+                        entries.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        entries
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, e)| e.name.to_lowercase().contains(&filter_lower))
+                            .collect()
+                    };
+                    let filtered_len = filtered_entries.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Restore selected entry to its original location
+                            if let Some((ix, entry)) = filtered_entries.get(*selected_index) {
+                                let ix = *ix;
+                                let entry = (*entry).clone();
+                                drop(filtered_entries);
+                                match undo::restore_trash_entry(&entry) {
+                                    Ok(original) => {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Restored {:?} from trash", original),
+                                        );
+                                        entries.remove(ix);
+                                        if *selected_index >= entries.len() {
+                                            *selected_index = entries.len().saturating_sub(1);
+                                        }
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                format!("Restored {}", entry.name),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                        restored_file = true;
+                                    }
+                                    Err(e) => {
+                                        logging::log(
+                                            "ERROR",
+                                            &format!("Failed to restore from trash: {}", e),
+                                        );
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Restore failed: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if restored_file {
+                    // Pick the restored script back up in the main list
+                    this.refresh_scripts(cx);
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from(i18n::strings().search_deleted);
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(if filter.is_empty() {
+                    i18n::strings().deleted_empty
+                } else {
+                    i18n::strings().deleted_no_match
+                })
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let entries_for_closure: Vec<_> = filtered_entries
+                .iter()
+                .map(|(i, entry)| (*i, (*entry).clone()))
+                .collect();
+            let selected = selected_index;
+
+            uniform_list(
+                "recently-deleted-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, entry)) = entries_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+                                let age = undo::age_label(entry.deleted_at_ms);
+                                let description = match &entry.original {
+                                    Some(original) => format!(
+                                        "{} - deleted {} - Enter to restore",
+                                        original.to_string_lossy(),
+                                        age
+                                    ),
+                                    None => format!("deleted {} - no recorded origin", age),
+                                };
+
+                                div().id(ix).child(
+                                    ListItem::new(entry.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji("🗑️".to_string()))
+                                        .description_opt(Some(description))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("recently_deleted_list")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🗑️ Recently Deleted"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(format!("{} files", entries.len())),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Entry list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the bundled theme browser with live preview
+    fn render_theme_browser(
+        &mut self,
+        themes: Vec<theme::BundledTheme>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter themes based on current filter
+        let filtered_themes: Vec<_> = if filter.is_empty() {
+            themes.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            themes
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| t.name.to_lowercase().contains(&filter_lower))
+                .collect()
+        };
+        let filtered_len = filtered_themes.len();
+
+        // Key handler for the theme browser
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+
+                // Escape reverts any previewed theme before the view dismisses
+                if key_str == "escape" {
+                    if let Some(saved) = this.theme_browser_saved.take() {
+                        this.theme = saved;
+                    }
+                }
+
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                logging::log("KEY", &format!("ThemeBrowser key: '{}'", key_str));
+
+                if let AppView::ThemeBrowserView {
+                    themes,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_themes: Vec<_> = if filter.is_empty() {
+                        themes.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        themes
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, t)| t.name.to_lowercase().contains(&filter_lower))
+                            .collect()
+                    };
+                    let filtered_len = filtered_themes.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                // Live preview: apply the selected theme immediately
+                                if let Some((_, entry)) = filtered_themes.get(*selected_index) {
+                                    this.theme = entry.theme.clone();
+                                }
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                // Live preview: apply the selected theme immediately
+                                if let Some((_, entry)) = filtered_themes.get(*selected_index) {
+                                    this.theme = entry.theme.clone();
+                                }
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Apply selected theme and persist it to theme.json
+                            if let Some((_, entry)) = filtered_themes.get(*selected_index) {
+                                let entry = (*entry).clone();
+                                drop(filtered_themes);
+                                this.theme = entry.theme.clone();
+                                match theme::save_theme(&entry.theme) {
+                                    Ok(()) => {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Applied theme: {}", entry.name),
+                                        );
+                                        // Keep the applied theme even if Escape follows
+                                        this.theme_browser_saved = None;
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                format!("Theme applied: {}", entry.name),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        logging::log(
+                                            "ERROR",
+                                            &format!("Failed to save theme: {}", e),
+                                        );
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Failed to save theme: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Search themes...");
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child("No themes match your filter")
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let themes_for_closure: Vec<_> = filtered_themes
+                .iter()
+                .map(|(i, entry)| (*i, (*entry).clone()))
+                .collect();
+            let selected = selected_index;
+
+            uniform_list(
+                "theme-browser-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, entry)) = themes_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+
+                                div().id(ix).child(
+                                    ListItem::new(entry.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji("🎨".to_string()))
+                                        .description_opt(Some(format!(
+                                            "{} - Enter to apply",
+                                            entry.description
+                                        )))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("theme_browser")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🎨 Themes"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(format!("{} themes", themes.len())),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Theme list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the expansion statistics view (triggers, usage counts, conflicts)
+    fn render_expand_stats(
+        &mut self,
+        entries: Vec<expand_manager::ExpandStatEntry>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter entries on trigger or scriptlet name
+        let filtered_entries: Vec<_> = if filter.is_empty() {
+            entries.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            entries
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| {
+                    e.trigger.to_lowercase().contains(&filter_lower)
+                        || e.name.to_lowercase().contains(&filter_lower)
+                })
+                .collect()
+        };
+        let filtered_len = filtered_entries.len();
+        let conflict_count = entries
+            .iter()
+            .filter(|e| !e.conflicts_with.is_empty())
+            .count();
+
+        // Key handler for the expansion stats list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("ExpandStats key: '{}'", key_str));
+
+                if let AppView::ExpandStatsView {
+                    entries,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_entries: Vec<_> = if filter.is_empty() {
+                        entries.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        entries
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, e)| {
+                                e.trigger.to_lowercase().contains(&filter_lower)
+                                    || e.name.to_lowercase().contains(&filter_lower)
+                            })
+                            .collect()
+                    };
+                    let filtered_len = filtered_entries.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Open the scriptlet source so conflicts can be fixed
+                            if let Some((_, entry)) = filtered_entries.get(*selected_index) {
+                                let entry = (*entry).clone();
+                                drop(filtered_entries);
+                                match &entry.source_path {
+                                    Some(path) => {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Opening scriptlet source: {}", path),
+                                        );
+                                        if let Err(e) = open::that(path) {
+                                            this.toast_manager.push(
+                                                components::toast::Toast::error(
+                                                    format!("Failed to open {}: {}", path, e),
+                                                    &this.theme,
+                                                )
+                                                .duration_ms(Some(5000)),
+                                            );
+                                        }
+                                    }
+                                    None => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::warning(
+                                                format!(
+                                                    "No source file recorded for {}",
+                                                    entry.trigger
+                                                ),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Search triggers...");
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(if filter.is_empty() {
+                    "No expand triggers found. Add `expand` metadata to a scriptlet."
+                } else {
+                    "No triggers match your search"
+                })
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let entries_for_closure: Vec<_> = filtered_entries
+                .iter()
+                .map(|(i, entry)| (*i, (*entry).clone()))
+                .collect();
+            let selected = selected_index;
+
+            uniform_list(
+                "expand-stats-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, entry)) = entries_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+                                let uses = match entry.usage_count {
+                                    1 => "1 use".to_string(),
+                                    n => format!("{} uses", n),
+                                };
+                                let description = if entry.conflicts_with.is_empty() {
+                                    format!("{} - {}", entry.name, uses)
+                                } else {
+                                    format!(
+                                        "{} - {} - conflicts with {}",
+                                        entry.name,
+                                        uses,
+                                        entry.conflicts_with.join(", ")
+                                    )
+                                };
+                                let icon = if entry.conflicts_with.is_empty() {
+                                    "📈"
+                                } else {
+                                    "⚠️"
+                                };
+
+                                div().id(ix).child(
+                                    ListItem::new(entry.trigger.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji(icon.to_string()))
+                                        .description_opt(Some(description))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = if conflict_count > 0 {
+            format!("{} triggers - {} conflicts", entries.len(), conflict_count)
+        } else {
+            format!("{} triggers", entries.len())
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("expand_stats")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("📈 Expansion Stats"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Trigger list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the GitHub view (repos, PRs awaiting review, notifications)
+    fn render_github(
+        &mut self,
+        items: Vec<github::GitHubItem>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter items on title or repo/description
+        let filtered_items: Vec<_> = if filter.is_empty() {
+            items.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            items
+                .iter()
+                .enumerate()
+                .filter(|(_, i)| {
+                    i.title.to_lowercase().contains(&filter_lower)
+                        || i.subtitle
+                            .as_ref()
+                            .is_some_and(|s| s.to_lowercase().contains(&filter_lower))
+                })
+                .collect()
+        };
+        let filtered_len = filtered_items.len();
+
+        // Key handler for the GitHub list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
+                logging::log("KEY", &format!("GitHub key: '{}'", key_str));
+
+                if let AppView::GitHubView {
+                    items,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_items: Vec<_> = if filter.is_empty() {
+                        items.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        items
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, i)| {
+                                i.title.to_lowercase().contains(&filter_lower)
+                                    || i.subtitle
+                                        .as_ref()
+                                        .is_some_and(|s| s.to_lowercase().contains(&filter_lower))
+                            })
+                            .collect()
+                    };
+                    let filtered_len = filtered_items.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Open the item in the browser
+                            if let Some((_, item)) = filtered_items.get(*selected_index) {
+                                let url = item.url.clone();
+                                drop(filtered_items);
+                                logging::log("EXEC", &format!("Opening GitHub URL: {}", url));
+                                if let Err(e) = open::that(&url) {
+                                    this.toast_manager.push(
+                                        components::toast::Toast::error(
+                                            format!("Failed to open {}: {}", url, e),
+                                            &this.theme,
+                                        )
+                                        .duration_ms(Some(5000)),
+                                    );
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Cmd+C copies the URL without leaving the view
+                        "c" if has_cmd => {
+                            if let Some((_, item)) = filtered_items.get(*selected_index) {
+                                let url = item.url.clone();
+                                drop(filtered_items);
+                                let copied = arboard::Clipboard::new()
+                                    .and_then(|mut clipboard| clipboard.set_text(&url));
+                                match copied {
+                                    Ok(_) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                format!("Copied {}", url),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Failed to copy URL: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Search GitHub...");
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(if !filter.is_empty() {
+                    "No items match your search"
+                } else if github::has_token() {
+                    "No GitHub data yet - refreshing in the background"
+                } else {
+                    "Set GITHUB_TOKEN with the env() prompt to connect GitHub"
+                })
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let items_for_closure: Vec<_> = filtered_items
+                .iter()
+                .map(|(i, item)| (*i, (*item).clone()))
+                .collect();
+            let selected = selected_index;
+
+            uniform_list(
+                "github-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, item)) = items_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+                                let description = match &item.subtitle {
+                                    Some(subtitle) => {
+                                        format!("{} - {}", item.kind.label(), subtitle)
+                                    }
+                                    None => item.kind.label().to_string(),
+                                };
+
+                                div().id(ix).child(
+                                    ListItem::new(item.title.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji(
+                                            item.kind.icon().to_string(),
+                                        ))
+                                        .description_opt(Some(description))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = format!("{} items", items.len());
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("github")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🐙 GitHub"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Item list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the Connect view (known Wi-Fi networks and paired Bluetooth devices)
+    fn render_connect(
+        &mut self,
+        targets: Vec<connectivity::ConnectTarget>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter targets by name
+        let filtered_targets: Vec<_> = if filter.is_empty() {
+            targets.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            targets
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| t.name.to_lowercase().contains(&filter_lower))
+                .collect()
+        };
+        let filtered_len = filtered_targets.len();
+
+        // Key handler for the connect list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("Connect key: '{}'", key_str));
+
+                if let AppView::ConnectView {
+                    targets,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_targets: Vec<_> = if filter.is_empty() {
+                        targets.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        targets
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, t)| t.name.to_lowercase().contains(&filter_lower))
+                            .collect()
+                    };
+                    let filtered_len = filtered_targets.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Connect to the selected target
+                            if let Some((_, target)) = filtered_targets.get(*selected_index) {
+                                let target = (*target).clone();
+                                drop(filtered_targets);
+                                logging::log(
+                                    "EXEC",
+                                    &format!("Connecting to {} ({})", target.name, target.id),
+                                );
+                                match connectivity::connect(&target) {
+                                    Ok(()) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                format!("Connecting to {}", target.name),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Failed to connect: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Search networks and devices...");
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(if !filter.is_empty() {
+                    "No targets match your search"
+                } else {
+                    "No known networks or paired devices found"
+                })
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let targets_for_closure: Vec<_> = filtered_targets
+                .iter()
+                .map(|(i, target)| (*i, (*target).clone()))
+                .collect();
+            let selected = selected_index;
+
+            uniform_list(
+                "connect-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, target)) = targets_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+
+                                div().id(ix).child(
+                                    ListItem::new(target.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji(
+                                            target.kind.icon().to_string(),
+                                        ))
+                                        .description_opt(Some(target.kind.label().to_string()))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = format!("{} targets", targets.len());
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("connect")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🔗 Connect"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Target list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the Focus Modes view (active mode marked, Enter toggles)
+    fn render_focus_modes(
+        &mut self,
+        modes: Vec<focus_mode::FocusMode>,
+        active: Option<String>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Filter modes by name
+        let filtered_modes: Vec<_> = if filter.is_empty() {
+            modes.iter().enumerate().collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            modes
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| m.name.to_lowercase().contains(&filter_lower))
+                .collect()
+        };
+        let filtered_len = filtered_modes.len();
+
+        // Key handler for the focus mode list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("Focus Modes key: '{}'", key_str));
+
+                if let AppView::FocusModesView {
+                    modes,
+                    active,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Apply filter to get current filtered list
+                    let filtered_modes: Vec<_> = if filter.is_empty() {
+                        modes.iter().enumerate().collect()
+                    } else {
+                        let filter_lower = filter.to_lowercase();
+                        modes
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, m)| m.name.to_lowercase().contains(&filter_lower))
+                            .collect()
+                    };
+                    let filtered_len = filtered_modes.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Toggle: deactivate the active mode, activate any other
+                            if let Some((_, mode)) = filtered_modes.get(*selected_index) {
+                                let mode = (*mode).clone();
+                                drop(filtered_modes);
+                                let is_active = active.as_deref() == Some(&mode.identifier);
+                                let result = if is_active {
+                                    focus_mode::set_focus(false).map(|_| ())
+                                } else {
+                                    focus_mode::activate_mode(&mode.name)
+                                };
+                                match result {
+                                    Ok(()) => {
+                                        *active = if is_active {
+                                            None
+                                        } else {
+                                            Some(mode.identifier.clone())
+                                        };
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                if is_active {
+                                                    format!("{} off", mode.name)
+                                                } else {
+                                                    format!("{} on", mode.name)
+                                                },
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Focus change failed: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Search Focus modes...");
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(if !filter.is_empty() {
+                    "No Focus modes match your search"
+                } else {
+                    "No Focus modes found - configure them in System Settings"
+                })
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let modes_for_closure: Vec<_> = filtered_modes
+                .iter()
+                .map(|(i, mode)| (*i, (*mode).clone()))
+                .collect();
+            let active_for_closure = active.clone();
+            let selected = selected_index;
+
+            uniform_list(
+                "focus-modes-list",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, mode)) = modes_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+                                let is_active = active_for_closure.as_deref()
+                                    == Some(&mode.identifier);
+                                let description = if is_active {
+                                    "Active - Enter turns it off".to_string()
+                                } else {
+                                    "Enter activates this Focus".to_string()
+                                };
+
+                                div().id(ix).child(
+                                    ListItem::new(mode.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji(
+                                            if is_active { "🌙" } else { "💤" }.to_string(),
+                                        ))
+                                        .description_opt(Some(description))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = format!("{} modes", modes.len());
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("focus_modes")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🌙 Focus Modes"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Mode list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the World Clock view (favorites, lookups, and conversions)
+    fn render_world_clock(
+        &mut self,
+        favorites: Vec<String>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // The filter IS the query: rows are recomputed from it every frame
+        // so clocks stay current while the view is open
+        let rows = world_clock::results(&filter, &favorites);
+        let rows_len = rows.len();
+
+        // Key handler for the clock list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("World Clock key: '{}'", key_str));
+
+                if let AppView::WorldClockView {
+                    favorites,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    let rows = world_clock::results(filter, favorites);
+                    let rows_len = rows.len();
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < rows_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Copy the selected time to the clipboard
+                            if let Some(row) = rows.get(*selected_index) {
+                                let text = row.copy_text.clone();
+                                let label = row.title.clone();
+                                let copied = arboard::Clipboard::new()
+                                    .and_then(|mut clipboard| clipboard.set_text(&text));
+                                match copied {
+                                    Ok(_) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                format!("Copied {}", label),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(3000)),
+                                        );
+                                    }
+                                    Err(e) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Failed to copy time: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("time in tokyo, 3pm PT in CET...");
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if rows_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child("No matching timezones - try \"time in tokyo\" or \"3pm PT in CET\"")
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let rows_for_closure = rows.clone();
+            let selected = selected_index;
+
+            uniform_list(
+                "world-clock-list",
+                rows_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some(row) = rows_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+
+                                div().id(ix).child(
+                                    ListItem::new(row.title.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji("🕒".to_string()))
+                                        .description_opt(Some(row.description.clone()))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = format!("{} results", rows_len);
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("world_clock")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🕒 World Clock"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Clock list
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    /// Render the Search Files view (Spotlight-backed)
+    ///
+    /// Typing in this view IS the query: the key handler re-runs the
+    /// Spotlight search on every filter change and stores the results in the
+    /// view state. Enter opens the selected file, Cmd+Enter reveals it in
+    /// Finder.
+    fn render_file_search(
+        &mut self,
+        results: Vec<file_search::FileResult>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        let results_len = results.len();
+
+        // Key handler for the results list
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
+                logging::log("KEY", &format!("Search Files key: '{}'", key_str));
+
+                if let AppView::FileSearchView {
+                    results,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < results.len().saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Open the selected file (Cmd+Enter reveals in Finder)
+                            if let Some(hit) = results.get(*selected_index) {
+                                let path = hit.path.clone();
+                                let mut command = std::process::Command::new("open");
+                                if has_cmd {
+                                    logging::log("EXEC", &format!("Revealing in Finder: {}", path));
+                                    command.arg("-R");
+                                } else {
+                                    logging::log("EXEC", &format!("Opening file: {}", path));
+                                }
+                                if let Err(e) = command.arg(&path).spawn() {
+                                    logging::log("ERROR", &format!("Failed to open {}: {}", path, e));
+                                } else {
+                                    // Hide window after opening
+                                    script_kit_gpui::set_main_window_visible(false);
+                                    cx.hide();
+                                    NEEDS_RESET.store(true, Ordering::SeqCst);
+                                }
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                // Re-query Spotlight for the shorter filter
+                                *results = if filter.len() >= 2 {
+                                    file_search::search_files(filter, None, 50)
+                                } else {
+                                    Vec::new()
+                                };
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        // Re-query Spotlight; short filters match too
+                                        // much, so wait for at least 2 characters
+                                        *results = if filter.len() >= 2 {
+                                            file_search::search_files(filter, None, 50)
+                                        } else {
+                                            Vec::new()
+                                        };
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Search files...");
+
+        // Keep the preview cache in sync with the selected result
+        // (json/csv/md/png files get a right-hand preview panel,
+        // read on the task pool to keep the render path non-blocking)
+        let selected_path = results
+            .get(selected_index)
+            .filter(|hit| hit.file_type != file_search::FileType::Directory)
+            .map(|hit| hit.path.clone());
+        self.request_file_preview(selected_path, cx);
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if results_len == 0 {
+            let empty_message = if filter.len() < 2 {
+                "Type at least 2 characters to search"
+            } else {
+                "No files found"
+            };
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(empty_message)
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let results_for_closure = results.clone();
+            let selected = selected_index;
+
+            uniform_list(
+                "file-search-list",
+                results_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some(hit) = results_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+                                let icon = match hit.file_type {
+                                    file_search::FileType::Directory => "📁",
+                                    file_search::FileType::Application => "🚀",
+                                    file_search::FileType::Image => "🖼️",
+                                    file_search::FileType::Document => "📄",
+                                    file_search::FileType::Audio => "🎵",
+                                    file_search::FileType::Video => "🎬",
+                                    _ => "📄",
+                                };
+
+                                div().id(ix).child(
+                                    ListItem::new(hit.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji(icon.to_string()))
+                                        .description_opt(Some(hit.path.clone()))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = format!("{} results", results_len);
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("file_search")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("🔍 Search Files"),
+                    )
+                    // Search input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Results list with optional file preview panel on the right
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .flex_1()
+                            .min_w(px(0.))
+                            .py(px(design_spacing.padding_xs))
+                            .child(list_element),
+                    )
+                    // Preview is read on the task pool; hold the panel's
+                    // width with a placeholder while one is in flight
+                    .when(self.file_preview_loading.is_some(), |d| {
+                        d.child(
+                            div()
+                                .w(px(280.0))
+                                .flex_none()
+                                .h_full()
+                                .border_l_1()
+                                .border_color(rgba((ui_border << 8) | 0x60))
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .text_sm()
+                                .text_color(rgb(text_muted))
+                                .child("Loading preview…"),
+                        )
+                    })
+                    .when_some(
+                        self.file_preview_cache
+                            .as_ref()
+                            .and_then(|(_, preview)| preview.as_ref()),
+                        |d, preview| {
+                            let preview_colors = file_preview::PreviewColors {
+                                text: design_colors.text_secondary,
+                                muted: design_colors.text_muted,
+                                border: design_colors.border,
+                                accent: design_colors.accent,
+                            };
+                            d.child(
+                                div()
+                                    .w(px(280.0))
+                                    .flex_none()
+                                    .h_full()
+                                    .overflow_hidden()
+                                    .border_l_1()
+                                    .border_color(rgba((ui_border << 8) | 0x60))
+                                    .p_2()
+                                    .child(file_preview::render_preview(preview, preview_colors)),
+                            )
+                        },
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_screenshot_history(
+        &mut self,
+        shots: Vec<screenshot_history::Screenshot>,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
+                logging::log("KEY", &format!("Screenshot History key: '{}'", key_str));
+
+                // OCR runs after the current_view borrow ends
+                let mut ocr_path: Option<std::path::PathBuf> = None;
+
+                if let AppView::ScreenshotHistoryView {
+                    shots,
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    let visible = screenshot_history::filter_screenshots(shots, filter);
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < visible.len().saturating_sub(1) {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            // Open in Preview for quick annotation
+                            // (Cmd+Enter reveals in Finder instead)
+                            if let Some(shot) = visible.get(*selected_index) {
+                                let path = shot.path.clone();
+                                let mut command = std::process::Command::new("open");
+                                if has_cmd {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("Revealing screenshot: {}", path.display()),
+                                    );
+                                    command.arg("-R");
+                                } else {
+                                    logging::log(
+                                        "EXEC",
+                                        &format!("Annotating screenshot: {}", path.display()),
+                                    );
+                                    command.args(["-a", "Preview"]);
+                                }
+                                if let Err(e) = command.arg(&path).spawn() {
+                                    logging::log(
+                                        "ERROR",
+                                        &format!("Failed to open {}: {}", path.display(), e),
+                                    );
+                                } else {
+                                    // Hide window after handing off
+                                    script_kit_gpui::set_main_window_visible(false);
+                                    cx.hide();
+                                    NEEDS_RESET.store(true, Ordering::SeqCst);
+                                }
+                            }
+                        }
+                        "o" if has_cmd => {
+                            if let Some(shot) = visible.get(*selected_index) {
+                                ocr_path = Some(shot.path.clone());
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() && !has_cmd {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(path) = ocr_path {
+                    #[cfg(not(feature = "ocr"))]
+                    {
+                        let _ = path;
+                        this.toast_manager.push(
+                            components::toast::Toast::warning(
+                                "OCR support is not enabled in this build",
+                                &this.theme,
+                            )
+                            .duration_ms(Some(3000)),
+                        );
+                        cx.notify();
+                    }
+                    #[cfg(feature = "ocr")]
+                    {
+                        logging::log(
+                            "EXEC",
+                            &format!("OCR on screenshot: {}", path.display()),
+                        );
+                        let toast = match ocr::extract_text_from_png_file(&path) {
+                            Ok(text) if !text.trim().is_empty() => {
+                                use arboard::Clipboard;
+                                match Clipboard::new().and_then(|mut c| c.set_text(&text)) {
+                                    Ok(()) => components::toast::Toast::success(
+                                        format!(
+                                            "Copied {} characters of recognized text",
+                                            text.chars().count()
+                                        ),
+                                        &this.theme,
+                                    )
+                                    .duration_ms(Some(3000)),
+                                    Err(e) => components::toast::Toast::error(
+                                        format!("Failed to copy text: {}", e),
+                                        &this.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                }
+                            }
+                            Ok(_) => components::toast::Toast::warning(
+                                "No text found in screenshot",
+                                &this.theme,
+                            )
+                            .duration_ms(Some(3000)),
+                            Err(e) => components::toast::Toast::error(
+                                format!("OCR failed: {}", e),
+                                &this.theme,
+                            )
+                            .duration_ms(Some(5000)),
+                        };
+                        this.toast_manager.push(toast);
+                        cx.notify();
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from("Filter screenshots...");
+
+        let visible: Vec<screenshot_history::Screenshot> =
+            screenshot_history::filter_screenshots(&shots, &filter)
+                .into_iter()
+                .cloned()
+                .collect();
+        let visible_len = visible.len();
+
+        // Keep the thumbnail cache in sync with the selected screenshot,
+        // decoding on the task pool to keep the render path non-blocking
+        let selected_path = visible
+            .get(selected_index)
+            .map(|shot| shot.path.to_string_lossy().into_owned());
+        self.request_file_preview(selected_path, cx);
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+
+        // Build virtualized list
+        let list_element: AnyElement = if visible_len == 0 {
+            let empty_message = if shots.is_empty() {
+                "No screenshots found"
+            } else {
+                "No screenshots match the filter"
+            };
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(empty_message)
+                .into_any_element()
+        } else {
+            let visible_for_closure = visible.clone();
+            let selected = selected_index;
+
+            uniform_list(
+                "screenshot-history-list",
+                visible_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some(shot) = visible_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+                                let age = screenshot_history::format_age(shot.modified);
+
+                                div().id(ix).child(
+                                    ListItem::new(shot.name.clone(), list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji("📸".to_string()))
+                                        .description_opt(Some(age))
+                                        .selected(is_selected)
+                                        .with_accent_bar(true),
+                                )
+                            } else {
+                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.list_scroll_handle)
+            .into_any_element()
+        };
+
+        let summary = format!("{} screenshots", visible_len);
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("screenshot_history")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    // Title
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child("📸 Screenshot History"),
+                    )
+                    // Filter input with blinking cursor
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(summary),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // List with thumbnail panel for the selected screenshot
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .flex_1()
+                            .min_w(px(0.))
+                            .py(px(design_spacing.padding_xs))
+                            .child(list_element),
+                    )
+                    // Preview is read on the task pool; hold the panel's
+                    // width with a placeholder while one is in flight
+                    .when(self.file_preview_loading.is_some(), |d| {
+                        d.child(
+                            div()
+                                .w(px(280.0))
+                                .flex_none()
+                                .h_full()
+                                .border_l_1()
+                                .border_color(rgba((ui_border << 8) | 0x60))
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .text_sm()
+                                .text_color(rgb(text_muted))
+                                .child("Loading preview…"),
+                        )
+                    })
+                    .when_some(
+                        self.file_preview_cache
+                            .as_ref()
+                            .and_then(|(_, preview)| preview.as_ref()),
+                        |d, preview| {
+                            let preview_colors = file_preview::PreviewColors {
+                                text: design_colors.text_secondary,
+                                muted: design_colors.text_muted,
+                                border: design_colors.border,
+                                accent: design_colors.accent,
+                            };
+                            d.child(
+                                div()
+                                    .w(px(280.0))
+                                    .flex_none()
+                                    .h_full()
+                                    .overflow_hidden()
+                                    .border_l_1()
+                                    .border_color(rgba((ui_border << 8) | 0x60))
+                                    .p_2()
+                                    .child(file_preview::render_preview(preview, preview_colors)),
+                            )
+                        },
+                    ),
+            )
+            // Footer with shortcut hints
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_xs))
+                    .text_xs()
+                    .text_color(rgb(text_dimmed))
+                    .child("⏎ Annotate · ⌘⏎ Reveal · ⌘O Copy Text"),
+            )
+            .into_any_element()
+    }
+
+    fn render_batch_rename(
+        &mut self,
+        state: batch_rename::BatchRenameState,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
+
+                // Escape in the pattern stage steps back to file picking;
+                // everywhere else the global handler dismisses the view
+                if key_str == "escape" {
+                    if let AppView::BatchRenameView { state } = &mut this.current_view {
+                        if state.stage == batch_rename::Stage::Pattern {
+                            state.back_to_pick();
+                            cx.notify();
+                            return;
+                        }
+                    }
+                }
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                // Cmd+Z reverts the last applied batch from any stage
+                if has_cmd && key_str == "z" {
+                    let toast = match batch_rename::undo_last() {
+                        Ok(0) => components::toast::Toast::info(
+                            "Nothing to undo".to_string(),
+                            &this.theme,
+                        ),
+                        Ok(restored) => components::toast::Toast::success(
+                            format!("Restored {} file name(s)", restored),
+                            &this.theme,
+                        ),
+                        Err(e) => components::toast::Toast::error(
+                            format!("Undo failed: {}", e),
+                            &this.theme,
+                        ),
+                    };
+                    this.toast_manager.push(toast);
+                    cx.notify();
+                    return;
+                }
+
+                let mut applied_toast = None;
+                if let AppView::BatchRenameView { state } = &mut this.current_view {
+                    match state.stage {
+                        batch_rename::Stage::PickFiles => match key_str.as_str() {
+                            "up" | "arrowup" => {
+                                if state.selected_index > 0 {
+                                    state.selected_index -= 1;
+                                    cx.notify();
+                                }
+                            }
+                            "down" | "arrowdown" => {
+                                let count = state.visible_entries().len();
+                                if state.selected_index < count.saturating_sub(1) {
+                                    state.selected_index += 1;
+                                    cx.notify();
+                                }
+                            }
+                            "space" | " " => {
+                                state.toggle_selected(state.selected_index);
+                                cx.notify();
+                            }
+                            "right" | "arrowright" | "tab" => {
+                                state.enter_dir(state.selected_index);
+                                cx.notify();
+                            }
+                            "left" | "arrowleft" => {
+                                state.parent_dir();
+                                cx.notify();
+                            }
+                            "enter" => {
+                                state.enter_pattern_stage();
+                                cx.notify();
+                            }
+                            "backspace" => {
+                                if !state.filter.is_empty() {
+                                    state.filter.pop();
+                                    state.selected_index = 0;
+                                    cx.notify();
+                                }
+                            }
+                            _ => {
+                                if let Some(ref key_char) = event.keystroke.key_char {
+                                    if let Some(ch) = key_char.chars().next() {
+                                        if !ch.is_control() {
+                                            state.filter.push(ch);
+                                            state.selected_index = 0;
+                                            cx.notify();
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        batch_rename::Stage::Pattern => match key_str.as_str() {
+                            "up" | "arrowup" => {
+                                if state.selected_index > 0 {
+                                    state.selected_index -= 1;
+                                    cx.notify();
+                                }
+                            }
+                            "down" | "arrowdown" => {
+                                if state.selected_index < state.selected.len().saturating_sub(1) {
+                                    state.selected_index += 1;
+                                    cx.notify();
+                                }
+                            }
+                            "enter" => {
+                                let plan = state.plan();
+                                if plan.has_conflicts() || plan.ready_count() == 0 {
+                                    applied_toast = Some(components::toast::Toast::warning(
+                                        "Nothing to rename (fix conflicts first)".to_string(),
+                                        &this.theme,
+                                    ));
+                                } else {
+                                    match batch_rename::apply_plan(&plan) {
+                                        Ok(renamed) => {
+                                            applied_toast =
+                                                Some(components::toast::Toast::success(
+                                                    format!(
+                                                        "Renamed {} file(s) - ⌘Z to undo",
+                                                        renamed
+                                                    ),
+                                                    &this.theme,
+                                                ));
+                                            // Back to a fresh pick stage in the same dir
+                                            *state = batch_rename::BatchRenameState::new();
+                                        }
+                                        Err(e) => {
+                                            applied_toast = Some(components::toast::Toast::error(
+                                                format!("Rename failed: {}", e),
+                                                &this.theme,
+                                            ));
+                                        }
+                                    }
+                                }
+                                cx.notify();
+                            }
+                            "backspace" => {
+                                if !state.pattern.is_empty() {
+                                    state.pattern.pop();
+                                    cx.notify();
+                                }
+                            }
+                            _ => {
+                                if let Some(ref key_char) = event.keystroke.key_char {
+                                    if let Some(ch) = key_char.chars().next() {
+                                        if !ch.is_control() {
+                                            state.pattern.push(ch);
+                                            cx.notify();
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+                if let Some(toast) = applied_toast {
+                    this.toast_manager.push(toast);
+                }
+            },
+        );
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+        let accent = design_colors.accent;
+        let error_color = design_colors.error;
+
+        let (title, input_text, input_placeholder, summary) = match state.stage {
+            batch_rename::Stage::PickFiles => (
+                "✏️ Batch Rename",
+                state.filter.clone(),
+                SharedString::from("Filter files... (Space selects, Enter continues)"),
+                format!("{} selected", state.selected.len()),
+            ),
+            batch_rename::Stage::Pattern => {
+                let plan = state.plan();
+                let summary = if plan.has_conflicts() {
+                    "conflicts - fix pattern".to_string()
+                } else {
+                    format!("{} will rename", plan.ready_count())
+                };
+                (
+                    "✏️ Rename Pattern",
+                    state.pattern.clone(),
+                    SharedString::from("e.g. photo-{n:3}.{ext} or /IMG_(\\d+)/pic-{1}.png"),
+                    summary,
+                )
+            }
+        };
+
+        // Build the stage-specific list
+        let list_element: AnyElement = match state.stage {
+            batch_rename::Stage::PickFiles => {
+                let entries = state.visible_entries();
+                if entries.is_empty() {
+                    div()
+                        .w_full()
+                        .py(px(design_spacing.padding_xl))
+                        .text_center()
+                        .text_color(rgb(text_muted))
+                        .font_family(design_typography.font_family)
+                        .child("Empty directory")
+                        .into_any_element()
+                } else {
+                    let selected = state.selected_index;
+                    let selected_flags: Vec<bool> =
+                        (0..entries.len()).map(|ix| state.is_selected(ix)).collect();
+
+                    uniform_list(
+                        "batch-rename-list",
+                        entries.len(),
+                        move |visible_range, _window, _cx| {
+                            visible_range
+                                .map(|ix| {
+                                    let (name, is_dir) = &entries[ix];
+                                    let is_focused = ix == selected;
+                                    let icon = if *is_dir {
+                                        "📁"
+                                    } else if selected_flags[ix] {
+                                        "☑"
+                                    } else {
+                                        "☐"
+                                    };
+                                    div().id(ix).child(
+                                        ListItem::new(name.clone(), list_colors)
+                                            .icon_kind(list_item::IconKind::Emoji(
+                                                icon.to_string(),
+                                            ))
+                                            .selected(is_focused)
+                                            .with_accent_bar(true),
+                                    )
+                                })
+                                .collect()
+                        },
+                    )
+                    .h_full()
+                    .track_scroll(&self.list_scroll_handle)
+                    .into_any_element()
+                }
+            }
+            batch_rename::Stage::Pattern => {
+                let plan = state.plan();
+                let mut rows = div().flex().flex_col().w_full();
+                for (ix, item) in plan.items.iter().enumerate() {
+                    let old_name = item
+                        .from
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    let is_focused = ix == state.selected_index;
+                    let (new_color, note) = match &item.status {
+                        batch_rename::ItemStatus::Ready => (accent, String::new()),
+                        batch_rename::ItemStatus::Unchanged => {
+                            (text_muted, " (unchanged)".to_string())
+                        }
+                        batch_rename::ItemStatus::Conflict(reason) => {
+                            (error_color, format!(" ({})", reason))
+                        }
+                    };
+                    rows = rows.child(
+                        div()
+                            .w_full()
+                            .px(px(design_spacing.padding_lg))
+                            .py(px(design_spacing.padding_xs))
+                            .flex()
+                            .flex_row()
+                            .gap_2()
+                            .items_center()
+                            .when(is_focused, |d| {
+                                d.bg(rgba((design_colors.background_selected << 8) | 0x60))
+                            })
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .overflow_hidden()
+                                    .whitespace_nowrap()
+                                    .text_sm()
+                                    .text_color(rgb(text_primary))
+                                    .child(old_name),
+                            )
+                            .child(div().text_sm().text_color(rgb(text_dimmed)).child("→"))
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .overflow_hidden()
+                                    .whitespace_nowrap()
+                                    .text_sm()
+                                    .text_color(rgb(new_color))
+                                    .child(format!("{}{}", item.new_name, note)),
+                            ),
+                    );
+                }
+                rows.into_any_element()
+            }
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("batch_rename")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .child(div().text_sm().text_color(rgb(text_dimmed)).child(title))
+                    .child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .text_lg()
+                            .child(
+                                TextInput::from_text(input_text)
+                                    .placeholder(input_placeholder)
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
+                    )
+                    .child(div().text_sm().text_color(rgb(text_dimmed)).child(summary)),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Breadcrumb: current directory in the pick stage
+            .when(state.stage == batch_rename::Stage::PickFiles, |d| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(design_spacing.padding_lg))
+                        .py(px(design_spacing.padding_xs))
+                        .text_xs()
+                        .text_color(rgb(text_muted))
+                        .child(state.dir.to_string_lossy().into_owned()),
+                )
+            })
+            // Stage content
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(list_element),
+            )
+            .into_any_element()
+    }
+
+    fn render_file_mover(
+        &mut self,
+        state: file_mover::MoverState,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
+
+                // Escape steps back a stage; only the pick stage dismisses
+                if key_str == "escape" {
+                    if let AppView::FileMoverView { state } = &mut this.current_view {
+                        match state.stage {
+                            file_mover::Stage::PickDest => {
+                                state.back_to_pick();
+                                cx.notify();
+                                return;
+                            }
+                            file_mover::Stage::Conflicts => {
+                                state.stage = file_mover::Stage::PickDest;
+                                state.selected_index = 0;
+                                cx.notify();
+                                return;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                // Set when a destination (or conflict choice) is confirmed;
+                // the worker thread and poller start after the borrow ends
+                let mut start_moves: Option<Vec<(std::path::PathBuf, std::path::PathBuf)>> = None;
+                let mut bookmark_toast = None;
+
+                if let AppView::FileMoverView { state } = &mut this.current_view {
+                    match state.stage {
+                        file_mover::Stage::PickFiles => match key_str.as_str() {
+                            "up" | "arrowup" => {
+                                if state.selected_index > 0 {
+                                    state.selected_index -= 1;
+                                    cx.notify();
+                                }
+                            }
+                            "down" | "arrowdown" => {
+                                let count = state.visible_entries().len();
+                                if state.selected_index < count.saturating_sub(1) {
+                                    state.selected_index += 1;
+                                    cx.notify();
+                                }
+                            }
+                            "space" | " " => {
+                                state.toggle_selected(state.selected_index);
+                                cx.notify();
+                            }
+                            "right" | "arrowright" | "tab" => {
+                                state.enter_dir(state.selected_index);
+                                cx.notify();
+                            }
+                            "left" | "arrowleft" => {
+                                state.parent_dir();
+                                cx.notify();
+                            }
+                            "enter" => {
+                                state.enter_dest_stage();
+                                cx.notify();
+                            }
+                            "backspace" => {
+                                if !state.filter.is_empty() {
+                                    state.filter.pop();
+                                    state.selected_index = 0;
+                                    cx.notify();
+                                }
+                            }
+                            _ => {
+                                if let Some(ref key_char) = event.keystroke.key_char {
+                                    if let Some(ch) = key_char.chars().next() {
+                                        if !ch.is_control() {
+                                            state.filter.push(ch);
+                                            state.selected_index = 0;
+                                            cx.notify();
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        file_mover::Stage::PickDest => match key_str.as_str() {
+                            "up" | "arrowup" => {
+                                if state.selected_index > 0 {
+                                    state.selected_index -= 1;
+                                    cx.notify();
+                                }
+                            }
+                            "down" | "arrowdown" => {
+                                let count = state.dest_rows().len();
+                                if state.selected_index < count.saturating_sub(1) {
+                                    state.selected_index += 1;
+                                    cx.notify();
+                                }
+                            }
+                            "right" | "arrowright" | "tab" => {
+                                state.enter_dir(state.selected_index);
+                                cx.notify();
+                            }
+                            "left" | "arrowleft" => {
+                                state.parent_dir();
+                                cx.notify();
+                            }
+                            "d" if has_cmd => {
+                                file_mover::add_bookmark(&state.dir);
+                                bookmark_toast = Some(components::toast::Toast::success(
+                                    format!("Bookmarked {}", state.dir.display()),
+                                    &this.theme,
+                                ));
+                                cx.notify();
+                            }
+                            "enter" => {
+                                if let Some(dest) = state.chosen_dest(state.selected_index) {
+                                    file_mover::add_bookmark(&dest);
+                                    if state.choose_destination(dest) {
+                                        // No conflicts - start right away
+                                        start_moves = Some(file_mover::resolve_moves(
+                                            &state.plan,
+                                            file_mover::ConflictChoice::Skip,
+                                        ));
+                                    }
+                                    cx.notify();
+                                }
+                            }
+                            "backspace" => {
+                                if !state.filter.is_empty() {
+                                    state.filter.pop();
+                                    state.selected_index = 0;
+                                    cx.notify();
+                                }
+                            }
+                            _ => {
+                                if let Some(ref key_char) = event.keystroke.key_char {
+                                    if let Some(ch) = key_char.chars().next() {
+                                        if !ch.is_control() && !has_cmd {
+                                            state.filter.push(ch);
+                                            state.selected_index = 0;
+                                            cx.notify();
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        file_mover::Stage::Conflicts => match key_str.as_str() {
+                            "up" | "arrowup" => {
+                                if state.selected_index > 0 {
+                                    state.selected_index -= 1;
+                                    cx.notify();
+                                }
+                            }
+                            "down" | "arrowdown" => {
+                                if state.selected_index < 2 {
+                                    state.selected_index += 1;
+                                    cx.notify();
+                                }
+                            }
+                            "enter" => {
+                                let choice = match state.selected_index {
+                                    0 => file_mover::ConflictChoice::Skip,
+                                    1 => file_mover::ConflictChoice::Replace,
+                                    _ => file_mover::ConflictChoice::KeepBoth,
+                                };
+                                start_moves =
+                                    Some(file_mover::resolve_moves(&state.plan, choice));
+                                state.stage = file_mover::Stage::Moving;
+                                cx.notify();
+                            }
+                            _ => {}
+                        },
+                        // Moves run on the worker thread; nothing to handle
+                        file_mover::Stage::Moving => {}
+                    }
+                }
+
+                if let Some(toast) = bookmark_toast {
+                    this.toast_manager.push(toast);
+                }
+
+                if let Some(moves) = start_moves {
+                    if moves.is_empty() {
+                        this.toast_manager.push(components::toast::Toast::info(
+                            "Nothing to move".to_string(),
+                            &this.theme,
+                        ));
+                        if let AppView::FileMoverView { state } = &mut this.current_view {
+                            *state = file_mover::MoverState::new();
+                        }
+                        cx.notify();
+                        return;
+                    }
+
+                    let progress = std::sync::Arc::new(file_mover::MoveProgress::default());
+                    if let AppView::FileMoverView { state } = &mut this.current_view {
+                        state.progress = Some(progress.clone());
+                    }
+
+                    // Worker thread does the renames/copies; the poller
+                    // repaints the progress bar and reports the outcome
+                    let worker_progress = progress.clone();
+                    std::thread::spawn(move || {
+                        file_mover::run_moves(moves, worker_progress);
+                    });
+
+                    cx.spawn(async move |this, cx| {
+                        loop {
+                            Timer::after(std::time::Duration::from_millis(100)).await;
+                            let finished = progress
+                                .finished
+                                .load(std::sync::atomic::Ordering::Relaxed);
+                            let done = cx.update(|cx| {
+                                this.update(cx, |app, cx| {
+                                    if finished {
+                                        let moved = progress
+                                            .files_done
+                                            .load(std::sync::atomic::Ordering::Relaxed);
+                                        let errors = progress
+                                            .errors
+                                            .lock()
+                                            .map(|e| e.len())
+                                            .unwrap_or(0);
+                                        let toast = if errors > 0 {
+                                            components::toast::Toast::warning(
+                                                format!(
+                                                    "Moved {} item(s), {} failed",
+                                                    moved - errors,
+                                                    errors
+                                                ),
+                                                &app.theme,
+                                            )
+                                            .duration_ms(Some(5000))
+                                        } else {
+                                            components::toast::Toast::success(
+                                                format!("Moved {} item(s)", moved),
+                                                &app.theme,
+                                            )
+                                            .duration_ms(Some(3000))
+                                        };
+                                        app.toast_manager.push(toast);
+                                        if let AppView::FileMoverView { state } =
+                                            &mut app.current_view
+                                        {
+                                            *state = file_mover::MoverState::new();
+                                        }
+                                    }
+                                    cx.notify();
+                                })
+                            });
+                            if finished || done.is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .detach();
+                    cx.notify();
+                }
+            },
+        );
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+        let accent = design_colors.accent;
+
+        let (title, input_placeholder, summary) = match state.stage {
+            file_mover::Stage::PickFiles => (
+                "📦 Move Files",
+                SharedString::from("Filter... (Space selects, Enter picks destination)"),
+                format!("{} selected", state.selected.len()),
+            ),
+            file_mover::Stage::PickDest => (
+                "📦 Choose Destination",
+                SharedString::from("Filter folders... (⌘D bookmarks this folder)"),
+                format!("moving {} item(s)", state.selected.len()),
+            ),
+            file_mover::Stage::Conflicts => (
+                "📦 Resolve Conflicts",
+                SharedString::from(""),
+                format!("{} name(s) already exist", state.conflicts().len()),
+            ),
+            file_mover::Stage::Moving => (
+                "📦 Moving...",
+                SharedString::from(""),
+                String::new(),
+            ),
+        };
+
+        // Build the stage-specific content
+        let content: AnyElement = match state.stage {
+            file_mover::Stage::PickFiles => {
+                let entries = state.visible_entries();
+                if entries.is_empty() {
+                    div()
+                        .w_full()
+                        .py(px(design_spacing.padding_xl))
+                        .text_center()
+                        .text_color(rgb(text_muted))
+                        .font_family(design_typography.font_family)
+                        .child("Empty directory")
+                        .into_any_element()
+                } else {
+                    let selected = state.selected_index;
+                    let selected_flags: Vec<bool> =
+                        (0..entries.len()).map(|ix| state.is_selected(ix)).collect();
+
+                    uniform_list(
+                        "file-mover-pick-list",
+                        entries.len(),
+                        move |visible_range, _window, _cx| {
+                            visible_range
+                                .map(|ix| {
+                                    let (name, is_dir) = &entries[ix];
+                                    let is_focused = ix == selected;
+                                    let icon = if selected_flags[ix] {
+                                        "☑"
+                                    } else if *is_dir {
+                                        "📁"
+                                    } else {
+                                        "☐"
+                                    };
+                                    div().id(ix).child(
+                                        ListItem::new(name.clone(), list_colors)
+                                            .icon_kind(list_item::IconKind::Emoji(
+                                                icon.to_string(),
+                                            ))
+                                            .selected(is_focused)
+                                            .with_accent_bar(true),
+                                    )
+                                })
+                                .collect()
+                        },
+                    )
+                    .h_full()
+                    .track_scroll(&self.list_scroll_handle)
+                    .into_any_element()
+                }
+            }
+            file_mover::Stage::PickDest => {
+                let rows = state.dest_rows();
+                let selected = state.selected_index;
+                let item_count = state.selected.len();
+
+                uniform_list(
+                    "file-mover-dest-list",
+                    rows.len(),
+                    move |visible_range, _window, _cx| {
+                        visible_range
+                            .map(|ix| {
+                                let (icon, label) = match &rows[ix] {
+                                    file_mover::DestRow::Here => (
+                                        "📍",
+                                        format!("Move {} item(s) here", item_count),
+                                    ),
+                                    file_mover::DestRow::Bookmark(path) => {
+                                        ("🔖", path.to_string_lossy().into_owned())
+                                    }
+                                    file_mover::DestRow::Dir(name) => ("📁", name.clone()),
+                                };
+                                div().id(ix).child(
+                                    ListItem::new(label, list_colors)
+                                        .icon_kind(list_item::IconKind::Emoji(icon.to_string()))
+                                        .selected(ix == selected)
+                                        .with_accent_bar(true),
+                                )
+                            })
+                            .collect()
+                    },
+                )
+                .h_full()
+                .track_scroll(&self.list_scroll_handle)
+                .into_any_element()
+            }
+            file_mover::Stage::Conflicts => {
+                let mut rows = div().flex().flex_col().w_full();
+                for item in state.conflicts() {
+                    let name = item
+                        .to
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    rows = rows.child(
+                        div()
+                            .w_full()
+                            .px(px(design_spacing.padding_lg))
+                            .py(px(design_spacing.padding_xs))
+                            .text_sm()
+                            .text_color(rgb(text_muted))
+                            .child(format!("⚠ {} already exists", name)),
+                    );
+                }
+                rows = rows.child(
+                    div()
+                        .mx(px(design_spacing.padding_lg))
+                        .my(px(design_spacing.padding_xs))
+                        .h(px(design_visual.border_thin))
+                        .bg(rgba((ui_border << 8) | 0x60)),
+                );
+                for (ix, label) in [
+                    "Skip conflicting items",
+                    "Replace existing items",
+                    "Keep both (rename with a number)",
+                ]
+                .iter()
+                .enumerate()
+                {
+                    let is_focused = ix == state.selected_index;
+                    rows = rows.child(
+                        div()
+                            .w_full()
+                            .px(px(design_spacing.padding_lg))
+                            .py(px(design_spacing.padding_sm))
+                            .text_sm()
+                            .text_color(rgb(if is_focused { accent } else { text_primary }))
+                            .when(is_focused, |d| {
+                                d.bg(rgba((design_colors.background_selected << 8) | 0x60))
+                            })
+                            .child(*label),
+                    );
+                }
+                rows.into_any_element()
+            }
+            file_mover::Stage::Moving => {
+                let (fraction, files_done, files_total, current) = state
+                    .progress
+                    .as_ref()
+                    .map(|p| {
+                        (
+                            p.fraction(),
+                            p.files_done.load(Ordering::Relaxed),
+                            p.files_total.load(Ordering::Relaxed),
+                            p.current.lock().map(|c| c.clone()).unwrap_or_default(),
+                        )
+                    })
+                    .unwrap_or((0.0, 0, 0, String::new()));
+
+                div()
+                    .flex()
+                    .flex_col()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_xl))
+                    .gap_3()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_primary))
+                            .child(format!("Moving {} ({}/{})", current, files_done, files_total)),
+                    )
+                    // Byte-weighted progress bar
+                    .child(
+                        div()
+                            .w_full()
+                            .h(px(8.0))
+                            .rounded(px(design_visual.radius_sm))
+                            .bg(rgba((ui_border << 8) | 0x40))
+                            .flex()
+                            .flex_row()
+                            .child(
+                                div()
+                                    .h_full()
+                                    .rounded(px(design_visual.radius_sm))
+                                    .flex_basis(gpui::relative(fraction.max(0.02)))
+                                    .bg(rgb(accent)),
+                            ),
+                    )
+                    .into_any_element()
+            }
+        };
+
+        let show_input = matches!(
+            state.stage,
+            file_mover::Stage::PickFiles | file_mover::Stage::PickDest
+        );
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("file_mover")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .child(div().text_sm().text_color(rgb(text_dimmed)).child(title))
+                    .when(show_input, |d| {
+                        d.child(
+                            div()
+                                .flex_1()
+                                .flex()
+                                .flex_row()
+                                .items_center()
+                                .text_lg()
+                                .child(
+                                    TextInput::from_text(state.filter.clone())
+                                        .placeholder(input_placeholder.clone())
+                                        .cursor_visible(self.cursor_visible)
+                                        .text_color(text_primary)
+                                        .placeholder_color(text_muted),
+                                ),
+                        )
+                    })
+                    .when(!show_input, |d| d.child(div().flex_1()))
+                    .child(div().text_sm().text_color(rgb(text_dimmed)).child(summary)),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Breadcrumb: current directory while browsing
+            .when(show_input, |d| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(design_spacing.padding_lg))
+                        .py(px(design_spacing.padding_xs))
+                        .text_xs()
+                        .text_color(rgb(text_muted))
+                        .child(state.dir.to_string_lossy().into_owned()),
+                )
+            })
+            // Stage content
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(content),
+            )
+            .into_any_element()
+    }
+
+    fn render_secrets_vault(
+        &mut self,
+        state: secrets_vault::SecretsState,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
+
+                // Escape in a sub-stage steps back to the key list
+                if key_str == "escape" {
+                    if let AppView::SecretsVaultView { state } = &mut this.current_view {
+                        if state.stage != secrets_vault::Stage::List {
+                            state.stage = secrets_vault::Stage::List;
+                            state.edit_value.clear();
+                            cx.notify();
+                            return;
+                        }
+                    }
+                }
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let mut toast = None;
+                if let AppView::SecretsVaultView { state } = &mut this.current_view {
+                    match state.stage {
+                        secrets_vault::Stage::List => match key_str.as_str() {
+                            "up" | "arrowup" => {
+                                if state.selected_index > 0 {
+                                    state.selected_index -= 1;
+                                    state.revealed = None;
+                                    cx.notify();
+                                }
+                            }
+                            "down" | "arrowdown" => {
+                                let count = state.visible_keys().len();
+                                if state.selected_index < count.saturating_sub(1) {
+                                    state.selected_index += 1;
+                                    state.revealed = None;
+                                    cx.notify();
+                                }
+                            }
+                            "enter" => {
+                                // Reveal until the selection moves
+                                state.reveal_selected();
+                                cx.notify();
+                            }
+                            "c" if has_cmd => {
+                                if let Some(key) = state.selected_key() {
+                                    match prompts::env::get_secret(&key) {
+                                        Some(value) => {
+                                            let copied = arboard::Clipboard::new()
+                                                .and_then(|mut c| c.set_text(&value));
+                                            toast = Some(match copied {
+                                                Ok(()) => components::toast::Toast::success(
+                                                    format!("Copied {}", key),
+                                                    &this.theme,
+                                                )
+                                                .duration_ms(Some(3000)),
+                                                Err(e) => components::toast::Toast::error(
+                                                    format!("Failed to copy: {}", e),
+                                                    &this.theme,
+                                                )
+                                                .duration_ms(Some(5000)),
+                                            });
+                                        }
+                                        None => {
+                                            toast = Some(components::toast::Toast::warning(
+                                                format!("No value stored for {}", key),
+                                                &this.theme,
+                                            ));
+                                        }
+                                    }
+                                    cx.notify();
+                                }
+                            }
+                            "e" if has_cmd => {
+                                state.begin_edit();
+                                cx.notify();
+                            }
+                            "backspace" if has_cmd => {
+                                if let Some(key) = state.selected_key() {
+                                    toast =
+                                        Some(match prompts::env::delete_secret(&key) {
+                                            Ok(()) => components::toast::Toast::success(
+                                                format!("Deleted {}", key),
+                                                &this.theme,
+                                            ),
+                                            Err(e) => components::toast::Toast::error(
+                                                format!("Delete failed: {}", e),
+                                                &this.theme,
+                                            ),
+                                        });
+                                    state.reload();
+                                    cx.notify();
+                                }
+                            }
+                            "s" if has_cmd => {
+                                state.stage = secrets_vault::Stage::ConfirmExport;
+                                state.selected_index = state.selected_index.min(
+                                    state.visible_keys().len().saturating_sub(1),
+                                );
+                                cx.notify();
+                            }
+                            "backspace" => {
+                                if !state.filter.is_empty() {
+                                    state.filter.pop();
+                                    state.selected_index = 0;
+                                    state.revealed = None;
+                                    cx.notify();
+                                }
+                            }
+                            _ => {
+                                if let Some(ref key_char) = event.keystroke.key_char {
+                                    if let Some(ch) = key_char.chars().next() {
+                                        if !ch.is_control() && !has_cmd {
+                                            state.filter.push(ch);
+                                            state.selected_index = 0;
+                                            state.revealed = None;
+                                            cx.notify();
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        secrets_vault::Stage::Edit => match key_str.as_str() {
+                            "enter" => {
+                                let key = state.edit_key.clone();
+                                toast = Some(match state.commit_edit() {
+                                    Ok(()) => components::toast::Toast::success(
+                                        format!("Updated {}", key),
+                                        &this.theme,
+                                    ),
+                                    Err(e) => components::toast::Toast::error(
+                                        format!("Update failed: {}", e),
+                                        &this.theme,
+                                    ),
+                                });
+                                cx.notify();
+                            }
+                            "backspace" => {
+                                state.edit_value.pop();
+                                cx.notify();
+                            }
+                            _ => {
+                                if let Some(ref key_char) = event.keystroke.key_char {
+                                    if let Some(ch) = key_char.chars().next() {
+                                        if !ch.is_control() {
+                                            state.edit_value.push(ch);
+                                            cx.notify();
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        secrets_vault::Stage::ConfirmExport => {
+                            if key_str == "enter" {
+                                toast = Some(match secrets_vault::export_to_env_file() {
+                                    Ok((count, path)) => components::toast::Toast::success(
+                                        format!(
+                                            "Exported {} secret(s) to {}",
+                                            count,
+                                            path.display()
+                                        ),
+                                        &this.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                    Err(e) => components::toast::Toast::error(
+                                        format!("Export failed: {}", e),
+                                        &this.theme,
+                                    ),
+                                });
+                                state.stage = secrets_vault::Stage::List;
+                                cx.notify();
+                            }
+                        }
+                    }
+                }
+                if let Some(toast) = toast {
+                    this.toast_manager.push(toast);
+                }
+            },
+        );
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+        let accent = design_colors.accent;
+        let warning_color = design_colors.warning;
+
+        let visible = state.visible_keys();
+
+        let (title, summary) = match state.stage {
+            secrets_vault::Stage::List => {
+                ("🔐 Secrets Vault", format!("{} keys", visible.len()))
+            }
+            secrets_vault::Stage::Edit => {
+                ("🔐 Update Secret", state.edit_key.clone())
+            }
+            secrets_vault::Stage::ConfirmExport => {
+                ("🔐 Export Secrets", format!("{} keys", state.keys.len()))
+            }
+        };
+
+        // Build the stage-specific content
+        let content: AnyElement = match state.stage {
+            secrets_vault::Stage::List => {
+                if visible.is_empty() {
+                    let empty_message = if state.keys.is_empty() {
+                        "No secrets stored yet - scripts using env() will add them"
+                    } else {
+                        "No keys match the filter"
+                    };
+                    div()
+                        .w_full()
+                        .py(px(design_spacing.padding_xl))
+                        .text_center()
+                        .text_color(rgb(text_muted))
+                        .font_family(design_typography.font_family)
+                        .child(empty_message)
+                        .into_any_element()
+                } else {
+                    let selected = state.selected_index;
+                    let revealed = state.revealed.clone();
+                    let keys_for_closure = visible.clone();
+
+                    uniform_list(
+                        "secrets-vault-list",
+                        visible.len(),
+                        move |visible_range, _window, _cx| {
+                            visible_range
+                                .map(|ix| {
+                                    let key = &keys_for_closure[ix];
+                                    let value = match &revealed {
+                                        Some((revealed_key, value)) if revealed_key == key => {
+                                            value.clone()
+                                        }
+                                        _ => "••••••••".to_string(),
+                                    };
+                                    div().id(ix).child(
+                                        ListItem::new(key.clone(), list_colors)
+                                            .icon_kind(list_item::IconKind::Emoji(
+                                                "🔑".to_string(),
+                                            ))
+                                            .description_opt(Some(value))
+                                            .selected(ix == selected)
+                                            .with_accent_bar(true),
+                                    )
+                                })
+                                .collect()
+                        },
+                    )
+                    .h_full()
+                    .track_scroll(&self.list_scroll_handle)
+                    .into_any_element()
+                }
+            }
+            secrets_vault::Stage::Edit => div()
+                .flex()
+                .flex_col()
+                .w_full()
+                .px(px(design_spacing.padding_lg))
+                .py(px(design_spacing.padding_xl))
+                .gap_2()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(text_muted))
+                        .child(format!("New value for {} (stored in the keyring)", state.edit_key)),
+                )
+                .child(
+                    div()
+                        .text_lg()
+                        .text_color(rgb(text_primary))
+                        .child(if state.edit_value.is_empty() {
+                            "…".to_string()
+                        } else {
+                            "•".repeat(state.edit_value.chars().count())
+                        }),
+                )
+                .into_any_element(),
+            secrets_vault::Stage::ConfirmExport => div()
+                .flex()
+                .flex_col()
+                .w_full()
+                .px(px(design_spacing.padding_lg))
+                .py(px(design_spacing.padding_xl))
+                .gap_2()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(warning_color))
+                        .child(format!(
+                            "⚠ Write {} secret(s) as plaintext to ~/.sk/kit/.env?",
+                            state.keys.len()
+                        )),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(text_muted))
+                        .child("Anyone with access to that file can read the values."),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(accent))
+                        .child("⏎ Export · Esc Cancel"),
+                )
+                .into_any_element(),
+        };
+
+        let show_input = state.stage == secrets_vault::Stage::List;
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("secrets_vault")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header with input
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .child(div().text_sm().text_color(rgb(text_dimmed)).child(title))
+                    .when(show_input, |d| {
+                        d.child(
+                            div()
+                                .flex_1()
+                                .flex()
+                                .flex_row()
+                                .items_center()
+                                .text_lg()
+                                .child(
+                                    TextInput::from_text(state.filter.clone())
+                                        .placeholder(SharedString::from("Filter keys..."))
+                                        .cursor_visible(self.cursor_visible)
+                                        .text_color(text_primary)
+                                        .placeholder_color(text_muted),
+                                ),
+                        )
+                    })
+                    .when(!show_input, |d| d.child(div().flex_1()))
+                    .child(div().text_sm().text_color(rgb(text_dimmed)).child(summary)),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            // Stage content
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .w_full()
+                    .py(px(design_spacing.padding_xs))
+                    .child(content),
+            )
+            // Footer with shortcut hints
+            .when(show_input, |d| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(design_spacing.padding_lg))
+                        .py(px(design_spacing.padding_xs))
+                        .text_xs()
+                        .text_color(rgb(text_dimmed))
+                        .child("⏎ Reveal · ⌘C Copy · ⌘E Update · ⌘⌫ Delete · ⌘S Export .env"),
+                )
+            })
+            .into_any_element()
+    }
+
+    /// Render the rich script-failure panel: error message, highlighted
+    /// source snippet at the failing line, and action rows
+    fn render_error_view(
+        &mut self,
+        error_message: String,
+        details: Option<String>,
+        script_path: String,
+        frame: executor::StackFrame,
+        snippet: Vec<(u32, String)>,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Key handler: up/down picks an action, Enter runs it
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+
+                // Copy needed data out before any mutation below
+                let (frame_path, frame_line, copy_text, selected) =
+                    if let AppView::ErrorView {
+                        frame,
+                        details,
+                        error_message,
+                        selected_index,
+                        ..
+                    } = &this.current_view
+                    {
+                        (
+                            frame.path.clone(),
+                            frame.line,
+                            details.clone().unwrap_or_else(|| error_message.clone()),
+                            *selected_index,
+                        )
+                    } else {
+                        return;
+                    };
+
+                match key_str.as_str() {
+                    "up" | "arrowup" => {
+                        if let AppView::ErrorView { selected_index, .. } = &mut this.current_view {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                cx.notify();
+                            }
+                        }
+                    }
+                    "down" | "arrowdown" => {
+                        if let AppView::ErrorView { selected_index, .. } = &mut this.current_view {
+                            if *selected_index < 1 {
+                                *selected_index += 1;
+                                cx.notify();
+                            }
+                        }
+                    }
+                    "enter" => {
+                        if selected == 0 {
+                            // Open the failing file at the error line
+                            if let Err(e) = script_creation::open_in_editor_at_line(
+                                std::path::Path::new(&frame_path),
+                                frame_line,
+                                &this.config,
+                            ) {
+                                logging::log("ERROR", &format!("Failed to open editor: {}", e));
+                                this.toast_manager.push(
+                                    components::toast::Toast::error(
+                                        format!("Failed to open editor: {}", e),
+                                        &this.theme,
+                                    )
+                                    .duration_ms(Some(5000)),
+                                );
+                                cx.notify();
+                            } else {
+                                script_kit_gpui::set_main_window_visible(false);
+                                cx.hide();
+                                NEEDS_RESET.store(true, Ordering::SeqCst);
+                            }
+                        } else {
+                            // Copy the full error text
+                            let copied = arboard::Clipboard::new()
+                                .and_then(|mut clipboard| clipboard.set_text(&copy_text));
+                            match copied {
+                                Ok(_) => {
+                                    this.toast_manager.push(
+                                        components::toast::Toast::success(
+                                            "Error copied to clipboard",
+                                            &this.theme,
+                                        )
+                                        .duration_ms(Some(3000)),
+                                    );
+                                }
+                                Err(e) => {
+                                    this.toast_manager.push(
+                                        components::toast::Toast::error(
+                                            format!("Failed to copy: {}", e),
+                                            &this.theme,
+                                        )
+                                        .duration_ms(Some(5000)),
+                                    );
+                                }
+                            }
+                            cx.notify();
+                        }
+                    }
+                    _ => {}
+                }
+            },
+        );
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let error_color = design_colors.error;
+        let ui_border = design_colors.border;
+
+        let script_name = std::path::Path::new(&script_path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(script_path.as_str())
+            .to_string();
+        let location = format!("{}:{}", frame.path, frame.line);
+        let error_line = frame.line;
+        let _ = details; // Full text only needed by the copy action above
+
+        // Source snippet with the failing line highlighted
+        let snippet_block = div()
+            .flex()
+            .flex_col()
+            .mx(px(design_spacing.padding_lg))
+            .my(px(design_spacing.padding_sm))
+            .px(px(design_spacing.padding_md))
+            .py(px(design_spacing.padding_sm))
+            .rounded(px(design_visual.radius_md))
+            .bg(rgba((design_colors.background_tertiary << 8) | 0x80))
+            .font_family(design_typography.font_family_mono)
+            .text_sm()
+            .children(snippet.into_iter().map(|(line_number, text)| {
+                let is_error_line = line_number == error_line;
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_3()
+                    .px(px(design_spacing.padding_xs))
+                    .when(is_error_line, |d| {
+                        d.bg(rgba((error_color << 8) | 0x30))
+                            .rounded(px(design_visual.radius_sm))
+                    })
+                    .child(
+                        div()
+                            .w(px(40.))
+                            .flex_none()
+                            .text_color(rgb(if is_error_line {
+                                error_color
+                            } else {
+                                text_dimmed
+                            }))
+                            .child(format!("{}", line_number)),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_color(rgb(if is_error_line {
+                                text_primary
+                            } else {
+                                text_muted
+                            }))
+                            .child(text),
+                    )
+            }));
+
+        // Action rows
+        let actions = div()
+            .flex()
+            .flex_col()
+            .py(px(design_spacing.padding_xs))
+            .child(
+                div().id("error-open-at-line").child(
+                    ListItem::new("Open at line", list_colors)
+                        .icon_kind(list_item::IconKind::Emoji("📝".to_string()))
+                        .description_opt(Some(location.clone()))
+                        .selected(selected_index == 0)
+                        .with_accent_bar(true),
+                ),
+            )
+            .child(
+                div().id("error-copy").child(
+                    ListItem::new("Copy error", list_colors)
+                        .icon_kind(list_item::IconKind::Emoji("📋".to_string()))
+                        .description_opt(Some("Copy the full error text".to_string()))
+                        .selected(selected_index == 1)
+                        .with_accent_bar(true),
+                ),
+            );
+
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .rounded(px(design_visual.radius_lg))
+            .text_color(rgb(text_primary))
+            .font_family(design_typography.font_family)
+            .key_context("error_view")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            // Header: error message + failing script
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .gap_2()
+                            .child(div().child("❌"))
+                            .child(
+                                div()
+                                    .text_lg()
+                                    .text_color(rgb(error_color))
+                                    .child(error_message),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(format!("{} · {}", script_name, location)),
+                    ),
+            )
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            .child(snippet_block)
+            // Divider
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            .child(actions)
+            .into_any_element()
+    }
+
+    /// Render design gallery view with group header and icon variations
+    fn render_design_gallery(
+        &mut self,
+        filter: String,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        use designs::group_header_variations::{GroupHeaderCategory, GroupHeaderStyle};
+        use designs::icon_variations::{IconCategory, IconName, IconStyle};
+
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Build gallery items: group headers grouped by category, then icons grouped by category
+        #[derive(Clone)]
+        enum GalleryItem {
+            GroupHeaderCategory(GroupHeaderCategory),
+            GroupHeader(GroupHeaderStyle),
+            IconCategoryHeader(IconCategory),
+            Icon(IconName, IconStyle),
+        }
+
+        let mut gallery_items: Vec<GalleryItem> = Vec::new();
+
+        // Add group headers by category
+        for category in GroupHeaderCategory::all() {
+            gallery_items.push(GalleryItem::GroupHeaderCategory(*category));
+            for style in category.styles() {
+                gallery_items.push(GalleryItem::GroupHeader(*style));
+            }
+        }
+
+        // Add icons by category, showing each icon with default style
+        for category in IconCategory::all() {
+            gallery_items.push(GalleryItem::IconCategoryHeader(*category));
+            for icon in category.icons() {
+                gallery_items.push(GalleryItem::Icon(icon, IconStyle::Default));
+            }
+        }
+
+        // Filter items based on current filter
+        let filtered_items: Vec<(usize, GalleryItem)> = if filter.is_empty() {
+            gallery_items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i, item.clone()))
+                .collect()
+        } else {
+            let filter_lower = filter.to_lowercase();
+            gallery_items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| match item {
+                    GalleryItem::GroupHeaderCategory(cat) => {
+                        cat.name().to_lowercase().contains(&filter_lower)
+                    }
+                    GalleryItem::GroupHeader(style) => {
+                        style.name().to_lowercase().contains(&filter_lower)
+                            || style.description().to_lowercase().contains(&filter_lower)
+                    }
+                    GalleryItem::IconCategoryHeader(cat) => {
+                        cat.name().to_lowercase().contains(&filter_lower)
+                    }
+                    GalleryItem::Icon(icon, _) => {
+                        icon.name().to_lowercase().contains(&filter_lower)
+                            || icon.description().to_lowercase().contains(&filter_lower)
+                    }
+                })
+                .map(|(i, item)| (i, item.clone()))
+                .collect()
+        };
+        let filtered_len = filtered_items.len();
+
+        // Key handler for design gallery
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W) - handled first regardless of view state
+                // Global shortcuts (Cmd+W, ESC for dismissable views)
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("DesignGallery key: '{}'", key_str));
+
+                if let AppView::DesignGalleryView {
+                    filter,
+                    selected_index,
+                } = &mut this.current_view
+                {
+                    // Re-compute filtered_len for this scope
+                    let total_items = GroupHeaderStyle::count()
+                        + IconName::count()
+                        + GroupHeaderCategory::all().len()
+                        + IconCategory::all().len();
+                    let current_filtered_len = total_items;
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                this.design_gallery_scroll_handle
+                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index < current_filtered_len.saturating_sub(1) {
+                                *selected_index += 1;
+                                this.design_gallery_scroll_handle
+                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                cx.notify();
+                            }
+                        }
+                        // Note: "escape" is handled by handle_global_shortcut_with_options above
+                        "backspace" => {
+                            if !filter.is_empty() {
+                                filter.pop();
+                                *selected_index = 0;
+                                this.design_gallery_scroll_handle
+                                    .scroll_to_item(0, ScrollStrategy::Top);
+                                cx.notify();
+                            }
+                        }
+                        _ => {
+                            if let Some(ref key_char) = event.keystroke.key_char {
+                                if let Some(ch) = key_char.chars().next() {
+                                    if !ch.is_control() {
+                                        filter.push(ch);
+                                        *selected_index = 0;
+                                        this.design_gallery_scroll_handle
+                                            .scroll_to_item(0, ScrollStrategy::Top);
+                                        cx.notify();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let input_placeholder = SharedString::from(i18n::strings().search_design);
+
+        // Pre-compute colors
+        let list_colors = ListItemColors::from_design(&design_colors);
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+        let _accent = design_colors.accent;
+
+        // Build virtualized list
+        let list_element: AnyElement = if filtered_len == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(design_colors.text_muted))
+                .font_family(design_typography.font_family)
+                .child(i18n::strings().design_no_match)
+                .into_any_element()
+        } else {
+            // Clone data for the closure
+            let items_for_closure = filtered_items.clone();
+            let selected = selected_index;
+            let _list_colors_clone = list_colors; // Kept for future use
+            let design_spacing_clone = design_spacing;
+            let design_typography_clone = design_typography;
+            let design_visual_clone = design_visual;
+            let design_colors_clone = design_colors;
+
+            uniform_list(
+                "design-gallery",
+                filtered_len,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some((_, item)) = items_for_closure.get(ix) {
+                                let is_selected = ix == selected;
+
+                                let element: AnyElement = match item {
+                                    GalleryItem::GroupHeaderCategory(category) => {
+                                        // Category header - styled as section header
+                                        div()
+                                            .id(ElementId::NamedInteger(
+                                                "gallery-header-cat".into(),
+                                                ix as u64,
+                                            ))
+                                            .w_full()
+                                            .h(px(32.0))
+                                            .px(px(design_spacing_clone.padding_lg))
+                                            .flex()
+                                            .items_center()
+                                            .bg(rgba(
+                                                (design_colors_clone.background_secondary << 8)
+                                                    | 0x80,
+                                            ))
+                                            .child(
+                                                div()
+                                                    .text_sm()
+                                                    .font_weight(gpui::FontWeight::BOLD)
+                                                    .text_color(rgb(design_colors_clone.accent))
+                                                    .child(format!(
+                                                        "── Group Headers: {} ──",
+                                                        category.name()
+                                                    )),
+                                            )
+                                            .into_any_element()
+                                    }
+                                    GalleryItem::GroupHeader(style) => render_group_header_item(
+                                        ix,
+                                        is_selected,
+                                        style,
+                                        &design_spacing_clone,
+                                        &design_typography_clone,
+                                        &design_visual_clone,
+                                        &design_colors_clone,
+                                    ),
+                                    GalleryItem::IconCategoryHeader(category) => {
+                                        // Icon category header
+                                        div()
+                                            .id(ElementId::NamedInteger(
+                                                "gallery-icon-cat".into(),
+                                                ix as u64,
+                                            ))
                                             .w_full()
                                             .h(px(32.0))
                                             .px(px(design_spacing_clone.padding_lg))
@@ -1962,39 +6948,15 @@ impl ScriptListApp {
                             .flex_row()
                             .items_center()
                             .text_lg()
-                            .text_color(if input_is_empty {
-                                rgb(text_muted)
-                            } else {
-                                rgb(text_primary)
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .mr(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            })
-                            .when(input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
-                                        .child(input_display.clone()),
-                                )
-                            })
-                            .when(!input_is_empty, |d| d.child(input_display.clone()))
-                            .when(!input_is_empty, |d| {
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .ml(px(CURSOR_GAP_X))
-                                        .when(self.cursor_visible, |d| d.bg(rgb(text_primary))),
-                                )
-                            }),
+                            // Shared TextInput component: placeholder alignment,
+                            // cursor-at-end, and blink handling
+                            .child(
+                                TextInput::from_text(filter.clone())
+                                    .placeholder(input_placeholder.clone())
+                                    .cursor_visible(self.cursor_visible)
+                                    .text_color(text_primary)
+                                    .placeholder_color(text_muted),
+                            ),
                     )
                     .child(
                         div()
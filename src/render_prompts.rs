@@ -6,76 +6,15 @@
 
 impl ScriptListApp {
     /// Render the arg input text with cursor and selection highlight
-    fn render_arg_input_text(&self, text_primary: u32, accent_color: u32) -> gpui::Div {
-        let text = self.arg_input.text();
-        let chars: Vec<char> = text.chars().collect();
-        let cursor_pos = self.arg_input.cursor();
-        let has_selection = self.arg_input.has_selection();
+    fn render_arg_input_text(&self, text_primary: u32, accent_color: u32) -> TextInput {
         // Separate focus state from blink state to avoid layout shift
         let is_focused = self.focused_input == FocusedInput::ArgPrompt;
         let is_cursor_visible = is_focused && self.cursor_visible;
 
-        if text.is_empty() {
-            // Empty - always reserve cursor space, only show bg when visible
-            return div().flex().flex_row().items_center().child(
-                div()
-                    .w(px(CURSOR_WIDTH))
-                    .h(px(CURSOR_HEIGHT_LG))
-                    .when(is_cursor_visible, |d: gpui::Div| d.bg(rgb(text_primary))),
-            );
-        }
-
-        if has_selection {
-            // With selection: before | selected | after (no cursor shown during selection)
-            let selection = self.arg_input.selection();
-            let (start, end) = selection.range();
-
-            let before: String = chars[..start].iter().collect();
-            let selected: String = chars[start..end].iter().collect();
-            let after: String = chars[end..].iter().collect();
-
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .overflow_x_hidden()
-                .when(!before.is_empty(), |d: gpui::Div| {
-                    d.child(div().child(before))
-                })
-                .child(
-                    div()
-                        .bg(rgba((accent_color << 8) | 0x60))
-                        .text_color(rgb(0xffffff))
-                        .child(selected),
-                )
-                .when(!after.is_empty(), |d: gpui::Div| {
-                    d.child(div().child(after))
-                })
-        } else {
-            // No selection: before cursor | cursor | after cursor
-            // Always reserve cursor space to prevent layout shift during blink
-            let before: String = chars[..cursor_pos].iter().collect();
-            let after: String = chars[cursor_pos..].iter().collect();
-
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .overflow_x_hidden()
-                .when(!before.is_empty(), |d: gpui::Div| {
-                    d.child(div().child(before))
-                })
-                // Always render cursor element, only show bg when visible
-                .child(
-                    div()
-                        .w(px(CURSOR_WIDTH))
-                        .h(px(CURSOR_HEIGHT_LG))
-                        .when(is_cursor_visible, |d: gpui::Div| d.bg(rgb(text_primary))),
-                )
-                .when(!after.is_empty(), |d: gpui::Div| {
-                    d.child(div().child(after))
-                })
-        }
+        TextInput::from_state(&self.arg_input, false)
+            .text_color(text_primary)
+            .accent_color(accent_color)
+            .cursor_visible(is_cursor_visible)
     }
 
     fn render_arg_prompt(
@@ -92,7 +31,7 @@ impl ScriptListApp {
         let has_choices = !choices.is_empty();
 
         // Use design tokens for GLOBAL theming - all prompts use current design
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
@@ -169,19 +108,21 @@ impl ScriptListApp {
                                 cx.notify();
                                 return;
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                                return;
-                            }
                             _ => {
-                                // Route character input to the dialog for search
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                                 return;
                             }
                         }
@@ -234,6 +175,8 @@ impl ScriptListApp {
                             );
                             cx.notify();
                         }
+                        // Paginated prompts: fetch the next page near the end
+                        this.maybe_request_more_choices();
                         return;
                     }
                     "enter" => {
@@ -254,13 +197,15 @@ impl ScriptListApp {
                     _ => {}
                 }
 
-                // Delegate all other keys to TextInputState for editing, selection, clipboard
-                let key_char = event.keystroke.key_char.as_deref();
+                // Delegate all other keys to TextInputState for editing, selection, clipboard.
+                // key_char is withheld: printable characters arrive through the OS
+                // input handler (replace_text_in_range), which also carries IME
+                // composition and dead keys - inserting here would double them.
                 let old_text = this.arg_input.text().to_string();
 
                 let handled = this.arg_input.handle_key(
                     &key_str,
-                    key_char,
+                    None,
                     modifiers.platform, // Cmd key on macOS
                     modifiers.alt,
                     modifiers.shift,
@@ -278,8 +223,6 @@ impl ScriptListApp {
             },
         );
 
-        let input_is_empty = self.arg_input.is_empty();
-
         // P4: Pre-compute theme values for arg prompt using design tokens for GLOBAL theming
         let arg_list_colors = ListItemColors::from_design(&design_colors);
         let text_primary = design_colors.text_primary;
@@ -288,6 +231,7 @@ impl ScriptListApp {
 
         // P0: Clone data needed for uniform_list closure
         let arg_selected_index = self.arg_selected_index;
+        let arg_filter_text = self.arg_input.text().to_string();
         let filtered_choices = self.get_filtered_arg_choices_owned();
         let filtered_choices_len = filtered_choices.len();
         logging::log_debug(
@@ -298,6 +242,28 @@ impl ScriptListApp {
             ),
         );
 
+        // Pre-decode choice `img` paths into the cache so the render closure stays cheap.
+        // Failed reads/decodes are skipped silently; the emoji `icon` (if any) is the fallback.
+        for (_, choice) in &filtered_choices {
+            if let Some(img_path) = &choice.img {
+                if !self.arg_choice_image_cache.contains_key(img_path) {
+                    if let Ok(png_data) = std::fs::read(img_path) {
+                        if let Ok(render_image) = list_item::decode_png_to_render_image(&png_data) {
+                            self.arg_choice_image_cache
+                                .insert(img_path.clone(), render_image);
+                        }
+                    }
+                }
+            }
+        }
+        let arg_image_cache = self.arg_choice_image_cache.clone();
+
+        // Mouse support for choice rows: handlers go through the entity handle
+        // because the uniform_list closure only receives `&mut App`, not a Context
+        let list_entity = cx.entity();
+        let mouse_prompt_id = id.clone();
+        let mouse_has_actions = has_actions;
+
         // P0: Build virtualized choice list using uniform_list
         let list_element: AnyElement = if filtered_choices_len == 0 {
             div()
@@ -324,14 +290,80 @@ impl ScriptListApp {
                             if let Some((_, choice)) = filtered_choices.get(ix) {
                                 let is_selected = ix == arg_selected_index;
 
+                                // Prefer a decoded `img` over the emoji `icon`
+                                let icon_kind = choice
+                                    .img
+                                    .as_ref()
+                                    .and_then(|path| arg_image_cache.get(path).cloned())
+                                    .map(IconKind::Image)
+                                    .or_else(|| choice.icon.clone().map(IconKind::Emoji));
+
+                                // P4: Lazy match indices - only computed for visible rows
+                                let (name_indices, desc_indices) = if arg_filter_text.is_empty() {
+                                    (Vec::new(), Vec::new())
+                                } else {
+                                    scripts::fuzzy_match_choice(
+                                        &choice.name,
+                                        choice.description.as_deref(),
+                                        &arg_filter_text,
+                                    )
+                                    .map(|(_, name_ix, desc_ix)| (name_ix, desc_ix))
+                                    .unwrap_or_default()
+                                };
+
+                                let click_entity = list_entity.clone();
+                                let click_prompt_id = mouse_prompt_id.clone();
+                                let click_value = choice.value.clone();
+                                let context_entity = list_entity.clone();
+
                                 // Use shared ListItem component for consistent design
-                                div().id(ix).child(
-                                    ListItem::new(choice.name.clone(), arg_list_colors)
-                                        .description_opt(choice.description.clone())
-                                        .selected(is_selected)
-                                        .with_accent_bar(true)
-                                        .index(ix),
-                                )
+                                div()
+                                    .id(ix)
+                                    .cursor_pointer()
+                                    // Click selects; double-click submits the choice
+                                    .on_click(move |event: &gpui::ClickEvent, _window, cx| {
+                                        click_entity.update(cx, |this, cx| {
+                                            if this.arg_selected_index != ix {
+                                                this.arg_selected_index = ix;
+                                                cx.notify();
+                                            }
+                                            if let gpui::ClickEvent::Mouse(mouse_event) = event {
+                                                if mouse_event.down.click_count == 2 {
+                                                    this.submit_prompt_response(
+                                                        click_prompt_id.clone(),
+                                                        Some(click_value.clone()),
+                                                        cx,
+                                                    );
+                                                }
+                                            }
+                                        });
+                                    })
+                                    // Right-click selects and opens the actions
+                                    // dialog (when the script provided actions)
+                                    .on_mouse_down(
+                                        gpui::MouseButton::Right,
+                                        move |_event, window, cx| {
+                                            context_entity.update(cx, |this, cx| {
+                                                if this.arg_selected_index != ix {
+                                                    this.arg_selected_index = ix;
+                                                    cx.notify();
+                                                }
+                                                if mouse_has_actions {
+                                                    this.toggle_arg_actions(cx, window);
+                                                }
+                                            });
+                                        },
+                                    )
+                                    .child(
+                                        ListItem::new(choice.name.clone(), arg_list_colors)
+                                            .description_opt(choice.description.clone())
+                                            .icon_kind_opt(icon_kind)
+                                            .name_match_indices(name_indices)
+                                            .description_match_indices(desc_indices)
+                                            .selected(is_selected)
+                                            .with_accent_bar(true)
+                                            .index(ix),
+                                    )
                             } else {
                                 div().id(ix).h(px(LIST_ITEM_HEIGHT))
                             }
@@ -368,6 +400,25 @@ impl ScriptListApp {
             .key_context("arg_prompt")
             .track_focus(&self.focus_handle)
             .on_key_down(handle_key)
+            // Register the OS text-input handler over the prompt so IME
+            // composition and dead keys reach arg_input (the EntityInputHandler
+            // impl in app_impl.rs routes the protocol to TextInputState)
+            .child({
+                let ime_entity = cx.entity();
+                let ime_focus_handle = self.focus_handle.clone();
+                gpui::canvas(
+                    move |bounds, window, cx| {
+                        window.handle_input(
+                            &ime_focus_handle,
+                            gpui::ElementInputHandler::new(bounds, ime_entity),
+                            cx,
+                        );
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
             // Header with input - uses shared header constants for visual consistency with main menu
             .child(
                 div()
@@ -386,40 +437,13 @@ impl ScriptListApp {
                             .flex_row()
                             .items_center()
                             .text_xl()
-                            .text_color(if input_is_empty {
-                                rgb(text_muted)
-                            } else {
-                                rgb(text_primary)
-                            })
-                            // When empty: show cursor (always reserve space) + placeholder
-                            .when(input_is_empty, |d: gpui::Div| {
-                                let is_cursor_visible = self.focused_input
-                                    == FocusedInput::ArgPrompt
-                                    && self.cursor_visible;
-                                // Always render cursor div to reserve space, only show bg when visible
-                                // ALIGNMENT: cursor takes CURSOR_WIDTH + CURSOR_GAP_X space, so we
-                                // apply negative margin to placeholder to align it with typed text
-                                d.child(
-                                    div()
-                                        .w(px(CURSOR_WIDTH))
-                                        .h(px(CURSOR_HEIGHT_LG))
-                                        .my(px(CURSOR_MARGIN_Y))
-                                        .mr(px(CURSOR_GAP_X))
-                                        .when(is_cursor_visible, |d: gpui::Div| {
-                                            d.bg(rgb(text_primary))
-                                        }),
-                                )
-                                .child(
-                                    div()
-                                        .ml(px(-(CURSOR_WIDTH + CURSOR_GAP_X)))
-                                        .text_color(rgb(text_muted))
-                                        .child(placeholder.clone()),
-                                )
-                            })
-                            // When has text: show text with cursor/selection via helper
-                            .when(!input_is_empty, |d: gpui::Div| {
-                                d.child(self.render_arg_input_text(text_primary, accent_color))
-                            }),
+                            // Shared TextInput component: placeholder, cursor,
+                            // and selection rendering (blink handled via helper)
+                            .child(
+                                self.render_arg_input_text(text_primary, accent_color)
+                                    .placeholder(placeholder.clone())
+                                    .placeholder_color(text_muted),
+                            ),
                     )
                     // CLS-FREE ACTIONS AREA: Matches main menu pattern exactly
                     // Both states are always rendered at the same position, visibility toggled via opacity
@@ -432,7 +456,7 @@ impl ScriptListApp {
                         let search_text = self
                             .actions_dialog
                             .as_ref()
-                            .map(|dialog| dialog.read(cx).search_text.clone())
+                            .map(|dialog| dialog.read(cx).search_text().to_string())
                             .unwrap_or_default();
                         let search_is_empty = search_text.is_empty();
                         let search_display: SharedString = if search_is_empty {
@@ -579,6 +603,42 @@ impl ScriptListApp {
             )
             // Choices list (only when prompt has choices)
             .when(has_choices, |d| {
+                // Wheel scrolling mirrors keyboard navigation: index-based
+                // movement that clamps to the filtered choices
+                let handle_wheel = cx.listener(
+                    |this: &mut Self, event: &gpui::ScrollWheelEvent, _window, cx| {
+                        let lines = match event.delta {
+                            gpui::ScrollDelta::Lines(point) => point.y,
+                            gpui::ScrollDelta::Pixels(point) => {
+                                let pixels: f32 = point.y.into();
+                                pixels / LIST_ITEM_HEIGHT
+                            }
+                        };
+                        // Invert: wheel up (positive delta) moves toward earlier items
+                        let delta = -lines.round() as i32;
+                        if delta == 0 {
+                            return;
+                        }
+                        let filtered_len = this.filtered_arg_choices().len();
+                        if filtered_len == 0 {
+                            return;
+                        }
+                        let target = (this.arg_selected_index as i32 + delta)
+                            .clamp(0, filtered_len as i32 - 1)
+                            as usize;
+                        if target != this.arg_selected_index {
+                            this.arg_selected_index = target;
+                            this.arg_list_scroll_handle
+                                .scroll_to_item(target, ScrollStrategy::Nearest);
+                            cx.notify();
+                        }
+                        // Paginated prompts: fetch the next page near the end
+                        if delta > 0 {
+                            this.maybe_request_more_choices();
+                        }
+                    },
+                );
+
                 d.child(
                     div()
                         .mx(px(design_spacing.padding_lg))
@@ -593,6 +653,7 @@ impl ScriptListApp {
                         .min_h(px(0.)) // P0: Allow flex container to shrink
                         .w_full()
                         .py(px(design_spacing.padding_xs))
+                        .on_scroll_wheel(handle_wheel)
                         .child(list_element),
                 )
             })
@@ -658,7 +719,7 @@ impl ScriptListApp {
             self.sdk_actions.is_some() && !self.sdk_actions.as_ref().unwrap().is_empty();
 
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_visual = tokens.visual();
@@ -722,17 +783,21 @@ impl ScriptListApp {
                                 window.focus(&this.focus_handle, cx);
                                 cx.notify();
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                            }
                             _ => {
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                             }
                         }
                     }
@@ -761,7 +826,7 @@ impl ScriptListApp {
             let search_text = self
                 .actions_dialog
                 .as_ref()
-                .map(|dialog| dialog.read(cx).search_text.clone())
+                .map(|dialog| dialog.read(cx).search_text().to_string())
                 .unwrap_or_default();
             let search_is_empty = search_text.is_empty();
             let search_display: SharedString = if search_is_empty {
@@ -980,7 +1045,7 @@ impl ScriptListApp {
             self.sdk_actions.is_some() && !self.sdk_actions.as_ref().unwrap().is_empty();
 
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_typography = tokens.typography();
@@ -1069,18 +1134,21 @@ impl ScriptListApp {
                                 cx.notify();
                                 return;
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                                return;
-                            }
                             _ => {
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                                 return;
                             }
                         }
@@ -1201,7 +1269,7 @@ impl ScriptListApp {
                         let search_text = self
                             .actions_dialog
                             .as_ref()
-                            .map(|dialog| dialog.read(cx).search_text.clone())
+                            .map(|dialog| dialog.read(cx).search_text().to_string())
                             .unwrap_or_default();
                         let search_is_empty = search_text.is_empty();
                         let search_display: SharedString = if search_is_empty {
@@ -1387,7 +1455,7 @@ impl ScriptListApp {
         });
 
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
 
@@ -1464,18 +1532,21 @@ impl ScriptListApp {
                                 cx.notify();
                                 return;
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                                return;
-                            }
                             _ => {
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                                 return;
                             }
                         }
@@ -1520,7 +1591,7 @@ impl ScriptListApp {
                 let search_text = self
                     .actions_dialog
                     .as_ref()
-                    .map(|dialog| dialog.read(cx).search_text.clone())
+                    .map(|dialog| dialog.read(cx).search_text().to_string())
                     .unwrap_or_default();
                 let search_is_empty = search_text.is_empty();
                 let search_display: SharedString = if search_is_empty {
@@ -1706,7 +1777,7 @@ impl ScriptListApp {
         });
 
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_visual = tokens.visual();
@@ -1784,18 +1855,21 @@ impl ScriptListApp {
                                 cx.notify();
                                 return;
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                                return;
-                            }
                             _ => {
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                                 return;
                             }
                         }
@@ -1843,7 +1917,7 @@ impl ScriptListApp {
                 let search_text = self
                     .actions_dialog
                     .as_ref()
-                    .map(|dialog| dialog.read(cx).search_text.clone())
+                    .map(|dialog| dialog.read(cx).search_text().to_string())
                     .unwrap_or_default();
                 let search_is_empty = search_text.is_empty();
                 let search_display: SharedString = if search_is_empty {
@@ -2020,7 +2094,7 @@ impl ScriptListApp {
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_visual = tokens.visual();
 
@@ -2058,13 +2132,57 @@ impl ScriptListApp {
             .into_any_element()
     }
 
+    fn render_table_prompt(
+        &mut self,
+        entity: Entity<TablePrompt>,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Key handler for global shortcuts (Cmd+W, ESC)
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // Global shortcuts (Cmd+W, ESC for dismissable prompts)
+                // Other keys are handled by the TablePrompt entity's own key handler
+                let _ = this.handle_global_shortcut_with_options(event, true, cx);
+            },
+        );
+
+        // TablePrompt entity has its own track_focus and on_key_down in its render method.
+        // We wrap with our own handler to intercept Cmd+W and ESC first.
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .overflow_hidden()
+            .rounded(px(design_visual.radius_lg))
+            .on_key_down(handle_key)
+            .child(div().size_full().child(entity))
+            .into_any_element()
+    }
+
     fn render_path_prompt(
         &mut self,
         entity: Entity<PathPrompt>,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_visual = tokens.visual();
 
@@ -2159,7 +2277,7 @@ impl ScriptListApp {
         // Sync the actions search text from the dialog to the shared state
         // This allows PathPrompt to display the search text in its header
         if let Some(ref dialog) = actions_dialog {
-            let search_text = dialog.read(cx).search_text.clone();
+            let search_text = dialog.read(cx).search_text().to_string();
             if let Ok(mut guard) = self.path_actions_search_text.lock() {
                 *guard = search_text;
             }
@@ -2280,18 +2398,21 @@ impl ScriptListApp {
                                 }
                                 cx.notify();
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                            }
                             _ => {
-                                // Route character input to the dialog for search
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                             }
                         }
                     }
@@ -2337,7 +2458,7 @@ impl ScriptListApp {
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_visual = tokens.visual();
 
@@ -2375,13 +2496,99 @@ impl ScriptListApp {
             .into_any_element()
     }
 
+    fn render_number_prompt(
+        &mut self,
+        entity: Entity<NumberPrompt>,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Key handler for global shortcuts (Cmd+W)
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // ESC is handled by the NumberPrompt entity (submits cancel);
+                // we only intercept the window-level shortcuts here.
+                let _ = this.handle_global_shortcut_with_options(event, false, cx);
+            },
+        );
+
+        // NumberPrompt entity has its own track_focus and on_key_down in its render method.
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .overflow_hidden()
+            .rounded(px(design_visual.radius_lg))
+            .on_key_down(handle_key)
+            .child(div().size_full().child(entity))
+            .into_any_element()
+    }
+
+    fn render_date_prompt(
+        &mut self,
+        entity: Entity<DatePrompt>,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.effective_design());
+        let design_colors = tokens.colors();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = self.hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Key handler for global shortcuts (Cmd+W)
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                // ESC is handled by the DatePrompt entity (submits cancel);
+                // we only intercept the window-level shortcuts here.
+                let _ = this.handle_global_shortcut_with_options(event, false, cx);
+            },
+        );
+
+        // DatePrompt entity has its own track_focus and on_key_down in its render method.
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .overflow_hidden()
+            .rounded(px(design_visual.radius_lg))
+            .on_key_down(handle_key)
+            .child(div().size_full().child(entity))
+            .into_any_element()
+    }
+
     fn render_drop_prompt(
         &mut self,
         entity: Entity<DropPrompt>,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_visual = tokens.visual();
 
@@ -2425,7 +2632,7 @@ impl ScriptListApp {
         cx: &mut Context<Self>,
     ) -> AnyElement {
         // Use design tokens for GLOBAL theming
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_visual = tokens.visual();
 
@@ -13,7 +13,7 @@ impl ScriptListApp {
         let flat_results = flat_results.clone();
 
         // Get design tokens for current design variant
-        let tokens = get_tokens(self.current_design);
+        let tokens = get_tokens(self.effective_design());
         let design_colors = tokens.colors();
         let design_spacing = tokens.spacing();
         let design_visual = tokens.visual();
@@ -22,7 +22,7 @@ impl ScriptListApp {
 
         // For Default design, use theme.colors for backward compatibility
         // For other designs, use design tokens
-        let is_default_design = self.current_design == DesignVariant::Default;
+        let is_default_design = self.effective_design() == DesignVariant::Default;
 
         // P4: Pre-compute theme values using ListItemColors
         let _list_colors = ListItemColors::from_theme(theme);
@@ -94,7 +94,9 @@ impl ScriptListApp {
             for item in grouped_items.iter() {
                 match item {
                     GroupedListItem::SectionHeader(_) => header_count += 1,
-                    GroupedListItem::Item(_) => item_count_regular += 1,
+                    GroupedListItem::Item(_) | GroupedListItem::ShowMore { .. } => {
+                        item_count_regular += 1
+                    }
                 }
             }
 
@@ -102,20 +104,26 @@ impl ScriptListApp {
             let total_content_height = (header_count as f32 * SECTION_HEADER_HEIGHT)
                 + (item_count_regular as f32 * LIST_ITEM_HEIGHT);
 
-            // Typical visible container height
-            let estimated_container_height = 400.0_f32;
-
-            // Calculate visible items as a ratio of container to total content
-            // This gives a more accurate thumb size for the scrollbar
-            let visible_ratio = if total_content_height > 0.0 {
-                (estimated_container_height / total_content_height).min(1.0)
-            } else {
-                1.0
-            };
-            let visible_items = ((item_count as f32) * visible_ratio).ceil() as usize;
+            // Real container height, measured at paint time via the bounds
+            // canvas below. Falls back to a typical height for the first
+            // frame, before anything has been painted.
+            let container_height = self
+                .list_bounds
+                .map(|bounds| f32::from(bounds.size.height))
+                .unwrap_or(400.0);
 
-            // Get actual scroll position from ListState (not approximated from selected_index)
-            let scroll_offset = self.main_list_state.logical_scroll_top().item_ix;
+            // Pixel-accurate scroll offset: sum the real heights of the items
+            // above the first visible one, plus the partial offset into it.
+            // selected_index is NOT a proxy here - wheel scrolling moves the
+            // viewport without moving the selection.
+            let scroll_top = self.main_list_state.logical_scroll_top();
+            let mut scroll_offset_px = f32::from(scroll_top.offset_in_item);
+            for item in grouped_items.iter().take(scroll_top.item_ix) {
+                scroll_offset_px += match item {
+                    GroupedListItem::SectionHeader(_) => SECTION_HEADER_HEIGHT,
+                    GroupedListItem::Item(_) | GroupedListItem::ShowMore { .. } => LIST_ITEM_HEIGHT,
+                };
+            }
 
             // Get scrollbar colors from theme or design
             let scrollbar_colors = if is_default_design {
@@ -124,11 +132,15 @@ impl ScriptListApp {
                 ScrollbarColors::from_design(&design_colors)
             };
 
-            // Create scrollbar (only visible if content overflows and scrolling is active)
-            let scrollbar =
-                Scrollbar::new(item_count, visible_items, scroll_offset, scrollbar_colors)
-                    .container_height(estimated_container_height)
-                    .visible(self.is_scrolling);
+            // Create scrollbar from real pixel metrics (only visible if
+            // content overflows and scrolling/dragging is active)
+            let scrollbar = Scrollbar::from_pixels(
+                total_content_height,
+                container_height,
+                scroll_offset_px,
+                scrollbar_colors,
+            )
+            .visible(self.is_scrolling || self.scrollbar_dragging);
 
             // Update list state if item count changed
             if self.main_list_state.item_count() != item_count {
@@ -144,7 +156,7 @@ impl ScriptListApp {
 
             // Clone values needed in the closure (can't access self in FnMut)
             let theme_colors = ListItemColors::from_theme(&self.theme);
-            let current_design = self.current_design;
+            let current_design = self.effective_design();
 
             let variable_height_list =
                 list(self.main_list_state.clone(), move |ix, _window, cx| {
@@ -156,14 +168,46 @@ impl ScriptListApp {
                         if let Some(grouped_item) = grouped_items_clone.get(ix) {
                             match grouped_item {
                                 GroupedListItem::SectionHeader(label) => {
-                                    // Section header at 24px height (SECTION_HEADER_HEIGHT)
+                                    // Section header at 24px height (SECTION_HEADER_HEIGHT).
+                                    // The header carries the canonical section id; the
+                                    // display name can be overridden via sections.names
+                                    let display_name = this
+                                        .config
+                                        .get_sections()
+                                        .names
+                                        .get(label)
+                                        .cloned()
+                                        .unwrap_or_else(|| label.clone());
+                                    let is_collapsed = this.section_state.is_collapsed(label);
+                                    let display_label = if is_collapsed {
+                                        format!("▸ {}", display_name)
+                                    } else {
+                                        display_name
+                                    };
+
+                                    // Click toggles collapsed state (persisted)
+                                    let section_id = label.clone();
+                                    let toggle_handler = cx.listener(
+                                        move |this: &mut ScriptListApp,
+                                              _event: &gpui::ClickEvent,
+                                              _window,
+                                              cx| {
+                                            this.toggle_section_collapsed(&section_id, cx);
+                                        },
+                                    );
+
                                     div()
                                         .id(ElementId::NamedInteger(
                                             "section-header".into(),
                                             ix as u64,
                                         ))
                                         .h(px(SECTION_HEADER_HEIGHT))
-                                        .child(render_section_header(label, theme_colors))
+                                        .cursor_pointer()
+                                        .on_click(toggle_handler)
+                                        .child(render_section_header(
+                                            &display_label,
+                                            theme_colors,
+                                        ))
                                         .into_any_element()
                                 }
                                 GroupedListItem::Item(result_idx) => {
@@ -281,6 +325,21 @@ impl ScriptListApp {
                                             item_element
                                         };
 
+                                        // Right-click: select the clicked item and open the
+                                        // context menu at the cursor position
+                                        let context_menu_handler = cx.listener(
+                                            move |this: &mut ScriptListApp,
+                                                  event: &gpui::MouseDownEvent,
+                                                  _window,
+                                                  cx| {
+                                                if this.selected_index != ix {
+                                                    this.selected_index = ix;
+                                                }
+                                                this.context_menu = Some((event.position, ix));
+                                                cx.notify();
+                                            },
+                                        );
+
                                         div()
                                             .id(ElementId::NamedInteger(
                                                 "script-item".into(),
@@ -289,6 +348,10 @@ impl ScriptListApp {
                                             .h(px(LIST_ITEM_HEIGHT)) // Explicit 48px height
                                             .on_hover(hover_handler)
                                             .on_click(click_handler)
+                                            .on_mouse_down(
+                                                gpui::MouseButton::Right,
+                                                context_menu_handler,
+                                            )
                                             .child(final_element)
                                             .into_any_element()
                                     } else {
@@ -296,6 +359,43 @@ impl ScriptListApp {
                                         div().h(px(LIST_ITEM_HEIGHT)).into_any_element()
                                     }
                                 }
+                                GroupedListItem::ShowMore { section, hidden } => {
+                                    // Tail row of a capped section - same height as a
+                                    // regular item, selectable, Enter or click expands
+                                    let is_selected = ix == current_selected;
+                                    let section_id = section.clone();
+                                    let click_handler = cx.listener(
+                                        move |this: &mut ScriptListApp,
+                                              _event: &gpui::ClickEvent,
+                                              _window,
+                                              cx| {
+                                            this.expand_section(&section_id, cx);
+                                        },
+                                    );
+
+                                    div()
+                                        .id(ElementId::NamedInteger(
+                                            "show-more".into(),
+                                            ix as u64,
+                                        ))
+                                        .h(px(LIST_ITEM_HEIGHT))
+                                        .w_full()
+                                        .flex()
+                                        .items_center()
+                                        .px(px(16.))
+                                        .cursor_pointer()
+                                        .on_click(click_handler)
+                                        .when(is_selected, |d| {
+                                            d.bg(rgb(theme_colors.background_selected))
+                                        })
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(rgb(theme_colors.text_muted))
+                                                .child(format!("Show more… ({} hidden)", hidden)),
+                                        )
+                                        .into_any_element()
+                                }
                             }
                         } else {
                             // Fallback for out-of-bounds index
@@ -360,16 +460,85 @@ impl ScriptListApp {
                                 "Mouse wheel scroll - index-based"
                             );
                         }
+
+                        // Keep the scrollbar visible for the duration of wheel
+                        // activity, even when the selection doesn't move
+                        // (e.g. small deltas or scrolling at the list edges)
+                        this.trigger_scroll_activity(cx);
                     },
                 ))
+                // Continue/end a scrollbar thumb drag anywhere over the list,
+                // so the pointer doesn't have to stay inside the 6px track
+                .on_mouse_move(cx.listener(
+                    move |this, event: &gpui::MouseMoveEvent, _window, cx| {
+                        if this.scrollbar_dragging {
+                            if let Some(bounds) = this.list_bounds {
+                                let track_height = f32::from(bounds.size.height);
+                                if track_height > 0.0 {
+                                    let y = f32::from(event.position.y - bounds.origin.y);
+                                    this.scroll_main_list_to_ratio(y / track_height, cx);
+                                }
+                            }
+                        }
+                    },
+                ))
+                .on_mouse_up(
+                    gpui::MouseButton::Left,
+                    cx.listener(move |this, _event: &gpui::MouseUpEvent, _window, cx| {
+                        if this.scrollbar_dragging {
+                            this.scrollbar_dragging = false;
+                            cx.notify();
+                        }
+                    }),
+                )
                 .child(variable_height_list)
-                .child(scrollbar)
+                // Invisible canvas that records the container's real bounds.
+                // The scrollbar reads them for viewport height and the drag
+                // handlers use them to map pointer positions to scroll ratios.
+                .child({
+                    let entity = cx.entity();
+                    gpui::canvas(
+                        move |bounds, _window, cx| {
+                            entity.update(cx, |this, _| this.list_bounds = Some(bounds))
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full()
+                })
+                // Scrollbar hit area: a strip slightly wider than the visual
+                // track so the thumb is easy to grab. Mouse-down jumps to the
+                // clicked position and starts a drag that the container-level
+                // handlers above track and finish.
+                .child(
+                    div()
+                        .id("main-scrollbar-hit")
+                        .absolute()
+                        .top_0()
+                        .bottom_0()
+                        .right_0()
+                        .w(px(SCROLLBAR_WIDTH + SCROLLBAR_PADDING * 4.0))
+                        .on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(move |this, event: &gpui::MouseDownEvent, _window, cx| {
+                                if let Some(bounds) = this.list_bounds {
+                                    let track_height = f32::from(bounds.size.height);
+                                    if track_height > 0.0 {
+                                        let y = f32::from(event.position.y - bounds.origin.y);
+                                        this.scrollbar_dragging = true;
+                                        this.scroll_main_list_to_ratio(y / track_height, cx);
+                                    }
+                                }
+                            }),
+                        )
+                        .child(scrollbar),
+                )
                 .into_any_element()
         };
 
         // Log panel
         let log_panel = if self.show_logs {
-            let logs = logging::get_last_logs(10);
+            let logs = logging::get_last_log_entries(10);
             let mut log_container = div()
                 .flex()
                 .flex_col()
@@ -379,15 +548,33 @@ impl ScriptListApp {
                 .border_color(rgb(theme.colors.ui.border))
                 .p(px(design_spacing.padding_md))
                 .max_h(px(120.))
-                .font_family("SF Mono");
+                .font_family(theme.get_fonts().mono_family);
 
-            for log_line in logs.iter().rev() {
-                log_container = log_container.child(
-                    div()
-                        .text_color(rgb(theme.colors.ui.success))
-                        .text_xs()
-                        .child(log_line.clone()),
-                );
+            for (ix, entry) in logs.iter().rev().enumerate() {
+                let line_div = div()
+                    .text_color(rgb(theme.colors.ui.success))
+                    .text_xs()
+                    .child(entry.display());
+                // Script lines jump to the source in the editor on click
+                let line_div = if let Some(ref source_path) = entry.script_path {
+                    let source_path = source_path.clone();
+                    let source_line = entry.line.unwrap_or(1);
+                    line_div
+                        .id(("log-line", ix))
+                        .cursor_pointer()
+                        .on_click(cx.listener(
+                            move |this: &mut Self,
+                                  _event: &gpui::ClickEvent,
+                                  _window: &mut Window,
+                                  cx: &mut Context<Self>| {
+                                this.open_log_source(&source_path, source_line, cx);
+                            },
+                        ))
+                        .into_any_element()
+                } else {
+                    line_div.into_any_element()
+                };
+                log_container = log_container.child(line_div);
             }
             Some(log_container)
         } else {
@@ -459,6 +646,21 @@ impl ScriptListApp {
                             this.handle_action("copy_path".to_string(), cx);
                             return;
                         }
+                        "p" => {
+                            // Cmd+P - Pin/unpin selected item
+                            this.handle_action("toggle_pin".to_string(), cx);
+                            return;
+                        }
+                        "up" | "arrowup" => {
+                            // Cmd+Up - Move pinned item up
+                            this.move_selected_pin(-1, cx);
+                            return;
+                        }
+                        "down" | "arrowdown" => {
+                            // Cmd+Down - Move pinned item down
+                            this.move_selected_pin(1, cx);
+                            return;
+                        }
                         // Global shortcuts
                         "n" => {
                             // Cmd+N - Create Script
@@ -525,19 +727,21 @@ impl ScriptListApp {
                                 cx.notify();
                                 return;
                             }
-                            "backspace" => {
-                                dialog.update(cx, |d, cx| d.handle_backspace(cx));
-                                return;
-                            }
                             _ => {
-                                // Route character input to the dialog for search
-                                if let Some(ref key_char) = event.keystroke.key_char {
-                                    if let Some(ch) = key_char.chars().next() {
-                                        if !ch.is_control() {
-                                            dialog.update(cx, |d, cx| d.handle_char(ch, cx));
-                                        }
-                                    }
-                                }
+                                // Route everything else to the dialog's search input so
+                                // cursor movement, selection, and editing all work
+                                let key_char = event.keystroke.key_char.clone();
+                                let modifiers = event.keystroke.modifiers;
+                                dialog.update(cx, |d, cx| {
+                                    d.handle_key(
+                                        &key_str,
+                                        key_char.as_deref(),
+                                        modifiers.platform,
+                                        modifiers.alt,
+                                        modifiers.shift,
+                                        cx,
+                                    );
+                                });
                                 return;
                             }
                         }
@@ -546,6 +750,15 @@ impl ScriptListApp {
 
                 match key_str.as_str() {
                     "up" | "arrowup" => {
+                        // Shell-style history: with nowhere further up to go
+                        // on an empty filter (or mid-cycle), Up recalls the
+                        // previous query instead of moving the selection
+                        if this.history_cycle_index.is_some()
+                            || (this.filter_text.is_empty() && this.selection_at_top())
+                        {
+                            this.cycle_query_history(true, window, cx);
+                            return;
+                        }
                         let _key_perf = crate::perf::KeyEventPerfGuard::new();
                         match this.nav_coalescer.record(NavDirection::Up) {
                             NavRecord::ApplyImmediate => this.move_selection_up(cx),
@@ -560,6 +773,12 @@ impl ScriptListApp {
                         this.ensure_nav_flush_task(cx);
                     }
                     "down" | "arrowdown" => {
+                        // While cycling history, Down steps back toward the
+                        // newest query (and past it to an empty filter)
+                        if this.history_cycle_index.is_some() {
+                            this.cycle_query_history(false, window, cx);
+                            return;
+                        }
                         let _key_perf = crate::perf::KeyEventPerfGuard::new();
                         match this.nav_coalescer.record(NavDirection::Down) {
                             NavRecord::ApplyImmediate => this.move_selection_down(cx),
@@ -573,14 +792,44 @@ impl ScriptListApp {
                         }
                         this.ensure_nav_flush_task(cx);
                     }
+                    "r" if event.keystroke.modifiers.control => {
+                        // Ctrl+R recalls query history regardless of filter state
+                        this.cycle_query_history(true, window, cx);
+                    }
                     "enter" => {
-                        if !this.gpui_input_focused {
+                        if let Some((script, args, hash)) = this.pending_trust_run.take() {
+                            // Trust confirmed - record the hash so the re-entry
+                            // below passes the trust gate, then run
+                            logging::log("EXEC", &format!("Trust confirmed: {}", script.name));
+                            let path_key = script.path.to_string_lossy().to_string();
+                            this.trust_store.approve(&path_key, hash);
+                            if let Err(e) = this.trust_store.save() {
+                                logging::log("ERROR", &format!("Failed to save trust store: {}", e));
+                            }
+                            this.execute_interactive_with_args(&script, &args, cx);
+                        } else if let Some((script, args)) = this.pending_url_run.take() {
+                            // Confirm a scriptkit:// URL-triggered run
+                            logging::log("EXEC", &format!("URL run confirmed: {}", script.name));
+                            this.execute_interactive_with_args(&script, &args, cx);
+                        } else if !this.gpui_input_focused {
                             this.execute_selected(cx);
                         }
                     }
                     "escape" => {
-                        // First check if we have a pending confirmation to clear
-                        if this.pending_confirmation.is_some() {
+                        // First check if we have a pending URL run or confirmation to clear
+                        if this.context_menu.is_some() {
+                            logging::log("KEY", "ESC - closing context menu");
+                            this.context_menu = None;
+                            cx.notify();
+                        } else if this.pending_trust_run.is_some() {
+                            logging::log("KEY", "ESC - dismissing pending trust run");
+                            this.pending_trust_run = None;
+                            cx.notify();
+                        } else if this.pending_url_run.is_some() {
+                            logging::log("KEY", "ESC - dismissing pending URL run");
+                            this.pending_url_run = None;
+                            cx.notify();
+                        } else if this.pending_confirmation.is_some() {
                             logging::log("KEY", "ESC - clearing pending confirmation");
                             this.pending_confirmation = None;
                             cx.notify();
@@ -717,7 +966,7 @@ impl ScriptListApp {
                         let search_text = self
                             .actions_dialog
                             .as_ref()
-                            .map(|dialog| dialog.read(cx).search_text.clone())
+                            .map(|dialog| dialog.read(cx).search_text().to_string())
                             .unwrap_or_default();
                         let search_is_empty = search_text.is_empty();
                         let search_display = if search_is_empty {
@@ -881,6 +1130,38 @@ impl ScriptListApp {
                                     ),
                             )
                     })
+                    // Running-scripts badge - only present while script processes are
+                    // active, so users know something is still in flight after a
+                    // prompt closes. Clicking it opens the Background Tasks view.
+                    .children({
+                        let running = PROCESS_MANAGER.active_count();
+                        (running > 0).then(|| {
+                            div()
+                                .id("running-badge")
+                                .cursor_pointer()
+                                .flex_shrink_0()
+                                .flex()
+                                .flex_row()
+                                .items_center()
+                                .gap(px(4.))
+                                .px(px(6.))
+                                .py(px(2.))
+                                .rounded(px(10.))
+                                .bg(rgba((accent_color << 8) | 0x20))
+                                .text_xs()
+                                .text_color(rgb(accent_color))
+                                .child("●")
+                                .child(SharedString::from(format!("{}", running)))
+                                .on_click(cx.listener(
+                                    |this: &mut Self,
+                                     _event: &gpui::ClickEvent,
+                                     _window: &mut Window,
+                                     cx: &mut Context<Self>| {
+                                        this.open_background_tasks(cx);
+                                    },
+                                ))
+                        })
+                    })
                     // Script Kit Logo - ALWAYS visible
                     // Size slightly larger than text for visual presence
                     .child(
@@ -996,6 +1277,93 @@ impl ScriptListApp {
             main_div = main_div.child(panel);
         }
 
+        // Right-click context menu, positioned at the cursor. It shares the
+        // Cmd+K action list and dispatches through handle_action, so the two
+        // menus can't drift apart.
+        if let Some((menu_position, _menu_ix)) = self.context_menu {
+            if let Some(script_info) = self.get_focused_script_info() {
+                let menu_actions = actions::get_script_context_actions(&script_info);
+                let menu_bg = self.theme.colors.background.main;
+                let menu_border = self.theme.colors.ui.border;
+                let menu_text = self.theme.colors.text.primary;
+                let menu_dimmed = self.theme.colors.text.dimmed;
+                let menu_hover = self.theme.colors.accent.selected;
+
+                // Clicking anywhere outside the menu dismisses it
+                let backdrop_click = cx.listener(
+                    |this: &mut Self, _event: &gpui::ClickEvent, _window, cx| {
+                        this.context_menu = None;
+                        cx.notify();
+                    },
+                );
+
+                let mut menu = div()
+                    .absolute()
+                    .left(menu_position.x)
+                    .top(menu_position.y)
+                    .w(px(240.))
+                    .flex()
+                    .flex_col()
+                    .py(px(4.))
+                    .bg(rgba((menu_bg << 8) | 0xF8))
+                    .border_1()
+                    .border_color(rgba((menu_border << 8) | 0x80))
+                    .rounded(px(8.))
+                    .shadow_lg();
+
+                for action in menu_actions {
+                    let action_id = action.id.clone();
+                    let row_click = cx.listener(
+                        move |this: &mut Self, _event: &gpui::ClickEvent, _window, cx| {
+                            logging::log(
+                                "UI",
+                                &format!("Context menu action: {}", action_id),
+                            );
+                            this.context_menu = None;
+                            this.handle_action(action_id.clone(), cx);
+                        },
+                    );
+
+                    let mut row = div()
+                        .id(ElementId::Name(
+                            format!("context-menu-{}", action.id).into(),
+                        ))
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .justify_between()
+                        .px(px(12.))
+                        .py(px(5.))
+                        .text_sm()
+                        .text_color(rgb(menu_text))
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(rgba((menu_hover << 8) | 0x30)))
+                        .on_click(row_click)
+                        .child(action.title.clone());
+                    if let Some(shortcut) = action.shortcut.clone() {
+                        row = row.child(
+                            div().text_xs().text_color(rgb(menu_dimmed)).child(shortcut),
+                        );
+                    }
+                    menu = menu.child(row);
+                }
+
+                main_div = main_div.relative().child(
+                    div()
+                        .absolute()
+                        .inset_0()
+                        .child(
+                            div()
+                                .id("context-menu-backdrop")
+                                .absolute()
+                                .inset_0()
+                                .on_click(backdrop_click),
+                        )
+                        .child(menu),
+                );
+            }
+        }
+
         // Note: Toast notifications are now handled by gpui-component's NotificationList
         // via the Root wrapper. Toasts are flushed in render() via flush_pending_toasts().
 
@@ -0,0 +1,169 @@
+//! Per-execution script log files
+//!
+//! Each script run gets its own log file under `~/.sk/kit/logs/runs/`.
+//! Console output forwarded by the SDK's console wrapper (`log` protocol
+//! messages) and raw script stderr are appended there, so run history and
+//! crash reports can show what a script said long after the session ended.
+//!
+//! Files are named `<script>-<timestamp>-<pid>.log`; the directory is capped
+//! at `MAX_RUN_LOGS` files with the oldest pruned when a new run starts.
+
+use chrono::Local;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Maximum run log files kept before the oldest are pruned
+const MAX_RUN_LOGS: usize = 200;
+
+/// Directory holding per-run log files
+fn runs_dir() -> PathBuf {
+    dirs::home_dir()
+        .map(|h| h.join(".sk/kit/logs/runs"))
+        .unwrap_or_else(|| std::env::temp_dir().join("script-kit-run-logs"))
+}
+
+/// Start a log file for a new run, pruning old files.
+/// Returns the path all of this run's lines should be appended to.
+pub fn create(script_name: &str, pid: u32) -> PathBuf {
+    create_in(&runs_dir(), script_name, pid)
+}
+
+/// Test-injectable variant of [`create`]
+fn create_in(dir: &Path, script_name: &str, pid: u32) -> PathBuf {
+    if let Err(e) = fs::create_dir_all(dir) {
+        crate::logging::log("EXEC", &format!("Failed to create run log dir: {}", e));
+    }
+    prune(dir, MAX_RUN_LOGS.saturating_sub(1));
+    let stamp = Local::now().format("%Y%m%d-%H%M%S");
+    dir.join(format!("{}-{}-{}.log", sanitize(script_name), stamp, pid))
+}
+
+/// File-name-safe version of a script name
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Append one line to a run log: `[HH:MM:SS] [level] text`
+pub fn append(path: &Path, level: &str, text: &str) {
+    let line = format!(
+        "[{}] [{}] {}\n",
+        Local::now().format("%H:%M:%S"),
+        level,
+        text
+    );
+    let result = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()));
+    if let Err(e) = result {
+        crate::logging::log("EXEC", &format!("Failed to write run log: {}", e));
+    }
+}
+
+/// The last `n` lines of a run log, oldest first (empty if the file is
+/// missing - a run that never logged anything has no file)
+pub fn read_tail(path: &Path, n: usize) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let lines: Vec<&str> = content.lines().collect();
+    lines
+        .iter()
+        .skip(lines.len().saturating_sub(n))
+        .map(|l| l.to_string())
+        .collect()
+}
+
+/// Delete the oldest run logs so at most `max` files remain
+fn prune(dir: &Path, max: usize) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut logs: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "log"))
+        .collect();
+    if logs.len() <= max {
+        return;
+    }
+    // Timestamped names sort oldest-first lexicographically per script; use
+    // modification time so mixed script names prune correctly too
+    logs.sort_by_key(|p| {
+        fs::metadata(p)
+            .and_then(|m| m.modified())
+            .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+    });
+    for stale in logs.iter().take(logs.len() - max) {
+        let _ = fs::remove_file(stale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sk-run-logs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_append_and_read_tail() {
+        let dir = temp_dir("tail");
+        let path = create_in(&dir, "My Script!", 4242);
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("My-Script--"));
+        assert!(name.ends_with("-4242.log"));
+
+        append(&path, "info", "first");
+        append(&path, "error", "second");
+        let tail = read_tail(&path, 10);
+        assert_eq!(tail.len(), 2);
+        assert!(tail[0].contains("[info] first"));
+        assert!(tail[1].contains("[error] second"));
+
+        // Tail keeps the newest lines
+        let tail = read_tail(&path, 1);
+        assert_eq!(tail.len(), 1);
+        assert!(tail[0].contains("second"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_tail_missing_file() {
+        let dir = temp_dir("missing");
+        assert!(read_tail(&dir.join("nope.log"), 5).is_empty());
+    }
+
+    #[test]
+    fn test_prune_keeps_newest() {
+        let dir = temp_dir("prune");
+        fs::create_dir_all(&dir).unwrap();
+        for i in 0..5 {
+            let path = dir.join(format!("script-{}.log", i));
+            fs::write(&path, "x").unwrap();
+            // Distinct mtimes so the prune order is deterministic
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        prune(&dir, 2);
+        let remaining: Vec<_> = fs::read_dir(&dir).unwrap().flatten().collect();
+        assert_eq!(remaining.len(), 2);
+        assert!(dir.join("script-4.log").exists());
+        assert!(!dir.join("script-0.log").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,197 @@
+//! Structured script results (`result` protocol message)
+//!
+//! Scripts can set a typed final return value with
+//! `{"type":"result","value":{...}}` instead of relying on whatever the
+//! last submit happened to be. The reader loop records the value here as
+//! the run's result; invokers (script-to-script `run`, CLI, URL scheme,
+//! MCP) read it back from the in-memory registry or the persisted run
+//! history at `~/.sk/kit/run-results.json`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::logging;
+use crate::protocol::Message;
+
+/// File the run history is persisted to
+const RESULTS_FILE: &str = "~/.sk/kit/run-results.json";
+
+/// Maximum persisted run results (newest first)
+const MAX_RESULTS: usize = 50;
+
+/// One recorded script result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunResult {
+    /// Path of the script that produced the value
+    pub script_path: String,
+    /// Typed return value from the `result` message
+    pub value: serde_json::Value,
+    /// Unix timestamp (seconds) when the result was recorded
+    pub finished_at: u64,
+    /// Per-run log file for this execution, if one was written
+    /// (see the `run_logs` module)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<String>,
+}
+
+/// Latest result per script path, for cheap lookups by invokers
+static LATEST_RESULTS: Mutex<Option<HashMap<String, serde_json::Value>>> = Mutex::new(None);
+
+fn results_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde(RESULTS_FILE).into_owned())
+}
+
+fn load_results_from(path: &Path) -> Vec<RunResult> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_results_to(path: &Path, results: &[RunResult]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(results)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    std::fs::write(path, json)
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Record a script's result: updates the in-memory registry and prepends
+/// to the persisted run history (capped at [`MAX_RESULTS`])
+pub fn record(script_path: &str, value: serde_json::Value, log_file: Option<String>) {
+    if let Ok(mut latest) = LATEST_RESULTS.lock() {
+        latest
+            .get_or_insert_with(HashMap::new)
+            .insert(script_path.to_string(), value.clone());
+    }
+    record_at(&results_path(), script_path, value, log_file);
+}
+
+fn record_at(path: &Path, script_path: &str, value: serde_json::Value, log_file: Option<String>) {
+    let mut results = load_results_from(path);
+    results.insert(
+        0,
+        RunResult {
+            script_path: script_path.to_string(),
+            value,
+            finished_at: now_secs(),
+            log_file,
+        },
+    );
+    results.truncate(MAX_RESULTS);
+    if let Err(e) = save_results_to(path, &results) {
+        logging::log("EXEC", &format!("Failed to save run results: {}", e));
+    }
+}
+
+/// Latest recorded result for a script, if any
+pub fn latest_for(script_path: &str) -> Option<serde_json::Value> {
+    if let Ok(latest) = LATEST_RESULTS.lock() {
+        if let Some(value) = latest.as_ref().and_then(|map| map.get(script_path)) {
+            return Some(value.clone());
+        }
+    }
+    load_results_from(&results_path())
+        .into_iter()
+        .find(|r| r.script_path == script_path)
+        .map(|r| r.value)
+}
+
+/// Persisted run history, newest first
+pub fn history() -> Vec<RunResult> {
+    load_results_from(&results_path())
+}
+
+/// Extract the `result` message value from a non-interactive run's output
+///
+/// Blocking runs capture raw stdout, so protocol messages appear as JSONL
+/// lines among normal prints. The last `result` line wins, matching the
+/// interactive reader's behavior.
+pub fn extract_result_value(output: &str) -> Option<serde_json::Value> {
+    let mut result = None;
+    for line in output.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+        if let Ok(Message::ScriptResult { value }) = serde_json::from_str::<Message>(line) {
+            result = Some(value);
+        }
+    }
+    result
+}
+
+/// Render a result value the way invokers expect a plain-text reply:
+/// bare strings stay unquoted, everything else is compact JSON
+pub fn value_to_display(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_results(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sk-results-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("run-results.json")
+    }
+
+    #[test]
+    fn test_record_prepends_and_caps() {
+        let path = temp_results("cap");
+        for i in 0..(MAX_RESULTS + 5) {
+            record_at(&path, "/tmp/a.ts", serde_json::json!(i), None);
+        }
+        let results = load_results_from(&path);
+        assert_eq!(results.len(), MAX_RESULTS);
+        // Newest first
+        assert_eq!(results[0].value, serde_json::json!(MAX_RESULTS + 4));
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_extract_result_value_last_wins() {
+        let output = r#"starting up
+{"type":"result","value":{"count":1}}
+some log line
+{"type":"result","value":{"count":2}}
+done"#;
+        assert_eq!(
+            extract_result_value(output),
+            Some(serde_json::json!({"count": 2}))
+        );
+    }
+
+    #[test]
+    fn test_extract_result_value_ignores_other_messages() {
+        let output = "{\"type\":\"toast\",\"text\":\"hi\"}\nplain text\n";
+        assert_eq!(extract_result_value(output), None);
+    }
+
+    #[test]
+    fn test_value_to_display() {
+        assert_eq!(
+            value_to_display(&serde_json::json!("plain string")),
+            "plain string"
+        );
+        assert_eq!(
+            value_to_display(&serde_json::json!({"ok": true})),
+            r#"{"ok":true}"#
+        );
+    }
+}
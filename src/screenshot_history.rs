@@ -0,0 +1,227 @@
+//! Screenshot History builtin
+//!
+//! Lists recent screenshots from the user's screenshot directory (the
+//! macOS `com.apple.screencapture location` default, falling back to
+//! ~/Desktop), newest first. The view shows a thumbnail of the selected
+//! shot; Enter opens it in Preview for quick annotation, Cmd+Enter
+//! reveals it in Finder, and Cmd+O runs OCR and copies the recognized
+//! text. The list is rescanned each time the builtin opens.
+
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::logging;
+
+/// Maximum screenshots listed
+const MAX_SCREENSHOTS: usize = 100;
+
+/// One screenshot on disk
+#[derive(Debug, Clone)]
+pub struct Screenshot {
+    pub path: PathBuf,
+    pub name: String,
+    /// Modified time as Unix timestamp
+    pub modified: u64,
+    /// File size in bytes
+    pub size: u64,
+}
+
+/// The directory macOS saves screenshots to
+///
+/// Reads the `com.apple.screencapture location` default; falls back to
+/// ~/Desktop (the system default) when unset or unreadable.
+pub fn screenshots_dir() -> PathBuf {
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(output) = std::process::Command::new("defaults")
+            .args(["read", "com.apple.screencapture", "location"])
+            .output()
+        {
+            if output.status.success() {
+                let location = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if !location.is_empty() {
+                    let expanded = shellexpand::tilde(&location).into_owned();
+                    let dir = PathBuf::from(expanded);
+                    if dir.is_dir() {
+                        return dir;
+                    }
+                }
+            }
+        }
+    }
+    dirs::home_dir()
+        .map(|h| h.join("Desktop"))
+        .unwrap_or_else(|| PathBuf::from("/"))
+}
+
+/// Whether a file name looks like a screenshot capture
+///
+/// Matches the default macOS patterns ("Screenshot 2024-...", the older
+/// "Screen Shot ..."), plus common third-party prefixes (CleanShot, Kap).
+pub fn is_screenshot_name(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    if !lower.ends_with(".png") && !lower.ends_with(".jpg") && !lower.ends_with(".jpeg") {
+        return false;
+    }
+    lower.starts_with("screenshot")
+        || lower.starts_with("screen shot")
+        || lower.starts_with("cleanshot")
+        || lower.starts_with("kap ")
+}
+
+/// Scan a directory for screenshots, newest first
+pub fn scan_dir(dir: &Path) -> Vec<Screenshot> {
+    let mut shots: Vec<Screenshot> = Vec::new();
+    if let Ok(read_dir) = std::fs::read_dir(dir) {
+        for entry in read_dir.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !is_screenshot_name(&name) {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            shots.push(Screenshot {
+                path: entry.path(),
+                name,
+                modified,
+                size: metadata.len(),
+            });
+        }
+    }
+    shots.sort_by(|a, b| b.modified.cmp(&a.modified));
+    shots.truncate(MAX_SCREENSHOTS);
+    shots
+}
+
+/// Scan the user's screenshot directory
+pub fn list_screenshots() -> Vec<Screenshot> {
+    let dir = screenshots_dir();
+    let shots = scan_dir(&dir);
+    logging::log(
+        "SCREENSHOTS",
+        &format!("Found {} screenshot(s) in {}", shots.len(), dir.display()),
+    );
+    shots
+}
+
+/// Filter screenshots by name (case-insensitive contains)
+pub fn filter_screenshots<'a>(shots: &'a [Screenshot], filter: &str) -> Vec<&'a Screenshot> {
+    if filter.is_empty() {
+        return shots.iter().collect();
+    }
+    let filter_lower = filter.to_lowercase();
+    shots
+        .iter()
+        .filter(|s| s.name.to_lowercase().contains(&filter_lower))
+        .collect()
+}
+
+/// Relative age like "5m ago", "3h ago", "2d ago"
+pub fn format_age(modified: u64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let age = now.saturating_sub(modified);
+    if age < 60 {
+        "just now".to_string()
+    } else if age < 3600 {
+        format!("{}m ago", age / 60)
+    } else if age < 86400 {
+        format!("{}h ago", age / 3600)
+    } else {
+        format!("{}d ago", age / 86400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_screenshot_name() {
+        assert!(is_screenshot_name("Screenshot 2026-08-30 at 10.00.00.png"));
+        assert!(is_screenshot_name(
+            "Screen Shot 2020-01-01 at 9.00.00 AM.png"
+        ));
+        assert!(is_screenshot_name("CleanShot 2026-08-30.png"));
+        assert!(!is_screenshot_name("vacation.png"));
+        assert!(!is_screenshot_name("Screenshot notes.txt"));
+    }
+
+    #[test]
+    fn test_scan_dir_sorts_newest_first() {
+        let dir = std::env::temp_dir().join(format!("sk-shots-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let old = dir.join("Screenshot old.png");
+        let new = dir.join("Screenshot new.png");
+        std::fs::write(&old, b"png").unwrap();
+        std::fs::write(&new, b"png").unwrap();
+        // Make the mtimes unambiguous
+        filetime::set_file_mtime(&old, filetime::FileTime::from_unix_time(1_000_000, 0)).unwrap();
+        filetime::set_file_mtime(&new, filetime::FileTime::from_unix_time(2_000_000, 0)).unwrap();
+
+        let shots = scan_dir(&dir);
+        assert_eq!(shots.len(), 2);
+        assert_eq!(shots[0].name, "Screenshot new.png");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_scan_dir_ignores_non_screenshots() {
+        let dir = std::env::temp_dir().join(format!("sk-shots-mixed-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Screenshot a.png"), b"png").unwrap();
+        std::fs::write(dir.join("notes.txt"), b"txt").unwrap();
+
+        let shots = scan_dir(&dir);
+        assert_eq!(shots.len(), 1);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_filter_screenshots() {
+        let shots = vec![
+            Screenshot {
+                path: PathBuf::from("/tmp/Screenshot alpha.png"),
+                name: "Screenshot alpha.png".to_string(),
+                modified: 0,
+                size: 1,
+            },
+            Screenshot {
+                path: PathBuf::from("/tmp/Screenshot beta.png"),
+                name: "Screenshot beta.png".to_string(),
+                modified: 0,
+                size: 1,
+            },
+        ];
+        assert_eq!(filter_screenshots(&shots, "").len(), 2);
+        assert_eq!(filter_screenshots(&shots, "ALPHA").len(), 1);
+        assert_eq!(filter_screenshots(&shots, "gamma").len(), 0);
+    }
+
+    #[test]
+    fn test_format_age_buckets() {
+        let now = std::time::SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert_eq!(format_age(now), "just now");
+        assert_eq!(format_age(now - 120), "2m ago");
+        assert_eq!(format_age(now - 7200), "2h ago");
+        assert_eq!(format_age(now - 172800), "2d ago");
+    }
+}
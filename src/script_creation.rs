@@ -287,6 +287,52 @@ pub fn open_in_editor(path: &Path, config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Open a file in the user's configured editor at a specific line
+///
+/// Editors with a goto syntax (VS Code family, Sublime, Zed, vim) get the
+/// line number; anything else falls back to [`open_in_editor`].
+#[instrument(name = "open_in_editor_at_line", skip(config), fields(path = %path.display(), line))]
+pub fn open_in_editor_at_line(path: &Path, line: u32, config: &Config) -> Result<()> {
+    let editor = config.get_editor();
+    let editor_name = Path::new(&editor)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(editor.as_str())
+        .to_string();
+
+    let mut command = Command::new(&editor);
+    match editor_name.as_str() {
+        "code" | "code-insiders" | "codium" | "vscodium" | "cursor" | "windsurf" => {
+            command
+                .arg("--goto")
+                .arg(format!("{}:{}", path.display(), line));
+        }
+        "subl" | "sublime_text" | "zed" => {
+            command.arg(format!("{}:{}", path.display(), line));
+        }
+        "vim" | "nvim" | "vi" => {
+            command.arg(format!("+{}", line)).arg(path);
+        }
+        _ => {
+            // Unknown editor - plain open is better than a bad argument
+            return open_in_editor(path, config);
+        }
+    }
+
+    info!(editor = %editor, path = %path.display(), line, "Opening file in editor at line");
+
+    let status = command.spawn().with_context(|| {
+        format!(
+            "Failed to spawn editor '{}' for file: {}",
+            editor,
+            path.display()
+        )
+    })?;
+    drop(status);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -466,6 +512,7 @@ mod tests {
             notes_hotkey: None,
             ai_hotkey: None,
             commands: None,
+            ..Default::default()
         };
         assert_eq!(default_config.get_editor(), "code");
 
@@ -485,6 +532,7 @@ mod tests {
             notes_hotkey: None,
             ai_hotkey: None,
             commands: None,
+            ..Default::default()
         };
         assert_eq!(custom_config.get_editor(), "vim");
 
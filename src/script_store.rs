@@ -0,0 +1,187 @@
+//! SQLite-backed key-value store for scripts
+//!
+//! Backs the `db.get`/`db.set`/`db.delete` protocol messages so scripts get
+//! persistent storage without managing their own files. Values are stored as
+//! JSON strings in ~/.sk/kit/db/store.sqlite, namespaced per script so two
+//! scripts can use the same key without colliding.
+
+#![allow(dead_code)]
+
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Shared connection, opened lazily on first use
+static DB_CONNECTION: OnceLock<Arc<Mutex<Connection>>> = OnceLock::new();
+
+/// Get the database path (~/.sk/kit/db/store.sqlite)
+fn get_db_path() -> Result<PathBuf> {
+    let db_dir = crate::setup::get_kit_path().join("db");
+    if !db_dir.exists() {
+        std::fs::create_dir_all(&db_dir).context("Failed to create ~/.sk/kit/db directory")?;
+    }
+    Ok(db_dir.join("store.sqlite"))
+}
+
+/// Create the kv table on a connection (idempotent)
+fn init_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")
+        .context("Failed to enable WAL mode")?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS kv (
+            namespace TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (namespace, key)
+        )",
+        [],
+    )
+    .context("Failed to create kv table")?;
+    Ok(())
+}
+
+/// Get or create the database connection
+fn get_connection() -> Result<Arc<Mutex<Connection>>> {
+    if let Some(conn) = DB_CONNECTION.get() {
+        return Ok(conn.clone());
+    }
+
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)
+        .with_context(|| format!("Failed to open database at {:?}", db_path))?;
+    init_schema(&conn)?;
+
+    let arc = Arc::new(Mutex::new(conn));
+    // Another thread may have won the race - use whichever got stored
+    Ok(DB_CONNECTION.get_or_init(|| arc).clone())
+}
+
+/// Derive a store namespace from a script path
+///
+/// Uses the path relative to the kit dir when the script lives under it
+/// (stable across machines), otherwise the absolute path. Two scripts only
+/// share state if they are literally the same file.
+pub fn namespace_for_script(script_path: &str) -> String {
+    let kit_dir = crate::setup::get_kit_path();
+    let path = Path::new(script_path);
+    match path.strip_prefix(&kit_dir) {
+        Ok(relative) => relative.to_string_lossy().to_string(),
+        Err(_) => script_path.to_string(),
+    }
+}
+
+/// Get a value by key within a namespace (JSON string as stored)
+pub fn get(namespace: &str, key: &str) -> Result<Option<String>> {
+    let conn = get_connection()?;
+    let conn = conn.lock().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT value FROM kv WHERE namespace = ?1 AND key = ?2")
+        .context("Failed to prepare get statement")?;
+    let mut rows = stmt
+        .query_map([namespace, key], |row| row.get::<_, String>(0))
+        .context("Failed to query value")?;
+    match rows.next() {
+        Some(value) => Ok(Some(value.context("Failed to read value")?)),
+        None => Ok(None),
+    }
+}
+
+/// Set a value by key within a namespace (upsert)
+pub fn set(namespace: &str, key: &str, value: &str) -> Result<()> {
+    let conn = get_connection()?;
+    let conn = conn.lock().unwrap();
+    conn.execute(
+        "INSERT INTO kv (namespace, key, value, updated_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(namespace, key) DO UPDATE SET
+             value = excluded.value,
+             updated_at = excluded.updated_at",
+        rusqlite::params![namespace, key, value, chrono::Utc::now().timestamp()],
+    )
+    .context("Failed to upsert value")?;
+    Ok(())
+}
+
+/// Delete a key within a namespace; returns whether a row was removed
+pub fn delete(namespace: &str, key: &str) -> Result<bool> {
+    let conn = get_connection()?;
+    let conn = conn.lock().unwrap();
+    let deleted = conn
+        .execute(
+            "DELETE FROM kv WHERE namespace = ?1 AND key = ?2",
+            [namespace, key],
+        )
+        .context("Failed to delete value")?;
+    Ok(deleted > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_conn() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+        conn
+    }
+
+    fn get_in(conn: &Connection, namespace: &str, key: &str) -> Option<String> {
+        conn.query_row(
+            "SELECT value FROM kv WHERE namespace = ?1 AND key = ?2",
+            [namespace, key],
+            |row| row.get(0),
+        )
+        .ok()
+    }
+
+    fn set_in(conn: &Connection, namespace: &str, key: &str, value: &str) {
+        conn.execute(
+            "INSERT INTO kv (namespace, key, value, updated_at)
+             VALUES (?1, ?2, ?3, 0)
+             ON CONFLICT(namespace, key) DO UPDATE SET value = excluded.value",
+            [namespace, key, value],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_set_get_roundtrip() {
+        let conn = test_conn();
+        set_in(&conn, "a.ts", "count", "42");
+        assert_eq!(get_in(&conn, "a.ts", "count"), Some("42".to_string()));
+        assert_eq!(get_in(&conn, "a.ts", "missing"), None);
+    }
+
+    #[test]
+    fn test_set_overwrites_existing_value() {
+        let conn = test_conn();
+        set_in(&conn, "a.ts", "key", "\"old\"");
+        set_in(&conn, "a.ts", "key", "\"new\"");
+        assert_eq!(get_in(&conn, "a.ts", "key"), Some("\"new\"".to_string()));
+    }
+
+    #[test]
+    fn test_namespaces_are_isolated() {
+        let conn = test_conn();
+        set_in(&conn, "a.ts", "key", "1");
+        set_in(&conn, "b.ts", "key", "2");
+        assert_eq!(get_in(&conn, "a.ts", "key"), Some("1".to_string()));
+        assert_eq!(get_in(&conn, "b.ts", "key"), Some("2".to_string()));
+    }
+
+    #[test]
+    fn test_namespace_for_script_inside_kit_dir() {
+        let kit_dir = crate::setup::get_kit_path();
+        let script = kit_dir.join("main").join("scripts").join("hello.ts");
+        let ns = namespace_for_script(&script.to_string_lossy());
+        assert_eq!(ns, "main/scripts/hello.ts");
+    }
+
+    #[test]
+    fn test_namespace_for_script_outside_kit_dir() {
+        let ns = namespace_for_script("/tmp/elsewhere/script.ts");
+        assert_eq!(ns, "/tmp/elsewhere/script.ts");
+    }
+}
@@ -0,0 +1,224 @@
+//! Script trust tracking
+//!
+//! Records a SHA-256 hash of every script the user has approved for
+//! execution. The first time a script runs - or when its content has changed
+//! since it was last approved - execution is held behind a confirmation, so
+//! kits synced from another machine (or tampered with on disk) can't run
+//! modified code silently.
+//!
+//! The store lives at `~/.sk/kit/trusted-scripts.json` and maps script paths
+//! to the hash that was approved. Approval happens when the user confirms the
+//! trust prompt (Enter); dismissing it (Escape) leaves the store untouched.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, info, instrument};
+
+/// Hash script content for trust comparison (SHA-256, lowercase hex)
+pub fn hash_content(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Why a script needs (or does not need) confirmation before running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustStatus {
+    /// Hash matches the approved one - run without prompting
+    Trusted,
+    /// Never approved on this machine
+    FirstRun,
+    /// Approved before, but the content hash no longer matches
+    Changed,
+}
+
+/// A single approval record
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrustEntry {
+    /// SHA-256 hex hash of the approved content
+    pub hash: String,
+    /// Unix timestamp (seconds) of the approval
+    pub approved_at: u64,
+}
+
+/// Store of approved script hashes with persistence
+#[derive(Debug, Clone)]
+pub struct TrustStore {
+    /// Map of script path to approval record
+    entries: HashMap<String, TrustEntry>,
+    /// Path to the trust data file
+    file_path: PathBuf,
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Serialize, Deserialize)]
+struct TrustData {
+    entries: HashMap<String, TrustEntry>,
+}
+
+impl TrustStore {
+    /// Create a new TrustStore with the default path (~/.sk/kit/trusted-scripts.json)
+    pub fn new() -> Self {
+        TrustStore {
+            entries: HashMap::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a TrustStore with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        TrustStore {
+            entries: HashMap::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    /// Get the default trust file path
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.sk/kit/trusted-scripts.json").as_ref())
+    }
+
+    /// Load trust data from disk
+    ///
+    /// Creates an empty store if the file doesn't exist.
+    #[instrument(name = "trust_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            info!(path = %self.file_path.display(), "Trust file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path)
+            .with_context(|| format!("Failed to read trust file: {}", self.file_path.display()))?;
+
+        let data: TrustData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse trust JSON")?;
+
+        self.entries = data.entries;
+
+        info!(
+            path = %self.file_path.display(),
+            entry_count = self.entries.len(),
+            "Loaded trust data"
+        );
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Save trust data to disk
+    #[instrument(name = "trust_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let data = TrustData {
+            entries: self.entries.clone(),
+        };
+
+        let json = serde_json::to_string_pretty(&data).context("Failed to serialize trust data")?;
+
+        std::fs::write(&self.file_path, json)
+            .with_context(|| format!("Failed to write trust file: {}", self.file_path.display()))?;
+
+        debug!(
+            path = %self.file_path.display(),
+            entry_count = self.entries.len(),
+            "Saved trust data"
+        );
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Check whether a script's current content is approved
+    pub fn check(&self, path: &str, content_hash: &str) -> TrustStatus {
+        match self.entries.get(path) {
+            None => TrustStatus::FirstRun,
+            Some(entry) if entry.hash == content_hash => TrustStatus::Trusted,
+            Some(_) => TrustStatus::Changed,
+        }
+    }
+
+    /// Record approval of a script's current content
+    pub fn approve(&mut self, path: &str, content_hash: String) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.entries.insert(
+            path.to_string(),
+            TrustEntry {
+                hash: content_hash,
+                approved_at: now,
+            },
+        );
+        self.dirty = true;
+    }
+}
+
+impl Default for TrustStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_content_is_stable_and_distinct() {
+        assert_eq!(hash_content("a"), hash_content("a"));
+        assert_ne!(hash_content("a"), hash_content("b"));
+        assert_eq!(hash_content("x").len(), 64);
+    }
+
+    #[test]
+    fn test_check_reports_first_run_then_trusted_then_changed() {
+        let mut store = TrustStore::with_path(PathBuf::from("/nonexistent/trust.json"));
+        let hash = hash_content("console.log('hi')");
+
+        assert_eq!(store.check("/kit/a.ts", &hash), TrustStatus::FirstRun);
+
+        store.approve("/kit/a.ts", hash.clone());
+        assert_eq!(store.check("/kit/a.ts", &hash), TrustStatus::Trusted);
+
+        let edited = hash_content("console.log('bye')");
+        assert_eq!(store.check("/kit/a.ts", &edited), TrustStatus::Changed);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("trust_test_{}.json", uuid::Uuid::new_v4()));
+
+        let hash = hash_content("body");
+        let mut store = TrustStore::with_path(path.clone());
+        store.approve("/kit/b.ts", hash.clone());
+        store.save().unwrap();
+
+        let mut reloaded = TrustStore::with_path(path.clone());
+        reloaded.load().unwrap();
+        assert_eq!(reloaded.check("/kit/b.ts", &hash), TrustStatus::Trusted);
+
+        std::fs::remove_file(path).ok();
+    }
+}
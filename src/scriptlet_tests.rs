@@ -193,6 +193,47 @@ fn test_parse_metadata_colons_in_value() {
     );
 }
 
+// ========================================
+// Execution Metadata Tests (cwd, env, confirm)
+// ========================================
+
+#[test]
+fn test_parse_metadata_cwd() {
+    let metadata = parse_html_comment_metadata("<!-- cwd: ~/projects/foo -->");
+    assert_eq!(metadata.cwd, Some("~/projects/foo".to_string()));
+}
+
+#[test]
+fn test_parse_metadata_env() {
+    let metadata = parse_html_comment_metadata(
+        "<!-- env: FOO=bar -->\nSome text\n<!-- env: API_URL=https://example.com -->",
+    );
+    assert_eq!(metadata.env.get("FOO"), Some(&"bar".to_string()));
+    assert_eq!(
+        metadata.env.get("API_URL"),
+        Some(&"https://example.com".to_string())
+    );
+}
+
+#[test]
+fn test_parse_metadata_env_malformed_skipped() {
+    // Entries without KEY=value form are ignored
+    let metadata = parse_html_comment_metadata("<!-- env: not-a-pair -->");
+    assert!(metadata.env.is_empty());
+
+    let metadata = parse_html_comment_metadata("<!-- env: =value-without-key -->");
+    assert!(metadata.env.is_empty());
+}
+
+#[test]
+fn test_parse_metadata_confirm_bool() {
+    let metadata = parse_html_comment_metadata("<!-- confirm: true -->");
+    assert_eq!(metadata.confirm, Some(true));
+
+    let metadata = parse_html_comment_metadata("<!-- confirm: false -->");
+    assert_eq!(metadata.confirm, Some(false));
+}
+
 // ========================================
 // Expand Metadata Tests
 // ========================================
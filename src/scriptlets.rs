@@ -247,6 +247,13 @@ pub struct ScriptletMetadata {
     pub expand: Option<String>,
     /// Alias trigger - when user types alias + space, immediately run script
     pub alias: Option<String>,
+    /// Working directory for execution (e.g., "~/projects/foo")
+    pub cwd: Option<String>,
+    /// Environment variables for execution, from `env: KEY=value` comments
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Whether to ask for confirmation before running
+    pub confirm: Option<bool>,
     /// Any additional metadata key-value pairs
     #[serde(flatten)]
     pub extra: HashMap<String, String>,
@@ -416,6 +423,20 @@ pub fn parse_html_comment_metadata(text: &str) -> ScriptletMetadata {
                         "description" => metadata.description = Some(value),
                         "expand" => metadata.expand = Some(value),
                         "alias" => metadata.alias = Some(value),
+                        "cwd" => metadata.cwd = Some(value),
+                        "env" => {
+                            // Format: "env: KEY=value"; malformed entries are skipped
+                            if let Some(eq_pos) = value.find('=') {
+                                let var_name = value[..eq_pos].trim().to_string();
+                                let var_value = value[eq_pos + 1..].trim().to_string();
+                                if !var_name.is_empty() {
+                                    metadata.env.insert(var_name, var_value);
+                                }
+                            }
+                        }
+                        "confirm" => {
+                            metadata.confirm = Some(value.to_lowercase() == "true" || value == "1")
+                        }
                         _ => {
                             metadata.extra.insert(key, value);
                         }
@@ -30,6 +30,46 @@ pub struct Script {
     pub alias: Option<String>,
     /// Keyboard shortcut for direct invocation (e.g., "opt i", "cmd shift k")
     pub shortcut: Option<String>,
+    /// Positional argument names from `// Args: url message` metadata.
+    /// Trailing tokens typed after the script name in the main filter are
+    /// passed to the script as argv in this order.
+    pub args: Option<Vec<String>>,
+    /// Hidden from search and grouped results via `// Hidden: true` or
+    /// `metadata = { hidden: true }`. Hidden scripts stay loaded so aliases,
+    /// shortcuts, and the `run` protocol message still work.
+    pub hidden: bool,
+    /// Run under the restricted sandbox profile via `// Sandbox: true` or
+    /// `metadata = { sandbox: true }`: no network, minimal environment.
+    /// Surfaced as a badge in the preview panel.
+    pub sandbox: bool,
+    /// Run with elevated privileges via `// Sudo: true` or
+    /// `metadata = { sudo: true }`. The system authorization dialog prompts
+    /// at launch; the password is handled by the OS and never passes through
+    /// (or is cached by) this app. Surfaced as a badge in the list and
+    /// preview panel.
+    pub sudo: bool,
+    /// Named environment profile via `// Profile: work` or
+    /// `metadata = { profile: "work" }`. Resolved against the `profiles`
+    /// map in config.json at spawn time: env vars, PATH additions, and a
+    /// working directory, so client-specific credentials stay organized.
+    pub profile: Option<String>,
+    /// Dotenv file via `// EnvFile: ./.env`, resolved relative to the
+    /// script's directory (tilde-expanded). Parsed and injected into the
+    /// child environment at spawn time, above the kit-level `~/.sk/kit/.env`
+    /// but below config.json `env` entries and profile vars.
+    pub env_file: Option<String>,
+    /// Working directory via `// Cwd: ~/projects/foo`, tilde-expanded at
+    /// spawn time. Without it scripts inherit the app's working directory;
+    /// a `// Profile:` cwd still wins when both are set.
+    pub cwd: Option<String>,
+    /// Process niceness via `// Nice: 10`. Positive values lower the
+    /// script's scheduling priority so heavy scripts don't starve the UI;
+    /// falls back to `processLimits.nice` in config.json when unset.
+    pub nice: Option<i32>,
+    /// Tags from `// Tags: git, work` or `metadata = { tags: [...] }`.
+    /// Shown as chips in the preview panel; `#tag` filter queries restrict
+    /// results to a tag.
+    pub tags: Vec<String>,
     /// Typed metadata from `metadata = { ... }` declaration in script
     pub typed_metadata: Option<TypedMetadata>,
     /// Schema definition from `schema = { ... }` declaration in script
@@ -54,6 +94,12 @@ pub struct Scriptlet {
     pub command: Option<String>,
     /// Alias for quick triggering
     pub alias: Option<String>,
+    /// Working directory from `<!-- cwd: ... -->` metadata
+    pub cwd: Option<String>,
+    /// Environment variables from `<!-- env: KEY=value -->` metadata
+    pub env: std::collections::HashMap<String, String>,
+    /// Whether to confirm before running, from `<!-- confirm: true -->`
+    pub confirm: Option<bool>,
 }
 
 /// Represents match indices for highlighting matched characters
@@ -176,6 +222,26 @@ pub struct ScriptMetadata {
     pub alias: Option<String>,
     /// Keyboard shortcut for direct invocation (e.g., "opt i", "cmd shift k")
     pub shortcut: Option<String>,
+    /// Positional argument names from `// Args: url message` metadata.
+    /// Shown as inline hints and filled from trailing filter tokens.
+    pub args: Option<Vec<String>>,
+    /// Hide from the main search list via `// Hidden: true`
+    pub hidden: Option<bool>,
+    /// Run sandboxed (no network, minimal env) via `// Sandbox: true`
+    pub sandbox: Option<bool>,
+    /// Run with elevated privileges (system authorization prompt) via
+    /// `// Sudo: true`
+    pub sudo: Option<bool>,
+    /// Named environment profile via `// Profile: work`
+    pub profile: Option<String>,
+    /// Dotenv file via `// EnvFile: ./.env`, relative to the script's dir
+    pub env_file: Option<String>,
+    /// Working directory via `// Cwd: ~/projects/foo`
+    pub cwd: Option<String>,
+    /// Process niceness via `// Nice: 10` (non-numeric values ignored)
+    pub nice: Option<i32>,
+    /// Comma-separated tags from `// Tags: git, work`
+    pub tags: Option<Vec<String>>,
 }
 
 /// Schedule metadata extracted from script file comments
@@ -259,6 +325,58 @@ pub fn extract_script_metadata(content: &str) -> ScriptMetadata {
                         metadata.shortcut = Some(value);
                     }
                 }
+                "args" => {
+                    if metadata.args.is_none() && !value.is_empty() {
+                        metadata.args = Some(value.split_whitespace().map(String::from).collect());
+                    }
+                }
+                "hidden" => {
+                    if metadata.hidden.is_none() && !value.is_empty() {
+                        metadata.hidden = Some(value.eq_ignore_ascii_case("true"));
+                    }
+                }
+                "sandbox" => {
+                    if metadata.sandbox.is_none() && !value.is_empty() {
+                        metadata.sandbox = Some(value.eq_ignore_ascii_case("true"));
+                    }
+                }
+                "sudo" => {
+                    if metadata.sudo.is_none() && !value.is_empty() {
+                        metadata.sudo = Some(value.eq_ignore_ascii_case("true"));
+                    }
+                }
+                "profile" => {
+                    if metadata.profile.is_none() && !value.is_empty() {
+                        metadata.profile = Some(value.to_string());
+                    }
+                }
+                "envfile" => {
+                    if metadata.env_file.is_none() && !value.is_empty() {
+                        metadata.env_file = Some(value.to_string());
+                    }
+                }
+                "cwd" => {
+                    if metadata.cwd.is_none() && !value.is_empty() {
+                        metadata.cwd = Some(value.to_string());
+                    }
+                }
+                "nice" => {
+                    if metadata.nice.is_none() {
+                        metadata.nice = value.parse::<i32>().ok();
+                    }
+                }
+                "tags" => {
+                    if metadata.tags.is_none() && !value.is_empty() {
+                        let tags: Vec<String> = value
+                            .split(',')
+                            .map(|t| t.trim().to_string())
+                            .filter(|t| !t.is_empty())
+                            .collect();
+                        if !tags.is_empty() {
+                            metadata.tags = Some(tags);
+                        }
+                    }
+                }
                 _ => {} // Ignore other metadata keys for now
             }
         }
@@ -299,6 +417,35 @@ pub fn extract_full_metadata(
             icon: typed.icon.clone().or(comment_meta.icon),
             alias: typed.alias.clone().or(comment_meta.alias),
             shortcut: typed.shortcut.clone().or(comment_meta.shortcut),
+            args: comment_meta.args,
+            // Typed `hidden: true` wins; `hidden: false` falls back to comments
+            hidden: if typed.hidden {
+                Some(true)
+            } else {
+                comment_meta.hidden
+            },
+            // Same rule for sandbox: typed `true` wins, otherwise comments
+            sandbox: if typed.sandbox {
+                Some(true)
+            } else {
+                comment_meta.sandbox
+            },
+            // And for sudo
+            sudo: if typed.sudo {
+                Some(true)
+            } else {
+                comment_meta.sudo
+            },
+            // Typed profile wins when present
+            profile: typed.profile.clone().or(comment_meta.profile),
+            env_file: comment_meta.env_file,
+            cwd: comment_meta.cwd,
+            nice: comment_meta.nice,
+            tags: if typed.tags.is_empty() {
+                comment_meta.tags
+            } else {
+                Some(typed.tags.clone())
+            },
         }
     } else {
         comment_meta
@@ -383,36 +530,203 @@ pub fn extract_schedule_metadata_from_file(path: &PathBuf) -> ScheduleMetadata {
     }
 }
 
+/// Extract background metadata from script content
+/// Parses lines looking for "// Background: true" with lenient matching
+/// Only checks the first 30 lines of the file
+pub fn extract_background_metadata(content: &str) -> bool {
+    for line in content.lines().take(30) {
+        if let Some((key, value)) = parse_metadata_line(line) {
+            if key.to_lowercase() == "background" {
+                return value.eq_ignore_ascii_case("true");
+            }
+        }
+    }
+    false
+}
+
+/// Check whether a script should run in background mode (no main window).
+/// Typed `metadata = { background: true }` takes precedence; falls back to
+/// the `// Background: true` comment.
+pub fn is_background_script(script: &Script) -> bool {
+    if let Some(ref typed) = script.typed_metadata {
+        if typed.background {
+            return true;
+        }
+    }
+    match fs::read_to_string(&script.path) {
+        Ok(content) => extract_background_metadata(&content),
+        Err(_) => false,
+    }
+}
+
+/// Extract restart metadata from script content
+/// Parses lines looking for "// Restart: true" with lenient matching
+/// Only checks the first 30 lines of the file
+pub fn extract_restart_metadata(content: &str) -> bool {
+    for line in content.lines().take(30) {
+        if let Some((key, value)) = parse_metadata_line(line) {
+            if key.to_lowercase() == "restart" {
+                return value.eq_ignore_ascii_case("true");
+            }
+        }
+    }
+    false
+}
+
+/// Check whether a crashed background script should be restarted automatically.
+/// Typed `metadata = { restart: true }` takes precedence; falls back to the
+/// `// Restart: true` comment.
+pub fn should_restart_script(script: &Script) -> bool {
+    if let Some(ref typed) = script.typed_metadata {
+        if typed.restart {
+            return true;
+        }
+    }
+    match fs::read_to_string(&script.path) {
+        Ok(content) => extract_restart_metadata(&content),
+        Err(_) => false,
+    }
+}
+
+/// Extract retries metadata from script content
+/// Parses lines looking for "// Retries: 3" with lenient matching
+/// Only checks the first 30 lines of the file
+pub fn extract_retries_metadata(content: &str) -> Option<u32> {
+    for line in content.lines().take(30) {
+        if let Some((key, value)) = parse_metadata_line(line) {
+            if key.to_lowercase() == "retries" {
+                return value.parse::<u32>().ok();
+            }
+        }
+    }
+    None
+}
+
+/// Maximum retries for a background/scheduled script that exits non-zero,
+/// from the `// Retries: N` comment. `None` means no retry policy.
+pub fn script_retry_limit(script: &Script) -> Option<u32> {
+    match fs::read_to_string(&script.path) {
+        Ok(content) => extract_retries_metadata(&content),
+        Err(_) => None,
+    }
+}
+
+/// Extract AllowURL metadata from script content
+/// Parses lines looking for "// AllowURL: true" with lenient matching
+/// Only checks the first 30 lines of the file
+pub fn extract_allow_url_metadata(content: &str) -> bool {
+    for line in content.lines().take(30) {
+        if let Some((key, value)) = parse_metadata_line(line) {
+            if key.to_lowercase() == "allowurl" {
+                return value.eq_ignore_ascii_case("true");
+            }
+        }
+    }
+    false
+}
+
+/// Check whether a script may be run directly from a scriptkit:// URL
+/// without confirmation. Scripts opt in with `// AllowURL: true`.
+pub fn is_url_allowed(script: &Script) -> bool {
+    match fs::read_to_string(&script.path) {
+        Ok(content) => extract_allow_url_metadata(&content),
+        Err(_) => false,
+    }
+}
+
+/// Extract MCP metadata from script content
+/// Parses lines looking for "// MCP: true" with lenient matching
+/// Only checks the first 30 lines of the file
+pub fn extract_mcp_metadata(content: &str) -> bool {
+    for line in content.lines().take(30) {
+        if let Some((key, value)) = parse_metadata_line(line) {
+            if key.to_lowercase() == "mcp" {
+                return value.eq_ignore_ascii_case("true");
+            }
+        }
+    }
+    false
+}
+
+/// Check whether a script is allow-listed as an MCP tool for AI agents.
+/// Scripts opt in with `// MCP: true`; everything else stays invisible
+/// to connected agents.
+pub fn is_mcp_exposed(script: &Script) -> bool {
+    match fs::read_to_string(&script.path) {
+        Ok(content) => extract_mcp_metadata(&content),
+        Err(_) => false,
+    }
+}
+
 /// Extract metadata from HTML comments in scriptlet markdown
-/// Looks for <!-- key: value --> patterns
+/// Looks for <!-- key: value --> patterns across all comment blocks
 fn extract_html_comment_metadata(text: &str) -> std::collections::HashMap<String, String> {
     use std::collections::HashMap;
     let mut metadata = HashMap::new();
 
-    // Find HTML comment blocks
-    if let Some(start) = text.find("<!--") {
-        if let Some(end) = text.find("-->") {
-            if start < end {
-                let comment_content = &text[start + 4..end];
-                // Parse key: value pairs
-                for line in comment_content.lines() {
-                    let trimmed = line.trim();
-                    if !trimmed.is_empty() {
-                        // Handle format: "key: value"
-                        if let Some(colon_pos) = trimmed.find(':') {
-                            let key = trimmed[..colon_pos].trim().to_string();
-                            let value = trimmed[colon_pos + 1..].trim().to_string();
-                            metadata.insert(key, value);
-                        }
-                    }
+    // Walk every HTML comment block; sections often use one comment per key
+    let mut remaining = text;
+    while let Some(start) = remaining.find("<!--") {
+        let Some(end) = remaining[start..].find("-->") else {
+            break;
+        };
+        let comment_content = &remaining[start + 4..start + end];
+        // Parse key: value pairs
+        for line in comment_content.lines() {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                // Handle format: "key: value"
+                if let Some(colon_pos) = trimmed.find(':') {
+                    let key = trimmed[..colon_pos].trim().to_string();
+                    let value = trimmed[colon_pos + 1..].trim().to_string();
+                    metadata.insert(key, value);
                 }
             }
         }
+        remaining = &remaining[start + end + 3..];
     }
 
     metadata
 }
 
+/// Extract environment variables from `<!-- env: KEY=value -->` comments
+///
+/// Collected separately from `extract_html_comment_metadata` because the
+/// `env` key may appear once per variable; a plain map would keep only the
+/// last occurrence.
+fn extract_env_metadata(text: &str) -> std::collections::HashMap<String, String> {
+    use std::collections::HashMap;
+    let mut env = HashMap::new();
+
+    let mut remaining = text;
+    while let Some(start) = remaining.find("<!--") {
+        let Some(end) = remaining[start..].find("-->") else {
+            break;
+        };
+        let comment_content = &remaining[start + 4..start + end];
+        for line in comment_content.lines() {
+            let trimmed = line.trim();
+            let Some(value) = trimmed
+                .strip_prefix("env:")
+                .or_else(|| trimmed.strip_prefix("env :"))
+            else {
+                continue;
+            };
+            // Format: "env: KEY=value"; malformed entries are skipped
+            if let Some(eq_pos) = value.find('=') {
+                let var_name = value[..eq_pos].trim().to_string();
+                let var_value = value[eq_pos + 1..].trim().to_string();
+                if !var_name.is_empty() {
+                    env.insert(var_name, var_value);
+                }
+            }
+        }
+        remaining = &remaining[start + end + 3..];
+    }
+
+    env
+}
+
 /// Extract code block from markdown text
 /// Looks for ```language ... ``` pattern and returns (language, code)
 fn extract_code_block(text: &str) -> Option<(String, String)> {
@@ -496,6 +810,11 @@ fn parse_scriptlet_section(
         file_path,
         command: Some(command),
         alias: metadata.get("alias").cloned(),
+        cwd: metadata.get("cwd").cloned(),
+        env: extract_env_metadata(section),
+        confirm: metadata
+            .get("confirm")
+            .map(|v| v.to_lowercase() == "true" || v == "1"),
     })
 }
 
@@ -653,6 +972,9 @@ pub fn load_scriptlets() -> Vec<Scriptlet> {
                                             file_path: Some(file_path),
                                             command: Some(parsed_scriptlet.command),
                                             alias: parsed_scriptlet.metadata.alias,
+                                            cwd: parsed_scriptlet.metadata.cwd,
+                                            env: parsed_scriptlet.metadata.env,
+                                            confirm: parsed_scriptlet.metadata.confirm,
                                         });
                                     }
                                 }
@@ -780,6 +1102,9 @@ pub fn read_scriptlets_from_file(path: &std::path::Path) -> Vec<Scriptlet> {
                 file_path: Some(file_path),
                 command: Some(parsed_scriptlet.command),
                 alias: parsed_scriptlet.metadata.alias,
+                cwd: parsed_scriptlet.metadata.cwd,
+                env: parsed_scriptlet.metadata.env,
+                confirm: parsed_scriptlet.metadata.confirm,
             }
         })
         .collect();
@@ -832,6 +1157,24 @@ pub fn read_scripts() -> Vec<Script> {
     scripts
 }
 
+/// Find a script by its display name or file stem (case-insensitive)
+///
+/// Used by the `run` protocol message so scripts can invoke each other by
+/// the name shown in the main list without knowing file paths.
+pub fn find_script_by_name(name: &str) -> Option<Script> {
+    let needle = name.trim().to_lowercase();
+    read_scripts().into_iter().find(|script| {
+        if script.name.to_lowercase() == needle {
+            return true;
+        }
+        script
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_lowercase() == needle)
+            .unwrap_or(false)
+    })
+}
+
 /// Read scripts from a single directory and append to the scripts vector
 fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Script>) {
     // Read the directory contents
@@ -866,6 +1209,15 @@ fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Script>) {
                                                 icon: script_metadata.icon,
                                                 alias: script_metadata.alias,
                                                 shortcut: script_metadata.shortcut,
+                                                args: script_metadata.args,
+                                                hidden: script_metadata.hidden.unwrap_or(false),
+                                                sandbox: script_metadata.sandbox.unwrap_or(false),
+                                                sudo: script_metadata.sudo.unwrap_or(false),
+                                                profile: script_metadata.profile,
+                                                env_file: script_metadata.env_file,
+                                                cwd: script_metadata.cwd,
+                                                nice: script_metadata.nice,
+                                                tags: script_metadata.tags.unwrap_or_default(),
                                                 typed_metadata,
                                                 schema,
                                             });
@@ -998,6 +1350,61 @@ fn fuzzy_match_with_indices(haystack: &str, pattern: &str) -> (bool, Vec<usize>)
     (matched, if matched { indices } else { Vec::new() })
 }
 
+/// Split a main-filter query into (name query, trailing argv tokens).
+///
+/// Returns None when the query has no trailing tokens. Callers fall back to
+/// the head token when the full query matches nothing, so "deploy prod --fast"
+/// runs the "deploy" script with ["prod", "--fast"] as argv.
+pub fn split_query_args(query: &str) -> Option<(&str, Vec<String>)> {
+    let trimmed = query.trim();
+    let (head, rest) = trimmed.split_once(char::is_whitespace)?;
+    let args: Vec<String> = rest.split_whitespace().map(String::from).collect();
+    if head.is_empty() || args.is_empty() {
+        None
+    } else {
+        Some((head, args))
+    }
+}
+
+/// Fuzzy-match an arg choice against a query.
+///
+/// Tries the choice name first, then falls back to the description.
+/// Returns Some((score, name_indices, description_indices)) when either
+/// matches. Name matches outrank description-only matches so name hits sort
+/// first; prefix and substring name matches get additional bonuses matching
+/// the weights used for scripts (~50 name, ~35 secondary field).
+pub fn fuzzy_match_choice(
+    name: &str,
+    description: Option<&str>,
+    query: &str,
+) -> Option<(i32, Vec<usize>, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new(), Vec::new()));
+    }
+    let query_lower = query.to_lowercase();
+
+    let (name_matched, name_indices) = fuzzy_match_with_indices_ascii(name, &query_lower);
+    if name_matched {
+        let name_lower = name.to_lowercase();
+        let mut score = 50;
+        if name_lower.starts_with(&query_lower) {
+            score += 25;
+        } else if name_lower.contains(&query_lower) {
+            score += 10;
+        }
+        return Some((score, name_indices, Vec::new()));
+    }
+
+    if let Some(desc) = description {
+        let (desc_matched, desc_indices) = fuzzy_match_with_indices_ascii(desc, &query_lower);
+        if desc_matched {
+            return Some((35, Vec::new(), desc_indices));
+        }
+    }
+
+    None
+}
+
 /// Score a haystack against a nucleo pattern.
 /// Returns Some(score) if the pattern matches, None otherwise.
 /// Score range is typically 0-1000+ (higher = better match).
@@ -1612,6 +2019,51 @@ pub fn fuzzy_search_unified_with_builtins(
 /// Returns combined and ranked results sorted by relevance
 /// Built-ins appear at the TOP of results (before scripts) when scores are equal
 /// Apps appear after built-ins but before scripts when scores are equal
+/// A scope operator parsed off the front of the main filter
+///
+/// Scopes let power users disambiguate quickly: `s:` limits to scripts (and
+/// scriptlets), `a:` to apps, `w:` to windows, `c:` to clipboard history,
+/// and `>` runs the rest of the query as a shell one-liner. Parsed before
+/// fuzzy matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryScope {
+    /// `s:` - scripts and scriptlets only
+    Scripts,
+    /// `a:` - applications only
+    Apps,
+    /// `w:` - open windows only
+    Windows,
+    /// `c:` - clipboard history entries
+    Clipboard,
+    /// `>` - shell one-liner (the rest of the query is the command)
+    Shell,
+}
+
+/// Split a scope operator off the front of a query
+///
+/// Returns the scope and the remaining query (leading whitespace stripped),
+/// or None when the query has no scope prefix. Prefixes are matched
+/// case-insensitively.
+pub fn parse_query_scope(query: &str) -> Option<(QueryScope, &str)> {
+    if let Some(rest) = query.strip_prefix('>') {
+        return Some((QueryScope::Shell, rest.trim_start()));
+    }
+
+    let mut chars = query.chars();
+    let letter = chars.next()?;
+    if chars.next()? != ':' {
+        return None;
+    }
+    let scope = match letter.to_ascii_lowercase() {
+        's' => QueryScope::Scripts,
+        'a' => QueryScope::Apps,
+        'w' => QueryScope::Windows,
+        'c' => QueryScope::Clipboard,
+        _ => return None,
+    };
+    Some((scope, query[2..].trim_start()))
+}
+
 pub fn fuzzy_search_unified_all(
     scripts: &[Script],
     scriptlets: &[Scriptlet],
@@ -1619,6 +2071,45 @@ pub fn fuzzy_search_unified_all(
     apps: &[crate::app_launcher::AppInfo],
     query: &str,
 ) -> Vec<SearchResult> {
+    // Scope operators: `s:` and `a:` narrow the search to one result type
+    // here. Window, clipboard, and shell scopes need data this module
+    // doesn't have, so the app resolves them before calling in (see
+    // get_grouped_results_cached).
+    match parse_query_scope(query) {
+        Some((QueryScope::Scripts, rest)) => {
+            let mut results: Vec<SearchResult> = fuzzy_search_scripts(scripts, rest)
+                .into_iter()
+                .map(SearchResult::Script)
+                .collect();
+            results.extend(
+                fuzzy_search_scriptlets(scriptlets, rest)
+                    .into_iter()
+                    .map(SearchResult::Scriptlet),
+            );
+            results.sort_by(|a, b| match b.score().cmp(&a.score()) {
+                Ordering::Equal => a.name().cmp(b.name()),
+                other => other,
+            });
+            return results;
+        }
+        Some((QueryScope::Apps, rest)) => {
+            return fuzzy_search_apps(apps, rest)
+                .into_iter()
+                .map(SearchResult::App)
+                .collect();
+        }
+        _ => {}
+    }
+
+    // Tag queries: "#git" restricts results to scripts tagged "git"; any
+    // text after the tag fuzzy-filters within that set. A bare "#" falls
+    // through (the app shows the tags browser for it).
+    if let Some(rest) = query.strip_prefix('#') {
+        if !rest.trim().is_empty() {
+            return search_scripts_by_tag(scripts, rest);
+        }
+    }
+
     let mut results = Vec::new();
 
     // Search built-ins first (they should appear at top when scores are equal)
@@ -1670,9 +2161,132 @@ pub fn fuzzy_search_unified_all(
         }
     });
 
+    // Argument passing: "script-name arg1 arg2" matches nothing as a whole,
+    // so retry with the first token only. The trailing tokens are passed to
+    // the launched script as argv (see split_query_args and execute paths).
+    if results.is_empty() {
+        if let Some((head, _)) = split_query_args(query) {
+            return fuzzy_search_unified_all(scripts, scriptlets, builtins, apps, head);
+        }
+    }
+
     results
 }
 
+/// Whether `next` is a pure append of `prev` that the incremental
+/// re-filter can serve (see [`fuzzy_search_unified_incremental`])
+///
+/// Excluded: tag queries (`#` matches tags exactly, so appending can widen
+/// the set), scoped queries (resolved against data this module doesn't
+/// hold), and queries with whitespace (the argument-passing retry searches
+/// with the first token only, which must see the full corpus).
+pub fn incremental_refilter_applies(prev: &str, next: &str) -> bool {
+    !prev.is_empty()
+        && next.len() > prev.len()
+        && next.starts_with(prev)
+        && !next.starts_with('#')
+        && !next.starts_with('\0')
+        && parse_query_scope(next).is_none()
+        && !next.contains(char::is_whitespace)
+}
+
+/// Re-score only a previous result set against a longer query
+///
+/// Substring and fuzzy-subsequence matching are monotone: appending a
+/// character can only shrink the match set. So when the new query extends
+/// the old one, re-scoring the previous results gives the same answer as a
+/// full corpus search at a fraction of the cost - per-item scores don't
+/// depend on the rest of the corpus.
+///
+/// Returns `None` when the previous set contains rows the shortcut can't
+/// reproduce (windows, dead-end fallback rows, tag-browser rows); the
+/// caller falls back to the full search.
+pub fn fuzzy_search_unified_incremental(
+    previous: &[SearchResult],
+    query: &str,
+) -> Option<Vec<SearchResult>> {
+    let mut scripts: Vec<Script> = Vec::new();
+    let mut scriptlets: Vec<Scriptlet> = Vec::new();
+    let mut builtins: Vec<BuiltInEntry> = Vec::new();
+    let mut apps: Vec<AppInfo> = Vec::new();
+
+    for result in previous {
+        match result {
+            SearchResult::Script(sm) => scripts.push(sm.script.clone()),
+            SearchResult::Scriptlet(sm) => scriptlets.push(sm.scriptlet.clone()),
+            SearchResult::App(am) => apps.push(am.app.clone()),
+            SearchResult::BuiltIn(bm) => {
+                // Fallback and tag rows are dynamic one-offs built for the
+                // previous query text - they can't just be re-scored
+                if matches!(
+                    bm.entry.feature,
+                    crate::builtins::BuiltInFeature::Fallback(_)
+                        | crate::builtins::BuiltInFeature::TagFilter(_)
+                ) {
+                    return None;
+                }
+                builtins.push(bm.entry.clone());
+            }
+            // Windows only appear via the `w:` scope, which never takes
+            // the incremental path
+            SearchResult::Window(_) => return None,
+        }
+    }
+
+    Some(fuzzy_search_unified_all(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        query,
+    ))
+}
+
+/// Filter scripts down to a tag query (the part after a leading `#`)
+///
+/// The first token is the tag (matched case-insensitively against
+/// `Script::tags`); any remaining text fuzzy-filters within the tagged set.
+fn search_scripts_by_tag(scripts: &[Script], rest: &str) -> Vec<SearchResult> {
+    let rest = rest.trim_start();
+    let (tag, remainder) = match rest.split_once(char::is_whitespace) {
+        Some((tag, remainder)) => (tag, remainder.trim()),
+        None => (rest.trim(), ""),
+    };
+
+    let tagged: Vec<Script> = scripts
+        .iter()
+        .filter(|s| s.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        .cloned()
+        .collect();
+
+    fuzzy_search_scripts(&tagged, remainder)
+        .into_iter()
+        .map(SearchResult::Script)
+        .collect()
+}
+
+/// Collect every tag across scripts with how many scripts carry it
+///
+/// Tags are merged case-insensitively (first-seen casing wins) and sorted by
+/// count descending, then name. Backs the tags browser shown for a bare `#`.
+pub fn collect_tags(scripts: &[Script]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for script in scripts {
+        for tag in &script.tags {
+            if let Some(entry) = counts
+                .iter_mut()
+                .find(|(name, _)| name.eq_ignore_ascii_case(tag))
+            {
+                entry.1 += 1;
+            } else {
+                counts.push((tag.clone(), 1));
+            }
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
 /// Perform unified fuzzy search across scripts, scriptlets, built-ins, apps, and windows
 /// Returns combined and ranked results sorted by relevance
 /// Order by type when scores are equal: Built-ins > Apps > Windows > Scripts > Scriptlets
@@ -1747,6 +2361,58 @@ pub fn fuzzy_search_unified_with_windows(
 /// Default maximum number of items to show in the RECENT section
 pub const DEFAULT_MAX_RECENT_ITEMS: usize = 10;
 
+/// Canonical section ids for the grouped (empty-filter) view, in default order
+pub const SECTION_IDS: &[&str] = &[
+    "PINNED",
+    "RECENT",
+    "SCRIPTS",
+    "SCRIPTLETS",
+    "COMMANDS",
+    "APPS",
+];
+
+/// Stable identity key for a search result
+///
+/// This is the key scheme shared by the frecency store and the pin store:
+/// scripts and apps use their path, everything else a typed prefix.
+pub fn result_key(result: &SearchResult) -> Option<String> {
+    match result {
+        SearchResult::Script(sm) => Some(sm.script.path.to_string_lossy().to_string()),
+        SearchResult::App(am) => Some(am.app.path.to_string_lossy().to_string()),
+        SearchResult::BuiltIn(bm) => Some(format!("builtin:{}", bm.entry.name)),
+        SearchResult::Scriptlet(sm) => Some(format!("scriptlet:{}", sm.scriptlet.name)),
+        SearchResult::Window(wm) => Some(format!("window:{}:{}", wm.window.app, wm.window.title)),
+    }
+}
+
+/// Presentation options for grouped-view sections
+///
+/// Order comes from config (`sections.order`), collapsed state from the
+/// persisted section state store. Display-name overrides are applied at
+/// render time, so `GroupedListItem::SectionHeader` always carries the
+/// canonical id.
+#[derive(Clone, Debug, Default)]
+pub struct SectionOptions {
+    /// Section ids in display order; unknown ids are ignored and omitted
+    /// sections keep their default relative order after the listed ones.
+    /// Empty means the built-in order.
+    pub order: Vec<String>,
+    /// Sections whose items are hidden (header still shown)
+    pub collapsed: std::collections::HashSet<String>,
+    /// Pinned item keys (see [`result_key`]) in display order; matching items
+    /// go to the PINNED section instead of their type section
+    pub pinned: Vec<String>,
+    /// Item keys hidden via the "Hide from Search" action; unlike the other
+    /// options this also applies in search mode
+    pub hidden: std::collections::HashSet<String>,
+    /// Maximum items per section before a "Show more…" row; 0 means no cap.
+    /// Keeps the initial grouped render fast when a section (typically APPS)
+    /// has hundreds of entries.
+    pub max_items: usize,
+    /// Sections the user expanded via "Show more…" - these ignore `max_items`
+    pub expanded: std::collections::HashSet<String>,
+}
+
 /// Get grouped results with RECENT/MAIN sections based on frecency
 ///
 /// This function creates a grouped view of search results:
@@ -1774,7 +2440,6 @@ pub const DEFAULT_MAX_RECENT_ITEMS: usize = 10;
 /// `(Vec<GroupedListItem>, Vec<SearchResult>)` - Grouped items and the flat results array.
 /// The `usize` in `Item(usize)` is the index into the flat results array.
 ///
-#[instrument(level = "debug", skip_all, fields(filter_len = filter_text.len()))]
 pub fn get_grouped_results(
     scripts: &[Script],
     scriptlets: &[Scriptlet],
@@ -1783,9 +2448,51 @@ pub fn get_grouped_results(
     frecency_store: &FrecencyStore,
     filter_text: &str,
     max_recent_items: usize,
+) -> (Vec<GroupedListItem>, Vec<SearchResult>) {
+    get_grouped_results_with_sections(
+        scripts,
+        scriptlets,
+        builtins,
+        apps,
+        frecency_store,
+        filter_text,
+        max_recent_items,
+        &SectionOptions::default(),
+    )
+}
+
+/// Like [`get_grouped_results`], but with custom section order and collapsed state
+///
+/// Sections listed in `sections.order` come first (in that order); the rest
+/// follow in default order. Collapsed sections keep their header but hide
+/// their items. Search mode (non-empty filter) ignores sections entirely,
+/// except for `sections.hidden`, which filters results in every mode.
+#[allow(clippy::too_many_arguments)]
+#[instrument(level = "debug", skip_all, fields(filter_len = filter_text.len()))]
+pub fn get_grouped_results_with_sections(
+    scripts: &[Script],
+    scriptlets: &[Scriptlet],
+    builtins: &[BuiltInEntry],
+    apps: &[AppInfo],
+    frecency_store: &FrecencyStore,
+    filter_text: &str,
+    max_recent_items: usize,
+    sections: &SectionOptions,
 ) -> (Vec<GroupedListItem>, Vec<SearchResult>) {
     // Get all unified search results
-    let results = fuzzy_search_unified_all(scripts, scriptlets, builtins, apps, filter_text);
+    let mut results = fuzzy_search_unified_all(scripts, scriptlets, builtins, apps, filter_text);
+
+    // Drop hidden items (script `// Hidden: true` metadata or the persisted
+    // hidden set) from every view. They stay loaded, so aliases, shortcuts,
+    // and `run` messages still work.
+    results.retain(|result| {
+        if let SearchResult::Script(sm) = result {
+            if sm.script.hidden {
+                return false;
+            }
+        }
+        !result_key(result).is_some_and(|key| sections.hidden.contains(&key))
+    });
 
     // Search mode: return flat list with no headers
     if !filter_text.is_empty() {
@@ -1810,21 +2517,9 @@ pub fn get_grouped_results(
         .map(|(path, _): &(String, f64)| path.clone())
         .collect();
 
-    // Map each result to its frecency score (if any)
-    // We need to get the path for each result type
-    let get_result_path = |result: &SearchResult| -> Option<String> {
-        match result {
-            SearchResult::Script(sm) => Some(sm.script.path.to_string_lossy().to_string()),
-            SearchResult::App(am) => Some(am.app.path.to_string_lossy().to_string()),
-            SearchResult::BuiltIn(bm) => Some(format!("builtin:{}", bm.entry.name)),
-            SearchResult::Scriptlet(sm) => Some(format!("scriptlet:{}", sm.scriptlet.name)),
-            SearchResult::Window(wm) => {
-                Some(format!("window:{}:{}", wm.window.app, wm.window.title))
-            }
-        }
-    };
-
-    // Find indices of results that are "recent" and categorize non-recent by type
+    // Find indices of results that are pinned or "recent", and categorize the
+    // rest by type. Pinned wins over recent so a pinned item never shows twice.
+    let mut pinned_indices: Vec<usize> = Vec::new();
     let mut recent_indices: Vec<(usize, f64)> = Vec::new();
     let mut scripts_indices: Vec<usize> = Vec::new();
     let mut scriptlets_indices: Vec<usize> = Vec::new();
@@ -1832,7 +2527,11 @@ pub fn get_grouped_results(
     let mut apps_indices: Vec<usize> = Vec::new();
 
     for (idx, result) in results.iter().enumerate() {
-        if let Some(path) = get_result_path(result) {
+        if let Some(path) = result_key(result) {
+            if sections.pinned.contains(&path) {
+                pinned_indices.push(idx);
+                continue;
+            }
             let score = frecency_store.get_score(&path);
             if score > 0.0 && recent_paths.contains(&path) {
                 recent_indices.push((idx, score));
@@ -1858,6 +2557,13 @@ pub fn get_grouped_results(
         }
     }
 
+    // Pinned items keep the user-chosen pin order, not alphabetical
+    pinned_indices.sort_by_key(|&idx| {
+        result_key(&results[idx])
+            .and_then(|key| sections.pinned.iter().position(|p| *p == key))
+            .unwrap_or(usize::MAX)
+    });
+
     // Sort recent items by frecency score (highest first)
     recent_indices.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
 
@@ -1879,43 +2585,76 @@ pub fn get_grouped_results(
     sort_alphabetically(&mut commands_indices);
     sort_alphabetically(&mut apps_indices);
 
-    // Build grouped list: RECENT first, then SCRIPTS, SCRIPTLETS, COMMANDS, APPS
-    if !recent_indices.is_empty() {
-        grouped.push(GroupedListItem::SectionHeader("RECENT".to_string()));
-        for (idx, _score) in &recent_indices {
-            grouped.push(GroupedListItem::Item(*idx));
+    // Build grouped list. Default order: RECENT, SCRIPTS, SCRIPTLETS,
+    // COMMANDS, APPS; config can move sections ahead of the rest.
+    let section_indices: Vec<(&str, Vec<usize>)> = vec![
+        ("PINNED", pinned_indices.clone()),
+        (
+            "RECENT",
+            recent_indices.iter().map(|(idx, _)| *idx).collect(),
+        ),
+        ("SCRIPTS", scripts_indices.clone()),
+        ("SCRIPTLETS", scriptlets_indices.clone()),
+        ("COMMANDS", commands_indices.clone()),
+        ("APPS", apps_indices.clone()),
+    ];
+
+    // Configured ids first (normalized, unknown ids dropped), then the rest
+    // in default order
+    let mut ordered_ids: Vec<&str> = Vec::with_capacity(SECTION_IDS.len());
+    for configured in &sections.order {
+        if let Some(id) = SECTION_IDS
+            .iter()
+            .find(|id| configured.eq_ignore_ascii_case(id))
+        {
+            if !ordered_ids.contains(id) {
+                ordered_ids.push(id);
+            }
         }
     }
-
-    if !scripts_indices.is_empty() {
-        grouped.push(GroupedListItem::SectionHeader("SCRIPTS".to_string()));
-        for idx in &scripts_indices {
-            grouped.push(GroupedListItem::Item(*idx));
+    for id in SECTION_IDS {
+        if !ordered_ids.contains(id) {
+            ordered_ids.push(id);
         }
     }
 
-    if !scriptlets_indices.is_empty() {
-        grouped.push(GroupedListItem::SectionHeader("SCRIPTLETS".to_string()));
-        for idx in &scriptlets_indices {
-            grouped.push(GroupedListItem::Item(*idx));
+    for id in ordered_ids {
+        let Some((_, indices)) = section_indices.iter().find(|(sid, _)| *sid == id) else {
+            continue;
+        };
+        if indices.is_empty() {
+            continue;
         }
-    }
-
-    if !commands_indices.is_empty() {
-        grouped.push(GroupedListItem::SectionHeader("COMMANDS".to_string()));
-        for idx in &commands_indices {
-            grouped.push(GroupedListItem::Item(*idx));
+        grouped.push(GroupedListItem::SectionHeader(id.to_string()));
+        // Collapsed sections keep their header but hide their items
+        if sections.collapsed.contains(id) {
+            continue;
         }
-    }
-
-    if !apps_indices.is_empty() {
-        grouped.push(GroupedListItem::SectionHeader("APPS".to_string()));
-        for idx in &apps_indices {
-            grouped.push(GroupedListItem::Item(*idx));
+        // Cap long sections behind a "Show more…" row unless expanded.
+        // The cap is presentation-only: flat_results still holds everything,
+        // so aliases, shortcuts, and `run` messages are unaffected.
+        let cap = if sections.max_items > 0 && !sections.expanded.contains(id) {
+            sections.max_items
+        } else {
+            usize::MAX
+        };
+        if indices.len() > cap {
+            for idx in indices.iter().take(cap) {
+                grouped.push(GroupedListItem::Item(*idx));
+            }
+            grouped.push(GroupedListItem::ShowMore {
+                section: id.to_string(),
+                hidden: indices.len() - cap,
+            });
+        } else {
+            for idx in indices {
+                grouped.push(GroupedListItem::Item(*idx));
+            }
         }
     }
 
     debug!(
+        pinned_count = pinned_indices.len(),
         recent_count = recent_indices.len(),
         scripts_count = scripts_indices.len(),
         scriptlets_count = scriptlets_indices.len(),
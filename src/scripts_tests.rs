@@ -13,6 +13,9 @@ fn test_scriptlet(name: &str, tool: &str, code: &str) -> Scriptlet {
         file_path: None,
         command: None,
         alias: None,
+        cwd: None,
+        env: std::collections::HashMap::new(),
+        confirm: None,
     }
 }
 
@@ -29,6 +32,9 @@ fn test_scriptlet_with_desc(name: &str, tool: &str, code: &str, desc: &str) -> S
         file_path: None,
         command: None,
         alias: None,
+        cwd: None,
+        env: std::collections::HashMap::new(),
+        confirm: None,
     }
 }
 
@@ -111,6 +117,9 @@ fn test_scriptlet_new_fields() {
         file_path: Some("/path/to/file.md#test".to_string()),
         command: Some("test".to_string()),
         alias: None,
+        cwd: None,
+        env: std::collections::HashMap::new(),
+        confirm: None,
     };
 
     assert_eq!(scriptlet.group, Some("My Group".to_string()));
@@ -807,6 +816,9 @@ fn test_scriptlet_with_all_metadata() {
         file_path: None,
         command: None,
         alias: None,
+        cwd: None,
+        env: std::collections::HashMap::new(),
+        confirm: None,
     };
 
     assert_eq!(scriptlet.name, "Full Scriptlet");
@@ -2794,6 +2806,384 @@ fn test_get_grouped_results_empty_filter_grouped_view() {
     assert!(matches!(&grouped[0], GroupedListItem::SectionHeader(s) if s == "SCRIPTS"));
 }
 
+#[test]
+fn test_grouped_results_custom_section_order() {
+    let scripts = vec![Script {
+        name: "alpha".to_string(),
+        path: PathBuf::from("/alpha.ts"),
+        extension: "ts".to_string(),
+        ..Default::default()
+    }];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins = create_test_builtins();
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    // COMMANDS listed first in config; unlisted sections follow in default
+    // order. Ids are case-insensitive and unknown ids are ignored.
+    let sections = SectionOptions {
+        order: vec!["bogus".to_string(), "commands".to_string()],
+        ..Default::default()
+    };
+    let (grouped, _results) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+
+    let headers: Vec<&str> = grouped
+        .iter()
+        .filter_map(|item| match item {
+            GroupedListItem::SectionHeader(s) => Some(s.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(headers, vec!["COMMANDS", "SCRIPTS"]);
+}
+
+#[test]
+fn test_grouped_results_collapsed_section_hides_items() {
+    let scripts = vec![Script {
+        name: "alpha".to_string(),
+        path: PathBuf::from("/alpha.ts"),
+        extension: "ts".to_string(),
+        ..Default::default()
+    }];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins = create_test_builtins();
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    let mut collapsed = std::collections::HashSet::new();
+    collapsed.insert("SCRIPTS".to_string());
+    let sections = SectionOptions {
+        collapsed,
+        ..Default::default()
+    };
+    let (grouped, _results) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+
+    // The SCRIPTS header is still there, but its item is hidden: the entry
+    // after it must be the next section header
+    let scripts_pos = grouped
+        .iter()
+        .position(|item| matches!(item, GroupedListItem::SectionHeader(s) if s == "SCRIPTS"))
+        .expect("SCRIPTS header should remain when collapsed");
+    assert!(matches!(
+        grouped.get(scripts_pos + 1),
+        Some(GroupedListItem::SectionHeader(_))
+    ));
+
+    // COMMANDS section is unaffected and still has its items
+    let commands_pos = grouped
+        .iter()
+        .position(|item| matches!(item, GroupedListItem::SectionHeader(s) if s == "COMMANDS"))
+        .expect("COMMANDS header should exist");
+    assert!(matches!(
+        grouped.get(commands_pos + 1),
+        Some(GroupedListItem::Item(_))
+    ));
+}
+
+#[test]
+fn test_grouped_results_pinned_section_first_in_pin_order() {
+    let scripts = vec![
+        Script {
+            name: "alpha".to_string(),
+            path: PathBuf::from("/alpha.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+        Script {
+            name: "beta".to_string(),
+            path: PathBuf::from("/beta.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+        Script {
+            name: "gamma".to_string(),
+            path: PathBuf::from("/gamma.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+    ];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    // Pin gamma then alpha - PINNED keeps that order, not alphabetical
+    let sections = SectionOptions {
+        pinned: vec!["/gamma.ts".to_string(), "/alpha.ts".to_string()],
+        ..Default::default()
+    };
+    let (grouped, results) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+
+    assert!(matches!(&grouped[0], GroupedListItem::SectionHeader(s) if s == "PINNED"));
+    let pinned_names: Vec<&str> = grouped[1..=2]
+        .iter()
+        .filter_map(|item| match item {
+            GroupedListItem::Item(idx) => Some(results[*idx].name()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(pinned_names, vec!["gamma", "alpha"]);
+
+    // beta stays in SCRIPTS and pinned items don't repeat there
+    assert!(matches!(&grouped[3], GroupedListItem::SectionHeader(s) if s == "SCRIPTS"));
+    assert!(matches!(
+        &grouped[4],
+        GroupedListItem::Item(idx) if results[*idx].name() == "beta"
+    ));
+    assert_eq!(grouped.len(), 5);
+}
+
+#[test]
+fn test_grouped_results_sections_ignored_in_search_mode() {
+    let scripts = vec![Script {
+        name: "alpha".to_string(),
+        path: PathBuf::from("/alpha.ts"),
+        extension: "ts".to_string(),
+        ..Default::default()
+    }];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    let mut collapsed = std::collections::HashSet::new();
+    collapsed.insert("SCRIPTS".to_string());
+    let sections = SectionOptions {
+        collapsed,
+        ..Default::default()
+    };
+    let (grouped, results) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "alpha",
+        10,
+        &sections,
+    );
+
+    // Search mode returns a flat list - collapsing never hides matches
+    assert_eq!(results.len(), 1);
+    assert!(grouped
+        .iter()
+        .all(|item| matches!(item, GroupedListItem::Item(_))));
+}
+
+#[test]
+fn test_hidden_metadata_script_excluded_from_results() {
+    let scripts = vec![
+        Script {
+            name: "visible".to_string(),
+            path: PathBuf::from("/visible.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+        Script {
+            name: "helper".to_string(),
+            path: PathBuf::from("/helper.ts"),
+            extension: "ts".to_string(),
+            hidden: true,
+            ..Default::default()
+        },
+    ];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    // Hidden in the grouped view
+    let (_, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name(), "visible");
+
+    // Hidden in search mode too, even when the filter matches exactly
+    let (_, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "helper",
+        10,
+    );
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_hidden_keys_excluded_from_results() {
+    let scripts = vec![
+        Script {
+            name: "alpha".to_string(),
+            path: PathBuf::from("/alpha.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+        Script {
+            name: "beta".to_string(),
+            path: PathBuf::from("/beta.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+    ];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    let mut hidden = std::collections::HashSet::new();
+    hidden.insert("/beta.ts".to_string());
+    let sections = SectionOptions {
+        hidden,
+        ..Default::default()
+    };
+    let (_, results) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name(), "alpha");
+}
+
+#[test]
+fn test_grouped_results_section_cap_show_more() {
+    let scripts: Vec<Script> = (0..5)
+        .map(|i| Script {
+            name: format!("script-{}", i),
+            path: PathBuf::from(format!("/script-{}.ts", i)),
+            extension: "ts".to_string(),
+            ..Default::default()
+        })
+        .collect();
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    let sections = SectionOptions {
+        max_items: 3,
+        ..Default::default()
+    };
+    let (grouped, results) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+
+    // Header + 3 capped items + the "Show more…" row
+    assert_eq!(grouped.len(), 5);
+    assert!(matches!(&grouped[0], GroupedListItem::SectionHeader(s) if s == "SCRIPTS"));
+    assert!(matches!(&grouped[3], GroupedListItem::Item(_)));
+    assert!(matches!(
+        &grouped[4],
+        GroupedListItem::ShowMore { section, hidden } if section == "SCRIPTS" && *hidden == 2
+    ));
+    // The cap is presentation-only: the flat results keep everything
+    assert_eq!(results.len(), 5);
+}
+
+#[test]
+fn test_grouped_results_section_cap_expanded_and_disabled() {
+    let scripts: Vec<Script> = (0..5)
+        .map(|i| Script {
+            name: format!("script-{}", i),
+            path: PathBuf::from(format!("/script-{}.ts", i)),
+            extension: "ts".to_string(),
+            ..Default::default()
+        })
+        .collect();
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    // Expanded section ignores the cap
+    let mut expanded = std::collections::HashSet::new();
+    expanded.insert("SCRIPTS".to_string());
+    let sections = SectionOptions {
+        max_items: 3,
+        expanded,
+        ..Default::default()
+    };
+    let (grouped, _) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+    assert_eq!(grouped.len(), 6); // header + all 5 items, no ShowMore
+    assert!(!grouped
+        .iter()
+        .any(|item| matches!(item, GroupedListItem::ShowMore { .. })));
+
+    // max_items == 0 disables the cap entirely
+    let sections = SectionOptions {
+        max_items: 0,
+        ..Default::default()
+    };
+    let (grouped, _) = get_grouped_results_with_sections(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        10,
+        &sections,
+    );
+    assert_eq!(grouped.len(), 6);
+}
+
 #[test]
 fn test_get_grouped_results_with_frecency() {
     let scripts = vec![
@@ -3520,6 +3910,9 @@ fn test_fuzzy_search_scriptlets_by_file_path() {
             file_path: Some("/path/to/urls.md#open-github".to_string()),
             command: Some("open-github".to_string()),
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         },
         Scriptlet {
             name: "Copy Text".to_string(),
@@ -3532,6 +3925,9 @@ fn test_fuzzy_search_scriptlets_by_file_path() {
             file_path: Some("/path/to/clipboard.md#copy-text".to_string()),
             command: Some("copy-text".to_string()),
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         },
     ];
 
@@ -3554,6 +3950,9 @@ fn test_fuzzy_search_scriptlets_by_anchor() {
             file_path: Some("/path/to/file.md#open-github".to_string()),
             command: Some("open-github".to_string()),
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         },
         Scriptlet {
             name: "Close Tab".to_string(),
@@ -3566,6 +3965,9 @@ fn test_fuzzy_search_scriptlets_by_anchor() {
             file_path: Some("/path/to/file.md#close-tab".to_string()),
             command: Some("close-tab".to_string()),
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         },
     ];
 
@@ -3588,6 +3990,9 @@ fn test_fuzzy_search_scriptlets_display_file_path() {
         file_path: Some("/home/user/.sk/kit/scriptlets/urls.md#test-slug".to_string()),
         command: Some("test-slug".to_string()),
         alias: None,
+        cwd: None,
+        env: std::collections::HashMap::new(),
+        confirm: None,
     }];
 
     let results = fuzzy_search_scriptlets(&scriptlets, "");
@@ -3612,6 +4017,9 @@ fn test_fuzzy_search_scriptlets_match_indices() {
         file_path: Some("/path/urls.md#test".to_string()),
         command: None,
         alias: None,
+        cwd: None,
+        env: std::collections::HashMap::new(),
+        confirm: None,
     }];
 
     let results = fuzzy_search_scriptlets(&scriptlets, "url");
@@ -4001,6 +4409,9 @@ fn bench_get_grouped_results_repeated_calls() {
             group: None,
             command: None,
             alias: None,
+            cwd: None,
+            env: std::collections::HashMap::new(),
+            confirm: None,
         })
         .collect();
 
@@ -4804,10 +5215,225 @@ fn test_frecency_change_invalidates_cache() {
     );
 }
 
-// ============================================================================
-// NUCLEO INTEGRATION TESTS
-// ============================================================================
-// These tests verify the nucleo_score helper function for fuzzy matching
+/// Models the dirty-flag invalidation in get_grouped_results_cached():
+/// the cache is keyed off the filter text, and a separate dirty flag covers
+/// everything the key can't see (script reload, frecency update, pin change).
+/// Invalidation must NOT touch the filter text - the old sentinel-key
+/// approach clobbered computed_filter_text, which could leak the sentinel
+/// into query history.
+#[test]
+fn test_grouped_cache_dirty_flag_invalidation() {
+    let scripts = vec![
+        test_script_with_path("AlphaScript", "/test/alpha.ts"),
+        test_script_with_path("BetaScript", "/test/beta.ts"),
+    ];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<crate::app_launcher::AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    struct DirtyFlagCache {
+        grouped_cache_key: String,
+        dirty: bool,
+        cached_grouped: Vec<GroupedListItem>,
+        compute_count: usize,
+    }
+
+    impl DirtyFlagCache {
+        fn new() -> Self {
+            DirtyFlagCache {
+                grouped_cache_key: String::from("\0_UNINITIALIZED_\0"),
+                dirty: false,
+                cached_grouped: vec![],
+                compute_count: 0,
+            }
+        }
+
+        /// Simulates the hit check in get_grouped_results_cached()
+        #[allow(clippy::too_many_arguments)]
+        fn get_cached(
+            &mut self,
+            scripts: &[Script],
+            scriptlets: &[Scriptlet],
+            builtins: &[BuiltInEntry],
+            apps: &[crate::app_launcher::AppInfo],
+            frecency_store: &FrecencyStore,
+            filter_text: &str,
+            sections: &SectionOptions,
+        ) -> Vec<GroupedListItem> {
+            if !self.dirty && filter_text == self.grouped_cache_key {
+                return self.cached_grouped.clone();
+            }
+            let (grouped, _) = get_grouped_results_with_sections(
+                scripts,
+                scriptlets,
+                builtins,
+                apps,
+                frecency_store,
+                filter_text,
+                10,
+                sections,
+            );
+            self.cached_grouped = grouped.clone();
+            self.grouped_cache_key = filter_text.to_string();
+            self.dirty = false;
+            self.compute_count += 1;
+            grouped
+        }
+
+        /// Simulates invalidate_grouped_cache(reason): flag only, key intact
+        fn invalidate(&mut self) {
+            self.dirty = true;
+        }
+    }
+
+    let mut cache = DirtyFlagCache::new();
+    let sections = SectionOptions::default();
+
+    // First access computes, second hits
+    cache.get_cached(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        &sections,
+    );
+    cache.get_cached(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        &sections,
+    );
+    assert_eq!(cache.compute_count, 1, "same filter should hit the cache");
+
+    // Pin change: invalidate, then the next access must pick up the new
+    // section options even though the filter text didn't change
+    let initial_has_pinned = cache
+        .cached_grouped
+        .iter()
+        .any(|item| matches!(item, GroupedListItem::SectionHeader(s) if s == "PINNED"));
+    assert!(!initial_has_pinned);
+
+    let pinned_sections = SectionOptions {
+        pinned: vec!["/test/alpha.ts".to_string()],
+        ..Default::default()
+    };
+    cache.invalidate();
+    assert_eq!(
+        cache.grouped_cache_key, "",
+        "invalidation must not clobber the cache key / filter text"
+    );
+    let grouped = cache.get_cached(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "",
+        &pinned_sections,
+    );
+    assert_eq!(cache.compute_count, 2, "dirty flag should force recompute");
+    assert!(
+        grouped
+            .iter()
+            .any(|item| matches!(item, GroupedListItem::SectionHeader(s) if s == "PINNED")),
+        "recompute after pin change should surface the PINNED section"
+    );
+
+    // Filter change invalidates implicitly via the key
+    cache.get_cached(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &frecency_store,
+        "alpha",
+        &pinned_sections,
+    );
+    assert_eq!(cache.compute_count, 3);
+}
+
+#[test]
+fn test_incremental_refilter_applies() {
+    // Pure append of a plain query qualifies
+    assert!(incremental_refilter_applies("al", "alp"));
+
+    // Deletion, unchanged, or non-extension edits do not
+    assert!(!incremental_refilter_applies("alp", "al"));
+    assert!(!incremental_refilter_applies("al", "al"));
+    assert!(!incremental_refilter_applies("al", "be"));
+
+    // Grouped view (empty previous query) always takes the full path
+    assert!(!incremental_refilter_applies("", "a"));
+
+    // Tag queries match tags exactly - appending can widen the set
+    assert!(!incremental_refilter_applies("#g", "#gi"));
+
+    // Scoped queries are resolved with app-level data
+    assert!(!incremental_refilter_applies("s:a", "s:al"));
+
+    // Whitespace triggers the argument-passing retry, which must see
+    // the full corpus
+    assert!(!incremental_refilter_applies("build", "build x"));
+}
+
+#[test]
+fn test_incremental_refilter_matches_full_search() {
+    let scripts = vec![
+        test_script_with_path("alpha-one", "/test/alpha-one.ts"),
+        test_script_with_path("alpha-two", "/test/alpha-two.ts"),
+        test_script_with_path("beta", "/test/beta.ts"),
+    ];
+    let scriptlets: Vec<Scriptlet> = vec![];
+    let builtins = create_test_builtins();
+    let apps: Vec<crate::app_launcher::AppInfo> = vec![];
+
+    // Full search for the shorter query is the "previous result set"
+    let previous = fuzzy_search_unified_all(&scripts, &scriptlets, &builtins, &apps, "alpha");
+    assert!(previous.len() >= 2);
+
+    // Re-scoring the previous set must give the same results, in the same
+    // order, as a full corpus search for the longer query
+    let incremental = fuzzy_search_unified_incremental(&previous, "alpha-o").unwrap();
+    let full = fuzzy_search_unified_all(&scripts, &scriptlets, &builtins, &apps, "alpha-o");
+
+    let incremental_names: Vec<&str> = incremental.iter().map(|r| r.name()).collect();
+    let full_names: Vec<&str> = full.iter().map(|r| r.name()).collect();
+    assert_eq!(incremental_names, full_names);
+    assert!(incremental_names.contains(&"alpha-one"));
+    assert!(!incremental_names.contains(&"beta"));
+}
+
+#[test]
+fn test_incremental_refilter_bails_on_dynamic_rows() {
+    use crate::builtins::{BuiltInFeature, BuiltInGroup, FallbackActionType};
+
+    // Dead-end fallback rows are built for the previous query text and
+    // can't be re-scored - the caller must run the full search
+    let previous = vec![SearchResult::BuiltIn(BuiltInMatch {
+        entry: BuiltInEntry {
+            id: "fallback-web-search".to_string(),
+            name: "Search Google for 'foo'".to_string(),
+            description: "Open a web search".to_string(),
+            keywords: vec![],
+            feature: BuiltInFeature::Fallback(FallbackActionType::WebSearch),
+            icon: None,
+            group: BuiltInGroup::Core,
+        },
+        score: 0,
+    })];
+    assert!(fuzzy_search_unified_incremental(&previous, "foob").is_none());
+}
+
+// ============================================================================
+// NUCLEO INTEGRATION TESTS
+// ============================================================================
+// These tests verify the nucleo_score helper function for fuzzy matching
 
 #[test]
 fn test_nucleo_score_basic_match() {
@@ -4939,3 +5565,457 @@ fn test_nucleo_score_case_insensitive() {
         "nucleo with Smart case matching should match lowercase 'hello' in uppercase 'HELLO WORLD'"
     );
 }
+
+#[test]
+fn test_extract_background_metadata_true() {
+    let content = r#"// Name: Poller
+// Background: true
+
+console.log("polling");
+"#;
+    assert!(extract_background_metadata(content));
+}
+
+#[test]
+fn test_extract_background_metadata_absent() {
+    let content = r#"// Name: Regular Script
+console.log("hello");
+"#;
+    assert!(!extract_background_metadata(content));
+}
+
+#[test]
+fn test_extract_background_metadata_false_value() {
+    let content = "// Background: false\nconsole.log(\"hi\");\n";
+    assert!(!extract_background_metadata(content));
+}
+
+#[test]
+fn test_extract_background_metadata_case_insensitive() {
+    let content = "// background: TRUE\n";
+    assert!(extract_background_metadata(content));
+}
+
+#[test]
+fn test_extract_background_metadata_only_first_30_lines() {
+    let mut content = String::new();
+    for i in 0..31 {
+        content.push_str(&format!("// line {}\n", i));
+    }
+    content.push_str("// Background: true\n");
+    assert!(!extract_background_metadata(&content));
+}
+
+#[test]
+fn test_extract_restart_metadata_true() {
+    let content = r#"// Name: Watcher
+// Background: true
+// Restart: true
+
+console.log("watching");
+"#;
+    assert!(extract_restart_metadata(content));
+}
+
+#[test]
+fn test_extract_restart_metadata_absent_or_false() {
+    assert!(!extract_restart_metadata(
+        "// Name: Regular\nconsole.log('hi');\n"
+    ));
+    assert!(!extract_restart_metadata("// Restart: false\n"));
+}
+
+#[test]
+fn test_extract_retries_metadata() {
+    let content = r#"// Name: Flaky Sync
+// Background: true
+// Retries: 3
+
+await sync();
+"#;
+    assert_eq!(extract_retries_metadata(content), Some(3));
+
+    // Non-numeric values and absent metadata mean no retry policy
+    assert_eq!(extract_retries_metadata("// Retries: lots\n"), None);
+    assert_eq!(extract_retries_metadata("// Name: Regular\n"), None);
+}
+
+#[test]
+fn test_should_restart_script_typed_metadata_wins() {
+    let script = Script {
+        name: "watcher".to_string(),
+        path: PathBuf::from("/nonexistent/watcher.ts"),
+        typed_metadata: Some(TypedMetadata {
+            restart: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(should_restart_script(&script));
+
+    // No typed metadata and an unreadable file means no restart
+    let script = Script {
+        name: "plain".to_string(),
+        path: PathBuf::from("/nonexistent/plain.ts"),
+        ..Default::default()
+    };
+    assert!(!should_restart_script(&script));
+}
+
+#[test]
+fn test_extract_allow_url_metadata_true() {
+    let content = "// Name: Deploy\n// AllowURL: true\n\nconsole.log('hi');";
+    assert!(extract_allow_url_metadata(content));
+}
+
+#[test]
+fn test_extract_allow_url_metadata_absent_or_false() {
+    assert!(!extract_allow_url_metadata(
+        "// Name: Deploy\nconsole.log('hi');"
+    ));
+    assert!(!extract_allow_url_metadata("// AllowURL: false\n"));
+}
+
+#[test]
+fn test_extract_allow_url_metadata_case_insensitive() {
+    assert!(extract_allow_url_metadata("// allowurl: TRUE\n"));
+}
+
+#[test]
+fn test_extract_mcp_metadata_true() {
+    let content = "// Name: Summarize\n// MCP: true\n\nconsole.log('hi');";
+    assert!(extract_mcp_metadata(content));
+}
+
+#[test]
+fn test_extract_mcp_metadata_absent_or_false() {
+    assert!(!extract_mcp_metadata(
+        "// Name: Summarize\nconsole.log('hi');"
+    ));
+    assert!(!extract_mcp_metadata("// MCP: false\n"));
+}
+
+#[test]
+fn test_extract_mcp_metadata_case_insensitive() {
+    assert!(extract_mcp_metadata("// mcp: TRUE\n"));
+}
+
+#[test]
+fn test_fuzzy_match_choice_name_match() {
+    let (score, name_ix, desc_ix) =
+        fuzzy_match_choice("Apple", Some("a red fruit"), "app").unwrap();
+    assert!(score >= 50, "name match should score at least 50");
+    assert_eq!(name_ix, vec![0, 1, 2]);
+    assert!(desc_ix.is_empty());
+}
+
+#[test]
+fn test_fuzzy_match_choice_prefix_beats_scattered() {
+    let (prefix_score, _, _) = fuzzy_match_choice("Apple", None, "app").unwrap();
+    let (scattered_score, _, _) = fuzzy_match_choice("Apricot Plus", None, "app").unwrap();
+    assert!(
+        prefix_score > scattered_score,
+        "prefix match should outrank scattered match"
+    );
+}
+
+#[test]
+fn test_fuzzy_match_choice_description_fallback() {
+    let (score, name_ix, desc_ix) =
+        fuzzy_match_choice("Banana", Some("yellow fruit"), "yellow").unwrap();
+    assert_eq!(score, 35, "description-only match scores below name match");
+    assert!(name_ix.is_empty());
+    assert_eq!(desc_ix.len(), 6);
+}
+
+#[test]
+fn test_fuzzy_match_choice_no_match() {
+    assert!(fuzzy_match_choice("Banana", Some("yellow fruit"), "xyz").is_none());
+}
+
+#[test]
+fn test_fuzzy_match_choice_empty_query_matches_all() {
+    let (score, name_ix, desc_ix) = fuzzy_match_choice("Banana", None, "").unwrap();
+    assert_eq!(score, 0);
+    assert!(name_ix.is_empty() && desc_ix.is_empty());
+}
+
+#[test]
+fn test_split_query_args() {
+    assert_eq!(
+        split_query_args("deploy prod --fast"),
+        Some(("deploy", vec!["prod".to_string(), "--fast".to_string()]))
+    );
+    assert_eq!(split_query_args("deploy"), None);
+    assert_eq!(split_query_args(""), None);
+    assert_eq!(split_query_args("  deploy  "), None);
+}
+
+#[test]
+fn test_extract_args_metadata() {
+    let content = "// Name: Open URL\n// Args: url message\n";
+    let metadata = extract_script_metadata(content);
+    assert_eq!(
+        metadata.args,
+        Some(vec!["url".to_string(), "message".to_string()])
+    );
+
+    let metadata = extract_script_metadata("// Name: No Args\n");
+    assert_eq!(metadata.args, None);
+}
+
+#[test]
+fn test_extract_hidden_metadata() {
+    let metadata = extract_script_metadata("// Name: Helper\n// Hidden: true\n");
+    assert_eq!(metadata.hidden, Some(true));
+
+    // Case-insensitive value, anything other than "true" means visible
+    let metadata = extract_script_metadata("// Hidden: TRUE\n");
+    assert_eq!(metadata.hidden, Some(true));
+    let metadata = extract_script_metadata("// Hidden: false\n");
+    assert_eq!(metadata.hidden, Some(false));
+
+    let metadata = extract_script_metadata("// Name: Visible\n");
+    assert_eq!(metadata.hidden, None);
+}
+
+#[test]
+fn test_extract_sandbox_metadata() {
+    let metadata = extract_script_metadata("// Name: Untrusted\n// Sandbox: true\n");
+    assert_eq!(metadata.sandbox, Some(true));
+
+    let metadata = extract_script_metadata("// Sandbox: false\n");
+    assert_eq!(metadata.sandbox, Some(false));
+
+    let metadata = extract_script_metadata("// Name: Trusted\n");
+    assert_eq!(metadata.sandbox, None);
+}
+
+#[test]
+fn test_extract_full_metadata_typed_sandbox_wins() {
+    let content = "metadata = {\n  name: \"Fetcher\",\n  sandbox: true\n}\n";
+    let (script_meta, typed, _) = extract_full_metadata(content);
+    assert!(typed.is_some());
+    assert_eq!(script_meta.sandbox, Some(true));
+}
+
+#[test]
+fn test_extract_sudo_metadata() {
+    let metadata = extract_script_metadata("// Name: Flush DNS\n// Sudo: true\n");
+    assert_eq!(metadata.sudo, Some(true));
+
+    let metadata = extract_script_metadata("// Sudo: false\n");
+    assert_eq!(metadata.sudo, Some(false));
+
+    let metadata = extract_script_metadata("// Name: Regular\n");
+    assert_eq!(metadata.sudo, None);
+}
+
+#[test]
+fn test_extract_full_metadata_typed_sudo_wins() {
+    let content = "metadata = {\n  name: \"Flush DNS\",\n  sudo: true\n}\n";
+    let (script_meta, typed, _) = extract_full_metadata(content);
+    assert!(typed.is_some());
+    assert_eq!(script_meta.sudo, Some(true));
+}
+
+#[test]
+fn test_extract_profile_metadata() {
+    let metadata = extract_script_metadata("// Name: Deploy\n// Profile: work\n");
+    assert_eq!(metadata.profile, Some("work".to_string()));
+
+    let metadata = extract_script_metadata("// Name: Regular\n");
+    assert_eq!(metadata.profile, None);
+}
+
+#[test]
+fn test_extract_full_metadata_typed_profile_wins() {
+    let content = "// Profile: home\nmetadata = {\n  name: \"Deploy\",\n  profile: \"work\"\n}\n";
+    let (script_meta, typed, _) = extract_full_metadata(content);
+    assert!(typed.is_some());
+    assert_eq!(script_meta.profile, Some("work".to_string()));
+}
+
+#[test]
+fn test_extract_cwd_metadata() {
+    let metadata = extract_script_metadata("// Name: Deploy\n// Cwd: ~/projects/foo\n");
+    assert_eq!(metadata.cwd, Some("~/projects/foo".to_string()));
+
+    let metadata = extract_script_metadata("// Name: Regular\n");
+    assert_eq!(metadata.cwd, None);
+}
+
+#[test]
+fn test_extract_nice_metadata() {
+    let metadata = extract_script_metadata("// Name: Heavy\n// Nice: 10\n");
+    assert_eq!(metadata.nice, Some(10));
+
+    // Non-numeric values are ignored
+    let metadata = extract_script_metadata("// Nice: low\n");
+    assert_eq!(metadata.nice, None);
+
+    let metadata = extract_script_metadata("// Name: Regular\n");
+    assert_eq!(metadata.nice, None);
+}
+
+#[test]
+fn test_extract_env_file_metadata() {
+    let metadata = extract_script_metadata("// Name: Deploy\n// EnvFile: ./.env\n");
+    assert_eq!(metadata.env_file, Some("./.env".to_string()));
+
+    let metadata = extract_script_metadata("// Name: Regular\n");
+    assert_eq!(metadata.env_file, None);
+}
+
+#[test]
+fn test_extract_tags_metadata() {
+    let metadata = extract_script_metadata("// Name: Deploy\n// Tags: git, work , ci\n");
+    assert_eq!(
+        metadata.tags,
+        Some(vec![
+            "git".to_string(),
+            "work".to_string(),
+            "ci".to_string()
+        ])
+    );
+
+    // Empty segments are dropped, an all-empty list counts as no tags
+    let metadata = extract_script_metadata("// Tags: , ,\n");
+    assert_eq!(metadata.tags, None);
+
+    let metadata = extract_script_metadata("// Name: Untagged\n");
+    assert_eq!(metadata.tags, None);
+}
+
+#[test]
+fn test_tag_query_restricts_to_tag() {
+    let scripts = vec![
+        Script {
+            name: "deploy".to_string(),
+            path: PathBuf::from("/deploy.ts"),
+            extension: "ts".to_string(),
+            tags: vec!["git".to_string(), "work".to_string()],
+            ..Default::default()
+        },
+        Script {
+            name: "commit".to_string(),
+            path: PathBuf::from("/commit.ts"),
+            extension: "ts".to_string(),
+            tags: vec!["Git".to_string()],
+            ..Default::default()
+        },
+        Script {
+            name: "notes".to_string(),
+            path: PathBuf::from("/notes.ts"),
+            extension: "ts".to_string(),
+            ..Default::default()
+        },
+    ];
+
+    // "#git" matches both tagged scripts, case-insensitively, and nothing else
+    let results = fuzzy_search_unified_all(&scripts, &[], &[], &[], "#git");
+    let names: Vec<&str> = results.iter().map(|r| r.name()).collect();
+    assert_eq!(names, vec!["commit", "deploy"]);
+
+    // Text after the tag fuzzy-filters within the tagged set
+    let results = fuzzy_search_unified_all(&scripts, &[], &[], &[], "#git dep");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name(), "deploy");
+
+    // Unknown tags match nothing
+    let results = fuzzy_search_unified_all(&scripts, &[], &[], &[], "#missing");
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_collect_tags_counts_and_order() {
+    let scripts = vec![
+        Script {
+            name: "a".to_string(),
+            path: PathBuf::from("/a.ts"),
+            extension: "ts".to_string(),
+            tags: vec!["git".to_string(), "work".to_string()],
+            ..Default::default()
+        },
+        Script {
+            name: "b".to_string(),
+            path: PathBuf::from("/b.ts"),
+            extension: "ts".to_string(),
+            tags: vec!["Git".to_string()],
+            ..Default::default()
+        },
+    ];
+
+    // Case-insensitive merge (first-seen casing wins), count desc then name
+    let tags = collect_tags(&scripts);
+    assert_eq!(tags, vec![("git".to_string(), 2), ("work".to_string(), 1)]);
+}
+
+#[test]
+fn test_unified_search_falls_back_to_head_token() {
+    let scripts = vec![Script {
+        name: "deploy".to_string(),
+        path: PathBuf::from("/test/deploy.ts"),
+        extension: "ts".to_string(),
+        ..Default::default()
+    }];
+
+    // Full query matches nothing, head token matches the script
+    let results = fuzzy_search_unified_all(&scripts, &[], &[], &[], "deploy prod --fast");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name(), "deploy");
+}
+
+#[test]
+fn test_parse_query_scope_prefixes() {
+    assert_eq!(
+        parse_query_scope("s:deploy"),
+        Some((QueryScope::Scripts, "deploy"))
+    );
+    assert_eq!(
+        parse_query_scope("a:safari"),
+        Some((QueryScope::Apps, "safari"))
+    );
+    assert_eq!(
+        parse_query_scope("w:chrome"),
+        Some((QueryScope::Windows, "chrome"))
+    );
+    assert_eq!(
+        parse_query_scope("c:token"),
+        Some((QueryScope::Clipboard, "token"))
+    );
+    assert_eq!(
+        parse_query_scope("> ls -la"),
+        Some((QueryScope::Shell, "ls -la"))
+    );
+}
+
+#[test]
+fn test_parse_query_scope_case_and_whitespace() {
+    // Scope letters are case-insensitive; whitespace after the prefix is trimmed
+    assert_eq!(
+        parse_query_scope("S: deploy"),
+        Some((QueryScope::Scripts, "deploy"))
+    );
+    assert_eq!(
+        parse_query_scope("A:  music"),
+        Some((QueryScope::Apps, "music"))
+    );
+}
+
+#[test]
+fn test_parse_query_scope_non_scopes() {
+    // Ordinary queries (including colons past position 1) are not scopes
+    assert_eq!(parse_query_scope("deploy"), None);
+    assert_eq!(parse_query_scope("http://example.com"), None);
+    assert_eq!(parse_query_scope("x:whatever"), None);
+    assert_eq!(parse_query_scope(""), None);
+    assert_eq!(parse_query_scope("s"), None);
+}
+
+#[test]
+fn test_parse_query_scope_empty_rest() {
+    // A bare prefix is still a scope - the caller shows everything in that scope
+    assert_eq!(parse_query_scope("s:"), Some((QueryScope::Scripts, "")));
+    assert_eq!(parse_query_scope(">"), Some((QueryScope::Shell, "")));
+}
@@ -0,0 +1,311 @@
+//! Secrets Vault builtin - browse env secrets stored in the system keyring
+//!
+//! The keyring can't enumerate its own entries, so this module keeps an index
+//! of known env keys at `~/.sk/kit/secrets-index.json`. `EnvPrompt`'s
+//! set/delete helpers keep the index in sync; the vault lists the indexed
+//! keys with values hidden and offers reveal / copy / update / delete plus a
+//! confirmation-gated export to `~/.sk/kit/.env`.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::logging;
+use crate::prompts::env::{get_secret, set_secret};
+
+/// File the key index is persisted to
+const INDEX_FILE: &str = "~/.sk/kit/secrets-index.json";
+
+/// File secrets are exported to
+const EXPORT_FILE: &str = "~/.sk/kit/.env";
+
+// ============================================================================
+// Key index
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SecretsIndex {
+    /// Known env keys, sorted
+    keys: Vec<String>,
+}
+
+fn index_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde(INDEX_FILE).into_owned())
+}
+
+fn load_index_from(path: &Path) -> SecretsIndex {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_index_to(path: &Path, index: &SecretsIndex) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(index)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    std::fs::write(path, json)
+}
+
+/// Env keys known to be stored in the keyring, sorted
+pub fn known_keys() -> Vec<String> {
+    load_index_from(&index_path()).keys
+}
+
+/// Record a key as present in the keyring
+pub fn record_key(key: &str) {
+    record_key_at(&index_path(), key);
+}
+
+fn record_key_at(path: &Path, key: &str) {
+    let mut index = load_index_from(path);
+    if !index.keys.iter().any(|k| k == key) {
+        index.keys.push(key.to_string());
+        index.keys.sort();
+        if let Err(e) = save_index_to(path, &index) {
+            logging::log("KEYRING", &format!("Failed to save secrets index: {}", e));
+        }
+    }
+}
+
+/// Remove a key from the index (after deleting it from the keyring)
+pub fn forget_key(key: &str) {
+    forget_key_at(&index_path(), key);
+}
+
+fn forget_key_at(path: &Path, key: &str) {
+    let mut index = load_index_from(path);
+    let before = index.keys.len();
+    index.keys.retain(|k| k != key);
+    if index.keys.len() != before {
+        if let Err(e) = save_index_to(path, &index) {
+            logging::log("KEYRING", &format!("Failed to save secrets index: {}", e));
+        }
+    }
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Quote a value for a .env file when it needs it
+fn quote_env_value(value: &str) -> String {
+    if value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '#' || c == '"' || c == '\'')
+    {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render (key, value) pairs as .env file contents
+fn format_env(pairs: &[(String, String)]) -> String {
+    let mut out = String::new();
+    for (key, value) in pairs {
+        out.push_str(key);
+        out.push('=');
+        out.push_str(&quote_env_value(value));
+        out.push('\n');
+    }
+    out
+}
+
+/// Export all indexed secrets to `~/.sk/kit/.env`
+///
+/// Returns the number of keys written and the target path. Keys whose value
+/// is missing from the keyring are skipped.
+pub fn export_to_env_file() -> Result<(usize, PathBuf), String> {
+    let path = PathBuf::from(shellexpand::tilde(EXPORT_FILE).into_owned());
+    let pairs: Vec<(String, String)> = known_keys()
+        .into_iter()
+        .filter_map(|key| get_secret(&key).map(|value| (key, value)))
+        .collect();
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(&path, format_env(&pairs))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    logging::log(
+        "KEYRING",
+        &format!("Exported {} secret(s) to {}", pairs.len(), path.display()),
+    );
+    Ok((pairs.len(), path))
+}
+
+// ============================================================================
+// View state
+// ============================================================================
+
+/// Which part of the vault is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Key list with reveal/copy/update/delete actions
+    List,
+    /// Typing a new value for the selected key (input masked)
+    Edit,
+    /// Export confirmation before writing plaintext to disk
+    ConfirmExport,
+}
+
+/// State backing the Secrets Vault builtin view
+#[derive(Debug, Clone)]
+pub struct SecretsState {
+    pub stage: Stage,
+    /// Indexed env keys
+    pub keys: Vec<String>,
+    /// Filter text for the key list
+    pub filter: String,
+    /// Focused row in the key list
+    pub selected_index: usize,
+    /// Key whose value is currently revealed (cleared on navigation)
+    pub revealed: Option<(String, String)>,
+    /// Key being updated in the Edit stage
+    pub edit_key: String,
+    /// New value typed so far (rendered masked)
+    pub edit_value: String,
+}
+
+impl SecretsState {
+    pub fn new() -> Self {
+        SecretsState {
+            stage: Stage::List,
+            keys: known_keys(),
+            filter: String::new(),
+            selected_index: 0,
+            revealed: None,
+            edit_key: String::new(),
+            edit_value: String::new(),
+        }
+    }
+
+    /// Keys matching the current filter
+    pub fn visible_keys(&self) -> Vec<String> {
+        if self.filter.is_empty() {
+            return self.keys.clone();
+        }
+        let filter_lower = self.filter.to_lowercase();
+        self.keys
+            .iter()
+            .filter(|key| key.to_lowercase().contains(&filter_lower))
+            .cloned()
+            .collect()
+    }
+
+    /// Key at the focused row
+    pub fn selected_key(&self) -> Option<String> {
+        self.visible_keys().get(self.selected_index).cloned()
+    }
+
+    /// Reveal the focused key's value (fetches from the keyring)
+    pub fn reveal_selected(&mut self) {
+        if let Some(key) = self.selected_key() {
+            if let Some(value) = get_secret(&key) {
+                self.revealed = Some((key, value));
+            }
+        }
+    }
+
+    /// Begin updating the focused key
+    pub fn begin_edit(&mut self) {
+        if let Some(key) = self.selected_key() {
+            self.stage = Stage::Edit;
+            self.edit_key = key;
+            self.edit_value.clear();
+        }
+    }
+
+    /// Store the typed value and return to the list
+    pub fn commit_edit(&mut self) -> Result<(), String> {
+        let result = set_secret(&self.edit_key, &self.edit_value);
+        if result.is_ok() {
+            record_key(&self.edit_key);
+            self.keys = known_keys();
+        }
+        self.stage = Stage::List;
+        self.edit_value.clear();
+        self.revealed = None;
+        result
+    }
+
+    /// Reload the key index (after deletes)
+    pub fn reload(&mut self) {
+        self.keys = known_keys();
+        if self.selected_index >= self.visible_keys().len() {
+            self.selected_index = self.visible_keys().len().saturating_sub(1);
+        }
+        self.revealed = None;
+    }
+}
+
+impl Default for SecretsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_index(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sk-secrets-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("index.json")
+    }
+
+    #[test]
+    fn test_record_and_forget_keys() {
+        let path = temp_index("record");
+        record_key_at(&path, "OPENAI_API_KEY");
+        record_key_at(&path, "GITHUB_TOKEN");
+        record_key_at(&path, "OPENAI_API_KEY");
+
+        let index = load_index_from(&path);
+        assert_eq!(index.keys, vec!["GITHUB_TOKEN", "OPENAI_API_KEY"]);
+
+        forget_key_at(&path, "GITHUB_TOKEN");
+        assert_eq!(load_index_from(&path).keys, vec!["OPENAI_API_KEY"]);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_format_env_plain_values() {
+        let pairs = vec![("API_KEY".to_string(), "abc123".to_string())];
+        assert_eq!(format_env(&pairs), "API_KEY=abc123\n");
+    }
+
+    #[test]
+    fn test_format_env_quotes_when_needed() {
+        let pairs = vec![
+            ("GREETING".to_string(), "hello world".to_string()),
+            ("EMPTY".to_string(), String::new()),
+            ("QUOTED".to_string(), "say \"hi\"".to_string()),
+        ];
+        let out = format_env(&pairs);
+        assert!(out.contains("GREETING=\"hello world\"\n"));
+        assert!(out.contains("EMPTY=\"\"\n"));
+        assert!(out.contains("QUOTED=\"say \\\"hi\\\"\"\n"));
+    }
+
+    #[test]
+    fn test_visible_keys_filter() {
+        let state = SecretsState {
+            stage: Stage::List,
+            keys: vec!["OPENAI_API_KEY".to_string(), "GITHUB_TOKEN".to_string()],
+            filter: "git".to_string(),
+            selected_index: 0,
+            revealed: None,
+            edit_key: String::new(),
+            edit_value: String::new(),
+        };
+        assert_eq!(state.visible_keys(), vec!["GITHUB_TOKEN"]);
+    }
+}
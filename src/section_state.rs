@@ -0,0 +1,204 @@
+//! Persisted collapsed-state for main list sections
+//!
+//! Users can click a section header (RECENT, SCRIPTS, APPS, ...) to collapse
+//! it; the set of collapsed sections is remembered across restarts in
+//! `~/.sk/kit/sections.json`. Section order and display names come from
+//! config (`sections.order` / `sections.names`) - only the collapsed state
+//! lives here, since it changes at runtime.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// Store for collapsed section state with persistence
+#[derive(Debug, Clone)]
+pub struct SectionStateStore {
+    /// Canonical section ids (e.g., "APPS") that are currently collapsed
+    collapsed: HashSet<String>,
+    /// Path to the section state file
+    file_path: PathBuf,
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Serialize, Deserialize)]
+struct SectionStateData {
+    collapsed: HashSet<String>,
+}
+
+impl SectionStateStore {
+    /// Create a new SectionStateStore with the default path (~/.sk/kit/sections.json)
+    pub fn new() -> Self {
+        SectionStateStore {
+            collapsed: HashSet::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a SectionStateStore with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        SectionStateStore {
+            collapsed: HashSet::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    /// Get the default section state file path
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.sk/kit/sections.json").as_ref())
+    }
+
+    /// Load collapsed state from disk
+    ///
+    /// Starts with nothing collapsed if the file doesn't exist.
+    #[instrument(name = "section_state_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            debug!(path = %self.file_path.display(), "Section state file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path).with_context(|| {
+            format!(
+                "Failed to read section state file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        let data: SectionStateData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse section state JSON")?;
+
+        self.collapsed = data.collapsed;
+        self.dirty = false;
+
+        info!(
+            path = %self.file_path.display(),
+            collapsed_count = self.collapsed.len(),
+            "Loaded section state"
+        );
+        Ok(())
+    }
+
+    /// Save collapsed state to disk
+    #[instrument(name = "section_state_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let data = SectionStateData {
+            collapsed: self.collapsed.clone(),
+        };
+
+        let json = serde_json::to_string_pretty(&data)
+            .context("Failed to serialize section state data")?;
+
+        std::fs::write(&self.file_path, json).with_context(|| {
+            format!(
+                "Failed to write section state file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Toggle a section's collapsed state, returning the new state
+    pub fn toggle(&mut self, section_id: &str) -> bool {
+        let now_collapsed = if self.collapsed.remove(section_id) {
+            false
+        } else {
+            self.collapsed.insert(section_id.to_string());
+            true
+        };
+        self.dirty = true;
+        now_collapsed
+    }
+
+    /// Whether a section is currently collapsed
+    pub fn is_collapsed(&self, section_id: &str) -> bool {
+        self.collapsed.contains(section_id)
+    }
+
+    /// The set of collapsed section ids
+    pub fn collapsed(&self) -> &HashSet<String> {
+        &self.collapsed
+    }
+}
+
+impl Default for SectionStateStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "sk-section-state-test-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_toggle_and_query() {
+        let mut store = SectionStateStore::with_path(temp_path("toggle"));
+        assert!(!store.is_collapsed("APPS"));
+        assert!(store.toggle("APPS"));
+        assert!(store.is_collapsed("APPS"));
+        assert!(!store.toggle("APPS"));
+        assert!(!store.is_collapsed("APPS"));
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        {
+            let mut store = SectionStateStore::with_path(path.clone());
+            store.toggle("APPS");
+            store.toggle("COMMANDS");
+            store.save().unwrap();
+        }
+        {
+            let mut store = SectionStateStore::with_path(path.clone());
+            store.load().unwrap();
+            assert!(store.is_collapsed("APPS"));
+            assert!(store.is_collapsed("COMMANDS"));
+            assert!(!store.is_collapsed("SCRIPTS"));
+        }
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_load_missing_file_starts_fresh() {
+        let mut store = SectionStateStore::with_path(temp_path("missing-nonexistent"));
+        assert!(store.load().is_ok());
+        assert!(store.collapsed().is_empty());
+    }
+
+    #[test]
+    fn test_save_skips_when_not_dirty() {
+        let path = temp_path("not-dirty");
+        let mut store = SectionStateStore::with_path(path.clone());
+        store.save().unwrap();
+        assert!(!path.exists());
+    }
+}
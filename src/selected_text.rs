@@ -146,12 +146,66 @@ pub fn get_selected_text() -> Result<String> {
             }
         }
         Err(e) => {
-            warn!(error = %e, "Failed to get selected text");
-            bail!("Failed to get selected text: {}", e)
+            // Some apps expose no AX text attributes at all; fall back to our
+            // own Cmd+C simulation with clipboard save/restore before giving up
+            warn!(error = %e, "AX-based selected text failed, trying clipboard fallback");
+            get_via_clipboard_fallback()
+                .map_err(|fallback_err| {
+                    anyhow::anyhow!(
+                        "Failed to get selected text: {} (clipboard fallback: {})",
+                        e,
+                        fallback_err
+                    )
+                })
         }
     }
 }
 
+/// Clipboard-based fallback for reading the current selection.
+///
+/// This function:
+/// 1. Saves the current clipboard contents
+/// 2. Simulates Cmd+C to copy the selection using Core Graphics
+/// 3. Reads the copied text from the clipboard
+/// 4. Restores the original clipboard (best effort)
+fn get_via_clipboard_fallback() -> Result<String> {
+    let mut clipboard = Clipboard::new().context("Failed to access clipboard")?;
+
+    // Save original clipboard contents
+    let original = clipboard.get_text().ok();
+    debug!(
+        had_original = original.is_some(),
+        "Saved original clipboard"
+    );
+
+    // Clear so we can tell whether Cmd+C actually copied anything
+    clipboard
+        .set_text("")
+        .context("Failed to clear clipboard")?;
+
+    simulate_copy_with_cg()?;
+
+    // Wait for the frontmost app to service the copy
+    thread::sleep(Duration::from_millis(100));
+
+    let copied = clipboard.get_text().unwrap_or_default();
+
+    // Restore original clipboard (best effort)
+    if let Some(original_text) = original {
+        if let Err(e) = clipboard.set_text(&original_text) {
+            warn!(error = %e, "Failed to restore original clipboard");
+        } else {
+            debug!("Restored original clipboard");
+        }
+    }
+
+    info!(
+        text_len = copied.len(),
+        "Got selected text via clipboard fallback"
+    );
+    Ok(copied)
+}
+
 // ============================================================================
 // Set Selected Text
 // ============================================================================
@@ -267,6 +321,42 @@ pub fn simulate_paste_with_cg() -> Result<()> {
     Ok(())
 }
 
+/// Simulate Cmd+C copy using Core Graphics events.
+///
+/// Used by the clipboard fallback in [`get_selected_text`] when the AX APIs
+/// can't read the selection from the frontmost app.
+pub fn simulate_copy_with_cg() -> Result<()> {
+    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    // 'c' key is keycode 8 on macOS
+    const KEY_C: CGKeyCode = 8;
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .ok()
+        .context("Failed to create CGEventSource")?;
+
+    // Create key down event for 'c' with Cmd modifier
+    let key_down = CGEvent::new_keyboard_event(source.clone(), KEY_C, true)
+        .ok()
+        .context("Failed to create key down event")?;
+    key_down.set_flags(CGEventFlags::CGEventFlagCommand);
+
+    // Create key up event for 'c' with Cmd modifier
+    let key_up = CGEvent::new_keyboard_event(source, KEY_C, false)
+        .ok()
+        .context("Failed to create key up event")?;
+    key_up.set_flags(CGEventFlags::CGEventFlagCommand);
+
+    // Post events
+    key_down.post(CGEventTapLocation::HID);
+    thread::sleep(Duration::from_millis(5));
+    key_up.post(CGEventTapLocation::HID);
+
+    debug!("Simulated Cmd+C via Core Graphics");
+    Ok(())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
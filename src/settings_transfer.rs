@@ -0,0 +1,281 @@
+//! Settings Export/Import
+//!
+//! Bundles the user-owned settings files from ~/.sk/kit (config.ts,
+//! theme.json, and optional keymap/aliases/quicklinks files) into a single
+//! zip archive for migrating between machines, and restores them on import.
+//!
+//! Archives are created and extracted with `ditto` (always available on
+//! macOS, no archive crate needed). Import never destroys data: conflicting
+//! files are detected up front so the UI can ask for confirmation, and
+//! overwritten files are backed up next to the original first.
+
+#![allow(dead_code)]
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// User-owned settings files bundled by export, relative to the kit dir
+///
+/// Only files that actually exist are included — the optional entries
+/// (keymap, aliases, quicklinks) are bundled when present so archives stay
+/// forward-compatible as those features land.
+pub const SETTINGS_FILES: &[&str] = &[
+    "config.ts",
+    "theme.json",
+    "keymap.json",
+    "aliases.json",
+    "quicklinks.md",
+];
+
+/// Name of the manifest written into every archive
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Summary of a completed import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Files copied into the kit dir
+    pub imported: Vec<String>,
+    /// Conflicting files skipped (import without overwrite)
+    pub skipped: Vec<String>,
+    /// Backup paths created for overwritten files
+    pub backed_up: Vec<String>,
+}
+
+/// Default archive file name, dated for easy identification
+pub fn default_archive_name() -> String {
+    format!(
+        "script-kit-settings-{}.zip",
+        chrono::Local::now().format("%Y-%m-%d")
+    )
+}
+
+/// Settings files currently present in the kit dir (candidates for export)
+pub fn exportable_files() -> Vec<String> {
+    let kit_dir = crate::setup::get_kit_path();
+    SETTINGS_FILES
+        .iter()
+        .filter(|name| kit_dir.join(name).exists())
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Export the settings files to a zip archive at `dest`
+///
+/// Stages the files plus a manifest in a temp directory, then zips with
+/// `ditto -ck`. Returns the list of bundled file names.
+pub fn export_settings(dest: &Path) -> Result<Vec<String>> {
+    let kit_dir = crate::setup::get_kit_path();
+    let files = exportable_files();
+    if files.is_empty() {
+        anyhow::bail!("No settings files found in {}", kit_dir.display());
+    }
+
+    let staging = std::env::temp_dir().join("script-kit-settings-export");
+    let _ = std::fs::remove_dir_all(&staging);
+    std::fs::create_dir_all(&staging).context("Failed to create staging directory")?;
+
+    for name in &files {
+        std::fs::copy(kit_dir.join(name), staging.join(name))
+            .with_context(|| format!("Failed to stage {}", name))?;
+    }
+
+    let manifest = serde_json::json!({
+        "app": "script-kit-gpui",
+        "version": env!("CARGO_PKG_VERSION"),
+        "exported_at": chrono::Utc::now().to_rfc3339(),
+        "files": files,
+    });
+    std::fs::write(
+        staging.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(&manifest)?,
+    )
+    .context("Failed to write manifest")?;
+
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create destination directory")?;
+    }
+    let _ = std::fs::remove_file(dest);
+    let status = std::process::Command::new("ditto")
+        .arg("-ck")
+        .arg(&staging)
+        .arg(dest)
+        .status()
+        .context("Failed to run ditto")?;
+    if !status.success() {
+        anyhow::bail!("ditto archive creation failed with {}", status);
+    }
+
+    let _ = std::fs::remove_dir_all(&staging);
+    Ok(files)
+}
+
+/// Extract an archive to a fresh temp directory and return its path
+fn extract_archive(archive: &Path) -> Result<PathBuf> {
+    let staging = std::env::temp_dir().join("script-kit-settings-import");
+    let _ = std::fs::remove_dir_all(&staging);
+    std::fs::create_dir_all(&staging).context("Failed to create extraction directory")?;
+
+    let status = std::process::Command::new("ditto")
+        .arg("-xk")
+        .arg(archive)
+        .arg(&staging)
+        .status()
+        .context("Failed to run ditto")?;
+    if !status.success() {
+        anyhow::bail!("ditto extraction failed with {}", status);
+    }
+    Ok(staging)
+}
+
+/// Settings files in `extracted` that exist locally with different contents
+///
+/// These are the files that would be clobbered by an import — the UI asks
+/// for confirmation before overwriting them.
+pub fn find_conflicts(extracted: &Path) -> Vec<String> {
+    let kit_dir = crate::setup::get_kit_path();
+    SETTINGS_FILES
+        .iter()
+        .filter(|name| {
+            let incoming = extracted.join(name);
+            let existing = kit_dir.join(name);
+            if !incoming.exists() || !existing.exists() {
+                return false;
+            }
+            // Identical files aren't conflicts - importing them is a no-op
+            match (std::fs::read(&incoming), std::fs::read(&existing)) {
+                (Ok(a), Ok(b)) => a != b,
+                _ => true,
+            }
+        })
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Extract an archive and report the conflicts an import would cause
+///
+/// Returns the extraction dir (pass it to [`import_extracted`]) and the
+/// conflicting file names.
+pub fn inspect_archive(archive: &Path) -> Result<(PathBuf, Vec<String>)> {
+    let extracted = extract_archive(archive)?;
+    let has_any = SETTINGS_FILES
+        .iter()
+        .any(|name| extracted.join(name).exists());
+    if !has_any {
+        anyhow::bail!("Archive does not contain any Script Kit settings files");
+    }
+    let conflicts = find_conflicts(&extracted);
+    Ok((extracted, conflicts))
+}
+
+/// Copy settings files from an extraction dir into the kit dir
+///
+/// When `overwrite` is false, conflicting files are skipped. When true,
+/// the existing file is backed up to `<name>.backup-<timestamp>` before
+/// being replaced.
+pub fn import_extracted(extracted: &Path, overwrite: bool) -> Result<ImportSummary> {
+    let kit_dir = crate::setup::get_kit_path();
+    std::fs::create_dir_all(&kit_dir).context("Failed to create kit directory")?;
+    let conflicts = find_conflicts(extracted);
+    let mut summary = ImportSummary::default();
+
+    for name in SETTINGS_FILES {
+        let incoming = extracted.join(name);
+        if !incoming.exists() {
+            continue;
+        }
+        let dest = kit_dir.join(name);
+        if conflicts.iter().any(|c| c == name) {
+            if !overwrite {
+                summary.skipped.push(name.to_string());
+                continue;
+            }
+            let backup = kit_dir.join(format!(
+                "{}.backup-{}",
+                name,
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ));
+            std::fs::copy(&dest, &backup).with_context(|| format!("Failed to back up {}", name))?;
+            summary.backed_up.push(
+                backup
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        } else if dest.exists() {
+            // Identical contents - nothing to do, but count it as imported
+            summary.imported.push(name.to_string());
+            continue;
+        }
+        std::fs::copy(&incoming, &dest).with_context(|| format!("Failed to import {}", name))?;
+        summary.imported.push(name.to_string());
+    }
+
+    Ok(summary)
+}
+
+/// Find the most recent settings archive in ~/Downloads and ~/Desktop
+///
+/// Import looks here so the common "export on the old machine, AirDrop to
+/// the new one" flow works without a file picker.
+pub fn find_latest_archive() -> Option<PathBuf> {
+    let home = dirs::home_dir()?;
+    let mut candidates: Vec<(std::time::SystemTime, PathBuf)> = Vec::new();
+    for dir in [home.join("Downloads"), home.join("Desktop")] {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if name.starts_with("script-kit-settings-") && name.ends_with(".zip") {
+                if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                    candidates.push((modified, path));
+                }
+            }
+        }
+    }
+    candidates.sort_by_key(|(modified, _)| *modified);
+    candidates.pop().map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_archive_name_is_dated_zip() {
+        let name = default_archive_name();
+        assert!(name.starts_with("script-kit-settings-"));
+        assert!(name.ends_with(".zip"));
+    }
+
+    #[test]
+    fn test_settings_files_cover_required_set() {
+        assert!(SETTINGS_FILES.contains(&"config.ts"));
+        assert!(SETTINGS_FILES.contains(&"theme.json"));
+        assert!(SETTINGS_FILES.contains(&"keymap.json"));
+        assert!(SETTINGS_FILES.contains(&"aliases.json"));
+        assert!(SETTINGS_FILES.contains(&"quicklinks.md"));
+    }
+
+    #[test]
+    fn test_find_conflicts_ignores_missing_files() {
+        // An empty extraction dir can't conflict with anything
+        let staging = std::env::temp_dir().join("sk-test-conflicts-empty");
+        let _ = std::fs::remove_dir_all(&staging);
+        std::fs::create_dir_all(&staging).unwrap();
+        assert!(find_conflicts(&staging).is_empty());
+        let _ = std::fs::remove_dir_all(&staging);
+    }
+
+    #[test]
+    fn test_import_summary_default_is_empty() {
+        let summary = ImportSummary::default();
+        assert!(summary.imported.is_empty());
+        assert!(summary.skipped.is_empty());
+        assert!(summary.backed_up.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! Quick-share actions for files via NSSharingService (macOS)
+//!
+//! Backs the share entries in the path context actions dialog: AirDrop,
+//! Mail, Messages, and a plain copy into the iCloud Drive folder. The
+//! NSSharingService targets present their own system UI (the AirDrop
+//! browser, a compose window), so callers should hide the main window
+//! after a successful call.
+//!
+//! On non-macOS platforms every service reports unavailable.
+
+use std::path::Path;
+
+use crate::logging;
+
+#[cfg(target_os = "macos")]
+use cocoa::base::{id, nil};
+#[cfg(target_os = "macos")]
+use cocoa::foundation::NSString;
+#[cfg(target_os = "macos")]
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Share targets offered in the actions dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareService {
+    AirDrop,
+    Mail,
+    Messages,
+    /// Not an NSSharingService: copies the file into iCloud Drive
+    ICloudDrive,
+}
+
+impl ShareService {
+    /// Underlying NSSharingService identifier (serviceNamed: argument)
+    ///
+    /// These are the raw values of the AppKit NSSharingServiceName*
+    /// constants, which aren't exported by the cocoa crate.
+    #[cfg(target_os = "macos")]
+    fn service_name(&self) -> Option<&'static str> {
+        match self {
+            ShareService::AirDrop => Some("com.apple.share.AirDrop.send"),
+            ShareService::Mail => Some("com.apple.share.Mail.compose"),
+            ShareService::Messages => Some("com.apple.share.Messages.compose"),
+            ShareService::ICloudDrive => None,
+        }
+    }
+
+    /// Display label for logs and errors
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShareService::AirDrop => "AirDrop",
+            ShareService::Mail => "Mail",
+            ShareService::Messages => "Messages",
+            ShareService::ICloudDrive => "iCloud Drive",
+        }
+    }
+}
+
+/// Share a file through a system service
+///
+/// Must be called on the main thread (NSSharingService presents UI).
+/// Returns an error message suitable for a toast on failure.
+pub fn share_file(path: &Path, service: ShareService) -> Result<(), String> {
+    if !path.exists() {
+        return Err(format!("File not found: {}", path.display()));
+    }
+
+    logging::log(
+        "SHARE",
+        &format!("Sharing {} via {}", path.display(), service.label()),
+    );
+
+    match service {
+        ShareService::ICloudDrive => copy_to_icloud(path),
+        _ => share_via_sharing_service(path, service),
+    }
+}
+
+/// Copy a file into the user's iCloud Drive folder
+fn copy_to_icloud(path: &Path) -> Result<(), String> {
+    let icloud_dir = dirs::home_dir()
+        .map(|h| h.join("Library/Mobile Documents/com~apple~CloudDocs"))
+        .filter(|dir| dir.is_dir())
+        .ok_or_else(|| "iCloud Drive folder not found".to_string())?;
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| "Path has no file name".to_string())?;
+    let target = icloud_dir.join(file_name);
+    if target.exists() {
+        return Err(format!(
+            "{} already exists in iCloud Drive",
+            file_name.to_string_lossy()
+        ));
+    }
+
+    std::fs::copy(path, &target)
+        .map(|_| ())
+        .map_err(|e| format!("Copy to iCloud Drive failed: {}", e))
+}
+
+#[cfg(target_os = "macos")]
+fn share_via_sharing_service(path: &Path, service: ShareService) -> Result<(), String> {
+    let Some(service_name) = service.service_name() else {
+        return Err(format!("{} is not a sharing service", service.label()));
+    };
+    let path_str = path.to_string_lossy();
+
+    unsafe {
+        let name: id = NSString::alloc(nil).init_str(service_name);
+        let sharing_service: id = msg_send![class!(NSSharingService), sharingServiceNamed: name];
+        if sharing_service == nil {
+            return Err(format!("{} is not available", service.label()));
+        }
+
+        let ns_path: id = NSString::alloc(nil).init_str(&path_str);
+        let url: id = msg_send![class!(NSURL), fileURLWithPath: ns_path];
+        let items: id = msg_send![class!(NSArray), arrayWithObject: url];
+
+        let can_perform: bool = msg_send![sharing_service, canPerformWithItems: items];
+        if !can_perform {
+            return Err(format!("{} can't share this file", service.label()));
+        }
+
+        let _: () = msg_send![sharing_service, performWithItems: items];
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn share_via_sharing_service(_path: &Path, service: ShareService) -> Result<(), String> {
+    Err(format!(
+        "{} sharing is only available on macOS",
+        service.label()
+    ))
+}
@@ -0,0 +1,234 @@
+//! Persisted shell one-liner history for the `>` filter scope
+//!
+//! Commands run from the main filter (`> ls -la ~/Downloads`) are recorded
+//! here so the `>` scope can offer a history section for reuse. Entries are
+//! stored most-recent-first, deduplicated, capped, and persisted to
+//! `~/.sk/kit/shell_history.json` alongside `frecency.json` and `pins.json`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// Maximum commands kept in history
+const MAX_HISTORY_ENTRIES: usize = 50;
+
+/// Store for shell one-liner history with persistence
+#[derive(Debug, Clone)]
+pub struct ShellHistoryStore {
+    /// Commands, most recent first
+    commands: Vec<String>,
+    /// Path to the history file
+    file_path: PathBuf,
+    /// Whether there are unsaved changes
+    dirty: bool,
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Serialize, Deserialize)]
+struct ShellHistoryData {
+    commands: Vec<String>,
+}
+
+impl ShellHistoryStore {
+    /// Create a new store with the default path (~/.sk/kit/shell_history.json)
+    pub fn new() -> Self {
+        ShellHistoryStore {
+            commands: Vec::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a store with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        ShellHistoryStore {
+            commands: Vec::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    /// Get the default history file path
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.sk/kit/shell_history.json").as_ref())
+    }
+
+    /// Load history from disk
+    ///
+    /// Starts empty if the file doesn't exist.
+    #[instrument(name = "shell_history_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            debug!(path = %self.file_path.display(), "Shell history file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path).with_context(|| {
+            format!(
+                "Failed to read shell history file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        let data: ShellHistoryData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse shell history JSON")?;
+
+        self.commands = data.commands;
+        self.commands.truncate(MAX_HISTORY_ENTRIES);
+        self.dirty = false;
+
+        info!(
+            path = %self.file_path.display(),
+            command_count = self.commands.len(),
+            "Loaded shell history"
+        );
+        Ok(())
+    }
+
+    /// Save history to disk
+    #[instrument(name = "shell_history_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        // Ensure parent directory exists
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let data = ShellHistoryData {
+            commands: self.commands.clone(),
+        };
+
+        let json =
+            serde_json::to_string_pretty(&data).context("Failed to serialize shell history")?;
+
+        std::fs::write(&self.file_path, json).with_context(|| {
+            format!(
+                "Failed to write shell history file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Record a command, moving it to the front if already present
+    ///
+    /// Blank commands are ignored; the list is capped at
+    /// `MAX_HISTORY_ENTRIES` with the oldest entries dropped.
+    pub fn record(&mut self, command: &str) {
+        let command = command.trim();
+        if command.is_empty() {
+            return;
+        }
+        if let Some(pos) = self.commands.iter().position(|c| c == command) {
+            self.commands.remove(pos);
+        }
+        self.commands.insert(0, command.to_string());
+        self.commands.truncate(MAX_HISTORY_ENTRIES);
+        self.dirty = true;
+    }
+
+    /// The recorded commands, most recent first
+    pub fn commands(&self) -> &[String] {
+        &self.commands
+    }
+
+    /// Commands containing `needle` (case-insensitive), most recent first
+    pub fn matching(&self, needle: &str) -> Vec<&String> {
+        let needle = needle.to_lowercase();
+        self.commands
+            .iter()
+            .filter(|c| c.to_lowercase().contains(&needle))
+            .collect()
+    }
+}
+
+impl Default for ShellHistoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "sk-shell-history-test-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_record_is_most_recent_first() {
+        let mut store = ShellHistoryStore::with_path(temp_path("recent"));
+        store.record("ls -la");
+        store.record("git status");
+        assert_eq!(store.commands(), &["git status", "ls -la"]);
+    }
+
+    #[test]
+    fn test_record_dedupes_and_moves_to_front() {
+        let mut store = ShellHistoryStore::with_path(temp_path("dedupe"));
+        store.record("ls -la");
+        store.record("git status");
+        store.record("ls -la");
+        assert_eq!(store.commands(), &["ls -la", "git status"]);
+    }
+
+    #[test]
+    fn test_record_ignores_blank_and_trims() {
+        let mut store = ShellHistoryStore::with_path(temp_path("blank"));
+        store.record("   ");
+        store.record("  echo hi  ");
+        assert_eq!(store.commands(), &["echo hi"]);
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut store = ShellHistoryStore::with_path(temp_path("cap"));
+        for i in 0..(MAX_HISTORY_ENTRIES + 10) {
+            store.record(&format!("echo {}", i));
+        }
+        assert_eq!(store.commands().len(), MAX_HISTORY_ENTRIES);
+        // Most recent entry survives the cap
+        assert_eq!(
+            store.commands()[0],
+            format!("echo {}", MAX_HISTORY_ENTRIES + 9)
+        );
+    }
+
+    #[test]
+    fn test_matching_is_case_insensitive() {
+        let mut store = ShellHistoryStore::with_path(temp_path("matching"));
+        store.record("Git Status");
+        store.record("ls -la");
+        let matches = store.matching("git");
+        assert_eq!(matches, vec!["Git Status"]);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        let mut store = ShellHistoryStore::with_path(path.clone());
+        store.record("ls -la");
+        store.record("git status");
+        store.save().unwrap();
+
+        let mut loaded = ShellHistoryStore::with_path(path.clone());
+        loaded.load().unwrap();
+        assert_eq!(loaded.commands(), &["git status", "ls -la"]);
+
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -1,17 +1,42 @@
-//! Syntax highlighting module using syntect
+//! Syntax highlighting module
 //!
 //! Provides syntax highlighting for code strings with colors that integrate
 //! with the existing theme system. Colors are returned as hex u32 values.
 //!
-//! NOTE: syntect's default syntax set doesn't include TypeScript, so we use
-//! JavaScript syntax for .ts files (which works well for highlighting).
+//! TypeScript, JavaScript, Rust, Bash, and Markdown are highlighted with
+//! bundled tree-sitter grammars (accurate, and the parse trees open the door
+//! to structural features like folding and symbol outlines). Every other
+//! language falls back to syntect.
+//!
+//! NOTE: syntect's default syntax set doesn't include TypeScript, so the
+//! fallback maps .ts files to JavaScript syntax.
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{Style, ThemeSet};
 use syntect::parsing::SyntaxSet;
 use syntect::util::LinesWithEndings;
+use tree_sitter_highlight::{HighlightConfiguration, HighlightEvent, Highlighter};
+
+/// Maximum characters of a single line that get syntax highlighting
+///
+/// Minified single-line bundles can run to hundreds of kilobytes; feeding
+/// those through syntect freezes the UI. Longer lines are truncated to this
+/// many characters and rendered as plain text with a truncation marker.
+pub const MAX_HIGHLIGHT_LINE_LEN: usize = 500;
+
+/// Marker appended to lines cut at `MAX_HIGHLIGHT_LINE_LEN`
+pub const TRUNCATION_MARKER: &str = "…";
+
+/// Default foreground color for plain text (light gray)
+const PLAIN_COLOR: u32 = 0xcccccc;
+
+/// Dimmed color for the truncation marker
+const MARKER_COLOR: u32 = 0x808080;
 
 /// A highlighted span of text with its associated color
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +74,205 @@ fn style_to_hex_color(style: &Style) -> u32 {
     ((fg.r as u32) << 16) | ((fg.g as u32) << 8) | (fg.b as u32)
 }
 
+/// If a line exceeds `MAX_HIGHLIGHT_LINE_LEN` characters, return plain-text
+/// spans with the overflow replaced by a truncation marker
+///
+/// Returns `None` for lines under the cap, which should be highlighted
+/// normally. The byte-length check short-circuits the common case without
+/// counting characters.
+fn truncate_long_line(clean_line: &str) -> Option<Vec<HighlightedSpan>> {
+    if clean_line.len() <= MAX_HIGHLIGHT_LINE_LEN {
+        return None;
+    }
+    let (cut, _) = clean_line.char_indices().nth(MAX_HIGHLIGHT_LINE_LEN)?;
+    Some(vec![
+        HighlightedSpan::new(&clean_line[..cut], PLAIN_COLOR),
+        HighlightedSpan::new(TRUNCATION_MARKER, MARKER_COLOR),
+    ])
+}
+
+/// Capture names recognized by the tree-sitter configurations
+///
+/// `HIGHLIGHT_COLORS` maps these one-to-one; tree-sitter matches dotted
+/// capture names (e.g. "function.method") by longest prefix.
+const HIGHLIGHT_NAMES: &[&str] = &[
+    "attribute",
+    "comment",
+    "constant",
+    "constructor",
+    "function",
+    "keyword",
+    "number",
+    "operator",
+    "property",
+    "punctuation",
+    "string",
+    "tag",
+    "type",
+    "variable",
+];
+
+/// Colors for `HIGHLIGHT_NAMES`, following the base16-eighties palette the
+/// syntect fallback renders with so both paths look alike
+const HIGHLIGHT_COLORS: &[u32] = &[
+    0xffcc66, // attribute - yellow
+    0x999999, // comment - gray
+    0xf99157, // constant - orange
+    0x6699cc, // constructor - blue
+    0x6699cc, // function - blue
+    0xcc99cc, // keyword - purple
+    0xf99157, // number - orange
+    0x66cccc, // operator - cyan
+    0xf2777a, // property - red
+    0xcccccc, // punctuation - fg
+    0x99cc99, // string - green
+    0xf2777a, // tag - red
+    0xffcc66, // type - yellow
+    0xcccccc, // variable - fg
+];
+
+/// Map a language identifier to a bundled tree-sitter grammar key
+fn tree_sitter_key(language: &str) -> Option<&'static str> {
+    match language.to_lowercase().as_str() {
+        "typescript" | "ts" | "tsx" => Some("typescript"),
+        "javascript" | "js" | "jsx" | "mjs" => Some("javascript"),
+        "rust" | "rs" => Some("rust"),
+        "shell" | "sh" | "bash" | "zsh" => Some("bash"),
+        "markdown" | "md" => Some("markdown"),
+        _ => None,
+    }
+}
+
+/// Build one tree-sitter highlight configuration, or None if its queries fail
+fn make_tree_sitter_config(
+    language: tree_sitter::Language,
+    name: &'static str,
+    highlights: &str,
+    injections: &str,
+    locals: &str,
+) -> Option<HighlightConfiguration> {
+    let mut config =
+        HighlightConfiguration::new(language, name, highlights, injections, locals).ok()?;
+    config.configure(HIGHLIGHT_NAMES);
+    Some(config)
+}
+
+/// Lazily built highlight configurations for the bundled grammars
+///
+/// A grammar whose queries fail to compile is simply absent from the map,
+/// so its languages fall back to syntect instead of breaking previews.
+fn tree_sitter_configs() -> &'static HashMap<&'static str, HighlightConfiguration> {
+    static CONFIGS: OnceLock<HashMap<&'static str, HighlightConfiguration>> = OnceLock::new();
+    CONFIGS.get_or_init(|| {
+        let mut configs = HashMap::new();
+        if let Some(config) = make_tree_sitter_config(
+            tree_sitter_javascript::LANGUAGE.into(),
+            "javascript",
+            tree_sitter_javascript::HIGHLIGHT_QUERY,
+            tree_sitter_javascript::INJECTIONS_QUERY,
+            tree_sitter_javascript::LOCALS_QUERY,
+        ) {
+            configs.insert("javascript", config);
+        }
+        // TypeScript captures extend the JavaScript ones
+        let ts_highlights = format!(
+            "{}\n{}",
+            tree_sitter_javascript::HIGHLIGHT_QUERY,
+            tree_sitter_typescript::HIGHLIGHTS_QUERY
+        );
+        if let Some(config) = make_tree_sitter_config(
+            tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            "typescript",
+            &ts_highlights,
+            "",
+            tree_sitter_javascript::LOCALS_QUERY,
+        ) {
+            configs.insert("typescript", config);
+        }
+        if let Some(config) = make_tree_sitter_config(
+            tree_sitter_rust::LANGUAGE.into(),
+            "rust",
+            tree_sitter_rust::HIGHLIGHTS_QUERY,
+            tree_sitter_rust::INJECTIONS_QUERY,
+            "",
+        ) {
+            configs.insert("rust", config);
+        }
+        if let Some(config) = make_tree_sitter_config(
+            tree_sitter_bash::LANGUAGE.into(),
+            "bash",
+            tree_sitter_bash::HIGHLIGHT_QUERY,
+            "",
+            "",
+        ) {
+            configs.insert("bash", config);
+        }
+        // Block grammar only - inline emphasis/links keep the default color
+        if let Some(config) = make_tree_sitter_config(
+            tree_sitter_md::LANGUAGE.into(),
+            "markdown",
+            tree_sitter_md::HIGHLIGHT_QUERY_BLOCK,
+            "",
+            "",
+        ) {
+            configs.insert("markdown", config);
+        }
+        configs
+    })
+}
+
+/// Highlight with a bundled tree-sitter grammar, preserving line structure
+///
+/// Returns None when no grammar covers `language` or highlighting fails,
+/// in which case the caller falls back to syntect.
+fn highlight_with_tree_sitter(code: &str, language: &str) -> Option<Vec<HighlightedLine>> {
+    let config = tree_sitter_configs().get(tree_sitter_key(language)?)?;
+    let mut highlighter = Highlighter::new();
+    let events = highlighter
+        .highlight(config, code.as_bytes(), None, |_| None)
+        .ok()?;
+
+    let mut lines = Vec::new();
+    let mut current: Vec<HighlightedSpan> = Vec::new();
+    // Innermost capture wins; the stack tracks nested highlight scopes
+    let mut color_stack: Vec<u32> = Vec::new();
+
+    for event in events {
+        match event.ok()? {
+            HighlightEvent::HighlightStart(highlight) => {
+                let color = HIGHLIGHT_COLORS
+                    .get(highlight.0)
+                    .copied()
+                    .unwrap_or(PLAIN_COLOR);
+                color_stack.push(color);
+            }
+            HighlightEvent::HighlightEnd => {
+                color_stack.pop();
+            }
+            HighlightEvent::Source { start, end } => {
+                let color = color_stack.last().copied().unwrap_or(PLAIN_COLOR);
+                let text = code.get(start..end)?;
+                for part in text.split_inclusive('\n') {
+                    let clean = part.trim_end_matches('\n');
+                    if !clean.is_empty() {
+                        current.push(HighlightedSpan::new(clean, color));
+                    }
+                    if part.ends_with('\n') {
+                        lines.push(HighlightedLine {
+                            spans: std::mem::take(&mut current),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    // Final line when the code doesn't end with a newline
+    if !current.is_empty() || (!code.is_empty() && !code.ends_with('\n')) {
+        lines.push(HighlightedLine { spans: current });
+    }
+    Some(lines)
+}
+
 /// Map language name/extension to syntect syntax name
 /// NOTE: TypeScript is NOT in syntect defaults, so we map to JavaScript
 fn map_language_to_syntax(language: &str) -> &str {
@@ -80,15 +304,21 @@ fn map_language_to_syntax(language: &str) -> &str {
 /// A vector of `HighlightedLine` structs, each containing spans for one line.
 /// This preserves line structure for proper rendering.
 pub fn highlight_code_lines(code: &str, language: &str) -> Vec<HighlightedLine> {
+    // Prefer the bundled tree-sitter grammars; syntect handles the rest.
+    // Code with extreme lines takes the syntect path below so the per-line
+    // truncation cap still applies.
+    if !code.lines().any(|l| l.len() > MAX_HIGHLIGHT_LINE_LEN) {
+        if let Some(lines) = highlight_with_tree_sitter(code, language) {
+            return lines;
+        }
+    }
+
     let ps = SyntaxSet::load_defaults_newlines();
     let ts = ThemeSet::load_defaults();
 
     // Use base16-eighties.dark theme which looks good on dark backgrounds
     let theme = &ts.themes["base16-eighties.dark"];
 
-    // Default foreground color for plain text (light gray)
-    let default_color = 0xcccccc_u32;
-
     let syntax_name = map_language_to_syntax(language);
 
     // Try to find the syntax by name, or fall back to JavaScript for unknown
@@ -102,6 +332,14 @@ pub fn highlight_code_lines(code: &str, language: &str) -> Vec<HighlightedLine>
     let mut result = Vec::new();
 
     for line in LinesWithEndings::from(code) {
+        // Cap extreme lines (minified bundles) before they reach syntect.
+        // Skipped lines never feed the highlighter's parse state, so later
+        // lines may highlight imperfectly - acceptable for previews.
+        if let Some(spans) = truncate_long_line(line.trim_end_matches('\n')) {
+            result.push(HighlightedLine { spans });
+            continue;
+        }
+
         let mut line_spans = Vec::new();
 
         match highlighter.highlight_line(line, &ps) {
@@ -121,7 +359,7 @@ pub fn highlight_code_lines(code: &str, language: &str) -> Vec<HighlightedLine>
                 // On error, push the line as plain text
                 let clean_line = line.trim_end_matches('\n');
                 if !clean_line.is_empty() {
-                    line_spans.push(HighlightedSpan::new(clean_line, default_color));
+                    line_spans.push(HighlightedSpan::new(clean_line, PLAIN_COLOR));
                 }
             }
         }
@@ -149,9 +387,6 @@ pub fn highlight_code(code: &str, language: &str) -> Vec<HighlightedSpan> {
     // Use base16-eighties.dark theme which looks good on dark backgrounds
     let theme = &ts.themes["base16-eighties.dark"];
 
-    // Default foreground color for plain text (light gray)
-    let default_color = 0xcccccc_u32;
-
     let syntax_name = map_language_to_syntax(language);
 
     // Try to find the syntax by name, or fall back to JavaScript for unknown
@@ -165,6 +400,15 @@ pub fn highlight_code(code: &str, language: &str) -> Vec<HighlightedSpan> {
     let mut result = Vec::new();
 
     for line in LinesWithEndings::from(code) {
+        // Same line-length cap as highlight_code_lines
+        if let Some(spans) = truncate_long_line(line.trim_end_matches('\n')) {
+            result.extend(spans);
+            if line.ends_with('\n') {
+                result.push(HighlightedSpan::new("\n", PLAIN_COLOR));
+            }
+            continue;
+        }
+
         match highlighter.highlight_line(line, &ps) {
             Ok(ranges) => {
                 for (style, text) in ranges {
@@ -175,14 +419,14 @@ pub fn highlight_code(code: &str, language: &str) -> Vec<HighlightedSpan> {
             }
             Err(_) => {
                 // On error, push the line as plain text
-                result.push(HighlightedSpan::new(line, default_color));
+                result.push(HighlightedSpan::new(line, PLAIN_COLOR));
             }
         }
     }
 
     // If no spans were produced, return the original code as plain text
     if result.is_empty() && !code.is_empty() {
-        result.push(HighlightedSpan::new(code, default_color));
+        result.push(HighlightedSpan::new(code, PLAIN_COLOR));
     }
 
     result
@@ -332,6 +576,109 @@ mod tests {
         assert!(!lines[1].spans.is_empty());
     }
 
+    #[test]
+    fn test_tree_sitter_highlights_rust() {
+        let code = "fn main() {\n    let x: u32 = 42;\n}";
+        let lines = highlight_code_lines(code, "rust");
+
+        assert_eq!(lines.len(), 3);
+        // Reconstruction matches the source line-for-line
+        for (line, expected) in lines.iter().zip(code.lines()) {
+            let reconstructed: String = line.spans.iter().map(|s| s.text.as_str()).collect();
+            assert_eq!(reconstructed, expected);
+        }
+        // Real highlighting produces multiple colors
+        let unique_colors: std::collections::HashSet<u32> = lines
+            .iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.color))
+            .collect();
+        assert!(unique_colors.len() > 1);
+    }
+
+    #[test]
+    fn test_tree_sitter_preserves_blank_lines() {
+        let code = "const a = 1;\n\nconst b = 2;";
+        let lines = highlight_code_lines(code, "javascript");
+
+        assert_eq!(lines.len(), 3);
+        assert!(!lines[0].spans.is_empty());
+        assert!(lines[1].spans.is_empty());
+        assert!(!lines[2].spans.is_empty());
+    }
+
+    #[test]
+    fn test_tree_sitter_trailing_newline() {
+        let lines = highlight_code_lines("let x = 1;\n", "typescript");
+        assert_eq!(lines.len(), 1);
+    }
+
+    #[test]
+    fn test_uncovered_language_uses_syntect_fallback() {
+        // JSON has no bundled tree-sitter grammar - syntect still highlights it
+        let code = "{\"key\": [1, 2, 3]}";
+        let lines = highlight_code_lines(code, "json");
+
+        assert_eq!(lines.len(), 1);
+        let reconstructed: String = lines[0].spans.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(reconstructed, code);
+    }
+
+    #[test]
+    fn test_highlight_names_and_colors_aligned() {
+        assert_eq!(HIGHLIGHT_NAMES.len(), HIGHLIGHT_COLORS.len());
+    }
+
+    #[test]
+    fn test_long_line_truncated_as_plain_text() {
+        let long = format!("const x = \"{}\";", "a".repeat(10_000));
+        let lines = highlight_code_lines(&long, "javascript");
+
+        assert_eq!(lines.len(), 1);
+        let spans = &lines[0].spans;
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].text.chars().count(), MAX_HIGHLIGHT_LINE_LEN);
+        assert_eq!(spans[1].text, TRUNCATION_MARKER);
+    }
+
+    #[test]
+    fn test_long_line_preserves_line_structure() {
+        let code = format!("const a = 1;\n{}\nconst b = 2;", "x".repeat(10_000));
+        let lines = highlight_code_lines(&code, "javascript");
+
+        assert_eq!(lines.len(), 3);
+        // Short lines still get real highlighting
+        assert!(!lines[0].spans.is_empty());
+        assert!(!lines[2].spans.is_empty());
+        // The long line is truncated
+        assert_eq!(
+            lines[1].spans.last().map(|s| s.text.as_str()),
+            Some(TRUNCATION_MARKER)
+        );
+    }
+
+    #[test]
+    fn test_long_line_truncation_is_multibyte_safe() {
+        // Multi-byte characters must be cut at a char boundary, not mid-codepoint
+        let long = "é".repeat(MAX_HIGHLIGHT_LINE_LEN * 2);
+        let lines = highlight_code_lines(&long, "javascript");
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            lines[0].spans[0].text.chars().count(),
+            MAX_HIGHLIGHT_LINE_LEN
+        );
+    }
+
+    #[test]
+    fn test_line_at_cap_is_not_truncated() {
+        let code = "z".repeat(MAX_HIGHLIGHT_LINE_LEN);
+        let lines = highlight_code_lines(&code, "javascript");
+
+        assert_eq!(lines.len(), 1);
+        let reconstructed: String = lines[0].spans.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(reconstructed, code);
+    }
+
     #[test]
     fn test_highlight_produces_colors() {
         // Use JavaScript which IS in syntect defaults
@@ -18,7 +18,10 @@ use tracing::{debug, error, info};
 // ============================================================================
 
 /// Execute an AppleScript command and return the result
-fn run_applescript(script: &str) -> Result<(), String> {
+///
+/// pub(crate) so sibling modules (e.g. focus_mode) can reuse it for their
+/// own UI scripting.
+pub(crate) fn run_applescript(script: &str) -> Result<(), String> {
     debug!(script = %script, "Executing AppleScript");
 
     let output = Command::new("osascript")
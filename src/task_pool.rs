@@ -0,0 +1,123 @@
+//! Shared background thread pool for blocking work
+//!
+//! Render and event handlers must not block on file I/O (preview reads,
+//! directory listings, plist parsing). Jobs queued here run on a small fixed
+//! pool of worker threads instead of a new thread per call. Results come
+//! back through a plain mpsc receiver that callers poll from a GPUI timer
+//! task - the same completion pattern the app already uses for background
+//! app scanning - so completions land on the main thread without extra
+//! synchronization.
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Worker threads in the shared pool
+///
+/// Enough to overlap a few slow reads without spawning a thread per
+/// keystroke; jobs here are short (a single file or directory).
+const POOL_SIZE: usize = 3;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+static POOL: OnceLock<Sender<Job>> = OnceLock::new();
+
+fn pool() -> &'static Sender<Job> {
+    POOL.get_or_init(|| {
+        let (tx, rx) = channel::<Job>();
+        let rx = Arc::new(Mutex::new(rx));
+        for i in 0..POOL_SIZE {
+            let rx = rx.clone();
+            std::thread::Builder::new()
+                .name(format!("task-pool-{}", i))
+                .spawn(move || {
+                    loop {
+                        // The lock is held only while dequeuing; the job
+                        // itself runs with the queue released
+                        let job = match rx.lock() {
+                            Ok(rx) => rx.recv(),
+                            Err(_) => return, // Another worker panicked mid-dequeue
+                        };
+                        match job {
+                            Ok(job) => {
+                                // A panicking job must not take a worker with it
+                                let result = std::panic::catch_unwind(
+                                    std::panic::AssertUnwindSafe(job),
+                                );
+                                if result.is_err() {
+                                    crate::logging::log("POOL", "Background job panicked");
+                                }
+                            }
+                            Err(_) => return, // Sender dropped (shutdown)
+                        }
+                    }
+                })
+                .ok();
+        }
+        tx
+    })
+}
+
+/// Queue a fire-and-forget job on the pool
+pub fn spawn<F>(job: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    if pool().send(Box::new(job)).is_err() {
+        crate::logging::log("POOL", "Task pool unavailable; job dropped");
+    }
+}
+
+/// Queue a job and get a receiver for its result
+///
+/// Poll the receiver with `try_recv` from a GPUI timer task. The sender is
+/// dropped when the job finishes, so `Disconnected` means the job panicked
+/// or the result was already taken - treat both as "stop polling".
+pub fn run<T, F>(job: F) -> Receiver<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = channel();
+    spawn(move || {
+        let _ = tx.send(job());
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_returns_result() {
+        let rx = run(|| 2 + 2);
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_spawn_executes_all_jobs() {
+        let (tx, rx) = channel();
+        for i in 0..10 {
+            let tx = tx.clone();
+            spawn(move || {
+                let _ = tx.send(i);
+            });
+        }
+        drop(tx);
+        let mut seen: Vec<i32> = Vec::new();
+        while let Ok(i) = rx.recv_timeout(Duration::from_secs(5)) {
+            seen.push(i);
+        }
+        seen.sort_unstable();
+        assert_eq!(seen, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_pool_survives_panicking_job() {
+        spawn(|| panic!("job panic must not kill the pool"));
+        // A later job still runs even though an earlier one panicked
+        let rx = run(|| "alive");
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), "alive");
+    }
+}
@@ -423,7 +423,7 @@ impl TermPrompt {
             .min_h(px(0.)) // Critical for flex children sizing
             .overflow_hidden()
             .bg(default_bg)
-            .font_family("Menlo")
+            .font_family(self.theme.get_fonts().mono_family)
             .text_size(px(font_size))
             .line_height(px(cell_height)); // Use calculated line height for proper descender room
 
@@ -606,12 +606,18 @@ pub struct FontConfig {
     /// Monospace font family for editor/terminal (default: "Menlo" on macOS)
     #[serde(default = "default_mono_font_family")]
     pub mono_family: String,
+    /// Fallback families tried in order when `mono_family` is not installed
+    #[serde(default)]
+    pub mono_fallbacks: Vec<String>,
     /// Monospace font size in pixels (default: 14.0)
     #[serde(default = "default_mono_font_size")]
     pub mono_size: f32,
     /// UI font family (default: system font)
     #[serde(default = "default_ui_font_family")]
     pub ui_family: String,
+    /// Fallback families tried in order when `ui_family` is not installed
+    #[serde(default)]
+    pub ui_fallbacks: Vec<String>,
     /// UI font size in pixels (default: 14.0)
     #[serde(default = "default_ui_font_size")]
     pub ui_size: f32,
@@ -641,13 +647,116 @@ impl Default for FontConfig {
     fn default() -> Self {
         FontConfig {
             mono_family: default_mono_font_family(),
+            mono_fallbacks: Vec::new(),
             mono_size: default_mono_font_size(),
             ui_family: default_ui_font_family(),
+            ui_fallbacks: Vec::new(),
             ui_size: default_ui_font_size(),
         }
     }
 }
 
+impl FontConfig {
+    /// Resolve the configured families against the installed font list,
+    /// replacing any that aren't installed with the first available fallback
+    /// (or the bundled default when the whole chain is missing).
+    ///
+    /// When `installed` is empty (enumeration failed or unsupported platform)
+    /// the configured names are trusted as-is.
+    pub fn validated(&self, installed: &[String]) -> FontConfig {
+        let mut resolved = self.clone();
+        resolved.ui_family = resolve_family(
+            &self.ui_family,
+            &self.ui_fallbacks,
+            default_ui_font_family(),
+            installed,
+            "ui",
+        );
+        resolved.mono_family = resolve_family(
+            &self.mono_family,
+            &self.mono_fallbacks,
+            default_mono_font_family(),
+            installed,
+            "mono",
+        );
+        resolved
+    }
+}
+
+/// Walk a font fallback chain and return the first installed family.
+///
+/// Dot-prefixed names (".SystemUIFont", ".AppleSystemUIFont") are hidden
+/// system fonts that never appear in the installed list but always resolve,
+/// so they're accepted without checking.
+fn resolve_family(
+    primary: &str,
+    fallbacks: &[String],
+    default: String,
+    installed: &[String],
+    role: &str,
+) -> String {
+    if installed.is_empty() {
+        return primary.to_string();
+    }
+    let is_installed = |family: &str| {
+        family.starts_with('.')
+            || installed
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(family))
+    };
+    for candidate in std::iter::once(primary).chain(fallbacks.iter().map(String::as_str)) {
+        if is_installed(candidate) {
+            if candidate != primary {
+                warn!(
+                    requested = primary,
+                    using = candidate,
+                    role,
+                    "Configured font not installed, using fallback"
+                );
+            }
+            return candidate.to_string();
+        }
+    }
+    warn!(
+        requested = primary,
+        using = %default,
+        role,
+        "No font in the fallback chain is installed, using default"
+    );
+    default
+}
+
+/// Font families installed on this system, for validating theme fonts.
+/// Returns an empty list when enumeration fails (validation is skipped).
+#[cfg(target_os = "macos")]
+pub fn installed_font_families() -> Vec<String> {
+    use cocoa::base::{id, nil};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let manager: id = msg_send![class!(NSFontManager), sharedFontManager];
+        let families: id = msg_send![manager, availableFontFamilies];
+        if families == nil {
+            return Vec::new();
+        }
+        let count: usize = msg_send![families, count];
+        let mut result = Vec::with_capacity(count);
+        for i in 0..count {
+            let name: id = msg_send![families, objectAtIndex: i];
+            let utf8: *const std::os::raw::c_char = msg_send![name, UTF8String];
+            if !utf8.is_null() {
+                result.push(std::ffi::CStr::from_ptr(utf8).to_string_lossy().to_string());
+            }
+        }
+        result
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn installed_font_families() -> Vec<String> {
+    Vec::new()
+}
+
 /// Complete theme definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Theme {
@@ -931,6 +1040,33 @@ impl Theme {
     pub fn get_fonts(&self) -> FontConfig {
         self.fonts.clone().unwrap_or_default()
     }
+
+    /// Return a copy whose font families have been checked against the
+    /// installed fonts, walking each fallback chain (see [`FontConfig::validated`])
+    pub fn with_validated_fonts(mut self) -> Theme {
+        if let Some(fonts) = self.fonts.take() {
+            self.fonts = Some(fonts.validated(&installed_font_families()));
+        }
+        self
+    }
+
+    /// Classify the theme as "dark" or "light" from the main background color
+    ///
+    /// Exposed to scripts via the SK_THEME env var and `getAppState`, so they
+    /// can match the app's appearance without parsing theme.json.
+    pub fn appearance(&self) -> &'static str {
+        let bg = self.colors.background.main;
+        let r = ((bg >> 16) & 0xff) as f32;
+        let g = ((bg >> 8) & 0xff) as f32;
+        let b = (bg & 0xff) as f32;
+        // Rec. 601 luma; backgrounds brighter than 50% read as a light theme
+        let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+        if luma > 127.5 {
+            "light"
+        } else {
+            "dark"
+        }
+    }
 }
 
 /// Detect system appearance preference on macOS
@@ -1050,6 +1186,8 @@ pub fn load_theme() -> Theme {
         Ok(contents) => match serde_json::from_str::<Theme>(&contents) {
             Ok(theme) => {
                 debug!(path = %theme_path.display(), "Successfully loaded theme");
+                // Swap out any configured font that isn't actually installed
+                let theme = theme.with_validated_fonts();
                 log_theme_config(&theme);
                 theme
             }
@@ -1081,6 +1219,269 @@ pub fn load_theme() -> Theme {
     }
 }
 
+/// Write a theme to `~/.sk/kit/theme.json`.
+///
+/// This is the same file the theme watcher monitors, so saving also triggers
+/// a reload in any other open windows.
+pub fn save_theme(theme: &Theme) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    let theme_path = PathBuf::from(shellexpand::tilde("~/.sk/kit/theme.json").as_ref());
+    if let Some(parent) = theme_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {:?}", parent))?;
+    }
+    let json = serde_json::to_string_pretty(theme).context("Failed to serialize theme")?;
+    std::fs::write(&theme_path, json)
+        .with_context(|| format!("Failed to write {:?}", theme_path))?;
+    info!(path = %theme_path.display(), "Theme saved");
+    Ok(())
+}
+
+// ============================================================================
+// Bundled Themes
+// ============================================================================
+
+/// A theme shipped with the app, browsable via the Theme Browser builtin
+#[derive(Debug, Clone)]
+pub struct BundledTheme {
+    pub name: String,
+    pub description: String,
+    pub theme: Theme,
+}
+
+/// Wrap a color scheme in a full theme with default opacity/shadow/fonts
+fn bundled(name: &str, description: &str, colors: ColorScheme) -> BundledTheme {
+    BundledTheme {
+        name: name.to_string(),
+        description: description.to_string(),
+        theme: Theme {
+            colors,
+            focus_aware: None,
+            opacity: Some(BackgroundOpacity::default()),
+            drop_shadow: Some(DropShadow::default()),
+            vibrancy: Some(VibrancySettings::default()),
+            fonts: Some(FontConfig::default()),
+        },
+    }
+}
+
+/// The themes bundled with the app, in display order
+pub fn bundled_themes() -> Vec<BundledTheme> {
+    vec![
+        bundled(
+            "Script Kit Dark",
+            "The default dark theme",
+            ColorScheme::dark_default(),
+        ),
+        bundled(
+            "Script Kit Light",
+            "The default light theme",
+            ColorScheme::light_default(),
+        ),
+        bundled("Dracula", "Dark theme with vivid purples", dracula()),
+        bundled(
+            "Solarized Dark",
+            "Low-contrast dark with warm accents",
+            solarized_dark(),
+        ),
+        bundled(
+            "Catppuccin Mocha",
+            "Soothing pastels on a dark base",
+            catppuccin_mocha(),
+        ),
+        bundled("Nord", "Arctic blues and muted tones", nord()),
+    ]
+}
+
+fn dracula() -> ColorScheme {
+    ColorScheme {
+        background: BackgroundColors {
+            main: 0x282a36,
+            title_bar: 0x21222c,
+            search_box: 0x343746,
+            log_panel: 0x1e1f29,
+        },
+        text: TextColors {
+            primary: 0xf8f8f2,
+            secondary: 0xd8d8d2,
+            tertiary: 0x9ea8c7,
+            muted: 0x6272a4,
+            dimmed: 0x50546e,
+        },
+        accent: AccentColors {
+            selected: 0xbd93f9,
+            selected_subtle: 0x343746,
+        },
+        ui: UIColors {
+            border: 0x44475a,
+            success: 0x50fa7b,
+            error: 0xff5555,
+            warning: 0xffb86c,
+            info: 0x8be9fd,
+        },
+        terminal: TerminalColors {
+            black: 0x21222c,
+            red: 0xff5555,
+            green: 0x50fa7b,
+            yellow: 0xf1fa8c,
+            blue: 0xbd93f9,
+            magenta: 0xff79c6,
+            cyan: 0x8be9fd,
+            white: 0xf8f8f2,
+            bright_black: 0x6272a4,
+            bright_red: 0xff6e6e,
+            bright_green: 0x69ff94,
+            bright_yellow: 0xffffa5,
+            bright_blue: 0xd6acff,
+            bright_magenta: 0xff92df,
+            bright_cyan: 0xa4ffff,
+            bright_white: 0xffffff,
+        },
+    }
+}
+
+fn solarized_dark() -> ColorScheme {
+    ColorScheme {
+        background: BackgroundColors {
+            main: 0x002b36,
+            title_bar: 0x073642,
+            search_box: 0x073642,
+            log_panel: 0x00212b,
+        },
+        text: TextColors {
+            primary: 0x93a1a1,
+            secondary: 0x839496,
+            tertiary: 0x657b83,
+            muted: 0x586e75,
+            dimmed: 0x475b62,
+        },
+        accent: AccentColors {
+            selected: 0x268bd2,
+            selected_subtle: 0x073642,
+        },
+        ui: UIColors {
+            border: 0x586e75,
+            success: 0x859900,
+            error: 0xdc322f,
+            warning: 0xb58900,
+            info: 0x268bd2,
+        },
+        terminal: TerminalColors {
+            black: 0x073642,
+            red: 0xdc322f,
+            green: 0x859900,
+            yellow: 0xb58900,
+            blue: 0x268bd2,
+            magenta: 0xd33682,
+            cyan: 0x2aa198,
+            white: 0xeee8d5,
+            bright_black: 0x002b36,
+            bright_red: 0xcb4b16,
+            bright_green: 0x586e75,
+            bright_yellow: 0x657b83,
+            bright_blue: 0x839496,
+            bright_magenta: 0x6c71c4,
+            bright_cyan: 0x93a1a1,
+            bright_white: 0xfdf6e3,
+        },
+    }
+}
+
+fn catppuccin_mocha() -> ColorScheme {
+    ColorScheme {
+        background: BackgroundColors {
+            main: 0x1e1e2e,
+            title_bar: 0x181825,
+            search_box: 0x313244,
+            log_panel: 0x11111b,
+        },
+        text: TextColors {
+            primary: 0xcdd6f4,
+            secondary: 0xbac2de,
+            tertiary: 0xa6adc8,
+            muted: 0x7f849c,
+            dimmed: 0x6c7086,
+        },
+        accent: AccentColors {
+            selected: 0xcba6f7,
+            selected_subtle: 0x313244,
+        },
+        ui: UIColors {
+            border: 0x45475a,
+            success: 0xa6e3a1,
+            error: 0xf38ba8,
+            warning: 0xf9e2af,
+            info: 0x89b4fa,
+        },
+        terminal: TerminalColors {
+            black: 0x45475a,
+            red: 0xf38ba8,
+            green: 0xa6e3a1,
+            yellow: 0xf9e2af,
+            blue: 0x89b4fa,
+            magenta: 0xf5c2e7,
+            cyan: 0x94e2d5,
+            white: 0xbac2de,
+            bright_black: 0x585b70,
+            bright_red: 0xf38ba8,
+            bright_green: 0xa6e3a1,
+            bright_yellow: 0xf9e2af,
+            bright_blue: 0x89b4fa,
+            bright_magenta: 0xf5c2e7,
+            bright_cyan: 0x94e2d5,
+            bright_white: 0xa6adc8,
+        },
+    }
+}
+
+fn nord() -> ColorScheme {
+    ColorScheme {
+        background: BackgroundColors {
+            main: 0x2e3440,
+            title_bar: 0x3b4252,
+            search_box: 0x434c5e,
+            log_panel: 0x272c36,
+        },
+        text: TextColors {
+            primary: 0xeceff4,
+            secondary: 0xd8dee9,
+            tertiary: 0xaeb8c9,
+            muted: 0x7b88a1,
+            dimmed: 0x616e88,
+        },
+        accent: AccentColors {
+            selected: 0x88c0d0,
+            selected_subtle: 0x3b4252,
+        },
+        ui: UIColors {
+            border: 0x4c566a,
+            success: 0xa3be8c,
+            error: 0xbf616a,
+            warning: 0xebcb8b,
+            info: 0x81a1c1,
+        },
+        terminal: TerminalColors {
+            black: 0x3b4252,
+            red: 0xbf616a,
+            green: 0xa3be8c,
+            yellow: 0xebcb8b,
+            blue: 0x81a1c1,
+            magenta: 0xb48ead,
+            cyan: 0x88c0d0,
+            white: 0xe5e9f0,
+            bright_black: 0x4c566a,
+            bright_red: 0xbf616a,
+            bright_green: 0xa3be8c,
+            bright_yellow: 0xebcb8b,
+            bright_blue: 0x81a1c1,
+            bright_magenta: 0xb48ead,
+            bright_cyan: 0x8fbcbb,
+            bright_white: 0xeceff4,
+        },
+    }
+}
+
 // ============================================================================
 // Lightweight Theme Extraction Helpers
 // ============================================================================
@@ -1830,4 +2231,89 @@ mod tests {
         assert_eq!(theme.colors.background.search_box, 0x3C3C3C);
         assert_eq!(theme.colors.accent.selected, 0xFBBF24);
     }
+
+    #[test]
+    fn test_bundled_themes_have_unique_names_and_round_trip() {
+        let themes = bundled_themes();
+        assert!(themes.iter().any(|t| t.name == "Dracula"));
+        assert!(themes.iter().any(|t| t.name == "Nord"));
+
+        let mut names: Vec<_> = themes.iter().map(|t| t.name.clone()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(
+            names.len(),
+            themes.len(),
+            "bundled theme names must be unique"
+        );
+
+        // Every bundled theme must survive the save/load JSON round trip
+        for bundled in &themes {
+            let json = serde_json::to_string(&bundled.theme).unwrap();
+            let parsed: Theme = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                parsed.colors.background.main, bundled.theme.colors.background.main,
+                "{} did not round-trip",
+                bundled.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_font_config_validated_uses_fallback() {
+        let fonts = FontConfig {
+            ui_family: "Does Not Exist".to_string(),
+            ui_fallbacks: vec!["Also Missing".to_string(), "Helvetica".to_string()],
+            mono_family: "Fira Code".to_string(),
+            mono_fallbacks: vec![],
+            ..FontConfig::default()
+        };
+        let installed = vec!["Helvetica".to_string(), "Fira Code".to_string()];
+
+        let resolved = fonts.validated(&installed);
+        // First installed family in the chain wins
+        assert_eq!(resolved.ui_family, "Helvetica");
+        // An installed primary is kept as-is
+        assert_eq!(resolved.mono_family, "Fira Code");
+    }
+
+    #[test]
+    fn test_font_config_validated_falls_back_to_default() {
+        let fonts = FontConfig {
+            mono_family: "Does Not Exist".to_string(),
+            mono_fallbacks: vec!["Also Missing".to_string()],
+            ..FontConfig::default()
+        };
+        let installed = vec!["Helvetica".to_string()];
+
+        let resolved = fonts.validated(&installed);
+        assert_eq!(resolved.mono_family, default_mono_font_family());
+    }
+
+    #[test]
+    fn test_font_config_validated_trusts_hidden_and_unknown() {
+        let fonts = FontConfig::default();
+
+        // Hidden system fonts (dot-prefixed) never appear in the installed
+        // list but always resolve
+        let resolved = fonts.validated(&["Helvetica".to_string()]);
+        assert_eq!(resolved.ui_family, default_ui_font_family());
+
+        // An empty installed list means enumeration failed - trust the config
+        let fonts = FontConfig {
+            ui_family: "Anything".to_string(),
+            ..FontConfig::default()
+        };
+        assert_eq!(fonts.validated(&[]).ui_family, "Anything");
+    }
+
+    #[test]
+    fn test_font_config_matches_case_insensitively() {
+        let fonts = FontConfig {
+            mono_family: "jetbrains mono".to_string(),
+            ..FontConfig::default()
+        };
+        let installed = vec!["JetBrains Mono".to_string()];
+        assert_eq!(fonts.validated(&installed).mono_family, "jetbrains mono");
+    }
 }
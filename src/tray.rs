@@ -265,6 +265,21 @@ impl TrayManager {
         MenuEvent::receiver()
     }
 
+    /// Updates the tray tooltip to reflect how many scripts are running
+    ///
+    /// Shows a plain "Script Kit" tooltip when idle so the running count
+    /// only appears while something is actually in flight.
+    pub fn set_running_count(&self, count: usize) {
+        let tooltip = match count {
+            0 => "Script Kit".to_string(),
+            1 => "Script Kit — 1 script running".to_string(),
+            n => format!("Script Kit — {} scripts running", n),
+        };
+        if let Err(e) = self.tray_icon.set_tooltip(Some(tooltip)) {
+            crate::logging::log("TRAY", &format!("Failed to update tray tooltip: {}", e));
+        }
+    }
+
     /// Matches a menu event to a TrayMenuAction
     ///
     /// Returns `Some(action)` if the event matches a known menu item,
@@ -0,0 +1,320 @@
+//! Undo support for destructive actions
+//!
+//! Destructive actions (moving a file to trash, unpinning an item, clearing
+//! clipboard history) record a [`PendingUndo`] that can be reverted within a
+//! short window (Cmd+Z). Deleted files are moved into a small app-managed
+//! trash directory (`~/.sk/kit/.trash`) instead of being removed outright, so
+//! the undo simply moves them back.
+
+#![allow(dead_code)]
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::setup::get_kit_path;
+
+/// How long a destructive action stays undoable
+pub const UNDO_WINDOW_MS: u64 = 5_000;
+
+/// The app-managed trash directory (`~/.sk/kit/.trash`), created on demand
+pub fn trash_dir() -> PathBuf {
+    get_kit_path().join(".trash")
+}
+
+/// A destructive action that can be reverted
+#[derive(Debug, Clone)]
+pub enum UndoAction {
+    /// A file was moved into the app trash; undo moves it back
+    FileTrashed {
+        /// Where the file originally lived
+        original: PathBuf,
+        /// Where it sits in the trash directory
+        trashed: PathBuf,
+    },
+    /// An item was unpinned; undo re-pins it by key
+    PinRemoved { key: String },
+    /// Clipboard history was cleared into the backup table; undo restores it
+    ClipboardCleared,
+}
+
+/// A recorded destructive action with its undo deadline
+#[derive(Debug, Clone)]
+pub struct PendingUndo {
+    /// Short human-readable description ("Moved to Trash: notes.txt")
+    pub description: String,
+    /// What to do on undo
+    pub action: UndoAction,
+    /// When the undo window closes
+    pub expires_at: Instant,
+}
+
+impl PendingUndo {
+    /// Record an action with the default 5 second undo window
+    pub fn new(description: impl Into<String>, action: UndoAction) -> Self {
+        PendingUndo {
+            description: description.into(),
+            action,
+            expires_at: Instant::now() + Duration::from_millis(UNDO_WINDOW_MS),
+        }
+    }
+
+    /// Whether the undo window has closed
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+/// Move `path` into the trash directory and return its new location.
+///
+/// The trashed name is prefixed with a timestamp so repeated deletes of the
+/// same filename don't collide. Falls back to copy+remove when a plain rename
+/// fails (e.g. the trash directory is on a different filesystem).
+pub fn move_to_trash(path: &Path) -> Result<PathBuf> {
+    let trash = trash_dir();
+    std::fs::create_dir_all(&trash)
+        .with_context(|| format!("Failed to create trash directory {:?}", trash))?;
+
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("Path has no file name: {:?}", path))?
+        .to_string_lossy()
+        .to_string();
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let trashed = trash.join(format!("{}-{}", timestamp, file_name));
+
+    if std::fs::rename(path, &trashed).is_err() {
+        // Cross-device fallback: copy then remove the original
+        if path.is_dir() {
+            anyhow::bail!("Cannot trash directory across filesystems: {:?}", path);
+        }
+        std::fs::copy(path, &trashed)
+            .with_context(|| format!("Failed to copy {:?} to trash", path))?;
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove original {:?}", path))?;
+    }
+
+    // Record where the file came from so "Recently Deleted" can restore it.
+    // Best-effort: a missing sidecar only disables restore for this entry.
+    let _ = std::fs::write(sidecar_path(&trashed), path.to_string_lossy().as_bytes());
+
+    Ok(trashed)
+}
+
+/// Path of the sidecar file recording a trashed file's original location
+fn sidecar_path(trashed: &Path) -> PathBuf {
+    let mut os = trashed.as_os_str().to_owned();
+    os.push(".trashinfo");
+    PathBuf::from(os)
+}
+
+/// Move a trashed file back to its original location
+pub fn restore_from_trash(trashed: &Path, original: &Path) -> Result<()> {
+    if let Some(parent) = original.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to recreate directory {:?}", parent))?;
+    }
+    std::fs::rename(trashed, original)
+        .with_context(|| format!("Failed to restore {:?} to {:?}", trashed, original))?;
+    let _ = std::fs::remove_file(sidecar_path(trashed));
+    Ok(())
+}
+
+/// A file sitting in the app trash directory
+#[derive(Debug, Clone)]
+pub struct TrashEntry {
+    /// Current location inside the trash directory
+    pub trashed: PathBuf,
+    /// Original file name (timestamp prefix stripped)
+    pub name: String,
+    /// Where the file came from, when recorded at delete time
+    pub original: Option<PathBuf>,
+    /// Deletion time in milliseconds since the Unix epoch (0 when unknown)
+    pub deleted_at_ms: u128,
+}
+
+/// List trash contents, newest first
+pub fn list_trash() -> Vec<TrashEntry> {
+    let trash = trash_dir();
+    let Ok(dir) = std::fs::read_dir(&trash) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<TrashEntry> = dir
+        .flatten()
+        .filter_map(|entry| {
+            let trashed = entry.path();
+            let file_name = trashed.file_name()?.to_string_lossy().to_string();
+            // Skip the sidecar metadata files
+            if file_name.ends_with(".trashinfo") {
+                return None;
+            }
+            // Trashed names are "{timestamp_ms}-{original_name}"
+            let (deleted_at_ms, name) = match file_name.split_once('-') {
+                Some((ts, rest)) if ts.chars().all(|c| c.is_ascii_digit()) => {
+                    (ts.parse().unwrap_or(0), rest.to_string())
+                }
+                _ => (0, file_name.clone()),
+            };
+            let original = std::fs::read_to_string(sidecar_path(&trashed))
+                .ok()
+                .map(|s| PathBuf::from(s.trim()));
+            Some(TrashEntry {
+                trashed,
+                name,
+                original,
+                deleted_at_ms,
+            })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| b.deleted_at_ms.cmp(&a.deleted_at_ms));
+    entries
+}
+
+/// Restore a trash entry to its recorded original location.
+///
+/// Fails when the entry has no recorded origin or a file already exists at
+/// the original path (so a restore never silently overwrites newer work).
+pub fn restore_trash_entry(entry: &TrashEntry) -> Result<PathBuf> {
+    let original = entry
+        .original
+        .clone()
+        .with_context(|| format!("No recorded origin for {:?}", entry.trashed))?;
+    if original.exists() {
+        anyhow::bail!("A file already exists at {:?}", original);
+    }
+    restore_from_trash(&entry.trashed, &original)?;
+    Ok(original)
+}
+
+/// Human-readable age for a trash entry ("just now", "5m ago", "3d ago")
+pub fn age_label(deleted_at_ms: u128) -> String {
+    if deleted_at_ms == 0 {
+        return "unknown age".to_string();
+    }
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let elapsed_secs = now_ms.saturating_sub(deleted_at_ms) / 1000;
+    match elapsed_secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", elapsed_secs / 60),
+        3600..=86_399 => format!("{}h ago", elapsed_secs / 3600),
+        _ => format!("{}d ago", elapsed_secs / 86_400),
+    }
+}
+
+/// Delete trash entries older than `max_age` (best-effort housekeeping so the
+/// trash directory doesn't grow without bound)
+pub fn prune_trash(max_age: Duration) -> Result<usize> {
+    let trash = trash_dir();
+    if !trash.exists() {
+        return Ok(0);
+    }
+
+    let mut removed = 0;
+    for entry in std::fs::read_dir(&trash).context("Failed to read trash directory")? {
+        let entry = entry?;
+        let modified = entry.metadata().and_then(|m| m.modified());
+        let old_enough = modified
+            .ok()
+            .and_then(|m| m.elapsed().ok())
+            .map(|age| age > max_age)
+            .unwrap_or(false);
+        if old_enough {
+            let path = entry.path();
+            let result = if path.is_dir() {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            if result.is_ok() {
+                removed += 1;
+            }
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sk-undo-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn test_pending_undo_window() {
+        let undo = PendingUndo::new(
+            "Unpinned",
+            UndoAction::PinRemoved {
+                key: "script:test".to_string(),
+            },
+        );
+        assert!(!undo.is_expired());
+        assert_eq!(undo.description, "Unpinned");
+    }
+
+    #[test]
+    fn test_trash_round_trip() {
+        let original = temp_file("trash-round-trip.txt");
+        std::fs::write(&original, "hello").unwrap();
+
+        let trashed = move_to_trash(&original).unwrap();
+        assert!(!original.exists());
+        assert!(trashed.exists());
+
+        restore_from_trash(&trashed, &original).unwrap();
+        assert!(original.exists());
+        assert_eq!(std::fs::read_to_string(&original).unwrap(), "hello");
+
+        std::fs::remove_file(&original).ok();
+    }
+
+    #[test]
+    fn test_list_and_restore_trash_entry() {
+        let original = temp_file("recently-deleted.txt");
+        std::fs::write(&original, "restore me").unwrap();
+
+        let trashed = move_to_trash(&original).unwrap();
+        let entry = list_trash()
+            .into_iter()
+            .find(|e| e.trashed == trashed)
+            .expect("trashed file should be listed");
+        assert_eq!(entry.name, "recently-deleted.txt");
+        assert_eq!(entry.original.as_deref(), Some(original.as_path()));
+        assert!(entry.deleted_at_ms > 0);
+
+        let restored = restore_trash_entry(&entry).unwrap();
+        assert_eq!(restored, original);
+        assert_eq!(std::fs::read_to_string(&original).unwrap(), "restore me");
+
+        std::fs::remove_file(&original).ok();
+    }
+
+    #[test]
+    fn test_age_label() {
+        assert_eq!(age_label(0), "unknown age");
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        assert_eq!(age_label(now_ms), "just now");
+        assert_eq!(age_label(now_ms - 5 * 60 * 1000), "5m ago");
+        assert_eq!(age_label(now_ms - 2 * 86_400 * 1000), "2d ago");
+    }
+
+    #[test]
+    fn test_move_to_trash_missing_file() {
+        let missing = temp_file("does-not-exist.txt");
+        assert!(move_to_trash(&missing).is_err());
+    }
+}
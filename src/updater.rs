@@ -0,0 +1,377 @@
+//! Self-Update Subsystem
+//!
+//! Checks the GitHub releases API for a newer version of Script Kit,
+//! config-gated via `autoUpdate` in config.ts. When an update is found the
+//! main window surfaces a toast; the "Install Update" built-in downloads the
+//! release asset, swaps the app bundle on disk, and relaunches. The
+//! "View Changelog" built-in renders the release notes in a div prompt.
+//!
+//! All network calls are blocking (ureq) and must run off the UI thread —
+//! callers spawn a thread and poll a channel, matching the app-scan pattern.
+
+#![allow(dead_code)]
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+/// GitHub releases API endpoint for the app repository
+const RELEASES_URL: &str =
+    "https://api.github.com/repos/johnlindquist/script-kit-gpui/releases/latest";
+
+/// Version compiled into this binary (from Cargo.toml)
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A release fetched from the GitHub API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseInfo {
+    /// Version string without a leading 'v' (e.g. "0.2.0")
+    pub version: String,
+    /// Release notes body (markdown)
+    pub changelog: String,
+    /// Download URL for the app bundle asset (.zip), if the release has one
+    pub download_url: Option<String>,
+    /// Link to the release page on GitHub
+    pub html_url: String,
+}
+
+/// The update found by the most recent check, if any
+///
+/// Written by the startup check thread and the "Check for Updates" built-in;
+/// read by the render loop (for the one-shot toast) and the changelog/install
+/// built-ins.
+static AVAILABLE_UPDATE: OnceLock<Mutex<Option<ReleaseInfo>>> = OnceLock::new();
+
+/// Whether the availability toast has been shown for the current update
+static UPDATE_NOTIFIED: OnceLock<Mutex<bool>> = OnceLock::new();
+
+fn available_update_cell() -> &'static Mutex<Option<ReleaseInfo>> {
+    AVAILABLE_UPDATE.get_or_init(|| Mutex::new(None))
+}
+
+fn notified_cell() -> &'static Mutex<bool> {
+    UPDATE_NOTIFIED.get_or_init(|| Mutex::new(false))
+}
+
+/// Store the result of an update check (resets the toast-shown flag)
+pub fn set_available_update(release: Option<ReleaseInfo>) {
+    *available_update_cell().lock().unwrap() = release;
+    *notified_cell().lock().unwrap() = false;
+}
+
+/// The currently known update, if any (does not affect toast state)
+pub fn available_update() -> Option<ReleaseInfo> {
+    available_update_cell().lock().unwrap().clone()
+}
+
+/// Returns the available update the first time it is called after a check,
+/// then None until a new check completes. Used by the render loop so the
+/// availability toast only fires once per discovered update.
+pub fn take_unnotified_update() -> Option<ReleaseInfo> {
+    let update = available_update_cell().lock().unwrap().clone()?;
+    let mut notified = notified_cell().lock().unwrap();
+    if *notified {
+        return None;
+    }
+    *notified = true;
+    Some(update)
+}
+
+/// Parse a semver-ish version string ("1.2.3" or "v1.2.3") into components
+///
+/// Missing components default to 0, so "1.2" parses as (1, 2, 0).
+/// Returns None when the leading component is not numeric.
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let trimmed = version.trim().trim_start_matches('v');
+    let mut parts = trimmed.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+    let patch = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+/// Whether `remote` is a strictly newer version than `current`
+///
+/// Unparseable versions are never considered newer (fail closed so a
+/// malformed tag can't trigger an update prompt).
+pub fn is_newer(remote: &str, current: &str) -> bool {
+    match (parse_version(remote), parse_version(current)) {
+        (Some(r), Some(c)) => r > c,
+        _ => false,
+    }
+}
+
+/// Parse a GitHub release API response into a ReleaseInfo
+///
+/// Returns None when the response is missing a tag name. The download URL
+/// comes from the first `.zip` asset, if any.
+pub fn parse_release(json: &serde_json::Value) -> Option<ReleaseInfo> {
+    let tag = json.get("tag_name")?.as_str()?;
+    let version = tag.trim_start_matches('v').to_string();
+    let changelog = json
+        .get("body")
+        .and_then(|b| b.as_str())
+        .unwrap_or("")
+        .to_string();
+    let html_url = json
+        .get("html_url")
+        .and_then(|u| u.as_str())
+        .unwrap_or("")
+        .to_string();
+    let download_url = json
+        .get("assets")
+        .and_then(|a| a.as_array())
+        .and_then(|assets| {
+            assets.iter().find_map(|asset| {
+                let url = asset.get("browser_download_url")?.as_str()?;
+                if url.ends_with(".zip") {
+                    Some(url.to_string())
+                } else {
+                    None
+                }
+            })
+        });
+    Some(ReleaseInfo {
+        version,
+        changelog,
+        download_url,
+        html_url,
+    })
+}
+
+/// Fetch the latest release from the GitHub releases API
+///
+/// Blocking — run off the UI thread.
+pub fn fetch_latest_release() -> Result<ReleaseInfo> {
+    let response = ureq::get(RELEASES_URL)
+        .header(
+            "User-Agent",
+            &format!("script-kit-gpui/{}", CURRENT_VERSION),
+        )
+        .header("Accept", "application/vnd.github+json")
+        .call()
+        .context("Failed to query GitHub releases API")?;
+
+    let json: serde_json::Value = response
+        .into_body()
+        .read_json()
+        .context("Failed to parse releases response")?;
+
+    parse_release(&json).context("Release response missing tag_name")
+}
+
+/// Query the GitHub releases API for the latest release
+///
+/// Returns Ok(Some) when a release newer than CURRENT_VERSION exists,
+/// Ok(None) when we're up to date. Blocking — run off the UI thread.
+pub fn check_for_update() -> Result<Option<ReleaseInfo>> {
+    let release = fetch_latest_release()?;
+    if is_newer(&release.version, CURRENT_VERSION) {
+        Ok(Some(release))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Render release notes as simple HTML for the changelog div prompt
+///
+/// The markdown body is HTML-escaped and shown preformatted under a
+/// version header — enough for release notes without a markdown renderer.
+pub fn changelog_html(release: &ReleaseInfo) -> String {
+    let escaped = release
+        .changelog
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    let body = if escaped.trim().is_empty() {
+        "No release notes provided.".to_string()
+    } else {
+        escaped
+    };
+    format!(
+        "<div><h1>Script Kit {}</h1><pre>{}</pre></div>",
+        release.version, body
+    )
+}
+
+/// Path to the .app bundle containing the running binary, if there is one
+///
+/// Walks up from the current executable looking for a path component ending
+/// in ".app" (e.g. /Applications/Script Kit.app/Contents/MacOS/script-kit).
+fn current_bundle_path() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    exe.ancestors()
+        .find(|p| p.extension().is_some_and(|ext| ext == "app"))
+        .map(|p| p.to_path_buf())
+}
+
+/// Download the release asset, swap the app bundle, and relaunch
+///
+/// Steps:
+/// 1. Download the .zip asset to a temp directory
+/// 2. Extract it with `ditto -xk` (preserves macOS bundle metadata)
+/// 3. Move the current bundle aside and move the new one into place
+/// 4. Relaunch via `open -n` and exit this process
+///
+/// Blocking — run off the UI thread. Errors leave the current install
+/// untouched (the swap is the last step).
+pub fn download_and_install(release: &ReleaseInfo) -> Result<()> {
+    let url = release
+        .download_url
+        .as_deref()
+        .context("Release has no downloadable .zip asset")?;
+    let bundle_path = current_bundle_path()
+        .context("Not running from an .app bundle - install updates manually")?;
+
+    let staging = std::env::temp_dir().join(format!("script-kit-update-{}", release.version));
+    let _ = std::fs::remove_dir_all(&staging);
+    std::fs::create_dir_all(&staging).context("Failed to create staging directory")?;
+    let zip_path = staging.join("update.zip");
+
+    crate::logging::log("UPDATER", &format!("Downloading {}", url));
+    let response = ureq::get(url)
+        .header(
+            "User-Agent",
+            &format!("script-kit-gpui/{}", CURRENT_VERSION),
+        )
+        .call()
+        .context("Failed to download update asset")?;
+    let mut reader = response.into_body().into_reader();
+    let mut file = std::fs::File::create(&zip_path).context("Failed to create download file")?;
+    std::io::copy(&mut reader, &mut file).context("Failed to write update asset")?;
+
+    // ditto preserves resource forks, symlinks, and code signatures
+    let status = std::process::Command::new("ditto")
+        .arg("-xk")
+        .arg(&zip_path)
+        .arg(&staging)
+        .status()
+        .context("Failed to run ditto")?;
+    if !status.success() {
+        anyhow::bail!("ditto extraction failed with {}", status);
+    }
+
+    // Find the extracted .app bundle
+    let new_bundle = std::fs::read_dir(&staging)
+        .context("Failed to read staging directory")?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.extension().is_some_and(|ext| ext == "app"))
+        .context("Update archive did not contain an .app bundle")?;
+
+    // Swap: move current bundle aside, then move the new one into place.
+    // Keep the old bundle next to the new one so a failed relaunch is recoverable.
+    let old_bundle = bundle_path.with_extension("app.old");
+    let _ = std::fs::remove_dir_all(&old_bundle);
+    std::fs::rename(&bundle_path, &old_bundle).context("Failed to move current bundle aside")?;
+    if let Err(e) = std::fs::rename(&new_bundle, &bundle_path) {
+        // Roll back so the user still has a working install
+        let _ = std::fs::rename(&old_bundle, &bundle_path);
+        return Err(e).context("Failed to move new bundle into place");
+    }
+
+    crate::logging::log(
+        "UPDATER",
+        &format!(
+            "Installed {} at {} - relaunching",
+            release.version,
+            bundle_path.display()
+        ),
+    );
+    std::process::Command::new("open")
+        .arg("-n")
+        .arg(&bundle_path)
+        .spawn()
+        .context("Failed to relaunch - start the app manually")?;
+    std::process::exit(0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_variants() {
+        assert_eq!(parse_version("1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("v0.10.0"), Some((0, 10, 0)));
+        assert_eq!(parse_version("1.2"), Some((1, 2, 0)));
+        assert_eq!(parse_version("2"), Some((2, 0, 0)));
+        assert_eq!(parse_version("not-a-version"), None);
+    }
+
+    #[test]
+    fn test_is_newer_comparisons() {
+        assert!(is_newer("0.2.0", "0.1.0"));
+        assert!(is_newer("1.0.0", "0.99.99"));
+        assert!(is_newer("0.1.10", "0.1.9"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1.0", "0.2.0"));
+        // Malformed versions never trigger an update
+        assert!(!is_newer("nightly", "0.1.0"));
+    }
+
+    #[test]
+    fn test_parse_release_full() {
+        let json = serde_json::json!({
+            "tag_name": "v0.3.0",
+            "body": "## Changes\n- Fixed things",
+            "html_url": "https://github.com/johnlindquist/script-kit-gpui/releases/tag/v0.3.0",
+            "assets": [
+                {"browser_download_url": "https://example.com/ScriptKit.dmg"},
+                {"browser_download_url": "https://example.com/ScriptKit.zip"}
+            ]
+        });
+        let release = parse_release(&json).unwrap();
+        assert_eq!(release.version, "0.3.0");
+        assert_eq!(release.changelog, "## Changes\n- Fixed things");
+        assert_eq!(
+            release.download_url.as_deref(),
+            Some("https://example.com/ScriptKit.zip")
+        );
+    }
+
+    #[test]
+    fn test_parse_release_missing_tag() {
+        let json = serde_json::json!({"body": "notes"});
+        assert!(parse_release(&json).is_none());
+    }
+
+    #[test]
+    fn test_changelog_html_escapes_markup() {
+        let release = ReleaseInfo {
+            version: "0.2.0".to_string(),
+            changelog: "Support <div> & friends".to_string(),
+            download_url: None,
+            html_url: String::new(),
+        };
+        let html = changelog_html(&release);
+        assert!(html.contains("Support &lt;div&gt; &amp; friends"));
+        assert!(html.contains("Script Kit 0.2.0"));
+    }
+
+    #[test]
+    fn test_changelog_html_empty_body() {
+        let release = ReleaseInfo {
+            version: "0.2.0".to_string(),
+            changelog: "  ".to_string(),
+            download_url: None,
+            html_url: String::new(),
+        };
+        assert!(changelog_html(&release).contains("No release notes provided."));
+    }
+
+    #[test]
+    fn test_unnotified_update_is_one_shot() {
+        let release = ReleaseInfo {
+            version: "9.9.9".to_string(),
+            changelog: String::new(),
+            download_url: None,
+            html_url: String::new(),
+        };
+        set_available_update(Some(release.clone()));
+        assert_eq!(take_unnotified_update(), Some(release.clone()));
+        assert_eq!(take_unnotified_update(), None);
+        assert_eq!(available_update(), Some(release));
+        set_available_update(None);
+    }
+}
@@ -0,0 +1,177 @@
+//! scriptkit:// URL Scheme Handling
+//!
+//! Parses URLs delivered to the app via the `scriptkit` URL scheme that the
+//! bundle registers (see `osx_url_schemes` in Cargo.toml). Browsers, other
+//! apps, and webhooks can trigger scripts with:
+//!
+//! ```text
+//! scriptkit://run?script=my-script&arg=first&arg=second
+//! ```
+//!
+//! Scripts marked `// AllowURL: true` run immediately; everything else goes
+//! through a confirmation step in the main window so a stray link can't run
+//! arbitrary code.
+
+/// A parsed script run request from a scriptkit:// URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlRequest {
+    /// Script name (without extension) to run
+    pub script: String,
+    /// Positional arguments, in URL order
+    pub args: Vec<String>,
+}
+
+/// Decode percent-encoding (and `+` as space) in a URL component
+///
+/// Invalid escapes are passed through unchanged rather than rejected, since
+/// URLs arrive from arbitrary external callers.
+pub fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = &input[i + 1..i + 3];
+                if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                    out.push(byte);
+                    i += 3;
+                } else {
+                    out.push(b'%');
+                    i += 1;
+                }
+            }
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Percent-encode a string for use as a URL query component
+///
+/// Unreserved characters (RFC 3986: alphanumerics, `-`, `_`, `.`, `~`) pass
+/// through; everything else is encoded byte-by-byte as `%XX`.
+pub fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Parse a scriptkit:// URL into a run request
+///
+/// Accepts `scriptkit://run?script=name&arg=value` with `arg` repeatable.
+/// Returns `None` for other schemes, other actions, or a missing script name.
+pub fn parse_scriptkit_url(url: &str) -> Option<UrlRequest> {
+    let rest = url.strip_prefix("scriptkit://")?;
+    let (action, query) = match rest.split_once('?') {
+        Some((action, query)) => (action, query),
+        None => (rest, ""),
+    };
+
+    // Only the run action is supported; tolerate a trailing slash
+    if action.trim_end_matches('/') != "run" {
+        return None;
+    }
+
+    let mut script = None;
+    let mut args = Vec::new();
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = match pair.split_once('=') {
+            Some((key, value)) => (key, percent_decode(value)),
+            None => (pair, String::new()),
+        };
+        match key {
+            "script" if script.is_none() => script = Some(value),
+            "arg" => args.push(value),
+            _ => {} // Ignore unknown parameters for forward compatibility
+        }
+    }
+
+    let script = script?;
+    if script.is_empty() {
+        return None;
+    }
+
+    Some(UrlRequest { script, args })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_decode_basic() {
+        assert_eq!(percent_decode("hello%20world"), "hello world");
+        assert_eq!(percent_decode("a+b"), "a b");
+        assert_eq!(percent_decode("plain"), "plain");
+    }
+
+    #[test]
+    fn test_percent_decode_invalid_escape_passes_through() {
+        assert_eq!(percent_decode("100%zz"), "100%zz");
+        assert_eq!(percent_decode("trailing%2"), "trailing%2");
+    }
+
+    #[test]
+    fn test_percent_encode_basic() {
+        assert_eq!(percent_encode("hello world"), "hello%20world");
+        assert_eq!(percent_encode("a&b=c"), "a%26b%3Dc");
+        assert_eq!(percent_encode("plain-text_1.0~"), "plain-text_1.0~");
+    }
+
+    #[test]
+    fn test_percent_encode_decode_roundtrip() {
+        let original = "rust async/await & more";
+        assert_eq!(percent_decode(&percent_encode(original)), original);
+    }
+
+    #[test]
+    fn test_parse_run_url_with_args() {
+        let req = parse_scriptkit_url("scriptkit://run?script=deploy&arg=prod&arg=fast")
+            .expect("should parse");
+        assert_eq!(req.script, "deploy");
+        assert_eq!(req.args, vec!["prod", "fast"]);
+    }
+
+    #[test]
+    fn test_parse_run_url_without_args() {
+        let req = parse_scriptkit_url("scriptkit://run?script=hello").expect("should parse");
+        assert_eq!(req.script, "hello");
+        assert!(req.args.is_empty());
+    }
+
+    #[test]
+    fn test_parse_run_url_decodes_script_name() {
+        let req = parse_scriptkit_url("scriptkit://run?script=my%20script").expect("should parse");
+        assert_eq!(req.script, "my script");
+    }
+
+    #[test]
+    fn test_parse_rejects_other_schemes_and_actions() {
+        assert!(parse_scriptkit_url("https://run?script=x").is_none());
+        assert!(parse_scriptkit_url("scriptkit://open?script=x").is_none());
+        assert!(parse_scriptkit_url("scriptkit://run").is_none());
+        assert!(parse_scriptkit_url("scriptkit://run?script=").is_none());
+    }
+
+    #[test]
+    fn test_parse_ignores_unknown_params() {
+        let req = parse_scriptkit_url("scriptkit://run?script=x&token=abc").expect("should parse");
+        assert_eq!(req.script, "x");
+        assert!(req.args.is_empty());
+    }
+}
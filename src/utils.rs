@@ -1426,6 +1426,64 @@ fn get_rose_color(shade: u32) -> Option<u32> {
     }
 }
 
+// ============================================================================
+// Scriptlet Source Navigation
+// ============================================================================
+
+/// Find the 1-based line number of a scriptlet's `## Name` heading in markdown.
+///
+/// Headings inside code fences are ignored so a scriptlet whose code happens to
+/// contain `## something` doesn't produce a false match. Returns `None` when no
+/// matching heading exists.
+#[allow(dead_code)]
+pub fn find_scriptlet_heading_line(content: &str, name: &str) -> Option<usize> {
+    let mut in_fence = false;
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            if heading.trim() == name {
+                return Some(i + 1);
+            }
+        }
+    }
+    None
+}
+
+/// Build editor arguments that open `path` at `line`.
+///
+/// Different editors use different goto syntaxes:
+/// - VS Code family (`code`, `cursor`, `windsurf`, `codium`): `-g path:line`
+/// - Sublime Text (`subl`): `path:line`
+/// - vi family and emacs (`vim`, `nvim`, `vi`, `emacs`): `+line path`
+///
+/// Unknown editors just get the path; opening the file is better than failing.
+#[allow(dead_code)]
+pub fn editor_goto_args(editor: &str, path: &str, line: usize) -> Vec<String> {
+    // The editor may be configured with a full path (e.g. /usr/local/bin/code)
+    let editor_name = std::path::Path::new(editor)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| editor.to_string());
+
+    match editor_name.as_str() {
+        "code" | "cursor" | "windsurf" | "codium" => {
+            vec!["-g".to_string(), format!("{}:{}", path, line)]
+        }
+        "subl" => vec![format!("{}:{}", path, line)],
+        "vim" | "nvim" | "vi" | "emacs" => {
+            vec![format!("+{}", line), path.to_string()]
+        }
+        _ => vec![path.to_string()],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1966,4 +2024,49 @@ mod tests {
         assert_eq!(result[1], ("o".to_string(), true));
         assert_eq!(result[2], ("ther.txt".to_string(), false));
     }
+
+    // ========================================================================
+    // Scriptlet source navigation tests
+    // ========================================================================
+
+    #[test]
+    fn test_find_scriptlet_heading_line_basic() {
+        let md = "# Group\n\n## First\n\n```bash\necho hi\n```\n\n## Second\n";
+        assert_eq!(find_scriptlet_heading_line(md, "First"), Some(3));
+        assert_eq!(find_scriptlet_heading_line(md, "Second"), Some(9));
+        assert_eq!(find_scriptlet_heading_line(md, "Missing"), None);
+    }
+
+    #[test]
+    fn test_find_scriptlet_heading_line_ignores_fenced_headings() {
+        let md = "## Real\n\n```md\n## Fake\n```\n\n## Fake\n";
+        // The first "## Fake" is inside a code fence; the match is line 7
+        assert_eq!(find_scriptlet_heading_line(md, "Fake"), Some(7));
+    }
+
+    #[test]
+    fn test_editor_goto_args_vscode_family() {
+        assert_eq!(
+            editor_goto_args("code", "/tmp/a.md", 12),
+            vec!["-g".to_string(), "/tmp/a.md:12".to_string()]
+        );
+        // Full path to the editor binary resolves to the same syntax
+        assert_eq!(
+            editor_goto_args("/usr/local/bin/cursor", "/tmp/a.md", 3),
+            vec!["-g".to_string(), "/tmp/a.md:3".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_editor_goto_args_vim_and_unknown() {
+        assert_eq!(
+            editor_goto_args("vim", "/tmp/a.md", 5),
+            vec!["+5".to_string(), "/tmp/a.md".to_string()]
+        );
+        // Unknown editors fall back to just opening the file
+        assert_eq!(
+            editor_goto_args("my-editor", "/tmp/a.md", 5),
+            vec!["/tmp/a.md".to_string()]
+        );
+    }
 }
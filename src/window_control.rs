@@ -931,6 +931,221 @@ pub fn focus_window(window_id: u32) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Window Thumbnails (CoreGraphics window capture)
+// ============================================================================
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGWindowListCopyWindowInfo(option: u32, relative_to_window: u32) -> CFArrayRef;
+    fn CGWindowListCreateImage(
+        screen_bounds: CGRect,
+        list_option: u32,
+        window_id: u32,
+        image_option: u32,
+    ) -> *const c_void;
+    fn CGImageGetWidth(image: *const c_void) -> usize;
+    fn CGImageGetHeight(image: *const c_void) -> usize;
+    fn CGImageGetBytesPerRow(image: *const c_void) -> usize;
+    fn CGImageGetBitsPerPixel(image: *const c_void) -> usize;
+    fn CGImageGetDataProvider(image: *const c_void) -> *const c_void;
+    fn CGImageRelease(image: *const c_void);
+    fn CGDataProviderCopyData(provider: *const c_void) -> CFTypeRef;
+    fn CFDataGetBytePtr(data: CFTypeRef) -> *const u8;
+    fn CFDataGetLength(data: CFTypeRef) -> i64;
+    fn CFDictionaryGetValue(dict: CFTypeRef, key: CFStringRef) -> CFTypeRef;
+}
+
+// CGWindowList options
+const kCGWindowListOptionOnScreenOnly: u32 = 1 << 0;
+const kCGWindowListOptionIncludingWindow: u32 = 1 << 3;
+const kCGWindowListExcludeDesktopElements: u32 = 1 << 4;
+const kCGNullWindowID: u32 = 0;
+
+// CGWindowImage options
+const kCGWindowImageBoundsIgnoreFraming: u32 = 1 << 0;
+const kCGWindowImageNominalResolution: u32 = 1 << 4;
+
+/// A captured window image as raw BGRA pixels
+///
+/// CoreGraphics window captures come back 32 bits per pixel in little-endian
+/// ARGB order, which in memory is the BGRA layout GPUI's Metal renderer
+/// expects - no channel swap needed.
+#[derive(Debug, Clone)]
+pub struct WindowThumbnail {
+    pub width: u32,
+    pub height: u32,
+    /// Tightly packed BGRA pixel data (width * height * 4 bytes)
+    pub bgra: Vec<u8>,
+}
+
+/// Find the CGWindowID for one of our AX-derived windows
+///
+/// Our `WindowInfo::id` is synthetic (pid << 16 | index), but
+/// `CGWindowListCreateImage` needs the real CoreGraphics window number.
+/// Match by owner pid, preferring an exact title match; untitled CG windows
+/// are kept as a fallback since many apps don't expose kCGWindowName.
+fn cg_window_id_for(window: &WindowInfo) -> Option<u32> {
+    unsafe {
+        let list = CGWindowListCopyWindowInfo(
+            kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+            kCGNullWindowID,
+        );
+        if list.is_null() {
+            return None;
+        }
+
+        let pid_key = create_cf_string("kCGWindowOwnerPID");
+        let number_key = create_cf_string("kCGWindowNumber");
+        let name_key = create_cf_string("kCGWindowName");
+
+        // (window number, match quality): 2 = exact title, 1 = untitled, 0 = other
+        let mut best: Option<(u32, i32)> = None;
+        let count = CFArrayGetCount(list);
+        for i in 0..count {
+            let dict = CFArrayGetValueAtIndex(list, i);
+            if dict.is_null() {
+                continue;
+            }
+
+            let pid_ref = CFDictionaryGetValue(dict, pid_key);
+            let mut pid: i32 = 0;
+            if pid_ref.is_null()
+                || !CFNumberGetValue(
+                    pid_ref,
+                    kCFNumberSInt32Type,
+                    &mut pid as *mut _ as *mut c_void,
+                )
+                || pid != window.pid
+            {
+                continue;
+            }
+
+            let number_ref = CFDictionaryGetValue(dict, number_key);
+            let mut number: i32 = 0;
+            if number_ref.is_null()
+                || !CFNumberGetValue(
+                    number_ref,
+                    kCFNumberSInt32Type,
+                    &mut number as *mut _ as *mut c_void,
+                )
+            {
+                continue;
+            }
+
+            let title = {
+                let name_ref = CFDictionaryGetValue(dict, name_key);
+                if name_ref.is_null() {
+                    None
+                } else {
+                    cf_string_to_string(name_ref)
+                }
+            };
+
+            let score = match title.as_deref() {
+                Some(t) if t == window.title => 2,
+                Some("") | None => 1,
+                Some(_) => 0,
+            };
+
+            // The CG list is front-to-back, so the first window at a given
+            // score wins ties (most likely the one the user means)
+            if best.map_or(true, |(_, s)| score > s) {
+                best = Some((number as u32, score));
+            }
+        }
+
+        CFRelease(pid_key);
+        CFRelease(number_key);
+        CFRelease(name_key);
+        CFRelease(list);
+
+        best.map(|(number, _)| number)
+    }
+}
+
+/// Capture a thumbnail of a window's current contents
+///
+/// Uses `CGWindowListCreateImage` at nominal (1x) resolution so thumbnails
+/// stay small. Requires the Screen Recording permission in System Settings >
+/// Privacy & Security; without it macOS returns an empty or blank image and
+/// this returns an error.
+#[instrument(name = "capture_window_thumbnail", skip(window), fields(window_id = window.id))]
+pub fn capture_window_thumbnail(window: &WindowInfo) -> Result<WindowThumbnail> {
+    let cg_window_id = cg_window_id_for(window)
+        .context("Window not found in CoreGraphics window list (it may have closed)")?;
+
+    unsafe {
+        // CGRectNull: capture the window's own bounds
+        let null_rect = CGRect::new(
+            &core_graphics::geometry::CGPoint::new(f64::INFINITY, f64::INFINITY),
+            &core_graphics::geometry::CGSize::new(0.0, 0.0),
+        );
+        let image = CGWindowListCreateImage(
+            null_rect,
+            kCGWindowListOptionIncludingWindow,
+            cg_window_id,
+            kCGWindowImageBoundsIgnoreFraming | kCGWindowImageNominalResolution,
+        );
+        if image.is_null() {
+            bail!("CGWindowListCreateImage returned null (Screen Recording permission missing?)");
+        }
+
+        let width = CGImageGetWidth(image);
+        let height = CGImageGetHeight(image);
+        let bytes_per_row = CGImageGetBytesPerRow(image);
+        let bits_per_pixel = CGImageGetBitsPerPixel(image);
+
+        if width == 0 || height == 0 {
+            CGImageRelease(image);
+            bail!("Captured image is empty (Screen Recording permission missing?)");
+        }
+        if bits_per_pixel != 32 {
+            CGImageRelease(image);
+            bail!("Unexpected pixel format: {} bits per pixel", bits_per_pixel);
+        }
+
+        let provider = CGImageGetDataProvider(image);
+        if provider.is_null() {
+            CGImageRelease(image);
+            bail!("Captured image has no data provider");
+        }
+        let data = CGDataProviderCopyData(provider);
+        if data.is_null() {
+            CGImageRelease(image);
+            bail!("Failed to copy captured image data");
+        }
+
+        let data_ptr = CFDataGetBytePtr(data);
+        let data_len = CFDataGetLength(data) as usize;
+
+        // Copy row by row: bytes_per_row can include padding beyond width * 4
+        let row_bytes = width * 4;
+        let mut bgra = Vec::with_capacity(row_bytes * height);
+        for row in 0..height {
+            let offset = row * bytes_per_row;
+            if offset + row_bytes > data_len {
+                break;
+            }
+            bgra.extend_from_slice(std::slice::from_raw_parts(data_ptr.add(offset), row_bytes));
+        }
+
+        CFRelease(data);
+        CGImageRelease(image);
+
+        if bgra.len() != row_bytes * height {
+            bail!("Captured image data was truncated");
+        }
+
+        debug!(width, height, "Captured window thumbnail");
+        Ok(WindowThumbnail {
+            width: width as u32,
+            height: height as u32,
+            bgra,
+        })
+    }
+}
+
 // ============================================================================
 // Helper Functions for Display Bounds
 // ============================================================================
@@ -0,0 +1,374 @@
+//! World clock and timezone conversion for the World Clock builtin
+//!
+//! Turns filter text into clock rows: a bare "time" query lists the
+//! favorite timezones (configurable via `worldClockFavorites` in
+//! config.json), "time in tokyo" looks up the current time in matching
+//! zones, and "3pm PT in CET" converts a wall-clock time between zones.
+//! Zone names accept common abbreviations (PT, CET, IST, ...) and IANA
+//! city names; DST is handled by chrono-tz so offsets are correct
+//! year-round.
+
+#![allow(dead_code)]
+
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Favorites shown for a bare "time" query when none are configured
+const DEFAULT_FAVORITES: &[&str] = &[
+    "America/Los_Angeles",
+    "America/New_York",
+    "Europe/London",
+    "Europe/Berlin",
+    "Asia/Tokyo",
+];
+
+/// Cap on zone-search matches so broad queries stay readable
+const MAX_ZONE_MATCHES: usize = 8;
+
+/// Common zone abbreviations and shorthand mapped to IANA names
+///
+/// DST-observing abbreviations map to a representative city so chrono-tz
+/// picks the offset in effect on the queried date.
+const ALIASES: &[(&str, &str)] = &[
+    ("pt", "America/Los_Angeles"),
+    ("pst", "America/Los_Angeles"),
+    ("pdt", "America/Los_Angeles"),
+    ("pacific", "America/Los_Angeles"),
+    ("mt", "America/Denver"),
+    ("mst", "America/Denver"),
+    ("mdt", "America/Denver"),
+    ("mountain", "America/Denver"),
+    ("ct", "America/Chicago"),
+    ("cst", "America/Chicago"),
+    ("cdt", "America/Chicago"),
+    ("central", "America/Chicago"),
+    ("et", "America/New_York"),
+    ("est", "America/New_York"),
+    ("edt", "America/New_York"),
+    ("eastern", "America/New_York"),
+    ("utc", "UTC"),
+    ("gmt", "UTC"),
+    ("bst", "Europe/London"),
+    ("cet", "Europe/Paris"),
+    ("cest", "Europe/Paris"),
+    ("eet", "Europe/Helsinki"),
+    ("eest", "Europe/Helsinki"),
+    ("msk", "Europe/Moscow"),
+    ("gst", "Asia/Dubai"),
+    ("ist", "Asia/Kolkata"),
+    ("india", "Asia/Kolkata"),
+    ("sgt", "Asia/Singapore"),
+    ("hkt", "Asia/Hong_Kong"),
+    ("jst", "Asia/Tokyo"),
+    ("japan", "Asia/Tokyo"),
+    ("kst", "Asia/Seoul"),
+    ("korea", "Asia/Seoul"),
+    ("aest", "Australia/Sydney"),
+    ("aedt", "Australia/Sydney"),
+    ("nzst", "Pacific/Auckland"),
+    ("nzdt", "Pacific/Auckland"),
+    ("brt", "America/Sao_Paulo"),
+];
+
+/// One row in the World Clock builtin
+#[derive(Clone, Debug)]
+pub struct ClockRow {
+    /// Time plus zone, e.g. "21:00 Tokyo"
+    pub title: String,
+    /// Date and UTC offset context, e.g. "Thu Jan 15 · UTC+09:00"
+    pub description: String,
+    /// What Enter copies to the clipboard
+    pub copy_text: String,
+}
+
+/// Compute the rows for the current filter text
+pub fn results(query: &str, favorites: &[String]) -> Vec<ClockRow> {
+    rows_at(Utc::now(), query, favorites)
+}
+
+/// [`results`] with an injectable clock for tests
+fn rows_at(now: DateTime<Utc>, query: &str, favorites: &[String]) -> Vec<ClockRow> {
+    let query = query.trim().to_lowercase();
+
+    // "time" / "time in tokyo" — favorites or a zone lookup
+    if let Some(rest) = strip_word(&query, "time") {
+        let rest = strip_word(rest, "in").unwrap_or(rest);
+        if rest.is_empty() {
+            return favorite_rows(now, favorites);
+        }
+        return search_zones(rest)
+            .into_iter()
+            .map(|tz| current_row(now, tz))
+            .collect();
+    }
+
+    // "3pm pt in cet" — wall-clock conversion between two zones
+    if let Some((left, right)) = query.split_once(" in ") {
+        if let Some(rows) = conversion_rows(now, left.trim(), right.trim()) {
+            return rows;
+        }
+    }
+
+    // Plain zone lookup ("tokyo", "cet")
+    search_zones(&query)
+        .into_iter()
+        .map(|tz| current_row(now, tz))
+        .collect()
+}
+
+/// Current time in each favorite (configured or [`DEFAULT_FAVORITES`])
+fn favorite_rows(now: DateTime<Utc>, favorites: &[String]) -> Vec<ClockRow> {
+    let configured: Vec<Tz> = favorites.iter().filter_map(|f| resolve_zone(f)).collect();
+    let zones: Vec<Tz> = if configured.is_empty() {
+        DEFAULT_FAVORITES
+            .iter()
+            .filter_map(|f| resolve_zone(f))
+            .collect()
+    } else {
+        configured
+    };
+    zones.into_iter().map(|tz| current_row(now, tz)).collect()
+}
+
+/// Convert `left` ("3pm pt") into the zone(s) named by `right` ("cet")
+///
+/// Returns None when the left side doesn't parse as a time, so the caller
+/// can fall through to a plain zone lookup.
+fn conversion_rows(now: DateTime<Utc>, left: &str, right: &str) -> Option<Vec<ClockRow>> {
+    let (time_part, zone_part) = match left.split_once(char::is_whitespace) {
+        Some((t, z)) => (t, z.trim()),
+        None => (left, ""),
+    };
+    let (hour, minute) = parse_clock_time(time_part)?;
+    let src = if zone_part.is_empty() {
+        resolve_zone("utc")?
+    } else {
+        resolve_zone(zone_part)?
+    };
+
+    // Interpret the time as today's wall clock in the source zone
+    let date = now.with_timezone(&src).date_naive();
+    let naive = date.and_hms_opt(hour, minute, 0)?;
+    let src_dt = src.from_local_datetime(&naive).single()?;
+
+    let targets = search_zones(right);
+    if targets.is_empty() {
+        return None;
+    }
+    Some(
+        targets
+            .into_iter()
+            .map(|dst| {
+                let out = src_dt.with_timezone(&dst);
+                let time = out.format("%H:%M").to_string();
+                ClockRow {
+                    title: format!("{} {}", time, display_name(&dst)),
+                    description: format!(
+                        "{} {} · {} · UTC{}",
+                        src_dt.format("%H:%M"),
+                        display_name(&src),
+                        out.format("%a %b %-d"),
+                        out.format("%:z")
+                    ),
+                    copy_text: time,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Current-time row for one zone
+fn current_row(now: DateTime<Utc>, tz: Tz) -> ClockRow {
+    let local = now.with_timezone(&tz);
+    let time = local.format("%H:%M").to_string();
+    ClockRow {
+        title: format!("{} {}", time, display_name(&tz)),
+        description: format!("{} · UTC{}", local.format("%a %b %-d"), local.format("%:z")),
+        copy_text: time,
+    }
+}
+
+/// Human name for a zone: the last IANA segment with underscores spaced
+fn display_name(tz: &Tz) -> String {
+    tz.name()
+        .rsplit('/')
+        .next()
+        .unwrap_or(tz.name())
+        .replace('_', " ")
+}
+
+/// Resolve one zone name (alias, exact IANA name, or city search)
+fn resolve_zone(name: &str) -> Option<Tz> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+    if let Some((_, iana)) = ALIASES.iter().find(|(alias, _)| *alias == name) {
+        return iana.parse().ok();
+    }
+    search_zones(&name).into_iter().next()
+}
+
+/// Zones whose city segment matches the query, best matches first
+fn search_zones(query: &str) -> Vec<Tz> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    if let Some((_, iana)) = ALIASES.iter().find(|(alias, _)| *alias == query) {
+        return iana.parse().into_iter().collect();
+    }
+    // Exact IANA names ("Asia/Tokyo", "asia/tokyo") bypass the city search
+    if let Ok(tz) = query.replace(' ', "_").parse::<Tz>() {
+        return vec![tz];
+    }
+    if let Some(tz) = exact_iana_match(&query) {
+        return vec![tz];
+    }
+
+    let mut matches: Vec<Tz> = chrono_tz::TZ_VARIANTS
+        .iter()
+        .filter(|tz| city_of(tz).contains(&query))
+        .copied()
+        .collect();
+    // Exact city matches first, then shortest names (closest match)
+    matches.sort_by_key(|tz| (city_of(tz) != query, tz.name().len()));
+    matches.truncate(MAX_ZONE_MATCHES);
+    matches
+}
+
+/// Case-insensitive exact match on a full IANA name
+fn exact_iana_match(query: &str) -> Option<Tz> {
+    let normalized = query.replace(' ', "_");
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().to_lowercase() == normalized)
+        .copied()
+}
+
+/// Lowercased city segment of an IANA name ("new york" for America/New_York)
+fn city_of(tz: &Tz) -> String {
+    tz.name()
+        .rsplit('/')
+        .next()
+        .unwrap_or("")
+        .to_lowercase()
+        .replace('_', " ")
+}
+
+/// Parse "3pm", "3:30pm", "15:00", or "15" into (hour, minute)
+fn parse_clock_time(s: &str) -> Option<(u32, u32)> {
+    let s = s.trim().to_lowercase();
+    let (digits, meridiem) = if let Some(rest) = s.strip_suffix("am") {
+        (rest.trim(), Some(false))
+    } else if let Some(rest) = s.strip_suffix("pm") {
+        (rest.trim(), Some(true))
+    } else {
+        (s.as_str(), None)
+    };
+
+    let (hour_str, minute_str) = match digits.split_once(':') {
+        Some((h, m)) => (h, m),
+        None => (digits, "0"),
+    };
+    let mut hour: u32 = hour_str.parse().ok()?;
+    let minute: u32 = minute_str.parse().ok()?;
+
+    match meridiem {
+        Some(pm) => {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            hour %= 12;
+            if pm {
+                hour += 12;
+            }
+        }
+        None => {
+            if hour > 23 {
+                return None;
+            }
+        }
+    }
+    if minute > 59 {
+        return None;
+    }
+    Some((hour, minute))
+}
+
+/// Strip a leading word followed by whitespace (or the whole string)
+///
+/// Unlike `strip_prefix`, "time" does not match "timezone".
+fn strip_word<'a>(s: &'a str, word: &str) -> Option<&'a str> {
+    let rest = s.strip_prefix(word)?;
+    if rest.is_empty() {
+        return Some(rest);
+    }
+    if rest.starts_with(char::is_whitespace) {
+        return Some(rest.trim_start());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noon_utc_january() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_bare_time_query_shows_default_favorites() {
+        let rows = rows_at(noon_utc_january(), "time", &[]);
+        assert_eq!(rows.len(), DEFAULT_FAVORITES.len());
+        assert!(rows.iter().any(|r| r.title.ends_with("Tokyo")));
+    }
+
+    #[test]
+    fn test_configured_favorites_override_defaults() {
+        let rows = rows_at(
+            noon_utc_january(),
+            "time",
+            &["Asia/Tokyo".to_string(), "cet".to_string()],
+        );
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].title, "21:00 Tokyo");
+    }
+
+    #[test]
+    fn test_time_in_city_query() {
+        let rows = rows_at(noon_utc_january(), "time in tokyo", &[]);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].title, "21:00 Tokyo");
+        assert!(rows[0].description.contains("UTC+09:00"));
+    }
+
+    #[test]
+    fn test_conversion_across_midnight() {
+        // 3pm PST on Jan 15 is 23:00 UTC, which is 00:00 CET the next day
+        let rows = rows_at(noon_utc_january(), "3pm pt in cet", &[]);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].title, "00:00 Paris");
+        assert!(rows[0].description.contains("15:00 Los Angeles"));
+        assert!(rows[0].description.contains("Jan 16"));
+    }
+
+    #[test]
+    fn test_resolve_zone_aliases_and_cities() {
+        assert_eq!(resolve_zone("pt"), Some(chrono_tz::America::Los_Angeles));
+        assert_eq!(resolve_zone("tokyo"), Some(chrono_tz::Asia::Tokyo));
+        assert_eq!(resolve_zone("new york"), Some(chrono_tz::America::New_York));
+        assert_eq!(resolve_zone(""), None);
+    }
+
+    #[test]
+    fn test_parse_clock_time_formats() {
+        assert_eq!(parse_clock_time("3pm"), Some((15, 0)));
+        assert_eq!(parse_clock_time("3:30pm"), Some((15, 30)));
+        assert_eq!(parse_clock_time("12am"), Some((0, 0)));
+        assert_eq!(parse_clock_time("15:00"), Some((15, 0)));
+        assert_eq!(parse_clock_time("25:00"), None);
+        assert_eq!(parse_clock_time("tokyo"), None);
+    }
+}